@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 use components::ui::toast::{ToastManager, ToastFrame};
+use components::db::notifications::NotificationWatcher;
 use assets::Assets;
 use pages::routes::Route;
 
@@ -19,5 +20,6 @@ fn App() -> Element {
         document::Title { "My Desktop Application" }
         Router::<Route> {}
         ToastFrame { manager: toast_manager }
+        NotificationWatcher {}
     }
 }
\ No newline at end of file
@@ -0,0 +1,109 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::borrowers::create_borrower;
+use shared::models::BorrowerInput;
+use shared::money::parse_dollars;
+use crate::ui::input::{Input, InputType};
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+#[component]
+pub fn AddBorrower(on_borrower_added: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut first_name = use_signal(String::new);
+    let mut last_name = use_signal(String::new);
+    let mut email = use_signal(String::new);
+    let mut phone = use_signal(String::new);
+    let mut monthly_income = use_signal(String::new);
+
+    let on_submit = move |_| {
+        let optional = |s: String| (!s.trim().is_empty()).then(|| s.trim().to_string());
+        let income = monthly_income.read().clone();
+        let Some(monthly_income_cents) = (if income.trim().is_empty() { Some(0) } else { parse_dollars(&income) }) else {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Monthly income must be a dollar amount", Some("Invalid input")));
+            return;
+        };
+
+        let input = BorrowerInput {
+            first_name: first_name.read().trim().to_string(),
+            last_name: last_name.read().trim().to_string(),
+            email: optional(email.read().clone()),
+            phone: optional(phone.read().clone()),
+            monthly_income_cents,
+        };
+
+        spawn(async move {
+            match create_borrower(input).await {
+                Ok(borrower_id) => {
+                    tracing::info!("Created borrower with ID: {}", borrower_id);
+                    first_name.set(String::new());
+                    last_name.set(String::new());
+                    email.set(String::new());
+                    phone.set(String::new());
+                    monthly_income.set(String::new());
+                    on_borrower_added.call(borrower_id);
+                }
+                Err(e) => {
+                    tracing::error!("Failed to create borrower: {}", e);
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&e.to_string(), Some("Could not add borrower")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "my-1 flex flex-col gap-2",
+            h2 { class: "text-lg font-medium mb-3", "Add Borrower" }
+            div { class: "grid grid-cols-2 gap-2",
+                Input {
+                    name: "first_name".to_string(),
+                    input_type: Some(InputType::Text),
+                    value: Some(first_name()),
+                    label: Some("First Name".to_string()),
+                    required: Some(true),
+                    oninput: move |event: FormEvent| first_name.set(event.value()),
+                }
+                Input {
+                    name: "last_name".to_string(),
+                    input_type: Some(InputType::Text),
+                    value: Some(last_name()),
+                    label: Some("Last Name".to_string()),
+                    required: Some(true),
+                    oninput: move |event: FormEvent| last_name.set(event.value()),
+                }
+                Input {
+                    name: "email".to_string(),
+                    input_type: Some(InputType::Email),
+                    value: Some(email()),
+                    label: Some("Email".to_string()),
+                    oninput: move |event: FormEvent| email.set(event.value()),
+                }
+                Input {
+                    name: "phone".to_string(),
+                    input_type: Some(InputType::Text),
+                    value: Some(phone()),
+                    label: Some("Phone".to_string()),
+                    oninput: move |event: FormEvent| phone.set(event.value()),
+                }
+                Input {
+                    name: "monthly_income".to_string(),
+                    input_type: Some(InputType::Text),
+                    placeholder: Some("0.00".to_string()),
+                    value: Some(monthly_income()),
+                    label: Some("Gross Monthly Income".to_string()),
+                    oninput: move |event: FormEvent| monthly_income.set(event.value()),
+                }
+            }
+            div { class: "mt-4",
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    on_click: on_submit,
+                    text: "Add Borrower".to_string(),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use chrono::NaiveDate;
+use dioxus::{logger::tracing, prelude::*};
+use server::borrowers::{get_assets, get_employments, update_borrower_date_of_birth, update_borrower_ssn};
+use shared::masking::{display_date_of_birth, mask_ssn, RevealField};
+use shared::money::format_cents;
+use super::masked_value::MaskedValue;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::{DateInput, Input, InputType};
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableFoot, TableHead, TableHeaderCell, TableRow};
+
+/// SSN, date of birth, employment history and assets for one borrower, as carried in Fannie Mae files
+///
+/// `ssn` and `date_of_birth` come masked from the server (`pii_masked`);
+/// full values are shown only through [`MaskedValue`].
+#[component]
+pub fn ApplicantDetails(
+    borrower_id: i32,
+    ssn: Option<String>,
+    date_of_birth: Option<NaiveDate>,
+    pii_masked: bool,
+) -> Element {
+    let employments = use_resource(move || async move { get_employments(borrower_id).await });
+    let assets = use_resource(move || async move { get_assets(borrower_id).await });
+
+    let jobs = match &*employments.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get employments error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let holdings = match &*assets.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get assets error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let total_assets: i64 = holdings.iter().map(|asset| asset.balance_cents).sum();
+
+    rsx! {
+        div { class: "flex flex-col gap-4",
+            SsnField { borrower_id, ssn }
+            DateOfBirthField { borrower_id, date_of_birth, pii_masked }
+            Table {
+                striped: true,
+                caption: rsx! { "Employment" },
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Employer" }
+                        TableHeaderCell { "Position" }
+                        TableHeaderCell { "Status" }
+                        TableHeaderCell { "Dates" }
+                        TableHeaderCell { "Time on Job" }
+                        TableHeaderCell { "Monthly Income" }
+                        TableHeaderCell { "Source" }
+                    }
+                }
+                TableBody {
+                    for job in jobs.iter() {
+                        TableRow { key: "{job.id}",
+                            TableCell {
+                                "{job.employer_name}"
+                                if job.self_employed { " (self-employed)" }
+                            }
+                            TableCell { {job.position.clone().unwrap_or_default()} }
+                            TableCell {
+                                match (job.is_primary, job.is_current) {
+                                    (true, _) => "Primary",
+                                    (false, true) => "Secondary",
+                                    (false, false) => "Previous",
+                                }
+                            }
+                            TableCell {
+                                {job.start_date.map(|d| d.format("%m/%d/%Y").to_string()).unwrap_or_default()}
+                                if job.start_date.is_some() || job.end_date.is_some() { " – " }
+                                {job.end_date.map(|d| d.format("%m/%d/%Y").to_string()).unwrap_or_default()}
+                            }
+                            TableCell {
+                                {job.months_on_job.map(|m| format!("{} yr {} mo", m / 12, m % 12)).unwrap_or_default()}
+                            }
+                            TableCell {
+                                if job.is_primary {
+                                    "See gross monthly income"
+                                } else {
+                                    {format_cents(job.monthly_income_cents)}
+                                }
+                            }
+                            TableCell { "{job.source}" }
+                        }
+                    }
+                }
+            }
+            Table {
+                striped: true,
+                caption: rsx! { "Assets" },
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Type" }
+                        TableHeaderCell { "Institution" }
+                        TableHeaderCell { "Account" }
+                        TableHeaderCell { "Balance" }
+                        TableHeaderCell { "Source" }
+                    }
+                }
+                TableBody {
+                    for asset in holdings.iter() {
+                        TableRow { key: "{asset.id}",
+                            TableCell { "{asset.asset_type}" }
+                            TableCell { "{asset.institution_name}" }
+                            TableCell {
+                                if let Some(account) = asset.account_number.clone() {
+                                    MaskedValue { field: RevealField::AccountNumber, entity_id: asset.id, masked: account }
+                                } else {
+                                    {asset.account_last4.as_ref().map(|l| format!("…{}", l)).unwrap_or_default()}
+                                }
+                            }
+                            TableCell { {format_cents(asset.balance_cents)} }
+                            TableCell { "{asset.source}" }
+                        }
+                    }
+                }
+                TableFoot {
+                    TableRow {
+                        TableHeaderCell { colspan: Some(3), "Total" }
+                        TableCell { {format_cents(total_assets)} }
+                        TableCell { "" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Shows the last four digits of the SSN, revealable in full, and lets it be replaced
+#[component]
+fn SsnField(borrower_id: i32, ssn: Option<String>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut saved = use_signal(move || ssn.clone());
+    let mut entry = use_signal(String::new);
+
+    let on_save = move |_| {
+        let value = entry.read().trim().to_string();
+        spawn(async move {
+            match update_borrower_ssn(borrower_id, (!value.is_empty()).then_some(value)).await {
+                Ok(borrower) => {
+                    saved.set(borrower.ssn);
+                    entry.set(String::new());
+                }
+                Err(err) => {
+                    tracing::error!("update SSN error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not save SSN")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-row items-end gap-2",
+            p { class: "text-gray-600 mr-4",
+                "SSN: "
+                if let Some(ssn) = saved() {
+                    MaskedValue { key: "{ssn}", field: RevealField::Ssn, entity_id: borrower_id, masked: mask_ssn(&ssn) }
+                } else {
+                    "not on file"
+                }
+            }
+            Input {
+                name: "ssn".to_string(),
+                input_type: Some(InputType::Text),
+                placeholder: Some("123-45-6789".to_string()),
+                value: Some(entry()),
+                oninput: move |event: FormEvent| entry.set(event.value()),
+            }
+            Button {
+                button_scheme: ButtonScheme::Default,
+                on_click: on_save,
+                text: if saved().is_some() { "Replace SSN".to_string() } else { "Save SSN".to_string() },
+            }
+        }
+    }
+}
+
+/// Shows the date of birth, masked to its year, and lets it be replaced or cleared
+#[component]
+fn DateOfBirthField(borrower_id: i32, date_of_birth: Option<NaiveDate>, pii_masked: bool) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut saved = use_signal(move || (date_of_birth, pii_masked));
+    let mut entry = use_signal(String::new);
+
+    let save = move |value: Option<NaiveDate>| {
+        spawn(async move {
+            match update_borrower_date_of_birth(borrower_id, value).await {
+                Ok(borrower) => {
+                    saved.set((borrower.date_of_birth, borrower.pii_masked));
+                    entry.set(String::new());
+                }
+                Err(err) => {
+                    tracing::error!("update date of birth error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not save date of birth")));
+                }
+            }
+        });
+    };
+
+    let entered = NaiveDate::parse_from_str(&entry(), "%Y-%m-%d").ok();
+    let (date, masked) = saved();
+
+    rsx! {
+        div { class: "flex flex-row items-end gap-2",
+            p { class: "text-gray-600 mr-4",
+                "Date of birth: "
+                if let Some(date) = date {
+                    MaskedValue {
+                        key: "{date}-{masked}",
+                        field: RevealField::DateOfBirth,
+                        entity_id: borrower_id,
+                        masked: display_date_of_birth(date, masked),
+                    }
+                } else {
+                    "not on file"
+                }
+            }
+            DateInput {
+                i_value: entry(),
+                on_input: move |event: FormEvent| entry.set(event.value()),
+            }
+            Button {
+                button_scheme: ButtonScheme::Default,
+                on_click: move |_| save(entered),
+                disabled: entered.is_none(),
+                text: if date.is_some() { "Replace".to_string() } else { "Save".to_string() },
+            }
+            if date.is_some() {
+                Button {
+                    button_scheme: ButtonScheme::Outline,
+                    on_click: move |_| save(None),
+                    text: "Clear".to_string(),
+                }
+            }
+        }
+    }
+}
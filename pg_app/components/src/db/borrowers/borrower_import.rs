@@ -0,0 +1,290 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::borrowers::{commit_borrower_import, preview_borrower_import};
+use shared::dtos::BorrowerImportResult;
+use shared::imports::borrowers::{
+    check_mapping, error_report, guess_mapping, read_csv, BorrowerField, BorrowerImportRow, CsvTable, RowStatus,
+};
+use shared::money::format_cents;
+use strum::IntoEnumIterator;
+use crate::db::loans::mismo_exchange::percent_encode;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::{FileInput, SelectInput};
+use crate::ui::steps::Steps;
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+const UPLOAD: usize = 0;
+const MAP: usize = 1;
+const PREVIEW: usize = 2;
+const DONE: usize = 3;
+
+/// Wizard for bringing in a spreadsheet of borrowers and leads
+///
+/// Upload a CSV, map its columns to borrower fields, review each row's
+/// problems and duplicates, then import the ready rows in one go. Rows with
+/// a loan amount open a lead for the signed-in loan officer.
+#[component]
+pub fn BorrowerImport(on_imported: EventHandler<usize>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut step = use_signal(|| UPLOAD);
+    let mut contents = use_signal(String::new);
+    let mut file_name = use_signal(String::new);
+    let mut table = use_signal(|| None::<CsvTable>);
+    let mut mapping = use_signal(Vec::<Option<BorrowerField>>::new);
+    let mut rows = use_signal(Vec::<BorrowerImportRow>::new);
+    let mut result = use_signal(|| None::<BorrowerImportResult>);
+
+    let mut restart = move || {
+        step.set(UPLOAD);
+        contents.set(String::new());
+        table.set(None);
+        mapping.set(Vec::new());
+        rows.set(Vec::new());
+        result.set(None);
+    };
+
+    let on_file = move |event: FormEvent| {
+        spawn(async move {
+            let Some(engine) = event.files() else { return };
+            let Some(name) = engine.files().into_iter().next() else { return };
+            let Some(text) = engine.read_file_to_string(&name).await else {
+                tracing::error!("could not read {name}");
+                return;
+            };
+            match read_csv(&text) {
+                Ok(parsed) => {
+                    mapping.set(guess_mapping(&parsed.headers));
+                    table.set(Some(parsed));
+                    contents.set(text);
+                    file_name.set(name);
+                    step.set(MAP);
+                }
+                Err(err) => {
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not read file")));
+                }
+            }
+        });
+    };
+
+    let on_preview = move |_| {
+        spawn(async move {
+            match preview_borrower_import(contents(), mapping()).await {
+                Ok(checked) => {
+                    rows.set(checked);
+                    step.set(PREVIEW);
+                }
+                Err(err) => {
+                    tracing::error!("preview borrower import error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Preview failed")));
+                }
+            }
+        });
+    };
+
+    let on_commit = move |_| {
+        spawn(async move {
+            match commit_borrower_import(contents(), mapping()).await {
+                Ok(imported) => {
+                    toast_manager.write().popup(ToastInfo::success(
+                        &format!("Imported {} borrowers", imported.borrower_ids.len()),
+                        Some("Import complete"),
+                    ));
+                    on_imported.call(imported.borrower_ids.len());
+                    result.set(Some(imported));
+                    step.set(DONE);
+                }
+                Err(err) => {
+                    tracing::error!("commit borrower import error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Import failed")));
+                }
+            }
+        });
+    };
+
+    let stem = file_name.read().trim_end_matches(".csv").to_string();
+
+    rsx! {
+        div { class: "flex flex-col gap-4 rounded-lg bg-white shadow-sm p-4",
+            h3 { class: "text-lg font-semibold", "Import borrowers and leads" }
+            Steps {
+                steps: vec!["Upload".to_string(), "Map columns".to_string(), "Preview".to_string(), "Done".to_string()],
+                current: step(),
+            }
+            match step() {
+                UPLOAD => rsx! {
+                    div { class: "flex flex-col gap-1",
+                        label { class: "text-sm font-medium text-blue-900", "Spreadsheet saved as CSV, with column names on the first line" }
+                        FileInput { i_value: String::new(), on_input: on_file }
+                    }
+                },
+                MAP => rsx! {
+                    if let Some(csv) = table() {
+                        ColumnMapping { table: csv, mapping }
+                    }
+                    div { class: "flex flex-row gap-2",
+                        Button {
+                            button_scheme: ButtonScheme::Default,
+                            on_click: move |_| restart(),
+                            text: "Back".to_string(),
+                        }
+                        Button {
+                            button_scheme: ButtonScheme::Success,
+                            on_click: on_preview,
+                            disabled: check_mapping(&mapping.read()).is_err(),
+                            text: "Preview".to_string(),
+                        }
+                    }
+                },
+                PREVIEW => rsx! {
+                    RowPreview { rows: rows() }
+                    div { class: "flex flex-row items-center gap-2",
+                        Button {
+                            button_scheme: ButtonScheme::Default,
+                            on_click: move |_| step.set(MAP),
+                            text: "Back".to_string(),
+                        }
+                        Button {
+                            button_scheme: ButtonScheme::Success,
+                            on_click: on_commit,
+                            disabled: !rows.read().iter().any(|row| row.status == RowStatus::Ready),
+                            text: format!(
+                                "Import {} borrowers",
+                                rows.read().iter().filter(|row| row.status == RowStatus::Ready).count(),
+                            ),
+                        }
+                        if let Some(report) = table().and_then(|csv| error_report(&csv, &rows.read())) {
+                            ErrorReportLink { report, file_stem: stem.clone() }
+                        }
+                    }
+                },
+                _ => rsx! {
+                    if let Some(imported) = result() {
+                        p { class: "text-sm text-green-700",
+                            "Created {imported.borrower_ids.len()} borrowers and {imported.leads_created} leads. "
+                            "{imported.skipped} rows were skipped."
+                        }
+                        if let Some(report) = imported.error_report {
+                            ErrorReportLink { report, file_stem: stem.clone() }
+                        }
+                    }
+                    Button {
+                        button_scheme: ButtonScheme::Default,
+                        on_click: move |_| restart(),
+                        text: "Import another file".to_string(),
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// One select per column, with the first row's value as a sample
+#[component]
+fn ColumnMapping(table: CsvTable, mapping: Signal<Vec<Option<BorrowerField>>>) -> Element {
+    let options: Vec<(String, String)> = std::iter::once((String::new(), "Don't import".to_string()))
+        .chain(BorrowerField::iter().map(|field| (field.code().to_string(), field.to_string())))
+        .collect();
+    let problem = check_mapping(&mapping.read()).err();
+
+    rsx! {
+        Table {
+            striped: true,
+            caption: rsx! { "Columns" },
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Column" }
+                    TableHeaderCell { "First Row" }
+                    TableHeaderCell { "Borrower Field" }
+                }
+            }
+            TableBody {
+                for (i , header) in table.headers.iter().enumerate() {
+                    TableRow { key: "{i}",
+                        TableCell { "{header}" }
+                        TableCell { class: "text-gray-500",
+                            {table.rows.first().and_then(|row| row.values.get(i)).cloned().unwrap_or_default()}
+                        }
+                        TableCell {
+                            SelectInput {
+                                i_value: mapping.read().get(i).copied().flatten().map(|f| f.code().to_string()).unwrap_or_default(),
+                                options: options.clone(),
+                                on_input: move |event: FormEvent| {
+                                    if let Some(slot) = mapping.write().get_mut(i) {
+                                        *slot = event.value().parse().ok();
+                                    }
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(problem) = problem {
+            p { class: "text-sm text-amber-700", "{problem}" }
+        }
+    }
+}
+
+/// Every row as it will be imported, with the reason for any that will not
+#[component]
+fn RowPreview(rows: Vec<BorrowerImportRow>) -> Element {
+    let ready = rows.iter().filter(|row| row.status == RowStatus::Ready).count();
+    let duplicates = rows
+        .iter()
+        .filter(|row| matches!(row.status, RowStatus::Duplicate { .. } | RowStatus::RepeatedRow { .. }))
+        .count();
+    let invalid = rows.len() - ready - duplicates;
+
+    rsx! {
+        p { class: "text-sm text-gray-700",
+            "{ready} ready, {duplicates} duplicates, {invalid} with errors"
+        }
+        Table {
+            striped: true,
+            caption: rsx! { "Preview" },
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Line" }
+                    TableHeaderCell { "Name" }
+                    TableHeaderCell { "Email" }
+                    TableHeaderCell { "Phone" }
+                    TableHeaderCell { "Monthly Income" }
+                    TableHeaderCell { "Lead Amount" }
+                    TableHeaderCell { "Status" }
+                }
+            }
+            TableBody {
+                for row in rows.iter() {
+                    TableRow {
+                        key: "{row.line}",
+                        class: if row.status == RowStatus::Ready { None } else { Some("opacity-70".to_string()) },
+                        TableCell { "{row.line}" }
+                        TableCell { "{row.input.first_name} {row.input.last_name}" }
+                        TableCell { {row.input.email.clone().unwrap_or_default()} }
+                        TableCell { {row.input.phone.clone().unwrap_or_default()} }
+                        TableCell { {format_cents(row.input.monthly_income_cents)} }
+                        TableCell { {row.loan_amount_cents.map(format_cents).unwrap_or_default()} }
+                        TableCell {
+                            match row.status.problem() {
+                                None => rsx! { span { class: "text-green-700", "Ready" } },
+                                Some(problem) => rsx! { span { class: "text-red-700", "{problem}" } },
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ErrorReportLink(report: String, file_stem: String) -> Element {
+    rsx! {
+        a {
+            class: "text-blue-600 hover:underline",
+            href: "data:text/csv;charset=utf-8,{percent_encode(&report)}",
+            download: "{file_stem}-skipped.csv",
+            "Download skipped rows"
+        }
+    }
+}
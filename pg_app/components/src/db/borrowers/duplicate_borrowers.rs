@@ -0,0 +1,318 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::borrowers::{get_borrower_merges, get_duplicate_candidates, merge_borrowers, undo_borrower_merge};
+use shared::duplicates::DuplicateCandidate;
+use shared::models::{merge_undo_deadline, Borrower, MergeField, MergeSelection, Permission};
+use strum::IntoEnumIterator;
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+/// Report of borrowers that look like the same person, with the merge tool
+/// and recent merges; for users with `ProcessLoans`
+///
+/// `on_merged` runs after a merge or an undo so the borrower list can reload.
+#[component]
+pub fn DuplicateBorrowers(on_merged: EventHandler<()>) -> Element {
+    let mut candidates = use_resource(move || async move { get_duplicate_candidates().await });
+    let mut merges = use_resource(move || async move { get_borrower_merges().await });
+    let mut reviewing = use_signal(|| None::<DuplicateCandidate>);
+
+    if !CURRENT_USER().is_some_and(|user| user.has_permission(Permission::ProcessLoans)) {
+        return rsx! {};
+    }
+
+    let mut reload = move || {
+        reviewing.set(None);
+        candidates.restart();
+        merges.restart();
+        on_merged.call(());
+    };
+
+    let rows = match &*candidates.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get duplicate candidates error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Possible Duplicates" }
+            if rows.is_empty() {
+                p { class: "text-sm text-gray-500", "No borrowers look like duplicates." }
+            } else {
+                Table {
+                    TableHead {
+                        TableRow {
+                            TableHeaderCell { "Match" }
+                            TableHeaderCell { "Borrower" }
+                            TableHeaderCell { "Possible Duplicate" }
+                            TableHeaderCell { "Why" }
+                            TableHeaderCell { "" }
+                        }
+                    }
+                    TableBody {
+                        for candidate in rows {
+                            TableRow { key: "{candidate.first.id}-{candidate.second.id}",
+                                TableCell {
+                                    span { class: if candidate.is_strong() { "font-semibold text-red-700" } else { "text-amber-700" },
+                                        if candidate.is_strong() { "Strong" } else { "Possible" }
+                                        " ({candidate.score})"
+                                    }
+                                }
+                                TableCell { BorrowerSummary { borrower: candidate.first.clone() } }
+                                TableCell { BorrowerSummary { borrower: candidate.second.clone() } }
+                                TableCell {
+                                    for (i, signal) in candidate.signals.iter().enumerate() {
+                                        div { key: "{i}", class: if signal.is_conflict() { "text-sm text-red-600" } else { "text-sm" },
+                                            "{signal}"
+                                        }
+                                    }
+                                }
+                                TableCell {
+                                    Button {
+                                        button_scheme: ButtonScheme::Outline,
+                                        on_click: move |_| reviewing.set(Some(candidate.clone())),
+                                        text: "Review".to_string(),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(candidate) = reviewing() {
+                MergeForm {
+                    key: "{candidate.first.id}-{candidate.second.id}",
+                    first: candidate.first.clone(),
+                    second: candidate.second.clone(),
+                    on_merged: move |_| reload(),
+                    on_cancel: move |_| reviewing.set(None),
+                }
+            }
+            MergeHistory {
+                merges: match &*merges.read() {
+                    Some(Ok(rows)) => rows.clone(),
+                    _ => Vec::new(),
+                },
+                on_undone: move |_| reload(),
+            }
+        }
+    }
+}
+
+#[component]
+fn BorrowerSummary(borrower: Borrower) -> Element {
+    let details: Vec<String> = [MergeField::Email, MergeField::Phone, MergeField::DateOfBirth, MergeField::Ssn]
+        .iter()
+        .map(|field| field.display(&borrower))
+        .filter(|value| !value.is_empty())
+        .collect();
+
+    rsx! {
+        div { class: "font-medium", "#{borrower.id} {borrower.full_name()}" }
+        div { class: "text-xs text-gray-500", {details.join(" · ")} }
+    }
+}
+
+/// Chooses the surviving record and the value kept for each field, then merges
+#[component]
+fn MergeForm(first: Borrower, second: Borrower, on_merged: EventHandler<()>, on_cancel: EventHandler<()>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut keep_first = use_signal(|| true);
+    let mut selection = use_signal({
+        let (first, second) = (first.clone(), second.clone());
+        move || MergeSelection::new(&first, &second)
+    });
+    let mut saving = use_signal(|| false);
+
+    let (survivor, merged) = if keep_first() { (first.clone(), second.clone()) } else { (second.clone(), first.clone()) };
+    let result = selection.read().apply(&survivor, &merged);
+
+    let on_merge = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        saving.set(true);
+        spawn(async move {
+            match merge_borrowers(selection()).await {
+                Ok(merge) => {
+                    toast_manager.write().popup(ToastInfo::success(
+                        &format!("Borrower #{} merged into #{}", merge.merged_id, merge.survivor_id),
+                        Some("Borrowers merged"),
+                    ));
+                    on_merged.call(());
+                }
+                Err(err) => {
+                    tracing::error!("merge borrowers error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not merge the borrowers")));
+                }
+            }
+            saving.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2 rounded border border-gray-300 p-4",
+            h4 { class: "font-semibold", "Merge {first.full_name()} and {second.full_name()}" }
+            div { class: "flex flex-row gap-4 text-sm",
+                "Keep:"
+                label { class: "flex items-center gap-1",
+                    input {
+                        r#type: "radio",
+                        name: "merge-survivor",
+                        checked: keep_first(),
+                        onchange: {
+                            let (first, second) = (first.clone(), second.clone());
+                            move |_| {
+                                keep_first.set(true);
+                                selection.set(MergeSelection::new(&first, &second));
+                            }
+                        },
+                    }
+                    "#{first.id}"
+                }
+                label { class: "flex items-center gap-1",
+                    input {
+                        r#type: "radio",
+                        name: "merge-survivor",
+                        checked: !keep_first(),
+                        onchange: {
+                            let (first, second) = (first.clone(), second.clone());
+                            move |_| {
+                                keep_first.set(false);
+                                selection.set(MergeSelection::new(&second, &first));
+                            }
+                        },
+                    }
+                    "#{second.id}"
+                }
+            }
+            Table {
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Field" }
+                        TableHeaderCell { "Kept #{survivor.id}" }
+                        TableHeaderCell { "Merged #{merged.id}" }
+                        TableHeaderCell { "Result" }
+                    }
+                }
+                TableBody {
+                    for field in MergeField::iter() {
+                        TableRow { key: "{field.code()}",
+                            TableCell { "{field}" }
+                            for (from_merged, source) in [(false, &survivor), (true, &merged)] {
+                                TableCell { key: "{from_merged}",
+                                    label { class: "flex items-center gap-2 text-sm",
+                                        input {
+                                            r#type: "radio",
+                                            name: "merge-field-{field.code()}",
+                                            checked: selection.read().takes(field) == from_merged,
+                                            onchange: move |_| selection.write().choose(field, from_merged),
+                                        }
+                                        {Some(field.display(source)).filter(|v| !v.is_empty()).unwrap_or_else(|| "—".to_string())}
+                                    }
+                                }
+                            }
+                            TableCell { span { class: "font-medium", {field.display(&result)} } }
+                        }
+                    }
+                }
+            }
+            p { class: "text-sm text-gray-600",
+                "Loans, notes, liabilities, employment and assets of #{merged.id} move to #{survivor.id}; documents and tasks follow their loans. "
+                "The merge can be undone for {shared::models::BORROWER_MERGE_UNDO_HOURS} hours."
+            }
+            div { class: "flex flex-row gap-2",
+                Button {
+                    button_scheme: ButtonScheme::Danger,
+                    on_click: on_merge,
+                    disabled: saving(),
+                    text: "Merge".to_string(),
+                }
+                Button {
+                    button_scheme: ButtonScheme::Outline,
+                    on_click: move |_| on_cancel.call(()),
+                    text: "Cancel".to_string(),
+                }
+            }
+        }
+    }
+}
+
+/// Merges from the last 30 days, with undo while the window is open
+#[component]
+fn MergeHistory(merges: Vec<shared::dtos::BorrowerMergeRow>, on_undone: EventHandler<()>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let now = chrono::Utc::now();
+
+    if merges.is_empty() {
+        return rsx! {};
+    }
+
+    let on_undo = move |merge_id: i32| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        spawn(async move {
+            match undo_borrower_merge(merge_id).await {
+                Ok(merge) => {
+                    toast_manager.write().popup(ToastInfo::success(
+                        &format!("Borrower #{} is back on its own", merge.merged_id),
+                        Some("Merge undone"),
+                    ));
+                    on_undone.call(());
+                }
+                Err(err) => {
+                    tracing::error!("undo borrower merge error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not undo the merge")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        h4 { class: "font-semibold", "Recent Merges" }
+        Table {
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Merged" }
+                    TableHeaderCell { "Into" }
+                    TableHeaderCell { "Loans Moved" }
+                    TableHeaderCell { "By" }
+                    TableHeaderCell { "When" }
+                    TableHeaderCell { "" }
+                }
+            }
+            TableBody {
+                for merge in merges {
+                    TableRow { key: "{merge.id}",
+                        TableCell { "#{merge.merged_id} {merge.merged_name}" }
+                        TableCell { "#{merge.survivor_id} {merge.survivor_name}" }
+                        TableCell { "{merge.loan_count}" }
+                        TableCell { {merge.merged_by_name.clone().unwrap_or_default()} }
+                        TableCell { {merge.merged_at.format("%m/%d/%Y %H:%M").to_string()} }
+                        TableCell {
+                            if merge.undone_at.is_some() {
+                                span { class: "text-sm text-gray-500", "Undone" }
+                            } else if now <= merge_undo_deadline(merge.merged_at) {
+                                Button {
+                                    button_scheme: ButtonScheme::Warn,
+                                    on_click: move |_| on_undo(merge.id),
+                                    text: "Undo".to_string(),
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
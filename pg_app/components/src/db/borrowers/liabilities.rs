@@ -0,0 +1,293 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::borrowers::{create_liability, delete_liability, get_liabilities, import_tradelines, update_liability};
+use shared::calculations::dti::{debt_to_income, DebtSummary};
+use shared::imports::tradelines::TradelineFormat;
+use shared::models::{Liability, LiabilityInput, LiabilityType};
+use shared::money::{format_cents, parse_dollars};
+use strum::IntoEnumIterator;
+use crate::ui::button::{Button, ButtonScheme, ButtonSize};
+use crate::ui::input::{FileInput, Input, InputType, SelectInput};
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableFoot, TableHead, TableHeaderCell, TableRow};
+
+/// Liabilities for one borrower with DTI totals, an add form and credit report import
+#[component]
+pub fn Liabilities(borrower_id: i32, monthly_income_cents: i64) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut liabilities = use_resource(move || async move { get_liabilities(borrower_id).await });
+
+    let rows = match &*liabilities.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get liabilities error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    let summary = DebtSummary::from_liabilities(&rows);
+    let dti = debt_to_income(summary.counted_monthly_cents, monthly_income_cents)
+        .map(|ratio| format!("{:.2}%", ratio))
+        .unwrap_or_else(|| "N/A".to_string());
+
+    let toggle = move |liability: Liability, paid_off_at_closing: bool, excluded: bool| {
+        let mut input = LiabilityInput::from(&liability);
+        input.paid_off_at_closing = paid_off_at_closing;
+        input.excluded = excluded;
+        spawn(async move {
+            match update_liability(liability.id, input).await {
+                Ok(_) => liabilities.restart(),
+                Err(err) => {
+                    tracing::error!("update liability error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Update failed")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-4",
+            div { class: "grid grid-cols-4 gap-4",
+                SummaryTile { label: "Total Balance", value: format_cents(summary.total_balance_cents) }
+                SummaryTile { label: "Monthly Payments", value: format_cents(summary.total_monthly_cents) }
+                SummaryTile { label: "Counted Toward DTI", value: format_cents(summary.counted_monthly_cents) }
+                SummaryTile { label: "Debt-to-Income", value: dti }
+            }
+            Table {
+                striped: true,
+                hoverable: true,
+                caption: rsx! { "Liabilities" },
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Type" }
+                        TableHeaderCell { "Creditor" }
+                        TableHeaderCell { "Account" }
+                        TableHeaderCell { "Balance" }
+                        TableHeaderCell { "Monthly" }
+                        TableHeaderCell { "Paid Off at Closing" }
+                        TableHeaderCell { "Excluded" }
+                        TableHeaderCell { "Source" }
+                        TableHeaderCell { "" }
+                    }
+                }
+                TableBody {
+                    for liability in rows.iter().cloned() {
+                        TableRow {
+                            key: "{liability.id}",
+                            class: if liability.counts_toward_dti() { None } else { Some("opacity-60".to_string()) },
+                            TableCell { "{liability.liability_type}" }
+                            TableCell { "{liability.creditor_name}" }
+                            TableCell {
+                                {liability.account_last4.as_ref().map(|l| format!("…{}", l)).unwrap_or_default()}
+                            }
+                            TableCell { {format_cents(liability.balance_cents)} }
+                            TableCell { {format_cents(liability.monthly_payment_cents)} }
+                            TableCell {
+                                input {
+                                    r#type: "checkbox",
+                                    checked: liability.paid_off_at_closing,
+                                    onchange: {
+                                        let liability = liability.clone();
+                                        move |event: FormEvent| {
+                                            toggle(liability.clone(), event.checked(), liability.excluded)
+                                        }
+                                    },
+                                }
+                            }
+                            TableCell {
+                                input {
+                                    r#type: "checkbox",
+                                    checked: liability.excluded,
+                                    onchange: {
+                                        let liability = liability.clone();
+                                        move |event: FormEvent| {
+                                            toggle(liability.clone(), liability.paid_off_at_closing, event.checked())
+                                        }
+                                    },
+                                }
+                            }
+                            TableCell { "{liability.source}" }
+                            TableCell {
+                                Button {
+                                    button_scheme: ButtonScheme::Danger,
+                                    button_size: ButtonSize::ExtraSmall,
+                                    on_click: move |_| {
+                                        spawn(async move {
+                                            match delete_liability(liability.id).await {
+                                                Ok(_) => liabilities.restart(),
+                                                Err(err) => tracing::error!("delete liability error: {err}"),
+                                            }
+                                        });
+                                    },
+                                    text: "Delete".to_string(),
+                                }
+                            }
+                        }
+                    }
+                }
+                TableFoot {
+                    TableRow {
+                        TableHeaderCell { colspan: Some(3), "Total" }
+                        TableCell { {format_cents(summary.total_balance_cents)} }
+                        TableCell { {format_cents(summary.total_monthly_cents)} }
+                        TableCell { colspan: Some(4), "" }
+                    }
+                }
+            }
+            AddLiability { borrower_id, on_added: move |_| liabilities.restart() }
+            TradelineImport { borrower_id, on_imported: move |_| liabilities.restart() }
+        }
+    }
+}
+
+#[component]
+fn SummaryTile(label: String, value: String) -> Element {
+    rsx! {
+        div { class: "rounded-lg bg-white shadow-sm p-4",
+            p { class: "text-xs uppercase text-gray-500", "{label}" }
+            p { class: "text-xl font-semibold", "{value}" }
+        }
+    }
+}
+
+#[component]
+fn AddLiability(borrower_id: i32, on_added: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut liability_type = use_signal(LiabilityType::default);
+    let mut creditor = use_signal(String::new);
+    let mut account = use_signal(String::new);
+    let mut balance = use_signal(String::new);
+    let mut monthly = use_signal(String::new);
+
+    let type_options: Vec<(String, String)> = LiabilityType::iter()
+        .map(|t| (t.code().to_string(), t.to_string()))
+        .collect();
+
+    let on_submit = move |_| {
+        let (Some(balance_cents), Some(monthly_payment_cents)) =
+            (parse_dollars(&balance.read()), parse_dollars(&monthly.read()))
+        else {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Balance and payment must be dollar amounts", Some("Invalid input")));
+            return;
+        };
+        let account_last4 = account.read().trim().to_string();
+
+        let input = LiabilityInput {
+            liability_type: liability_type(),
+            creditor_name: creditor.read().trim().to_string(),
+            account_last4: (!account_last4.is_empty()).then_some(account_last4),
+            balance_cents,
+            monthly_payment_cents,
+            paid_off_at_closing: false,
+            excluded: false,
+        };
+
+        spawn(async move {
+            match create_liability(borrower_id, input).await {
+                Ok(id) => {
+                    creditor.set(String::new());
+                    account.set(String::new());
+                    balance.set(String::new());
+                    monthly.set(String::new());
+                    on_added.call(id);
+                }
+                Err(err) => {
+                    tracing::error!("create liability error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not add liability")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-row flex-wrap items-end gap-2",
+            SelectInput {
+                i_value: liability_type().code().to_string(),
+                options: type_options,
+                on_input: move |event: FormEvent| {
+                    if let Ok(t) = event.value().parse() {
+                        liability_type.set(t);
+                    }
+                },
+            }
+            Input {
+                name: "creditor".to_string(),
+                input_type: Some(InputType::Text),
+                placeholder: Some("Creditor".to_string()),
+                value: Some(creditor()),
+                oninput: move |event: FormEvent| creditor.set(event.value()),
+            }
+            Input {
+                name: "account_last4".to_string(),
+                input_type: Some(InputType::Text),
+                placeholder: Some("Last 4".to_string()),
+                value: Some(account()),
+                oninput: move |event: FormEvent| account.set(event.value()),
+            }
+            Input {
+                name: "balance".to_string(),
+                input_type: Some(InputType::Text),
+                placeholder: Some("Balance".to_string()),
+                value: Some(balance()),
+                oninput: move |event: FormEvent| balance.set(event.value()),
+            }
+            Input {
+                name: "monthly_payment".to_string(),
+                input_type: Some(InputType::Text),
+                placeholder: Some("Monthly payment".to_string()),
+                value: Some(monthly()),
+                oninput: move |event: FormEvent| monthly.set(event.value()),
+            }
+            Button {
+                button_scheme: ButtonScheme::Success,
+                on_click: on_submit,
+                text: "Add Liability".to_string(),
+            }
+        }
+    }
+}
+
+/// Uploads a credit report tradeline file (see `shared::imports::tradelines` for the layout)
+#[component]
+fn TradelineImport(borrower_id: i32, on_imported: EventHandler<usize>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+
+    let on_file = move |event: FormEvent| {
+        spawn(async move {
+            let Some(engine) = event.files() else { return };
+            for file_name in engine.files() {
+                let Some(format) = TradelineFormat::from_file_name(&file_name) else {
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error("Upload a .json or .csv file", Some("Unsupported file")));
+                    continue;
+                };
+                let Some(contents) = engine.read_file_to_string(&file_name).await else {
+                    tracing::error!("could not read {file_name}");
+                    continue;
+                };
+                match import_tradelines(borrower_id, contents, format).await {
+                    Ok(count) => {
+                        toast_manager
+                            .write()
+                            .popup(ToastInfo::success(&format!("Imported {} tradelines", count), Some("Import complete")));
+                        on_imported.call(count);
+                    }
+                    Err(err) => {
+                        tracing::error!("import tradelines error: {err}");
+                        toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Import failed")));
+                    }
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-1",
+            label { class: "text-sm font-medium text-blue-900", "Import credit report tradelines (.json or .csv)" }
+            FileInput { i_value: String::new(), on_input: on_file }
+        }
+    }
+}
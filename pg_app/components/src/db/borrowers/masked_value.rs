@@ -0,0 +1,56 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::borrowers::reveal_pii;
+use shared::masking::RevealField;
+use shared::models::Permission;
+use crate::db::session::CURRENT_USER;
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// A masked SSN, date of birth or account number; users with `RevealPii`
+/// can show it in full, which the server writes to the audit log
+///
+/// `entity_id` is the borrower for an SSN or date of birth and the asset for
+/// an account number.
+#[component]
+pub fn MaskedValue(field: RevealField, entity_id: i32, masked: String) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut revealed = use_signal(|| None::<String>);
+
+    let can_reveal = CURRENT_USER().is_some_and(|user| user.has_permission(Permission::RevealPii));
+
+    let on_reveal = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        spawn(async move {
+            match reveal_pii(field, entity_id).await {
+                Ok(value) => revealed.set(Some(value)),
+                Err(err) => {
+                    tracing::error!("reveal {field} error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some(&format!("Could not reveal {field}"))));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        span { class: "inline-flex items-center gap-2",
+            span { class: "font-mono", {revealed().unwrap_or(masked)} }
+            if revealed().is_some() {
+                button {
+                    class: "text-xs text-blue-600 hover:underline cursor-pointer",
+                    onclick: move |_| revealed.set(None),
+                    "Hide"
+                }
+            } else if can_reveal {
+                button {
+                    class: "text-xs text-blue-600 hover:underline cursor-pointer",
+                    title: "Shows the full value; the reveal is logged",
+                    onclick: on_reveal,
+                    "Reveal"
+                }
+            }
+        }
+    }
+}
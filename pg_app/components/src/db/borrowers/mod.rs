@@ -0,0 +1,15 @@
+pub use add_borrower::AddBorrower;
+pub use applicant_details::ApplicantDetails;
+pub use borrower_import::BorrowerImport;
+pub use borrower_table::BorrowerTable;
+pub use duplicate_borrowers::DuplicateBorrowers;
+pub use liabilities::Liabilities;
+pub use masked_value::MaskedValue;
+
+pub mod add_borrower;      // Contains AddBorrower
+pub mod applicant_details; // Contains ApplicantDetails with the SSN, employment and assets
+pub mod borrower_import;   // Contains BorrowerImport, the CSV import wizard
+pub mod borrower_table;    // Contains BorrowerTable
+pub mod duplicate_borrowers; // Contains DuplicateBorrowers, the duplicate report and merge tool
+pub mod liabilities;       // Contains Liabilities and the tradeline import
+pub mod masked_value;      // Contains MaskedValue, a masked PII value with an audited reveal
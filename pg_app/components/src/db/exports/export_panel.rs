@@ -0,0 +1,141 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::exports::export_list;
+use shared::dtos::DownloadFile;
+use shared::exports::{ExportFormat, ExportList, ExportRequest, ExportSort};
+use shared::PipelineFilter;
+use strum::IntoEnumIterator;
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::SelectInput;
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// Export of a list view to CSV or XLSX
+///
+/// Collapsed to a button until opened. The file follows the list's current
+/// `sort` and, for loans, `loan_filter`; the user picks the columns, starting
+/// from `columns` (every column when empty).
+#[component]
+pub fn ExportPanel(
+    list: ExportList,
+    #[props(default)] columns: Vec<String>,
+    #[props(default)] sort: Vec<ExportSort>,
+    #[props(default)] loan_filter: PipelineFilter,
+) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut open = use_signal(|| false);
+    let mut chosen = use_signal(move || {
+        if columns.is_empty() {
+            list.columns().iter().map(|column| column.code.to_string()).collect()
+        } else {
+            columns.clone()
+        }
+    });
+    let mut format = use_signal(ExportFormat::default);
+    let mut file = use_signal(|| None::<DownloadFile>);
+    let mut exporting = use_signal(|| false);
+
+    let format_options: Vec<(String, String)> =
+        ExportFormat::iter().map(|f| (f.code().to_string(), f.to_string())).collect();
+
+    let on_export = move |_| {
+        if CURRENT_USER().is_none() {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Sign in to export", Some("Not signed in")));
+            return;
+        }
+        // Keep the list's column order whatever order the boxes were ticked in
+        let codes = list
+            .columns()
+            .iter()
+            .map(|column| column.code.to_string())
+            .filter(|code| chosen.read().contains(code));
+        let request = ExportRequest {
+            sort: sort.clone(),
+            loan_filter: loan_filter.clone(),
+            ..ExportRequest::new(list, format(), codes)
+        };
+        exporting.set(true);
+        spawn(async move {
+            match export_list(request).await {
+                Ok(exported) => file.set(Some(exported)),
+                Err(err) => {
+                    tracing::error!("export {list} error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Export failed")));
+                }
+            }
+            exporting.set(false);
+        });
+    };
+
+    if !open() {
+        return rsx! {
+            Button {
+                button_scheme: ButtonScheme::Default,
+                on_click: move |_| open.set(true),
+                text: format!("Export {}", list.to_string().to_lowercase()),
+            }
+        };
+    }
+
+    rsx! {
+        div { class: "flex flex-col gap-3 rounded-lg bg-white shadow-sm p-4",
+            div { class: "flex justify-between",
+                h3 { class: "text-lg font-semibold", "Export {list.to_string().to_lowercase()}" }
+                button {
+                    class: "text-gray-500 hover:text-gray-800 cursor-pointer",
+                    onclick: move |_| {
+                        open.set(false);
+                        file.set(None);
+                    },
+                    "Close"
+                }
+            }
+            div { class: "flex flex-row flex-wrap gap-x-4 gap-y-1",
+                for column in list.columns().iter() {
+                    label { key: "{column.code}", class: "flex items-center gap-1 text-sm",
+                        input {
+                            r#type: "checkbox",
+                            checked: chosen.read().iter().any(|code| code == column.code),
+                            onchange: move |event: FormEvent| {
+                                let code = column.code.to_string();
+                                chosen.write().retain(|c| *c != code);
+                                if event.checked() {
+                                    chosen.write().push(code);
+                                }
+                                file.set(None);
+                            },
+                        }
+                        "{column.label}"
+                    }
+                }
+            }
+            div { class: "flex flex-row items-center gap-2",
+                SelectInput {
+                    i_value: format().code().to_string(),
+                    options: format_options,
+                    on_input: move |event: FormEvent| {
+                        if let Ok(picked) = event.value().parse() {
+                            format.set(picked);
+                            file.set(None);
+                        }
+                    },
+                }
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    on_click: on_export,
+                    disabled: exporting() || chosen.read().is_empty(),
+                    text: if exporting() { "Exporting...".to_string() } else { "Export".to_string() },
+                }
+                if let Some(exported) = file() {
+                    a {
+                        class: "text-blue-600 hover:underline",
+                        href: "{exported.data_url()}",
+                        download: "{exported.file_name}",
+                        "Download {exported.file_name} ({exported.rows} rows)"
+                    }
+                }
+            }
+        }
+    }
+}
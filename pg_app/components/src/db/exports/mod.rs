@@ -0,0 +1,3 @@
+pub use export_panel::ExportPanel;
+
+pub mod export_panel;  // Contains ExportPanel, the CSV/XLSX export of a list view
@@ -0,0 +1,149 @@
+use chrono::NaiveDate;
+use dioxus::{logger::tracing, prelude::*};
+use server::borrowers::get_all_borrowers;
+use server::loans::create_loan;
+use shared::models::{LoanInput, LoanPurpose, LoanType};
+use shared::money::parse_dollars;
+use strum::IntoEnumIterator;
+use crate::ui::input::{DateInput, Input, InputType, SelectInput};
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// Form for opening a new loan
+///
+/// When `borrower_id` is not given the user picks the borrower from a list.
+#[component]
+pub fn AddLoan(borrower_id: Option<i32>, on_loan_added: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let borrowers = use_resource(|| async { get_all_borrowers().await });
+    let mut selected_borrower = use_signal(move || borrower_id.map(|id| id.to_string()).unwrap_or_default());
+    let mut loan_type = use_signal(LoanType::default);
+    let mut loan_purpose = use_signal(LoanPurpose::default);
+    let mut amount = use_signal(String::new);
+    let mut rate = use_signal(String::new);
+    let mut term = use_signal(|| "360".to_string());
+    let mut application_date = use_signal(String::new);
+
+    let borrower_options: Vec<(String, String)> = match &*borrowers.read() {
+        Some(Ok(list)) => std::iter::once((String::new(), "Select borrower".to_string()))
+            .chain(list.iter().map(|b| (b.id.to_string(), b.full_name())))
+            .collect(),
+        _ => Vec::new(),
+    };
+    let type_options: Vec<(String, String)> = LoanType::iter()
+        .map(|t| (t.code().to_string(), t.to_string()))
+        .collect();
+    let purpose_options: Vec<(String, String)> = LoanPurpose::iter()
+        .map(|p| (p.code().to_string(), p.to_string()))
+        .collect();
+
+    let on_submit = move |_| {
+        let mut error = |message: &str| {
+            toast_manager.write().popup(ToastInfo::error(message, Some("Invalid input")));
+        };
+        let Ok(borrower_id) = selected_borrower.read().parse::<i32>() else {
+            return error("Select a borrower");
+        };
+        let Some(amount_cents) = parse_dollars(&amount.read()) else {
+            return error("Loan amount must be a dollar amount");
+        };
+        let Ok(note_rate) = rate.read().trim().trim_end_matches('%').parse::<f64>() else {
+            return error("Rate must be a number such as 6.875");
+        };
+        let Ok(term_months) = term.read().trim().parse::<i32>() else {
+            return error("Term must be a number of months");
+        };
+        let application_date = NaiveDate::parse_from_str(&application_date.read(), "%Y-%m-%d").ok();
+
+        let input = LoanInput {
+            borrower_id,
+            loan_officer_id: None,
+            loan_type: loan_type(),
+            loan_purpose: loan_purpose(),
+            amount_cents,
+            note_rate,
+            term_months,
+            application_date,
+        };
+
+        spawn(async move {
+            match create_loan(input).await {
+                Ok(loan_id) => {
+                    tracing::info!("Created loan with ID: {}", loan_id);
+                    amount.set(String::new());
+                    rate.set(String::new());
+                    on_loan_added.call(loan_id);
+                }
+                Err(e) => {
+                    tracing::error!("Failed to create loan: {}", e);
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&e.to_string(), Some("Could not create loan")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "my-1 flex flex-col gap-2",
+            h2 { class: "text-lg font-medium mb-3", "New Loan" }
+            div { class: "flex flex-row flex-wrap items-end gap-2",
+                if borrower_id.is_none() {
+                    SelectInput {
+                        i_value: selected_borrower(),
+                        options: borrower_options,
+                        on_input: move |event: FormEvent| selected_borrower.set(event.value()),
+                    }
+                }
+                SelectInput {
+                    i_value: loan_type().code().to_string(),
+                    options: type_options,
+                    on_input: move |event: FormEvent| {
+                        if let Ok(t) = event.value().parse() {
+                            loan_type.set(t);
+                        }
+                    },
+                }
+                SelectInput {
+                    i_value: loan_purpose().code().to_string(),
+                    options: purpose_options,
+                    on_input: move |event: FormEvent| {
+                        if let Ok(p) = event.value().parse() {
+                            loan_purpose.set(p);
+                        }
+                    },
+                }
+                Input {
+                    name: "amount".to_string(),
+                    input_type: Some(InputType::Text),
+                    placeholder: Some("Loan amount".to_string()),
+                    value: Some(amount()),
+                    oninput: move |event: FormEvent| amount.set(event.value()),
+                }
+                Input {
+                    name: "note_rate".to_string(),
+                    input_type: Some(InputType::Text),
+                    placeholder: Some("Rate %".to_string()),
+                    value: Some(rate()),
+                    oninput: move |event: FormEvent| rate.set(event.value()),
+                }
+                Input {
+                    name: "term_months".to_string(),
+                    input_type: Some(InputType::Number),
+                    placeholder: Some("Term (months)".to_string()),
+                    value: Some(term()),
+                    oninput: move |event: FormEvent| term.set(event.value()),
+                }
+                DateInput {
+                    i_value: application_date(),
+                    on_input: move |event: FormEvent| application_date.set(event.value()),
+                }
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    on_click: on_submit,
+                    text: "Create Loan".to_string(),
+                }
+            }
+        }
+    }
+}
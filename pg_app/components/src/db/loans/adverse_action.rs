@@ -0,0 +1,415 @@
+use chrono::NaiveDate;
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{
+    deny_loan, download_loan_document, generate_loan_document, get_adverse_action, get_pending_notices,
+    record_notice_delivery,
+};
+use shared::dtos::DownloadFile;
+use shared::models::{
+    notice_due_date, AdverseAction, DenialInput, DenialReason, DisclosureDelivery, DocumentKind, Loan, LoanStatus,
+    NoticeDelivery, HMDA_MAX_DENIAL_REASONS,
+};
+use strum::IntoEnumIterator;
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::{DateInput, SelectInput};
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+const FIELD_INPUT: &str = "rounded border border-gray-300 bg-white px-2 py-1 text-sm";
+
+fn show(date: NaiveDate) -> String {
+    date.format("%m/%d/%Y").to_string()
+}
+
+/// Denial of the application and its adverse action notice
+///
+/// Shown while the loan can still be denied, with the reasons to choose;
+/// once denied, shows the reasons, the notice and its delivery. `on_denied`
+/// runs after a denial so the page can pick up the new status.
+#[component]
+pub fn AdverseActionPanel(loan: Loan, on_denied: EventHandler<()>) -> Element {
+    let loan_id = loan.id;
+    let mut denial = use_resource(move || async move { get_adverse_action(loan_id).await });
+
+    if loan.status != LoanStatus::Denied && !loan.status.can_transition_to(LoanStatus::Denied) {
+        return rsx! {};
+    }
+
+    match &*denial.read() {
+        Some(Ok(Some(existing))) => rsx! {
+            DenialNotice {
+                key: "{existing.updated_at}-{existing.document_id:?}",
+                denial: existing.clone(),
+                on_changed: move |_| denial.restart(),
+            }
+        },
+        Some(Ok(None)) => rsx! {
+            DenialForm {
+                loan_id,
+                already_denied: loan.status == LoanStatus::Denied,
+                on_denied: move |_| {
+                    denial.restart();
+                    on_denied.call(());
+                },
+            }
+        },
+        Some(Err(err)) => rsx! {
+            div { class: "text-red-600", "Could not load the denial: {err}" }
+        },
+        None => rsx! {
+            div { "Loading..." }
+        },
+    }
+}
+
+/// Reason picker; the order ticked is the order printed, principal reason first
+#[component]
+fn DenialForm(loan_id: i32, already_denied: bool, on_denied: EventHandler<()>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut reasons = use_signal(Vec::<DenialReason>::new);
+    let mut other_reason = use_signal(String::new);
+    let mut saving = use_signal(|| false);
+
+    let on_deny = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        let input = DenialInput {
+            reasons: reasons(),
+            other_reason: Some(other_reason()).filter(|text| !text.trim().is_empty()),
+        };
+        saving.set(true);
+        spawn(async move {
+            match deny_loan(loan_id, input).await {
+                Ok(denial) => {
+                    let message = if denial.document_id.is_some() {
+                        "The adverse action notice is ready to send"
+                    } else {
+                        "Generate the adverse action notice from this panel"
+                    };
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success(message, Some("Application denied")));
+                    on_denied.call(());
+                }
+                Err(err) => {
+                    tracing::error!("deny loan error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not deny the application")));
+                }
+            }
+            saving.set(false);
+        });
+    };
+
+    let needs_other = reasons.read().contains(&DenialReason::Other);
+    let ready = !reasons.read().is_empty() && (!needs_other || !other_reason.read().trim().is_empty());
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Adverse Action" }
+            p { class: "text-sm text-gray-600",
+                if already_denied {
+                    "This application was denied without reasons. Record them to generate the notice."
+                } else {
+                    "Denying the application requires its principal reasons, up to {HMDA_MAX_DENIAL_REASONS}, in order of importance."
+                }
+            }
+            div { class: "flex flex-col gap-1",
+                for reason in DenialReason::iter() {
+                    label { key: "{reason.code()}", class: "flex items-center gap-2 text-sm",
+                        input {
+                            r#type: "checkbox",
+                            checked: reasons.read().contains(&reason),
+                            disabled: !reasons.read().contains(&reason) && reasons.read().len() >= HMDA_MAX_DENIAL_REASONS,
+                            onchange: move |event: FormEvent| {
+                                reasons.write().retain(|r| *r != reason);
+                                if event.checked() {
+                                    reasons.write().push(reason);
+                                }
+                            },
+                        }
+                        span { class: "w-5 text-xs font-semibold text-gray-500",
+                            {reasons.read().iter().position(|r| *r == reason).map(|i| format!("{}.", i + 1)).unwrap_or_default()}
+                        }
+                        "{reason}"
+                    }
+                }
+            }
+            if needs_other {
+                input {
+                    class: FIELD_INPUT,
+                    placeholder: "Describe the other reason",
+                    maxlength: "200",
+                    value: "{other_reason}",
+                    oninput: move |event: FormEvent| other_reason.set(event.value()),
+                }
+            }
+            div {
+                Button {
+                    button_scheme: ButtonScheme::Danger,
+                    on_click: on_deny,
+                    disabled: !ready || saving(),
+                    text: if already_denied { "Record Reasons".to_string() } else { "Deny Application".to_string() },
+                }
+            }
+        }
+    }
+}
+
+/// The recorded denial, its notice and the delivery form
+#[component]
+fn DenialNotice(denial: AdverseAction, on_changed: EventHandler<()>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let today = chrono::Local::now().date_naive();
+    let loan_id = denial.loan_id;
+    let mut delivered_date = use_signal(|| today.format("%Y-%m-%d").to_string());
+    let mut delivery_method = use_signal(|| DisclosureDelivery::Mail);
+    let mut notice = use_signal(|| None::<DownloadFile>);
+    let mut busy = use_signal(|| false);
+
+    let method_options: Vec<(String, String)> = DisclosureDelivery::iter()
+        .map(|m| (m.code().to_string(), m.to_string()))
+        .collect();
+    let due = denial.due_date();
+
+    let on_generate = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        busy.set(true);
+        spawn(async move {
+            match generate_loan_document(loan_id, DocumentKind::AdverseActionNotice).await {
+                Ok(document) => {
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success(&document.file_name, Some("Notice generated")));
+                    on_changed.call(());
+                }
+                Err(err) => {
+                    tracing::error!("generate adverse action notice error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not generate the notice")));
+                }
+            }
+            busy.set(false);
+        });
+    };
+
+    let document_id = denial.document_id;
+    let on_fetch = move |_| {
+        let Some(document_id) = document_id else { return };
+        spawn(async move {
+            match download_loan_document(document_id).await {
+                Ok(file) => notice.set(Some(file)),
+                Err(err) => {
+                    tracing::error!("download adverse action notice error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Download failed")));
+                }
+            }
+        });
+    };
+
+    let on_record = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        let Ok(date) = NaiveDate::parse_from_str(&delivered_date(), "%Y-%m-%d") else {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Enter the delivery date", Some("Missing date")));
+            return;
+        };
+        let delivery = NoticeDelivery {
+            delivered_date: date,
+            delivery_method: delivery_method(),
+        };
+        busy.set(true);
+        spawn(async move {
+            match record_notice_delivery(loan_id, delivery).await {
+                Ok(_) => {
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success("Notice delivery recorded", None));
+                    on_changed.call(());
+                }
+                Err(err) => {
+                    tracing::error!("record notice delivery error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not record delivery")));
+                }
+            }
+            busy.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Adverse Action" }
+            p { class: "text-gray-700",
+                "Denied "
+                span { class: "font-semibold", {show(denial.decided_date)} }
+                " · Notice due by "
+                span { class: "font-semibold", {show(due)} }
+            }
+            ol { class: "list-decimal pl-6 text-sm",
+                for (i, statement) in denial.statements().into_iter().enumerate() {
+                    li { key: "{i}", "{statement}" }
+                }
+            }
+            div { class: "flex flex-row items-center gap-3",
+                if denial.document_id.is_some() {
+                    match notice() {
+                        Some(file) => rsx! {
+                            a {
+                                class: "text-blue-600 hover:underline",
+                                href: "{file.data_url()}",
+                                download: "{file.file_name}",
+                                "Save {file.file_name}"
+                            }
+                        },
+                        None => rsx! {
+                            button {
+                                class: "text-blue-600 hover:underline cursor-pointer",
+                                onclick: on_fetch,
+                                "Download notice"
+                            }
+                        },
+                    }
+                }
+                if denial.delivered_date.is_none() {
+                    Button {
+                        button_scheme: ButtonScheme::Default,
+                        on_click: on_generate,
+                        disabled: busy(),
+                        text: if denial.document_id.is_some() { "Regenerate Notice".to_string() } else { "Generate Notice".to_string() },
+                    }
+                }
+            }
+            match (denial.delivered_date, denial.delivery_method) {
+                (Some(date), Some(method)) => {
+                    let on_time = denial.delivered_on_time() == Some(true);
+                    rsx! {
+                        p { class: if on_time { "text-green-700" } else { "text-red-700" },
+                            "Delivered {show(date)} by {method}"
+                            if !on_time {
+                                ", after the {show(due)} deadline"
+                            }
+                        }
+                    }
+                }
+                _ => rsx! {
+                    div { class: "flex flex-row flex-wrap items-end gap-2",
+                        div { class: "flex flex-col",
+                            label { class: "text-sm font-medium text-blue-900", "Delivered or Mailed" }
+                            DateInput {
+                                i_value: delivered_date(),
+                                on_input: move |event: FormEvent| delivered_date.set(event.value()),
+                            }
+                        }
+                        div { class: "flex flex-col",
+                            label { class: "text-sm font-medium text-blue-900", "Method" }
+                            SelectInput {
+                                i_value: delivery_method().code().to_string(),
+                                options: method_options,
+                                on_input: move |event: FormEvent| {
+                                    if let Ok(method) = event.value().parse() {
+                                        delivery_method.set(method);
+                                    }
+                                },
+                            }
+                        }
+                        Button {
+                            button_scheme: ButtonScheme::Success,
+                            on_click: on_record,
+                            disabled: busy() || denial.document_id.is_none(),
+                            text: "Record Delivery".to_string(),
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Dashboard widget listing denied applications whose notice has not been delivered
+#[component]
+pub fn PendingNotices(on_view: EventHandler<i32>) -> Element {
+    let rows = use_resource(|| async { get_pending_notices().await });
+    let today = chrono::Local::now().date_naive();
+
+    let rows = match &*rows.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get pending notices error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        Table {
+            striped: true,
+            hoverable: true,
+            caption: rsx! { "Denied applications awaiting an adverse action notice" },
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Loan #" }
+                    TableHeaderCell { "Borrower" }
+                    TableHeaderCell { "Loan Officer" }
+                    TableHeaderCell { "Denied" }
+                    TableHeaderCell { "Notice Due" }
+                    TableHeaderCell { "Notice" }
+                    TableHeaderCell { "View" }
+                }
+            }
+            TableBody {
+                if rows.is_empty() {
+                    TableRow {
+                        TableCell { colspan: Some(7), class: Some("text-gray-500".to_string()), "Every adverse action notice has been delivered" }
+                    }
+                }
+                for row in rows.iter().cloned() {
+                    TableRow {
+                        key: "{row.loan_id}",
+                        TableCell { {row.loan_number.clone().unwrap_or_else(|| format!("#{}", row.loan_id))} }
+                        TableCell { "{row.borrower_name}" }
+                        TableCell { {row.loan_officer_name.clone().unwrap_or_else(|| "Unassigned".to_string())} }
+                        TableCell { {show(row.decided_date)} }
+                        TableCell {
+                            {
+                                let due = notice_due_date(row.decided_date);
+                                let days_left = (due - today).num_days();
+                                let class = if days_left < 0 {
+                                    "text-red-700 font-semibold"
+                                } else if days_left <= 7 {
+                                    "text-amber-700 font-semibold"
+                                } else {
+                                    ""
+                                };
+                                rsx! {
+                                    span { class,
+                                        {show(due)}
+                                        if days_left < 0 { " (overdue)" } else { " ({days_left} days)" }
+                                    }
+                                }
+                            }
+                        }
+                        TableCell { if row.has_notice { "Generated" } else { "Not generated" } }
+                        TableCell {
+                            button {
+                                class: "text-blue-600 hover:underline cursor-pointer",
+                                onclick: move |_| on_view.call(row.loan_id),
+                                "View"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
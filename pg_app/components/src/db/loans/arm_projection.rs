@@ -0,0 +1,344 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{delete_arm_terms, get_arm_terms, save_arm_terms};
+use shared::calculations::arm::{fully_indexed_rate, max_payment_cents, project_arm, ArmPeriod, ArmScenario};
+use shared::models::{ArmIndex, ArmTerms, Loan};
+use shared::money::format_cents;
+use strum::IntoEnumIterator;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::{Input, InputType, SelectInput};
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+const CHART_WIDTH: f64 = 640.0;
+const CHART_HEIGHT: f64 = 220.0;
+const CHART_PAD: f64 = 40.0;
+
+/// Adjustable-rate terms for a loan with worst-case and what-if projections
+#[component]
+pub fn ArmProjection(loan: Loan) -> Element {
+    let loan_id = loan.id;
+    let terms = use_resource(move || async move { get_arm_terms(loan_id).await });
+    let Some(note_rate) = loan.note_rate else {
+        return rsx! {
+            p { class: "text-gray-600", "Set the note rate to project adjustable-rate payments." }
+        };
+    };
+
+    match &*terms.read() {
+        Some(Ok(existing)) => rsx! {
+            ArmForm { loan: loan.clone(), note_rate, existing: existing.clone() }
+        },
+        Some(Err(err)) => rsx! {
+            div { class: "text-red-600", "Could not load ARM terms: {err}" }
+        },
+        None => rsx! {
+            div { "Loading ARM terms..." }
+        },
+    }
+}
+
+#[component]
+fn ArmForm(loan: Loan, note_rate: f64, existing: Option<ArmTerms>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let initial = existing.clone().unwrap_or_default();
+    let rate = |value: f64| format!("{value:.3}");
+
+    let mut saved = use_signal(|| existing.is_some());
+    let mut initial_fixed_months = use_signal(|| initial.initial_fixed_months.to_string());
+    let mut adjustment_interval_months = use_signal(|| initial.adjustment_interval_months.to_string());
+    let mut rate_index = use_signal(|| initial.rate_index);
+    let mut index_value = use_signal(|| rate(initial.index_value));
+    let mut margin = use_signal(|| rate(initial.margin));
+    let mut initial_cap = use_signal(|| rate(initial.initial_cap));
+    let mut periodic_cap = use_signal(|| rate(initial.periodic_cap));
+    let mut lifetime_cap = use_signal(|| rate(initial.lifetime_cap));
+    let mut floor_rate = use_signal(|| initial.floor_rate.map(rate).unwrap_or_default());
+    let mut what_if_index = use_signal(|| rate(initial.index_value));
+
+    let index_options: Vec<(String, String)> = ArmIndex::iter()
+        .map(|i| (i.code().to_string(), i.to_string()))
+        .collect();
+
+    let number = |s: &str| s.trim().parse::<f64>().unwrap_or(0.0);
+    let terms = ArmTerms {
+        loan_id: loan.id,
+        initial_fixed_months: initial_fixed_months.read().trim().parse().unwrap_or(0),
+        adjustment_interval_months: adjustment_interval_months.read().trim().parse().unwrap_or(0),
+        rate_index: rate_index(),
+        index_value: number(&index_value.read()),
+        margin: number(&margin.read()),
+        initial_cap: number(&initial_cap.read()),
+        periodic_cap: number(&periodic_cap.read()),
+        lifetime_cap: number(&lifetime_cap.read()),
+        floor_rate: Some(floor_rate())
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| number(&s)),
+    };
+
+    let worst_case = project_arm(loan.amount_cents, note_rate, loan.term_months, &terms, ArmScenario::WorstCase);
+    let what_if_rate = number(&what_if_index.read());
+    let what_if = project_arm(loan.amount_cents, note_rate, loan.term_months, &terms, ArmScenario::Index(what_if_rate));
+
+    let save_terms = terms.clone();
+    let on_save = move |_| {
+        let terms = save_terms.clone();
+        spawn(async move {
+            match save_arm_terms(terms.loan_id, terms).await {
+                Ok(_) => {
+                    saved.set(true);
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success("ARM terms saved", None));
+                }
+                Err(err) => {
+                    tracing::error!("save ARM terms error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not save ARM terms")));
+                }
+            }
+        });
+    };
+
+    let loan_id = loan.id;
+    let on_remove = move |_| {
+        spawn(async move {
+            match delete_arm_terms(loan_id).await {
+                Ok(()) => {
+                    saved.set(false);
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success("Loan is now fixed-rate", Some("ARM terms removed")));
+                }
+                Err(err) => {
+                    tracing::error!("delete ARM terms error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not remove ARM terms")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-4",
+            h3 { class: "text-lg font-semibold",
+                "Adjustable Rate"
+                if saved() {
+                    span { class: "ml-2 text-sm font-normal text-gray-600", "{terms.product_name()}" }
+                } else {
+                    span { class: "ml-2 text-sm font-normal text-gray-500", "(fixed-rate until saved)" }
+                }
+            }
+            div { class: "grid grid-cols-5 gap-2",
+                Input {
+                    name: "initial_fixed_months".to_string(),
+                    input_type: Some(InputType::Number),
+                    label: Some("Initial Fixed (months)".to_string()),
+                    value: Some(initial_fixed_months()),
+                    oninput: move |event: FormEvent| initial_fixed_months.set(event.value()),
+                }
+                Input {
+                    name: "adjustment_interval_months".to_string(),
+                    input_type: Some(InputType::Number),
+                    label: Some("Adjusts Every (months)".to_string()),
+                    value: Some(adjustment_interval_months()),
+                    oninput: move |event: FormEvent| adjustment_interval_months.set(event.value()),
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Index" }
+                    SelectInput {
+                        i_value: rate_index().code().to_string(),
+                        options: index_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(i) = event.value().parse() {
+                                rate_index.set(i);
+                            }
+                        },
+                    }
+                }
+                Input {
+                    name: "index_value".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Current Index %".to_string()),
+                    value: Some(index_value()),
+                    oninput: move |event: FormEvent| index_value.set(event.value()),
+                }
+                Input {
+                    name: "margin".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Margin %".to_string()),
+                    value: Some(margin()),
+                    oninput: move |event: FormEvent| margin.set(event.value()),
+                }
+                Input {
+                    name: "initial_cap".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Initial Cap %".to_string()),
+                    value: Some(initial_cap()),
+                    oninput: move |event: FormEvent| initial_cap.set(event.value()),
+                }
+                Input {
+                    name: "periodic_cap".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Periodic Cap %".to_string()),
+                    value: Some(periodic_cap()),
+                    oninput: move |event: FormEvent| periodic_cap.set(event.value()),
+                }
+                Input {
+                    name: "lifetime_cap".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Lifetime Cap %".to_string()),
+                    value: Some(lifetime_cap()),
+                    oninput: move |event: FormEvent| lifetime_cap.set(event.value()),
+                }
+                Input {
+                    name: "floor_rate".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Floor %".to_string()),
+                    placeholder: Some("Margin".to_string()),
+                    value: Some(floor_rate()),
+                    oninput: move |event: FormEvent| floor_rate.set(event.value()),
+                }
+            }
+            div { class: "flex flex-row gap-2",
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    on_click: on_save,
+                    text: "Save ARM Terms".to_string(),
+                }
+                if saved() {
+                    Button {
+                        button_scheme: ButtonScheme::Danger,
+                        on_click: on_remove,
+                        text: "Make Fixed-Rate".to_string(),
+                    }
+                }
+            }
+            p { class: "text-gray-700",
+                "Fully indexed rate today: "
+                span { class: "font-semibold",
+                    {format!("{:.3}%", fully_indexed_rate(terms.index_value, terms.margin))}
+                }
+                " · Rate range: "
+                span { class: "font-semibold",
+                    {format!("{:.3}% – {:.3}%", terms.floor(), note_rate + terms.lifetime_cap)}
+                }
+            }
+            ArmChart { worst_case: worst_case.clone(), what_if: what_if.clone() }
+            h4 { class: "font-semibold",
+                "Worst Case · max payment "
+                {format_cents(max_payment_cents(&worst_case))}
+            }
+            ArmPeriodTable { periods: worst_case }
+            div { class: "flex flex-row items-end gap-2",
+                Input {
+                    name: "what_if_index".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("What-if Index %".to_string()),
+                    value: Some(what_if_index()),
+                    oninput: move |event: FormEvent| what_if_index.set(event.value()),
+                }
+                h4 { class: "font-semibold pb-2",
+                    "Index at {what_if_rate:.3}% · max payment "
+                    {format_cents(max_payment_cents(&what_if))}
+                }
+            }
+            ArmPeriodTable { periods: what_if }
+        }
+    }
+}
+
+/// One row per rate change on a projected path
+#[component]
+fn ArmPeriodTable(periods: Vec<ArmPeriod>) -> Element {
+    rsx! {
+        Table {
+            striped: true,
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Payments" }
+                    TableHeaderCell { "Rate" }
+                    TableHeaderCell { "Monthly P&I" }
+                    TableHeaderCell { "Starting Balance" }
+                }
+            }
+            TableBody {
+                for period in periods.iter() {
+                    TableRow { key: "{period.first_payment}",
+                        TableCell { "{period.first_payment}–{period.last_payment}" }
+                        TableCell { {format!("{:.3}%", period.rate)} }
+                        TableCell { {format_cents(period.payment_cents)} }
+                        TableCell { {format_cents(period.starting_balance_cents)} }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Step-line points for one series, scaled into the chart area
+fn step_points(periods: &[ArmPeriod], last_payment: u32, value: impl Fn(&ArmPeriod) -> f64, max: f64) -> String {
+    let x = |payment: u32| CHART_PAD + (payment - 1) as f64 / last_payment.max(1) as f64 * (CHART_WIDTH - 2.0 * CHART_PAD);
+    let y = |v: f64| CHART_HEIGHT - CHART_PAD - v / max.max(f64::EPSILON) * (CHART_HEIGHT - 2.0 * CHART_PAD);
+
+    periods
+        .iter()
+        .flat_map(|period| {
+            let y = y(value(period));
+            [(x(period.first_payment), y), (x(period.last_payment + 1), y)]
+        })
+        .map(|(x, y)| format!("{x:.1},{y:.1}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Inline SVG of rate (left axis) and payment (right axis) over the term
+#[component]
+fn ArmChart(worst_case: Vec<ArmPeriod>, what_if: Vec<ArmPeriod>) -> Element {
+    let last_payment = worst_case.iter().chain(&what_if).map(|p| p.last_payment).max().unwrap_or(1);
+    let max_rate = worst_case.iter().chain(&what_if).map(|p| p.rate).fold(0.0, f64::max).ceil();
+    let max_payment = max_payment_cents(&worst_case).max(max_payment_cents(&what_if)) as f64;
+
+    let rate = |p: &ArmPeriod| p.rate;
+    let payment = |p: &ArmPeriod| p.payment_cents as f64;
+    let worst_rate = step_points(&worst_case, last_payment, rate, max_rate);
+    let worst_payment = step_points(&worst_case, last_payment, payment, max_payment);
+    let what_if_rate = step_points(&what_if, last_payment, rate, max_rate);
+    let what_if_payment = step_points(&what_if, last_payment, payment, max_payment);
+
+    let left = CHART_PAD;
+    let right = CHART_WIDTH - CHART_PAD;
+    let top = CHART_PAD;
+    let bottom = CHART_HEIGHT - CHART_PAD;
+
+    rsx! {
+        div { class: "rounded-lg bg-white shadow-sm p-4",
+            svg {
+                view_box: "0 0 {CHART_WIDTH} {CHART_HEIGHT}",
+                width: "100%",
+                xmlns: "http://www.w3.org/2000/svg",
+                line { x1: "{left}", y1: "{bottom}", x2: "{right}", y2: "{bottom}", stroke: "#9ca3af" }
+                line { x1: "{left}", y1: "{top}", x2: "{left}", y2: "{bottom}", stroke: "#9ca3af" }
+                line { x1: "{right}", y1: "{top}", x2: "{right}", y2: "{bottom}", stroke: "#9ca3af" }
+                text { x: "{left - 4.0}", y: "{top + 4.0}", font_size: "10", text_anchor: "end", fill: "#1e3a8a", "{max_rate:.0}%" }
+                text { x: "{left - 4.0}", y: "{bottom}", font_size: "10", text_anchor: "end", fill: "#1e3a8a", "0%" }
+                text { x: "{right + 4.0}", y: "{top + 4.0}", font_size: "10", fill: "#15803d",
+                    {format_cents(max_payment as i64)}
+                }
+                text { x: "{left}", y: "{bottom + 14.0}", font_size: "10", fill: "#6b7280", "1" }
+                text { x: "{right}", y: "{bottom + 14.0}", font_size: "10", text_anchor: "end", fill: "#6b7280", "{last_payment}" }
+                polyline { points: "{worst_rate}", fill: "none", stroke: "#1e3a8a", stroke_width: "2" }
+                polyline { points: "{what_if_rate}", fill: "none", stroke: "#1e3a8a", stroke_width: "2", stroke_dasharray: "4 3" }
+                polyline { points: "{worst_payment}", fill: "none", stroke: "#15803d", stroke_width: "2" }
+                polyline { points: "{what_if_payment}", fill: "none", stroke: "#15803d", stroke_width: "2", stroke_dasharray: "4 3" }
+            }
+            p { class: "text-xs text-gray-600 mt-1",
+                span { class: "text-blue-900", "Rate" }
+                " / "
+                span { class: "text-green-700", "Payment" }
+                " · solid: worst case · dashed: what-if index"
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use chrono::{Days, Local, NaiveDate};
+use dioxus::prelude::*;
+use server::loans::get_cycle_time_report;
+use shared::calculations::status_timeline::CycleTimeReport;
+use crate::ui::input::DateInput;
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+/// Days the report covers when first opened
+const DEFAULT_PERIOD_DAYS: u64 = 90;
+
+fn days(average: Option<f64>) -> String {
+    average.map(|days| format!("{:.1}", days)).unwrap_or_else(|| "—".to_string())
+}
+
+/// Average business days per status and per loan officer over a period,
+/// with application-to-closing cycle times
+#[component]
+pub fn CycleTimePanel() -> Element {
+    let today = Local::now().date_naive();
+    let mut from = use_signal(move || today.checked_sub_days(Days::new(DEFAULT_PERIOD_DAYS)).unwrap_or(today));
+    let mut to = use_signal(move || today);
+    let report = use_resource(move || async move { get_cycle_time_report(from(), to()).await });
+
+    let parse = |s: String| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok();
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Cycle Times" }
+            div { class: "flex flex-row items-center gap-2",
+                label { class: "text-sm font-medium text-blue-900", "From" }
+                DateInput {
+                    i_value: from().format("%Y-%m-%d").to_string(),
+                    on_input: move |event: FormEvent| {
+                        if let Some(date) = parse(event.value()) {
+                            from.set(date);
+                        }
+                    },
+                }
+                label { class: "text-sm font-medium text-blue-900", "To" }
+                DateInput {
+                    i_value: to().format("%Y-%m-%d").to_string(),
+                    on_input: move |event: FormEvent| {
+                        if let Some(date) = parse(event.value()) {
+                            to.set(date);
+                        }
+                    },
+                }
+            }
+            match &*report.read() {
+                Some(Ok(report)) => rsx! {
+                    CycleTimeTables { report: report.clone() }
+                },
+                Some(Err(err)) => rsx! {
+                    div { class: "text-red-600", "Could not load cycle times: {err}" }
+                },
+                None => rsx! {
+                    div { "Loading cycle times..." }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn CycleTimeTables(report: CycleTimeReport) -> Element {
+    let statuses: Vec<_> = report.stages.iter().map(|stage| stage.status).collect();
+
+    rsx! {
+        p { class: "text-gray-700",
+            "Loans closed: "
+            span { class: "font-semibold", "{report.closed_loans}" }
+            " · Average application to closing: "
+            span { class: "font-semibold", {days(report.average_cycle_days)} }
+            " business days"
+        }
+        Table {
+            striped: true,
+            caption: rsx! { "Average business days per status, for stages that ended in the period" },
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Status" }
+                    TableHeaderCell { "Stages" }
+                    TableHeaderCell { "Average Days" }
+                    TableHeaderCell { "Over Target" }
+                }
+            }
+            TableBody {
+                if report.stages.is_empty() {
+                    TableRow {
+                        TableCell { colspan: Some(4), class: Some("text-gray-500".to_string()), "No status changes in this period" }
+                    }
+                }
+                for stage in report.stages.iter() {
+                    TableRow { key: "{stage.status.code()}",
+                        TableCell { "{stage.status}" }
+                        TableCell { "{stage.completed}" }
+                        TableCell { {days(Some(stage.average_business_days))} }
+                        TableCell { "{stage.breaches}" }
+                    }
+                }
+            }
+        }
+        Table {
+            striped: true,
+            caption: rsx! { "Average business days per loan officer" },
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Loan Officer" }
+                    for status in statuses.iter() {
+                        TableHeaderCell { key: "{status.code()}", "{status}" }
+                    }
+                    TableHeaderCell { "Closed" }
+                    TableHeaderCell { "Application to Closing" }
+                }
+            }
+            TableBody {
+                for officer in report.officers.iter() {
+                    TableRow { key: "{officer.loan_officer_id:?}",
+                        TableCell { {officer.loan_officer_name.clone().unwrap_or_else(|| "Unassigned".to_string())} }
+                        for status in statuses.iter() {
+                            TableCell { key: "{status.code()}",
+                                {days(officer.stage(*status).map(|stage| stage.average_business_days))}
+                            }
+                        }
+                        TableCell { "{officer.closed_loans}" }
+                        TableCell { {days(officer.average_cycle_days)} }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use chrono::NaiveDate;
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{get_disclosures, get_tolerance_report, issue_disclosure};
+use shared::calculations::tolerance::ToleranceBucket;
+use shared::models::{Disclosure, DisclosureInput, DisclosureKind};
+use shared::money::format_cents;
+use strum::IntoEnumIterator;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::{DateInput, SelectInput};
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableFoot, TableHead, TableHeaderCell, TableRow};
+
+/// Disclosure snapshots for a loan with the LE-versus-CD tolerance report
+#[component]
+pub fn DisclosureTolerance(loan_id: i32) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut disclosures = use_resource(move || async move { get_disclosures(loan_id).await });
+    let today = chrono::Local::now().date_naive();
+
+    let mut kind = use_signal(DisclosureKind::default);
+    let mut issued_date = use_signal(move || today.format("%Y-%m-%d").to_string());
+    let mut baseline = use_signal(|| None::<i32>);
+    let mut compared = use_signal(|| None::<i32>);
+
+    let rows = match &*disclosures.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get disclosures error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    // Default to the latest LE against the latest disclosure issued after it
+    let latest_le = rows.iter().rev().find(|d| d.kind == DisclosureKind::LoanEstimate).map(|d| d.id);
+    let baseline_id = baseline().or(latest_le);
+    let baseline_position = rows.iter().position(|d| Some(d.id) == baseline_id);
+    let later: Vec<Disclosure> = rows
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| baseline_position.is_some_and(|b| *i > b))
+        .map(|(_, d)| d.clone())
+        .collect();
+    let compared_id = compared()
+        .filter(|id| later.iter().any(|d| d.id == *id))
+        .or_else(|| later.iter().rev().find(|d| d.kind == DisclosureKind::ClosingDisclosure).map(|d| d.id))
+        .or_else(|| later.last().map(|d| d.id));
+
+    let kind_options: Vec<(String, String)> = DisclosureKind::iter()
+        .map(|k| (k.code().to_string(), k.to_string()))
+        .collect();
+    let le_options: Vec<(String, String)> = rows
+        .iter()
+        .filter(|d| d.kind == DisclosureKind::LoanEstimate)
+        .map(|d| (d.id.to_string(), d.label()))
+        .collect();
+    let later_options: Vec<(String, String)> = later.iter().map(|d| (d.id.to_string(), d.label())).collect();
+
+    let on_issue = move |_| {
+        let Ok(date) = NaiveDate::parse_from_str(&issued_date.read(), "%Y-%m-%d") else {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Pick the date the disclosure was issued", Some("Invalid input")));
+            return;
+        };
+        let input = DisclosureInput { kind: kind(), issued_date: date };
+        spawn(async move {
+            match issue_disclosure(loan_id, input).await {
+                Ok(disclosure) => {
+                    baseline.set(None);
+                    compared.set(None);
+                    disclosures.restart();
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success(&disclosure.label(), Some("Fee worksheet snapshot saved")));
+                }
+                Err(err) => {
+                    tracing::error!("issue disclosure error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not issue disclosure")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-4",
+            h3 { class: "text-lg font-semibold", "Disclosures & Tolerances" }
+            div { class: "flex flex-row flex-wrap items-end gap-2",
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Form" }
+                    SelectInput {
+                        i_value: kind().code().to_string(),
+                        options: kind_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(k) = event.value().parse() {
+                                kind.set(k);
+                            }
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Issued" }
+                    DateInput {
+                        i_value: issued_date(),
+                        on_input: move |event: FormEvent| issued_date.set(event.value()),
+                    }
+                }
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    on_click: on_issue,
+                    text: "Issue & Freeze Fees".to_string(),
+                }
+            }
+            if rows.is_empty() {
+                p { class: "text-gray-500", "No disclosures issued yet" }
+            } else {
+                div { class: "flex flex-row flex-wrap items-end gap-2",
+                    div { class: "flex flex-col",
+                        label { class: "text-sm font-medium text-blue-900", "Loan Estimate" }
+                        SelectInput {
+                            i_value: baseline_id.map(|id| id.to_string()).unwrap_or_default(),
+                            options: le_options,
+                            on_input: move |event: FormEvent| baseline.set(event.value().parse().ok()),
+                        }
+                    }
+                    div { class: "flex flex-col",
+                        label { class: "text-sm font-medium text-blue-900", "Compared To" }
+                        SelectInput {
+                            i_value: compared_id.map(|id| id.to_string()).unwrap_or_default(),
+                            options: later_options,
+                            on_input: move |event: FormEvent| compared.set(event.value().parse().ok()),
+                        }
+                    }
+                }
+            }
+            match (baseline_id, compared_id) {
+                (Some(disclosed_id), Some(final_id)) => rsx! {
+                    ToleranceReportTable { key: "{disclosed_id}-{final_id}", disclosed_id, final_id }
+                },
+                _ => rsx! {
+                    p { class: "text-gray-500", "Issue a Loan Estimate and a later disclosure to compare fees" }
+                },
+            }
+        }
+    }
+}
+
+/// Fee-by-fee tolerance comparison of two disclosures with the cure owed
+#[component]
+fn ToleranceReportTable(disclosed_id: i32, final_id: i32) -> Element {
+    let report = use_resource(move || async move { get_tolerance_report(disclosed_id, final_id).await });
+    let money = |cents: Option<i64>| cents.map(format_cents).unwrap_or_else(|| "—".to_string());
+
+    match &*report.read() {
+        Some(Ok(report)) => rsx! {
+            Table {
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Fee" }
+                        TableHeaderCell { "Bucket" }
+                        TableHeaderCell { "Disclosed" }
+                        TableHeaderCell { "Final" }
+                        TableHeaderCell { "Change" }
+                        TableHeaderCell { "Cure" }
+                    }
+                }
+                TableBody {
+                    // Two worksheet lines may share a section and name
+                    for (i, change) in report.changes.iter().enumerate() {
+                        TableRow {
+                            key: "{i}",
+                            class: if change.cure_cents > 0 { Some("text-red-600".to_string()) } else { None },
+                            TableCell { "{change.section.letter()}. {change.name}" }
+                            TableCell { "{change.bucket}" }
+                            TableCell { {money(change.disclosed_cents)} }
+                            TableCell { {money(change.final_cents)} }
+                            TableCell { {format_cents(change.change_cents())} }
+                            TableCell {
+                                if change.bucket == ToleranceBucket::Zero {
+                                    {format_cents(change.cure_cents)}
+                                }
+                            }
+                        }
+                    }
+                }
+                TableFoot {
+                    TableRow {
+                        TableHeaderCell { colspan: Some(2), "10% Aggregate Bucket" }
+                        TableCell { {format_cents(report.ten_percent_disclosed_cents)} }
+                        TableCell { {format_cents(report.ten_percent_final_cents)} }
+                        TableCell { "limit {format_cents(report.ten_percent_limit_cents())}" }
+                        TableCell { {format_cents(report.ten_percent_cure_cents)} }
+                    }
+                    TableRow {
+                        TableHeaderCell { colspan: Some(2), "Lender Credits" }
+                        TableCell { {format_cents(report.lender_credits_disclosed_cents)} }
+                        TableCell { {format_cents(report.lender_credits_final_cents)} }
+                        TableCell { {format_cents(report.lender_credits_final_cents - report.lender_credits_disclosed_cents)} }
+                        TableCell { {format_cents(report.lender_credit_cure_cents)} }
+                    }
+                    TableRow {
+                        TableHeaderCell { colspan: Some(5), "Zero Tolerance Cure" }
+                        TableCell { {format_cents(report.zero_tolerance_cure_cents)} }
+                    }
+                    TableRow {
+                        TableHeaderCell { colspan: Some(5), "Total Cure Owed to Borrower" }
+                        TableCell {
+                            class: Some(if report.total_cure_cents() > 0 { "font-semibold text-red-600" } else { "font-semibold text-green-700" }.to_string()),
+                            {format_cents(report.total_cure_cents())}
+                        }
+                    }
+                }
+            }
+        },
+        Some(Err(err)) => rsx! {
+            div { class: "text-red-600", "Could not compare disclosures: {err}" }
+        },
+        None => rsx! {
+            div { "Comparing disclosures..." }
+        },
+    }
+}
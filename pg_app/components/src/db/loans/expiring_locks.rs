@@ -0,0 +1,62 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::get_expiring_rate_locks;
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+/// Dashboard widget listing active locks that expire within the next 7 days
+#[component]
+pub fn ExpiringLocks(on_view: EventHandler<i32>) -> Element {
+    let rows = use_resource(|| async { get_expiring_rate_locks(7).await });
+    let today = chrono::Local::now().date_naive();
+
+    let rows = match &*rows.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get expiring rate locks error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        Table {
+            striped: true,
+            hoverable: true,
+            caption: rsx! { "Locks expiring in the next 7 days" },
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Loan #" }
+                    TableHeaderCell { "Borrower" }
+                    TableHeaderCell { "Rate" }
+                    TableHeaderCell { "Expires" }
+                    TableHeaderCell { "Days Left" }
+                    TableHeaderCell { "View" }
+                }
+            }
+            TableBody {
+                if rows.is_empty() {
+                    TableRow {
+                        TableCell { colspan: Some(6), class: Some("text-gray-500".to_string()), "No locks expiring soon" }
+                    }
+                }
+                for row in rows.iter().cloned() {
+                    TableRow {
+                        key: "{row.rate_lock_id}",
+                        class: if (row.expiration_date - today).num_days() <= 3 { Some("text-red-600".to_string()) } else { None },
+                        TableCell { {row.loan_number.clone().unwrap_or_else(|| format!("#{}", row.loan_id))} }
+                        TableCell { "{row.borrower_name}" }
+                        TableCell { {format!("{:.3}%", row.rate)} }
+                        TableCell { {row.expiration_date.format("%m/%d/%Y").to_string()} }
+                        TableCell { {(row.expiration_date - today).num_days().to_string()} }
+                        TableCell {
+                            button {
+                                class: "text-blue-600 hover:underline cursor-pointer",
+                                onclick: move |_| on_view.call(row.loan_id),
+                                "View"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,505 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{
+    create_loan_fee, delete_loan_fee, get_closing_adjustments, get_loan_fees, get_property,
+    save_closing_adjustments, update_loan_fee,
+};
+use shared::calculations::apr::{apr_within_tolerance, APR_TOLERANCE};
+use shared::calculations::closing_costs::ClosingCostSummary;
+use shared::models::{ClosingAdjustments, FeePayer, FeeSection, Loan, LoanFee, LoanFeeInput};
+use shared::money::{format_cents, parse_dollars};
+use strum::IntoEnumIterator;
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableFoot, TableHead, TableHeaderCell, TableRow};
+
+const CELL_INPUT: &str = "w-full rounded border border-gray-300 bg-white px-1 py-0.5 text-sm";
+
+type AdjustmentField = (&'static str, i64, fn(&mut ClosingAdjustments, i64));
+
+/// Closing cost worksheet for a loan, edited inline, with the cost summary
+#[component]
+pub fn FeeWorksheet(loan: Loan) -> Element {
+    let loan_id = loan.id;
+    let mut fees = use_resource(move || async move { get_loan_fees(loan_id).await });
+    let mut adjustments = use_resource(move || async move { get_closing_adjustments(loan_id).await });
+    let property = use_resource(move || async move { get_property(loan_id).await });
+
+    let rows = match &*fees.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get loan fees error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let current_adjustments = match &*adjustments.read() {
+        Some(Ok(a)) => a.clone(),
+        _ => ClosingAdjustments { loan_id, ..Default::default() },
+    };
+    let purchase_price_cents = match &*property.read() {
+        Some(Ok(Some(p))) => p.purchase_price_cents,
+        _ => None,
+    };
+    let summary = ClosingCostSummary::calculate(&rows, &current_adjustments, &loan, purchase_price_cents);
+
+    rsx! {
+        div { class: "flex flex-col gap-4",
+            h3 { class: "text-lg font-semibold", "Fee Worksheet" }
+            Table {
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Fee" }
+                        TableHeaderCell { "Amount" }
+                        TableHeaderCell { "Payer" }
+                        TableHeaderCell { "Paid To" }
+                        TableHeaderCell { "Finance Charge" }
+                        TableHeaderCell { "Off-List / Recording" }
+                        TableHeaderCell { "" }
+                    }
+                }
+                for section in FeeSection::iter() {
+                    TableBody { key: "{section.code()}",
+                        TableRow { class: Some("bg-gray-100".to_string()),
+                            TableHeaderCell { colspan: Some(6), "{section.letter()}. {section}" }
+                            TableHeaderCell { {format_cents(summary.section_total(section))} }
+                        }
+                        for fee in rows.iter().filter(|fee| fee.section == section).cloned() {
+                            FeeRow {
+                                key: "{fee.id}",
+                                fee,
+                                on_changed: move |_| fees.restart(),
+                            }
+                        }
+                        NewFeeRow { loan_id, section, on_added: move |_| fees.restart() }
+                    }
+                }
+                TableFoot {
+                    TableRow {
+                        TableHeaderCell { colspan: Some(6), "D. Total Loan Costs (A + B + C)" }
+                        TableCell { {format_cents(summary.loan_costs_cents)} }
+                    }
+                    TableRow {
+                        TableHeaderCell { colspan: Some(6), "I. Total Other Costs (E + F + G)" }
+                        TableCell { {format_cents(summary.other_costs_cents)} }
+                    }
+                    TableRow {
+                        TableHeaderCell { colspan: Some(6), "Lender Credits" }
+                        TableCell { {format!("-{}", format_cents(summary.lender_credits_cents))} }
+                    }
+                    TableRow {
+                        TableHeaderCell { colspan: Some(6), "J. Total Closing Costs" }
+                        TableCell { class: Some("font-semibold".to_string()), {format_cents(summary.total_closing_costs_cents)} }
+                    }
+                }
+            }
+            ClosingAdjustmentsForm {
+                key: "{current_adjustments.earnest_money_cents}-{current_adjustments.seller_credits_cents}-{current_adjustments.lender_credits_cents}-{current_adjustments.payoffs_cents}",
+                adjustments: current_adjustments.clone(),
+                on_saved: move |_| adjustments.restart(),
+            }
+            div { class: "grid grid-cols-4 gap-4",
+                SummaryTile { label: "Total Closing Costs", value: format_cents(summary.total_closing_costs_cents) }
+                SummaryTile {
+                    label: if summary.cash_to_close_cents < 0 { "Cash to Borrower" } else { "Cash to Close" },
+                    value: format_cents(summary.cash_to_close_cents.abs()),
+                }
+                SummaryTile { label: "Prepaid Finance Charges", value: format_cents(summary.prepaid_finance_charge_cents) }
+                SummaryTile { label: "Total Finance Charge", value: format_cents(summary.total_finance_charge_cents) }
+            }
+            AprComparison {
+                note_rate: loan.note_rate,
+                apr: summary.apr,
+                adjustments: current_adjustments.clone(),
+                on_saved: move |_| adjustments.restart(),
+            }
+            if summary.seller_paid_cents > 0 || summary.paid_by_others_cents > 0 {
+                p { class: "text-sm text-gray-600",
+                    "Seller-paid: {format_cents(summary.seller_paid_cents)} · Paid by others: {format_cents(summary.paid_by_others_cents)}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SummaryTile(label: String, value: String) -> Element {
+    rsx! {
+        div { class: "rounded-lg bg-white shadow-sm p-4",
+            p { class: "text-xs uppercase text-gray-500", "{label}" }
+            p { class: "text-xl font-semibold", "{value}" }
+        }
+    }
+}
+
+fn payer_options() -> Vec<(String, String)> {
+    FeePayer::iter().map(|p| (p.code().to_string(), p.to_string())).collect()
+}
+
+fn dollars(cents: i64) -> String {
+    format!("{:.2}", cents as f64 / 100.0)
+}
+
+/// One existing fee; each field saves when it changes
+#[component]
+fn FeeRow(fee: LoanFee, on_changed: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let fee_id = fee.id;
+
+    let save = move |input: LoanFeeInput| {
+        spawn(async move {
+            match update_loan_fee(fee_id, input).await {
+                Ok(_) => on_changed.call(fee_id),
+                Err(err) => {
+                    tracing::error!("update fee error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not save fee")));
+                    on_changed.call(fee_id);
+                }
+            }
+        });
+    };
+    let base = LoanFeeInput::from(&fee);
+
+    rsx! {
+        TableRow {
+            TableCell {
+                input {
+                    class: CELL_INPUT,
+                    value: "{fee.name}",
+                    onchange: {
+                        let base = base.clone();
+                        move |event: FormEvent| save(LoanFeeInput { name: event.value(), ..base.clone() })
+                    },
+                }
+            }
+            TableCell {
+                input {
+                    class: CELL_INPUT,
+                    value: dollars(fee.amount_cents),
+                    onchange: {
+                        let base = base.clone();
+                        move |event: FormEvent| match parse_dollars(&event.value()) {
+                            Some(amount_cents) => save(LoanFeeInput { amount_cents, ..base.clone() }),
+                            None => {
+                                toast_manager
+                                    .write()
+                                    .popup(ToastInfo::error("Amount must be a dollar amount", Some("Invalid input")));
+                                on_changed.call(fee_id);
+                            }
+                        }
+                    },
+                }
+            }
+            TableCell {
+                select {
+                    class: CELL_INPUT,
+                    value: fee.payer.code(),
+                    onchange: {
+                        let base = base.clone();
+                        move |event: FormEvent| {
+                            if let Ok(payer) = event.value().parse() {
+                                save(LoanFeeInput { payer, ..base.clone() });
+                            }
+                        }
+                    },
+                    for (value, label) in payer_options() {
+                        option { value: "{value}", selected: value == fee.payer.code(), "{label}" }
+                    }
+                }
+            }
+            TableCell {
+                input {
+                    class: CELL_INPUT,
+                    value: fee.paid_to.clone().unwrap_or_default(),
+                    onchange: {
+                        let base = base.clone();
+                        move |event: FormEvent| save(LoanFeeInput { paid_to: Some(event.value()), ..base.clone() })
+                    },
+                }
+            }
+            TableCell {
+                input {
+                    r#type: "checkbox",
+                    checked: fee.finance_charge,
+                    onchange: {
+                        let base = base.clone();
+                        move |event: FormEvent| save(LoanFeeInput { finance_charge: event.checked(), ..base.clone() })
+                    },
+                }
+            }
+            TableCell {
+                if fee.section == FeeSection::CanShop {
+                    input {
+                        r#type: "checkbox",
+                        checked: fee.off_list_provider,
+                        onchange: {
+                            let base = base.clone();
+                            move |event: FormEvent| save(LoanFeeInput { off_list_provider: event.checked(), ..base.clone() })
+                        },
+                    }
+                }
+                if fee.section == FeeSection::TaxesGovernment {
+                    input {
+                        r#type: "checkbox",
+                        title: "Recording fee",
+                        checked: fee.recording_fee,
+                        onchange: {
+                            let base = base.clone();
+                            move |event: FormEvent| save(LoanFeeInput { recording_fee: event.checked(), ..base.clone() })
+                        },
+                    }
+                }
+            }
+            TableCell {
+                button {
+                    class: "text-xs text-red-600 hover:underline cursor-pointer",
+                    onclick: move |_| {
+                        spawn(async move {
+                            match delete_loan_fee(fee_id).await {
+                                Ok(_) => on_changed.call(fee_id),
+                                Err(err) => tracing::error!("delete fee error: {err}"),
+                            }
+                        });
+                    },
+                    "Remove"
+                }
+            }
+        }
+    }
+}
+
+/// Blank row at the end of a section for adding a fee
+#[component]
+fn NewFeeRow(loan_id: i32, section: FeeSection, on_added: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut name = use_signal(String::new);
+    let mut amount = use_signal(String::new);
+    let mut payer = use_signal(FeePayer::default);
+    let mut paid_to = use_signal(String::new);
+    let mut finance_charge = use_signal(|| false);
+    let mut off_list_provider = use_signal(|| false);
+    let mut recording_fee = use_signal(|| false);
+
+    let mut add = move || {
+        if name.read().trim().is_empty() {
+            return;
+        }
+        let Some(amount_cents) = parse_dollars(&amount.read()) else {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Amount must be a dollar amount", Some("Invalid input")));
+            return;
+        };
+        let input = LoanFeeInput {
+            section,
+            name: name(),
+            amount_cents,
+            payer: payer(),
+            paid_to: Some(paid_to()),
+            finance_charge: finance_charge(),
+            off_list_provider: section == FeeSection::CanShop && off_list_provider(),
+            recording_fee: section == FeeSection::TaxesGovernment && recording_fee(),
+        };
+        spawn(async move {
+            match create_loan_fee(loan_id, input).await {
+                Ok(fee) => {
+                    name.set(String::new());
+                    amount.set(String::new());
+                    paid_to.set(String::new());
+                    finance_charge.set(false);
+                    off_list_provider.set(false);
+                    recording_fee.set(false);
+                    on_added.call(fee.id);
+                }
+                Err(err) => {
+                    tracing::error!("create fee error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not add fee")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        TableRow {
+            TableCell {
+                input {
+                    class: CELL_INPUT,
+                    placeholder: "Add fee…",
+                    value: "{name}",
+                    oninput: move |event: FormEvent| name.set(event.value()),
+                }
+            }
+            TableCell {
+                input {
+                    class: CELL_INPUT,
+                    placeholder: "0.00",
+                    value: "{amount}",
+                    oninput: move |event: FormEvent| amount.set(event.value()),
+                }
+            }
+            TableCell {
+                select {
+                    class: CELL_INPUT,
+                    value: payer().code(),
+                    onchange: move |event: FormEvent| {
+                        if let Ok(p) = event.value().parse() {
+                            payer.set(p);
+                        }
+                    },
+                    for (value, label) in payer_options() {
+                        option { value: "{value}", selected: value == payer().code(), "{label}" }
+                    }
+                }
+            }
+            TableCell {
+                input {
+                    class: CELL_INPUT,
+                    value: "{paid_to}",
+                    oninput: move |event: FormEvent| paid_to.set(event.value()),
+                }
+            }
+            TableCell {
+                input {
+                    r#type: "checkbox",
+                    checked: finance_charge(),
+                    onchange: move |event: FormEvent| finance_charge.set(event.checked()),
+                }
+            }
+            TableCell {
+                if section == FeeSection::CanShop {
+                    input {
+                        r#type: "checkbox",
+                        checked: off_list_provider(),
+                        onchange: move |event: FormEvent| off_list_provider.set(event.checked()),
+                    }
+                }
+                if section == FeeSection::TaxesGovernment {
+                    input {
+                        r#type: "checkbox",
+                        title: "Recording fee",
+                        checked: recording_fee(),
+                        onchange: move |event: FormEvent| recording_fee.set(event.checked()),
+                    }
+                }
+            }
+            TableCell {
+                button {
+                    class: "text-xs text-blue-600 hover:underline cursor-pointer",
+                    onclick: move |_| add(),
+                    "Add"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ClosingAdjustmentsForm(adjustments: ClosingAdjustments, on_saved: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let loan_id = adjustments.loan_id;
+
+    let save = move |updated: ClosingAdjustments| {
+        spawn(async move {
+            match save_closing_adjustments(updated).await {
+                Ok(_) => on_saved.call(loan_id),
+                Err(err) => {
+                    tracing::error!("save closing adjustments error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not save")));
+                }
+            }
+        });
+    };
+
+    let fields: [AdjustmentField; 4] = [
+        ("Earnest Money Deposit", adjustments.earnest_money_cents, |a, v| a.earnest_money_cents = v),
+        ("Seller Credits", adjustments.seller_credits_cents, |a, v| a.seller_credits_cents = v),
+        ("Lender Credits", adjustments.lender_credits_cents, |a, v| a.lender_credits_cents = v),
+        ("Payoffs (refinance)", adjustments.payoffs_cents, |a, v| a.payoffs_cents = v),
+    ];
+
+    rsx! {
+        div { class: "grid grid-cols-4 gap-4",
+            for (label, cents, set) in fields {
+                div { key: "{label}", class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "{label}" }
+                    input {
+                        class: CELL_INPUT,
+                        value: dollars(cents),
+                        onchange: {
+                            let adjustments = adjustments.clone();
+                            move |event: FormEvent| match parse_dollars(&event.value()) {
+                                Some(value) => {
+                                    let mut updated = adjustments.clone();
+                                    set(&mut updated, value);
+                                    save(updated);
+                                }
+                                None => {
+                                    toast_manager
+                                        .write()
+                                        .popup(ToastInfo::error("Enter a dollar amount", Some("Invalid input")));
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Note rate beside the worksheet APR, flagged when it has moved more than
+/// the tolerance from the APR last disclosed
+#[component]
+fn AprComparison(note_rate: Option<f64>, apr: Option<f64>, adjustments: ClosingAdjustments, on_saved: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let loan_id = adjustments.loan_id;
+    let apr_text = apr.map(|apr| format!("{apr:.3}%")).unwrap_or_else(|| "—".to_string());
+    let disclosed_text = adjustments.disclosed_apr.map(|apr| format!("{apr:.3}")).unwrap_or_default();
+    let out_of_tolerance = match (adjustments.disclosed_apr, apr) {
+        (Some(disclosed), Some(apr)) => !apr_within_tolerance(disclosed, apr, false),
+        _ => false,
+    };
+
+    let save = move |disclosed_apr: Option<f64>| {
+        let updated = ClosingAdjustments { disclosed_apr, ..adjustments.clone() };
+        spawn(async move {
+            match save_closing_adjustments(updated).await {
+                Ok(_) => on_saved.call(loan_id),
+                Err(err) => {
+                    tracing::error!("save disclosed APR error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not save")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "grid grid-cols-4 gap-4 items-end",
+            SummaryTile {
+                label: "Note Rate",
+                value: note_rate.map(|rate| format!("{rate:.3}%")).unwrap_or_else(|| "—".to_string()),
+            }
+            SummaryTile { label: "APR", value: apr_text }
+            div { class: "flex flex-col",
+                label { class: "text-sm font-medium text-blue-900", "Last Disclosed APR (%)" }
+                input {
+                    class: CELL_INPUT,
+                    value: disclosed_text,
+                    onchange: move |event: FormEvent| {
+                        let value = event.value();
+                        if value.trim().is_empty() {
+                            save(None);
+                        } else if let Ok(disclosed) = value.trim().trim_end_matches('%').parse::<f64>() {
+                            save(Some(disclosed));
+                        } else {
+                            toast_manager.write().popup(ToastInfo::error("Enter a percentage", Some("Invalid input")));
+                        }
+                    },
+                }
+            }
+            if out_of_tolerance {
+                p { class: "rounded bg-red-50 p-2 text-sm text-red-700",
+                    "APR has moved more than {APR_TOLERANCE}% since the last disclosure; a corrected disclosure is required."
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{export_loan_fnm, import_loan_fnm};
+use shared::dtos::FnmImportResult;
+use shared::models::Permission;
+use super::mismo_exchange::percent_encode;
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::FileInput;
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// Builds the loan's Fannie Mae 3.2 file and offers it as a download; the
+/// file carries full SSNs, so only users with `RevealPii` see it
+#[component]
+pub fn FnmExport(loan_id: i32, file_stem: String) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut document = use_signal(|| None::<String>);
+
+    if !CURRENT_USER().is_some_and(|user| user.has_permission(Permission::RevealPii)) {
+        return rsx! {};
+    }
+
+    let on_export = move |_| {
+        spawn(async move {
+            match export_loan_fnm(loan_id).await {
+                Ok(fnm) => document.set(Some(fnm)),
+                Err(err) => {
+                    tracing::error!("export FNM error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Export failed")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-row items-center gap-3",
+            Button {
+                button_scheme: ButtonScheme::Default,
+                on_click: on_export,
+                text: "Export Fannie Mae 3.2".to_string(),
+            }
+            if let Some(fnm) = document() {
+                a {
+                    class: "text-blue-600 hover:underline",
+                    href: "data:text/plain;charset=utf-8,{percent_encode(&fnm)}",
+                    download: "{file_stem}.fnm",
+                    "Download {file_stem}.fnm"
+                }
+            }
+        }
+    }
+}
+
+/// Uploads a Fannie Mae 3.2 file, matching applicants to existing borrowers by SSN and name
+#[component]
+pub fn FnmImport(on_imported: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut result = use_signal(|| None::<FnmImportResult>);
+    let mut errors = use_signal(Vec::<String>::new);
+
+    let on_file = move |event: FormEvent| {
+        spawn(async move {
+            let Some(engine) = event.files() else { return };
+            for file_name in engine.files() {
+                let Some(contents) = engine.read_file_to_string(&file_name).await else {
+                    tracing::error!("could not read {file_name}");
+                    continue;
+                };
+                match import_loan_fnm(contents).await {
+                    Ok(imported) => {
+                        let action = if imported.loan_updated { "Updated" } else { "Created" };
+                        toast_manager
+                            .write()
+                            .popup(ToastInfo::success(&format!("{} loan #{}", action, imported.loan_id), Some("Import complete")));
+                        on_imported.call(imported.loan_id);
+                        errors.set(Vec::new());
+                        result.set(Some(imported));
+                    }
+                    Err(err) => {
+                        tracing::error!("import FNM error: {err}");
+                        toast_manager.write().popup(ToastInfo::error("See the problems listed below the upload", Some("Import failed")));
+                        // Parse errors come back one per line, each with its line and column
+                        errors.set(err.to_string().lines().map(str::to_string).collect());
+                        result.set(None);
+                    }
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-1",
+            label { class: "text-sm font-medium text-blue-900", "Import Fannie Mae 3.2 loan file (.fnm)" }
+            FileInput { i_value: String::new(), on_input: on_file }
+            if let Some(imported) = result() {
+                p { class: "text-sm text-green-700",
+                    "Loan #{imported.loan_id}: {imported.borrower_ids.len()} applicants, "
+                    "{imported.matched_borrowers} matched to existing borrowers"
+                }
+                if !imported.ignored_records.is_empty() {
+                    p { class: "text-sm text-amber-700",
+                        "Records not imported: "
+                        {imported.ignored_records.join(", ")}
+                    }
+                }
+            }
+            if !errors.read().is_empty() {
+                ul { class: "list-disc pl-6 font-mono text-xs text-red-700",
+                    for (i, error) in errors.read().iter().enumerate() {
+                        li { key: "{i}", "{error}" }
+                    }
+                }
+            }
+        }
+    }
+}
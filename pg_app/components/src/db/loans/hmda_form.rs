@@ -0,0 +1,425 @@
+use chrono::NaiveDate;
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{get_hmda_data, save_hmda_data};
+use shared::models::{
+    ActionTaken, CreditScoreModel, DenialReason, Ethnicity, HmdaApplicant, HmdaData, HmdaInput, HoepaStatus,
+    LienStatus, Loan, ObservationBasis, Preapproval, PurchaserType, Race, Sex, HMDA_MAX_DENIAL_REASONS,
+    HMDA_MAX_SELECTIONS,
+};
+use strum::IntoEnumIterator;
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::{DateInput, SelectInput};
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+const FIELD_INPUT: &str = "rounded border border-gray-300 bg-white px-2 py-1 text-sm";
+
+/// Select options for an HMDA code enum, led by a blank choice when `optional`
+fn code_options<T: std::fmt::Display>(values: impl Iterator<Item = T>, code: fn(&T) -> i16, optional: bool) -> Vec<(String, String)> {
+    let blank = optional.then(|| (String::new(), "—".to_string()));
+    blank
+        .into_iter()
+        .chain(values.map(|value| (code(&value).to_string(), value.to_string())))
+        .collect()
+}
+
+/// Option's code for a select's value, blank when unset
+fn code_value(code: Option<i16>) -> String {
+    code.map(|c| c.to_string()).unwrap_or_default()
+}
+
+/// HMDA data for a loan: action taken, demographics, denial reasons and pricing
+///
+/// When nothing has been recorded yet the action taken is prefilled from the
+/// loan's status.
+#[component]
+pub fn HmdaForm(loan: Loan) -> Element {
+    let loan_id = loan.id;
+    let mut data = use_resource(move || async move { get_hmda_data(loan_id).await });
+
+    match &*data.read() {
+        Some(Ok(existing)) => {
+            let input = match existing {
+                Some(existing) => HmdaInput::from(existing),
+                None => HmdaInput {
+                    action_taken: ActionTaken::from_status(loan.status),
+                    action_taken_date: ActionTaken::from_status(loan.status)
+                        .map(|_| chrono::Local::now().date_naive()),
+                    ..Default::default()
+                },
+            };
+            let key = existing.as_ref().map(|e| e.updated_at.to_string()).unwrap_or_default();
+            rsx! {
+                HmdaFields {
+                    key: "{key}",
+                    loan_id,
+                    input,
+                    on_saved: move |_: HmdaData| data.restart(),
+                }
+            }
+        }
+        Some(Err(err)) => rsx! {
+            div { class: "text-red-600", "Could not load HMDA data: {err}" }
+        },
+        None => rsx! {
+            div { "Loading HMDA data..." }
+        },
+    }
+}
+
+#[component]
+fn HmdaFields(loan_id: i32, input: HmdaInput, on_saved: EventHandler<HmdaData>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut draft = use_signal(|| input.clone());
+    let changed = *draft.read() != input;
+
+    let action_options = code_options(ActionTaken::iter(), ActionTaken::code, true);
+    let preapproval_options = code_options(Preapproval::iter(), Preapproval::code, false);
+    let lien_options = code_options(LienStatus::iter(), LienStatus::code, false);
+    let hoepa_options = code_options(HoepaStatus::iter(), HoepaStatus::code, false);
+    let purchaser_options = code_options(PurchaserType::iter(), PurchaserType::code, false);
+
+    let show_date = |date: Option<NaiveDate>| date.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+    let show_number = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+    let denied = matches!(
+        draft.read().action_taken,
+        Some(ActionTaken::Denied | ActionTaken::PreapprovalDenied)
+    );
+
+    let on_save = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        spawn(async move {
+            match save_hmda_data(loan_id, draft()).await {
+                Ok(saved) => {
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success("HMDA data saved", None));
+                    on_saved.call(saved);
+                }
+                Err(err) => {
+                    tracing::error!("save HMDA data error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not save HMDA data")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-3",
+            h3 { class: "text-lg font-semibold", "HMDA" }
+            div { class: "grid grid-cols-4 gap-2",
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Action Taken" }
+                    SelectInput {
+                        i_value: code_value(draft.read().action_taken.map(|a| a.code())),
+                        options: action_options,
+                        on_input: move |event: FormEvent| draft.write().action_taken = event.value().parse().ok(),
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Action Taken Date" }
+                    DateInput {
+                        i_value: show_date(draft.read().action_taken_date),
+                        on_input: move |event: FormEvent| {
+                            draft.write().action_taken_date = NaiveDate::parse_from_str(&event.value(), "%Y-%m-%d").ok();
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Preapproval" }
+                    SelectInput {
+                        i_value: draft.read().preapproval.code().to_string(),
+                        options: preapproval_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(preapproval) = event.value().parse() {
+                                draft.write().preapproval = preapproval;
+                            }
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Lien Status" }
+                    SelectInput {
+                        i_value: draft.read().lien_status.code().to_string(),
+                        options: lien_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(lien) = event.value().parse() {
+                                draft.write().lien_status = lien;
+                            }
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "County (FIPS)" }
+                    input {
+                        class: "{FIELD_INPUT} font-mono",
+                        value: "{draft.read().county.clone().unwrap_or_default()}",
+                        oninput: move |event: FormEvent| {
+                            let value = event.value().trim().to_string();
+                            draft.write().county = (!value.is_empty()).then_some(value);
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Census Tract" }
+                    input {
+                        class: "{FIELD_INPUT} font-mono",
+                        value: "{draft.read().census_tract.clone().unwrap_or_default()}",
+                        oninput: move |event: FormEvent| {
+                            let value = event.value().trim().to_string();
+                            draft.write().census_tract = (!value.is_empty()).then_some(value);
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Rate Spread (%)" }
+                    input {
+                        class: FIELD_INPUT,
+                        r#type: "number",
+                        step: "0.001",
+                        value: "{show_number(draft.read().rate_spread)}",
+                        oninput: move |event: FormEvent| draft.write().rate_spread = event.value().parse().ok(),
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Debt-to-Income (%)" }
+                    input {
+                        class: FIELD_INPUT,
+                        r#type: "number",
+                        step: "0.01",
+                        value: "{show_number(draft.read().debt_to_income)}",
+                        oninput: move |event: FormEvent| draft.write().debt_to_income = event.value().parse().ok(),
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "HOEPA Status" }
+                    SelectInput {
+                        i_value: draft.read().hoepa_status.code().to_string(),
+                        options: hoepa_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(hoepa) = event.value().parse() {
+                                draft.write().hoepa_status = hoepa;
+                            }
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Purchaser" }
+                    SelectInput {
+                        i_value: draft.read().purchaser_type.code().to_string(),
+                        options: purchaser_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(purchaser) = event.value().parse() {
+                                draft.write().purchaser_type = purchaser;
+                            }
+                        },
+                    }
+                }
+            }
+            if denied {
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-sm font-medium text-blue-900",
+                        "Denial Reasons (up to {HMDA_MAX_DENIAL_REASONS}, principal reason first)"
+                    }
+                    div { class: "flex flex-row flex-wrap gap-x-4 gap-y-1",
+                        for reason in DenialReason::iter() {
+                            label { key: "{reason.code()}", class: "flex items-center gap-1 text-sm",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: draft.read().denial_reasons.contains(&reason),
+                                    disabled: !draft.read().denial_reasons.contains(&reason)
+                                        && draft.read().denial_reasons.len() >= HMDA_MAX_DENIAL_REASONS,
+                                    onchange: move |event: FormEvent| {
+                                        draft.write().denial_reasons.retain(|r| *r != reason);
+                                        if event.checked() {
+                                            draft.write().denial_reasons.push(reason);
+                                        }
+                                    },
+                                }
+                                "{reason}"
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "grid grid-cols-2 gap-4",
+                ApplicantFields {
+                    title: "Applicant".to_string(),
+                    applicant: draft.read().applicant.clone(),
+                    on_change: move |applicant| draft.write().applicant = applicant,
+                }
+                ApplicantFields {
+                    title: "Co-Applicant".to_string(),
+                    applicant: draft.read().co_applicant.clone(),
+                    on_change: move |applicant| draft.write().co_applicant = applicant,
+                }
+            }
+            div {
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    on_click: on_save,
+                    disabled: !changed,
+                    text: "Save HMDA Data".to_string(),
+                }
+            }
+        }
+    }
+}
+
+/// Demographic information and credit score for one applicant
+///
+/// Left blank for a co-applicant when the loan has none; the LAR reports
+/// "no co-applicant" for those fields.
+#[component]
+fn ApplicantFields(title: String, applicant: HmdaApplicant, on_change: EventHandler<HmdaApplicant>) -> Element {
+    let observed_options = code_options(ObservationBasis::iter(), ObservationBasis::code, true);
+    let sex_options = code_options(Sex::iter(), Sex::code, true);
+    let model_options = code_options(CreditScoreModel::iter(), CreditScoreModel::code, true);
+
+    let current = applicant.clone();
+    let update = move |change: &dyn Fn(&mut HmdaApplicant)| {
+        let mut next = current.clone();
+        change(&mut next);
+        on_change.call(next);
+    };
+    let (ethnicity_update, race_update) = (update.clone(), update.clone());
+    let (ethnicity_observed_update, race_observed_update) = (update.clone(), update.clone());
+    let (sex_update, sex_observed_update) = (update.clone(), update.clone());
+    let (age_update, score_update, model_update) = (update.clone(), update.clone(), update.clone());
+
+    rsx! {
+        div { class: "flex flex-col gap-2 rounded border border-gray-200 p-3",
+            h4 { class: "font-semibold", "{title}" }
+            label { class: "text-sm font-medium text-blue-900", "Ethnicity (up to {HMDA_MAX_SELECTIONS})" }
+            div { class: "flex flex-row flex-wrap gap-x-3 gap-y-1",
+                for ethnicity in Ethnicity::iter() {
+                    label { key: "{ethnicity.code()}", class: "flex items-center gap-1 text-sm",
+                        input {
+                            r#type: "checkbox",
+                            checked: applicant.ethnicities.contains(&ethnicity),
+                            disabled: !applicant.ethnicities.contains(&ethnicity)
+                                && applicant.ethnicities.len() >= HMDA_MAX_SELECTIONS,
+                            onchange: {
+                                let ethnicity_update = ethnicity_update.clone();
+                                move |event: FormEvent| ethnicity_update(&|next: &mut HmdaApplicant| {
+                                    next.ethnicities.retain(|e| *e != ethnicity);
+                                    if event.checked() {
+                                        next.ethnicities.push(ethnicity);
+                                    }
+                                })
+                            },
+                        }
+                        "{ethnicity}"
+                    }
+                }
+            }
+            label { class: "text-sm font-medium text-blue-900", "Race (up to {HMDA_MAX_SELECTIONS})" }
+            div { class: "flex flex-row flex-wrap gap-x-3 gap-y-1",
+                for race in Race::iter() {
+                    label { key: "{race.code()}", class: "flex items-center gap-1 text-sm",
+                        input {
+                            r#type: "checkbox",
+                            checked: applicant.races.contains(&race),
+                            disabled: !applicant.races.contains(&race) && applicant.races.len() >= HMDA_MAX_SELECTIONS,
+                            onchange: {
+                                let race_update = race_update.clone();
+                                move |event: FormEvent| race_update(&|next: &mut HmdaApplicant| {
+                                    next.races.retain(|r| *r != race);
+                                    if event.checked() {
+                                        next.races.push(race);
+                                    }
+                                })
+                            },
+                        }
+                        "{race}"
+                    }
+                }
+            }
+            div { class: "grid grid-cols-2 gap-2",
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Ethnicity Collected" }
+                    SelectInput {
+                        i_value: code_value(applicant.ethnicity_observed.map(|o| o.code())),
+                        options: observed_options.clone(),
+                        on_input: move |event: FormEvent| {
+                            let observed = event.value().parse().ok();
+                            ethnicity_observed_update(&|next: &mut HmdaApplicant| next.ethnicity_observed = observed);
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Race Collected" }
+                    SelectInput {
+                        i_value: code_value(applicant.race_observed.map(|o| o.code())),
+                        options: observed_options.clone(),
+                        on_input: move |event: FormEvent| {
+                            let observed = event.value().parse().ok();
+                            race_observed_update(&|next: &mut HmdaApplicant| next.race_observed = observed);
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Sex" }
+                    SelectInput {
+                        i_value: code_value(applicant.sex.map(|s| s.code())),
+                        options: sex_options,
+                        on_input: move |event: FormEvent| {
+                            let sex = event.value().parse().ok();
+                            sex_update(&|next: &mut HmdaApplicant| next.sex = sex);
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Sex Collected" }
+                    SelectInput {
+                        i_value: code_value(applicant.sex_observed.map(|o| o.code())),
+                        options: observed_options,
+                        on_input: move |event: FormEvent| {
+                            let observed = event.value().parse().ok();
+                            sex_observed_update(&|next: &mut HmdaApplicant| next.sex_observed = observed);
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Age" }
+                    input {
+                        class: FIELD_INPUT,
+                        r#type: "number",
+                        value: "{applicant.age.map(|a| a.to_string()).unwrap_or_default()}",
+                        oninput: move |event: FormEvent| {
+                            let age = event.value().parse().ok();
+                            age_update(&|next: &mut HmdaApplicant| next.age = age);
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Credit Score" }
+                    input {
+                        class: FIELD_INPUT,
+                        r#type: "number",
+                        value: "{applicant.credit_score.map(|s| s.to_string()).unwrap_or_default()}",
+                        oninput: move |event: FormEvent| {
+                            let score = event.value().parse().ok();
+                            score_update(&|next: &mut HmdaApplicant| next.credit_score = score);
+                        },
+                    }
+                }
+                div { class: "flex flex-col col-span-2",
+                    label { class: "text-sm font-medium text-blue-900", "Credit Score Model" }
+                    SelectInput {
+                        i_value: code_value(applicant.credit_model.map(|m| m.code())),
+                        options: model_options,
+                        on_input: move |event: FormEvent| {
+                            let model = event.value().parse().ok();
+                            model_update(&|next: &mut HmdaApplicant| next.credit_model = model);
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{download_loan_document, generate_loan_document, get_loan_documents};
+use shared::dtos::DownloadFile;
+use shared::models::DocumentKind;
+use strum::IntoEnumIterator;
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+/// Generated PDFs saved on a loan, with a button to generate each kind
+///
+/// Adverse action notices are generated from the denial instead.
+#[component]
+pub fn LoanDocuments(loan_id: i32) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut documents = use_resource(move || async move { get_loan_documents(loan_id).await });
+    let mut generating = use_signal(|| None::<DocumentKind>);
+    let mut downloaded = use_signal(|| None::<(i32, DownloadFile)>);
+
+    let rows = match &*documents.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get loan documents error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    let mut on_generate = move |kind: DocumentKind| {
+        if CURRENT_USER().is_none() {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Sign in to generate documents", Some("Not signed in")));
+            return;
+        }
+        generating.set(Some(kind));
+        spawn(async move {
+            match generate_loan_document(loan_id, kind).await {
+                Ok(document) => {
+                    documents.restart();
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success(&document.file_name, Some("Document saved")));
+                }
+                Err(err) => {
+                    tracing::error!("generate {kind} error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not generate document")));
+                }
+            }
+            generating.set(None);
+        });
+    };
+
+    let on_fetch = move |document_id: i32| {
+        spawn(async move {
+            match download_loan_document(document_id).await {
+                Ok(file) => downloaded.set(Some((document_id, file))),
+                Err(err) => {
+                    tracing::error!("download loan document error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Download failed")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-4",
+            h3 { class: "text-lg font-semibold", "Documents" }
+            div { class: "flex flex-row flex-wrap gap-2",
+                for kind in DocumentKind::iter().filter(|kind| *kind != DocumentKind::AdverseActionNotice) {
+                    Button {
+                        key: "{kind.code()}",
+                        button_scheme: ButtonScheme::Default,
+                        on_click: move |_| on_generate(kind),
+                        disabled: generating().is_some(),
+                        text: if generating() == Some(kind) { "Generating...".to_string() } else { format!("Generate {kind}") },
+                    }
+                }
+            }
+            if rows.is_empty() {
+                p { class: "text-gray-500", "No documents generated yet" }
+            } else {
+                Table {
+                    TableHead {
+                        TableRow {
+                            TableHeaderCell { "Document" }
+                            TableHeaderCell { "Generated" }
+                            TableHeaderCell { "Size" }
+                            TableHeaderCell { "File" }
+                        }
+                    }
+                    TableBody {
+                        for document in rows.iter() {
+                            TableRow { key: "{document.id}",
+                                TableCell { "{document.kind}" }
+                                TableCell { {document.created_at.format("%m/%d/%Y %H:%M").to_string()} }
+                                TableCell { {format!("{:.1} KB", document.size_bytes as f64 / 1024.0)} }
+                                TableCell {
+                                    match downloaded().filter(|(id, _)| *id == document.id) {
+                                        Some((_, file)) => rsx! {
+                                            a {
+                                                class: "text-blue-600 hover:underline",
+                                                href: "{file.data_url()}",
+                                                download: "{file.file_name}",
+                                                "Save {file.file_name}"
+                                            }
+                                        },
+                                        None => {
+                                            let document_id = document.id;
+                                            rsx! {
+                                                button {
+                                                    class: "text-blue-600 hover:underline cursor-pointer",
+                                                    onclick: move |_| on_fetch(document_id),
+                                                    "{document.file_name}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
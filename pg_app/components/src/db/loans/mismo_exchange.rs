@@ -0,0 +1,109 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{export_loan_mismo, import_loan_mismo};
+use shared::dtos::MismoImportResult;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::FileInput;
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// Builds the loan's MISMO 3.4 file and offers it as a download
+#[component]
+pub fn MismoExport(loan_id: i32, file_stem: String) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut document = use_signal(|| None::<String>);
+
+    let on_export = move |_| {
+        spawn(async move {
+            match export_loan_mismo(loan_id).await {
+                Ok(xml) => document.set(Some(xml)),
+                Err(err) => {
+                    tracing::error!("export MISMO error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Export failed")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-row items-center gap-3",
+            Button {
+                button_scheme: ButtonScheme::Default,
+                on_click: on_export,
+                text: "Export MISMO 3.4".to_string(),
+            }
+            if let Some(xml) = document() {
+                a {
+                    class: "text-blue-600 hover:underline",
+                    href: "data:application/xml;charset=utf-8,{percent_encode(&xml)}",
+                    download: "{file_stem}.xml",
+                    "Download {file_stem}.xml"
+                }
+            }
+        }
+    }
+}
+
+/// Uploads a MISMO 3.4 file as a new borrower and loan, listing what was left behind
+#[component]
+pub fn MismoImport(on_imported: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut result = use_signal(|| None::<MismoImportResult>);
+
+    let on_file = move |event: FormEvent| {
+        spawn(async move {
+            let Some(engine) = event.files() else { return };
+            for file_name in engine.files() {
+                let Some(contents) = engine.read_file_to_string(&file_name).await else {
+                    tracing::error!("could not read {file_name}");
+                    continue;
+                };
+                match import_loan_mismo(contents).await {
+                    Ok(imported) => {
+                        toast_manager
+                            .write()
+                            .popup(ToastInfo::success(&format!("Created loan #{}", imported.loan_id), Some("Import complete")));
+                        on_imported.call(imported.loan_id);
+                        result.set(Some(imported));
+                    }
+                    Err(err) => {
+                        tracing::error!("import MISMO error: {err}");
+                        toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Import failed")));
+                    }
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-1",
+            label { class: "text-sm font-medium text-blue-900", "Import MISMO 3.4 loan file (.xml)" }
+            FileInput { i_value: String::new(), on_input: on_file }
+            if let Some(imported) = result() {
+                if imported.unmapped.is_empty() {
+                    p { class: "text-sm text-green-700", "Every element of loan #{imported.loan_id}'s file was imported" }
+                } else {
+                    details { class: "text-sm text-amber-700",
+                        summary { "{imported.unmapped.len()} elements of loan #{imported.loan_id}'s file were not imported" }
+                        ul { class: "list-disc pl-6 font-mono text-xs text-gray-700",
+                            for path in imported.unmapped.iter() {
+                                li { key: "{path}", "{path}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Percent-encodes text for a `data:` URL
+pub(crate) fn percent_encode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
@@ -0,0 +1,37 @@
+pub use add_loan::AddLoan;
+pub use adverse_action::{AdverseActionPanel, PendingNotices};
+pub use arm_projection::ArmProjection;
+pub use cycle_time_report::CycleTimePanel;
+pub use disclosure_tolerance::DisclosureTolerance;
+pub use expiring_locks::ExpiringLocks;
+pub use fee_worksheet::FeeWorksheet;
+pub use fnm_exchange::{FnmExport, FnmImport};
+pub use hmda_form::HmdaForm;
+pub use loan_documents::LoanDocuments;
+pub use mismo_exchange::{MismoExport, MismoImport};
+pub use payment_quote::PaymentQuote;
+pub use pipeline_board::PipelineBoard;
+pub use rate_locks::RateLocks;
+pub use status_timeline::{SlaBreaches, StatusTimeline};
+pub use subject_property::SubjectProperty;
+pub use trid_at_risk::TridAtRisk;
+pub use trid_timeline::TridTimeline;
+
+pub mod add_loan;          // Contains AddLoan
+pub mod adverse_action;    // Contains AdverseActionPanel, denial reasons and the notice, and the PendingNotices widget
+pub mod arm_projection;    // Contains ArmProjection, the ARM terms and rate/payment paths
+pub mod cycle_time_report; // Contains CycleTimePanel, average days per status and loan officer
+pub mod disclosure_tolerance; // Contains DisclosureTolerance, LE/CD snapshots and the cure report
+pub mod expiring_locks;    // Contains the "locks expiring soon" dashboard widget
+pub mod fee_worksheet;     // Contains FeeWorksheet, the closing cost worksheet
+pub mod fnm_exchange;      // Contains FnmExport and FnmImport for Fannie Mae 3.2 loan files
+pub mod hmda_form;         // Contains HmdaForm, the action taken, demographics and denial reasons
+pub mod loan_documents;    // Contains LoanDocuments, the generated PDFs and their downloads
+pub mod mismo_exchange;    // Contains MismoExport and MismoImport for MISMO 3.4 loan files
+pub mod payment_quote;     // Contains PaymentQuote with escrow, PMI and HPA dates
+pub mod pipeline_board;    // Contains PipelineBoard, the status Kanban
+pub mod rate_locks;        // Contains RateLocks, the lock form and extension history
+pub mod status_timeline;   // Contains StatusTimeline, time in each status against SLA targets, and the SlaBreaches widget
+pub mod subject_property;  // Contains SubjectProperty and the LTV summary
+pub mod trid_at_risk;      // Contains the "TRID deadlines at risk" dashboard widget
+pub mod trid_timeline;     // Contains TridTimeline, the disclosure dates and waiting periods
@@ -0,0 +1,227 @@
+use chrono::{Datelike, Months, NaiveDate};
+use dioxus::{logger::tracing, prelude::*};
+use server::borrowers::get_loan_debts;
+use server::loans::get_property;
+use server::settings::get_pmi_rates;
+use shared::calculations::amortization::{amortization_schedule, monthly_payment_cents};
+use shared::calculations::dti::back_end_dti;
+use shared::calculations::escrow::{escrow_analysis, EscrowItem, MAX_CUSHION_MONTHS};
+use shared::calculations::ltv::loan_to_value;
+use shared::calculations::mortgage_insurance::{hpa_dates, monthly_pmi_cents, pmi_annual_rate, HPA_CANCELLATION_LTV};
+use shared::models::Loan;
+use shared::money::{format_cents, parse_dollars};
+use crate::ui::input::{DateInput, Input, InputType};
+use crate::ui::{Table, TableBody, TableCell, TableFoot, TableHead, TableHeaderCell, TableRow};
+
+/// Parses a list of calendar months such as `"6, 12"`
+fn parse_months(input: &str) -> Vec<u32> {
+    input
+        .split(',')
+        .filter_map(|month| month.trim().parse::<u32>().ok())
+        .filter(|month| (1..=12).contains(month))
+        .collect()
+}
+
+/// Full monthly payment quote: P&I, escrow, PMI, initial escrow deposit and
+/// the Homeowners Protection Act PMI dates
+#[component]
+pub fn PaymentQuote(loan: Loan) -> Element {
+    let loan_id = loan.id;
+    let property = use_resource(move || async move { get_property(loan_id).await });
+    let pmi_rates = use_resource(move || async move { get_pmi_rates().await });
+    let debts = use_resource(move || async move { get_loan_debts(loan_id).await });
+
+    let today = chrono::Local::now().date_naive();
+    let default_first_payment = today
+        .with_day(1)
+        .and_then(|date| date.checked_add_months(Months::new(2)))
+        .unwrap_or(today);
+    let mut annual_taxes = use_signal(|| "0".to_string());
+    let mut tax_months = use_signal(|| "6, 12".to_string());
+    let mut annual_insurance = use_signal(|| "0".to_string());
+    let mut insurance_months = use_signal(move || default_first_payment.month().to_string());
+    let mut credit_score = use_signal(|| "740".to_string());
+    let mut first_payment = use_signal(move || default_first_payment.format("%Y-%m-%d").to_string());
+
+    let value_cents = match &*property.read() {
+        Some(Ok(Some(p))) => p.ltv_value_cents(),
+        _ => None,
+    };
+    let rates = match &*pmi_rates.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get PMI rates error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let Some(note_rate) = loan.note_rate else {
+        return rsx! {
+            p { class: "text-gray-600", "Set the note rate to quote a payment." }
+        };
+    };
+
+    let first_payment_date = NaiveDate::parse_from_str(&first_payment(), "%Y-%m-%d").unwrap_or(default_first_payment);
+    let items = vec![
+        EscrowItem {
+            name: "Property Taxes".to_string(),
+            annual_cents: parse_dollars(&annual_taxes()).unwrap_or(0),
+            due_months: parse_months(&tax_months()),
+        },
+        EscrowItem {
+            name: "Homeowners Insurance".to_string(),
+            annual_cents: parse_dollars(&annual_insurance()).unwrap_or(0),
+            due_months: parse_months(&insurance_months()),
+        },
+    ];
+    let escrow = escrow_analysis(&items, first_payment_date.month(), MAX_CUSHION_MONTHS);
+    let principal_interest = monthly_payment_cents(loan.amount_cents, note_rate, loan.term_months);
+
+    let ltv = value_cents.and_then(|value| loan_to_value(loan.amount_cents, value));
+    let needs_pmi = ltv.is_some_and(|ltv| ltv > HPA_CANCELLATION_LTV);
+    let score = credit_score().trim().parse::<i32>().unwrap_or(0);
+    let pmi_rate = ltv.filter(|_| needs_pmi).and_then(|ltv| pmi_annual_rate(&rates, ltv, score));
+    let pmi = pmi_rate.map(|rate| monthly_pmi_cents(loan.amount_cents, rate)).unwrap_or(0);
+    let total = principal_interest + escrow.monthly_cents + pmi;
+    let back_end_ratio = match &*debts.read() {
+        Some(Ok(debts)) => back_end_dti(&debts.summary, total, debts.monthly_income_cents)
+            .map(|ratio| format!("{:.2}%", ratio))
+            .unwrap_or_else(|| "N/A".to_string()),
+        Some(Err(err)) => {
+            tracing::error!("get loan debts error: {err}");
+            "N/A".to_string()
+        }
+        None => "Loading...".to_string(),
+    };
+
+    let hpa = match value_cents {
+        Some(value) if needs_pmi => Some(hpa_dates(
+            &amortization_schedule(loan.amount_cents, note_rate, loan.term_months),
+            value,
+            first_payment_date,
+        )),
+        _ => None,
+    };
+    let show_date = |date: Option<NaiveDate>| date.map(|d| d.format("%m/%d/%Y").to_string()).unwrap_or_else(|| "—".to_string());
+
+    rsx! {
+        div { class: "flex flex-col gap-4",
+            h3 { class: "text-lg font-semibold", "Payment Quote" }
+            div { class: "flex flex-row flex-wrap items-end gap-2",
+                Input {
+                    name: "annual_taxes".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Annual Taxes".to_string()),
+                    value: Some(annual_taxes()),
+                    oninput: move |event: FormEvent| annual_taxes.set(event.value()),
+                }
+                Input {
+                    name: "tax_months".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Tax Due Months".to_string()),
+                    value: Some(tax_months()),
+                    oninput: move |event: FormEvent| tax_months.set(event.value()),
+                }
+                Input {
+                    name: "annual_insurance".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Annual Insurance".to_string()),
+                    value: Some(annual_insurance()),
+                    oninput: move |event: FormEvent| annual_insurance.set(event.value()),
+                }
+                Input {
+                    name: "insurance_months".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Insurance Due Month".to_string()),
+                    value: Some(insurance_months()),
+                    oninput: move |event: FormEvent| insurance_months.set(event.value()),
+                }
+                Input {
+                    name: "credit_score".to_string(),
+                    input_type: Some(InputType::Number),
+                    label: Some("Credit Score".to_string()),
+                    value: Some(credit_score()),
+                    oninput: move |event: FormEvent| credit_score.set(event.value()),
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "First Payment" }
+                    DateInput {
+                        i_value: first_payment(),
+                        on_input: move |event: FormEvent| first_payment.set(event.value()),
+                    }
+                }
+            }
+            Table {
+                TableBody {
+                    TableRow {
+                        TableCell { "Principal & Interest" }
+                        TableCell { {format_cents(principal_interest)} }
+                    }
+                    TableRow {
+                        TableCell { "Escrow (taxes & insurance)" }
+                        TableCell { {format_cents(escrow.monthly_cents)} }
+                    }
+                    TableRow {
+                        TableCell {
+                            match (needs_pmi, pmi_rate) {
+                                (false, _) => "Mortgage Insurance (not required)".to_string(),
+                                (true, Some(rate)) => format!("Mortgage Insurance ({rate:.2}% annual)"),
+                                (true, None) => "Mortgage Insurance (no rate for this LTV and score)".to_string(),
+                            }
+                        }
+                        TableCell { {format_cents(pmi)} }
+                    }
+                }
+                TableFoot {
+                    TableRow {
+                        TableHeaderCell { "Total Monthly Payment" }
+                        TableCell { class: Some("font-semibold".to_string()), {format_cents(total)} }
+                    }
+                    TableRow {
+                        TableHeaderCell { "Back-end DTI" }
+                        TableCell { {back_end_ratio} }
+                    }
+                }
+            }
+            h4 { class: "font-semibold", "Initial Escrow Deposit" }
+            Table {
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Item" }
+                        TableHeaderCell { "Per Month" }
+                        TableHeaderCell { "Months" }
+                        TableHeaderCell { "Amount" }
+                    }
+                }
+                TableBody {
+                    for item in escrow.items.iter() {
+                        TableRow { key: "{item.name}",
+                            TableCell { "{item.name}" }
+                            TableCell { {format_cents(item.monthly_cents)} }
+                            TableCell { "{item.months}" }
+                            TableCell { {format_cents(item.deposit_cents)} }
+                        }
+                    }
+                    TableRow {
+                        TableCell { colspan: Some(3), "Aggregate Adjustment" }
+                        TableCell { {format_cents(escrow.aggregate_adjustment_cents)} }
+                    }
+                }
+                TableFoot {
+                    TableRow {
+                        TableHeaderCell { colspan: Some(3), "Initial Escrow Payment at Closing" }
+                        TableCell { class: Some("font-semibold".to_string()), {format_cents(escrow.initial_deposit_cents)} }
+                    }
+                }
+            }
+            if let Some(dates) = hpa {
+                div { class: "rounded-lg bg-white shadow-sm p-4 text-sm",
+                    h4 { class: "font-semibold mb-1", "PMI Cancellation (Homeowners Protection Act)" }
+                    p { "Borrower may request cancellation at 80% LTV: {show_date(dates.cancellation_request)}" }
+                    p { "Automatic termination at 78% LTV: {show_date(dates.automatic_termination)}" }
+                    p { "Final termination at amortization midpoint: {show_date(dates.final_termination)}" }
+                }
+            }
+        }
+    }
+}
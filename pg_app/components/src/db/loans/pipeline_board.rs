@@ -0,0 +1,211 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{get_pipeline, transition_loan_status};
+use server::users::get_all_users;
+use shared::exports::{ExportList, ExportSort};
+use shared::models::{LoanStatus, LoanType, UserRole};
+use shared::money::format_cents;
+use shared::{PipelineCard, PipelineFilter};
+use strum::IntoEnumIterator;
+use crate::db::exports::ExportPanel;
+use crate::db::session::CURRENT_USER;
+use crate::ui::input::{DateInput, SelectInput};
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// Kanban board of loans in columns by status
+///
+/// Cards can be dragged to another column; the move goes through
+/// `transition_loan_status` as the signed-in user. Dropping on Denied opens
+/// the loan instead, where the denial reasons are chosen. The export uses
+/// the board's filter.
+#[component]
+pub fn PipelineBoard(on_view: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut officer = use_signal(String::new);
+    let mut loan_type = use_signal(String::new);
+    let mut from_date = use_signal(String::new);
+    let mut to_date = use_signal(String::new);
+    let mut dragging = use_signal(|| None::<PipelineCard>);
+
+    let filter = use_memo(move || PipelineFilter {
+        loan_officer_id: officer().parse().ok(),
+        loan_type: loan_type().parse::<LoanType>().ok(),
+        from_date: chrono::NaiveDate::parse_from_str(&from_date(), "%Y-%m-%d").ok(),
+        to_date: chrono::NaiveDate::parse_from_str(&to_date(), "%Y-%m-%d").ok(),
+    });
+
+    let mut cards = use_resource(move || async move { get_pipeline(filter()).await });
+    let users = use_resource(|| async { get_all_users().await });
+
+    let rows = match &*cards.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get pipeline error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    let mut officer_options = vec![(String::new(), "All loan officers".to_string())];
+    if let Some(Ok(users)) = &*users.read() {
+        officer_options.extend(
+            users
+                .iter()
+                .filter(|u| u.role == UserRole::LoanOfficer)
+                .map(|u| (u.id.to_string(), u.full_name())),
+        );
+    }
+    let mut type_options = vec![(String::new(), "All loan types".to_string())];
+    type_options.extend(LoanType::iter().map(|t| (t.code().to_string(), t.to_string())));
+
+    let mut on_drop = move |to: LoanStatus| {
+        let Some(card) = dragging.take() else { return };
+        if card.status == to {
+            return;
+        }
+        if !card.status.can_transition_to(to) {
+            toast_manager.write().popup(ToastInfo::error(
+                &format!("A loan cannot move from {} to {}", card.status, to),
+                Some("Move not allowed"),
+            ));
+            return;
+        }
+        if to == LoanStatus::Denied {
+            toast_manager
+                .write()
+                .popup(ToastInfo::info("Choose the reasons for the denial on the loan page", Some("Denial needs reasons")));
+            on_view.call(card.loan_id);
+            return;
+        }
+        if CURRENT_USER().is_none() {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Sign in to move loans", Some("Not signed in")));
+            return;
+        }
+        spawn(async move {
+            match transition_loan_status(card.loan_id, to).await {
+                Ok(_) => cards.restart(),
+                Err(err) => {
+                    tracing::error!("transition loan status error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not move loan")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-4",
+            div { class: "flex flex-row flex-wrap items-end gap-2",
+                SelectInput {
+                    i_value: officer(),
+                    options: officer_options,
+                    on_input: move |event: FormEvent| officer.set(event.value()),
+                }
+                SelectInput {
+                    i_value: loan_type(),
+                    options: type_options,
+                    on_input: move |event: FormEvent| loan_type.set(event.value()),
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "From" }
+                    DateInput {
+                        i_value: from_date(),
+                        on_input: move |event: FormEvent| from_date.set(event.value()),
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "To" }
+                    DateInput {
+                        i_value: to_date(),
+                        on_input: move |event: FormEvent| to_date.set(event.value()),
+                    }
+                }
+            }
+            ExportPanel {
+                list: ExportList::Loans,
+                columns: ["loan_number", "borrower", "status", "loan_type", "amount", "note_rate", "loan_officer", "start_date"]
+                    .map(String::from)
+                    .to_vec(),
+                sort: vec![ExportSort::descending("updated_at")],
+                loan_filter: filter(),
+            }
+            div { class: "flex flex-row gap-3 overflow-x-auto pb-4",
+                for status in LoanStatus::iter() {
+                    PipelineColumn {
+                        key: "{status.code()}",
+                        status,
+                        cards: rows.iter().filter(|card| card.status == status).cloned().collect::<Vec<_>>(),
+                        accepts_drop: dragging().is_some_and(|card| card.status.can_transition_to(status)),
+                        on_drag_start: move |card: PipelineCard| dragging.set(Some(card)),
+                        on_drop: move |_| on_drop(status),
+                        on_view,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn PipelineColumn(
+    status: LoanStatus,
+    cards: Vec<PipelineCard>,
+    accepts_drop: bool,
+    on_drag_start: EventHandler<PipelineCard>,
+    on_drop: EventHandler<()>,
+    on_view: EventHandler<i32>,
+) -> Element {
+    let volume: i64 = cards.iter().map(|card| card.amount_cents).sum();
+    let column_class = if accepts_drop {
+        "flex flex-col gap-2 min-w-56 w-56 rounded-lg bg-blue-50 ring-2 ring-blue-400 p-2"
+    } else {
+        "flex flex-col gap-2 min-w-56 w-56 rounded-lg bg-gray-100 p-2"
+    };
+
+    rsx! {
+        div {
+            class: column_class,
+            ondragover: move |event: DragEvent| event.prevent_default(),
+            ondrop: move |event: DragEvent| {
+                event.prevent_default();
+                on_drop.call(());
+            },
+            div { class: "flex flex-col border-b border-gray-300 pb-1",
+                div { class: "flex justify-between",
+                    span { class: "font-semibold", "{status}" }
+                    span { class: "rounded-full bg-gray-300 px-2 text-sm", "{cards.len()}" }
+                }
+                span { class: "text-xs text-gray-600", {format_cents(volume)} }
+            }
+            for card in cards.iter().cloned() {
+                div {
+                    key: "{card.loan_id}",
+                    class: "rounded-md bg-white shadow-sm p-2 cursor-grab text-sm",
+                    draggable: "true",
+                    ondragstart: {
+                        let card = card.clone();
+                        move |_| on_drag_start.call(card.clone())
+                    },
+                    ondoubleclick: move |_| on_view.call(card.loan_id),
+                    p { class: "font-semibold", "{card.borrower_name}" }
+                    p { class: "text-gray-600",
+                        {card.loan_number.clone().unwrap_or_else(|| format!("#{}", card.loan_id))}
+                        " · {card.loan_type}"
+                    }
+                    p {
+                        {format_cents(card.amount_cents)}
+                        if let Some(rate) = card.note_rate {
+                            " at {rate:.3}%"
+                        }
+                    }
+                    p { class: "text-xs text-gray-500",
+                        {card.start_date.format("%m/%d/%Y").to_string()}
+                        if let Some(name) = card.loan_officer_name.clone() {
+                            " · {name}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
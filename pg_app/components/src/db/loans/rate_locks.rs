@@ -0,0 +1,320 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{cancel_rate_lock, extend_rate_lock, get_rate_lock_extensions, get_rate_locks, lock_rate};
+use shared::models::{RateLock, RateLockExtensionInput, RateLockInput, RateLockStatus};
+use crate::ui::button::{Button, ButtonScheme, ButtonSize};
+use crate::ui::input::{DateInput, Input, InputType};
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+/// Rate locks on a loan with the lock form, extensions and cancellation
+#[component]
+pub fn RateLocks(loan_id: i32) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut locks = use_resource(move || async move { get_rate_locks(loan_id).await });
+    let mut expanded = use_signal(|| None::<i32>);
+    let today = chrono::Local::now().date_naive();
+
+    let rows = match &*locks.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get rate locks error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    let days_left_class = move |lock: &RateLock| -> Option<String> {
+        match lock.days_until_expiration(today) {
+            _ if lock.status != RateLockStatus::Active => Some("opacity-60".to_string()),
+            d if d <= 3 => Some("text-red-600 font-semibold".to_string()),
+            d if d <= 7 => Some("text-amber-600".to_string()),
+            _ => None,
+        }
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Rate Locks" }
+            Table {
+                striped: true,
+                hoverable: true,
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Rate" }
+                        TableHeaderCell { "Points" }
+                        TableHeaderCell { "Locked" }
+                        TableHeaderCell { "Period" }
+                        TableHeaderCell { "Expires" }
+                        TableHeaderCell { "Days Left" }
+                        TableHeaderCell { "Status" }
+                        TableHeaderCell { "" }
+                    }
+                }
+                TableBody {
+                    for lock in rows.iter().cloned() {
+                        TableRow { key: "{lock.id}", class: days_left_class(&lock),
+                            TableCell { {format!("{:.3}%", lock.rate)} }
+                            TableCell { {format!("{:.3}", lock.points)} }
+                            TableCell { {lock.lock_date.format("%m/%d/%Y").to_string()} }
+                            TableCell { "{lock.lock_period_days} days" }
+                            TableCell { {lock.expiration_date.format("%m/%d/%Y").to_string()} }
+                            TableCell {
+                                if lock.status == RateLockStatus::Active {
+                                    "{lock.days_until_expiration(today)}"
+                                }
+                            }
+                            TableCell { "{lock.status}" }
+                            TableCell {
+                                div { class: "flex gap-1",
+                                    Button {
+                                        button_scheme: ButtonScheme::Outline,
+                                        button_size: ButtonSize::ExtraSmall,
+                                        on_click: move |_| {
+                                            if expanded() == Some(lock.id) {
+                                                expanded.set(None);
+                                            } else {
+                                                expanded.set(Some(lock.id));
+                                            }
+                                        },
+                                        text: "History".to_string(),
+                                    }
+                                    if lock.status == RateLockStatus::Active {
+                                        Button {
+                                            button_scheme: ButtonScheme::Danger,
+                                            button_size: ButtonSize::ExtraSmall,
+                                            on_click: move |_| {
+                                                spawn(async move {
+                                                    match cancel_rate_lock(lock.id).await {
+                                                        Ok(_) => locks.restart(),
+                                                        Err(err) => {
+                                                            tracing::error!("cancel rate lock error: {err}");
+                                                            toast_manager
+                                                                .write()
+                                                                .popup(ToastInfo::error(&err.to_string(), Some("Could not cancel lock")));
+                                                        }
+                                                    }
+                                                });
+                                            },
+                                            text: "Cancel".to_string(),
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(rate_lock_id) = expanded() {
+                LockHistory {
+                    key: "{rate_lock_id}",
+                    rate_lock_id,
+                    can_extend: rows.iter().any(|l| l.id == rate_lock_id && l.status != RateLockStatus::Cancelled),
+                    on_extended: move |_| locks.restart(),
+                }
+            }
+            LockForm { loan_id, on_locked: move |_| locks.restart() }
+        }
+    }
+}
+
+#[component]
+fn LockForm(loan_id: i32, on_locked: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut rate = use_signal(String::new);
+    let mut points = use_signal(|| "0".to_string());
+    let mut lock_date = use_signal(|| chrono::Local::now().date_naive().format("%Y-%m-%d").to_string());
+    let mut period = use_signal(|| "30".to_string());
+
+    let on_submit = move |_| {
+        let (Ok(rate_value), Ok(points_value), Ok(date), Ok(lock_period_days)) = (
+            rate.read().trim().parse::<f64>(),
+            points.read().trim().parse::<f64>(),
+            chrono::NaiveDate::parse_from_str(&lock_date.read(), "%Y-%m-%d"),
+            period.read().trim().parse::<i32>(),
+        ) else {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Enter a rate, points, lock date and period", Some("Invalid input")));
+            return;
+        };
+
+        let input = RateLockInput {
+            rate: rate_value,
+            points: points_value,
+            lock_date: date,
+            lock_period_days,
+        };
+
+        spawn(async move {
+            match lock_rate(loan_id, input).await {
+                Ok(lock) => {
+                    rate.set(String::new());
+                    toast_manager.write().popup(ToastInfo::success(
+                        &format!("Locked through {}", lock.expiration_date.format("%m/%d/%Y")),
+                        Some("Rate locked"),
+                    ));
+                    on_locked.call(lock.id);
+                }
+                Err(err) => {
+                    tracing::error!("lock rate error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not lock rate")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-row flex-wrap items-end gap-2",
+            Input {
+                name: "lock_rate".to_string(),
+                input_type: Some(InputType::Text),
+                label: Some("Rate %".to_string()),
+                value: Some(rate()),
+                oninput: move |event: FormEvent| rate.set(event.value()),
+            }
+            Input {
+                name: "lock_points".to_string(),
+                input_type: Some(InputType::Text),
+                label: Some("Points".to_string()),
+                value: Some(points()),
+                oninput: move |event: FormEvent| points.set(event.value()),
+            }
+            div { class: "flex flex-col",
+                label { class: "text-sm font-medium text-blue-900", "Lock Date" }
+                DateInput {
+                    i_value: lock_date(),
+                    on_input: move |event: FormEvent| lock_date.set(event.value()),
+                }
+            }
+            Input {
+                name: "lock_period".to_string(),
+                input_type: Some(InputType::Number),
+                label: Some("Period (days)".to_string()),
+                value: Some(period()),
+                oninput: move |event: FormEvent| period.set(event.value()),
+            }
+            Button {
+                button_scheme: ButtonScheme::Success,
+                on_click: on_submit,
+                text: "Lock Rate".to_string(),
+            }
+        }
+    }
+}
+
+#[component]
+fn LockHistory(rate_lock_id: i32, can_extend: bool, on_extended: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut extensions = use_resource(move || async move { get_rate_lock_extensions(rate_lock_id).await });
+    let mut days = use_signal(|| "7".to_string());
+    let mut cost = use_signal(|| "0".to_string());
+    let mut reason = use_signal(String::new);
+
+    let rows = match &*extensions.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get rate lock extensions error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    let on_extend = move |_| {
+        let (Ok(extension_days), Ok(cost_points)) =
+            (days.read().trim().parse::<i32>(), cost.read().trim().parse::<f64>())
+        else {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Days and cost must be numbers", Some("Invalid input")));
+            return;
+        };
+        let reason_text = reason.read().trim().to_string();
+        let input = RateLockExtensionInput {
+            extension_days,
+            cost_points,
+            reason: (!reason_text.is_empty()).then_some(reason_text),
+        };
+
+        spawn(async move {
+            match extend_rate_lock(rate_lock_id, input).await {
+                Ok(lock) => {
+                    reason.set(String::new());
+                    extensions.restart();
+                    toast_manager.write().popup(ToastInfo::success(
+                        &format!("Now expires {}", lock.expiration_date.format("%m/%d/%Y")),
+                        Some("Lock extended"),
+                    ));
+                    on_extended.call(lock.id);
+                }
+                Err(err) => {
+                    tracing::error!("extend rate lock error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not extend lock")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2 rounded-lg bg-white shadow-sm p-4",
+            h4 { class: "font-semibold", "Extension History" }
+            if rows.is_empty() {
+                p { class: "text-gray-500", "No extensions" }
+            } else {
+                Table {
+                    TableHead {
+                        TableRow {
+                            TableHeaderCell { "Date" }
+                            TableHeaderCell { "Days" }
+                            TableHeaderCell { "From" }
+                            TableHeaderCell { "To" }
+                            TableHeaderCell { "Cost (pts)" }
+                            TableHeaderCell { "Reason" }
+                        }
+                    }
+                    TableBody {
+                        for extension in rows.iter().cloned() {
+                            TableRow { key: "{extension.id}",
+                                TableCell { {extension.created_at.format("%m/%d/%Y").to_string()} }
+                                TableCell { "{extension.extension_days}" }
+                                TableCell { {extension.previous_expiration.format("%m/%d/%Y").to_string()} }
+                                TableCell { {extension.new_expiration.format("%m/%d/%Y").to_string()} }
+                                TableCell { {format!("{:.3}", extension.cost_points)} }
+                                TableCell { {extension.reason.clone().unwrap_or_default()} }
+                            }
+                        }
+                    }
+                }
+            }
+            if can_extend {
+                div { class: "flex flex-row flex-wrap items-end gap-2",
+                    Input {
+                        name: "extension_days".to_string(),
+                        input_type: Some(InputType::Number),
+                        label: Some("Extend (days)".to_string()),
+                        value: Some(days()),
+                        oninput: move |event: FormEvent| days.set(event.value()),
+                    }
+                    Input {
+                        name: "extension_cost".to_string(),
+                        input_type: Some(InputType::Text),
+                        label: Some("Cost (pts)".to_string()),
+                        value: Some(cost()),
+                        oninput: move |event: FormEvent| cost.set(event.value()),
+                    }
+                    Input {
+                        name: "extension_reason".to_string(),
+                        input_type: Some(InputType::Text),
+                        label: Some("Reason".to_string()),
+                        value: Some(reason()),
+                        oninput: move |event: FormEvent| reason.set(event.value()),
+                    }
+                    Button {
+                        button_scheme: ButtonScheme::Default,
+                        on_click: on_extend,
+                        text: "Extend Lock".to_string(),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use chrono::{Local, TimeDelta, Utc};
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{get_loan_timeline, get_sla_breaches};
+use shared::calculations::status_timeline::StatusStage;
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+/// Calendar time as days and hours, e.g. `3d 4h`
+fn format_elapsed(elapsed: TimeDelta) -> String {
+    let hours = elapsed.num_hours().max(0);
+    match (hours / 24, hours % 24) {
+        (0, 0) => format!("{}m", elapsed.num_minutes().max(0)),
+        (0, h) => format!("{}h", h),
+        (d, h) => format!("{}d {}h", d, h),
+    }
+}
+
+/// Badge for a stage against its SLA target
+fn sla_badge(stage: &StatusStage) -> (&'static str, String) {
+    match stage.target_days {
+        _ if stage.is_breached() => ("bg-red-100 text-red-800", format!("{} over", plural_days(stage.days_over()))),
+        Some(_) if stage.is_current() => ("bg-amber-100 text-amber-800", "On track".to_string()),
+        Some(_) => ("bg-green-100 text-green-800", "Met".to_string()),
+        None => ("bg-gray-100 text-gray-700", "No target".to_string()),
+    }
+}
+
+/// Target with the day it falls due, e.g. `5 days by 06/10/2025`
+fn target_label(stage: &StatusStage) -> String {
+    match (stage.target_days, stage.due_date) {
+        (Some(days), Some(due)) => format!("{} by {}", plural_days(days.into()), due.format("%m/%d/%Y")),
+        _ => String::new(),
+    }
+}
+
+fn plural_days(days: i64) -> String {
+    if days == 1 { "1 day".to_string() } else { format!("{} days", days) }
+}
+
+/// Each status a loan has been in, how long it stayed and whether it kept to
+/// the SLA target
+///
+/// Key it on the loan's status so it reloads after a move.
+#[component]
+pub fn StatusTimeline(loan_id: i32) -> Element {
+    let stages = use_resource(move || async move { get_loan_timeline(loan_id).await });
+    let now = Utc::now();
+    let display_time = |at: chrono::DateTime<Utc>| at.with_timezone(&Local).format("%m/%d/%Y %I:%M %p").to_string();
+
+    let stages = match &*stages.read() {
+        Some(Ok(stages)) => stages.clone(),
+        Some(Err(err)) => {
+            return rsx! {
+                div { class: "text-red-600", "Could not load the status timeline: {err}" }
+            };
+        }
+        None => {
+            return rsx! {
+                div { "Loading status timeline..." }
+            };
+        }
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Status Timeline" }
+            Table {
+                striped: true,
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Status" }
+                        TableHeaderCell { "Entered" }
+                        TableHeaderCell { "By" }
+                        TableHeaderCell { "Time in Status" }
+                        TableHeaderCell { "Business Days" }
+                        TableHeaderCell { "Target" }
+                        TableHeaderCell { "SLA" }
+                    }
+                }
+                TableBody {
+                    for (index, stage) in stages.iter().enumerate() {
+                        TableRow { key: "{index}",
+                            TableCell {
+                                span { class: if stage.is_current() { "font-semibold" } else { "" }, "{stage.status}" }
+                            }
+                            TableCell { {display_time(stage.entered_at)} }
+                            TableCell { {stage.entered_by.clone().unwrap_or_default()} }
+                            TableCell {
+                                if stage.business_days.is_some() {
+                                    {format_elapsed(stage.elapsed(now))}
+                                    if stage.is_current() {
+                                        " so far"
+                                    }
+                                }
+                            }
+                            TableCell { {stage.business_days.map(|days| days.to_string()).unwrap_or_default()} }
+                            TableCell { {target_label(stage)} }
+                            TableCell {
+                                if stage.business_days.is_some() {
+                                    {
+                                        let (class, label) = sla_badge(stage);
+                                        rsx! {
+                                            span { class: "rounded px-2 py-0.5 text-xs font-semibold {class}", "{label}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Dashboard widget listing loans that have been in their status longer than its SLA target
+#[component]
+pub fn SlaBreaches(on_view: EventHandler<i32>) -> Element {
+    let rows = use_resource(|| async { get_sla_breaches().await });
+
+    let rows = match &*rows.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get SLA breaches error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        Table {
+            striped: true,
+            hoverable: true,
+            caption: rsx! { "Loans over their SLA target" },
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Loan #" }
+                    TableHeaderCell { "Borrower" }
+                    TableHeaderCell { "Loan Officer" }
+                    TableHeaderCell { "Status" }
+                    TableHeaderCell { "Due" }
+                    TableHeaderCell { "Over By" }
+                    TableHeaderCell { "View" }
+                }
+            }
+            TableBody {
+                if rows.is_empty() {
+                    TableRow {
+                        TableCell { colspan: Some(7), class: Some("text-gray-500".to_string()), "No loans over their SLA target" }
+                    }
+                }
+                for row in rows.iter().cloned() {
+                    TableRow {
+                        key: "{row.loan_id}",
+                        TableCell { {row.loan_number.clone().unwrap_or_else(|| format!("#{}", row.loan_id))} }
+                        TableCell { "{row.borrower_name}" }
+                        TableCell { {row.loan_officer_name.clone().unwrap_or_default()} }
+                        TableCell { "{row.stage.status}" }
+                        TableCell { {row.stage.due_date.map(|d| d.format("%m/%d/%Y").to_string()).unwrap_or_default()} }
+                        TableCell {
+                            span { class: "rounded px-2 py-0.5 text-xs font-semibold bg-red-100 text-red-800",
+                                {plural_days(row.stage.days_over())}
+                            }
+                        }
+                        TableCell {
+                            button {
+                                class: "text-blue-600 hover:underline cursor-pointer",
+                                onclick: move |_| on_view.call(row.loan_id),
+                                "View"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{get_property, save_property};
+use shared::calculations::ltv::loan_to_value;
+use shared::models::{Occupancy, Property, PropertyInput, PropertyType};
+use shared::money::{format_cents, parse_dollars};
+use strum::IntoEnumIterator;
+use crate::ui::input::{Input, InputType, SelectInput};
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// Subject property editor for a loan, with the resulting LTV
+#[component]
+pub fn SubjectProperty(loan_id: i32, loan_amount_cents: i64) -> Element {
+    let property = use_resource(move || async move { get_property(loan_id).await });
+
+    match &*property.read() {
+        Some(Ok(existing)) => rsx! {
+            PropertyForm { loan_id, loan_amount_cents, existing: existing.clone() }
+        },
+        Some(Err(err)) => rsx! {
+            div { class: "text-red-600", "Could not load property: {err}" }
+        },
+        None => rsx! {
+            div { "Loading property..." }
+        },
+    }
+}
+
+#[component]
+fn PropertyForm(loan_id: i32, loan_amount_cents: i64, existing: Option<Property>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let initial = existing.as_ref().map(PropertyInput::from).unwrap_or_default();
+    let money = |cents: Option<i64>| cents.map(|c| format!("{:.2}", c as f64 / 100.0)).unwrap_or_default();
+
+    let mut saved = use_signal(|| existing.clone());
+    let mut street = use_signal(|| initial.street.clone());
+    let mut city = use_signal(|| initial.city.clone());
+    let mut state = use_signal(|| initial.state.clone());
+    let mut zip = use_signal(|| initial.zip.clone());
+    let mut occupancy = use_signal(|| initial.occupancy);
+    let mut property_type = use_signal(|| initial.property_type);
+    let mut units = use_signal(|| initial.units.to_string());
+    let mut purchase_price = use_signal(|| money(initial.purchase_price_cents));
+    let mut appraised_value = use_signal(|| money(initial.appraised_value_cents));
+    let mut estimated_value = use_signal(|| money(initial.estimated_value_cents));
+
+    let occupancy_options: Vec<(String, String)> = Occupancy::iter()
+        .map(|o| (o.code().to_string(), o.to_string()))
+        .collect();
+    let type_options: Vec<(String, String)> = PropertyType::iter()
+        .map(|t| (t.code().to_string(), t.to_string()))
+        .collect();
+
+    let ltv_value = saved().as_ref().and_then(Property::ltv_value_cents);
+    let ltv = ltv_value
+        .and_then(|value| loan_to_value(loan_amount_cents, value))
+        .map(|ratio| format!("{:.2}%", ratio))
+        .unwrap_or_else(|| "N/A".to_string());
+
+    let on_save = move |_| {
+        let optional_money = |s: &str| -> Result<Option<i64>, ()> {
+            if s.trim().is_empty() {
+                Ok(None)
+            } else {
+                parse_dollars(s).map(Some).ok_or(())
+            }
+        };
+        let (Ok(purchase_price_cents), Ok(appraised_value_cents), Ok(estimated_value_cents)) = (
+            optional_money(&purchase_price.read()),
+            optional_money(&appraised_value.read()),
+            optional_money(&estimated_value.read()),
+        ) else {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Values must be dollar amounts", Some("Invalid input")));
+            return;
+        };
+
+        let input = PropertyInput {
+            street: street.read().trim().to_string(),
+            city: city.read().trim().to_string(),
+            state: state.read().trim().to_uppercase(),
+            zip: zip.read().trim().to_string(),
+            occupancy: occupancy(),
+            property_type: property_type(),
+            units: units.read().trim().parse().unwrap_or(0),
+            purchase_price_cents,
+            appraised_value_cents,
+            estimated_value_cents,
+        };
+
+        spawn(async move {
+            match save_property(loan_id, input).await {
+                Ok(property) => {
+                    saved.set(Some(property));
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success("Subject property saved", None));
+                }
+                Err(err) => {
+                    tracing::error!("save property error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not save property")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Subject Property" }
+            div { class: "grid grid-cols-4 gap-2",
+                Input {
+                    name: "street".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Street".to_string()),
+                    value: Some(street()),
+                    oninput: move |event: FormEvent| street.set(event.value()),
+                }
+                Input {
+                    name: "city".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("City".to_string()),
+                    value: Some(city()),
+                    oninput: move |event: FormEvent| city.set(event.value()),
+                }
+                Input {
+                    name: "state".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("State".to_string()),
+                    placeholder: Some("IL".to_string()),
+                    value: Some(state()),
+                    oninput: move |event: FormEvent| state.set(event.value()),
+                }
+                Input {
+                    name: "zip".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("ZIP".to_string()),
+                    value: Some(zip()),
+                    oninput: move |event: FormEvent| zip.set(event.value()),
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Occupancy" }
+                    SelectInput {
+                        i_value: occupancy().code().to_string(),
+                        options: occupancy_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(o) = event.value().parse() {
+                                occupancy.set(o);
+                            }
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Property Type" }
+                    SelectInput {
+                        i_value: property_type().code().to_string(),
+                        options: type_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(t) = event.value().parse() {
+                                property_type.set(t);
+                            }
+                        },
+                    }
+                }
+                Input {
+                    name: "units".to_string(),
+                    input_type: Some(InputType::Number),
+                    label: Some("Units".to_string()),
+                    value: Some(units()),
+                    oninput: move |event: FormEvent| units.set(event.value()),
+                }
+                div {}
+                Input {
+                    name: "purchase_price".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Purchase Price".to_string()),
+                    value: Some(purchase_price()),
+                    oninput: move |event: FormEvent| purchase_price.set(event.value()),
+                }
+                Input {
+                    name: "appraised_value".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Appraised Value".to_string()),
+                    value: Some(appraised_value()),
+                    oninput: move |event: FormEvent| appraised_value.set(event.value()),
+                }
+                Input {
+                    name: "estimated_value".to_string(),
+                    input_type: Some(InputType::Text),
+                    label: Some("Estimated Value".to_string()),
+                    value: Some(estimated_value()),
+                    oninput: move |event: FormEvent| estimated_value.set(event.value()),
+                }
+            }
+            p { class: "text-gray-700",
+                "LTV: "
+                span { class: "font-semibold", "{ltv}" }
+                if let Some(value) = ltv_value {
+                    span { class: "text-gray-500", " (value basis {format_cents(value)})" }
+                }
+            }
+            div {
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    on_click: on_save,
+                    text: "Save Property".to_string(),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::get_trid_at_risk;
+use super::trid_timeline::status_class;
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+/// Dashboard widget listing loans that have missed or are about to miss a TRID deadline
+#[component]
+pub fn TridAtRisk(on_view: EventHandler<i32>) -> Element {
+    let rows = use_resource(|| async { get_trid_at_risk().await });
+
+    let rows = match &*rows.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get TRID at risk error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        Table {
+            striped: true,
+            hoverable: true,
+            caption: rsx! { "Loans at risk of missing a TRID deadline" },
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Loan #" }
+                    TableHeaderCell { "Borrower" }
+                    TableHeaderCell { "Milestone" }
+                    TableHeaderCell { "Due" }
+                    TableHeaderCell { "Status" }
+                    TableHeaderCell { "View" }
+                }
+            }
+            TableBody {
+                if rows.is_empty() {
+                    TableRow {
+                        TableCell { colspan: Some(6), class: Some("text-gray-500".to_string()), "No TRID deadlines at risk" }
+                    }
+                }
+                for row in rows.iter().cloned() {
+                    TableRow {
+                        key: "{row.loan_id}-{row.milestone.name}",
+                        TableCell { {row.loan_number.clone().unwrap_or_else(|| format!("#{}", row.loan_id))} }
+                        TableCell { "{row.borrower_name}" }
+                        TableCell { "{row.milestone.name}" }
+                        TableCell { {row.milestone.due.map(|d| d.format("%m/%d/%Y").to_string()).unwrap_or_default()} }
+                        TableCell {
+                            span { class: "rounded px-2 py-0.5 text-xs font-semibold {status_class(row.milestone.status)}",
+                                "{row.milestone.status}"
+                            }
+                        }
+                        TableCell {
+                            button {
+                                class: "text-blue-600 hover:underline cursor-pointer",
+                                onclick: move |_| on_view.call(row.loan_id),
+                                "View"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
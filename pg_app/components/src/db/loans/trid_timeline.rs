@@ -0,0 +1,174 @@
+use chrono::NaiveDate;
+use dioxus::{logger::tracing, prelude::*};
+use server::loans::{get_trid_dates, save_trid_dates};
+use shared::calculations::trid::{earliest_consummation, trid_timeline, MilestoneStatus};
+use shared::models::{DisclosureDelivery, Loan, TridDates};
+use strum::IntoEnumIterator;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::{DateInput, SelectInput};
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+/// Badge classes for a milestone status: green when met, red when missed
+pub(crate) fn status_class(status: MilestoneStatus) -> &'static str {
+    match status {
+        MilestoneStatus::Met => "bg-green-100 text-green-800",
+        MilestoneStatus::Missed => "bg-red-100 text-red-800",
+        MilestoneStatus::AtRisk => "bg-amber-100 text-amber-800",
+        MilestoneStatus::Pending | MilestoneStatus::NotScheduled => "bg-gray-100 text-gray-700",
+    }
+}
+
+/// TRID compliance timeline for a loan with the disclosure date form
+#[component]
+pub fn TridTimeline(loan: Loan) -> Element {
+    let loan_id = loan.id;
+    let dates = use_resource(move || async move { get_trid_dates(loan_id).await });
+
+    match &*dates.read() {
+        Some(Ok(existing)) => rsx! {
+            TridForm { loan: loan.clone(), existing: existing.clone().unwrap_or_default() }
+        },
+        Some(Err(err)) => rsx! {
+            div { class: "text-red-600", "Could not load TRID dates: {err}" }
+        },
+        None => rsx! {
+            div { "Loading TRID dates..." }
+        },
+    }
+}
+
+#[component]
+fn TridForm(loan: Loan, existing: TridDates) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let today = chrono::Local::now().date_naive();
+    let show = |date: Option<NaiveDate>| date.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+
+    let mut le_delivered = use_signal(|| show(existing.le_delivered_date));
+    let mut cd_delivered = use_signal(|| show(existing.cd_delivered_date));
+    let mut cd_method = use_signal(|| existing.cd_delivery_method);
+    let mut cd_received = use_signal(|| show(existing.cd_received_date));
+    let mut consummation = use_signal(|| show(existing.consummation_date));
+
+    let method_options: Vec<(String, String)> = DisclosureDelivery::iter()
+        .map(|m| (m.code().to_string(), m.to_string()))
+        .collect();
+
+    let parse = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+    let dates = TridDates {
+        loan_id: loan.id,
+        le_delivered_date: parse(&le_delivered.read()),
+        cd_delivered_date: parse(&cd_delivered.read()),
+        cd_delivery_method: cd_method(),
+        cd_received_date: parse(&cd_received.read()),
+        consummation_date: parse(&consummation.read()),
+    };
+    let timeline = trid_timeline(loan.application_date, &dates, today);
+    let earliest_close = earliest_consummation(&dates);
+    let display = |date: Option<NaiveDate>| date.map(|d| d.format("%m/%d/%Y").to_string()).unwrap_or_else(|| "—".to_string());
+
+    let save_dates = dates.clone();
+    let on_save = move |_| {
+        let dates = save_dates.clone();
+        spawn(async move {
+            match save_trid_dates(dates.loan_id, dates).await {
+                Ok(_) => {
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success("Disclosure dates saved", None));
+                }
+                Err(err) => {
+                    tracing::error!("save TRID dates error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not save disclosure dates")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "TRID Timeline" }
+            p { class: "text-gray-700",
+                "Application: "
+                span { class: "font-semibold", {display(loan.application_date)} }
+                " · Earliest closing allowed by the CD: "
+                span { class: "font-semibold", {display(earliest_close)} }
+            }
+            div { class: "grid grid-cols-5 gap-2",
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "LE Delivered" }
+                    DateInput {
+                        i_value: le_delivered(),
+                        on_input: move |event: FormEvent| le_delivered.set(event.value()),
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "CD Delivered" }
+                    DateInput {
+                        i_value: cd_delivered(),
+                        on_input: move |event: FormEvent| cd_delivered.set(event.value()),
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "CD Delivery Method" }
+                    SelectInput {
+                        i_value: cd_method().code().to_string(),
+                        options: method_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(m) = event.value().parse() {
+                                cd_method.set(m);
+                            }
+                        },
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "CD Receipt Confirmed" }
+                    DateInput {
+                        i_value: cd_received(),
+                        on_input: move |event: FormEvent| cd_received.set(event.value()),
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Consummation" }
+                    DateInput {
+                        i_value: consummation(),
+                        on_input: move |event: FormEvent| consummation.set(event.value()),
+                    }
+                }
+            }
+            Table {
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Milestone" }
+                        TableHeaderCell { "Due" }
+                        TableHeaderCell { "Actual" }
+                        TableHeaderCell { "Status" }
+                    }
+                }
+                TableBody {
+                    for milestone in timeline.iter() {
+                        TableRow { key: "{milestone.name}",
+                            TableCell { "{milestone.name}" }
+                            TableCell { {display(milestone.due)} }
+                            TableCell { {display(milestone.actual)} }
+                            TableCell {
+                                span { class: "rounded px-2 py-0.5 text-xs font-semibold {status_class(milestone.status)}",
+                                    "{milestone.status}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    on_click: on_save,
+                    text: "Save Disclosure Dates".to_string(),
+                }
+            }
+        }
+    }
+}
@@ -4,4 +4,12 @@ pub use test_post::PostsContainer;
 
 pub mod add_post;  // Contains AddPost
 pub mod post;      // Contains Post
-pub mod test_post;
\ No newline at end of file
+pub mod test_post;
+pub mod borrowers;
+pub mod exports;
+pub mod loans;
+pub mod notes;
+pub mod notifications;
+pub mod session;
+pub mod settings;
+pub mod tasks;
@@ -0,0 +1,3 @@
+pub use note_list::Notes;
+
+pub mod note_list;  // Contains Notes, the threaded note list and editor
@@ -0,0 +1,304 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::notes::{create_note, delete_note, get_note_edits, get_notes, update_note};
+use shared::markdown::render_markdown;
+use shared::models::{build_threads, NoteInput, NoteSubject, NoteThread};
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme, ButtonSize};
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// Threaded notes on a loan or borrower with markdown, pinning and @mentions
+///
+/// The server leaves out internal-only notes the signed-in user may not see.
+#[component]
+pub fn Notes(subject: NoteSubject) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut notes = use_resource(move || async move { get_notes(subject).await });
+
+    let threads = match &*notes.read() {
+        Some(Ok(rows)) => build_threads(rows.clone()),
+        Some(Err(err)) => {
+            tracing::error!("get notes error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    let mut on_create = move |(parent_id, input): (Option<i32>, NoteInput)| {
+        if CURRENT_USER().is_none() {
+            toast_manager.write().popup(ToastInfo::error("Sign in to write notes", Some("Not signed in")));
+            return;
+        }
+        spawn(async move {
+            match create_note(subject, parent_id, input).await {
+                Ok(_) => notes.restart(),
+                Err(err) => {
+                    tracing::error!("create note error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not save note")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-3",
+            h3 { class: "text-lg font-semibold", "Notes" }
+            NoteEditor {
+                initial: NoteInput::default(),
+                allow_pin: true,
+                submit_text: "Add Note".to_string(),
+                on_submit: move |input| on_create((None, input)),
+            }
+            if threads.is_empty() {
+                p { class: "text-gray-500", "No notes yet" }
+            }
+            for thread in threads {
+                NoteItem {
+                    key: "{thread.note.id}",
+                    thread,
+                    on_reply: on_create,
+                    on_changed: move |_| notes.restart(),
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NoteItem(
+    thread: NoteThread,
+    on_reply: EventHandler<(Option<i32>, NoteInput)>,
+    on_changed: EventHandler<i32>,
+) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut replying = use_signal(|| false);
+    let mut editing = use_signal(|| false);
+    let mut show_history = use_signal(|| false);
+    let note = thread.note.clone();
+    let note_id = note.id;
+    let is_reply = note.parent_id.is_some();
+    let can_edit = CURRENT_USER().is_some_and(|user| {
+        note.author_id == Some(user.id) || user.role == shared::models::UserRole::Admin
+    });
+    let html = render_markdown(&note.body);
+
+    let save = move |input: NoteInput| {
+        spawn(async move {
+            match update_note(note_id, input).await {
+                Ok(_) => {
+                    editing.set(false);
+                    on_changed.call(note_id);
+                }
+                Err(err) => {
+                    tracing::error!("update note error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not update note")));
+                }
+            }
+        });
+    };
+
+    let container_class = match (is_reply, note.pinned) {
+        (true, _) => "ml-6 border-l-2 border-gray-200 pl-3 flex flex-col gap-1",
+        (false, true) => "rounded-lg bg-yellow-50 border border-yellow-200 shadow-sm p-3 flex flex-col gap-1",
+        (false, false) => "rounded-lg bg-white shadow-sm p-3 flex flex-col gap-1",
+    };
+
+    rsx! {
+        div { class: container_class,
+            div { class: "flex flex-wrap items-center gap-2 text-xs text-gray-500",
+                span { class: "font-semibold text-gray-800",
+                    {note.author_name.clone().unwrap_or_else(|| "Unknown".to_string())}
+                }
+                span { {note.created_at.format("%m/%d/%Y %H:%M").to_string()} }
+                if note.pinned {
+                    span { class: "rounded bg-yellow-200 px-1 text-yellow-900", "Pinned" }
+                }
+                if note.internal_only {
+                    span { class: "rounded bg-gray-200 px-1", "Internal" }
+                }
+                if note.edited_at.is_some() {
+                    button {
+                        class: "hover:underline cursor-pointer",
+                        onclick: move |_| show_history.set(!show_history()),
+                        "(edited)"
+                    }
+                }
+            }
+            if editing() {
+                NoteEditor {
+                    initial: NoteInput::from(&note),
+                    allow_pin: !is_reply,
+                    submit_text: "Save".to_string(),
+                    on_submit: save,
+                    on_cancel: move |_| editing.set(false),
+                }
+            } else {
+                div { class: "prose prose-sm max-w-none", dangerous_inner_html: "{html}" }
+            }
+            if show_history() {
+                NoteHistory { note_id }
+            }
+            div { class: "flex gap-3 text-xs",
+                button {
+                    class: "text-blue-600 hover:underline cursor-pointer",
+                    onclick: move |_| replying.set(!replying()),
+                    "Reply"
+                }
+                if can_edit {
+                    button {
+                        class: "text-blue-600 hover:underline cursor-pointer",
+                        onclick: move |_| editing.set(true),
+                        "Edit"
+                    }
+                    if !is_reply {
+                        button {
+                            class: "text-blue-600 hover:underline cursor-pointer",
+                            onclick: {
+                                let note = note.clone();
+                                move |_| {
+                                    let mut input = NoteInput::from(&note);
+                                    input.pinned = !note.pinned;
+                                    save(input);
+                                }
+                            },
+                            if note.pinned { "Unpin" } else { "Pin" }
+                        }
+                    }
+                    button {
+                        class: "text-red-600 hover:underline cursor-pointer",
+                        onclick: move |_| {
+                            spawn(async move {
+                                match delete_note(note_id).await {
+                                    Ok(_) => on_changed.call(note_id),
+                                    Err(err) => tracing::error!("delete note error: {err}"),
+                                }
+                            });
+                        },
+                        "Delete"
+                    }
+                }
+            }
+            if replying() {
+                NoteEditor {
+                    initial: NoteInput {
+                        internal_only: note.internal_only,
+                        ..NoteInput::default()
+                    },
+                    allow_pin: false,
+                    submit_text: "Reply".to_string(),
+                    on_submit: move |input| {
+                        replying.set(false);
+                        on_reply.call((Some(note_id), input));
+                    },
+                    on_cancel: move |_| replying.set(false),
+                }
+            }
+            for reply in thread.replies.iter().cloned() {
+                NoteItem {
+                    key: "{reply.note.id}",
+                    thread: reply,
+                    on_reply,
+                    on_changed,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NoteEditor(
+    initial: NoteInput,
+    allow_pin: bool,
+    submit_text: String,
+    on_submit: EventHandler<NoteInput>,
+    on_cancel: Option<EventHandler<()>>,
+) -> Element {
+    let mut body = use_signal(|| initial.body.clone());
+    let mut pinned = use_signal(|| initial.pinned);
+    let mut internal_only = use_signal(|| initial.internal_only);
+
+    rsx! {
+        div { class: "flex flex-col gap-1",
+            textarea {
+                class: "bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block p-2.5 w-full",
+                rows: "3",
+                placeholder: "Write a note. Markdown is supported; @username mentions a colleague.",
+                value: "{body}",
+                oninput: move |event: FormEvent| body.set(event.value()),
+            }
+            div { class: "flex flex-wrap items-center gap-4 text-sm",
+                label { class: "flex items-center gap-1",
+                    input {
+                        r#type: "checkbox",
+                        checked: internal_only(),
+                        onchange: move |event: FormEvent| internal_only.set(event.checked()),
+                    }
+                    "Internal only"
+                }
+                if allow_pin {
+                    label { class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: pinned(),
+                            onchange: move |event: FormEvent| pinned.set(event.checked()),
+                        }
+                        "Pinned"
+                    }
+                }
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    button_size: ButtonSize::Small,
+                    on_click: move |_| {
+                        if body.read().trim().is_empty() {
+                            return;
+                        }
+                        on_submit.call(NoteInput {
+                            body: body(),
+                            pinned: pinned() && allow_pin,
+                            internal_only: internal_only(),
+                        });
+                        body.set(String::new());
+                    },
+                    text: submit_text,
+                }
+                if let Some(on_cancel) = on_cancel {
+                    Button {
+                        button_scheme: ButtonScheme::Outline,
+                        button_size: ButtonSize::Small,
+                        on_click: move |_| on_cancel.call(()),
+                        text: "Cancel".to_string(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NoteHistory(note_id: i32) -> Element {
+    let edits = use_resource(move || async move { get_note_edits(note_id).await });
+
+    let rows = match &*edits.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get note edits error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "rounded bg-gray-50 p-2 text-xs flex flex-col gap-2",
+            span { class: "font-semibold", "Previous versions" }
+            for edit in rows {
+                div { key: "{edit.id}",
+                    p { class: "text-gray-500",
+                        {edit.edited_at.format("%m/%d/%Y %H:%M").to_string()}
+                        " · "
+                        {edit.editor_name.clone().unwrap_or_else(|| "Unknown".to_string())}
+                    }
+                    pre { class: "whitespace-pre-wrap", "{edit.previous_body}" }
+                }
+            }
+        }
+    }
+}
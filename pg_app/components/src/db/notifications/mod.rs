@@ -0,0 +1,5 @@
+pub use notification_bell::NotificationBell;
+pub use notification_watcher::NotificationWatcher;
+
+pub mod notification_bell;  // Contains NotificationBell for the navbar
+pub mod notification_watcher;  // Contains NotificationWatcher, which toasts the signed-in user's new notifications
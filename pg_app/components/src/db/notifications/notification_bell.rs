@@ -0,0 +1,95 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::notifications::{get_notifications, mark_all_notifications_read, mark_notification_read};
+use crate::db::session::CURRENT_USER;
+
+/// Seconds between notification refreshes
+const REFRESH_SECONDS: u64 = 60;
+
+/// Navbar bell with an unread count and a dropdown of the signed-in user's notifications
+#[component]
+pub fn NotificationBell() -> Element {
+    let mut notifications = use_resource(move || async move {
+        if CURRENT_USER().is_none() {
+            return Ok(Vec::new());
+        }
+        get_notifications().await
+    });
+    let mut show_dropdown = use_signal(|| false);
+
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(REFRESH_SECONDS)).await;
+            notifications.restart();
+        }
+    });
+
+    let rows = match &*notifications.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get notifications error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let unread = rows.iter().filter(|n| n.is_unread()).count();
+
+    rsx! {
+        div { class: "relative",
+            button {
+                class: "relative cursor-pointer hover:text-blue-400 transition",
+                onclick: move |_| show_dropdown.set(!show_dropdown()),
+                "🔔"
+                if unread > 0 {
+                    span { class: "absolute -top-2 -right-3 rounded-full bg-red-600 px-1.5 text-xs text-white",
+                        "{unread}"
+                    }
+                }
+            }
+            if show_dropdown() {
+                div { class: "absolute right-0 z-50 mt-2 w-80 max-h-96 overflow-y-auto rounded-md shadow-lg bg-white text-gray-900",
+                    div { class: "flex justify-between px-4 py-2 border-b border-gray-200",
+                        span { class: "font-semibold", "Notifications" }
+                        if unread > 0 {
+                            button {
+                                class: "text-sm text-blue-600 hover:underline cursor-pointer",
+                                onclick: move |_| {
+                                    spawn(async move {
+                                        match mark_all_notifications_read().await {
+                                            Ok(_) => notifications.restart(),
+                                            Err(err) => tracing::error!("mark notifications read error: {err}"),
+                                        }
+                                    });
+                                },
+                                "Mark all read"
+                            }
+                        }
+                    }
+                    if rows.is_empty() {
+                        p { class: "px-4 py-3 text-sm text-gray-500", "No notifications" }
+                    }
+                    for notification in rows.iter().cloned() {
+                        div {
+                            key: "{notification.id}",
+                            class: if notification.is_unread() { "px-4 py-2 border-b border-gray-100 bg-blue-50 cursor-pointer" } else { "px-4 py-2 border-b border-gray-100" },
+                            onclick: move |_| {
+                                if notification.is_unread() {
+                                    spawn(async move {
+                                        match mark_notification_read(notification.id).await {
+                                            Ok(_) => notifications.restart(),
+                                            Err(err) => tracing::error!("mark notification read error: {err}"),
+                                        }
+                                    });
+                                }
+                            },
+                            p { class: "text-sm font-semibold", "{notification.title}" }
+                            p { class: "text-sm", "{notification.message}" }
+                            p { class: "text-xs text-gray-500",
+                                {notification.created_at.format("%m/%d/%Y %H:%M").to_string()}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::HashSet;
+
+use dioxus::{logger::tracing, prelude::*};
+use server::notifications::get_unread_notifications;
+use crate::db::session::CURRENT_USER;
+use crate::ui::toast::{escape_html, time_sleep, ToastInfo, ToastManager};
+
+/// Seconds between polls for new notifications
+const POLL_SECONDS: u64 = 60;
+
+/// Polls the signed-in user's unread notifications and pops a toast for each
+/// one not toasted yet
+///
+/// The server raises rate lock alerts on its own schedule; this only shows
+/// them. Renders nothing; mount it once next to the `ToastFrame`.
+#[component]
+pub fn NotificationWatcher() -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+
+    use_future(move || async move {
+        let mut toasted = HashSet::new();
+        loop {
+            if CURRENT_USER.peek().is_some() {
+                match get_unread_notifications().await {
+                    Ok(notifications) => {
+                        for notification in notifications {
+                            if toasted.insert(notification.id) {
+                                // Messages quote borrower names and note text; the toast body is HTML
+                                let message = escape_html(&notification.message);
+                                toast_manager.write().popup(ToastInfo::warning(&message, Some(&notification.title)));
+                            }
+                        }
+                    }
+                    Err(err) => tracing::error!("get unread notifications error: {err}"),
+                }
+            } else {
+                toasted.clear();
+            }
+            time_sleep(POLL_SECONDS * 1000).await;
+        }
+    });
+
+    rsx! {}
+}
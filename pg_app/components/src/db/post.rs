@@ -3,7 +3,9 @@ use dioxus::{
 };
 use crate::ui::input::{Input, InputType};
 use crate::ui::button::{Button, ButtonScheme};
+use crate::db::exports::ExportPanel;
 use server::post_functions::{delete_post, update_post, get_all_posts};
+use shared::exports::ExportList;
 
 use shared::models;
 use std::sync::Arc;
@@ -116,6 +118,7 @@ pub fn Posts() -> Element {
             });
             rsx! {
                 div { {posts} }
+                ExportPanel { list: ExportList::Posts }
             }
         }
         _ => rsx! {
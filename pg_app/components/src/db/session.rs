@@ -0,0 +1,80 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::users::{get_session_user, login_user, logout_user};
+use shared::models::User;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::{Input, InputType};
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// The signed-in user, if any
+///
+/// Only used to decide what the UI shows; the server takes the acting user
+/// from the session cookie set at sign-in.
+pub static CURRENT_USER: GlobalSignal<Option<User>> = Global::new(|| None);
+
+/// Restores [`CURRENT_USER`] from the session cookie when the app starts
+pub fn use_restore_session() {
+    use_future(|| async {
+        match get_session_user().await {
+            Ok(user) => *CURRENT_USER.write() = user,
+            Err(err) => tracing::error!("get session user error: {err}"),
+        }
+    });
+}
+
+/// Ends the session on the server and forgets the signed-in user
+pub async fn sign_out() {
+    if let Err(err) = logout_user().await {
+        tracing::error!("logout error: {err}");
+    }
+    *CURRENT_USER.write() = None;
+}
+
+/// Username/email and password form that signs the user in
+#[component]
+pub fn SignInForm(on_signed_in: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut login = use_signal(String::new);
+    let mut password = use_signal(String::new);
+
+    let on_submit = move |_| {
+        let (login_value, password_value) = (login(), password());
+        spawn(async move {
+            match login_user(login_value, password_value).await {
+                Ok(user) => {
+                    let id = user.id;
+                    password.set(String::new());
+                    *CURRENT_USER.write() = Some(user);
+                    on_signed_in.call(id);
+                }
+                Err(err) => {
+                    tracing::error!("login error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Sign in failed")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col px-2 py-2",
+            Input {
+                name: "login".to_string(),
+                input_type: Some(InputType::Text),
+                placeholder: Some("Username or email".to_string()),
+                value: Some(login()),
+                oninput: move |event: FormEvent| login.set(event.value()),
+            }
+            Input {
+                name: "password".to_string(),
+                input_type: Some(InputType::Password),
+                placeholder: Some("Password".to_string()),
+                value: Some(password()),
+                oninput: move |event: FormEvent| password.set(event.value()),
+            }
+            Button {
+                button_scheme: ButtonScheme::Success,
+                on_click: on_submit,
+                text: "Sign in".to_string(),
+            }
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::exports::export_lar;
+use server::settings::{get_hmda_filer, save_hmda_filer};
+use shared::dtos::LarExport;
+use shared::models::{FederalAgency, HmdaFiler, HmdaFilerInput, Permission};
+use strum::IntoEnumIterator;
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::SelectInput;
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+const FIELD_INPUT: &str = "rounded border border-gray-300 bg-white px-2 py-1 text-sm";
+
+/// The HMDA filer details for the LAR transmittal sheet; editable with `ManageSettings`
+#[component]
+pub fn HmdaFilerForm() -> Element {
+    let mut filer = use_resource(move || async move { get_hmda_filer().await });
+
+    match &*filer.read() {
+        Some(Ok(current)) => rsx! {
+            HmdaFilerFields {
+                key: "{current.as_ref().map(|f| f.updated_at.to_string()).unwrap_or_default()}",
+                current: current.clone(),
+                on_saved: move |_| filer.restart(),
+            }
+        },
+        Some(Err(err)) => {
+            tracing::error!("get HMDA filer error: {err}");
+            rsx! { p { class: "text-red-600", "Could not load the HMDA filer details" } }
+        }
+        None => rsx! { p { class: "text-gray-500", "Loading..." } },
+    }
+}
+
+#[component]
+fn HmdaFilerFields(current: Option<HmdaFiler>, on_saved: EventHandler<()>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let saved = current.as_ref().map(HmdaFilerInput::from).unwrap_or_default();
+    let mut draft = use_signal(|| saved.clone());
+    let can_edit = CURRENT_USER().is_some_and(|user| user.has_permission(Permission::ManageSettings));
+    let changed = *draft.read() != saved;
+
+    let agency_options: Vec<(String, String)> =
+        FederalAgency::iter().map(|a| (a.code().to_string(), a.to_string())).collect();
+
+    let on_save = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        spawn(async move {
+            match save_hmda_filer(draft()).await {
+                Ok(saved) => {
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success(&saved.institution_name, Some("HMDA filer details saved")));
+                    on_saved.call(());
+                }
+                Err(err) => {
+                    tracing::error!("save HMDA filer error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not save the filer details")));
+                }
+            }
+        });
+    };
+
+    // (label, current value, setter) for the plain text fields
+    type Setter = fn(&mut HmdaFilerInput, String);
+    let fields: [(&str, String, Setter); 10] = [
+        ("LEI", draft.read().lei.clone(), |d, v| d.lei = v.trim().to_uppercase()),
+        ("Institution Name", draft.read().institution_name.clone(), |d, v| d.institution_name = v),
+        ("Tax ID", draft.read().tax_id.clone(), |d, v| d.tax_id = v.trim().to_string()),
+        ("Contact Name", draft.read().contact_name.clone(), |d, v| d.contact_name = v),
+        ("Contact Phone", draft.read().contact_phone.clone(), |d, v| d.contact_phone = v.trim().to_string()),
+        ("Contact Email", draft.read().contact_email.clone(), |d, v| d.contact_email = v),
+        ("Street", draft.read().contact_street.clone(), |d, v| d.contact_street = v),
+        ("City", draft.read().contact_city.clone(), |d, v| d.contact_city = v),
+        ("State", draft.read().contact_state.clone(), |d, v| d.contact_state = v.trim().to_uppercase()),
+        ("ZIP", draft.read().contact_zip.clone(), |d, v| d.contact_zip = v.trim().to_string()),
+    ];
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "HMDA Filer" }
+            p { class: "text-sm text-gray-600",
+                "Printed on the transmittal sheet of the LAR. The LEI also starts every loan's ULI."
+            }
+            div { class: "grid grid-cols-4 gap-2",
+                for (label, value, set) in fields {
+                    div { key: "{label}", class: "flex flex-col",
+                        label { class: "text-sm font-medium text-blue-900", "{label}" }
+                        input {
+                            class: FIELD_INPUT,
+                            disabled: !can_edit,
+                            value: "{value}",
+                            oninput: move |event: FormEvent| set(&mut draft.write(), event.value()),
+                        }
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Federal Agency" }
+                    SelectInput {
+                        i_value: draft.read().federal_agency.code().to_string(),
+                        options: agency_options,
+                        on_input: move |event: FormEvent| {
+                            if let Ok(agency) = event.value().parse() {
+                                draft.write().federal_agency = agency;
+                            }
+                        },
+                    }
+                }
+            }
+            if can_edit {
+                div {
+                    Button {
+                        button_scheme: ButtonScheme::Success,
+                        on_click: on_save,
+                        disabled: !changed,
+                        text: "Save".to_string(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Export of the year's HMDA LAR with its edit failures; for `ManageSettings`
+///
+/// The file downloads even when edits fail, so the list can be worked through
+/// and the export run again before submitting to the HMDA Platform.
+#[component]
+pub fn LarExportPanel() -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let last_year = chrono::Datelike::year(&chrono::Local::now().date_naive()) - 1;
+    let mut year = use_signal(move || last_year);
+    let mut export = use_signal(|| None::<LarExport>);
+    let mut exporting = use_signal(|| false);
+
+    if !CURRENT_USER().is_some_and(|user| user.has_permission(Permission::ManageSettings)) {
+        return rsx! {};
+    }
+
+    let on_export = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        exporting.set(true);
+        spawn(async move {
+            match export_lar(year()).await {
+                Ok(exported) => export.set(Some(exported)),
+                Err(err) => {
+                    tracing::error!("export LAR error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("LAR export failed")));
+                }
+            }
+            exporting.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "HMDA LAR" }
+            div { class: "flex flex-row items-center gap-2",
+                label { class: "text-sm font-medium text-blue-900", "Year" }
+                input {
+                    class: "{FIELD_INPUT} w-24",
+                    r#type: "number",
+                    value: "{year}",
+                    oninput: move |event: FormEvent| {
+                        if let Ok(picked) = event.value().parse() {
+                            year.set(picked);
+                            export.set(None);
+                        }
+                    },
+                }
+                Button {
+                    button_scheme: ButtonScheme::Success,
+                    on_click: on_export,
+                    disabled: exporting(),
+                    text: if exporting() { "Exporting...".to_string() } else { "Export LAR".to_string() },
+                }
+                if let Some(exported) = export() {
+                    a {
+                        class: "text-blue-600 hover:underline",
+                        href: "{exported.file.data_url()}",
+                        download: "{exported.file.file_name}",
+                        "Download {exported.file.file_name} ({exported.file.rows} records)"
+                    }
+                }
+            }
+            if let Some(exported) = export() {
+                if exported.edits.is_empty() {
+                    p { class: "text-sm text-green-700", "No syntactical or validity edits failed." }
+                } else {
+                    p { class: "text-sm text-red-700",
+                        "{exported.edits.len()} edit failures; the HMDA Platform will reject the file until they are fixed."
+                    }
+                    Table {
+                        TableHead {
+                            TableRow {
+                                TableHeaderCell { "Edit" }
+                                TableHeaderCell { "Kind" }
+                                TableHeaderCell { "ULI" }
+                                TableHeaderCell { "Message" }
+                            }
+                        }
+                        TableBody {
+                            for (i, failure) in exported.edits.iter().enumerate() {
+                                TableRow { key: "{i}",
+                                    TableCell { span { class: "font-mono", "{failure.edit}" } }
+                                    TableCell { "{failure.kind}" }
+                                    TableCell {
+                                        span { class: "font-mono",
+                                            {failure.uli.clone().unwrap_or_else(|| "Transmittal sheet".to_string())}
+                                        }
+                                    }
+                                    TableCell { "{failure.message}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
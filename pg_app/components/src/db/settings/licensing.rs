@@ -0,0 +1,292 @@
+use chrono::NaiveDate;
+use dioxus::{logger::tracing, prelude::*};
+use server::users::{delete_license, get_all_users, get_expiring_licenses, get_licenses, save_license, save_nmls_id};
+use shared::models::{LicenseInput, Permission, User, UserRole, LICENSE_EXPIRY_WARNING_DAYS};
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::SelectInput;
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+const FIELD_INPUT: &str = "rounded border border-gray-300 bg-white px-2 py-1 text-sm";
+
+/// A loan officer's NMLS ID and state licenses; editable with `ManageUsers`
+#[component]
+pub fn LicensingPanel() -> Element {
+    let users = use_resource(|| async { get_all_users().await });
+    let mut selected = use_signal(|| None::<i32>);
+
+    let officers: Vec<User> = match &*users.read() {
+        Some(Ok(users)) => {
+            let mut officers: Vec<User> = users
+                .iter()
+                .filter(|u| u.is_active && u.role == UserRole::LoanOfficer)
+                .cloned()
+                .collect();
+            officers.sort_by_key(|u| (u.last_name.clone(), u.first_name.clone()));
+            officers
+        }
+        Some(Err(err)) => {
+            tracing::error!("get users error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let officer = selected()
+        .and_then(|id| officers.iter().find(|u| u.id == id))
+        .or_else(|| officers.first())
+        .cloned();
+
+    let options: Vec<(String, String)> = officers.iter().map(|u| (u.id.to_string(), u.full_name())).collect();
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Loan Officer Licensing" }
+            p { class: "text-sm text-gray-600",
+                "A loan officer needs an NMLS ID and a current license in the subject property's state to be assigned a loan."
+            }
+            if let Some(officer) = officer {
+                SelectInput {
+                    i_value: officer.id.to_string(),
+                    options,
+                    on_input: move |event: FormEvent| selected.set(event.value().parse().ok()),
+                }
+                OfficerLicenses { key: "{officer.id}", officer }
+            } else {
+                p { class: "text-gray-500", "No active loan officers" }
+            }
+        }
+    }
+}
+
+#[component]
+fn OfficerLicenses(officer: User) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let user_id = officer.id;
+    let mut licenses = use_resource(move || async move { get_licenses(user_id).await });
+    let mut nmls_id = use_signal(|| officer.nmls_id.clone().unwrap_or_default());
+    let can_edit = CURRENT_USER().is_some_and(|user| user.has_permission(Permission::ManageUsers));
+    let today = chrono::Local::now().date_naive();
+
+    let rows = match &*licenses.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get licenses error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    let on_save_nmls = move |_| {
+        spawn(async move {
+            let value = Some(nmls_id()).filter(|id| !id.trim().is_empty());
+            match save_nmls_id(user_id, value).await {
+                Ok(()) => {
+                    toast_manager.write().popup(ToastInfo::success("NMLS ID saved", None));
+                }
+                Err(err) => {
+                    tracing::error!("save NMLS ID error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not save NMLS ID")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-row items-end gap-2",
+            div { class: "flex flex-col",
+                label { class: "text-sm font-medium text-blue-900", "NMLS ID" }
+                input {
+                    class: FIELD_INPUT,
+                    disabled: !can_edit,
+                    value: "{nmls_id}",
+                    oninput: move |event: FormEvent| nmls_id.set(event.value()),
+                }
+            }
+            if can_edit {
+                Button {
+                    button_scheme: ButtonScheme::Default,
+                    on_click: on_save_nmls,
+                    text: "Save NMLS ID".to_string(),
+                }
+            }
+        }
+        Table {
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "State" }
+                    TableHeaderCell { "License #" }
+                    TableHeaderCell { "Expires" }
+                    TableHeaderCell { "" }
+                }
+            }
+            TableBody {
+                for license in rows.iter().cloned() {
+                    TableRow {
+                        key: "{license.id}",
+                        class: if !license.is_current(today) { Some("text-red-600".to_string()) } else { None },
+                        TableCell { "{license.state}" }
+                        TableCell { "{license.license_number}" }
+                        TableCell { {license.expires_on.format("%m/%d/%Y").to_string()} }
+                        TableCell {
+                            if can_edit {
+                                button {
+                                    class: "text-xs text-red-600 hover:underline cursor-pointer",
+                                    onclick: move |_| {
+                                        spawn(async move {
+                                            match delete_license(license.id).await {
+                                                Ok(()) => licenses.restart(),
+                                                Err(err) => tracing::error!("delete license error: {err}"),
+                                            }
+                                        });
+                                    },
+                                    "Remove"
+                                }
+                            }
+                        }
+                    }
+                }
+                if can_edit {
+                    NewLicenseRow { user_id, on_saved: move |_| licenses.restart() }
+                }
+            }
+        }
+    }
+}
+
+/// Adds a license, or renews the officer's license for the same state
+#[component]
+fn NewLicenseRow(user_id: i32, on_saved: EventHandler<()>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut state = use_signal(String::new);
+    let mut license_number = use_signal(String::new);
+    let mut expires_on = use_signal(String::new);
+
+    let on_add = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        let Ok(expires) = NaiveDate::parse_from_str(&expires_on(), "%Y-%m-%d") else {
+            toast_manager
+                .write()
+                .popup(ToastInfo::error("Pick the expiration date", Some("Invalid input")));
+            return;
+        };
+        let input = LicenseInput {
+            state: state().trim().to_uppercase(),
+            license_number: license_number(),
+            expires_on: expires,
+        };
+        spawn(async move {
+            match save_license(user_id, input).await {
+                Ok(license) => {
+                    state.set(String::new());
+                    license_number.set(String::new());
+                    expires_on.set(String::new());
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success(&format!("{} license saved", license.state), None));
+                    on_saved.call(());
+                }
+                Err(err) => {
+                    tracing::error!("save license error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not save license")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        TableRow {
+            TableCell {
+                input {
+                    class: "{FIELD_INPUT} w-16",
+                    maxlength: 2,
+                    placeholder: "TX",
+                    value: "{state}",
+                    oninput: move |event: FormEvent| state.set(event.value()),
+                }
+            }
+            TableCell {
+                input {
+                    class: FIELD_INPUT,
+                    value: "{license_number}",
+                    oninput: move |event: FormEvent| license_number.set(event.value()),
+                }
+            }
+            TableCell {
+                input {
+                    class: FIELD_INPUT,
+                    r#type: "date",
+                    value: "{expires_on}",
+                    oninput: move |event: FormEvent| expires_on.set(event.value()),
+                }
+            }
+            TableCell {
+                button {
+                    class: "text-xs text-blue-600 hover:underline cursor-pointer",
+                    onclick: on_add,
+                    "Add / Renew"
+                }
+            }
+        }
+    }
+}
+
+/// Report of licenses expiring in the next 60 days, and any already expired
+#[component]
+pub fn ExpiringLicenses() -> Element {
+    let rows = use_resource(|| async { get_expiring_licenses(LICENSE_EXPIRY_WARNING_DAYS).await });
+    let today = chrono::Local::now().date_naive();
+
+    let rows = match &*rows.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get expiring licenses error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        Table {
+            striped: true,
+            hoverable: true,
+            caption: rsx! { "Licenses expiring in the next {LICENSE_EXPIRY_WARNING_DAYS} days" },
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Loan Officer" }
+                    TableHeaderCell { "NMLS ID" }
+                    TableHeaderCell { "State" }
+                    TableHeaderCell { "License #" }
+                    TableHeaderCell { "Expires" }
+                    TableHeaderCell { "Days Left" }
+                }
+            }
+            TableBody {
+                if rows.is_empty() {
+                    TableRow {
+                        TableCell { colspan: Some(6), class: Some("text-gray-500".to_string()), "No licenses expiring soon" }
+                    }
+                }
+                for row in rows.iter() {
+                    TableRow {
+                        key: "{row.license_id}",
+                        class: if (row.expires_on - today).num_days() < 0 { Some("text-red-600".to_string()) } else { None },
+                        TableCell { "{row.officer_name}" }
+                        TableCell { {row.nmls_id.clone().unwrap_or_else(|| "Missing".to_string())} }
+                        TableCell { "{row.state}" }
+                        TableCell { "{row.license_number}" }
+                        TableCell { {row.expires_on.format("%m/%d/%Y").to_string()} }
+                        TableCell {
+                            {
+                                let days = (row.expires_on - today).num_days();
+                                if days < 0 { "Expired".to_string() } else { days.to_string() }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
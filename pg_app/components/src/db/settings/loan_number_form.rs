@@ -0,0 +1,108 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::settings::{get_loan_number_settings, save_loan_number_settings};
+use shared::models::{LoanNumberSettings, LoanNumberSettingsInput, Permission};
+use crate::db::session::CURRENT_USER;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+const FIELD_INPUT: &str = "rounded border border-gray-300 bg-white px-2 py-1 text-sm font-mono";
+
+/// The loan number format and branch code; editable with `ManageSettings`
+#[component]
+pub fn LoanNumberForm() -> Element {
+    let mut settings = use_resource(move || async move { get_loan_number_settings().await });
+
+    match &*settings.read() {
+        Some(Ok(current)) => rsx! {
+            LoanNumberFields {
+                key: "{current.updated_at}",
+                current: current.clone(),
+                on_saved: move |_| settings.restart(),
+            }
+        },
+        Some(Err(err)) => {
+            tracing::error!("get loan number settings error: {err}");
+            rsx! { p { class: "text-red-600", "Could not load the loan number format" } }
+        }
+        None => rsx! { p { class: "text-gray-500", "Loading..." } },
+    }
+}
+
+#[component]
+fn LoanNumberFields(current: LoanNumberSettings, on_saved: EventHandler<()>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut draft = use_signal(|| LoanNumberSettingsInput::from(&current));
+    let can_edit = CURRENT_USER().is_some_and(|user| user.has_permission(Permission::ManageSettings));
+    let today = chrono::Local::now().date_naive();
+
+    let example = draft.read().example(today);
+    let changed = *draft.read() != LoanNumberSettingsInput::from(&current);
+
+    let on_save = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        spawn(async move {
+            match save_loan_number_settings(draft()).await {
+                Ok(saved) => {
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::success(&saved.format, Some("Loan number format saved")));
+                    on_saved.call(());
+                }
+                Err(err) => {
+                    tracing::error!("save loan number settings error: {err}");
+                    toast_manager
+                        .write()
+                        .popup(ToastInfo::error(&err.to_string(), Some("Could not save the format")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Loan Numbers" }
+            p { class: "text-sm text-gray-600",
+                "Tokens: {{BRANCH}}, {{YYYY}}, {{YY}}, {{MM}} and {{SEQ:n}}, the next number zero-padded to n digits. "
+                "Each prefix counts from 1 on its own. Existing loans keep their numbers when the format changes."
+            }
+            div { class: "flex flex-row flex-wrap items-end gap-3",
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Format" }
+                    input {
+                        class: FIELD_INPUT,
+                        disabled: !can_edit,
+                        value: "{draft.read().format}",
+                        oninput: move |event: FormEvent| draft.write().format = event.value(),
+                    }
+                }
+                div { class: "flex flex-col",
+                    label { class: "text-sm font-medium text-blue-900", "Branch Code" }
+                    input {
+                        class: "{FIELD_INPUT} w-28",
+                        disabled: !can_edit,
+                        value: "{draft.read().branch_code}",
+                        oninput: move |event: FormEvent| draft.write().branch_code = event.value().trim().to_uppercase(),
+                    }
+                }
+                if can_edit {
+                    Button {
+                        button_scheme: ButtonScheme::Success,
+                        on_click: on_save,
+                        disabled: !changed || example.is_err(),
+                        text: "Save".to_string(),
+                    }
+                }
+            }
+            match example {
+                Ok(number) => rsx! {
+                    p { class: "text-sm", "Example: "
+                        span { class: "font-mono", "{number}" }
+                    }
+                },
+                Err(err) => rsx! { p { class: "text-sm text-red-600", "{err}" } },
+            }
+        }
+    }
+}
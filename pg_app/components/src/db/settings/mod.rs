@@ -0,0 +1,11 @@
+pub use hmda_filer_form::{HmdaFilerForm, LarExportPanel};
+pub use licensing::{ExpiringLicenses, LicensingPanel};
+pub use loan_number_form::LoanNumberForm;
+pub use pmi_rate_table::PmiRateTable;
+pub use sla_target_table::SlaTargetTable;
+
+pub mod hmda_filer_form;  // Contains HmdaFilerForm, the transmittal sheet details, and LarExportPanel
+pub mod licensing;  // Contains LicensingPanel, NMLS IDs and state licenses, and the ExpiringLicenses report
+pub mod loan_number_form;  // Contains LoanNumberForm, the loan number format and branch code
+pub mod pmi_rate_table;  // Contains PmiRateTable, the editable PMI rate grid
+pub mod sla_target_table;  // Contains SlaTargetTable, the business days allowed in each status
@@ -0,0 +1,175 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::settings::{create_pmi_rate, delete_pmi_rate, get_pmi_rates, update_pmi_rate};
+use shared::models::{Permission, PmiRate, PmiRateInput};
+use crate::db::session::CURRENT_USER;
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+const CELL_INPUT: &str = "w-24 rounded border border-gray-300 bg-white px-1 py-0.5 text-sm";
+
+type RateField = (f64, fn(&mut PmiRateInput, f64));
+
+/// PMI rates by LTV and credit score band; editable with `ManageSettings`
+#[component]
+pub fn PmiRateTable() -> Element {
+    let mut rates = use_resource(move || async move { get_pmi_rates().await });
+    let can_edit = CURRENT_USER().is_some_and(|user| user.has_permission(Permission::ManageSettings));
+
+    let rows = match &*rates.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get PMI rates error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "PMI Rates" }
+            p { class: "text-sm text-gray-600",
+                "Annual premium as a percentage of the loan amount. The first band covering a loan's LTV and credit score applies."
+            }
+            Table {
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "LTV From (%)" }
+                        TableHeaderCell { "LTV To (%)" }
+                        TableHeaderCell { "Score From" }
+                        TableHeaderCell { "Score To" }
+                        TableHeaderCell { "Annual Rate (%)" }
+                        TableHeaderCell { "" }
+                    }
+                }
+                TableBody {
+                    for rate in rows {
+                        PmiRateRow {
+                            key: "{rate.id}-{rate.updated_at}",
+                            rate,
+                            can_edit,
+                            on_changed: move |_| rates.restart(),
+                        }
+                    }
+                    if can_edit {
+                        NewPmiRateRow { on_added: move |_| rates.restart() }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Band and rate cells shared by existing and new rows
+#[component]
+fn RateCells(input: PmiRateInput, disabled: bool, on_change: EventHandler<PmiRateInput>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let fields: [RateField; 5] = [
+        (input.ltv_min, |i, v| i.ltv_min = v),
+        (input.ltv_max, |i, v| i.ltv_max = v),
+        (input.credit_score_min as f64, |i, v| i.credit_score_min = v as i32),
+        (input.credit_score_max as f64, |i, v| i.credit_score_max = v as i32),
+        (input.annual_rate, |i, v| i.annual_rate = v),
+    ];
+
+    rsx! {
+        for (index, (value, set)) in fields.into_iter().enumerate() {
+            TableCell { key: "{index}",
+                input {
+                    class: CELL_INPUT,
+                    disabled,
+                    value: "{value}",
+                    onchange: {
+                        let input = input.clone();
+                        move |event: FormEvent| match event.value().trim().parse::<f64>() {
+                            Ok(parsed) => {
+                                let mut updated = input.clone();
+                                set(&mut updated, parsed);
+                                on_change.call(updated);
+                            }
+                            Err(_) => {
+                                toast_manager.write().popup(ToastInfo::error("Enter a number", Some("Invalid input")));
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn PmiRateRow(rate: PmiRate, can_edit: bool, on_changed: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let rate_id = rate.id;
+
+    rsx! {
+        TableRow {
+            RateCells {
+                input: PmiRateInput::from(&rate),
+                disabled: !can_edit,
+                on_change: move |input: PmiRateInput| {
+                    spawn(async move {
+                        if let Err(err) = update_pmi_rate(rate_id, input).await {
+                            tracing::error!("update PMI rate error: {err}");
+                            toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not save rate")));
+                        }
+                        on_changed.call(rate_id);
+                    });
+                },
+            }
+            TableCell {
+                if can_edit {
+                    button {
+                        class: "text-xs text-red-600 hover:underline cursor-pointer",
+                        onclick: move |_| {
+                            spawn(async move {
+                                match delete_pmi_rate(rate_id).await {
+                                    Ok(_) => on_changed.call(rate_id),
+                                    Err(err) => tracing::error!("delete PMI rate error: {err}"),
+                                }
+                            });
+                        },
+                        "Remove"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NewPmiRateRow(on_added: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut draft = use_signal(PmiRateInput::default);
+
+    let add = move |_| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        spawn(async move {
+            match create_pmi_rate(draft()).await {
+                Ok(rate) => {
+                    draft.set(PmiRateInput::default());
+                    on_added.call(rate.id);
+                }
+                Err(err) => {
+                    tracing::error!("create PMI rate error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not add rate")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        TableRow {
+            RateCells { input: draft(), disabled: false, on_change: move |input| draft.set(input) }
+            TableCell {
+                button {
+                    class: "text-xs text-blue-600 hover:underline cursor-pointer",
+                    onclick: add,
+                    "Add"
+                }
+            }
+        }
+    }
+}
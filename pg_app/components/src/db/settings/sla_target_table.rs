@@ -0,0 +1,86 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::settings::{get_sla_targets, save_sla_target};
+use shared::models::{sla_target_for, LoanStatus, Permission};
+use strum::IntoEnumIterator;
+use crate::db::session::CURRENT_USER;
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+const CELL_INPUT: &str = "w-24 rounded border border-gray-300 bg-white px-1 py-0.5 text-sm";
+
+/// SLA target per pipeline status in business days; editable with `ManageSettings`
+#[component]
+pub fn SlaTargetTable() -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut targets = use_resource(move || async move { get_sla_targets().await });
+    let can_edit = CURRENT_USER().is_some_and(|user| user.has_permission(Permission::ManageSettings));
+
+    let rows = match &*targets.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get SLA targets error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    let save = move |status: LoanStatus, business_days: Option<i32>| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        spawn(async move {
+            if let Err(err) = save_sla_target(status, business_days).await {
+                tracing::error!("save SLA target error: {err}");
+                toast_manager
+                    .write()
+                    .popup(ToastInfo::error(&err.to_string(), Some("Could not save SLA target")));
+            }
+            targets.restart();
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "SLA Targets" }
+            p { class: "text-sm text-gray-600",
+                "Business days a loan may spend in each status before it is flagged. Leave a status blank to stop tracking it."
+            }
+            Table {
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Status" }
+                        TableHeaderCell { "Target (business days)" }
+                    }
+                }
+                TableBody {
+                    for status in LoanStatus::iter().filter(|status| !status.is_final()) {
+                        TableRow {
+                            key: "{status.code()}-{sla_target_for(&rows, status).unwrap_or_default()}",
+                            TableCell { "{status}" }
+                            TableCell {
+                                input {
+                                    class: CELL_INPUT,
+                                    disabled: !can_edit,
+                                    value: sla_target_for(&rows, status).map(|days| days.to_string()).unwrap_or_default(),
+                                    onchange: move |event: FormEvent| {
+                                        let value = event.value();
+                                        let value = value.trim();
+                                        if value.is_empty() {
+                                            save(status, None);
+                                        } else if let Ok(days) = value.parse::<i32>() {
+                                            save(status, Some(days));
+                                        } else {
+                                            toast_manager
+                                                .write()
+                                                .popup(ToastInfo::error("Enter a whole number of days", Some("Invalid input")));
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
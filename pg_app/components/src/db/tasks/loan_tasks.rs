@@ -0,0 +1,115 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::tasks::{create_task, get_loan_tasks};
+use server::users::get_all_users;
+use shared::models::{TaskInput, TaskPriority};
+use strum::IntoEnumIterator;
+use crate::db::session::CURRENT_USER;
+use crate::db::tasks::TaskTable;
+use crate::ui::button::{Button, ButtonScheme};
+use crate::ui::input::{DateInput, Input, InputType, SelectInput};
+use crate::ui::toast::{ToastInfo, ToastManager};
+
+/// Tasks on a loan with the add task form
+#[component]
+pub fn LoanTasks(loan_id: i32) -> Element {
+    let mut tasks = use_resource(move || async move { get_loan_tasks(loan_id).await });
+
+    let rows = match &*tasks.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get loan tasks error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Tasks" }
+            TaskTable { rows, show_loan: false, on_changed: move |_| tasks.restart() }
+            AddTask { loan_id, on_added: move |_| tasks.restart() }
+        }
+    }
+}
+
+#[component]
+fn AddTask(loan_id: i32, on_added: EventHandler<i32>) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut title = use_signal(String::new);
+    // Tasks default to whoever adds them; only managers may assign others
+    let mut assignee = use_signal(|| CURRENT_USER.peek().as_ref().map(|u| u.id.to_string()).unwrap_or_default());
+    let mut due_date = use_signal(String::new);
+    let mut priority = use_signal(TaskPriority::default);
+    let users = use_resource(|| async { get_all_users().await });
+
+    let mut assignee_options = vec![(String::new(), "Unassigned".to_string())];
+    if let Some(Ok(users)) = &*users.read() {
+        assignee_options.extend(
+            users
+                .iter()
+                .filter(|u| u.is_active)
+                .map(|u| (u.id.to_string(), u.full_name())),
+        );
+    }
+    let priority_options: Vec<(String, String)> = TaskPriority::iter()
+        .map(|p| (p.code().to_string(), p.to_string()))
+        .collect();
+
+    let on_submit = move |_| {
+        let input = TaskInput {
+            title: title.read().trim().to_string(),
+            description: None,
+            assignee_id: assignee.read().parse().ok(),
+            due_date: chrono::NaiveDate::parse_from_str(&due_date.read(), "%Y-%m-%d").ok(),
+            priority: priority(),
+        };
+        spawn(async move {
+            match create_task(loan_id, input).await {
+                Ok(id) => {
+                    title.set(String::new());
+                    due_date.set(String::new());
+                    on_added.call(id);
+                }
+                Err(err) => {
+                    tracing::error!("create task error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not add task")));
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-row flex-wrap items-end gap-2",
+            Input {
+                name: "task_title".to_string(),
+                input_type: Some(InputType::Text),
+                placeholder: Some("New task".to_string()),
+                value: Some(title()),
+                oninput: move |event: FormEvent| title.set(event.value()),
+            }
+            SelectInput {
+                i_value: assignee(),
+                options: assignee_options,
+                on_input: move |event: FormEvent| assignee.set(event.value()),
+            }
+            DateInput {
+                i_value: due_date(),
+                on_input: move |event: FormEvent| due_date.set(event.value()),
+            }
+            SelectInput {
+                i_value: priority().code().to_string(),
+                options: priority_options,
+                on_input: move |event: FormEvent| {
+                    if let Ok(p) = event.value().parse() {
+                        priority.set(p);
+                    }
+                },
+            }
+            Button {
+                button_scheme: ButtonScheme::Success,
+                on_click: on_submit,
+                text: "Add Task".to_string(),
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub use loan_tasks::LoanTasks;
+pub use task_checklist::TaskChecklist;
+pub use task_table::TaskTable;
+
+pub mod loan_tasks;      // Contains LoanTasks and the add task form
+pub mod task_checklist;  // Contains TaskChecklist
+pub mod task_table;      // Contains TaskTable with overdue highlighting
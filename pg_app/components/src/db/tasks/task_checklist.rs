@@ -0,0 +1,92 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::tasks::{add_checklist_item, delete_checklist_item, get_checklist, set_checklist_item_done};
+use crate::ui::button::{Button, ButtonScheme, ButtonSize};
+use crate::ui::input::{Input, InputType};
+
+/// Checklist for one task with add, tick and remove
+#[component]
+pub fn TaskChecklist(task_id: i32, on_changed: EventHandler<i32>) -> Element {
+    let mut items = use_resource(move || async move { get_checklist(task_id).await });
+    let mut label = use_signal(String::new);
+
+    let rows = match &*items.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get checklist error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    let on_add = move |_| {
+        let text = label.read().trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        spawn(async move {
+            match add_checklist_item(task_id, text).await {
+                Ok(_) => {
+                    label.set(String::new());
+                    items.restart();
+                    on_changed.call(task_id);
+                }
+                Err(err) => tracing::error!("add checklist item error: {err}"),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-1",
+            for item in rows {
+                div { key: "{item.id}", class: "flex items-center gap-2",
+                    input {
+                        r#type: "checkbox",
+                        checked: item.is_done,
+                        onchange: move |event: FormEvent| {
+                            spawn(async move {
+                                match set_checklist_item_done(item.id, event.checked()).await {
+                                    Ok(_) => {
+                                        items.restart();
+                                        on_changed.call(task_id);
+                                    }
+                                    Err(err) => tracing::error!("update checklist item error: {err}"),
+                                }
+                            });
+                        },
+                    }
+                    span { class: if item.is_done { "line-through text-gray-500" } else { "" }, "{item.label}" }
+                    button {
+                        class: "text-xs text-red-600 hover:underline cursor-pointer",
+                        onclick: move |_| {
+                            spawn(async move {
+                                match delete_checklist_item(item.id).await {
+                                    Ok(_) => {
+                                        items.restart();
+                                        on_changed.call(task_id);
+                                    }
+                                    Err(err) => tracing::error!("delete checklist item error: {err}"),
+                                }
+                            });
+                        },
+                        "Remove"
+                    }
+                }
+            }
+            div { class: "flex flex-row items-end gap-2",
+                Input {
+                    name: "checklist_label".to_string(),
+                    input_type: Some(InputType::Text),
+                    placeholder: Some("New checklist item".to_string()),
+                    value: Some(label()),
+                    oninput: move |event: FormEvent| label.set(event.value()),
+                }
+                Button {
+                    button_scheme: ButtonScheme::Outline,
+                    button_size: ButtonSize::Small,
+                    on_click: on_add,
+                    text: "Add".to_string(),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use dioxus::{logger::tracing, prelude::*};
+use server::tasks::{reassign_task, set_task_status};
+use server::users::get_all_users;
+use shared::models::{Permission, TaskStatus};
+use shared::TaskRow;
+use strum::IntoEnumIterator;
+use crate::db::session::CURRENT_USER;
+use crate::db::tasks::TaskChecklist;
+use crate::ui::input::SelectInput;
+use crate::ui::toast::{ToastInfo, ToastManager};
+use crate::ui::{Table, TableBody, TableCell, TableHead, TableHeaderCell, TableRow};
+
+/// Tasks with status changes, checklists and (for managers) reassignment
+///
+/// Overdue tasks are highlighted. Rows are shown in the order given.
+#[component]
+pub fn TaskTable(
+    rows: Vec<TaskRow>,
+    show_loan: bool,
+    on_changed: EventHandler<i32>,
+    on_view_loan: Option<EventHandler<i32>>,
+) -> Element {
+    let mut toast_manager = use_context::<Signal<ToastManager>>();
+    let mut expanded = use_signal(|| None::<i32>);
+    let today = chrono::Local::now().date_naive();
+    let can_reassign = CURRENT_USER().is_some_and(|user| user.has_permission(Permission::AssignTasks));
+    let users = use_resource(|| async { get_all_users().await });
+
+    let mut assignee_options = vec![(String::new(), "Unassigned".to_string())];
+    if let Some(Ok(users)) = &*users.read() {
+        assignee_options.extend(
+            users
+                .iter()
+                .filter(|u| u.is_active)
+                .map(|u| (u.id.to_string(), u.full_name())),
+        );
+    }
+    let status_options: Vec<(String, String)> = TaskStatus::iter()
+        .map(|s| (s.code().to_string(), s.to_string()))
+        .collect();
+
+    let mut on_status = move |(task_id, status): (i32, TaskStatus)| {
+        if CURRENT_USER().is_none() {
+            toast_manager.write().popup(ToastInfo::error("Sign in to update tasks", Some("Not signed in")));
+            return;
+        }
+        spawn(async move {
+            match set_task_status(task_id, status).await {
+                Ok(_) => on_changed.call(task_id),
+                Err(err) => {
+                    tracing::error!("set task status error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not update task")));
+                }
+            }
+        });
+    };
+
+    let on_reassign = move |(task_id, assignee_id): (i32, Option<i32>)| {
+        if CURRENT_USER().is_none() {
+            return;
+        }
+        spawn(async move {
+            match reassign_task(task_id, assignee_id).await {
+                Ok(_) => on_changed.call(task_id),
+                Err(err) => {
+                    tracing::error!("reassign task error: {err}");
+                    toast_manager.write().popup(ToastInfo::error(&err.to_string(), Some("Could not reassign task")));
+                }
+            }
+        });
+    };
+
+    let columns = if show_loan { 9 } else { 8 };
+
+    rsx! {
+        Table {
+            hoverable: true,
+            TableHead {
+                TableRow {
+                    TableHeaderCell { "Due" }
+                    TableHeaderCell { "Priority" }
+                    TableHeaderCell { "Task" }
+                    if show_loan {
+                        TableHeaderCell { "Loan" }
+                    }
+                    TableHeaderCell { "Assignee" }
+                    TableHeaderCell { "Status" }
+                    TableHeaderCell { "Checklist" }
+                    TableHeaderCell { "Completed" }
+                    TableHeaderCell { "" }
+                }
+            }
+            TableBody {
+                if rows.is_empty() {
+                    TableRow {
+                        TableCell { colspan: Some(columns), class: Some("text-gray-500".to_string()), "No tasks" }
+                    }
+                }
+                for row in rows.iter().cloned() {
+                    TableRow {
+                        key: "{row.task.id}",
+                        class: if row.task.is_overdue(today) {
+                            Some("bg-red-50 text-red-700".to_string())
+                        } else if !row.task.status.is_open() {
+                            Some("opacity-60".to_string())
+                        } else {
+                            None
+                        },
+                        TableCell {
+                            {row.task.due_date.map(|d| d.format("%m/%d/%Y").to_string()).unwrap_or_default()}
+                            if row.task.is_overdue(today) {
+                                span { class: "ml-1 text-xs font-semibold uppercase", "Overdue" }
+                            }
+                        }
+                        TableCell { "{row.task.priority}" }
+                        TableCell {
+                            p { class: "font-medium", "{row.task.title}" }
+                            if let Some(description) = row.task.description.clone() {
+                                p { class: "text-xs text-gray-500", "{description}" }
+                            }
+                        }
+                        if show_loan {
+                            TableCell {
+                                button {
+                                    class: "text-blue-600 hover:underline cursor-pointer",
+                                    onclick: move |_| {
+                                        if let Some(handler) = on_view_loan {
+                                            handler.call(row.task.loan_id);
+                                        }
+                                    },
+                                    {row.loan_number.clone().unwrap_or_else(|| format!("#{}", row.task.loan_id))}
+                                    " · {row.borrower_name}"
+                                }
+                            }
+                        }
+                        TableCell {
+                            if can_reassign {
+                                SelectInput {
+                                    i_value: row.task.assignee_id.map(|id| id.to_string()).unwrap_or_default(),
+                                    options: assignee_options.clone(),
+                                    on_input: move |event: FormEvent| on_reassign((row.task.id, event.value().parse().ok())),
+                                }
+                            } else {
+                                {row.assignee_name.clone().unwrap_or_else(|| "Unassigned".to_string())}
+                            }
+                        }
+                        TableCell {
+                            SelectInput {
+                                i_value: row.task.status.code().to_string(),
+                                options: status_options.clone(),
+                                on_input: move |event: FormEvent| {
+                                    if let Ok(status) = event.value().parse() {
+                                        on_status((row.task.id, status));
+                                    }
+                                },
+                            }
+                        }
+                        TableCell {
+                            if row.checklist_total > 0 {
+                                "{row.checklist_done}/{row.checklist_total}"
+                            }
+                        }
+                        TableCell {
+                            if let Some(completed_at) = row.task.completed_at {
+                                {completed_at.format("%m/%d/%Y").to_string()}
+                                if let Some(name) = row.completed_by_name.clone() {
+                                    " by {name}"
+                                }
+                            }
+                        }
+                        TableCell {
+                            button {
+                                class: "text-blue-600 hover:underline cursor-pointer",
+                                onclick: move |_| {
+                                    if expanded() == Some(row.task.id) {
+                                        expanded.set(None);
+                                    } else {
+                                        expanded.set(Some(row.task.id));
+                                    }
+                                },
+                                if expanded() == Some(row.task.id) { "Hide" } else { "Checklist" }
+                            }
+                        }
+                    }
+                    if expanded() == Some(row.task.id) {
+                        TableRow { key: "{row.task.id}-checklist",
+                            TableCell { colspan: Some(columns),
+                                TaskChecklist { task_id: row.task.id, on_changed: move |_| on_changed.call(row.task.id) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
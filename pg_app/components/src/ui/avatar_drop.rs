@@ -1,12 +1,14 @@
 // pg_app/components/src/ui/avatar_drop.rs
 use dioxus::prelude::*;
 use crate::ui::Avatar;
+use crate::db::session::{sign_out, use_restore_session, SignInForm, CURRENT_USER};
 // use super::routes::Route;  
 
 
 #[component]
 pub fn AvatarDrop() -> Element {
     let mut show_dropdown: Signal<bool> = use_signal(|| false);
+    use_restore_session();
 
     let toggle_dropdown = move |_| {
         show_dropdown.set(!show_dropdown());
@@ -22,12 +24,18 @@ pub fn AvatarDrop() -> Element {
                 // Dropdown menu that appears when show_dropdown is true
                 if show_dropdown() {
                     div { class: "absolute right-0 z-50 mt-2 w-48 rounded-md shadow-lg bg-white dark:bg-gray-700",
-                        div { class: "px-4 py-3 border-b border-gray-200 dark:border-gray-600",
-                            span { class: "block text-sm text-gray-900 dark:text-white",
-                                "Bonnie Green"
+                        if let Some(user) = CURRENT_USER() {
+                            div { class: "px-4 py-3 border-b border-gray-200 dark:border-gray-600",
+                                span { class: "block text-sm text-gray-900 dark:text-white",
+                                    "{user.full_name()}"
+                                }
+                                span { class: "block text-sm text-gray-500 truncate dark:text-gray-400",
+                                    "{user.email} · {user.role}"
+                                }
                             }
-                            span { class: "block text-sm text-gray-500 truncate dark:text-gray-400",
-                                "name@flowbite.com"
+                        } else {
+                            div { class: "border-b border-gray-200 dark:border-gray-600",
+                                SignInForm { on_signed_in: move |_| show_dropdown.set(false) }
                             }
                         }
                         ul { class: "py-1",
@@ -45,11 +53,17 @@ pub fn AvatarDrop() -> Element {
                                     "Settings"
                                 }
                             }
-                            li {
-                                a {
-                                    href: "#",
-                                    class: "block px-4 py-2 text-sm text-gray-700 hover:bg-gray-100 dark:hover:bg-gray-600 dark:text-gray-200 dark:hover:text-white",
-                                    "Sign out"
+                            if CURRENT_USER().is_some() {
+                                li {
+                                    a {
+                                        href: "#",
+                                        class: "block px-4 py-2 text-sm text-gray-700 hover:bg-gray-100 dark:hover:bg-gray-600 dark:text-gray-200 dark:hover:text-white",
+                                        onclick: move |_| {
+                                            show_dropdown.set(false);
+                                            spawn(sign_out());
+                                        },
+                                        "Sign out"
+                                    }
                                 }
                             }
                         }
@@ -15,7 +15,7 @@ pub mod loading_spinner;
 
 // Re-export from modules
 pub use button::{Button, ButtonSize, ButtonScheme, ButtonType};
-pub use input::{Input, InputSize, InputType, InputProps, TextInput, PasswordInput, DateInput, NumberInput,SelectInput};
+pub use input::{Input, InputSize, InputType, InputProps, TextInput, PasswordInput, DateInput, NumberInput,SelectInput, FileInput};
 pub use inline_form::{InlineForm, InlineFormProps};
 pub use steps::Steps;
 pub use hero::Hero;
@@ -1,33 +1,40 @@
 use dioxus::prelude::*;
-use super::card::{Card, CardBody, CardTitle}; // Ensure this import is correct
 
-// Corrected variable name and asset handling
-const HOME_SVG: &str = "./assets/home.svg"; // If asset! is correct, ensure it's properly imported
+#[derive(Props, Clone, PartialEq)]
+pub struct StepsProps {
+    /// Step labels in order
+    steps: Vec<String>,
+    /// Index of the step in progress; earlier steps show as done
+    current: usize,
+    class: Option<String>,
+}
 
+/// Numbered progress bar for a multi-step form such as an import wizard
 #[component]
-pub fn Steps() -> Element {
-   rsx!{
-    div {
-        h2 { "Everything should be this easy." }
-    }
+pub fn Steps(props: StepsProps) -> Element {
+    let class = format!("flex w-full items-center text-sm font-medium {}", props.class.clone().unwrap_or_default());
+    let last = props.steps.len().saturating_sub(1);
+    let circle = "flex h-7 w-7 shrink-0 items-center justify-center rounded-full";
 
-    div { class: " grid grid-cols-3 gap-4 w-full",
-        Card {
-            image_src: Some(HOME_SVG.to_string()), // Ensure Card supports `image_src`
-            image_alt: Some("Card header image".to_string()),
-            CardTitle { "Noteworthy technology acquisitions 2021" }
-            CardBody {
-                "Lorem ipsum dolor sit amet consectetur. Adipiscing imperdiet bibendum in in vestibulum. "
+    rsx! {
+        ol { class: "{class}",
+            for (i , label) in props.steps.iter().enumerate() {
+                li {
+                    key: "{i}",
+                    class: if i < last { "flex flex-1 items-center after:mx-4 after:h-px after:flex-1 after:bg-gray-300" } else { "flex items-center" },
+                    if i < props.current {
+                        span { class: "{circle} bg-green-600 text-white", "✓" }
+                    } else if i == props.current {
+                        span { class: "{circle} bg-blue-600 text-white", "{i + 1}" }
+                    } else {
+                        span { class: "{circle} bg-gray-200 text-gray-600", "{i + 1}" }
+                    }
+                    span {
+                        class: if i == props.current { "ml-2 text-blue-700" } else { "ml-2 text-gray-600" },
+                        "{label}"
+                    }
+                }
             }
         }
-        Card {
-            CardTitle { "Need to Remove <a>" }
-            CardBody { "Content for the second card." }
-        }
-        Card {
-            CardTitle { "Need to Remove <a>" }
-            CardBody { "Content for the third card." }
-        }
     }
 }
-}
\ No newline at end of file
@@ -311,14 +311,30 @@ pub fn ToastFrame(props: ToastFrameProps) -> Element {
     }
 }
 
+/// Escapes text for a toast's HTML body, which is rendered as markup
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
 // Default desktop implementation
-async fn time_sleep(millis: u64) {
+pub(crate) async fn time_sleep(millis: u64) {
     tokio::time::sleep(tokio::time::Duration::from_millis(millis)).await;
 }
 
 // Web override when web feature is enabled
 #[cfg(feature = "web")]
-async fn time_sleep(millis: u64) {
+pub(crate) async fn time_sleep(millis: u64) {
     use wasm_bindgen_futures::JsFuture;
     use web_sys::window;
 
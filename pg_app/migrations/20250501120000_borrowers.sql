@@ -0,0 +1,53 @@
+-- Borrowers and their liabilities (credit tradelines)
+CREATE TABLE borrowers (
+    id SERIAL PRIMARY KEY,
+    first_name VARCHAR(100) NOT NULL CHECK (first_name <> ''),
+    last_name VARCHAR(100) NOT NULL CHECK (last_name <> ''),
+    email VARCHAR(255),
+    phone VARCHAR(30),
+    -- Gross monthly income in cents, used for DTI
+    monthly_income_cents BIGINT NOT NULL DEFAULT 0 CHECK (monthly_income_cents >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_borrowers_last_name ON borrowers(last_name);
+
+CREATE TYPE liability_type AS ENUM ('auto', 'student', 'revolving', 'mortgage', 'installment', 'other');
+
+CREATE TABLE liabilities (
+    id SERIAL PRIMARY KEY,
+    borrower_id INTEGER NOT NULL REFERENCES borrowers(id) ON DELETE CASCADE,
+    liability_type liability_type NOT NULL,
+    creditor_name VARCHAR(100) NOT NULL CHECK (creditor_name <> ''),
+    account_last4 VARCHAR(4),
+    balance_cents BIGINT NOT NULL DEFAULT 0 CHECK (balance_cents >= 0),
+    monthly_payment_cents BIGINT NOT NULL DEFAULT 0 CHECK (monthly_payment_cents >= 0),
+    paid_off_at_closing BOOLEAN NOT NULL DEFAULT false,
+    excluded BOOLEAN NOT NULL DEFAULT false,
+    -- 'manual' or 'credit_report'
+    source VARCHAR(20) NOT NULL DEFAULT 'manual',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_liabilities_borrower ON liabilities(borrower_id);
+
+-- Shared trigger for automatic updated_at
+CREATE OR REPLACE FUNCTION set_updated_at()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_at = NOW();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER set_borrowers_updated_at
+BEFORE UPDATE ON borrowers
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TRIGGER set_liabilities_updated_at
+BEFORE UPDATE ON liabilities
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
@@ -0,0 +1,63 @@
+-- Loans and their subject properties
+CREATE TYPE loan_status AS ENUM (
+    'lead', 'application', 'processing', 'underwriting', 'approved',
+    'clear_to_close', 'closed', 'denied', 'withdrawn'
+);
+CREATE TYPE loan_type AS ENUM ('conventional', 'fha', 'va', 'usda', 'jumbo');
+CREATE TYPE loan_purpose AS ENUM ('purchase', 'refinance', 'cash_out_refinance');
+
+CREATE TABLE loans (
+    id SERIAL PRIMARY KEY,
+    loan_number VARCHAR(30) UNIQUE,
+    borrower_id INTEGER NOT NULL REFERENCES borrowers(id) ON DELETE CASCADE,
+    loan_officer_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    status loan_status NOT NULL DEFAULT 'lead',
+    loan_type loan_type NOT NULL DEFAULT 'conventional',
+    loan_purpose loan_purpose NOT NULL DEFAULT 'purchase',
+    amount_cents BIGINT NOT NULL CHECK (amount_cents > 0),
+    -- Note rate as a percentage, e.g. 6.875
+    note_rate DOUBLE PRECISION NOT NULL CHECK (note_rate >= 0),
+    term_months INTEGER NOT NULL DEFAULT 360 CHECK (term_months > 0),
+    application_date DATE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_loans_borrower ON loans(borrower_id);
+CREATE INDEX idx_loans_loan_officer ON loans(loan_officer_id);
+CREATE INDEX idx_loans_status ON loans(status);
+
+CREATE TRIGGER set_loans_updated_at
+BEFORE UPDATE ON loans
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TYPE occupancy_type AS ENUM ('primary_residence', 'second_home', 'investment');
+CREATE TYPE property_type AS ENUM (
+    'single_family', 'condominium', 'townhouse', 'two_to_four_unit', 'manufactured', 'pud'
+);
+
+CREATE TABLE properties (
+    id SERIAL PRIMARY KEY,
+    -- One subject property per loan
+    loan_id INTEGER NOT NULL UNIQUE REFERENCES loans(id) ON DELETE CASCADE,
+    street VARCHAR(200) NOT NULL CHECK (street <> ''),
+    city VARCHAR(100) NOT NULL CHECK (city <> ''),
+    state CHAR(2) NOT NULL CHECK (state ~ '^[A-Z]{2}$'),
+    zip VARCHAR(10) NOT NULL CHECK (zip ~ '^[0-9]{5}(-[0-9]{4})?$'),
+    occupancy occupancy_type NOT NULL DEFAULT 'primary_residence',
+    property_type property_type NOT NULL DEFAULT 'single_family',
+    units INTEGER NOT NULL DEFAULT 1 CHECK (units BETWEEN 1 AND 4),
+    purchase_price_cents BIGINT CHECK (purchase_price_cents > 0),
+    appraised_value_cents BIGINT CHECK (appraised_value_cents > 0),
+    estimated_value_cents BIGINT CHECK (estimated_value_cents > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_properties_state ON properties(state);
+
+CREATE TRIGGER set_properties_updated_at
+BEFORE UPDATE ON properties
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
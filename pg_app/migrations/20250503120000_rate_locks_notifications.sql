@@ -0,0 +1,57 @@
+-- Rate locks with extension history, and in-app notifications
+CREATE TYPE rate_lock_status AS ENUM ('active', 'expired', 'cancelled');
+
+CREATE TABLE rate_locks (
+    id SERIAL PRIMARY KEY,
+    loan_id INTEGER NOT NULL REFERENCES loans(id) ON DELETE CASCADE,
+    -- Locked rate as a percentage, e.g. 6.875
+    rate DOUBLE PRECISION NOT NULL CHECK (rate >= 0),
+    -- Discount points (negative for lender credits)
+    points DOUBLE PRECISION NOT NULL DEFAULT 0,
+    lock_date DATE NOT NULL,
+    lock_period_days INTEGER NOT NULL CHECK (lock_period_days > 0),
+    expiration_date DATE NOT NULL,
+    status rate_lock_status NOT NULL DEFAULT 'active',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (expiration_date >= lock_date)
+);
+
+CREATE INDEX idx_rate_locks_loan ON rate_locks(loan_id);
+CREATE INDEX idx_rate_locks_expiration ON rate_locks(expiration_date) WHERE status = 'active';
+
+CREATE TRIGGER set_rate_locks_updated_at
+BEFORE UPDATE ON rate_locks
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TABLE rate_lock_extensions (
+    id SERIAL PRIMARY KEY,
+    rate_lock_id INTEGER NOT NULL REFERENCES rate_locks(id) ON DELETE CASCADE,
+    extension_days INTEGER NOT NULL CHECK (extension_days > 0),
+    previous_expiration DATE NOT NULL,
+    new_expiration DATE NOT NULL,
+    -- Price of the extension in points
+    cost_points DOUBLE PRECISION NOT NULL DEFAULT 0,
+    reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_rate_lock_extensions_lock ON rate_lock_extensions(rate_lock_id);
+
+CREATE TABLE notifications (
+    id SERIAL PRIMARY KEY,
+    -- NULL means the notification is for everyone
+    user_id INTEGER REFERENCES users(id) ON DELETE CASCADE,
+    kind VARCHAR(50) NOT NULL,
+    title VARCHAR(200) NOT NULL,
+    message TEXT NOT NULL,
+    loan_id INTEGER REFERENCES loans(id) ON DELETE CASCADE,
+    -- Identifies what raised the notification so background checks do not repeat it
+    reference_key VARCHAR(200) UNIQUE,
+    read_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_notifications_user ON notifications(user_id);
+CREATE INDEX idx_notifications_unread ON notifications(user_id) WHERE read_at IS NULL;
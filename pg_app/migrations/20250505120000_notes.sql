@@ -0,0 +1,38 @@
+-- Threaded notes on loans and borrowers, with edit history
+CREATE TABLE notes (
+    id SERIAL PRIMARY KEY,
+    loan_id INTEGER REFERENCES loans(id) ON DELETE CASCADE,
+    borrower_id INTEGER REFERENCES borrowers(id) ON DELETE CASCADE,
+    -- Replies point at the note they answer; top-level notes have no parent
+    parent_id INTEGER REFERENCES notes(id) ON DELETE CASCADE,
+    author_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    -- Markdown source
+    body TEXT NOT NULL CHECK (body <> ''),
+    pinned BOOLEAN NOT NULL DEFAULT false,
+    -- Internal notes are never shown in borrower-facing views
+    internal_only BOOLEAN NOT NULL DEFAULT true,
+    edited_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (num_nonnulls(loan_id, borrower_id) = 1)
+);
+
+CREATE INDEX idx_notes_loan ON notes(loan_id);
+CREATE INDEX idx_notes_borrower ON notes(borrower_id);
+CREATE INDEX idx_notes_parent ON notes(parent_id);
+
+CREATE TRIGGER set_notes_updated_at
+BEFORE UPDATE ON notes
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TABLE note_edits (
+    id SERIAL PRIMARY KEY,
+    note_id INTEGER NOT NULL REFERENCES notes(id) ON DELETE CASCADE,
+    -- Body as it was before the edit
+    previous_body TEXT NOT NULL,
+    edited_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    edited_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_note_edits_note ON note_edits(note_id);
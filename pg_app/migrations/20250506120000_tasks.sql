@@ -0,0 +1,43 @@
+-- Loan tasks with checklists, and a manager role that can reassign them
+ALTER TYPE user_role ADD VALUE IF NOT EXISTS 'manager';
+
+CREATE TYPE task_priority AS ENUM ('low', 'normal', 'high', 'urgent');
+CREATE TYPE task_status AS ENUM ('open', 'in_progress', 'blocked', 'completed', 'cancelled');
+
+CREATE TABLE tasks (
+    id SERIAL PRIMARY KEY,
+    loan_id INTEGER NOT NULL REFERENCES loans(id) ON DELETE CASCADE,
+    title VARCHAR(200) NOT NULL CHECK (title <> ''),
+    description TEXT,
+    assignee_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    due_date DATE,
+    priority task_priority NOT NULL DEFAULT 'normal',
+    status task_status NOT NULL DEFAULT 'open',
+    completed_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    completed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((status = 'completed') = (completed_at IS NOT NULL))
+);
+
+CREATE INDEX idx_tasks_loan ON tasks(loan_id);
+CREATE INDEX idx_tasks_assignee_open ON tasks(assignee_id, due_date) WHERE status NOT IN ('completed', 'cancelled');
+
+CREATE TRIGGER set_tasks_updated_at
+BEFORE UPDATE ON tasks
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TABLE task_checklist_items (
+    id SERIAL PRIMARY KEY,
+    task_id INTEGER NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    label VARCHAR(200) NOT NULL CHECK (label <> ''),
+    position INTEGER NOT NULL DEFAULT 0,
+    is_done BOOLEAN NOT NULL DEFAULT false,
+    done_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    done_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_task_checklist_items_task ON task_checklist_items(task_id);
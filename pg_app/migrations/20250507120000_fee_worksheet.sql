@@ -0,0 +1,42 @@
+-- Closing cost worksheet: fees by TRID section plus deposits and credits
+CREATE TYPE fee_section AS ENUM (
+    'origination',
+    'cannot_shop',
+    'can_shop',
+    'taxes_government',
+    'prepaids',
+    'initial_escrow'
+);
+
+CREATE TYPE fee_payer AS ENUM ('borrower', 'seller', 'lender', 'other');
+
+CREATE TABLE loan_fees (
+    id SERIAL PRIMARY KEY,
+    loan_id INTEGER NOT NULL REFERENCES loans(id) ON DELETE CASCADE,
+    section fee_section NOT NULL,
+    name VARCHAR(200) NOT NULL CHECK (name <> ''),
+    amount_cents BIGINT NOT NULL CHECK (amount_cents >= 0),
+    payer fee_payer NOT NULL DEFAULT 'borrower',
+    paid_to VARCHAR(200),
+    -- Counts toward the TILA finance charge (and so the APR)
+    finance_charge BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_loan_fees_loan ON loan_fees(loan_id);
+
+CREATE TRIGGER set_loan_fees_updated_at
+BEFORE UPDATE ON loan_fees
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+-- One row per loan; amounts that feed cash to close but are not fees
+CREATE TABLE closing_adjustments (
+    loan_id INTEGER PRIMARY KEY REFERENCES loans(id) ON DELETE CASCADE,
+    earnest_money_cents BIGINT NOT NULL DEFAULT 0 CHECK (earnest_money_cents >= 0),
+    seller_credits_cents BIGINT NOT NULL DEFAULT 0 CHECK (seller_credits_cents >= 0),
+    lender_credits_cents BIGINT NOT NULL DEFAULT 0 CHECK (lender_credits_cents >= 0),
+    -- Existing liens paid off by a refinance
+    payoffs_cents BIGINT NOT NULL DEFAULT 0 CHECK (payoffs_cents >= 0)
+);
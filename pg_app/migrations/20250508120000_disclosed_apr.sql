@@ -0,0 +1,2 @@
+-- APR last disclosed to the borrower, compared against the worksheet APR
+ALTER TABLE closing_adjustments ADD COLUMN disclosed_apr DOUBLE PRECISION;
@@ -0,0 +1,28 @@
+-- Private mortgage insurance rate table, keyed by LTV band and credit score band
+CREATE TABLE pmi_rates (
+    id SERIAL PRIMARY KEY,
+    ltv_min DOUBLE PRECISION NOT NULL,
+    ltv_max DOUBLE PRECISION NOT NULL,
+    credit_score_min INTEGER NOT NULL,
+    credit_score_max INTEGER NOT NULL,
+    -- Annual premium as a percentage of the loan amount
+    annual_rate DOUBLE PRECISION NOT NULL CHECK (annual_rate >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ltv_min <= ltv_max),
+    CHECK (credit_score_min <= credit_score_max)
+);
+
+CREATE INDEX idx_pmi_rates_bands ON pmi_rates(ltv_min, credit_score_min);
+
+CREATE TRIGGER set_pmi_rates_updated_at
+BEFORE UPDATE ON pmi_rates
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+-- Starting grid; managers adjust it from Settings
+INSERT INTO pmi_rates (ltv_min, ltv_max, credit_score_min, credit_score_max, annual_rate) VALUES
+    (80.01, 85.00, 760, 850, 0.17), (80.01, 85.00, 720, 759, 0.22), (80.01, 85.00, 680, 719, 0.33), (80.01, 85.00, 620, 679, 0.52),
+    (85.01, 90.00, 760, 850, 0.30), (85.01, 90.00, 720, 759, 0.41), (85.01, 90.00, 680, 719, 0.59), (85.01, 90.00, 620, 679, 0.96),
+    (90.01, 95.00, 760, 850, 0.41), (90.01, 95.00, 720, 759, 0.55), (90.01, 95.00, 680, 719, 0.78), (90.01, 95.00, 620, 679, 1.25),
+    (95.01, 97.00, 760, 850, 0.58), (95.01, 97.00, 720, 759, 0.76), (95.01, 97.00, 680, 719, 1.05), (95.01, 97.00, 620, 679, 1.67);
@@ -0,0 +1,26 @@
+-- Adjustable-rate terms; a loan with a row here is an ARM
+CREATE TYPE arm_index AS ENUM ('sofr_30_day', 'treasury_1_year', 'prime', 'other');
+
+CREATE TABLE arm_terms (
+    loan_id INTEGER PRIMARY KEY REFERENCES loans(id) ON DELETE CASCADE,
+    -- Months at the start rate before the first adjustment (60 for a 5/6 ARM)
+    initial_fixed_months INTEGER NOT NULL CHECK (initial_fixed_months > 0),
+    -- Months between later adjustments (6 for a 5/6 ARM)
+    adjustment_interval_months INTEGER NOT NULL CHECK (adjustment_interval_months > 0),
+    rate_index arm_index NOT NULL DEFAULT 'sofr_30_day',
+    -- Current index value, the starting point for what-if projections
+    index_value DOUBLE PRECISION NOT NULL DEFAULT 0,
+    margin DOUBLE PRECISION NOT NULL CHECK (margin >= 0),
+    initial_cap DOUBLE PRECISION NOT NULL CHECK (initial_cap >= 0),
+    periodic_cap DOUBLE PRECISION NOT NULL CHECK (periodic_cap >= 0),
+    lifetime_cap DOUBLE PRECISION NOT NULL CHECK (lifetime_cap >= 0),
+    -- Lowest rate allowed; NULL means the margin
+    floor_rate DOUBLE PRECISION CHECK (floor_rate >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER set_arm_terms_updated_at
+BEFORE UPDATE ON arm_terms
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
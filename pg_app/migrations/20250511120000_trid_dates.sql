@@ -0,0 +1,23 @@
+-- TRID disclosure dates; the application date lives on loans
+CREATE TYPE disclosure_delivery AS ENUM ('in_person', 'mail', 'electronic');
+
+CREATE TABLE trid_dates (
+    loan_id INTEGER PRIMARY KEY REFERENCES loans(id) ON DELETE CASCADE,
+    le_delivered_date DATE,
+    cd_delivered_date DATE,
+    cd_delivery_method disclosure_delivery NOT NULL DEFAULT 'electronic',
+    -- Confirmed receipt; when NULL, mailed and emailed disclosures are
+    -- presumed received three specific business days after delivery
+    cd_received_date DATE,
+    consummation_date DATE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (cd_received_date IS NULL OR cd_delivered_date IS NULL OR cd_received_date >= cd_delivered_date)
+);
+
+CREATE INDEX idx_trid_dates_consummation ON trid_dates(consummation_date);
+
+CREATE TRIGGER set_trid_dates_updated_at
+BEFORE UPDATE ON trid_dates
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
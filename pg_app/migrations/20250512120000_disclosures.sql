@@ -0,0 +1,31 @@
+-- Section C fees for a provider the borrower found off the lender's list have no tolerance limit
+ALTER TABLE loan_fees ADD COLUMN off_list_provider BOOLEAN NOT NULL DEFAULT false;
+
+-- Issued Loan Estimates and Closing Disclosures with the fee worksheet frozen at issue
+CREATE TYPE disclosure_kind AS ENUM ('loan_estimate', 'closing_disclosure');
+
+CREATE TABLE disclosures (
+    id SERIAL PRIMARY KEY,
+    loan_id INTEGER NOT NULL REFERENCES loans(id) ON DELETE CASCADE,
+    kind disclosure_kind NOT NULL,
+    issued_date DATE NOT NULL,
+    issued_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_disclosures_loan ON disclosures(loan_id);
+
+-- Copies of loan_fees rows; never updated once written
+CREATE TABLE disclosure_fees (
+    id SERIAL PRIMARY KEY,
+    disclosure_id INTEGER NOT NULL REFERENCES disclosures(id) ON DELETE CASCADE,
+    section fee_section NOT NULL,
+    name VARCHAR(200) NOT NULL,
+    amount_cents BIGINT NOT NULL CHECK (amount_cents >= 0),
+    payer fee_payer NOT NULL,
+    paid_to VARCHAR(200),
+    finance_charge BOOLEAN NOT NULL,
+    off_list_provider BOOLEAN NOT NULL
+);
+
+CREATE INDEX idx_disclosure_fees_disclosure ON disclosure_fees(disclosure_id);
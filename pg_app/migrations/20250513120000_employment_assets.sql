@@ -0,0 +1,67 @@
+-- Applicant data carried by Fannie Mae 3.2 files: SSN, employment, assets and co-applicants
+-- Nine digits without dashes; with the last name it identifies a borrower across imports
+ALTER TABLE borrowers ADD COLUMN ssn VARCHAR(9) CHECK (ssn ~ '^[0-9]{9}$');
+
+CREATE INDEX idx_borrowers_ssn ON borrowers(ssn);
+
+CREATE TABLE employments (
+    id SERIAL PRIMARY KEY,
+    borrower_id INTEGER NOT NULL REFERENCES borrowers(id) ON DELETE CASCADE,
+    employer_name VARCHAR(100) NOT NULL CHECK (employer_name <> ''),
+    position VARCHAR(100),
+    self_employed BOOLEAN NOT NULL DEFAULT false,
+    -- The primary current job; income for it is on borrowers.monthly_income_cents
+    is_primary BOOLEAN NOT NULL DEFAULT false,
+    is_current BOOLEAN NOT NULL DEFAULT true,
+    start_date DATE,
+    end_date DATE,
+    months_on_job INTEGER CHECK (months_on_job >= 0),
+    years_in_profession INTEGER CHECK (years_in_profession >= 0),
+    -- Monthly income from secondary or previous jobs, in cents
+    monthly_income_cents BIGINT NOT NULL DEFAULT 0 CHECK (monthly_income_cents >= 0),
+    -- 'manual' or 'fnm'
+    source VARCHAR(20) NOT NULL DEFAULT 'manual',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_employments_borrower ON employments(borrower_id);
+
+CREATE TRIGGER set_employments_updated_at
+BEFORE UPDATE ON employments
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TYPE asset_type AS ENUM (
+    'checking', 'savings', 'certificate_of_deposit', 'money_market', 'stocks', 'bonds', 'retirement', 'gift', 'other'
+);
+
+CREATE TABLE assets (
+    id SERIAL PRIMARY KEY,
+    borrower_id INTEGER NOT NULL REFERENCES borrowers(id) ON DELETE CASCADE,
+    asset_type asset_type NOT NULL,
+    institution_name VARCHAR(100) NOT NULL CHECK (institution_name <> ''),
+    account_last4 VARCHAR(4),
+    balance_cents BIGINT NOT NULL DEFAULT 0 CHECK (balance_cents >= 0),
+    -- 'manual' or 'fnm'
+    source VARCHAR(20) NOT NULL DEFAULT 'manual',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_assets_borrower ON assets(borrower_id);
+
+CREATE TRIGGER set_assets_updated_at
+BEFORE UPDATE ON assets
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+-- Co-applicants on a loan; the primary applicant stays on loans.borrower_id
+CREATE TABLE loan_co_borrowers (
+    loan_id INTEGER NOT NULL REFERENCES loans(id) ON DELETE CASCADE,
+    borrower_id INTEGER NOT NULL REFERENCES borrowers(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (loan_id, borrower_id)
+);
+
+CREATE INDEX idx_loan_co_borrowers_borrower ON loan_co_borrowers(borrower_id);
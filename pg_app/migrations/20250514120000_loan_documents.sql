@@ -0,0 +1,16 @@
+-- PDFs generated from a loan (pre-approval letters, summaries, amortization schedules)
+CREATE TYPE document_kind AS ENUM ('pre_approval_letter', 'loan_summary', 'amortization_schedule');
+
+CREATE TABLE loan_documents (
+    id SERIAL PRIMARY KEY,
+    loan_id INTEGER NOT NULL REFERENCES loans(id) ON DELETE CASCADE,
+    kind document_kind NOT NULL,
+    file_name VARCHAR(200) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    content BYTEA NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    created_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_loan_documents_loan ON loan_documents(loan_id);
@@ -0,0 +1,19 @@
+-- Company-wide loan number format; always exactly one row
+CREATE TABLE loan_number_settings (
+    id BOOLEAN PRIMARY KEY DEFAULT true CHECK (id),
+    format VARCHAR(100) NOT NULL,
+    branch_code VARCHAR(10) NOT NULL,
+    updated_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO loan_number_settings (format, branch_code) VALUES ('{BRANCH}-{YYYY}-{SEQ:6}', 'MAIN');
+
+-- One Postgres sequence per loan number prefix, named loan_number_seq_<id>.
+-- The sequence is created in the same transaction as its row, so a
+-- concurrent create of the same prefix waits on the unique key and reuses it.
+CREATE TABLE loan_number_sequences (
+    id SERIAL PRIMARY KEY,
+    prefix VARCHAR(100) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,21 @@
+-- NMLS unique identifier of each loan officer
+ALTER TABLE users ADD COLUMN nmls_id VARCHAR(12);
+
+-- State licenses to originate; one per officer and state, renewed in place
+CREATE TABLE loan_officer_licenses (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    state CHAR(2) NOT NULL,
+    license_number VARCHAR(50) NOT NULL,
+    expires_on DATE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, state)
+);
+
+CREATE INDEX idx_loan_officer_licenses_expires ON loan_officer_licenses(expires_on);
+
+CREATE TRIGGER set_loan_officer_licenses_updated_at
+BEFORE UPDATE ON loan_officer_licenses
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
@@ -0,0 +1,63 @@
+-- HMDA reporting: per-loan LAR fields and the filer's transmittal sheet.
+-- Coded columns hold the codes from the FFIEC Filing Instructions Guide.
+CREATE TABLE loan_hmda (
+    loan_id INTEGER PRIMARY KEY REFERENCES loans(id) ON DELETE CASCADE,
+    action_taken SMALLINT CHECK (action_taken BETWEEN 1 AND 8),
+    action_taken_date DATE,
+    preapproval SMALLINT NOT NULL DEFAULT 2,
+    county VARCHAR(5) CHECK (county ~ '^[0-9]{5}$'),
+    census_tract VARCHAR(11) CHECK (census_tract ~ '^[0-9]{11}$'),
+    applicant_ethnicities SMALLINT[] NOT NULL DEFAULT '{}',
+    applicant_ethnicity_observed SMALLINT,
+    applicant_races SMALLINT[] NOT NULL DEFAULT '{}',
+    applicant_race_observed SMALLINT,
+    applicant_sex SMALLINT,
+    applicant_sex_observed SMALLINT,
+    applicant_age INTEGER CHECK (applicant_age > 0),
+    applicant_credit_score INTEGER,
+    applicant_credit_model SMALLINT,
+    co_applicant_ethnicities SMALLINT[] NOT NULL DEFAULT '{}',
+    co_applicant_ethnicity_observed SMALLINT,
+    co_applicant_races SMALLINT[] NOT NULL DEFAULT '{}',
+    co_applicant_race_observed SMALLINT,
+    co_applicant_sex SMALLINT,
+    co_applicant_sex_observed SMALLINT,
+    co_applicant_age INTEGER CHECK (co_applicant_age > 0),
+    co_applicant_credit_score INTEGER,
+    co_applicant_credit_model SMALLINT,
+    denial_reasons SMALLINT[] NOT NULL DEFAULT '{}',
+    -- Percentages, e.g. 1.375
+    rate_spread DOUBLE PRECISION,
+    hoepa_status SMALLINT NOT NULL DEFAULT 2,
+    lien_status SMALLINT NOT NULL DEFAULT 1,
+    purchaser_type SMALLINT NOT NULL DEFAULT 0,
+    debt_to_income DOUBLE PRECISION CHECK (debt_to_income >= 0),
+    updated_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((action_taken IS NULL) = (action_taken_date IS NULL))
+);
+
+CREATE INDEX idx_loan_hmda_action_taken_date ON loan_hmda(action_taken_date);
+
+CREATE TRIGGER set_loan_hmda_updated_at
+BEFORE UPDATE ON loan_hmda
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+-- The reporting institution; at most one row, added when first saved
+CREATE TABLE hmda_filer (
+    id BOOLEAN PRIMARY KEY DEFAULT true CHECK (id),
+    lei CHAR(20) NOT NULL,
+    institution_name VARCHAR(200) NOT NULL,
+    tax_id VARCHAR(10) NOT NULL,
+    federal_agency SMALLINT NOT NULL,
+    contact_name VARCHAR(100) NOT NULL,
+    contact_phone VARCHAR(12) NOT NULL,
+    contact_email VARCHAR(255) NOT NULL,
+    contact_street VARCHAR(200) NOT NULL,
+    contact_city VARCHAR(100) NOT NULL,
+    contact_state CHAR(2) NOT NULL,
+    contact_zip VARCHAR(10) NOT NULL,
+    updated_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,26 @@
+-- Adverse action notices for denied applications (ECOA, Regulation B)
+ALTER TYPE document_kind ADD VALUE 'adverse_action_notice';
+
+CREATE TABLE adverse_actions (
+    loan_id INTEGER PRIMARY KEY REFERENCES loans(id) ON DELETE CASCADE,
+    -- HMDA denial reason codes, principal reason first
+    reasons SMALLINT[] NOT NULL CHECK (cardinality(reasons) BETWEEN 1 AND 4),
+    other_reason VARCHAR(200),
+    decided_date DATE NOT NULL,
+    decided_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    document_id INTEGER REFERENCES loan_documents(id) ON DELETE SET NULL,
+    delivered_date DATE,
+    delivery_method disclosure_delivery,
+    delivered_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((delivered_date IS NULL) = (delivery_method IS NULL))
+);
+
+-- The dashboard's compliance list looks for notices not yet delivered
+CREATE INDEX idx_adverse_actions_undelivered ON adverse_actions(decided_date) WHERE delivered_date IS NULL;
+
+CREATE TRIGGER set_adverse_actions_updated_at
+BEFORE UPDATE ON adverse_actions
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
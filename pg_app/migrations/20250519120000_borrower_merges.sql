@@ -0,0 +1,58 @@
+-- Date of birth, one of the fields duplicate borrowers are matched on
+ALTER TABLE borrowers ADD COLUMN date_of_birth DATE;
+
+-- A borrower merged into another stays in the table, hidden from lists,
+-- so the merge can be undone
+ALTER TABLE borrowers ADD COLUMN merged_into INTEGER REFERENCES borrowers(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_borrowers_active ON borrowers(last_name, first_name) WHERE merged_into IS NULL;
+
+-- Append-only log of sensitive actions
+CREATE TYPE audit_action AS ENUM ('borrower_merge', 'borrower_merge_undo');
+
+CREATE TABLE audit_log (
+    id SERIAL PRIMARY KEY,
+    action audit_action NOT NULL,
+    actor_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    -- What the action was taken on, e.g. 'borrower' and its id
+    entity VARCHAR(30) NOT NULL,
+    entity_id INTEGER NOT NULL,
+    detail TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_audit_log_entity ON audit_log(entity, entity_id, created_at);
+
+-- One merge of a duplicate borrower into the surviving record, with
+-- everything needed to undo it
+CREATE TABLE borrower_merges (
+    id SERIAL PRIMARY KEY,
+    survivor_id INTEGER NOT NULL REFERENCES borrowers(id) ON DELETE CASCADE,
+    merged_id INTEGER NOT NULL REFERENCES borrowers(id) ON DELETE CASCADE,
+    -- Fields the survivor took from the merged record (MergeField codes)
+    taken_fields SMALLINT[] NOT NULL DEFAULT '{}',
+    -- The survivor's values before the merge
+    prior_first_name VARCHAR(100) NOT NULL,
+    prior_last_name VARCHAR(100) NOT NULL,
+    prior_email VARCHAR(255),
+    prior_phone VARCHAR(30),
+    prior_date_of_birth DATE,
+    prior_ssn VARCHAR(9),
+    prior_monthly_income_cents BIGINT NOT NULL,
+    -- Rows moved from the merged borrower to the survivor
+    loan_ids INTEGER[] NOT NULL DEFAULT '{}',
+    co_borrower_loan_ids INTEGER[] NOT NULL DEFAULT '{}',
+    note_ids INTEGER[] NOT NULL DEFAULT '{}',
+    liability_ids INTEGER[] NOT NULL DEFAULT '{}',
+    employment_ids INTEGER[] NOT NULL DEFAULT '{}',
+    asset_ids INTEGER[] NOT NULL DEFAULT '{}',
+    merged_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    merged_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    undone_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    undone_at TIMESTAMPTZ,
+    CHECK (survivor_id <> merged_id)
+);
+
+CREATE INDEX idx_borrower_merges_merged_at ON borrower_merges(merged_at DESC);
+CREATE INDEX idx_borrower_merges_survivor ON borrower_merges(survivor_id) WHERE undone_at IS NULL;
+CREATE INDEX idx_borrower_merges_merged ON borrower_merges(merged_id) WHERE undone_at IS NULL;
@@ -0,0 +1,22 @@
+-- SSNs, dates of birth and account numbers are sealed by the application
+-- (see shared::crypto) and stored as 'v{key version}:{base64}' text.
+-- Existing values stay readable as plain text until `reencrypt_pii` seals
+-- them and fills in the blind indexes.
+ALTER TABLE borrowers DROP CONSTRAINT IF EXISTS borrowers_ssn_check;
+ALTER TABLE borrowers ALTER COLUMN ssn TYPE TEXT;
+ALTER TABLE borrowers ALTER COLUMN date_of_birth TYPE TEXT USING to_char(date_of_birth, 'YYYY-MM-DD');
+
+-- A sealed SSN cannot be searched; exact matches use an HMAC of the digits
+DROP INDEX IF EXISTS idx_borrowers_ssn;
+ALTER TABLE borrowers ADD COLUMN ssn_index BYTEA;
+CREATE INDEX idx_borrowers_ssn_index ON borrowers(ssn_index);
+
+-- Full account number of an asset, alongside the last four shown in lists
+ALTER TABLE assets ADD COLUMN account_number TEXT;
+ALTER TABLE assets ADD COLUMN account_number_index BYTEA;
+CREATE INDEX idx_assets_account_number_index ON assets(account_number_index);
+
+-- Values kept to undo a merge are sealed the same way
+ALTER TABLE borrower_merges ALTER COLUMN prior_ssn TYPE TEXT;
+ALTER TABLE borrower_merges ALTER COLUMN prior_date_of_birth TYPE TEXT
+    USING to_char(prior_date_of_birth, 'YYYY-MM-DD');
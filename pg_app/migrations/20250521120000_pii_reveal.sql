@@ -0,0 +1,2 @@
+-- Each reveal of a masked SSN, date of birth or account number is audited
+ALTER TYPE audit_action ADD VALUE 'pii_reveal';
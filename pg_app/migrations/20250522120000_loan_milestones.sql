@@ -0,0 +1,49 @@
+-- Status timelines: every loan's history starts with the status it was
+-- opened in, and each status can have an SLA target in business days
+
+CREATE OR REPLACE FUNCTION record_opening_status()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO loan_status_history (loan_id, from_status, to_status, changed_at)
+    VALUES (NEW.id, NULL, NEW.status, NEW.created_at);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER record_loans_opening_status
+    AFTER INSERT ON loans
+    FOR EACH ROW EXECUTE FUNCTION record_opening_status();
+
+-- Existing loans were opened in the status their first recorded move left
+INSERT INTO loan_status_history (loan_id, from_status, to_status, changed_at)
+SELECT
+    l.id,
+    NULL,
+    COALESCE(
+        (SELECT h.from_status FROM loan_status_history h
+         WHERE h.loan_id = l.id
+         ORDER BY h.changed_at, h.id
+         LIMIT 1),
+        l.status
+    ),
+    l.created_at
+FROM loans l
+WHERE NOT EXISTS (
+    SELECT 1 FROM loan_status_history h WHERE h.loan_id = l.id AND h.from_status IS NULL
+);
+
+CREATE INDEX idx_loan_status_history_changed_at ON loan_status_history(changed_at);
+
+CREATE TABLE status_sla_targets (
+    status loan_status PRIMARY KEY CHECK (status NOT IN ('closed', 'denied')),
+    business_days INTEGER NOT NULL CHECK (business_days BETWEEN 1 AND 90),
+    updated_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO status_sla_targets (status, business_days) VALUES
+    ('application', 3),
+    ('processing', 5),
+    ('underwriting', 5),
+    ('approved', 10),
+    ('clear_to_close', 3);
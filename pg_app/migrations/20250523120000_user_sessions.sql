@@ -0,0 +1,12 @@
+-- Server-side sessions: login_user hands out a random token in an HttpOnly
+-- cookie and only its SHA-256 is stored, so a leaked table cannot be replayed
+
+CREATE TABLE user_sessions (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    token_hash BYTEA NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_user_sessions_user ON user_sessions(user_id);
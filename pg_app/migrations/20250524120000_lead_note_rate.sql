@@ -0,0 +1,2 @@
+-- Leads imported from spreadsheets have no rate until one is quoted
+ALTER TABLE loans ALTER COLUMN note_rate DROP NOT NULL;
@@ -0,0 +1,20 @@
+-- Read receipts per user, so one user reading a notification addressed to
+-- everyone does not mark it read for the others
+CREATE TABLE notification_reads (
+    notification_id INTEGER NOT NULL REFERENCES notifications(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    read_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (notification_id, user_id)
+);
+
+CREATE INDEX idx_notification_reads_user ON notification_reads(user_id);
+
+-- Who read a notification for everyone was not recorded, so it stays read for all
+INSERT INTO notification_reads (notification_id, user_id, read_at)
+SELECT n.id, u.id, n.read_at
+FROM notifications n
+JOIN users u ON n.user_id IS NULL OR u.id = n.user_id
+WHERE n.read_at IS NOT NULL;
+
+DROP INDEX idx_notifications_unread;
+ALTER TABLE notifications DROP COLUMN read_at;
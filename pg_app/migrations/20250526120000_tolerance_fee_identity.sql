@@ -0,0 +1,28 @@
+-- Government recording fees share the 10% tolerance bucket; flagged rather
+-- than guessed from the fee name
+ALTER TABLE loan_fees ADD COLUMN recording_fee BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE disclosure_fees ADD COLUMN recording_fee BOOLEAN NOT NULL DEFAULT false;
+
+UPDATE loan_fees SET recording_fee = true
+WHERE section = 'taxes_government' AND name ILIKE '%record%';
+UPDATE disclosure_fees SET recording_fee = true
+WHERE section = 'taxes_government' AND name ILIKE '%record%';
+
+-- Worksheet line a snapshot was copied from, so a fee moved to another
+-- section between disclosures still matches itself. No foreign key: the
+-- snapshot outlives the worksheet line.
+ALTER TABLE disclosure_fees ADD COLUMN loan_fee_id INTEGER;
+
+UPDATE disclosure_fees df
+SET loan_fee_id = (
+    SELECT MIN(f.id)
+    FROM loan_fees f
+    JOIN disclosures d ON d.loan_id = f.loan_id
+    WHERE d.id = df.disclosure_id
+      AND f.section = df.section
+      AND LOWER(TRIM(f.name)) = LOWER(TRIM(df.name))
+);
+
+-- General lender credits at issue; a decrease is zero tolerance. Earlier
+-- snapshots did not record them and read as no credit on either side.
+ALTER TABLE disclosures ADD COLUMN lender_credits_cents BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,146 @@
+// pages/src/borrowers.rs
+use dioxus::{logger::tracing, prelude::*};
+use components::db::borrowers::{AddBorrower, ApplicantDetails, BorrowerImport, DuplicateBorrowers, Liabilities};
+use components::db::exports::ExportPanel;
+use components::db::loans::AddLoan;
+use components::ui::{Table, TableHead, TableBody, TableRow, TableCell, TableHeaderCell};
+use server::borrowers::{get_all_borrowers, get_borrower};
+use server::loans::get_borrower_loans;
+use shared::exports::{ExportList, ExportSort};
+use shared::models::NoteSubject;
+use shared::money::format_cents;
+use crate::layout::NotesPanel;
+use crate::routes::Route;
+
+/// Borrower list with the add form, spreadsheet import and export and the duplicate report, rendered at `[Route::Borrowers]`
+#[component]
+pub fn Borrowers() -> Element {
+    let mut borrowers = use_resource(|| async { get_all_borrowers().await });
+
+    let rows = match &*borrowers.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get all borrowers error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "container mx-auto px-4 py-8 flex flex-col gap-6",
+            AddBorrower { on_borrower_added: move |_| borrowers.restart() }
+            BorrowerImport { on_imported: move |_| borrowers.restart() }
+            ExportPanel {
+                list: ExportList::Borrowers,
+                columns: ["first_name", "last_name", "email", "phone", "monthly_income"].map(String::from).to_vec(),
+                sort: vec![ExportSort::ascending("last_name"), ExportSort::ascending("first_name")],
+            }
+            Table {
+                striped: true,
+                hoverable: true,
+                caption: rsx! { "Borrowers" },
+                TableHead {
+                    TableRow {
+                        TableHeaderCell { "Name" }
+                        TableHeaderCell { "Email" }
+                        TableHeaderCell { "Phone" }
+                        TableHeaderCell { "Monthly Income" }
+                        TableHeaderCell { "View" }
+                    }
+                }
+                TableBody {
+                    for borrower in rows {
+                        TableRow { key: "{borrower.id}",
+                            TableCell { {borrower.full_name()} }
+                            TableCell { {borrower.email.clone().unwrap_or_default()} }
+                            TableCell { {borrower.phone.clone().unwrap_or_default()} }
+                            TableCell { {format_cents(borrower.monthly_income_cents)} }
+                            TableCell {
+                                Link {
+                                    to: Route::BorrowerDetail { id: borrower.id },
+                                    class: "text-blue-600 hover:underline",
+                                    "View"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            DuplicateBorrowers { on_merged: move |_| borrowers.restart() }
+        }
+    }
+}
+
+/// Single borrower page, rendered at `[Route::BorrowerDetail]`
+#[component]
+pub fn BorrowerDetail(id: i32) -> Element {
+    let borrower = use_resource(move || async move { get_borrower(id).await });
+
+    match &*borrower.read() {
+        Some(Ok(borrower)) => rsx! {
+            div { class: "container mx-auto px-4 py-8 flex flex-col gap-6",
+                div {
+                    h2 { class: "text-2xl font-bold", {borrower.full_name()} }
+                    p { class: "text-gray-600",
+                        "Gross monthly income: "
+                        {format_cents(borrower.monthly_income_cents)}
+                    }
+                }
+                ApplicantDetails {
+                    borrower_id: borrower.id,
+                    ssn: borrower.ssn.clone(),
+                    date_of_birth: borrower.date_of_birth,
+                    pii_masked: borrower.pii_masked,
+                }
+                Liabilities {
+                    borrower_id: borrower.id,
+                    monthly_income_cents: borrower.monthly_income_cents,
+                }
+                BorrowerLoans { borrower_id: borrower.id }
+                NotesPanel { subject: NoteSubject::Borrower(borrower.id) }
+            }
+        },
+        Some(Err(err)) => rsx! {
+            div { class: "container mx-auto px-4 py-8 text-red-600", "Could not load borrower: {err}" }
+        },
+        None => rsx! {
+            div { class: "container mx-auto px-4 py-8", "Loading..." }
+        },
+    }
+}
+
+/// The borrower's loans with links to each loan page
+#[component]
+fn BorrowerLoans(borrower_id: i32) -> Element {
+    let mut loans = use_resource(move || async move { get_borrower_loans(borrower_id).await });
+
+    let rows = match &*loans.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            tracing::error!("get borrower loans error: {err}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg font-semibold", "Loans" }
+            ul {
+                for loan in rows {
+                    li { key: "{loan.id}",
+                        Link {
+                            to: Route::LoanDetail { id: loan.id },
+                            class: "text-blue-600 hover:underline",
+                            {loan.loan_number.clone().unwrap_or_else(|| format!("Loan #{}", loan.id))}
+                        }
+                        " · {loan.loan_type} · "
+                        {format_cents(loan.amount_cents)}
+                        " · {loan.status}"
+                    }
+                }
+            }
+            AddLoan { borrower_id, on_loan_added: move |_| loans.restart() }
+        }
+    }
+}
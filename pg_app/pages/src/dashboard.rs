@@ -1,56 +1,56 @@
 use dioxus::prelude::*;
 
-use components::ui::{Table, TableHead, TableBody, TableRow, TableCell, TableHeaderCell, TableFoot};
+use components::db::loans::{CycleTimePanel, ExpiringLocks, PendingNotices, SlaBreaches, TridAtRisk};
+use components::db::settings::ExpiringLicenses;
+use crate::routes::Route;
 
-/// The Home page component that will be rendered when the current route is `[Route::Home]`
+/// The Dashboard page component that will be rendered when the current route is `[Route::Dashboard]`
 #[component]
 pub fn Dashboard() -> Element {
+    let navigator = use_navigator();
+
     rsx! {
         div { class: "container mx-auto px-4 py-8",
             h2 { class: "text-2xl font-bold mb-6 text-center", "Good morning user" }
-            Table {
-                striped: true,
-                hoverable: true,
-                caption: rsx! { "Employee Information" },
-                TableHead {
-                    TableRow {
-                        TableHeaderCell { "Name" }
-                        TableHeaderCell { "Position" }
-                        TableHeaderCell { "Department" }
-                        TableHeaderCell { "Salary" }
-                    }
+            div { class: "mb-8",
+                ExpiringLocks {
+                    on_view: move |loan_id| {
+                        navigator.push(Route::LoanDetail { id: loan_id });
+                    },
+                }
+            }
+            div { class: "mb-8",
+                TridAtRisk {
+                    on_view: move |loan_id| {
+                        navigator.push(Route::LoanDetail { id: loan_id });
+                    },
                 }
-                TableBody {
-                    TableRow {
-                        TableCell { "John Doe" }
-                        TableCell { "Software Engineer" }
-                        TableCell { "Engineering" }
-                        TableCell { "$95,000" }
-                    }
-                    TableRow {
-                        TableCell { "Jane Smith" }
-                        TableCell { "Product Manager" }
-                        TableCell { "Product" }
-                        TableCell { "$105,000" }
-                    }
-                    TableRow {
-                        TableCell { "Bob Johnson" }
-                        TableCell { "UX Designer" }
-                        TableCell { "Design" }
-                        TableCell { "$85,000" }
-                    }
+            }
+            div { class: "mb-8",
+                SlaBreaches {
+                    on_view: move |loan_id| {
+                        navigator.push(Route::LoanDetail { id: loan_id });
+                    },
                 }
-                TableFoot {
-                    TableRow {
-                        TableHeaderCell { colspan: Some(3), "Total" }
-                        TableCell { "$285,000" }
-                    }
+            }
+            div { class: "mb-8",
+                PendingNotices {
+                    on_view: move |loan_id| {
+                        navigator.push(Route::LoanDetail { id: loan_id });
+                    },
                 }
             }
+            div { class: "mb-8",
+                ExpiringLicenses {}
+            }
+            div { class: "mb-8",
+                CycleTimePanel {}
+            }
+            Link {
+                to: Route::Pipeline {},
+                class: "text-blue-600 hover:underline",
+                "Open the loan pipeline"
+            }
         }
     }
 }
-
-
-
-
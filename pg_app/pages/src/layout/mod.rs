@@ -8,5 +8,6 @@ pub mod navbar;
 
 pub use footer::Footer;
 pub use navbar::Navbar;
+pub use notes::NotesPanel;
 // pub use app_layout::AppLayout;
 
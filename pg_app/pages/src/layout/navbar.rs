@@ -3,6 +3,7 @@ use crate::routes::Route;
 use dioxus::prelude::*;
 // pg_app/components/src/ui/avatar_drop.rs
 use components::ui::avatar_drop::AvatarDrop;
+use components::db::notifications::NotificationBell;
 
 
 /// The Navbar component that will be rendered on all pages of our app
@@ -29,11 +30,37 @@ pub fn Navbar() -> Element {
                         class: "hover:text-blue-400 transition",
                         "Dashboard"
                     }
+                    Link {
+                        to: Route::Borrowers {},
+                        class: "hover:text-blue-400 transition",
+                        "Borrowers"
+                    }
+                    Link {
+                        to: Route::Loans {},
+                        class: "hover:text-blue-400 transition",
+                        "Loans"
+                    }
+                    Link {
+                        to: Route::Pipeline {},
+                        class: "hover:text-blue-400 transition",
+                        "Pipeline"
+                    }
+                    Link {
+                        to: Route::MyTasks {},
+                        class: "hover:text-blue-400 transition",
+                        "My Tasks"
+                    }
+                    Link {
+                        to: Route::Settings {},
+                        class: "hover:text-blue-400 transition",
+                        "Settings"
+                    }
                     Link {
                         to: Route::Random {},
                         class: "hover:text-blue-400 transition",
                         "Random"
                     }
+                    NotificationBell {}
                     AvatarDrop {}
                 }
             }
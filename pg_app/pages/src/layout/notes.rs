@@ -0,0 +1,15 @@
+use dioxus::prelude::*;
+use components::db::notes::Notes;
+use shared::models::NoteSubject;
+
+/// Notes section shown at the bottom of loan and borrower pages
+///
+/// Internal notes are included when the signed-in user's role may see them.
+#[component]
+pub fn NotesPanel(subject: NoteSubject) -> Element {
+    rsx! {
+        section { class: "rounded-lg bg-gray-50 p-4",
+            Notes { subject }
+        }
+    }
+}
@@ -9,6 +9,21 @@ pub use blog::Blog;
 pub mod dashboard;
 pub use dashboard::Dashboard;
 
+pub mod borrowers;
+pub use borrowers::{Borrowers, BorrowerDetail};
+
+pub mod loans;
+pub use loans::{Loans, LoanDetail};
+
+pub mod pipeline;
+pub use pipeline::Pipeline;
+
+pub mod tasks;
+pub use tasks::MyTasks;
+
+pub mod settings;
+pub use settings::Settings;
+
 
 pub mod random;
 pub use random::Random;
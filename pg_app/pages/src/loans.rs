@@ -0,0 +1,112 @@
+// pages/src/loans.rs
+use dioxus::prelude::*;
+use components::db::borrowers::BorrowerTable;
+use components::db::exports::ExportPanel;
+use components::db::loans::{AddLoan, AdverseActionPanel, ArmProjection, DisclosureTolerance, FeeWorksheet, FnmExport, FnmImport, HmdaForm, LoanDocuments, MismoExport, MismoImport, PaymentQuote, RateLocks, StatusTimeline, SubjectProperty, TridTimeline};
+use components::db::tasks::LoanTasks;
+use server::loans::get_loan;
+use shared::exports::{ExportList, ExportSort};
+use shared::models::NoteSubject;
+use shared::money::format_cents;
+use crate::layout::NotesPanel;
+use crate::routes::Route;
+
+/// Loan list (the borrower table) with the new loan form, rendered at `[Route::Loans]`
+#[component]
+pub fn Loans() -> Element {
+    let navigator = use_navigator();
+    let mut refresh_count = use_signal(|| 0);
+
+    rsx! {
+        div { class: "container mx-auto px-4 py-8 flex flex-col gap-6",
+            AddLoan {
+                on_loan_added: move |_| refresh_count.set(refresh_count() + 1),
+            }
+            MismoImport {
+                on_imported: move |_| refresh_count.set(refresh_count() + 1),
+            }
+            FnmImport {
+                on_imported: move |_| refresh_count.set(refresh_count() + 1),
+            }
+            ExportPanel {
+                list: ExportList::Loans,
+                columns: ["status", "borrower", "loan_number", "application_date", "loan_type", "amount", "note_rate", "state"]
+                    .map(String::from)
+                    .to_vec(),
+                sort: vec![ExportSort::descending("created_at")],
+            }
+            BorrowerTable {
+                key: "{refresh_count}",
+                on_view: move |loan_id| {
+                    navigator.push(Route::LoanDetail { id: loan_id });
+                },
+            }
+        }
+    }
+}
+
+/// Single loan page, rendered at `[Route::LoanDetail]`
+#[component]
+pub fn LoanDetail(id: i32) -> Element {
+    let mut loan = use_resource(move || async move { get_loan(id).await });
+    let reload = move |_: ()| loan.restart();
+
+    match &*loan.read() {
+        Some(Ok(loan)) => rsx! {
+            div { class: "container mx-auto px-4 py-8 flex flex-col gap-6",
+                div {
+                    h2 { class: "text-2xl font-bold",
+                        "Loan "
+                        {loan.loan_number.clone().unwrap_or_else(|| format!("#{}", loan.id))}
+                    }
+                    p { class: "text-gray-600",
+                        "{loan.loan_type} {loan.loan_purpose} · "
+                        {format_cents(loan.amount_cents)}
+                        if let Some(rate) = loan.note_rate {
+                            " at {rate:.3}%"
+                        }
+                        " for {loan.term_months} months · {loan.status}"
+                    }
+                    Link {
+                        to: Route::BorrowerDetail { id: loan.borrower_id },
+                        class: "text-blue-600 hover:underline",
+                        "View borrower"
+                    }
+                }
+                div { class: "flex flex-row flex-wrap gap-6",
+                    MismoExport {
+                        loan_id: loan.id,
+                        file_stem: loan.loan_number.clone().unwrap_or_else(|| format!("loan-{}", loan.id)),
+                    }
+                    FnmExport {
+                        loan_id: loan.id,
+                        file_stem: loan.loan_number.clone().unwrap_or_else(|| format!("loan-{}", loan.id)),
+                    }
+                }
+                SubjectProperty { loan_id: loan.id, loan_amount_cents: loan.amount_cents }
+                RateLocks { loan_id: loan.id }
+                StatusTimeline { key: "{loan.status.code()}", loan_id: loan.id }
+                TridTimeline { loan: loan.clone() }
+                PaymentQuote { loan: loan.clone() }
+                ArmProjection { loan: loan.clone() }
+                FeeWorksheet { loan: loan.clone() }
+                DisclosureTolerance { loan_id: loan.id }
+                AdverseActionPanel {
+                    key: "{loan.status.code()}",
+                    loan: loan.clone(),
+                    on_denied: reload,
+                }
+                HmdaForm { key: "{loan.status.code()}", loan: loan.clone() }
+                LoanDocuments { key: "{loan.status.code()}", loan_id: loan.id }
+                LoanTasks { loan_id: loan.id }
+                NotesPanel { subject: NoteSubject::Loan(loan.id) }
+            }
+        },
+        Some(Err(err)) => rsx! {
+            div { class: "container mx-auto px-4 py-8 text-red-600", "Could not load loan: {err}" }
+        },
+        None => rsx! {
+            div { class: "container mx-auto px-4 py-8", "Loading..." }
+        },
+    }
+}
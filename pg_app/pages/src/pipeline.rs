@@ -0,0 +1,26 @@
+// pages/src/pipeline.rs
+use dioxus::prelude::*;
+use components::db::loans::PipelineBoard;
+use crate::routes::Route;
+
+/// Loan pipeline Kanban, rendered at `[Route::Pipeline]`
+#[component]
+pub fn Pipeline() -> Element {
+    let navigator = use_navigator();
+
+    rsx! {
+        div { class: "px-4 py-8 flex flex-col gap-4",
+            div {
+                h2 { class: "text-2xl font-bold", "Pipeline" }
+                p { class: "text-sm text-gray-600",
+                    "Drag a card to another column to change its status. Double-click a card to open the loan."
+                }
+            }
+            PipelineBoard {
+                on_view: move |loan_id| {
+                    navigator.push(Route::LoanDetail { id: loan_id });
+                },
+            }
+        }
+    }
+}
@@ -4,6 +4,11 @@
 use dioxus::prelude::*;
 use crate::home::Home;
 use crate::dashboard::Dashboard;
+use crate::borrowers::{Borrowers, BorrowerDetail};
+use crate::loans::{Loans, LoanDetail};
+use crate::pipeline::Pipeline;
+use crate::tasks::MyTasks;
+use crate::settings::Settings;
 use crate::blog::Blog;
 use crate::random::Random;
 use crate::not_found::NotFound;
@@ -57,6 +62,27 @@ pub enum Route {
     
     #[route("/dashboard")]
     Dashboard {},
+
+    #[route("/borrowers")]
+    Borrowers {},
+
+    #[route("/borrowers/:id")]
+    BorrowerDetail { id: i32 },
+
+    #[route("/loans")]
+    Loans {},
+
+    #[route("/loans/:id")]
+    LoanDetail { id: i32 },
+
+    #[route("/pipeline")]
+    Pipeline {},
+
+    #[route("/tasks")]
+    MyTasks {},
+
+    #[route("/settings")]
+    Settings {},
     
     #[route("/blog")]
     Blog {},
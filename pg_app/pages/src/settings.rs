@@ -0,0 +1,26 @@
+// pages/src/settings.rs
+use dioxus::prelude::*;
+use components::db::exports::ExportPanel;
+use components::db::settings::{ExpiringLicenses, HmdaFilerForm, LarExportPanel, LicensingPanel, LoanNumberForm, PmiRateTable, SlaTargetTable};
+use shared::exports::{ExportList, ExportSort};
+
+/// Company-wide settings and the user export, rendered at `[Route::Settings]`
+#[component]
+pub fn Settings() -> Element {
+    rsx! {
+        div { class: "container mx-auto px-4 py-8 flex flex-col gap-6",
+            h2 { class: "text-2xl font-bold", "Settings" }
+            LoanNumberForm {}
+            PmiRateTable {}
+            SlaTargetTable {}
+            LicensingPanel {}
+            ExpiringLicenses {}
+            HmdaFilerForm {}
+            LarExportPanel {}
+            ExportPanel {
+                list: ExportList::Users,
+                sort: vec![ExportSort::ascending("last_name"), ExportSort::ascending("first_name")],
+            }
+        }
+    }
+}
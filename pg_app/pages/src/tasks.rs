@@ -0,0 +1,71 @@
+// pages/src/tasks.rs
+use dioxus::prelude::*;
+use components::db::session::CURRENT_USER;
+use components::db::tasks::TaskTable;
+use server::tasks::get_my_tasks;
+use crate::routes::Route;
+
+/// The signed-in user's tasks, most urgent first, rendered at `[Route::MyTasks]`
+#[component]
+pub fn MyTasks() -> Element {
+    let navigator = use_navigator();
+    let mut include_closed = use_signal(|| false);
+    let mut tasks = use_resource(move || async move {
+        match CURRENT_USER() {
+            Some(_) => get_my_tasks(include_closed()).await,
+            None => Ok(Vec::new()),
+        }
+    });
+
+    let Some(user) = CURRENT_USER() else {
+        return rsx! {
+            div { class: "container mx-auto px-4 py-8 text-gray-600", "Sign in to see your tasks." }
+        };
+    };
+
+    let rows = match &*tasks.read() {
+        Some(Ok(rows)) => rows.clone(),
+        Some(Err(err)) => {
+            return rsx! {
+                div { class: "container mx-auto px-4 py-8 text-red-600", "Could not load tasks: {err}" }
+            };
+        }
+        None => Vec::new(),
+    };
+    let overdue = rows
+        .iter()
+        .filter(|row| row.task.is_overdue(chrono::Local::now().date_naive()))
+        .count();
+
+    rsx! {
+        div { class: "container mx-auto px-4 py-8 flex flex-col gap-4",
+            div { class: "flex items-end justify-between",
+                div {
+                    h2 { class: "text-2xl font-bold", "My Tasks" }
+                    p { class: "text-gray-600",
+                        "{user.full_name()} · {rows.len()} tasks"
+                        if overdue > 0 {
+                            span { class: "text-red-600 font-semibold", " · {overdue} overdue" }
+                        }
+                    }
+                }
+                label { class: "flex items-center gap-1 text-sm",
+                    input {
+                        r#type: "checkbox",
+                        checked: include_closed(),
+                        onchange: move |event: FormEvent| include_closed.set(event.checked()),
+                    }
+                    "Show completed and cancelled"
+                }
+            }
+            TaskTable {
+                rows,
+                show_loan: true,
+                on_changed: move |_| tasks.restart(),
+                on_view_loan: move |loan_id| {
+                    navigator.push(Route::LoanDetail { id: loan_id });
+                },
+            }
+        }
+    }
+}
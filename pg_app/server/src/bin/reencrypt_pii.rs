@@ -0,0 +1,133 @@
+//! Seals PII still stored as plain text or under an older key with the
+//! current key, and recomputes the blind indexes
+//!
+//! Run once after upgrading to encrypted PII, after adding a key to
+//! `PII_KEYS` and after changing `PII_INDEX_KEY`:
+//!
+//! ```text
+//! cargo run -p server --bin reencrypt_pii -- [--dry-run]
+//! ```
+//!
+//! Keys being retired must stay in `PII_KEYS` until this has finished.
+
+use shared::crypto::{Keyring, PiiField};
+use sqlx::{PgPool, Row};
+
+/// Rows read and rewritten per transaction
+const BATCH_SIZE: i64 = 500;
+
+/// A sealed column and the blind index kept beside it, if any
+struct SealedColumn {
+    name: &'static str,
+    index: Option<(&'static str, PiiField)>,
+}
+
+const BORROWERS: &[SealedColumn] = &[
+    SealedColumn { name: "ssn", index: Some(("ssn_index", PiiField::Ssn)) },
+    SealedColumn { name: "date_of_birth", index: None },
+];
+
+const ASSETS: &[SealedColumn] = &[SealedColumn {
+    name: "account_number",
+    index: Some(("account_number_index", PiiField::AccountNumber)),
+}];
+
+const BORROWER_MERGES: &[SealedColumn] = &[
+    SealedColumn { name: "prior_ssn", index: None },
+    SealedColumn { name: "prior_date_of_birth", index: None },
+];
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv::dotenv().ok();
+    let dry_run = std::env::args().skip(1).any(|arg| arg == "--dry-run");
+
+    let keyring = shared::crypto::keyring()?;
+    let db = &server::init_db().await?;
+    println!("Sealing with key version {}{}", keyring.current_version(), if dry_run { " (dry run)" } else { "" });
+
+    for (table, columns) in [("borrowers", BORROWERS), ("assets", ASSETS), ("borrower_merges", BORROWER_MERGES)] {
+        let (scanned, changed) = reencrypt_table(db, keyring, table, columns, dry_run).await?;
+        println!("{table}: {changed} of {scanned} rows {}", if dry_run { "need re-sealing" } else { "re-sealed" });
+    }
+    Ok(())
+}
+
+/// Walks `table` in id order, rewriting each value that is not sealed with
+/// the current key and each blind index that does not match its value;
+/// returns the rows scanned and the rows changed
+async fn reencrypt_table(
+    db: &PgPool,
+    keyring: &Keyring,
+    table: &str,
+    columns: &[SealedColumn],
+    dry_run: bool,
+) -> Result<(usize, usize), Box<dyn std::error::Error>> {
+    let mut selected: Vec<&str> = vec!["id"];
+    for column in columns {
+        selected.push(column.name);
+        selected.extend(column.index.map(|(index, _)| index));
+    }
+    let select = format!("SELECT {} FROM {} WHERE id > $1 ORDER BY id LIMIT $2", selected.join(", "), table);
+
+    let (mut scanned, mut changed) = (0, 0);
+    let mut last_id = 0;
+    loop {
+        let rows = sqlx::query(&select).bind(last_id).bind(BATCH_SIZE).fetch_all(db).await?;
+        let Some(last) = rows.last() else {
+            break;
+        };
+        last_id = last.try_get("id")?;
+
+        let mut tx = db.begin().await?;
+        for row in &rows {
+            scanned += 1;
+            let id: i32 = row.try_get("id")?;
+            let mut row_changed = false;
+
+            for column in columns {
+                let Some(stored) = row.try_get::<Option<String>, _>(column.name)? else {
+                    continue;
+                };
+                let plain = keyring
+                    .open(&stored)
+                    .map_err(|e| format!("{table} #{id} {}: {e}", column.name))?;
+                let reseal = keyring.needs_reseal(&stored);
+                let index = column.index.map(|(index, field)| (index, keyring.blind_index(field, &plain)));
+                let stale_index = match &index {
+                    Some((name, expected)) => row.try_get::<Option<Vec<u8>>, _>(*name)?.as_ref() != Some(expected),
+                    None => false,
+                };
+                if !reseal && !stale_index {
+                    continue;
+                }
+                row_changed = true;
+                if dry_run {
+                    continue;
+                }
+
+                let sealed = if reseal { keyring.seal(&plain)? } else { stored };
+                match index {
+                    Some((name, expected)) => {
+                        sqlx::query(&format!("UPDATE {} SET {} = $1, {} = $2 WHERE id = $3", table, column.name, name))
+                            .bind(sealed)
+                            .bind(expected)
+                            .bind(id)
+                            .execute(&mut *tx)
+                            .await?;
+                    }
+                    None => {
+                        sqlx::query(&format!("UPDATE {} SET {} = $1 WHERE id = $2", table, column.name))
+                            .bind(sealed)
+                            .bind(id)
+                            .execute(&mut *tx)
+                            .await?;
+                    }
+                }
+            }
+            changed += usize::from(row_changed);
+        }
+        tx.commit().await?;
+    }
+    Ok((scanned, changed))
+}
@@ -0,0 +1,102 @@
+// pg_app/server/src/borrowers/applicant_functions.rs
+use dioxus::prelude::*;
+use shared::models::{Asset, Borrower, Employment};
+
+#[server]
+pub async fn get_employments(borrower_id: i32) -> Result<Vec<Employment>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Employment>(
+        "SELECT * FROM employments WHERE borrower_id = $1 ORDER BY is_primary DESC, is_current DESC, start_date DESC NULLS LAST",
+    )
+    .bind(borrower_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+#[server]
+pub async fn get_assets(borrower_id: i32) -> Result<Vec<Asset>, ServerFnError> {
+    use shared::masking::MaskPii;
+
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Asset>(
+        "SELECT * FROM assets WHERE borrower_id = $1 ORDER BY asset_type, institution_name",
+    )
+    .bind(borrower_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result.mask_pii())
+}
+
+/// Sets or clears the borrower's SSN, given with or without dashes; the SSN
+/// is sealed and its blind index kept for exact-match lookups. Needs `ProcessLoans`.
+#[server]
+pub async fn update_borrower_ssn(borrower_id: i32, ssn: Option<String>) -> Result<Borrower, ServerFnError> {
+    use shared::masking::MaskPii;
+
+    crate::users::session_user_with(shared::models::Permission::ProcessLoans, "You cannot change SSNs").await?;
+
+    let db = crate::get_db().await;
+
+    let ssn = ssn
+        .map(|ssn| ssn.chars().filter(|c| *c != '-' && !c.is_whitespace()).collect::<String>())
+        .filter(|ssn| !ssn.is_empty());
+    if ssn.as_ref().is_some_and(|ssn| ssn.len() != 9 || !ssn.chars().all(|c| c.is_ascii_digit())) {
+        return Err(ServerFnError::Request("SSN must be 9 digits".into()));
+    }
+
+    let ssn_index = match &ssn {
+        Some(ssn) => Some(shared::crypto::blind_index(shared::crypto::PiiField::Ssn, ssn)?),
+        None => None,
+    };
+
+    sqlx::query_as::<_, Borrower>("UPDATE borrowers SET ssn = $1, ssn_index = $2 WHERE id = $3 RETURNING *")
+        .bind(shared::crypto::Sealed(ssn))
+        .bind(ssn_index)
+        .bind(borrower_id)
+        .fetch_one(db)
+        .await
+        .map(MaskPii::mask_pii)
+        .map_err(|e| {
+            tracing::error!("Failed to update SSN: {}", e);
+            ServerFnError::ServerError("Failed to update SSN".into())
+        })
+}
+
+/// Sets or clears the borrower's date of birth; needs `ProcessLoans`
+#[server]
+pub async fn update_borrower_date_of_birth(
+    borrower_id: i32,
+    date_of_birth: Option<sqlx::types::chrono::NaiveDate>,
+) -> Result<Borrower, ServerFnError> {
+    use shared::masking::MaskPii;
+
+    crate::users::session_user_with(shared::models::Permission::ProcessLoans, "You cannot change dates of birth")
+        .await?;
+
+    let db = crate::get_db().await;
+
+    let (today,): (sqlx::types::chrono::NaiveDate,) = sqlx::query_as("SELECT CURRENT_DATE").fetch_one(db).await?;
+    if date_of_birth.is_some_and(|born| born >= today) {
+        return Err(ServerFnError::Request("Date of birth must be in the past".into()));
+    }
+
+    sqlx::query_as::<_, Borrower>("UPDATE borrowers SET date_of_birth = $1 WHERE id = $2 RETURNING *")
+        .bind(shared::crypto::Sealed(date_of_birth))
+        .bind(borrower_id)
+        .fetch_one(db)
+        .await
+        .map(MaskPii::mask_pii)
+        .map_err(|e| {
+            tracing::error!("Failed to update date of birth: {}", e);
+            ServerFnError::ServerError("Failed to update date of birth".into())
+        })
+}
@@ -0,0 +1,121 @@
+// pg_app/server/src/borrowers/borrower_functions.rs
+use dioxus::prelude::*;
+use shared::models::{Borrower, BorrowerInput};
+
+/// Every borrower not merged into another, by name
+#[server]
+pub async fn get_all_borrowers() -> Result<Vec<Borrower>, ServerFnError> {
+    use shared::masking::MaskPii;
+
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Borrower>("SELECT * FROM borrowers WHERE merged_into IS NULL ORDER BY last_name, first_name")
+        .fetch_all(db)
+        .await?;
+
+    Ok(result.mask_pii())
+}
+
+#[server]
+pub async fn get_borrower(id: i32) -> Result<Borrower, ServerFnError> {
+    use shared::masking::MaskPii;
+
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Borrower>("SELECT * FROM borrowers WHERE id = $1")
+        .bind(id)
+        .fetch_one(db)
+        .await?;
+
+    Ok(result.mask_pii())
+}
+
+#[server]
+pub async fn create_borrower(input: BorrowerInput) -> Result<i32, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let (id,): (i32,) = sqlx::query_as(
+        r#"
+        INSERT INTO borrowers (first_name, last_name, email, phone, monthly_income_cents)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+    )
+    .bind(input.first_name.trim())
+    .bind(input.last_name.trim())
+    .bind(input.email.as_deref().map(str::trim))
+    .bind(input.phone.as_deref().map(str::trim))
+    .bind(input.monthly_income_cents)
+    .fetch_one(db)
+    .await?;
+
+    Ok(id)
+}
+
+#[server]
+pub async fn update_borrower(id: i32, input: BorrowerInput) -> Result<Borrower, ServerFnError> {
+    use shared::masking::MaskPii;
+
+    crate::users::session_borrower_editor(id, "You cannot edit this borrower").await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    sqlx::query_as::<_, Borrower>(
+        r#"
+        UPDATE borrowers
+        SET
+            first_name = $1,
+            last_name = $2,
+            email = $3,
+            phone = $4,
+            monthly_income_cents = $5
+        WHERE id = $6
+        RETURNING *
+        "#,
+    )
+    .bind(input.first_name.trim())
+    .bind(input.last_name.trim())
+    .bind(input.email.as_deref().map(str::trim))
+    .bind(input.phone.as_deref().map(str::trim))
+    .bind(input.monthly_income_cents)
+    .bind(id)
+    .fetch_one(db)
+    .await
+    .map(MaskPii::mask_pii)
+    .map_err(|e| {
+        tracing::error!("Failed to update borrower: {}", e);
+        ServerFnError::ServerError("Failed to update borrower".into())
+    })
+}
+
+#[server]
+pub async fn delete_borrower(id: i32) -> Result<(), ServerFnError> {
+    crate::users::session_borrower_editor(id, "You cannot delete this borrower").await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query("DELETE FROM borrowers WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows deleted".to_string()))
+    } else {
+        Ok(())
+    }
+}
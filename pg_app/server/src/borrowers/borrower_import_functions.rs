@@ -0,0 +1,113 @@
+// pg_app/server/src/borrowers/borrower_import_functions.rs
+use dioxus::prelude::*;
+use shared::dtos::BorrowerImportResult;
+use shared::imports::borrowers::{BorrowerField, BorrowerImportRow};
+
+/// Maps and checks every row of a borrower spreadsheet without saving anything
+///
+/// See `shared::imports::borrowers` for how rows are built and matched.
+#[server]
+pub async fn preview_borrower_import(
+    contents: String,
+    mapping: Vec<Option<BorrowerField>>,
+) -> Result<Vec<BorrowerImportRow>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let table = match shared::imports::borrowers::read_csv(&contents) {
+        Ok(table) => table,
+        Err(e) => return Err(ServerFnError::Request(e.to_string())),
+    };
+    if let Err(message) = shared::imports::borrowers::check_mapping(&mapping) {
+        return Err(ServerFnError::Request(message));
+    }
+
+    let existing = super::get_all_borrowers().await?;
+    Ok(shared::imports::borrowers::check_rows(&table, &mapping, &existing))
+}
+
+/// Creates a borrower for every ready row of a borrower spreadsheet
+///
+/// Rows are checked again here rather than trusted from the preview, since
+/// borrowers may have been added in between. Rows with a loan amount also
+/// get a loan in lead status, assigned to the signed-in user, who must be
+/// able to originate. Everything is inserted in one transaction.
+#[server]
+pub async fn commit_borrower_import(
+    contents: String,
+    mapping: Vec<Option<BorrowerField>>,
+) -> Result<BorrowerImportResult, ServerFnError> {
+    let loan_officer_id = crate::users::session_user().await?.id;
+
+    let db = crate::get_db().await;
+
+    let rows = preview_borrower_import(contents.clone(), mapping).await?;
+    let table = match shared::imports::borrowers::read_csv(&contents) {
+        Ok(table) => table,
+        Err(e) => return Err(ServerFnError::Request(e.to_string())),
+    };
+
+    let today = sqlx::types::chrono::Utc::now().date_naive();
+    let mut tx = db.begin().await?;
+    // Leads have no subject property yet, so there is no state to check
+    if rows.iter().any(|row| row.loan_amount_cents.is_some()) {
+        crate::users::ensure_licensed(&mut tx, loan_officer_id, None, today).await?;
+    }
+    let mut borrower_ids = Vec::new();
+    let mut leads_created = 0;
+    for row in rows.iter().filter(|row| row.status == shared::imports::borrowers::RowStatus::Ready) {
+        let input = &row.input;
+        let inserted: Result<(i32,), _> = sqlx::query_as(
+            r#"
+            INSERT INTO borrowers (first_name, last_name, email, phone, monthly_income_cents)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+        )
+        .bind(input.first_name.trim())
+        .bind(input.last_name.trim())
+        .bind(&input.email)
+        .bind(&input.phone)
+        .bind(input.monthly_income_cents)
+        .fetch_one(&mut *tx)
+        .await;
+        let borrower_id = match inserted {
+            Ok((id,)) => id,
+            Err(e) => {
+                tracing::error!("Failed to import borrower on line {}: {}", row.line, e);
+                return Err(ServerFnError::ServerError(format!("Failed to import the borrower on line {}", row.line)));
+            }
+        };
+
+        if let Some(amount_cents) = row.loan_amount_cents {
+            // Terms are not known yet: the rate stays NULL until one is quoted,
+            // and status, type and purpose take the table defaults
+            let loan_number = crate::settings::next_loan_number(&mut tx, today).await?;
+            sqlx::query(
+                "INSERT INTO loans (loan_number, borrower_id, loan_officer_id, amount_cents) VALUES ($1, $2, $3, $4)",
+            )
+            .bind(&loan_number)
+            .bind(borrower_id)
+            .bind(loan_officer_id)
+            .bind(amount_cents)
+            .execute(&mut *tx)
+            .await?;
+            leads_created += 1;
+        }
+        borrower_ids.push(borrower_id);
+    }
+    tx.commit().await?;
+
+    let skipped = rows.len() - borrower_ids.len();
+    tracing::info!(
+        "Imported {} borrowers and {} leads from a spreadsheet; {} rows skipped",
+        borrower_ids.len(),
+        leads_created,
+        skipped
+    );
+    Ok(BorrowerImportResult {
+        borrower_ids,
+        leads_created,
+        skipped,
+        error_report: shared::imports::borrowers::error_report(&table, &rows),
+    })
+}
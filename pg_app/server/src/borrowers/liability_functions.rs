@@ -0,0 +1,215 @@
+// pg_app/server/src/borrowers/liability_functions.rs
+use dioxus::prelude::*;
+use shared::dtos::LoanDebts;
+use shared::imports::tradelines::TradelineFormat;
+use shared::models::{Liability, LiabilityInput};
+
+#[server]
+pub async fn get_liabilities(borrower_id: i32) -> Result<Vec<Liability>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Liability>(
+        "SELECT * FROM liabilities WHERE borrower_id = $1 ORDER BY liability_type, creditor_name",
+    )
+    .bind(borrower_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Adds a debt for a borrower the signed-in user may edit
+#[server]
+pub async fn create_liability(borrower_id: i32, input: LiabilityInput) -> Result<i32, ServerFnError> {
+    crate::users::session_borrower_editor(borrower_id, "You cannot edit this borrower's liabilities").await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let (id,): (i32,) = sqlx::query_as(
+        r#"
+        INSERT INTO liabilities (
+            borrower_id, liability_type, creditor_name, account_last4,
+            balance_cents, monthly_payment_cents, paid_off_at_closing, excluded
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id
+        "#,
+    )
+    .bind(borrower_id)
+    .bind(input.liability_type)
+    .bind(input.creditor_name.trim())
+    .bind(input.account_last4)
+    .bind(input.balance_cents)
+    .bind(input.monthly_payment_cents)
+    .bind(input.paid_off_at_closing)
+    .bind(input.excluded)
+    .fetch_one(db)
+    .await?;
+
+    Ok(id)
+}
+
+/// Changes a debt of a borrower the signed-in user may edit
+#[server]
+pub async fn update_liability(id: i32, input: LiabilityInput) -> Result<Liability, ServerFnError> {
+    let db = crate::get_db().await;
+
+    let borrower_id = liability_borrower(db, id).await?;
+    crate::users::session_borrower_editor(borrower_id, "You cannot edit this borrower's liabilities").await?;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    sqlx::query_as::<_, Liability>(
+        r#"
+        UPDATE liabilities
+        SET
+            liability_type = $1,
+            creditor_name = $2,
+            account_last4 = $3,
+            balance_cents = $4,
+            monthly_payment_cents = $5,
+            paid_off_at_closing = $6,
+            excluded = $7
+        WHERE id = $8
+        RETURNING *
+        "#,
+    )
+    .bind(input.liability_type)
+    .bind(input.creditor_name.trim())
+    .bind(input.account_last4)
+    .bind(input.balance_cents)
+    .bind(input.monthly_payment_cents)
+    .bind(input.paid_off_at_closing)
+    .bind(input.excluded)
+    .bind(id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to update liability: {}", e);
+        ServerFnError::ServerError("Failed to update liability".into())
+    })
+}
+
+/// Removes a debt of a borrower the signed-in user may edit
+#[server]
+pub async fn delete_liability(id: i32) -> Result<(), ServerFnError> {
+    let db = crate::get_db().await;
+
+    let borrower_id = liability_borrower(db, id).await?;
+    crate::users::session_borrower_editor(borrower_id, "You cannot edit this borrower's liabilities").await?;
+
+    let result = sqlx::query("DELETE FROM liabilities WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows deleted".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Imports tradelines from a credit report file
+///
+/// The whole file is rejected if any record is invalid, so a partial import
+/// never has to be cleaned up. Returns the number of liabilities created.
+#[server]
+pub async fn import_tradelines(
+    borrower_id: i32,
+    contents: String,
+    format: TradelineFormat,
+) -> Result<usize, ServerFnError> {
+    crate::users::session_borrower_editor(borrower_id, "You cannot edit this borrower's liabilities").await?;
+
+    let db = crate::get_db().await;
+
+    let inputs = match shared::imports::tradelines::parse_tradelines(&contents, format) {
+        Ok(inputs) => inputs,
+        Err(errors) => {
+            let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            return Err(ServerFnError::Request(messages.join("; ")));
+        }
+    };
+
+    let mut tx = db.begin().await?;
+    for input in &inputs {
+        sqlx::query(
+            r#"
+            INSERT INTO liabilities (
+                borrower_id, liability_type, creditor_name, account_last4,
+                balance_cents, monthly_payment_cents, source
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, 'credit_report')
+            "#,
+        )
+        .bind(borrower_id)
+        .bind(input.liability_type)
+        .bind(&input.creditor_name)
+        .bind(&input.account_last4)
+        .bind(input.balance_cents)
+        .bind(input.monthly_payment_cents)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    tracing::info!("Imported {} tradelines for borrower {}", inputs.len(), borrower_id);
+    Ok(inputs.len())
+}
+
+/// Liabilities and income of the primary borrower and co-borrowers of a loan
+#[server]
+pub async fn get_loan_debts(loan_id: i32) -> Result<LoanDebts, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let borrower_ids: Vec<(i32,)> = sqlx::query_as(
+        r#"
+        SELECT borrower_id FROM loans WHERE id = $1
+        UNION
+        SELECT borrower_id FROM loan_co_borrowers WHERE loan_id = $1
+        "#,
+    )
+    .bind(loan_id)
+    .fetch_all(db)
+    .await?;
+    let borrower_ids: Vec<i32> = borrower_ids.into_iter().map(|(id,)| id).collect();
+
+    let liabilities = sqlx::query_as::<_, Liability>("SELECT * FROM liabilities WHERE borrower_id = ANY($1)")
+        .bind(&borrower_ids)
+        .fetch_all(db)
+        .await?;
+    let (monthly_income_cents,): (i64,) = sqlx::query_as(
+        "SELECT COALESCE(SUM(monthly_income_cents), 0)::BIGINT FROM borrowers WHERE id = ANY($1)",
+    )
+    .bind(&borrower_ids)
+    .fetch_one(db)
+    .await?;
+
+    Ok(LoanDebts {
+        summary: shared::calculations::dti::DebtSummary::from_liabilities(&liabilities),
+        monthly_income_cents,
+    })
+}
+
+/// Borrower who owes liability `id`
+pub async fn liability_borrower(db: &sqlx::PgPool, id: i32) -> Result<i32, ServerFnError> {
+    let borrower_id: Option<(i32,)> = sqlx::query_as("SELECT borrower_id FROM liabilities WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?;
+    match borrower_id {
+        Some((borrower_id,)) => Ok(borrower_id),
+        None => Err(ServerFnError::Request(format!("Liability {} not found", id))),
+    }
+}
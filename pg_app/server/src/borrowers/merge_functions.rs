@@ -0,0 +1,374 @@
+// pg_app/server/src/borrowers/merge_functions.rs
+use dioxus::prelude::*;
+use shared::duplicates::DuplicateCandidate;
+use shared::dtos::BorrowerMergeRow;
+use shared::models::{BorrowerMerge, MergeSelection};
+
+/// Pairs of borrowers that are probably the same person, best match first
+///
+/// See `shared::duplicates` for how pairs are scored. Merged borrowers are
+/// left out.
+#[server]
+pub async fn get_duplicate_candidates() -> Result<Vec<DuplicateCandidate>, ServerFnError> {
+    use shared::masking::MaskPii;
+
+    crate::users::session_user_with(shared::models::Permission::ProcessLoans, "You cannot review duplicate borrowers")
+        .await?;
+
+    let db = crate::get_db().await;
+
+    let borrowers = sqlx::query_as::<_, shared::models::Borrower>("SELECT * FROM borrowers WHERE merged_into IS NULL")
+        .fetch_all(db)
+        .await?;
+
+    Ok(shared::duplicates::find_duplicates(&borrowers).mask_pii())
+}
+
+/// Merges a duplicate borrower into the survivor; requires `ProcessLoans`
+///
+/// The survivor takes the chosen fields from the merged record, and the
+/// merged record's loans (as primary or co-applicant), notes, liabilities,
+/// employments and assets are moved to it. Documents and tasks belong to
+/// the loans and move with them. The merged record is hidden rather than
+/// deleted and the merge is written to the audit log, so it can be undone
+/// within `BORROWER_MERGE_UNDO_HOURS`.
+///
+/// Two applicants on the same loan are different people and cannot be merged.
+#[server]
+pub async fn merge_borrowers(selection: MergeSelection) -> Result<BorrowerMerge, ServerFnError> {
+    use shared::models::{AuditAction, Borrower, Permission};
+    use shared::masking::MaskPii;
+
+    if selection.survivor_id == selection.merged_id {
+        return Err(ServerFnError::Request("Choose two different borrowers to merge".to_string()));
+    }
+
+    let actor = crate::users::session_user_with(Permission::ProcessLoans, "You cannot merge borrowers").await?;
+
+    let db = crate::get_db().await;
+    let mut tx = db.begin().await?;
+
+    let records = sqlx::query_as::<_, Borrower>(
+        "SELECT * FROM borrowers WHERE id IN ($1, $2) AND merged_into IS NULL ORDER BY id FOR UPDATE",
+    )
+    .bind(selection.survivor_id)
+    .bind(selection.merged_id)
+    .fetch_all(&mut *tx)
+    .await?;
+    let survivor = records.iter().find(|b| b.id == selection.survivor_id);
+    let merged = records.iter().find(|b| b.id == selection.merged_id);
+    let (Some(survivor), Some(merged)) = (survivor, merged) else {
+        return Err(ServerFnError::Request("One of the borrowers was not found or is already merged".to_string()));
+    };
+
+    let shared_loan: Option<(i32,)> = sqlx::query_as(
+        r#"
+        SELECT loan_id FROM (
+            SELECT id AS loan_id, borrower_id FROM loans
+            UNION ALL
+            SELECT loan_id, borrower_id FROM loan_co_borrowers
+        ) applicants
+        WHERE borrower_id IN ($1, $2)
+        GROUP BY loan_id
+        HAVING COUNT(DISTINCT borrower_id) = 2
+        LIMIT 1
+        "#,
+    )
+    .bind(survivor.id)
+    .bind(merged.id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    if let Some((loan_id,)) = shared_loan {
+        return Err(ServerFnError::Request(format!(
+            "{} and {} are both applicants on loan #{}, so they are different people",
+            survivor.full_name(),
+            merged.full_name(),
+            loan_id
+        )));
+    }
+
+    let result = selection.apply(survivor, merged);
+    let ssn_index = match &result.ssn {
+        Some(ssn) => Some(shared::crypto::blind_index(shared::crypto::PiiField::Ssn, ssn)?),
+        None => None,
+    };
+    sqlx::query(
+        r#"
+        UPDATE borrowers
+        SET first_name = $1, last_name = $2, email = $3, phone = $4,
+            date_of_birth = $5, ssn = $6, ssn_index = $7, monthly_income_cents = $8
+        WHERE id = $9
+        "#,
+    )
+    .bind(&result.first_name)
+    .bind(&result.last_name)
+    .bind(&result.email)
+    .bind(&result.phone)
+    .bind(shared::crypto::Sealed(result.date_of_birth))
+    .bind(shared::crypto::Sealed(result.ssn.clone()))
+    .bind(ssn_index)
+    .bind(result.monthly_income_cents)
+    .bind(survivor.id)
+    .execute(&mut *tx)
+    .await?;
+
+    // Each statement moves one table's rows and returns what it moved
+    let mut moved: Vec<Vec<i32>> = Vec::new();
+    for sql in [
+        "UPDATE loans SET borrower_id = $1 WHERE borrower_id = $2 RETURNING id",
+        "UPDATE loan_co_borrowers SET borrower_id = $1 WHERE borrower_id = $2 RETURNING loan_id",
+        "UPDATE notes SET borrower_id = $1 WHERE borrower_id = $2 RETURNING id",
+        "UPDATE liabilities SET borrower_id = $1 WHERE borrower_id = $2 RETURNING id",
+        "UPDATE employments SET borrower_id = $1 WHERE borrower_id = $2 RETURNING id",
+        "UPDATE assets SET borrower_id = $1 WHERE borrower_id = $2 RETURNING id",
+    ] {
+        let ids: Vec<(i32,)> = sqlx::query_as(sql)
+            .bind(survivor.id)
+            .bind(merged.id)
+            .fetch_all(&mut *tx)
+            .await?;
+        moved.push(ids.into_iter().map(|(id,)| id).collect());
+    }
+
+    sqlx::query("UPDATE borrowers SET merged_into = $1 WHERE id = $2")
+        .bind(survivor.id)
+        .bind(merged.id)
+        .execute(&mut *tx)
+        .await?;
+
+    let merge = match sqlx::query_as::<_, BorrowerMerge>(
+        r#"
+        INSERT INTO borrower_merges (
+            survivor_id, merged_id, taken_fields,
+            prior_first_name, prior_last_name, prior_email, prior_phone,
+            prior_date_of_birth, prior_ssn, prior_monthly_income_cents,
+            loan_ids, co_borrower_loan_ids, note_ids, liability_ids, employment_ids, asset_ids,
+            merged_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+        RETURNING *
+        "#,
+    )
+    .bind(survivor.id)
+    .bind(merged.id)
+    .bind(&selection.from_merged)
+    .bind(&survivor.first_name)
+    .bind(&survivor.last_name)
+    .bind(&survivor.email)
+    .bind(&survivor.phone)
+    .bind(shared::crypto::Sealed(survivor.date_of_birth))
+    .bind(shared::crypto::Sealed(survivor.ssn.clone()))
+    .bind(survivor.monthly_income_cents)
+    .bind(&moved[0])
+    .bind(&moved[1])
+    .bind(&moved[2])
+    .bind(&moved[3])
+    .bind(&moved[4])
+    .bind(&moved[5])
+    .bind(actor.id)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(merge) => merge,
+        Err(e) => {
+            tracing::error!("Failed to record merge of borrower {} into {}: {}", merged.id, survivor.id, e);
+            return Err(ServerFnError::ServerError("Failed to merge the borrowers".into()));
+        }
+    };
+
+    let taken: Vec<String> = selection.from_merged.iter().map(ToString::to_string).collect();
+    let detail = format!(
+        "Merged borrower #{} ({}) into #{} ({}); moved {} loans, {} co-applications, {} notes, {} liabilities, {} employments and {} assets; took {} from #{}",
+        merged.id,
+        merged.full_name(),
+        survivor.id,
+        survivor.full_name(),
+        merge.loan_ids.len(),
+        merge.co_borrower_loan_ids.len(),
+        merge.note_ids.len(),
+        merge.liability_ids.len(),
+        merge.employment_ids.len(),
+        merge.asset_ids.len(),
+        if taken.is_empty() { "no fields".to_string() } else { taken.join(", ") },
+        merged.id,
+    );
+    sqlx::query("INSERT INTO audit_log (action, actor_id, entity, entity_id, detail) VALUES ($1, $2, 'borrower', $3, $4)")
+        .bind(AuditAction::BorrowerMerge)
+        .bind(actor.id)
+        .bind(survivor.id)
+        .bind(&detail)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    tracing::info!("User {}: {}", actor.id, detail);
+
+    Ok(merge.mask_pii())
+}
+
+/// Undoes a borrower merge within its undo window; requires `ProcessLoans`
+///
+/// The rows the merge moved go back to the merged record, which is shown
+/// again, and the survivor's fields taken from it get their old values back.
+/// Rows added to the survivor since the merge stay with it. A merge whose
+/// survivor was itself merged away later must wait for that merge to be
+/// undone first.
+#[server]
+pub async fn undo_borrower_merge(merge_id: i32) -> Result<BorrowerMerge, ServerFnError> {
+    use shared::models::{AuditAction, Borrower, Permission};
+    use shared::masking::MaskPii;
+
+    let actor = crate::users::session_user_with(Permission::ProcessLoans, "You cannot undo borrower merges").await?;
+
+    let db = crate::get_db().await;
+    let mut tx = db.begin().await?;
+
+    let merge = sqlx::query_as::<_, BorrowerMerge>("SELECT * FROM borrower_merges WHERE id = $1 FOR UPDATE")
+        .bind(merge_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+    let Some(merge) = merge else {
+        return Err(ServerFnError::Request(format!("Merge {} not found", merge_id)));
+    };
+    let (now,): (sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,) =
+        sqlx::query_as("SELECT NOW()").fetch_one(&mut *tx).await?;
+    if merge.undone_at.is_some() {
+        return Err(ServerFnError::Request("This merge has already been undone".to_string()));
+    }
+    if !merge.can_undo(now) {
+        return Err(ServerFnError::Request(format!(
+            "Merges can only be undone within {} hours",
+            shared::models::BORROWER_MERGE_UNDO_HOURS
+        )));
+    }
+
+    let survivor =
+        sqlx::query_as::<_, Borrower>("SELECT * FROM borrowers WHERE id = $1 AND merged_into IS NULL FOR UPDATE")
+            .bind(merge.survivor_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+    let Some(survivor) = survivor else {
+        return Err(ServerFnError::Request(
+            "The surviving borrower has since been merged into another; undo that merge first".to_string(),
+        ));
+    };
+
+    let moves: [(&str, &Vec<i32>); 6] = [
+        ("UPDATE loans SET borrower_id = $1 WHERE id = ANY($3) AND borrower_id = $2", &merge.loan_ids),
+        (
+            "UPDATE loan_co_borrowers SET borrower_id = $1 WHERE loan_id = ANY($3) AND borrower_id = $2",
+            &merge.co_borrower_loan_ids,
+        ),
+        ("UPDATE notes SET borrower_id = $1 WHERE id = ANY($3) AND borrower_id = $2", &merge.note_ids),
+        ("UPDATE liabilities SET borrower_id = $1 WHERE id = ANY($3) AND borrower_id = $2", &merge.liability_ids),
+        ("UPDATE employments SET borrower_id = $1 WHERE id = ANY($3) AND borrower_id = $2", &merge.employment_ids),
+        ("UPDATE assets SET borrower_id = $1 WHERE id = ANY($3) AND borrower_id = $2", &merge.asset_ids),
+    ];
+    for (sql, ids) in moves {
+        sqlx::query(sql)
+            .bind(merge.merged_id)
+            .bind(merge.survivor_id)
+            .bind(ids)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let restored = merge.restore(&survivor);
+    let ssn_index = match &restored.ssn {
+        Some(ssn) => Some(shared::crypto::blind_index(shared::crypto::PiiField::Ssn, ssn)?),
+        None => None,
+    };
+    sqlx::query(
+        r#"
+        UPDATE borrowers
+        SET first_name = $1, last_name = $2, email = $3, phone = $4,
+            date_of_birth = $5, ssn = $6, ssn_index = $7, monthly_income_cents = $8
+        WHERE id = $9
+        "#,
+    )
+    .bind(&restored.first_name)
+    .bind(&restored.last_name)
+    .bind(&restored.email)
+    .bind(&restored.phone)
+    .bind(shared::crypto::Sealed(restored.date_of_birth))
+    .bind(shared::crypto::Sealed(restored.ssn.clone()))
+    .bind(ssn_index)
+    .bind(restored.monthly_income_cents)
+    .bind(survivor.id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("UPDATE borrowers SET merged_into = NULL WHERE id = $1")
+        .bind(merge.merged_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let undone = match sqlx::query_as::<_, BorrowerMerge>(
+        "UPDATE borrower_merges SET undone_at = NOW(), undone_by = $1 WHERE id = $2 RETURNING *",
+    )
+    .bind(actor.id)
+    .bind(merge.id)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(undone) => undone,
+        Err(e) => {
+            tracing::error!("Failed to undo borrower merge {}: {}", merge.id, e);
+            return Err(ServerFnError::ServerError("Failed to undo the merge".into()));
+        }
+    };
+
+    let detail = format!(
+        "Undid merge {} of borrower #{} into #{} ({})",
+        merge.id,
+        merge.merged_id,
+        merge.survivor_id,
+        restored.full_name()
+    );
+    sqlx::query("INSERT INTO audit_log (action, actor_id, entity, entity_id, detail) VALUES ($1, $2, 'borrower', $3, $4)")
+        .bind(AuditAction::BorrowerMergeUndo)
+        .bind(actor.id)
+        .bind(merge.survivor_id)
+        .bind(&detail)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    tracing::info!("User {}: {}", actor.id, detail);
+
+    Ok(undone.mask_pii())
+}
+
+/// Merges made in the last 30 days, newest first
+#[server]
+pub async fn get_borrower_merges() -> Result<Vec<BorrowerMergeRow>, ServerFnError> {
+    crate::users::session_user_with(shared::models::Permission::ProcessLoans, "You cannot review borrower merges")
+        .await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, BorrowerMergeRow>(
+        r#"
+        SELECT
+            m.id,
+            m.survivor_id,
+            s.first_name || ' ' || s.last_name AS survivor_name,
+            m.merged_id,
+            d.first_name || ' ' || d.last_name AS merged_name,
+            u.first_name || ' ' || u.last_name AS merged_by_name,
+            (cardinality(m.loan_ids) + cardinality(m.co_borrower_loan_ids))::INTEGER AS loan_count,
+            m.merged_at,
+            m.undone_at
+        FROM borrower_merges m
+        JOIN borrowers s ON s.id = m.survivor_id
+        JOIN borrowers d ON d.id = m.merged_id
+        LEFT JOIN users u ON u.id = m.merged_by
+        WHERE m.merged_at > NOW() - INTERVAL '30 days'
+        ORDER BY m.merged_at DESC
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
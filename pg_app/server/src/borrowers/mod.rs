@@ -0,0 +1,13 @@
+pub mod applicant_functions;
+pub mod borrower_functions;
+pub mod borrower_import_functions;
+pub mod liability_functions;
+pub mod merge_functions;
+pub mod pii_functions;
+
+pub use applicant_functions::{get_employments, get_assets, update_borrower_ssn, update_borrower_date_of_birth};
+pub use borrower_functions::{get_all_borrowers, get_borrower, create_borrower, update_borrower, delete_borrower};
+pub use borrower_import_functions::{preview_borrower_import, commit_borrower_import};
+pub use liability_functions::{get_liabilities, create_liability, update_liability, delete_liability, import_tradelines, get_loan_debts};
+pub use merge_functions::{get_duplicate_candidates, merge_borrowers, undo_borrower_merge, get_borrower_merges};
+pub use pii_functions::reveal_pii;
@@ -0,0 +1,65 @@
+// pg_app/server/src/borrowers/pii_functions.rs
+use dioxus::prelude::*;
+use shared::masking::RevealField;
+
+/// A masked SSN, date of birth or account number in full; the signed-in
+/// user needs `RevealPii`, and every reveal is written to the audit log
+///
+/// `entity_id` is the borrower for an SSN or date of birth and the asset for
+/// an account number.
+#[server]
+pub async fn reveal_pii(field: RevealField, entity_id: i32) -> Result<String, ServerFnError> {
+    use shared::models::{Asset, AuditAction, Borrower, Permission};
+
+    let actor = crate::users::session_user_with(Permission::RevealPii, "You cannot reveal masked PII").await?;
+
+    let db = crate::get_db().await;
+
+    let (value, owner) = match field {
+        RevealField::Ssn | RevealField::DateOfBirth => {
+            let borrower = sqlx::query_as::<_, Borrower>("SELECT * FROM borrowers WHERE id = $1")
+                .bind(entity_id)
+                .fetch_optional(db)
+                .await?;
+            let Some(borrower) = borrower else {
+                return Err(ServerFnError::Request(format!("Borrower #{} not found", entity_id)));
+            };
+            let value = match field {
+                RevealField::Ssn => borrower.ssn.as_deref().map(shared::masking::format_ssn),
+                _ => borrower.date_of_birth.map(|d| d.format("%m/%d/%Y").to_string()),
+            };
+            (value, format!("borrower #{} ({})", borrower.id, borrower.full_name()))
+        }
+        RevealField::AccountNumber => {
+            let asset = sqlx::query_as::<_, Asset>("SELECT * FROM assets WHERE id = $1")
+                .bind(entity_id)
+                .fetch_optional(db)
+                .await?;
+            let Some(asset) = asset else {
+                return Err(ServerFnError::Request(format!("Asset #{} not found", entity_id)));
+            };
+            (asset.account_number, format!("asset #{} ({})", asset.id, asset.institution_name))
+        }
+    };
+    let Some(value) = value else {
+        return Err(ServerFnError::Request(format!("No {} on file", field)));
+    };
+
+    // The value is only returned once the reveal is on record
+    let detail = format!("Revealed {} of {}", field, owner);
+    let logged = sqlx::query("INSERT INTO audit_log (action, actor_id, entity, entity_id, detail) VALUES ($1, $2, $3, $4, $5)")
+        .bind(AuditAction::PiiReveal)
+        .bind(actor.id)
+        .bind(field.entity())
+        .bind(entity_id)
+        .bind(&detail)
+        .execute(db)
+        .await;
+    if let Err(e) = logged {
+        tracing::error!("Failed to audit PII reveal by user {}: {}", actor.id, e);
+        return Err(ServerFnError::ServerError("Failed to record the reveal".into()));
+    }
+    tracing::info!("User {}: {}", actor.id, detail);
+
+    Ok(value)
+}
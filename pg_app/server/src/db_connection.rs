@@ -11,11 +11,24 @@ static DB: OnceCell<PgPool> = OnceCell::const_new();
 pub async fn init_db() -> Result<PgPool, sqlx::Error> {
     dotenv().ok();
     let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    // SSNs, dates of birth and account numbers are sealed with these keys
+    if let Err(e) = shared::crypto::keyring() {
+        panic!("PII_KEYS and PII_INDEX_KEY must be set: {}", e);
+    }
     let connection_pool = PgPool::connect(&database_url).await?;
     sqlx::migrate!("./../migrations").run(&connection_pool).await?;
     Ok(connection_pool)
 }
 
+/// The app's database pool, connected on first use
+///
+/// Connecting also starts the scheduled rate lock check. One-off commands
+/// use [`init_db`] so they do not start it.
 pub async fn get_db() -> &'static PgPool {
-    DB.get_or_init(|| async { init_db().await.expect("Failed to initialize database") }).await
+    DB.get_or_init(|| async {
+        let pool = init_db().await.expect("Failed to initialize database");
+        crate::loans::start_rate_lock_alerts(pool.clone());
+        pool
+    })
+    .await
 }
\ No newline at end of file
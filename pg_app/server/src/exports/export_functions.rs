@@ -0,0 +1,91 @@
+// pg_app/server/src/exports/export_functions.rs
+use dioxus::prelude::*;
+use shared::dtos::DownloadFile;
+use shared::exports::ExportRequest;
+
+/// A list view as a CSV or XLSX file, exported by the signed-in user
+///
+/// Rows are written to the file as they stream in from the database rather
+/// than being collected first, so a large export only holds the file in
+/// memory. The user must be active and have the list's
+/// [`shared::exports::ExportList::permission`].
+#[server]
+pub async fn export_list(request: ExportRequest) -> Result<DownloadFile, ServerFnError> {
+    use base64::Engine;
+    use futures::TryStreamExt;
+
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    if let Some(permission) = request.list.permission()
+        && !actor.role.has_permission(permission)
+    {
+        return Err(ServerFnError::Request(format!(
+            "A {} cannot export {}",
+            actor.role,
+            request.list.to_string().to_lowercase()
+        )));
+    }
+
+    let columns = match request.columns() {
+        Ok(columns) => columns,
+        Err(e) => return Err(ServerFnError::Request(e.to_string())),
+    };
+    let sql = match request.query() {
+        Ok(sql) => sql,
+        Err(e) => return Err(ServerFnError::Request(e.to_string())),
+    };
+    let mut writer = match shared::exports::ExportWriter::new(request.format, &columns) {
+        Ok(writer) => writer,
+        Err(e) => {
+            tracing::error!("Failed to start {} export: {}", request.list, e);
+            return Err(ServerFnError::ServerError("Failed to start export".into()));
+        }
+    };
+
+    let mut query = sqlx::query(&sql);
+    if request.list == shared::exports::ExportList::Loans {
+        let filter = &request.loan_filter;
+        query = query
+            .bind(filter.loan_officer_id)
+            .bind(filter.loan_type)
+            .bind(filter.from_date)
+            .bind(filter.to_date);
+    }
+
+    let mut rows = query.fetch(db);
+    while let Some(row) = rows.try_next().await? {
+        let mut cells = Vec::with_capacity(columns.len());
+        for (index, column) in columns.iter().enumerate() {
+            cells.push(column.cell(&row, index)?);
+        }
+        if let Err(e) = writer.write_row(cells) {
+            tracing::error!("Failed to write {} export row: {}", request.list, e);
+            return Err(ServerFnError::ServerError("Failed to write export".into()));
+        }
+    }
+
+    let row_count = writer.rows();
+    let bytes = match writer.finish() {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!("Failed to finish {} export: {}", request.list, e);
+            return Err(ServerFnError::ServerError("Failed to write export".into()));
+        }
+    };
+
+    tracing::info!(
+        "User {} exported {} {} rows as {}",
+        actor.id,
+        row_count,
+        request.list.code(),
+        request.format
+    );
+    Ok(DownloadFile {
+        file_name: request.file_name(sqlx::types::chrono::Utc::now().date_naive()),
+        content_type: request.format.content_type().to_string(),
+        base64: base64::engine::general_purpose::STANDARD.encode(bytes),
+        rows: row_count,
+    })
+}
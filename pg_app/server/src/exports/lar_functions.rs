@@ -0,0 +1,154 @@
+// pg_app/server/src/exports/lar_functions.rs
+use dioxus::prelude::*;
+use shared::dtos::LarExport;
+
+/// The HMDA LAR for `year`, with the edits it fails; requires `ManageSettings`
+///
+/// Covers every loan whose action taken date falls in the year. The file is
+/// returned even when edits fail so the failures can be worked from it, but
+/// the HMDA Platform will reject it until the list is empty.
+#[server]
+pub async fn export_lar(year: i32) -> Result<LarExport, ServerFnError> {
+    use base64::Engine;
+    use shared::hmda::LarLoan;
+    use shared::models::{ArmTerms, HmdaData, HmdaFiler, Loan, LoanFee, Property};
+    use std::collections::HashMap;
+
+    #[derive(sqlx::FromRow)]
+    struct Applicants {
+        loan_id: i32,
+        nmls_id: Option<String>,
+        has_co_applicant: bool,
+        monthly_income_cents: i64,
+        lender_credits_cents: i64,
+    }
+
+    let actor = crate::users::session_user_with(
+        shared::models::Permission::ManageSettings,
+        "Only managers can export the HMDA LAR",
+    )
+    .await?;
+
+    let db = crate::get_db().await;
+
+    let filer = sqlx::query_as::<_, HmdaFiler>("SELECT * FROM hmda_filer").fetch_optional(db).await?;
+    let Some(filer) = filer else {
+        return Err(ServerFnError::Request("Enter the HMDA filer details in Settings first".to_string()));
+    };
+
+    let hmda = sqlx::query_as::<_, HmdaData>(
+        r#"
+        SELECT * FROM loan_hmda
+        WHERE action_taken IS NOT NULL AND EXTRACT(YEAR FROM action_taken_date) = $1
+        ORDER BY action_taken_date, loan_id
+        "#,
+    )
+    .bind(year)
+    .fetch_all(db)
+    .await?;
+    let loan_ids: Vec<i32> = hmda.iter().map(|data| data.loan_id).collect();
+
+    let mut loans: HashMap<i32, Loan> = sqlx::query_as::<_, Loan>("SELECT * FROM loans WHERE id = ANY($1)")
+        .bind(&loan_ids)
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(|loan| (loan.id, loan))
+        .collect();
+    let mut properties: HashMap<i32, Property> =
+        sqlx::query_as::<_, Property>("SELECT * FROM properties WHERE loan_id = ANY($1)")
+            .bind(&loan_ids)
+            .fetch_all(db)
+            .await?
+            .into_iter()
+            .map(|property| (property.loan_id, property))
+            .collect();
+    let mut arm_terms: HashMap<i32, ArmTerms> = sqlx::query_as::<_, ArmTerms>(
+        r#"
+        SELECT
+            loan_id, initial_fixed_months, adjustment_interval_months, rate_index, index_value,
+            margin, initial_cap, periodic_cap, lifetime_cap, floor_rate
+        FROM arm_terms
+        WHERE loan_id = ANY($1)
+        "#,
+    )
+    .bind(&loan_ids)
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|terms| (terms.loan_id, terms))
+    .collect();
+    let mut fees: HashMap<i32, Vec<LoanFee>> = HashMap::new();
+    for fee in sqlx::query_as::<_, LoanFee>("SELECT * FROM loan_fees WHERE loan_id = ANY($1) ORDER BY section, id")
+        .bind(&loan_ids)
+        .fetch_all(db)
+        .await?
+    {
+        fees.entry(fee.loan_id).or_default().push(fee);
+    }
+    let mut applicants: HashMap<i32, Applicants> = sqlx::query_as::<_, Applicants>(
+        r#"
+        SELECT
+            l.id AS loan_id,
+            u.nmls_id,
+            EXISTS (SELECT 1 FROM loan_co_borrowers cb WHERE cb.loan_id = l.id) AS has_co_applicant,
+            (b.monthly_income_cents + COALESCE((
+                SELECT SUM(co.monthly_income_cents)
+                FROM loan_co_borrowers cb
+                JOIN borrowers co ON co.id = cb.borrower_id
+                WHERE cb.loan_id = l.id
+            ), 0))::BIGINT AS monthly_income_cents,
+            COALESCE(ca.lender_credits_cents, 0) AS lender_credits_cents
+        FROM loans l
+        JOIN borrowers b ON b.id = l.borrower_id
+        LEFT JOIN users u ON u.id = l.loan_officer_id
+        LEFT JOIN closing_adjustments ca ON ca.loan_id = l.id
+        WHERE l.id = ANY($1)
+        "#,
+    )
+    .bind(&loan_ids)
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|row| (row.loan_id, row))
+    .collect();
+
+    let mut sources = Vec::with_capacity(hmda.len());
+    for data in hmda {
+        let loan_id = data.loan_id;
+        let (Some(loan), Some(people)) = (loans.remove(&loan_id), applicants.remove(&loan_id)) else {
+            continue;
+        };
+        sources.push(LarLoan {
+            loan,
+            property: properties.remove(&loan_id),
+            hmda: data,
+            has_co_applicant: people.has_co_applicant,
+            monthly_income_cents: people.monthly_income_cents,
+            nmls_id: people.nmls_id,
+            fees: fees.remove(&loan_id).unwrap_or_default(),
+            lender_credits_cents: people.lender_credits_cents,
+            arm_terms: arm_terms.remove(&loan_id),
+        });
+    }
+
+    let lar = shared::hmda::lar::build_lar(&filer, year, &sources);
+    let edits = shared::hmda::edits::check_lar(&lar, year);
+
+    tracing::info!(
+        "User {} exported the {} LAR: {} records, {} edit failures",
+        actor.id,
+        year,
+        lar.rows.len(),
+        edits.len()
+    );
+    Ok(LarExport {
+        file: shared::dtos::DownloadFile {
+            file_name: lar.file_name(),
+            content_type: "text/plain".to_string(),
+            base64: base64::engine::general_purpose::STANDARD.encode(lar.to_pipe_delimited()),
+            rows: lar.rows.len(),
+        },
+        edits,
+    })
+}
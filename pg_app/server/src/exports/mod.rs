@@ -0,0 +1,5 @@
+pub mod export_functions;
+pub mod lar_functions;
+
+pub use export_functions::export_list;
+pub use lar_functions::export_lar;
@@ -1,6 +1,13 @@
 //pg_app/server/src/lib.rs
 pub mod post_functions;  // Contains server logic (e.g., handling requests, etc.)
 pub mod users;             // Contains user management logic (e.g., authentication, CRUD operations)
+pub mod borrowers;         // Borrowers and their liabilities
+pub mod loans;             // Loans and their subject properties
+pub mod notifications;     // In-app notifications
+pub mod notes;             // Threaded notes on loans and borrowers
+pub mod tasks;             // Loan tasks and their checklists
+pub mod settings;          // Company-wide settings such as the PMI rate table
+pub mod exports;           // CSV and XLSX exports of the list views
 
 pub mod db_connection;
 pub use db_connection::{get_db, init_db};
\ No newline at end of file
@@ -0,0 +1,246 @@
+// pg_app/server/src/loans/adverse_action_functions.rs
+use dioxus::prelude::*;
+use shared::dtos::PendingNoticeRow;
+use shared::models::{AdverseAction, DenialInput, NoticeDelivery};
+
+/// Returns the denial recorded for a loan, if any
+#[server]
+pub async fn get_adverse_action(loan_id: i32) -> Result<Option<AdverseAction>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, AdverseAction>("SELECT * FROM adverse_actions WHERE loan_id = $1")
+        .bind(loan_id)
+        .fetch_optional(db)
+        .await?;
+
+    Ok(result)
+}
+
+/// Denies an application with its reasons and generates the adverse action notice
+///
+/// The move to Denied goes through the same checks as any other pipeline
+/// move and is recorded in `loan_status_history`. The reasons are also
+/// copied to the loan's HMDA data as action taken "denied". A loan that was
+/// denied before reasons were required may have them recorded this way too.
+///
+/// The notice is generated after the denial is saved; if that fails the
+/// denial stands and the notice can be generated again from the loan page.
+#[server]
+pub async fn deny_loan(loan_id: i32, input: DenialInput) -> Result<AdverseAction, ServerFnError> {
+    use shared::models::{ActionTaken, DocumentKind, Loan, LoanStatus, Permission};
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+    let other_reason = input
+        .other_reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|text| !text.is_empty() && input.reasons.contains(&shared::models::DenialReason::Other));
+
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+    let mut tx = db.begin().await?;
+
+    let loan = sqlx::query_as::<_, Loan>("SELECT * FROM loans WHERE id = $1 FOR UPDATE")
+        .bind(loan_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+    let Some(loan) = loan else {
+        return Err(ServerFnError::Request(format!("Loan {} not found", loan_id)));
+    };
+
+    if loan.status == LoanStatus::Denied {
+        let recorded: Option<(i32,)> = sqlx::query_as("SELECT loan_id FROM adverse_actions WHERE loan_id = $1")
+            .bind(loan_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        if recorded.is_some() {
+            return Err(ServerFnError::Request("The reasons for this denial are already recorded".to_string()));
+        }
+        let allowed = actor.has_permission(Permission::ProcessLoans)
+            || (actor.has_permission(Permission::EditOwnLoans) && loan.loan_officer_id == Some(actor.id));
+        if !allowed {
+            return Err(ServerFnError::Request("You cannot record denial reasons on this loan".to_string()));
+        }
+    } else {
+        if let Err(e) =
+            shared::models::check_transition(actor.role, actor.id, loan.loan_officer_id, loan.status, LoanStatus::Denied)
+        {
+            return Err(ServerFnError::Request(e.to_string()));
+        }
+
+        sqlx::query("UPDATE loans SET status = $1 WHERE id = $2")
+            .bind(LoanStatus::Denied)
+            .bind(loan_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            "INSERT INTO loan_status_history (loan_id, from_status, to_status, changed_by) VALUES ($1, $2, $3, $4)",
+        )
+        .bind(loan_id)
+        .bind(loan.status)
+        .bind(LoanStatus::Denied)
+        .bind(actor.id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let mut denial = match sqlx::query_as::<_, AdverseAction>(
+        r#"
+        INSERT INTO adverse_actions (loan_id, reasons, other_reason, decided_date, decided_by)
+        VALUES ($1, $2, $3, CURRENT_DATE, $4)
+        RETURNING *
+        "#,
+    )
+    .bind(loan_id)
+    .bind(&input.reasons)
+    .bind(other_reason)
+    .bind(actor.id)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(denial) => denial,
+        Err(e) => {
+            tracing::error!("Failed to record denial of loan {}: {}", loan_id, e);
+            return Err(ServerFnError::ServerError("Failed to record the denial".into()));
+        }
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO loan_hmda (loan_id, action_taken, action_taken_date, denial_reasons, updated_by)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (loan_id) DO UPDATE SET
+            action_taken = EXCLUDED.action_taken,
+            action_taken_date = EXCLUDED.action_taken_date,
+            denial_reasons = EXCLUDED.denial_reasons,
+            updated_by = EXCLUDED.updated_by
+        "#,
+    )
+    .bind(loan_id)
+    .bind(ActionTaken::Denied)
+    .bind(denial.decided_date)
+    .bind(&denial.reasons)
+    .bind(actor.id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    tracing::info!("Loan {} denied by user {} for reasons {:?}", loan_id, actor.id, denial.reasons);
+
+    match super::generate_loan_document(loan_id, DocumentKind::AdverseActionNotice).await {
+        Ok(document) => denial.document_id = Some(document.id),
+        Err(e) => tracing::error!("Failed to generate the adverse action notice for loan {}: {}", loan_id, e),
+    }
+
+    Ok(denial)
+}
+
+/// Records when and how the adverse action notice reached the applicant
+///
+/// `ProcessLoans` may record it on any loan; `EditOwnLoans` on loans
+/// assigned to the signed-in user. The notice must have been generated, and the date
+/// may not be before the denial or in the future.
+#[server]
+pub async fn record_notice_delivery(
+    loan_id: i32,
+    delivery: NoticeDelivery,
+) -> Result<AdverseAction, ServerFnError> {
+    use shared::models::Permission;
+
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+    let loan_officer_id: Option<(Option<i32>,)> = sqlx::query_as("SELECT loan_officer_id FROM loans WHERE id = $1")
+        .bind(loan_id)
+        .fetch_optional(db)
+        .await?;
+    let Some((loan_officer_id,)) = loan_officer_id else {
+        return Err(ServerFnError::Request(format!("Loan {} not found", loan_id)));
+    };
+    let allowed = actor.has_permission(Permission::ProcessLoans)
+        || (actor.has_permission(Permission::EditOwnLoans) && loan_officer_id == Some(actor.id));
+    if !allowed {
+        return Err(ServerFnError::Request("You cannot record notice delivery on this loan".to_string()));
+    }
+
+    let denial = sqlx::query_as::<_, AdverseAction>("SELECT * FROM adverse_actions WHERE loan_id = $1")
+        .bind(loan_id)
+        .fetch_optional(db)
+        .await?;
+    let Some(denial) = denial else {
+        return Err(ServerFnError::Request("Record the reasons for the denial first".to_string()));
+    };
+    if denial.document_id.is_none() {
+        return Err(ServerFnError::Request("Generate the notice before recording its delivery".to_string()));
+    }
+    let (today,): (sqlx::types::chrono::NaiveDate,) = sqlx::query_as("SELECT CURRENT_DATE").fetch_one(db).await?;
+    if delivery.delivered_date < denial.decided_date {
+        return Err(ServerFnError::Request("The notice cannot be delivered before the denial".to_string()));
+    }
+    if delivery.delivered_date > today {
+        return Err(ServerFnError::Request("The delivery date cannot be in the future".to_string()));
+    }
+
+    sqlx::query_as::<_, AdverseAction>(
+        r#"
+        UPDATE adverse_actions
+        SET delivered_date = $1, delivery_method = $2, delivered_by = $3
+        WHERE loan_id = $4
+        RETURNING *
+        "#,
+    )
+    .bind(delivery.delivered_date)
+    .bind(delivery.delivery_method)
+    .bind(actor.id)
+    .bind(loan_id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to record notice delivery for loan {}: {}", loan_id, e);
+        ServerFnError::ServerError("Failed to record notice delivery".into())
+    })
+}
+
+/// Denied applications whose notice has not been delivered, oldest denial first
+///
+/// Loans denied before reasons were recorded are included, dated by their
+/// move to Denied.
+#[server]
+pub async fn get_pending_notices() -> Result<Vec<PendingNoticeRow>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, PendingNoticeRow>(
+        r#"
+        SELECT
+            l.id AS loan_id,
+            l.loan_number,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            u.first_name || ' ' || u.last_name AS loan_officer_name,
+            COALESCE(
+                a.decided_date,
+                (SELECT MAX(h.changed_at)::DATE FROM loan_status_history h
+                 WHERE h.loan_id = l.id AND h.to_status = 'denied'),
+                l.updated_at::DATE
+            ) AS decided_date,
+            a.document_id IS NOT NULL AS has_notice
+        FROM loans l
+        JOIN borrowers b ON b.id = l.borrower_id
+        LEFT JOIN users u ON u.id = l.loan_officer_id
+        LEFT JOIN adverse_actions a ON a.loan_id = l.id
+        WHERE l.status = 'denied' AND a.delivered_date IS NULL
+        ORDER BY decided_date, l.id
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
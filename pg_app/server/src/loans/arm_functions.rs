@@ -0,0 +1,96 @@
+// pg_app/server/src/loans/arm_functions.rs
+use dioxus::prelude::*;
+use shared::models::ArmTerms;
+
+/// Returns the adjustable-rate terms for a loan; `None` means a fixed-rate loan
+#[server]
+pub async fn get_arm_terms(loan_id: i32) -> Result<Option<ArmTerms>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, ArmTerms>(
+        r#"
+        SELECT
+            loan_id, initial_fixed_months, adjustment_interval_months, rate_index, index_value,
+            margin, initial_cap, periodic_cap, lifetime_cap, floor_rate
+        FROM arm_terms
+        WHERE loan_id = $1
+        "#,
+    )
+    .bind(loan_id)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Creates or replaces the adjustable-rate terms for a loan
+#[server]
+pub async fn save_arm_terms(loan_id: i32, terms: ArmTerms) -> Result<ArmTerms, ServerFnError> {
+    crate::users::session_loan_editor(loan_id, "You cannot edit this loan's ARM terms").await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&terms) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    sqlx::query_as::<_, ArmTerms>(
+        r#"
+        INSERT INTO arm_terms (
+            loan_id, initial_fixed_months, adjustment_interval_months, rate_index, index_value,
+            margin, initial_cap, periodic_cap, lifetime_cap, floor_rate
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        ON CONFLICT (loan_id) DO UPDATE SET
+            initial_fixed_months = EXCLUDED.initial_fixed_months,
+            adjustment_interval_months = EXCLUDED.adjustment_interval_months,
+            rate_index = EXCLUDED.rate_index,
+            index_value = EXCLUDED.index_value,
+            margin = EXCLUDED.margin,
+            initial_cap = EXCLUDED.initial_cap,
+            periodic_cap = EXCLUDED.periodic_cap,
+            lifetime_cap = EXCLUDED.lifetime_cap,
+            floor_rate = EXCLUDED.floor_rate
+        RETURNING
+            loan_id, initial_fixed_months, adjustment_interval_months, rate_index, index_value,
+            margin, initial_cap, periodic_cap, lifetime_cap, floor_rate
+        "#,
+    )
+    .bind(loan_id)
+    .bind(terms.initial_fixed_months)
+    .bind(terms.adjustment_interval_months)
+    .bind(terms.rate_index)
+    .bind(terms.index_value)
+    .bind(terms.margin)
+    .bind(terms.initial_cap)
+    .bind(terms.periodic_cap)
+    .bind(terms.lifetime_cap)
+    .bind(terms.floor_rate)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to save ARM terms: {}", e);
+        ServerFnError::ServerError("Failed to save ARM terms".into())
+    })
+}
+
+/// Removes the adjustable-rate terms, making the loan fixed-rate again
+#[server]
+pub async fn delete_arm_terms(loan_id: i32) -> Result<(), ServerFnError> {
+    crate::users::session_loan_editor(loan_id, "You cannot edit this loan's ARM terms").await?;
+
+    let db = crate::get_db().await;
+
+    let deleted = sqlx::query("DELETE FROM arm_terms WHERE loan_id = $1")
+        .bind(loan_id)
+        .execute(db)
+        .await;
+    if let Err(e) = deleted {
+        tracing::error!("Failed to delete ARM terms: {}", e);
+        return Err(ServerFnError::ServerError("Failed to delete ARM terms".into()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+// pg_app/server/src/loans/disclosure_functions.rs
+use dioxus::prelude::*;
+use shared::calculations::tolerance::ToleranceReport;
+use shared::models::{Disclosure, DisclosureFee, DisclosureInput};
+
+/// Issued disclosures for a loan, oldest first
+#[server]
+pub async fn get_disclosures(loan_id: i32) -> Result<Vec<Disclosure>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Disclosure>(
+        "SELECT * FROM disclosures WHERE loan_id = $1 ORDER BY issued_date, id",
+    )
+    .bind(loan_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Records a disclosure issued by the signed-in user and freezes the loan's
+/// current fee worksheet on it
+#[server]
+pub async fn issue_disclosure(loan_id: i32, input: DisclosureInput) -> Result<Disclosure, ServerFnError> {
+    let issued_by = crate::users::session_user().await?.id;
+
+    let db = crate::get_db().await;
+
+    let mut tx = db.begin().await?;
+
+    // Leads imported without terms have nothing to disclose yet
+    let (note_rate,): (Option<f64>,) = sqlx::query_as("SELECT note_rate FROM loans WHERE id = $1 FOR SHARE")
+        .bind(loan_id)
+        .fetch_one(&mut *tx)
+        .await?;
+    if note_rate.is_none() {
+        return Err(ServerFnError::Request("Set the note rate before issuing a disclosure".into()));
+    }
+
+    let disclosure = match sqlx::query_as::<_, Disclosure>(
+        r#"
+        INSERT INTO disclosures (loan_id, kind, issued_date, issued_by, lender_credits_cents)
+        SELECT $1, $2, $3, $4, COALESCE(
+            (SELECT lender_credits_cents FROM closing_adjustments WHERE loan_id = $1),
+            0
+        )
+        RETURNING *
+        "#,
+    )
+    .bind(loan_id)
+    .bind(input.kind)
+    .bind(input.issued_date)
+    .bind(issued_by)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(disclosure) => disclosure,
+        Err(e) => {
+            tracing::error!("Failed to issue disclosure: {}", e);
+            return Err(ServerFnError::ServerError("Failed to issue disclosure".into()));
+        }
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO disclosure_fees (
+            disclosure_id, loan_fee_id, section, name, amount_cents, payer, paid_to, finance_charge,
+            off_list_provider, recording_fee
+        )
+        SELECT $1, id, section, name, amount_cents, payer, paid_to, finance_charge, off_list_provider, recording_fee
+        FROM loan_fees
+        WHERE loan_id = $2
+        ORDER BY section, id
+        "#,
+    )
+    .bind(disclosure.id)
+    .bind(loan_id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(disclosure)
+}
+
+/// Fees frozen on a disclosure, in section order
+#[server]
+pub async fn get_disclosure_fees(disclosure_id: i32) -> Result<Vec<DisclosureFee>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, DisclosureFee>(
+        "SELECT * FROM disclosure_fees WHERE disclosure_id = $1 ORDER BY section, id",
+    )
+    .bind(disclosure_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Tolerance comparison of a Loan Estimate against a later disclosure
+#[server]
+pub async fn get_tolerance_report(disclosed_id: i32, final_id: i32) -> Result<ToleranceReport, ServerFnError> {
+    let disclosed = get_disclosure_fees(disclosed_id).await?;
+    let final_fees = get_disclosure_fees(final_id).await?;
+
+    let db = crate::get_db().await;
+
+    let credits = |id: i32| async move {
+        sqlx::query_as::<_, (i64,)>("SELECT lender_credits_cents FROM disclosures WHERE id = $1")
+            .bind(id)
+            .fetch_optional(db)
+            .await
+    };
+    let (Some((disclosed_credits,)), Some((final_credits,))) = (credits(disclosed_id).await?, credits(final_id).await?)
+    else {
+        return Err(ServerFnError::Request("Disclosure not found".into()));
+    };
+
+    Ok(ToleranceReport::compare(&disclosed, &final_fees).with_lender_credits(disclosed_credits, final_credits))
+}
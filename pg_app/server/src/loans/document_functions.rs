@@ -0,0 +1,156 @@
+// pg_app/server/src/loans/document_functions.rs
+use dioxus::prelude::*;
+use shared::dtos::DownloadFile;
+use shared::models::{DocumentKind, LoanDocument};
+
+/// Documents saved on a loan, newest first, without their contents
+#[server]
+pub async fn get_loan_documents(loan_id: i32) -> Result<Vec<LoanDocument>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, LoanDocument>(
+        r#"
+        SELECT id, loan_id, kind, file_name, content_type, size_bytes, created_by, created_at
+        FROM loan_documents
+        WHERE loan_id = $1
+        ORDER BY created_at DESC, id DESC
+        "#,
+    )
+    .bind(loan_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Renders a PDF from the loan's current data and saves it on the loan
+///
+/// The signed-in user must be active and able to view loans. Each call saves a new
+/// document, so earlier letters stay on file as they were sent. An adverse
+/// action notice needs the loan's denial on file and becomes its notice.
+#[server]
+pub async fn generate_loan_document(
+    loan_id: i32,
+    kind: DocumentKind,
+) -> Result<LoanDocument, ServerFnError> {
+    let actor = crate::users::session_user().await?;
+    if !actor.role.has_permission(shared::models::Permission::ViewLoans) {
+        return Err(ServerFnError::Request(format!("A {} cannot generate loan documents", actor.role)));
+    }
+
+    let db = crate::get_db().await;
+
+    let loan = super::get_loan(loan_id).await?;
+    let borrower = crate::borrowers::get_borrower(loan.borrower_id).await?;
+    let property = super::get_property(loan_id).await?;
+    let loan_officer = match loan.loan_officer_id {
+        Some(id) => Some(crate::users::get_user(id).await?),
+        None => None,
+    };
+    let adverse_action =
+        sqlx::query_as::<_, shared::models::AdverseAction>("SELECT * FROM adverse_actions WHERE loan_id = $1")
+            .bind(loan_id)
+            .fetch_optional(db)
+            .await?;
+    if kind == DocumentKind::AdverseActionNotice && adverse_action.is_none() {
+        return Err(ServerFnError::Request(
+            "Only a denial with its reasons recorded has an adverse action notice".to_string(),
+        ));
+    }
+    let data = shared::documents::DocumentData {
+        loan,
+        borrower,
+        property,
+        loan_officer,
+        adverse_action,
+    };
+
+    let today = sqlx::types::chrono::Utc::now().date_naive();
+    let content = match shared::documents::render_document(kind, &data, today) {
+        Ok(content) => content,
+        Err(e) => {
+            tracing::error!("Failed to render {} for loan {}: {}", kind, loan_id, e);
+            return Err(ServerFnError::ServerError(format!("Failed to render {}", kind)));
+        }
+    };
+
+    let document = match sqlx::query_as::<_, LoanDocument>(
+        r#"
+        INSERT INTO loan_documents (loan_id, kind, file_name, content_type, content, size_bytes, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id, loan_id, kind, file_name, content_type, size_bytes, created_by, created_at
+        "#,
+    )
+    .bind(loan_id)
+    .bind(kind)
+    .bind(shared::documents::file_name(kind, &data.loan, today))
+    .bind(shared::documents::CONTENT_TYPE)
+    .bind(&content)
+    .bind(content.len() as i32)
+    .bind(actor.id)
+    .fetch_one(db)
+    .await
+    {
+        Ok(document) => document,
+        Err(e) => {
+            tracing::error!("Failed to save {} for loan {}: {}", kind, loan_id, e);
+            return Err(ServerFnError::ServerError("Failed to save document".into()));
+        }
+    };
+
+    if kind == DocumentKind::AdverseActionNotice
+        && let Err(e) = sqlx::query("UPDATE adverse_actions SET document_id = $1 WHERE loan_id = $2")
+            .bind(document.id)
+            .bind(loan_id)
+            .execute(db)
+            .await
+    {
+        tracing::error!("Failed to link notice {} to the denial of loan {}: {}", document.id, loan_id, e);
+        return Err(ServerFnError::ServerError("Failed to save document".into()));
+    }
+
+    tracing::info!("User {} generated {} {} for loan {}", actor.id, kind, document.id, loan_id);
+    Ok(document)
+}
+
+/// A saved document's file, ready to download, for the loan's officer or
+/// users who process loans
+#[server]
+pub async fn download_loan_document(document_id: i32) -> Result<DownloadFile, ServerFnError> {
+    use base64::Engine;
+
+    let db = crate::get_db().await;
+
+    let loan_id = document_loan(db, document_id).await?;
+    crate::users::session_loan_editor(loan_id, "You cannot download this loan's documents").await?;
+
+    let row: Option<(String, String, Vec<u8>)> =
+        sqlx::query_as("SELECT file_name, content_type, content FROM loan_documents WHERE id = $1")
+            .bind(document_id)
+            .fetch_optional(db)
+            .await?;
+    let Some((file_name, content_type, content)) = row else {
+        return Err(ServerFnError::Request(format!("Document {} not found", document_id)));
+    };
+
+    Ok(DownloadFile {
+        file_name,
+        content_type,
+        base64: base64::engine::general_purpose::STANDARD.encode(content),
+        rows: 0,
+    })
+}
+
+/// Loan that document `id` is saved on
+pub async fn document_loan(db: &sqlx::PgPool, id: i32) -> Result<i32, ServerFnError> {
+    let loan_id: Option<(i32,)> = sqlx::query_as("SELECT loan_id FROM loan_documents WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?;
+    match loan_id {
+        Some((loan_id,)) => Ok(loan_id),
+        None => Err(ServerFnError::Request(format!("Document {} not found", id))),
+    }
+}
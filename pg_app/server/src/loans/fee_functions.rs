@@ -0,0 +1,194 @@
+// pg_app/server/src/loans/fee_functions.rs
+use dioxus::prelude::*;
+use shared::models::{ClosingAdjustments, LoanFee, LoanFeeInput};
+
+/// Fee worksheet lines for a loan, in section order
+#[server]
+pub async fn get_loan_fees(loan_id: i32) -> Result<Vec<LoanFee>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, LoanFee>(
+        "SELECT * FROM loan_fees WHERE loan_id = $1 ORDER BY section, id",
+    )
+    .bind(loan_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Adds a fee to the worksheet of a loan the signed-in user may edit
+#[server]
+pub async fn create_loan_fee(loan_id: i32, input: LoanFeeInput) -> Result<LoanFee, ServerFnError> {
+    crate::users::session_loan_editor(loan_id, "You cannot edit this loan's fees").await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    sqlx::query_as::<_, LoanFee>(
+        r#"
+        INSERT INTO loan_fees (
+            loan_id, section, name, amount_cents, payer, paid_to, finance_charge, off_list_provider,
+            recording_fee
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING *
+        "#,
+    )
+    .bind(loan_id)
+    .bind(input.section)
+    .bind(input.name.trim())
+    .bind(input.amount_cents)
+    .bind(input.payer)
+    .bind(input.paid_to.as_deref().map(str::trim).filter(|p| !p.is_empty()))
+    .bind(input.finance_charge)
+    .bind(input.off_list_provider)
+    .bind(input.recording_fee)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to create fee: {}", e);
+        ServerFnError::ServerError("Failed to create fee".into())
+    })
+}
+
+/// Changes a fee on the worksheet of a loan the signed-in user may edit
+#[server]
+pub async fn update_loan_fee(id: i32, input: LoanFeeInput) -> Result<LoanFee, ServerFnError> {
+    let db = crate::get_db().await;
+
+    let loan_id = fee_loan(db, id).await?;
+    crate::users::session_loan_editor(loan_id, "You cannot edit this loan's fees").await?;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    sqlx::query_as::<_, LoanFee>(
+        r#"
+        UPDATE loan_fees
+        SET
+            section = $1,
+            name = $2,
+            amount_cents = $3,
+            payer = $4,
+            paid_to = $5,
+            finance_charge = $6,
+            off_list_provider = $7,
+            recording_fee = $8
+        WHERE id = $9
+        RETURNING *
+        "#,
+    )
+    .bind(input.section)
+    .bind(input.name.trim())
+    .bind(input.amount_cents)
+    .bind(input.payer)
+    .bind(input.paid_to.as_deref().map(str::trim).filter(|p| !p.is_empty()))
+    .bind(input.finance_charge)
+    .bind(input.off_list_provider)
+    .bind(input.recording_fee)
+    .bind(id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to update fee: {}", e);
+        ServerFnError::ServerError("Failed to update fee".into())
+    })
+}
+
+/// Removes a fee from the worksheet of a loan the signed-in user may edit
+#[server]
+pub async fn delete_loan_fee(id: i32) -> Result<(), ServerFnError> {
+    let db = crate::get_db().await;
+
+    let loan_id = fee_loan(db, id).await?;
+    crate::users::session_loan_editor(loan_id, "You cannot edit this loan's fees").await?;
+
+    let result = sqlx::query("DELETE FROM loan_fees WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows deleted".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Deposits, credits and payoffs for a loan; all zero until first saved
+#[server]
+pub async fn get_closing_adjustments(loan_id: i32) -> Result<ClosingAdjustments, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, ClosingAdjustments>("SELECT * FROM closing_adjustments WHERE loan_id = $1")
+        .bind(loan_id)
+        .fetch_optional(db)
+        .await?;
+
+    Ok(result.unwrap_or(ClosingAdjustments {
+        loan_id,
+        ..Default::default()
+    }))
+}
+
+/// Saves the deposits, credits and payoffs of a loan the signed-in user may edit
+#[server]
+pub async fn save_closing_adjustments(input: ClosingAdjustments) -> Result<ClosingAdjustments, ServerFnError> {
+    crate::users::session_loan_editor(input.loan_id, "You cannot edit this loan's fees").await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    sqlx::query_as::<_, ClosingAdjustments>(
+        r#"
+        INSERT INTO closing_adjustments (
+            loan_id, earnest_money_cents, seller_credits_cents, lender_credits_cents, payoffs_cents,
+            disclosed_apr
+        )
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (loan_id) DO UPDATE SET
+            earnest_money_cents = EXCLUDED.earnest_money_cents,
+            seller_credits_cents = EXCLUDED.seller_credits_cents,
+            lender_credits_cents = EXCLUDED.lender_credits_cents,
+            payoffs_cents = EXCLUDED.payoffs_cents,
+            disclosed_apr = EXCLUDED.disclosed_apr
+        RETURNING *
+        "#,
+    )
+    .bind(input.loan_id)
+    .bind(input.earnest_money_cents)
+    .bind(input.seller_credits_cents)
+    .bind(input.lender_credits_cents)
+    .bind(input.payoffs_cents)
+    .bind(input.disclosed_apr)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to save closing adjustments: {}", e);
+        ServerFnError::ServerError("Failed to save closing adjustments".into())
+    })
+}
+
+/// Loan that fee `id` is on
+pub async fn fee_loan(db: &sqlx::PgPool, id: i32) -> Result<i32, ServerFnError> {
+    let loan_id: Option<(i32,)> = sqlx::query_as("SELECT loan_id FROM loan_fees WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?;
+    match loan_id {
+        Some((loan_id,)) => Ok(loan_id),
+        None => Err(ServerFnError::Request(format!("Fee {} not found", id))),
+    }
+}
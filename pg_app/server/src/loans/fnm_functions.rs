@@ -0,0 +1,414 @@
+// pg_app/server/src/loans/fnm_functions.rs
+use dioxus::prelude::*;
+use shared::dtos::FnmImportResult;
+
+/// The loan with its applicants and subject property as a Fannie Mae 3.2 file
+///
+/// Every applicant needs an SSN, since the file keys their records on it.
+/// The file carries full SSNs and account numbers, so exporting one
+/// requires `RevealPii` and is written to the audit log.
+#[server]
+pub async fn export_loan_fnm(loan_id: i32) -> Result<String, ServerFnError> {
+    use shared::models::{AuditAction, Permission};
+
+    let actor = crate::users::session_user_with(Permission::RevealPii, "You cannot export files with full SSNs").await?;
+
+    let db = crate::get_db().await;
+
+    let loan = super::get_loan(loan_id).await?;
+    if loan.note_rate.is_none() {
+        return Err(ServerFnError::Request("Set the note rate before exporting a Fannie Mae file".into()));
+    }
+    let property = super::get_property(loan_id).await?;
+
+    let (co_borrowers,): (Vec<i32>,) = sqlx::query_as(
+        "SELECT COALESCE(array_agg(borrower_id ORDER BY created_at, borrower_id), '{}') FROM loan_co_borrowers WHERE loan_id = $1",
+    )
+    .bind(loan_id)
+    .fetch_one(db)
+    .await?;
+
+    let mut applicants = Vec::new();
+    for borrower_id in std::iter::once(loan.borrower_id).chain(co_borrowers) {
+        // Read directly: the borrower and asset server functions mask PII
+        let borrower = sqlx::query_as::<_, shared::models::Borrower>("SELECT * FROM borrowers WHERE id = $1")
+            .bind(borrower_id)
+            .fetch_one(db)
+            .await?;
+        let employments = crate::borrowers::get_employments(borrower_id).await?;
+        let assets = sqlx::query_as::<_, shared::models::Asset>(
+            "SELECT * FROM assets WHERE borrower_id = $1 ORDER BY asset_type, institution_name",
+        )
+        .bind(borrower_id)
+        .fetch_all(db)
+        .await?;
+        let liabilities = crate::borrowers::get_liabilities(borrower_id).await?;
+
+        match shared::fnm::FnmApplicant::from_records(&borrower, &employments, &assets, &liabilities) {
+            Some(applicant) => applicants.push(applicant),
+            None => {
+                return Err(ServerFnError::Request(format!(
+                    "Add an SSN for {} {} before exporting a Fannie Mae file",
+                    borrower.first_name, borrower.last_name
+                )))
+            }
+        }
+    }
+
+    let file = shared::fnm::FnmFile::from_records(&loan, property.as_ref(), applicants);
+    let detail = format!(
+        "Exported loan #{} as a Fannie Mae file with the full SSNs of {} applicant(s)",
+        loan_id,
+        file.applicants.len()
+    );
+    sqlx::query("INSERT INTO audit_log (action, actor_id, entity, entity_id, detail) VALUES ($1, $2, 'loan', $3, $4)")
+        .bind(AuditAction::PiiReveal)
+        .bind(actor.id)
+        .bind(loan_id)
+        .bind(&detail)
+        .execute(db)
+        .await?;
+    tracing::info!("User {}: {}", actor.id, detail);
+
+    Ok(shared::fnm::export::to_fnm(&file))
+}
+
+/// Creates or updates a loan and its applicants from a Fannie Mae 3.2 file
+///
+/// Importing the same file again changes nothing: applicants are matched to
+/// existing borrowers by SSN and last name, and the loan by its loan number.
+/// A matched borrower's employment, assets and liabilities from an earlier
+/// file are replaced, while records entered by hand are kept. Everything
+/// happens in one transaction, so a file with a bad record leaves nothing
+/// behind. Importing needs `ProcessLoans`; a new loan is assigned to the
+/// signed-in user, who must be able to originate in the property's state.
+#[server]
+pub async fn import_loan_fnm(contents: String) -> Result<FnmImportResult, ServerFnError> {
+    let actor =
+        crate::users::session_user_with(shared::models::Permission::ProcessLoans, "You cannot import loan files").await?;
+
+    let db = crate::get_db().await;
+
+    let import = match shared::fnm::import::parse_fnm(&contents) {
+        Ok(import) => import,
+        Err(errors) => {
+            let lines: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            return Err(ServerFnError::Request(lines.join("\n")));
+        }
+    };
+    let file = import.file;
+
+    let validation = file.applicants.iter().try_for_each(|applicant| {
+        validator::Validate::validate(&applicant.borrower)
+            .and_then(|_| applicant.employments.iter().try_for_each(validator::Validate::validate))
+            .and_then(|_| applicant.assets.iter().try_for_each(validator::Validate::validate))
+            .and_then(|_| applicant.liabilities.iter().try_for_each(validator::Validate::validate))
+    });
+    let validation = validation.and_then(|_| file.property.iter().try_for_each(validator::Validate::validate));
+    if let Err(e) = validation {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    let mut borrower_ids = Vec::new();
+    let mut matched_borrowers = 0;
+    for applicant in &file.applicants {
+        let person = &applicant.borrower;
+        let ssn_index = shared::crypto::blind_index(shared::crypto::PiiField::Ssn, &applicant.ssn)?;
+        let existing: Option<(i32,)> = sqlx::query_as(
+            "SELECT id FROM borrowers WHERE ssn_index = $1 AND LOWER(last_name) = LOWER($2) AND merged_into IS NULL ORDER BY id LIMIT 1",
+        )
+        .bind(&ssn_index)
+        .bind(person.last_name.trim())
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let borrower_id = match existing {
+            Some((id,)) => {
+                matched_borrowers += 1;
+                sqlx::query(
+                    r#"
+                    UPDATE borrowers
+                    SET
+                        first_name = $1,
+                        last_name = $2,
+                        email = COALESCE($3, email),
+                        phone = COALESCE($4, phone),
+                        monthly_income_cents = $5
+                    WHERE id = $6
+                    "#,
+                )
+                .bind(person.first_name.trim())
+                .bind(person.last_name.trim())
+                .bind(&person.email)
+                .bind(&person.phone)
+                .bind(person.monthly_income_cents)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+
+                for table in ["employments", "assets", "liabilities"] {
+                    sqlx::query(&format!("DELETE FROM {} WHERE borrower_id = $1 AND source = 'fnm'", table))
+                        .bind(id)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+                id
+            }
+            None => {
+                let inserted: Result<(i32,), _> = sqlx::query_as(
+                    r#"
+                    INSERT INTO borrowers (first_name, last_name, email, phone, monthly_income_cents, ssn, ssn_index)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                    RETURNING id
+                    "#,
+                )
+                .bind(person.first_name.trim())
+                .bind(person.last_name.trim())
+                .bind(&person.email)
+                .bind(&person.phone)
+                .bind(person.monthly_income_cents)
+                .bind(shared::crypto::Sealed(Some(applicant.ssn.clone())))
+                .bind(&ssn_index)
+                .fetch_one(&mut *tx)
+                .await;
+                match inserted {
+                    Ok((id,)) => id,
+                    Err(e) => {
+                        tracing::error!("Failed to import borrower: {}", e);
+                        return Err(ServerFnError::ServerError("Failed to import borrower".into()));
+                    }
+                }
+            }
+        };
+
+        for job in &applicant.employments {
+            sqlx::query(
+                r#"
+                INSERT INTO employments (
+                    borrower_id, employer_name, position, self_employed, is_primary, is_current,
+                    start_date, end_date, months_on_job, years_in_profession, monthly_income_cents, source
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, 'fnm')
+                "#,
+            )
+            .bind(borrower_id)
+            .bind(job.employer_name.trim())
+            .bind(&job.position)
+            .bind(job.self_employed)
+            .bind(job.is_primary)
+            .bind(job.is_current)
+            .bind(job.start_date)
+            .bind(job.end_date)
+            .bind(job.months_on_job)
+            .bind(job.years_in_profession)
+            .bind(job.monthly_income_cents)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for asset in &applicant.assets {
+            let account_number_index = match &asset.account_number {
+                Some(account) => Some(shared::crypto::blind_index(shared::crypto::PiiField::AccountNumber, account)?),
+                None => None,
+            };
+            sqlx::query(
+                r#"
+                INSERT INTO assets (
+                    borrower_id, asset_type, institution_name, account_last4,
+                    account_number, account_number_index, balance_cents, source
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, 'fnm')
+                "#,
+            )
+            .bind(borrower_id)
+            .bind(asset.asset_type)
+            .bind(asset.institution_name.trim())
+            .bind(&asset.account_last4)
+            .bind(shared::crypto::Sealed(asset.account_number.clone()))
+            .bind(account_number_index)
+            .bind(asset.balance_cents)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for liability in &applicant.liabilities {
+            sqlx::query(
+                r#"
+                INSERT INTO liabilities (
+                    borrower_id, liability_type, creditor_name, account_last4,
+                    balance_cents, monthly_payment_cents, paid_off_at_closing, excluded, source
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, 'fnm')
+                "#,
+            )
+            .bind(borrower_id)
+            .bind(liability.liability_type)
+            .bind(liability.creditor_name.trim())
+            .bind(&liability.account_last4)
+            .bind(liability.balance_cents)
+            .bind(liability.monthly_payment_cents)
+            .bind(liability.paid_off_at_closing)
+            .bind(liability.excluded)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        borrower_ids.push(borrower_id);
+    }
+
+    let existing_loan: Option<(i32, Option<i32>)> = match &file.loan_number {
+        Some(number) => {
+            sqlx::query_as("SELECT id, loan_officer_id FROM loans WHERE loan_number = $1 FOR UPDATE")
+                .bind(number)
+                .fetch_optional(&mut *tx)
+                .await?
+        }
+        None => None,
+    };
+
+    // An existing loan keeps its officer, who must still be licensed where
+    // the file puts the property
+    let state = file.property.as_ref().map(|property| property.state.as_str());
+    let loan_officer_id = match existing_loan {
+        Some((_, loan_officer_id)) => loan_officer_id.filter(|_| state.is_some()),
+        None => Some(actor.id),
+    };
+    if let Some(loan_officer_id) = loan_officer_id {
+        let today = sqlx::types::chrono::Utc::now().date_naive();
+        crate::users::ensure_licensed(&mut tx, loan_officer_id, state, today).await?;
+    }
+
+    let loan = &file.loan;
+    let loan_id = match existing_loan {
+        Some((id, _)) => {
+            let updated = sqlx::query(
+                r#"
+                UPDATE loans
+                SET
+                    borrower_id = $1,
+                    loan_type = $2,
+                    loan_purpose = $3,
+                    amount_cents = $4,
+                    note_rate = $5,
+                    term_months = $6
+                WHERE id = $7
+                "#,
+            )
+            .bind(borrower_ids[0])
+            .bind(loan.loan_type)
+            .bind(loan.loan_purpose)
+            .bind(loan.amount_cents)
+            .bind(loan.note_rate)
+            .bind(loan.term_months)
+            .bind(id)
+            .execute(&mut *tx)
+            .await;
+            if let Err(e) = updated {
+                tracing::error!("Failed to update imported loan: {}", e);
+                return Err(ServerFnError::ServerError("Failed to update imported loan".into()));
+            }
+            id
+        }
+        None => {
+            let loan_number = match &file.loan_number {
+                Some(number) => number.clone(),
+                None => crate::settings::next_loan_number(&mut tx, sqlx::types::chrono::Utc::now().date_naive()).await?,
+            };
+            let inserted: Result<(i32,), _> = sqlx::query_as(
+                r#"
+                INSERT INTO loans (
+                    loan_number, borrower_id, loan_officer_id, loan_type, loan_purpose, amount_cents, note_rate,
+                    term_months
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id
+                "#,
+            )
+            .bind(&loan_number)
+            .bind(borrower_ids[0])
+            .bind(actor.id)
+            .bind(loan.loan_type)
+            .bind(loan.loan_purpose)
+            .bind(loan.amount_cents)
+            .bind(loan.note_rate)
+            .bind(loan.term_months)
+            .fetch_one(&mut *tx)
+            .await;
+            match inserted {
+                Ok((id,)) => id,
+                Err(e) => {
+                    tracing::error!("Failed to import loan: {}", e);
+                    return Err(ServerFnError::ServerError("Failed to import loan".into()));
+                }
+            }
+        }
+    };
+
+    sqlx::query("DELETE FROM loan_co_borrowers WHERE loan_id = $1")
+        .bind(loan_id)
+        .execute(&mut *tx)
+        .await?;
+    for borrower_id in &borrower_ids[1..] {
+        sqlx::query("INSERT INTO loan_co_borrowers (loan_id, borrower_id) VALUES ($1, $2)")
+            .bind(loan_id)
+            .bind(borrower_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    if let Some(property) = &file.property {
+        // The file carries no valuations, and only the unit count hints at the
+        // property type, so those are kept from what was entered before
+        sqlx::query(
+            r#"
+            INSERT INTO properties (
+                loan_id, street, city, state, zip, occupancy, property_type, units, purchase_price_cents
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (loan_id) DO UPDATE SET
+                street = EXCLUDED.street,
+                city = EXCLUDED.city,
+                state = EXCLUDED.state,
+                zip = EXCLUDED.zip,
+                occupancy = EXCLUDED.occupancy,
+                property_type = CASE
+                    WHEN properties.units = EXCLUDED.units THEN properties.property_type
+                    ELSE EXCLUDED.property_type
+                END,
+                units = EXCLUDED.units,
+                purchase_price_cents = COALESCE(EXCLUDED.purchase_price_cents, properties.purchase_price_cents)
+            "#,
+        )
+        .bind(loan_id)
+        .bind(property.street.trim())
+        .bind(property.city.trim())
+        .bind(&property.state)
+        .bind(&property.zip)
+        .bind(property.occupancy)
+        .bind(property.property_type)
+        .bind(property.units)
+        .bind(property.purchase_price_cents)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    let loan_updated = existing_loan.is_some();
+    tracing::info!(
+        "Imported Fannie Mae loan {} ({}) with {} applicants, {} matched to existing borrowers",
+        loan_id,
+        if loan_updated { "updated" } else { "created" },
+        borrower_ids.len(),
+        matched_borrowers
+    );
+    Ok(FnmImportResult {
+        loan_id,
+        loan_updated,
+        borrower_ids,
+        matched_borrowers,
+        ignored_records: import.ignored_records,
+    })
+}
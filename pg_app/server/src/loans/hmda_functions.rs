@@ -0,0 +1,135 @@
+// pg_app/server/src/loans/hmda_functions.rs
+use dioxus::prelude::*;
+use shared::models::{HmdaData, HmdaInput};
+
+/// Returns the HMDA fields recorded for a loan, if any
+#[server]
+pub async fn get_hmda_data(loan_id: i32) -> Result<Option<HmdaData>, ServerFnError> {
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, HmdaData>("SELECT * FROM loan_hmda WHERE loan_id = $1")
+        .bind(loan_id)
+        .fetch_optional(db)
+        .await?;
+
+    Ok(result)
+}
+
+/// Creates or replaces the HMDA fields for a loan
+///
+/// `ProcessLoans` may edit any loan; `EditOwnLoans` covers loans assigned
+/// to the signed-in user, since loan officers collect the demographic information.
+#[server]
+pub async fn save_hmda_data(loan_id: i32, input: HmdaInput) -> Result<HmdaData, ServerFnError> {
+    use shared::models::Permission;
+
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let loan_officer_id: Option<(Option<i32>,)> = sqlx::query_as("SELECT loan_officer_id FROM loans WHERE id = $1")
+        .bind(loan_id)
+        .fetch_optional(db)
+        .await?;
+    let Some((loan_officer_id,)) = loan_officer_id else {
+        return Err(ServerFnError::Request(format!("Loan {} not found", loan_id)));
+    };
+    let allowed = actor.has_permission(Permission::ProcessLoans)
+        || (actor.has_permission(Permission::EditOwnLoans) && loan_officer_id == Some(actor.id));
+    if !allowed {
+        return Err(ServerFnError::Request("You cannot edit HMDA data on this loan".to_string()));
+    }
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let applicant = &input.applicant;
+    let co_applicant = &input.co_applicant;
+    sqlx::query_as::<_, HmdaData>(
+        r#"
+        INSERT INTO loan_hmda (
+            loan_id, action_taken, action_taken_date, preapproval, county, census_tract,
+            applicant_ethnicities, applicant_ethnicity_observed, applicant_races, applicant_race_observed,
+            applicant_sex, applicant_sex_observed, applicant_age, applicant_credit_score, applicant_credit_model,
+            co_applicant_ethnicities, co_applicant_ethnicity_observed, co_applicant_races, co_applicant_race_observed,
+            co_applicant_sex, co_applicant_sex_observed, co_applicant_age, co_applicant_credit_score,
+            co_applicant_credit_model, denial_reasons, rate_spread, hoepa_status, lien_status, purchaser_type,
+            debt_to_income, updated_by
+        )
+        VALUES (
+            $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16,
+            $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31
+        )
+        ON CONFLICT (loan_id) DO UPDATE SET
+            action_taken = EXCLUDED.action_taken,
+            action_taken_date = EXCLUDED.action_taken_date,
+            preapproval = EXCLUDED.preapproval,
+            county = EXCLUDED.county,
+            census_tract = EXCLUDED.census_tract,
+            applicant_ethnicities = EXCLUDED.applicant_ethnicities,
+            applicant_ethnicity_observed = EXCLUDED.applicant_ethnicity_observed,
+            applicant_races = EXCLUDED.applicant_races,
+            applicant_race_observed = EXCLUDED.applicant_race_observed,
+            applicant_sex = EXCLUDED.applicant_sex,
+            applicant_sex_observed = EXCLUDED.applicant_sex_observed,
+            applicant_age = EXCLUDED.applicant_age,
+            applicant_credit_score = EXCLUDED.applicant_credit_score,
+            applicant_credit_model = EXCLUDED.applicant_credit_model,
+            co_applicant_ethnicities = EXCLUDED.co_applicant_ethnicities,
+            co_applicant_ethnicity_observed = EXCLUDED.co_applicant_ethnicity_observed,
+            co_applicant_races = EXCLUDED.co_applicant_races,
+            co_applicant_race_observed = EXCLUDED.co_applicant_race_observed,
+            co_applicant_sex = EXCLUDED.co_applicant_sex,
+            co_applicant_sex_observed = EXCLUDED.co_applicant_sex_observed,
+            co_applicant_age = EXCLUDED.co_applicant_age,
+            co_applicant_credit_score = EXCLUDED.co_applicant_credit_score,
+            co_applicant_credit_model = EXCLUDED.co_applicant_credit_model,
+            denial_reasons = EXCLUDED.denial_reasons,
+            rate_spread = EXCLUDED.rate_spread,
+            hoepa_status = EXCLUDED.hoepa_status,
+            lien_status = EXCLUDED.lien_status,
+            purchaser_type = EXCLUDED.purchaser_type,
+            debt_to_income = EXCLUDED.debt_to_income,
+            updated_by = EXCLUDED.updated_by
+        RETURNING *
+        "#,
+    )
+    .bind(loan_id)
+    .bind(input.action_taken)
+    .bind(input.action_taken_date)
+    .bind(input.preapproval)
+    .bind(&input.county)
+    .bind(&input.census_tract)
+    .bind(&applicant.ethnicities)
+    .bind(applicant.ethnicity_observed)
+    .bind(&applicant.races)
+    .bind(applicant.race_observed)
+    .bind(applicant.sex)
+    .bind(applicant.sex_observed)
+    .bind(applicant.age)
+    .bind(applicant.credit_score)
+    .bind(applicant.credit_model)
+    .bind(&co_applicant.ethnicities)
+    .bind(co_applicant.ethnicity_observed)
+    .bind(&co_applicant.races)
+    .bind(co_applicant.race_observed)
+    .bind(co_applicant.sex)
+    .bind(co_applicant.sex_observed)
+    .bind(co_applicant.age)
+    .bind(co_applicant.credit_score)
+    .bind(co_applicant.credit_model)
+    .bind(&input.denial_reasons)
+    .bind(input.rate_spread)
+    .bind(input.hoepa_status)
+    .bind(input.lien_status)
+    .bind(input.purchaser_type)
+    .bind(input.debt_to_income)
+    .bind(actor.id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to save HMDA data for loan {}: {}", loan_id, e);
+        ServerFnError::ServerError("Failed to save HMDA data".into())
+    })
+}
@@ -0,0 +1,235 @@
+// pg_app/server/src/loans/loan_functions.rs
+use dioxus::prelude::*;
+use shared::models::{Loan, LoanInput};
+use shared::BorrowerTableRow;
+
+#[server]
+pub async fn get_all_loans() -> Result<Vec<Loan>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Loan>("SELECT * FROM loans ORDER BY created_at DESC")
+        .fetch_all(db)
+        .await?;
+
+    Ok(result)
+}
+
+#[server]
+pub async fn get_loan(id: i32) -> Result<Loan, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Loan>("SELECT * FROM loans WHERE id = $1")
+        .bind(id)
+        .fetch_one(db)
+        .await?;
+
+    Ok(result)
+}
+
+#[server]
+pub async fn get_borrower_loans(borrower_id: i32) -> Result<Vec<Loan>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Loan>(
+        "SELECT * FROM loans WHERE borrower_id = $1 ORDER BY created_at DESC",
+    )
+    .bind(borrower_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Loans joined with their borrower and subject property for the borrower table
+#[server]
+pub async fn get_borrower_table() -> Result<Vec<BorrowerTableRow>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, BorrowerTableRow>(
+        r#"
+        SELECT
+            l.id AS loan_id,
+            b.id AS borrower_id,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            l.status,
+            l.loan_number,
+            l.application_date,
+            l.loan_type,
+            l.amount_cents,
+            l.note_rate,
+            p.state::TEXT AS state
+        FROM loans l
+        JOIN borrowers b ON b.id = l.borrower_id
+        LEFT JOIN properties p ON p.loan_id = l.id
+        ORDER BY l.created_at DESC
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Opens a loan; its officer, if any, must be able to originate
+///
+/// Loan officers may only open loans assigned to themselves, and an
+/// unassigned loan opened by one is assigned to them.
+#[server]
+pub async fn create_loan(input: LoanInput) -> Result<i32, ServerFnError> {
+    let actor = crate::users::session_user().await?;
+    let mut input = input;
+    if input.loan_officer_id.is_none() && !actor.has_permission(shared::models::Permission::ProcessLoans) {
+        input.loan_officer_id = Some(actor.id);
+    }
+    if !shared::models::can_edit_loan(&actor, input.loan_officer_id) {
+        return Err(ServerFnError::Request("You can only open loans assigned to yourself".to_string()));
+    }
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let today = sqlx::types::chrono::Utc::now().date_naive();
+    let mut tx = db.begin().await?;
+    // A new loan has no subject property yet, so there is no state to check
+    if let Some(loan_officer_id) = input.loan_officer_id {
+        crate::users::ensure_licensed(&mut tx, loan_officer_id, None, today).await?;
+    }
+    let loan_number = crate::settings::next_loan_number(&mut tx, today).await?;
+
+    let (id,): (i32,) = sqlx::query_as(
+        r#"
+        INSERT INTO loans (
+            loan_number, borrower_id, loan_officer_id, loan_type, loan_purpose,
+            amount_cents, note_rate, term_months, application_date
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING id
+        "#,
+    )
+    .bind(&loan_number)
+    .bind(input.borrower_id)
+    .bind(input.loan_officer_id)
+    .bind(input.loan_type)
+    .bind(input.loan_purpose)
+    .bind(input.amount_cents)
+    .bind(input.note_rate)
+    .bind(input.term_months)
+    .bind(input.application_date)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    tracing::info!("Created loan {} ({}) for borrower {}", id, loan_number, input.borrower_id);
+    Ok(id)
+}
+
+/// Changes a loan's terms and officer
+///
+/// A newly assigned officer must be licensed where the subject property is,
+/// or able to originate while the property is not entered yet. The check
+/// and the update run in one transaction with the loan row locked.
+#[server]
+pub async fn update_loan(id: i32, input: LoanInput) -> Result<Loan, ServerFnError> {
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    let current: Option<(Option<i32>, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT l.loan_officer_id, p.state
+        FROM loans l
+        LEFT JOIN properties p ON p.loan_id = l.id
+        WHERE l.id = $1
+        FOR UPDATE OF l
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    let Some((current_officer_id, state)) = current else {
+        return Err(ServerFnError::Request(format!("Loan #{} not found", id)));
+    };
+    if !shared::models::can_edit_loan(&actor, current_officer_id) {
+        return Err(ServerFnError::Request("You cannot edit this loan".to_string()));
+    }
+    if let Some(loan_officer_id) = input.loan_officer_id
+        && current_officer_id != Some(loan_officer_id)
+    {
+        let today = sqlx::types::chrono::Utc::now().date_naive();
+        crate::users::ensure_licensed(&mut tx, loan_officer_id, state.as_deref(), today).await?;
+    }
+
+    let loan = match sqlx::query_as::<_, Loan>(
+        r#"
+        UPDATE loans
+        SET
+            borrower_id = $1,
+            loan_officer_id = $2,
+            loan_type = $3,
+            loan_purpose = $4,
+            amount_cents = $5,
+            note_rate = $6,
+            term_months = $7,
+            application_date = $8
+        WHERE id = $9
+        RETURNING *
+        "#,
+    )
+    .bind(input.borrower_id)
+    .bind(input.loan_officer_id)
+    .bind(input.loan_type)
+    .bind(input.loan_purpose)
+    .bind(input.amount_cents)
+    .bind(input.note_rate)
+    .bind(input.term_months)
+    .bind(input.application_date)
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(loan) => loan,
+        Err(e) => {
+            tracing::error!("Failed to update loan: {}", e);
+            return Err(ServerFnError::ServerError("Failed to update loan".into()));
+        }
+    };
+    tx.commit().await?;
+
+    Ok(loan)
+}
+
+/// Deletes a loan the signed-in user may edit
+#[server]
+pub async fn delete_loan(id: i32) -> Result<(), ServerFnError> {
+    crate::users::session_loan_editor(id, "You cannot delete this loan").await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query("DELETE FROM loans WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows deleted".to_string()))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+// pg_app/server/src/loans/mismo_functions.rs
+use dioxus::prelude::*;
+use shared::dtos::MismoImportResult;
+
+/// The loan with its borrower, liabilities, property and fees as a MISMO 3.4 document
+///
+/// The file carries no SSNs or dates of birth, so unlike the Fannie Mae
+/// export it does not need `RevealPii`; it is limited to the loan's officer
+/// and users who process loans.
+#[server]
+pub async fn export_loan_mismo(loan_id: i32) -> Result<String, ServerFnError> {
+    crate::users::session_loan_editor(loan_id, "You cannot export this loan").await?;
+
+    let loan = super::get_loan(loan_id).await?;
+    if loan.note_rate.is_none() {
+        return Err(ServerFnError::Request("Set the note rate before exporting a MISMO file".into()));
+    }
+    let borrower = crate::borrowers::get_borrower(loan.borrower_id).await?;
+    let liabilities = crate::borrowers::get_liabilities(loan.borrower_id).await?;
+    let property = super::get_property(loan_id).await?;
+    let fees = super::get_loan_fees(loan_id).await?;
+
+    let file = shared::mismo::MismoLoanFile::from_records(&loan, &borrower, &liabilities, property.as_ref(), &fees);
+    Ok(shared::mismo::export::to_xml(&file))
+}
+
+/// Creates a borrower and loan from a MISMO 3.4 document
+///
+/// Everything is inserted in one transaction, so a document with a bad
+/// record leaves nothing behind. Data the application does not keep is
+/// listed in the result rather than rejected. Importing needs `ProcessLoans`.
+#[server]
+pub async fn import_loan_mismo(contents: String) -> Result<MismoImportResult, ServerFnError> {
+    crate::users::session_user_with(shared::models::Permission::ProcessLoans, "You cannot import loan files").await?;
+
+    let db = crate::get_db().await;
+
+    let import = match shared::mismo::import::from_xml(&contents) {
+        Ok(import) => import,
+        Err(e) => return Err(ServerFnError::Request(e.to_string())),
+    };
+    let file = import.file;
+
+    let validation = validator::Validate::validate(&file.borrower)
+        .and_then(|_| file.liabilities.iter().try_for_each(validator::Validate::validate))
+        .and_then(|_| file.property.iter().try_for_each(validator::Validate::validate))
+        .and_then(|_| file.fees.iter().try_for_each(validator::Validate::validate));
+    if let Err(e) = validation {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    if let Some(number) = &file.loan_number {
+        let (exists,): (bool,) = sqlx::query_as("SELECT EXISTS (SELECT 1 FROM loans WHERE loan_number = $1)")
+            .bind(number)
+            .fetch_one(&mut *tx)
+            .await?;
+        if exists {
+            return Err(ServerFnError::Request(format!("Loan number {} already exists", number)));
+        }
+    }
+
+    let (borrower_id,): (i32,) = match sqlx::query_as(
+        r#"
+        INSERT INTO borrowers (first_name, last_name, email, phone, monthly_income_cents)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+    )
+    .bind(file.borrower.first_name.trim())
+    .bind(file.borrower.last_name.trim())
+    .bind(&file.borrower.email)
+    .bind(&file.borrower.phone)
+    .bind(file.borrower.monthly_income_cents)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(row) => row,
+        Err(e) => {
+            tracing::error!("Failed to import borrower: {}", e);
+            return Err(ServerFnError::ServerError("Failed to import borrower".into()));
+        }
+    };
+
+    let loan_number = match file.loan_number {
+        Some(number) => number,
+        None => crate::settings::next_loan_number(&mut tx, sqlx::types::chrono::Utc::now().date_naive()).await?,
+    };
+    let loan = file.loan;
+    let (loan_id,): (i32,) = match sqlx::query_as(
+        r#"
+        INSERT INTO loans (
+            loan_number, borrower_id, loan_type, loan_purpose,
+            amount_cents, note_rate, term_months, application_date
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id
+        "#,
+    )
+    .bind(&loan_number)
+    .bind(borrower_id)
+    .bind(loan.loan_type)
+    .bind(loan.loan_purpose)
+    .bind(loan.amount_cents)
+    .bind(loan.note_rate)
+    .bind(loan.term_months)
+    .bind(loan.application_date)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(row) => row,
+        Err(e) => {
+            tracing::error!("Failed to import loan: {}", e);
+            return Err(ServerFnError::ServerError("Failed to import loan".into()));
+        }
+    };
+
+    for liability in &file.liabilities {
+        sqlx::query(
+            r#"
+            INSERT INTO liabilities (
+                borrower_id, liability_type, creditor_name, account_last4,
+                balance_cents, monthly_payment_cents, paid_off_at_closing, excluded, source
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, 'mismo')
+            "#,
+        )
+        .bind(borrower_id)
+        .bind(liability.liability_type)
+        .bind(liability.creditor_name.trim())
+        .bind(&liability.account_last4)
+        .bind(liability.balance_cents)
+        .bind(liability.monthly_payment_cents)
+        .bind(liability.paid_off_at_closing)
+        .bind(liability.excluded)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    if let Some(property) = &file.property {
+        sqlx::query(
+            r#"
+            INSERT INTO properties (
+                loan_id, street, city, state, zip, occupancy, property_type, units,
+                purchase_price_cents, appraised_value_cents, estimated_value_cents
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+        )
+        .bind(loan_id)
+        .bind(property.street.trim())
+        .bind(property.city.trim())
+        .bind(&property.state)
+        .bind(&property.zip)
+        .bind(property.occupancy)
+        .bind(property.property_type)
+        .bind(property.units)
+        .bind(property.purchase_price_cents)
+        .bind(property.appraised_value_cents)
+        .bind(property.estimated_value_cents)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    for fee in &file.fees {
+        sqlx::query(
+            r#"
+            INSERT INTO loan_fees (
+                loan_id, section, name, amount_cents, payer, paid_to, finance_charge, off_list_provider,
+                recording_fee
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(loan_id)
+        .bind(fee.section)
+        .bind(fee.name.trim())
+        .bind(fee.amount_cents)
+        .bind(fee.payer)
+        .bind(&fee.paid_to)
+        .bind(fee.finance_charge)
+        .bind(fee.off_list_provider)
+        .bind(fee.recording_fee)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    tracing::info!(
+        "Imported MISMO loan {} for borrower {} ({} elements unmapped)",
+        loan_id,
+        borrower_id,
+        import.unmapped.len()
+    );
+    Ok(MismoImportResult {
+        borrower_id,
+        loan_id,
+        unmapped: import.unmapped,
+    })
+}
@@ -0,0 +1,35 @@
+pub mod adverse_action_functions;
+pub mod arm_functions;
+pub mod disclosure_functions;
+pub mod document_functions;
+pub mod fee_functions;
+pub mod fnm_functions;
+pub mod hmda_functions;
+pub mod loan_functions;
+pub mod mismo_functions;
+pub mod pipeline_functions;
+pub mod property_functions;
+pub mod rate_lock_functions;
+pub mod status_timeline_functions;
+pub mod trid_functions;
+
+pub use adverse_action_functions::{get_adverse_action, deny_loan, record_notice_delivery, get_pending_notices};
+pub use arm_functions::{get_arm_terms, save_arm_terms, delete_arm_terms};
+pub use disclosure_functions::{get_disclosures, issue_disclosure, get_disclosure_fees, get_tolerance_report};
+pub use document_functions::{get_loan_documents, generate_loan_document, download_loan_document};
+pub use fee_functions::{
+    get_loan_fees, create_loan_fee, update_loan_fee, delete_loan_fee, get_closing_adjustments,
+    save_closing_adjustments,
+};
+pub use fnm_functions::{export_loan_fnm, import_loan_fnm};
+pub use hmda_functions::{get_hmda_data, save_hmda_data};
+pub use loan_functions::{get_all_loans, get_loan, get_borrower_loans, create_loan, update_loan, delete_loan, get_borrower_table};
+pub use mismo_functions::{export_loan_mismo, import_loan_mismo};
+pub use pipeline_functions::{get_pipeline, transition_loan_status};
+pub use property_functions::{get_property, save_property};
+pub use rate_lock_functions::{
+    get_rate_locks, lock_rate, extend_rate_lock, cancel_rate_lock, get_rate_lock_extensions,
+    get_expiring_rate_locks, check_rate_lock_alerts, start_rate_lock_alerts,
+};
+pub use status_timeline_functions::{get_loan_timeline, get_sla_breaches, get_cycle_time_report};
+pub use trid_functions::{get_trid_dates, save_trid_dates, get_trid_at_risk};
@@ -0,0 +1,98 @@
+// pg_app/server/src/loans/pipeline_functions.rs
+use dioxus::prelude::*;
+use shared::models::{Loan, LoanStatus};
+use shared::{PipelineCard, PipelineFilter};
+
+/// Loans for the pipeline board, matching the filter
+///
+/// The date range applies to the application date, or the creation date for
+/// loans without one.
+#[server]
+pub async fn get_pipeline(filter: PipelineFilter) -> Result<Vec<PipelineCard>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, PipelineCard>(
+        r#"
+        SELECT
+            l.id AS loan_id,
+            l.loan_number,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            l.status,
+            l.loan_type,
+            l.amount_cents,
+            l.note_rate,
+            l.loan_officer_id,
+            u.first_name || ' ' || u.last_name AS loan_officer_name,
+            COALESCE(l.application_date, l.created_at::DATE) AS start_date
+        FROM loans l
+        JOIN borrowers b ON b.id = l.borrower_id
+        LEFT JOIN users u ON u.id = l.loan_officer_id
+        WHERE ($1::INTEGER IS NULL OR l.loan_officer_id = $1)
+          AND ($2::loan_type IS NULL OR l.loan_type = $2)
+          AND ($3::DATE IS NULL OR COALESCE(l.application_date, l.created_at::DATE) >= $3)
+          AND ($4::DATE IS NULL OR COALESCE(l.application_date, l.created_at::DATE) <= $4)
+        ORDER BY l.updated_at DESC
+        "#,
+    )
+    .bind(filter.loan_officer_id)
+    .bind(filter.loan_type)
+    .bind(filter.from_date)
+    .bind(filter.to_date)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Moves a loan to a new pipeline status on behalf of the signed-in user
+///
+/// The move must be one of the current status's allowed transitions and the
+/// user's role must permit it (see [`shared::models::check_transition`]).
+/// Every move is recorded in `loan_status_history`. Denials go through
+/// [`super::deny_loan`] instead, which requires their reasons.
+#[server]
+pub async fn transition_loan_status(loan_id: i32, to: LoanStatus) -> Result<Loan, ServerFnError> {
+    if to == LoanStatus::Denied {
+        return Err(ServerFnError::Request(
+            "Deny the application from the loan page so its reasons are recorded".to_string(),
+        ));
+    }
+
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let mut tx = db.begin().await?;
+
+    let loan = sqlx::query_as::<_, Loan>("SELECT * FROM loans WHERE id = $1 FOR UPDATE")
+        .bind(loan_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if let Err(e) = shared::models::check_transition(actor.role, actor.id, loan.loan_officer_id, loan.status, to) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let updated = sqlx::query_as::<_, Loan>("UPDATE loans SET status = $1 WHERE id = $2 RETURNING *")
+        .bind(to)
+        .bind(loan_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO loan_status_history (loan_id, from_status, to_status, changed_by) VALUES ($1, $2, $3, $4)",
+    )
+    .bind(loan_id)
+    .bind(loan.status)
+    .bind(to)
+    .bind(actor.id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Loan {} moved from {} to {} by user {}", loan_id, loan.status, to, actor.id);
+    Ok(updated)
+}
@@ -0,0 +1,104 @@
+// pg_app/server/src/loans/property_functions.rs
+use dioxus::prelude::*;
+use shared::models::{Property, PropertyInput};
+
+/// Returns the subject property for a loan, if one has been entered
+#[server]
+pub async fn get_property(loan_id: i32) -> Result<Option<Property>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Property>("SELECT * FROM properties WHERE loan_id = $1")
+        .bind(loan_id)
+        .fetch_optional(db)
+        .await?;
+
+    Ok(result)
+}
+
+/// Creates or replaces the subject property for a loan the signed-in user may edit
+#[server]
+pub async fn save_property(loan_id: i32, input: PropertyInput) -> Result<Property, ServerFnError> {
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    // Entering or moving the property to another state must not leave the loan with an unlicensed officer;
+    // the loan row stays locked so its officer cannot change before the property is saved
+    let current: Option<(Option<i32>, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT l.loan_officer_id, p.state
+        FROM loans l
+        LEFT JOIN properties p ON p.loan_id = l.id
+        WHERE l.id = $1
+        FOR UPDATE OF l
+        "#,
+    )
+    .bind(loan_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    let Some((loan_officer_id, state)) = current else {
+        return Err(ServerFnError::Request(format!("Loan #{} not found", loan_id)));
+    };
+    if !shared::models::can_edit_loan(&actor, loan_officer_id) {
+        return Err(ServerFnError::Request("You cannot edit this loan's property".to_string()));
+    }
+    if let Some(loan_officer_id) = loan_officer_id
+        && state.as_deref() != Some(input.state.as_str())
+    {
+        let today = sqlx::types::chrono::Utc::now().date_naive();
+        crate::users::ensure_licensed(&mut tx, loan_officer_id, Some(&input.state), today).await?;
+    }
+
+    let property = match sqlx::query_as::<_, Property>(
+        r#"
+        INSERT INTO properties (
+            loan_id, street, city, state, zip, occupancy, property_type, units,
+            purchase_price_cents, appraised_value_cents, estimated_value_cents
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        ON CONFLICT (loan_id) DO UPDATE SET
+            street = EXCLUDED.street,
+            city = EXCLUDED.city,
+            state = EXCLUDED.state,
+            zip = EXCLUDED.zip,
+            occupancy = EXCLUDED.occupancy,
+            property_type = EXCLUDED.property_type,
+            units = EXCLUDED.units,
+            purchase_price_cents = EXCLUDED.purchase_price_cents,
+            appraised_value_cents = EXCLUDED.appraised_value_cents,
+            estimated_value_cents = EXCLUDED.estimated_value_cents
+        RETURNING *
+        "#,
+    )
+    .bind(loan_id)
+    .bind(input.street.trim())
+    .bind(input.city.trim())
+    .bind(&input.state)
+    .bind(&input.zip)
+    .bind(input.occupancy)
+    .bind(input.property_type)
+    .bind(input.units)
+    .bind(input.purchase_price_cents)
+    .bind(input.appraised_value_cents)
+    .bind(input.estimated_value_cents)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(property) => property,
+        Err(e) => {
+            tracing::error!("Failed to save property: {}", e);
+            return Err(ServerFnError::ServerError("Failed to save property".into()));
+        }
+    };
+    tx.commit().await?;
+
+    Ok(property)
+}
@@ -0,0 +1,334 @@
+// pg_app/server/src/loans/rate_lock_functions.rs
+use dioxus::prelude::*;
+use shared::models::{Notification, RateLock, RateLockExtension, RateLockExtensionInput, RateLockInput};
+use shared::ExpiringLockRow;
+
+/// Rate locks for a loan, newest first
+#[server]
+pub async fn get_rate_locks(loan_id: i32) -> Result<Vec<RateLock>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, RateLock>(
+        "SELECT * FROM rate_locks WHERE loan_id = $1 ORDER BY lock_date DESC, id DESC",
+    )
+    .bind(loan_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Locks a rate on a loan; the expiration is derived from the lock date and period
+#[server]
+pub async fn lock_rate(loan_id: i32, input: RateLockInput) -> Result<RateLock, ServerFnError> {
+    crate::users::session_loan_editor(loan_id, "You cannot change rate locks on this loan").await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+    let Some(expiration_date) = shared::models::lock_expiration(input.lock_date, input.lock_period_days) else {
+        return Err(ServerFnError::Request("Lock period is out of range".to_string()));
+    };
+
+    sqlx::query_as::<_, RateLock>(
+        r#"
+        INSERT INTO rate_locks (loan_id, rate, points, lock_date, lock_period_days, expiration_date)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING *
+        "#,
+    )
+    .bind(loan_id)
+    .bind(input.rate)
+    .bind(input.points)
+    .bind(input.lock_date)
+    .bind(input.lock_period_days)
+    .bind(expiration_date)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to lock rate: {}", e);
+        ServerFnError::ServerError("Failed to lock rate".into())
+    })
+}
+
+/// Pushes a lock's expiration out and records the extension
+///
+/// Extending an expired lock reactivates it. Cancelled locks cannot be extended.
+#[server]
+pub async fn extend_rate_lock(id: i32, input: RateLockExtensionInput) -> Result<RateLock, ServerFnError> {
+    let db = crate::get_db().await;
+
+    let loan_id = rate_lock_loan(db, id).await?;
+    crate::users::session_loan_editor(loan_id, "You cannot change rate locks on this loan").await?;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    let lock = sqlx::query_as::<_, RateLock>("SELECT * FROM rate_locks WHERE id = $1 FOR UPDATE")
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if lock.status == shared::models::RateLockStatus::Cancelled {
+        return Err(ServerFnError::Request("Cancelled locks cannot be extended".to_string()));
+    }
+    let Some(new_expiration) = shared::models::lock_expiration(lock.expiration_date, input.extension_days) else {
+        return Err(ServerFnError::Request("Extension is out of range".to_string()));
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO rate_lock_extensions (
+            rate_lock_id, extension_days, previous_expiration, new_expiration, cost_points, reason
+        )
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(id)
+    .bind(input.extension_days)
+    .bind(lock.expiration_date)
+    .bind(new_expiration)
+    .bind(input.cost_points)
+    .bind(input.reason.as_deref().map(str::trim).filter(|r| !r.is_empty()))
+    .execute(&mut *tx)
+    .await?;
+
+    let updated = sqlx::query_as::<_, RateLock>(
+        "UPDATE rate_locks SET expiration_date = $1, status = 'active' WHERE id = $2 RETURNING *",
+    )
+    .bind(new_expiration)
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Extended rate lock {} to {}", id, new_expiration);
+    Ok(updated)
+}
+
+/// Marks a lock as cancelled
+#[server]
+pub async fn cancel_rate_lock(id: i32) -> Result<(), ServerFnError> {
+    let db = crate::get_db().await;
+
+    let loan_id = rate_lock_loan(db, id).await?;
+    crate::users::session_loan_editor(loan_id, "You cannot change rate locks on this loan").await?;
+
+    let result = sqlx::query("UPDATE rate_locks SET status = 'cancelled' WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows updated".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Extension history for a lock, oldest first
+#[server]
+pub async fn get_rate_lock_extensions(rate_lock_id: i32) -> Result<Vec<RateLockExtension>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, RateLockExtension>(
+        "SELECT * FROM rate_lock_extensions WHERE rate_lock_id = $1 ORDER BY created_at",
+    )
+    .bind(rate_lock_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Active locks expiring within `days` days (including any already past due)
+#[server]
+pub async fn get_expiring_rate_locks(days: i32) -> Result<Vec<ExpiringLockRow>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, ExpiringLockRow>(
+        r#"
+        SELECT
+            r.id AS rate_lock_id,
+            l.id AS loan_id,
+            l.loan_number,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            l.loan_officer_id,
+            r.rate,
+            r.expiration_date
+        FROM rate_locks r
+        JOIN loans l ON l.id = r.loan_id
+        JOIN borrowers b ON b.id = l.borrower_id
+        WHERE r.status = 'active'
+          AND r.expiration_date <= CURRENT_DATE + $1::INTEGER
+        ORDER BY r.expiration_date, r.id
+        "#,
+    )
+    .bind(days)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Loan that rate lock `id` is on
+pub async fn rate_lock_loan(db: &sqlx::PgPool, id: i32) -> Result<i32, ServerFnError> {
+    let loan_id: Option<(i32,)> = sqlx::query_as("SELECT loan_id FROM rate_locks WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?;
+    match loan_id {
+        Some((loan_id,)) => Ok(loan_id),
+        None => Err(ServerFnError::Request(format!("Rate lock {} not found", id))),
+    }
+}
+
+/// Minutes between scheduled rate lock checks
+const RATE_LOCK_CHECK_MINUTES: u64 = 15;
+
+/// Runs [`check_rate_lock_alerts`] on the server every
+/// [`RATE_LOCK_CHECK_MINUTES`]; started once with the database pool
+///
+/// Clients only read the stored notifications, so the check runs whether or
+/// not anyone has the app open.
+pub fn start_rate_lock_alerts(db: sqlx::PgPool) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(RATE_LOCK_CHECK_MINUTES * 60));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            if let Err(e) = check_rate_lock_alerts(&db).await {
+                tracing::error!("Rate lock check failed: {}", e);
+            }
+        }
+    });
+}
+
+/// Scheduled check that raises notifications for locks nearing expiry
+///
+/// Locks past their expiration date are marked expired and announced once.
+/// Active locks raise one notification per threshold in
+/// [`shared::models::RATE_LOCK_ALERT_DAYS`] plus one on the expiration day.
+/// Notifications go to the loan officer, or to everyone when the loan is
+/// unassigned. Returns only the notifications created by this run.
+pub async fn check_rate_lock_alerts(db: &sqlx::PgPool) -> Result<Vec<Notification>, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let expired = sqlx::query_as::<_, ExpiringLockRow>(
+        r#"
+        WITH expired AS (
+            UPDATE rate_locks SET status = 'expired'
+            WHERE status = 'active' AND expiration_date < CURRENT_DATE
+            RETURNING id, loan_id, rate, expiration_date
+        )
+        SELECT
+            e.id AS rate_lock_id,
+            l.id AS loan_id,
+            l.loan_number,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            l.loan_officer_id,
+            e.rate,
+            e.expiration_date
+        FROM expired e
+        JOIN loans l ON l.id = e.loan_id
+        JOIN borrowers b ON b.id = l.borrower_id
+        "#,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let max_days = shared::models::RATE_LOCK_ALERT_DAYS.iter().copied().max().unwrap_or(0);
+    let expiring = sqlx::query_as::<_, ExpiringLockRow>(
+        r#"
+        SELECT
+            r.id AS rate_lock_id,
+            l.id AS loan_id,
+            l.loan_number,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            l.loan_officer_id,
+            r.rate,
+            r.expiration_date
+        FROM rate_locks r
+        JOIN loans l ON l.id = r.loan_id
+        JOIN borrowers b ON b.id = l.borrower_id
+        WHERE r.status = 'active' AND r.expiration_date <= CURRENT_DATE + $1::INTEGER
+        "#,
+    )
+    .bind(max_days as i32)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let (today,): (sqlx::types::chrono::NaiveDate,) = sqlx::query_as("SELECT CURRENT_DATE").fetch_one(&mut *tx).await?;
+    let mut pending = Vec::new();
+    for row in &expired {
+        pending.push((
+            row,
+            "rate_lock_expired",
+            format!("rate_lock:{}:{}:expired", row.rate_lock_id, row.expiration_date),
+            "Rate lock expired".to_string(),
+            format!("expired on {}", row.expiration_date.format("%m/%d/%Y")),
+        ));
+    }
+    for row in &expiring {
+        let days_left = (row.expiration_date - today).num_days();
+        let Some(threshold) = shared::models::lock_alert_threshold(days_left) else {
+            continue;
+        };
+        let when = match days_left {
+            0 => "expires today".to_string(),
+            1 => "expires tomorrow".to_string(),
+            d => format!("expires in {} days", d),
+        };
+        pending.push((
+            row,
+            "rate_lock_expiring",
+            format!("rate_lock:{}:{}:{}", row.rate_lock_id, row.expiration_date, threshold),
+            "Rate lock expiring".to_string(),
+            format!("{} ({})", when, row.expiration_date.format("%m/%d/%Y")),
+        ));
+    }
+
+    let mut created = Vec::new();
+    for (row, kind, reference_key, title, when) in pending {
+        let loan = row.loan_number.clone().unwrap_or_else(|| format!("#{}", row.loan_id));
+        let message = format!("Lock at {:.3}% for {} (loan {}) {}", row.rate, row.borrower_name, loan, when);
+
+        let notification = sqlx::query_as::<_, Notification>(
+            r#"
+            INSERT INTO notifications (user_id, kind, title, message, loan_id, reference_key)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (reference_key) DO NOTHING
+            RETURNING *, NULL::TIMESTAMPTZ AS read_at
+            "#,
+        )
+        .bind(row.loan_officer_id)
+        .bind(kind)
+        .bind(title)
+        .bind(message)
+        .bind(row.loan_id)
+        .bind(reference_key)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        created.extend(notification);
+    }
+
+    tx.commit().await?;
+
+    if !created.is_empty() {
+        tracing::info!("Raised {} rate lock notifications", created.len());
+    }
+    Ok(created)
+}
@@ -0,0 +1,187 @@
+// pg_app/server/src/loans/status_timeline_functions.rs
+use dioxus::prelude::*;
+use shared::calculations::status_timeline::{CycleTimeReport, StatusStage};
+use shared::SlaBreachRow;
+
+/// A loan's status timeline: each status it has been in, the business days
+/// spent there and how that compares to the SLA target
+#[server]
+pub async fn get_loan_timeline(loan_id: i32) -> Result<Vec<StatusStage>, ServerFnError> {
+    use shared::calculations::status_timeline::loan_timeline;
+    use shared::models::{LoanStatus, SlaTarget, StatusChange};
+
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let (today,): (sqlx::types::chrono::NaiveDate,) = sqlx::query_as("SELECT CURRENT_DATE").fetch_one(db).await?;
+    let loan: Option<(LoanStatus, sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>)> =
+        sqlx::query_as("SELECT status, created_at FROM loans WHERE id = $1")
+            .bind(loan_id)
+            .fetch_optional(db)
+            .await?;
+    let Some((status, created_at)) = loan else {
+        return Err(ServerFnError::Request(format!("Loan #{} not found", loan_id)));
+    };
+
+    let changes = sqlx::query_as::<_, StatusChange>(
+        r#"
+        SELECT h.*, u.first_name || ' ' || u.last_name AS changed_by_name
+        FROM loan_status_history h
+        LEFT JOIN users u ON u.id = h.changed_by
+        WHERE h.loan_id = $1
+        "#,
+    )
+    .bind(loan_id)
+    .fetch_all(db)
+    .await?;
+    let targets = sqlx::query_as::<_, SlaTarget>("SELECT * FROM status_sla_targets").fetch_all(db).await?;
+
+    Ok(loan_timeline(created_at, status, &changes, &targets, today))
+}
+
+/// Active loans that have been in their current status longer than its SLA
+/// target, longest overdue first
+#[server]
+pub async fn get_sla_breaches() -> Result<Vec<SlaBreachRow>, ServerFnError> {
+    use shared::calculations::status_timeline::current_stage;
+    use shared::models::{LoanStatus, SlaTarget};
+
+    crate::users::session_user().await?;
+
+    #[derive(sqlx::FromRow)]
+    struct CurrentStatus {
+        loan_id: i32,
+        loan_number: Option<String>,
+        borrower_name: String,
+        loan_officer_name: Option<String>,
+        status: LoanStatus,
+        entered_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    }
+
+    let db = crate::get_db().await;
+
+    let (today,): (sqlx::types::chrono::NaiveDate,) = sqlx::query_as("SELECT CURRENT_DATE").fetch_one(db).await?;
+    let targets = sqlx::query_as::<_, SlaTarget>("SELECT * FROM status_sla_targets").fetch_all(db).await?;
+    let loans = sqlx::query_as::<_, CurrentStatus>(
+        r#"
+        SELECT
+            l.id AS loan_id,
+            l.loan_number,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            u.first_name || ' ' || u.last_name AS loan_officer_name,
+            l.status,
+            COALESCE(
+                (SELECT MAX(h.changed_at) FROM loan_status_history h WHERE h.loan_id = l.id),
+                l.created_at
+            ) AS entered_at
+        FROM loans l
+        JOIN borrowers b ON b.id = l.borrower_id
+        LEFT JOIN users u ON u.id = l.loan_officer_id
+        JOIN status_sla_targets s ON s.status = l.status
+        ORDER BY l.id
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut rows: Vec<SlaBreachRow> = loans
+        .into_iter()
+        .map(|loan| SlaBreachRow {
+            stage: current_stage(loan.status, loan.entered_at, &targets, today),
+            loan_id: loan.loan_id,
+            loan_number: loan.loan_number,
+            borrower_name: loan.borrower_name,
+            loan_officer_name: loan.loan_officer_name,
+        })
+        .filter(|row| row.stage.is_breached())
+        .collect();
+    rows.sort_by_key(|row| std::cmp::Reverse(row.stage.days_over()));
+
+    Ok(rows)
+}
+
+/// Average business days per status and per loan officer for stages that
+/// ended between `from` and `to`, with application-to-closing cycle times for
+/// loans closed in that period
+#[server]
+pub async fn get_cycle_time_report(
+    from: sqlx::types::chrono::NaiveDate,
+    to: sqlx::types::chrono::NaiveDate,
+) -> Result<CycleTimeReport, ServerFnError> {
+    use std::collections::HashMap;
+
+    use shared::calculations::status_timeline::{loan_timeline, LoanTimeline};
+    use shared::models::{LoanStatus, SlaTarget, StatusChange};
+
+    crate::users::session_user().await?;
+
+    #[derive(sqlx::FromRow)]
+    struct ReportLoan {
+        loan_id: i32,
+        status: LoanStatus,
+        created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        loan_officer_id: Option<i32>,
+        loan_officer_name: Option<String>,
+    }
+
+    if from > to {
+        return Err(ServerFnError::Request("The start date must not be after the end date".to_string()));
+    }
+
+    let db = crate::get_db().await;
+
+    let (today,): (sqlx::types::chrono::NaiveDate,) = sqlx::query_as("SELECT CURRENT_DATE").fetch_one(db).await?;
+    let targets = sqlx::query_as::<_, SlaTarget>("SELECT * FROM status_sla_targets").fetch_all(db).await?;
+
+    // Every stage ends with a move, so only loans moved around the period can
+    // count; the day either side covers time zones, and the report itself
+    // keeps to the exact dates
+    let loans = sqlx::query_as::<_, ReportLoan>(
+        r#"
+        SELECT
+            l.id AS loan_id,
+            l.status,
+            l.created_at,
+            l.loan_officer_id,
+            u.first_name || ' ' || u.last_name AS loan_officer_name
+        FROM loans l
+        LEFT JOIN users u ON u.id = l.loan_officer_id
+        WHERE EXISTS (
+            SELECT 1 FROM loan_status_history h
+            WHERE h.loan_id = l.id
+              AND h.from_status IS NOT NULL
+              AND h.changed_at BETWEEN $1::DATE - 1 AND $2::DATE + 2
+        )
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(db)
+    .await?;
+
+    let loan_ids: Vec<i32> = loans.iter().map(|loan| loan.loan_id).collect();
+    let mut history: HashMap<i32, Vec<StatusChange>> = HashMap::new();
+    for change in sqlx::query_as::<_, StatusChange>("SELECT * FROM loan_status_history WHERE loan_id = ANY($1)")
+        .bind(&loan_ids)
+        .fetch_all(db)
+        .await?
+    {
+        history.entry(change.loan_id).or_default().push(change);
+    }
+
+    let timelines: Vec<LoanTimeline> = loans
+        .into_iter()
+        .map(|loan| {
+            let changes = history.remove(&loan.loan_id).unwrap_or_default();
+            LoanTimeline {
+                loan_id: loan.loan_id,
+                loan_officer_id: loan.loan_officer_id,
+                loan_officer_name: loan.loan_officer_name,
+                stages: loan_timeline(loan.created_at, loan.status, &changes, &targets, today),
+            }
+        })
+        .collect();
+
+    Ok(CycleTimeReport::build(&timelines, from, to))
+}
@@ -0,0 +1,135 @@
+// pg_app/server/src/loans/trid_functions.rs
+use dioxus::prelude::*;
+use shared::models::TridDates;
+use shared::TridRiskRow;
+
+/// Returns the TRID disclosure dates for a loan, if any have been recorded
+#[server]
+pub async fn get_trid_dates(loan_id: i32) -> Result<Option<TridDates>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, TridDates>(
+        r#"
+        SELECT loan_id, le_delivered_date, cd_delivered_date, cd_delivery_method, cd_received_date, consummation_date
+        FROM trid_dates
+        WHERE loan_id = $1
+        "#,
+    )
+    .bind(loan_id)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Creates or replaces the TRID disclosure dates for a loan
+#[server]
+pub async fn save_trid_dates(loan_id: i32, dates: TridDates) -> Result<TridDates, ServerFnError> {
+    crate::users::session_loan_editor(loan_id, "You cannot edit this loan's disclosure dates").await?;
+
+    let db = crate::get_db().await;
+
+    if let (Some(delivered), Some(received)) = (dates.cd_delivered_date, dates.cd_received_date)
+        && received < delivered
+    {
+        return Err(ServerFnError::Request(
+            "Closing Disclosure cannot be received before it was delivered".into(),
+        ));
+    }
+
+    sqlx::query_as::<_, TridDates>(
+        r#"
+        INSERT INTO trid_dates (
+            loan_id, le_delivered_date, cd_delivered_date, cd_delivery_method, cd_received_date, consummation_date
+        )
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (loan_id) DO UPDATE SET
+            le_delivered_date = EXCLUDED.le_delivered_date,
+            cd_delivered_date = EXCLUDED.cd_delivered_date,
+            cd_delivery_method = EXCLUDED.cd_delivery_method,
+            cd_received_date = EXCLUDED.cd_received_date,
+            consummation_date = EXCLUDED.consummation_date
+        RETURNING loan_id, le_delivered_date, cd_delivered_date, cd_delivery_method, cd_received_date, consummation_date
+        "#,
+    )
+    .bind(loan_id)
+    .bind(dates.le_delivered_date)
+    .bind(dates.cd_delivered_date)
+    .bind(dates.cd_delivery_method)
+    .bind(dates.cd_received_date)
+    .bind(dates.consummation_date)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to save TRID dates: {}", e);
+        ServerFnError::ServerError("Failed to save TRID dates".into())
+    })
+}
+
+/// Open TRID deadlines that are missed or due within a business day, across active loans
+///
+/// Closed, denied and withdrawn files and leads without an application are skipped.
+#[server]
+pub async fn get_trid_at_risk() -> Result<Vec<TridRiskRow>, ServerFnError> {
+    use shared::calculations::trid::trid_timeline;
+
+    crate::users::session_user().await?;
+
+    #[derive(sqlx::FromRow)]
+    struct LoanDates {
+        #[sqlx(flatten)]
+        dates: TridDates,
+        loan_number: Option<String>,
+        borrower_name: String,
+        loan_officer_id: Option<i32>,
+        application_date: Option<sqlx::types::chrono::NaiveDate>,
+    }
+
+    let db = crate::get_db().await;
+
+    let (today,): (sqlx::types::chrono::NaiveDate,) = sqlx::query_as("SELECT CURRENT_DATE").fetch_one(db).await?;
+    let loans = sqlx::query_as::<_, LoanDates>(
+        r#"
+        SELECT
+            l.id AS loan_id,
+            t.le_delivered_date,
+            t.cd_delivered_date,
+            COALESCE(t.cd_delivery_method, 'electronic') AS cd_delivery_method,
+            t.cd_received_date,
+            t.consummation_date,
+            l.loan_number,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            l.loan_officer_id,
+            l.application_date
+        FROM loans l
+        JOIN borrowers b ON b.id = l.borrower_id
+        LEFT JOIN trid_dates t ON t.loan_id = l.id
+        WHERE l.status NOT IN ('lead', 'closed', 'denied', 'withdrawn')
+        ORDER BY l.id
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut rows: Vec<TridRiskRow> = loans
+        .into_iter()
+        .flat_map(|loan| {
+            trid_timeline(loan.application_date, &loan.dates, today)
+                .into_iter()
+                // A late delivery that has already happened cannot be fixed from the dashboard
+                .filter(|milestone| milestone.actual.is_none() && milestone.status.is_at_risk())
+                .map(move |milestone| TridRiskRow {
+                    loan_id: loan.dates.loan_id,
+                    loan_number: loan.loan_number.clone(),
+                    borrower_name: loan.borrower_name.clone(),
+                    loan_officer_id: loan.loan_officer_id,
+                    milestone,
+                })
+        })
+        .collect();
+    rows.sort_by_key(|row| row.milestone.due);
+
+    Ok(rows)
+}
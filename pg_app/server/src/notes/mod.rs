@@ -0,0 +1,3 @@
+pub mod note_functions;
+
+pub use note_functions::{get_notes, create_note, update_note, delete_note, get_note_edits};
@@ -0,0 +1,265 @@
+// pg_app/server/src/notes/note_functions.rs
+use dioxus::prelude::*;
+use shared::models::{Note, NoteEdit, NoteInput, NoteSubject};
+
+/// All notes and replies on a loan or borrower, flat
+///
+/// Internal-only notes are left out unless the signed-in user's role may see
+/// them (see [`shared::models::can_see_internal_notes`]). Use
+/// `shared::models::build_threads` to nest the replies.
+#[server]
+pub async fn get_notes(subject: NoteSubject) -> Result<Vec<Note>, ServerFnError> {
+    let reader = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+    let (loan_id, borrower_id) = subject.columns();
+
+    let result = sqlx::query_as::<_, Note>(
+        r#"
+        SELECT n.*, u.first_name || ' ' || u.last_name AS author_name
+        FROM notes n
+        LEFT JOIN users u ON u.id = n.author_id
+        WHERE (n.loan_id = $1 OR n.borrower_id = $2)
+          AND ($3 OR NOT n.internal_only)
+        ORDER BY n.created_at
+        "#,
+    )
+    .bind(loan_id)
+    .bind(borrower_id)
+    .bind(shared::models::can_see_internal_notes(&reader))
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Writes a note, or a reply when `parent_id` is given, as the signed-in user
+///
+/// Replies are attached to the parent's loan or borrower regardless of
+/// `subject`. Every `@username` mentioned (other than the author) gets a
+/// notification.
+#[server]
+pub async fn create_note(
+    subject: NoteSubject,
+    parent_id: Option<i32>,
+    input: NoteInput,
+) -> Result<Note, ServerFnError> {
+    let author = crate::users::session_user().await?;
+    let author_id = author.id;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    let (loan_id, borrower_id) = match parent_id {
+        Some(parent_id) => {
+            sqlx::query_as::<_, (Option<i32>, Option<i32>)>("SELECT loan_id, borrower_id FROM notes WHERE id = $1")
+                .bind(parent_id)
+                .fetch_one(&mut *tx)
+                .await?
+        }
+        None => subject.columns(),
+    };
+
+    let (id,): (i32,) = sqlx::query_as(
+        r#"
+        INSERT INTO notes (loan_id, borrower_id, parent_id, author_id, body, pinned, internal_only)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id
+        "#,
+    )
+    .bind(loan_id)
+    .bind(borrower_id)
+    .bind(parent_id)
+    .bind(author_id)
+    .bind(input.body.trim())
+    .bind(input.pinned && parent_id.is_none())
+    .bind(input.internal_only)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let note = sqlx::query_as::<_, Note>(
+        r#"
+        SELECT n.*, u.first_name || ' ' || u.last_name AS author_name
+        FROM notes n
+        LEFT JOIN users u ON u.id = n.author_id
+        WHERE n.id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    notify_mentions(&mut tx, &note, &author).await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Created note {} by user {}", id, author_id);
+    Ok(note)
+}
+
+/// Edits a note, keeping the previous body in its edit history
+///
+/// Only the author or an administrator may edit. Newly added mentions raise
+/// notifications; people already notified are not notified again.
+#[server]
+pub async fn update_note(id: i32, input: NoteInput) -> Result<Note, ServerFnError> {
+    let editor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    let existing = sqlx::query_as::<_, Note>(
+        r#"
+        SELECT n.*, u.first_name || ' ' || u.last_name AS author_name
+        FROM notes n
+        LEFT JOIN users u ON u.id = n.author_id
+        WHERE n.id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await?;
+    if existing.author_id != Some(editor.id) && editor.role != shared::models::UserRole::Admin {
+        return Err(ServerFnError::Request("Only the author can edit this note".to_string()));
+    }
+
+    let body = input.body.trim();
+    if body != existing.body {
+        sqlx::query("INSERT INTO note_edits (note_id, previous_body, edited_by) VALUES ($1, $2, $3)")
+            .bind(id)
+            .bind(&existing.body)
+            .bind(editor.id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let updated = sqlx::query(
+        r#"
+        UPDATE notes
+        SET
+            body = $1,
+            pinned = $2,
+            internal_only = $3,
+            edited_at = CASE WHEN body <> $1 THEN NOW() ELSE edited_at END
+        WHERE id = $4
+        "#,
+    )
+    .bind(body)
+    .bind(input.pinned && existing.parent_id.is_none())
+    .bind(input.internal_only)
+    .bind(id)
+    .execute(&mut *tx)
+    .await;
+    if let Err(e) = updated {
+        tracing::error!("Failed to update note: {}", e);
+        return Err(ServerFnError::ServerError("Failed to update note".into()));
+    }
+
+    let note = sqlx::query_as::<_, Note>(
+        r#"
+        SELECT n.*, u.first_name || ' ' || u.last_name AS author_name
+        FROM notes n
+        LEFT JOIN users u ON u.id = n.author_id
+        WHERE n.id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    notify_mentions(&mut tx, &note, &editor).await?;
+
+    tx.commit().await?;
+
+    Ok(note)
+}
+
+/// Deletes a note and its replies; only the author or an administrator may delete
+#[server]
+pub async fn delete_note(id: i32) -> Result<(), ServerFnError> {
+    let user = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query("DELETE FROM notes WHERE id = $1 AND (author_id = $2 OR $3)")
+        .bind(id)
+        .bind(user.id)
+        .bind(user.role == shared::models::UserRole::Admin)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows deleted".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Previous versions of a note, newest first
+///
+/// Empty for internal-only notes the signed-in user may not see.
+#[server]
+pub async fn get_note_edits(note_id: i32) -> Result<Vec<NoteEdit>, ServerFnError> {
+    let reader = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, NoteEdit>(
+        r#"
+        SELECT e.*, u.first_name || ' ' || u.last_name AS editor_name
+        FROM note_edits e
+        JOIN notes n ON n.id = e.note_id
+        LEFT JOIN users u ON u.id = e.edited_by
+        WHERE e.note_id = $1 AND ($2 OR NOT n.internal_only)
+        ORDER BY e.edited_at DESC, e.id DESC
+        "#,
+    )
+    .bind(note_id)
+    .bind(shared::models::can_see_internal_notes(&reader))
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Notifies everyone mentioned in `note` except `author`, who wrote this version
+///
+/// Each person is notified once per note, however often it is edited. The
+/// message names the note's loan or borrower and never quotes the body.
+pub async fn notify_mentions(
+    conn: &mut sqlx::PgConnection,
+    note: &Note,
+    author: &shared::models::User,
+) -> Result<(), ServerFnError> {
+    let author_name = author.full_name();
+
+    sqlx::query(
+        r#"
+        INSERT INTO notifications (user_id, kind, title, message, loan_id, reference_key)
+        SELECT u.id, 'mention', $2 || ' mentioned you', $3, $4, 'note:' || $1 || ':mention:' || u.id
+        FROM users u
+        WHERE u.username = ANY($5) AND u.id <> $6 AND u.is_active
+        ON CONFLICT (reference_key) DO NOTHING
+        "#,
+    )
+    .bind(note.id)
+    .bind(&author_name)
+    .bind(note.mention_message(&author_name))
+    .bind(note.loan_id)
+    .bind(note.mentions())
+    .bind(author.id)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod notification_functions;
+
+pub use notification_functions::{
+    get_notifications, get_unread_notifications, mark_notification_read, mark_all_notifications_read,
+};
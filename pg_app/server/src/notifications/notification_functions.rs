@@ -0,0 +1,106 @@
+// pg_app/server/src/notifications/notification_functions.rs
+use dioxus::prelude::*;
+use shared::models::Notification;
+
+/// Recent notifications for the signed-in user, including those addressed to everyone
+#[server]
+pub async fn get_notifications() -> Result<Vec<Notification>, ServerFnError> {
+    let user = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Notification>(
+        r#"
+        SELECT n.*, r.read_at
+        FROM notifications n
+        LEFT JOIN notification_reads r ON r.notification_id = n.id AND r.user_id = $1
+        WHERE n.user_id IS NULL OR n.user_id = $1
+        ORDER BY n.created_at DESC
+        LIMIT 50
+        "#,
+    )
+    .bind(user.id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// The signed-in user's unread notifications, oldest first
+///
+/// Polled by each client to toast what the scheduled checks have raised
+/// for its user since the last poll.
+#[server]
+pub async fn get_unread_notifications() -> Result<Vec<Notification>, ServerFnError> {
+    let user = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, Notification>(
+        r#"
+        SELECT n.*, NULL::TIMESTAMPTZ AS read_at
+        FROM notifications n
+        WHERE (n.user_id IS NULL OR n.user_id = $1)
+          AND NOT EXISTS (
+              SELECT 1 FROM notification_reads r WHERE r.notification_id = n.id AND r.user_id = $1
+          )
+        ORDER BY n.created_at, n.id
+        LIMIT 50
+        "#,
+    )
+    .bind(user.id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Marks one of the signed-in user's notifications as read
+///
+/// Reads are kept per user, so a notification for everyone stays unread for
+/// the others.
+#[server]
+pub async fn mark_notification_read(id: i32) -> Result<(), ServerFnError> {
+    let user = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query(
+        r#"
+        INSERT INTO notification_reads (notification_id, user_id)
+        SELECT id, $2 FROM notifications WHERE id = $1 AND (user_id IS NULL OR user_id = $2)
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(id)
+    .bind(user.id)
+    .execute(db)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows updated".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Marks every unread notification visible to the signed-in user as read
+#[server]
+pub async fn mark_all_notifications_read() -> Result<u64, ServerFnError> {
+    let user = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query(
+        r#"
+        INSERT INTO notification_reads (notification_id, user_id)
+        SELECT id, $1 FROM notifications WHERE user_id IS NULL OR user_id = $1
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(user.id)
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected())
+}
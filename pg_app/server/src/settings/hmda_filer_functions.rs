@@ -0,0 +1,80 @@
+// pg_app/server/src/settings/hmda_filer_functions.rs
+use dioxus::prelude::*;
+use shared::models::{HmdaFiler, HmdaFilerInput};
+
+/// The institution named on the LAR transmittal sheet, once entered
+#[server]
+pub async fn get_hmda_filer() -> Result<Option<HmdaFiler>, ServerFnError> {
+    let db = crate::get_db().await;
+
+    let filer = sqlx::query_as::<_, HmdaFiler>("SELECT * FROM hmda_filer")
+        .fetch_optional(db)
+        .await?;
+
+    Ok(filer)
+}
+
+/// Saves the HMDA filer details; requires the `ManageSettings` permission
+#[server]
+pub async fn save_hmda_filer(input: HmdaFilerInput) -> Result<HmdaFiler, ServerFnError> {
+    let actor = crate::users::session_user_with(
+        shared::models::Permission::ManageSettings,
+        "Only managers can change the HMDA filer details",
+    )
+    .await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let filer = match sqlx::query_as::<_, HmdaFiler>(
+        r#"
+        INSERT INTO hmda_filer (
+            lei, institution_name, tax_id, federal_agency, contact_name, contact_phone, contact_email,
+            contact_street, contact_city, contact_state, contact_zip, updated_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        ON CONFLICT (id) DO UPDATE SET
+            lei = EXCLUDED.lei,
+            institution_name = EXCLUDED.institution_name,
+            tax_id = EXCLUDED.tax_id,
+            federal_agency = EXCLUDED.federal_agency,
+            contact_name = EXCLUDED.contact_name,
+            contact_phone = EXCLUDED.contact_phone,
+            contact_email = EXCLUDED.contact_email,
+            contact_street = EXCLUDED.contact_street,
+            contact_city = EXCLUDED.contact_city,
+            contact_state = EXCLUDED.contact_state,
+            contact_zip = EXCLUDED.contact_zip,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        RETURNING *
+        "#,
+    )
+    .bind(&input.lei)
+    .bind(input.institution_name.trim())
+    .bind(&input.tax_id)
+    .bind(input.federal_agency)
+    .bind(input.contact_name.trim())
+    .bind(&input.contact_phone)
+    .bind(input.contact_email.trim())
+    .bind(input.contact_street.trim())
+    .bind(input.contact_city.trim())
+    .bind(&input.contact_state)
+    .bind(&input.contact_zip)
+    .bind(actor.id)
+    .fetch_one(db)
+    .await
+    {
+        Ok(filer) => filer,
+        Err(e) => {
+            tracing::error!("Failed to save HMDA filer: {}", e);
+            return Err(ServerFnError::ServerError("Failed to save HMDA filer".into()));
+        }
+    };
+
+    tracing::info!("User {} updated the HMDA filer details for LEI {}", actor.id, filer.lei);
+    Ok(filer)
+}
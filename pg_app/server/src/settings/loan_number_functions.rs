@@ -0,0 +1,138 @@
+// pg_app/server/src/settings/loan_number_functions.rs
+use dioxus::prelude::*;
+use shared::models::{LoanNumberSettings, LoanNumberSettingsInput};
+
+/// Tries before giving up on a number that is not already taken
+const MAX_ATTEMPTS: usize = 20;
+
+/// The company's loan number format
+#[server]
+pub async fn get_loan_number_settings() -> Result<LoanNumberSettings, ServerFnError> {
+    let db = crate::get_db().await;
+
+    let settings = sqlx::query_as::<_, LoanNumberSettings>(
+        "SELECT format, branch_code, updated_by, updated_at FROM loan_number_settings",
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(settings)
+}
+
+/// Changes the loan number format; requires the `ManageSettings` permission
+///
+/// Loans keep the numbers they already have. New loans continue an existing
+/// sequence when the new format produces the same prefix.
+#[server]
+pub async fn save_loan_number_settings(
+    input: LoanNumberSettingsInput,
+) -> Result<LoanNumberSettings, ServerFnError> {
+    let actor = crate::users::session_user_with(
+        shared::models::Permission::ManageSettings,
+        "Only managers can change the loan number format",
+    )
+    .await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let settings = match sqlx::query_as::<_, LoanNumberSettings>(
+        r#"
+        UPDATE loan_number_settings
+        SET format = $1, branch_code = $2, updated_by = $3, updated_at = NOW()
+        RETURNING format, branch_code, updated_by, updated_at
+        "#,
+    )
+    .bind(input.format.trim())
+    .bind(&input.branch_code)
+    .bind(actor.id)
+    .fetch_one(db)
+    .await
+    {
+        Ok(settings) => settings,
+        Err(e) => {
+            tracing::error!("Failed to save loan number settings: {}", e);
+            return Err(ServerFnError::ServerError("Failed to save loan number settings".into()));
+        }
+    };
+
+    tracing::info!("User {} set the loan number format to {}", actor.id, settings.format);
+    Ok(settings)
+}
+
+/// Draws the next loan number in the current format
+///
+/// Runs on the caller's connection so it joins the transaction creating the
+/// loan. A prefix seen for the first time gets its sequence created along
+/// with its `loan_number_sequences` row; a concurrent create of the same
+/// prefix waits on that row's unique key and then shares the sequence.
+/// `nextval` is never rolled back, so a failed create leaves a gap instead
+/// of handing the number out twice. Numbers already taken (e.g. by an
+/// imported loan) are skipped.
+pub async fn next_loan_number(
+    conn: &mut sqlx::PgConnection,
+    today: sqlx::types::chrono::NaiveDate,
+) -> Result<String, ServerFnError> {
+    let settings = sqlx::query_as::<_, LoanNumberSettings>(
+        "SELECT format, branch_code, updated_by, updated_at FROM loan_number_settings",
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+    let format = match shared::models::LoanNumberFormat::parse(&settings.format) {
+        Ok(format) => format,
+        Err(e) => {
+            tracing::error!("Stored loan number format {} is invalid: {}", settings.format, e);
+            return Err(ServerFnError::ServerError("The loan number format is invalid".into()));
+        }
+    };
+    let prefix = format.prefix(&settings.branch_code, today);
+
+    let created: Option<(i32,)> = sqlx::query_as(
+        "INSERT INTO loan_number_sequences (prefix) VALUES ($1) ON CONFLICT (prefix) DO NOTHING RETURNING id",
+    )
+    .bind(&prefix)
+    .fetch_optional(&mut *conn)
+    .await?;
+    let sequence_id = match created {
+        Some((id,)) => {
+            // The name is built from our own integer id, never from user input
+            sqlx::query(&format!("CREATE SEQUENCE loan_number_seq_{} MINVALUE 1", id))
+                .execute(&mut *conn)
+                .await?;
+            id
+        }
+        None => {
+            let (id,): (i32,) = sqlx::query_as("SELECT id FROM loan_number_sequences WHERE prefix = $1")
+                .bind(&prefix)
+                .fetch_one(&mut *conn)
+                .await?;
+            id
+        }
+    };
+    let sequence = format!("loan_number_seq_{}", sequence_id);
+
+    for _ in 0..MAX_ATTEMPTS {
+        let (value,): (i64,) = sqlx::query_as("SELECT nextval($1::regclass)")
+            .bind(&sequence)
+            .fetch_one(&mut *conn)
+            .await?;
+        let number = format.render(&settings.branch_code, today, value);
+        if number.len() > shared::models::LOAN_NUMBER_MAX_LEN {
+            tracing::error!("Loan number {} from {} is too long", number, sequence);
+            return Err(ServerFnError::ServerError("The loan number sequence is exhausted".into()));
+        }
+        let (taken,): (bool,) = sqlx::query_as("SELECT EXISTS (SELECT 1 FROM loans WHERE loan_number = $1)")
+            .bind(&number)
+            .fetch_one(&mut *conn)
+            .await?;
+        if !taken {
+            return Ok(number);
+        }
+    }
+
+    tracing::error!("No free loan number after {} tries on {}", MAX_ATTEMPTS, sequence);
+    Err(ServerFnError::ServerError("Could not assign a loan number".into()))
+}
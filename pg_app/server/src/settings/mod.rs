@@ -0,0 +1,9 @@
+pub mod hmda_filer_functions;
+pub mod loan_number_functions;
+pub mod pmi_rate_functions;
+pub mod sla_target_functions;
+
+pub use hmda_filer_functions::{get_hmda_filer, save_hmda_filer};
+pub use loan_number_functions::{get_loan_number_settings, save_loan_number_settings, next_loan_number};
+pub use pmi_rate_functions::{get_pmi_rates, create_pmi_rate, update_pmi_rate, delete_pmi_rate};
+pub use sla_target_functions::{get_sla_targets, save_sla_target};
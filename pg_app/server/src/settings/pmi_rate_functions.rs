@@ -0,0 +1,113 @@
+use dioxus::prelude::*;
+use shared::models::{PmiRate, PmiRateInput};
+
+/// Every PMI rate, ordered by LTV band then credit score band
+#[server]
+pub async fn get_pmi_rates() -> Result<Vec<PmiRate>, ServerFnError> {
+    let db = crate::get_db().await;
+
+    let rows = sqlx::query_as::<_, PmiRate>(
+        "SELECT * FROM pmi_rates ORDER BY ltv_min, credit_score_min DESC",
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Adds a rate to the table; requires the `ManageSettings` permission
+#[server]
+pub async fn create_pmi_rate(input: PmiRateInput) -> Result<PmiRate, ServerFnError> {
+    crate::users::session_user_with(shared::models::Permission::ManageSettings, "Only managers can change PMI rates")
+        .await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+    if input.ltv_min > input.ltv_max || input.credit_score_min > input.credit_score_max {
+        return Err(ServerFnError::Request("Band minimums must not exceed maximums".to_string()));
+    }
+
+    sqlx::query_as::<_, PmiRate>(
+        r#"
+        INSERT INTO pmi_rates (ltv_min, ltv_max, credit_score_min, credit_score_max, annual_rate)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#,
+    )
+    .bind(input.ltv_min)
+    .bind(input.ltv_max)
+    .bind(input.credit_score_min)
+    .bind(input.credit_score_max)
+    .bind(input.annual_rate)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to create PMI rate: {}", e);
+        ServerFnError::ServerError("Failed to create PMI rate".into())
+    })
+}
+
+/// Changes a rate's bands or premium; requires the `ManageSettings` permission
+#[server]
+pub async fn update_pmi_rate(id: i32, input: PmiRateInput) -> Result<PmiRate, ServerFnError> {
+    crate::users::session_user_with(shared::models::Permission::ManageSettings, "Only managers can change PMI rates")
+        .await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+    if input.ltv_min > input.ltv_max || input.credit_score_min > input.credit_score_max {
+        return Err(ServerFnError::Request("Band minimums must not exceed maximums".to_string()));
+    }
+
+    sqlx::query_as::<_, PmiRate>(
+        r#"
+        UPDATE pmi_rates
+        SET
+            ltv_min = $1,
+            ltv_max = $2,
+            credit_score_min = $3,
+            credit_score_max = $4,
+            annual_rate = $5
+        WHERE id = $6
+        RETURNING *
+        "#,
+    )
+    .bind(input.ltv_min)
+    .bind(input.ltv_max)
+    .bind(input.credit_score_min)
+    .bind(input.credit_score_max)
+    .bind(input.annual_rate)
+    .bind(id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to update PMI rate: {}", e);
+        ServerFnError::ServerError("Failed to update PMI rate".into())
+    })
+}
+
+/// Removes a rate from the table; requires the `ManageSettings` permission
+#[server]
+pub async fn delete_pmi_rate(id: i32) -> Result<(), ServerFnError> {
+    crate::users::session_user_with(shared::models::Permission::ManageSettings, "Only managers can change PMI rates")
+        .await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query("DELETE FROM pmi_rates WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows deleted".to_string()))
+    } else {
+        Ok(())
+    }
+}
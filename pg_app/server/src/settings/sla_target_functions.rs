@@ -0,0 +1,78 @@
+// pg_app/server/src/settings/sla_target_functions.rs
+use dioxus::prelude::*;
+use shared::models::{LoanStatus, SlaTarget};
+
+/// SLA targets for each status that has one, in pipeline order
+#[server]
+pub async fn get_sla_targets() -> Result<Vec<SlaTarget>, ServerFnError> {
+    let db = crate::get_db().await;
+
+    let rows = sqlx::query_as::<_, SlaTarget>("SELECT * FROM status_sla_targets ORDER BY status")
+        .fetch_all(db)
+        .await?;
+
+    Ok(rows)
+}
+
+/// Sets or clears the SLA target for a status; requires the `ManageSettings` permission
+///
+/// `None` removes the target, so loans in that status are no longer flagged.
+#[server]
+pub async fn save_sla_target(
+    status: LoanStatus,
+    business_days: Option<i32>,
+) -> Result<Option<SlaTarget>, ServerFnError> {
+    use shared::models::SLA_TARGET_MAX_DAYS;
+
+    let actor = crate::users::session_user_with(
+        shared::models::Permission::ManageSettings,
+        "Only managers can change SLA targets",
+    )
+    .await?;
+
+    let db = crate::get_db().await;
+
+    if status.is_final() {
+        return Err(ServerFnError::Request(format!("{} is final and cannot have an SLA target", status)));
+    }
+
+    let Some(business_days) = business_days else {
+        sqlx::query("DELETE FROM status_sla_targets WHERE status = $1")
+            .bind(status)
+            .execute(db)
+            .await?;
+        tracing::info!("User {} cleared the SLA target for {}", actor.id, status);
+        return Ok(None);
+    };
+    if !(1..=SLA_TARGET_MAX_DAYS).contains(&business_days) {
+        return Err(ServerFnError::Request(format!(
+            "SLA targets must be 1-{} business days",
+            SLA_TARGET_MAX_DAYS
+        )));
+    }
+
+    let target = match sqlx::query_as::<_, SlaTarget>(
+        r#"
+        INSERT INTO status_sla_targets (status, business_days, updated_by)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (status) DO UPDATE
+        SET business_days = EXCLUDED.business_days, updated_by = EXCLUDED.updated_by, updated_at = NOW()
+        RETURNING *
+        "#,
+    )
+    .bind(status)
+    .bind(business_days)
+    .bind(actor.id)
+    .fetch_one(db)
+    .await
+    {
+        Ok(target) => target,
+        Err(e) => {
+            tracing::error!("Failed to save SLA target for {}: {}", status, e);
+            return Err(ServerFnError::ServerError("Failed to save SLA target".into()));
+        }
+    };
+
+    tracing::info!("User {} set the SLA target for {} to {} business days", actor.id, status, business_days);
+    Ok(Some(target))
+}
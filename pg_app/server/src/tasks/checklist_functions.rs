@@ -0,0 +1,102 @@
+// pg_app/server/src/tasks/checklist_functions.rs
+use dioxus::prelude::*;
+use shared::models::ChecklistItem;
+
+/// A task's checklist in display order
+#[server]
+pub async fn get_checklist(task_id: i32) -> Result<Vec<ChecklistItem>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, ChecklistItem>(
+        "SELECT * FROM task_checklist_items WHERE task_id = $1 ORDER BY position, id",
+    )
+    .bind(task_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Appends an item to the end of a task's checklist
+#[server]
+pub async fn add_checklist_item(task_id: i32, label: String) -> Result<ChecklistItem, ServerFnError> {
+    crate::tasks::session_task_editor(task_id, "You cannot change this task's checklist").await?;
+
+    let db = crate::get_db().await;
+
+    let label = label.trim();
+    if label.is_empty() || label.chars().count() > 200 {
+        return Err(ServerFnError::Request("Checklist item must be 1-200 characters".to_string()));
+    }
+
+    let item = sqlx::query_as::<_, ChecklistItem>(
+        r#"
+        INSERT INTO task_checklist_items (task_id, label, position)
+        SELECT $1, $2, COALESCE(MAX(position) + 1, 0) FROM task_checklist_items WHERE task_id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(task_id)
+    .bind(label)
+    .fetch_one(db)
+    .await?;
+
+    Ok(item)
+}
+
+/// Ticks or unticks a checklist item, recording the signed-in user as who ticked it
+#[server]
+pub async fn set_checklist_item_done(id: i32, is_done: bool) -> Result<ChecklistItem, ServerFnError> {
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    sqlx::query_as::<_, ChecklistItem>(
+        r#"
+        UPDATE task_checklist_items
+        SET
+            is_done = $1,
+            done_by = CASE WHEN $1 THEN $2 END,
+            done_at = CASE WHEN $1 THEN NOW() END
+        WHERE id = $3
+        RETURNING *
+        "#,
+    )
+    .bind(is_done)
+    .bind(actor.id)
+    .bind(id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to update checklist item: {}", e);
+        ServerFnError::ServerError("Failed to update checklist item".into())
+    })
+}
+
+/// Removes an item from a checklist the signed-in user may change
+#[server]
+pub async fn delete_checklist_item(id: i32) -> Result<(), ServerFnError> {
+    let db = crate::get_db().await;
+
+    let task_id: Option<(i32,)> = sqlx::query_as("SELECT task_id FROM task_checklist_items WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?;
+    let Some((task_id,)) = task_id else {
+        return Err(ServerFnError::Request(format!("Checklist item {} not found", id)));
+    };
+    crate::tasks::session_task_editor(task_id, "You cannot change this task's checklist").await?;
+
+    let result = sqlx::query("DELETE FROM task_checklist_items WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows deleted".to_string()))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+pub mod checklist_functions;
+pub mod task_functions;
+
+pub use checklist_functions::{get_checklist, add_checklist_item, set_checklist_item_done, delete_checklist_item};
+pub use task_functions::{
+    get_loan_tasks, get_my_tasks, create_task, update_task, reassign_task, set_task_status, delete_task,
+    session_task_editor,
+};
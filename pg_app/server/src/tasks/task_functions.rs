@@ -0,0 +1,252 @@
+// pg_app/server/src/tasks/task_functions.rs
+use dioxus::prelude::*;
+use shared::models::{Task, TaskInput, TaskStatus};
+use shared::TaskRow;
+
+/// Tasks on a loan, most urgent first
+#[server]
+pub async fn get_loan_tasks(loan_id: i32) -> Result<Vec<TaskRow>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, TaskRow>(
+        r#"
+        SELECT
+            t.*,
+            l.loan_number,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            a.first_name || ' ' || a.last_name AS assignee_name,
+            c.first_name || ' ' || c.last_name AS completed_by_name,
+            (SELECT COUNT(*) FROM task_checklist_items i WHERE i.task_id = t.id AND i.is_done) AS checklist_done,
+            (SELECT COUNT(*) FROM task_checklist_items i WHERE i.task_id = t.id) AS checklist_total
+        FROM tasks t
+        JOIN loans l ON l.id = t.loan_id
+        JOIN borrowers b ON b.id = l.borrower_id
+        LEFT JOIN users a ON a.id = t.assignee_id
+        LEFT JOIN users c ON c.id = t.completed_by
+        WHERE t.loan_id = $1
+        ORDER BY t.status IN ('completed', 'cancelled'), t.due_date NULLS LAST, t.priority DESC, t.id
+        "#,
+    )
+    .bind(loan_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Tasks assigned to the signed-in user, most urgent first
+///
+/// Closed tasks are only included when `include_closed` is set.
+#[server]
+pub async fn get_my_tasks(include_closed: bool) -> Result<Vec<TaskRow>, ServerFnError> {
+    let user = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, TaskRow>(
+        r#"
+        SELECT
+            t.*,
+            l.loan_number,
+            b.first_name || ' ' || b.last_name AS borrower_name,
+            a.first_name || ' ' || a.last_name AS assignee_name,
+            c.first_name || ' ' || c.last_name AS completed_by_name,
+            (SELECT COUNT(*) FROM task_checklist_items i WHERE i.task_id = t.id AND i.is_done) AS checklist_done,
+            (SELECT COUNT(*) FROM task_checklist_items i WHERE i.task_id = t.id) AS checklist_total
+        FROM tasks t
+        JOIN loans l ON l.id = t.loan_id
+        JOIN borrowers b ON b.id = l.borrower_id
+        LEFT JOIN users a ON a.id = t.assignee_id
+        LEFT JOIN users c ON c.id = t.completed_by
+        WHERE t.assignee_id = $1
+          AND ($2 OR t.status NOT IN ('completed', 'cancelled'))
+        ORDER BY t.status IN ('completed', 'cancelled'), t.due_date NULLS LAST, t.priority DESC, t.id
+        "#,
+    )
+    .bind(user.id)
+    .bind(include_closed)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Adds a task to a loan, created by the signed-in user
+///
+/// Assigning it to anyone but themselves requires the `AssignTasks` permission.
+#[server]
+pub async fn create_task(loan_id: i32, input: TaskInput) -> Result<i32, ServerFnError> {
+    let actor = crate::users::session_user().await?;
+    if !shared::models::can_assign_task(&actor, input.assignee_id) {
+        return Err(ServerFnError::Request("Only managers can assign tasks to others".to_string()));
+    }
+    let created_by = actor.id;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    let (id,): (i32,) = sqlx::query_as(
+        r#"
+        INSERT INTO tasks (loan_id, title, description, assignee_id, created_by, due_date, priority)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id
+        "#,
+    )
+    .bind(loan_id)
+    .bind(input.title.trim())
+    .bind(input.description.as_deref().map(str::trim).filter(|d| !d.is_empty()))
+    .bind(input.assignee_id)
+    .bind(created_by)
+    .bind(input.due_date)
+    .bind(input.priority)
+    .fetch_one(db)
+    .await?;
+
+    tracing::info!("Created task {} on loan {}", id, loan_id);
+    Ok(id)
+}
+
+/// Edits a task's details
+///
+/// The assignee is not changed here; use [`reassign_task`].
+#[server]
+pub async fn update_task(id: i32, input: TaskInput) -> Result<Task, ServerFnError> {
+    session_task_editor(id, "You cannot edit this task").await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    sqlx::query_as::<_, Task>(
+        r#"
+        UPDATE tasks
+        SET
+            title = $1,
+            description = $2,
+            due_date = $3,
+            priority = $4
+        WHERE id = $5
+        RETURNING *
+        "#,
+    )
+    .bind(input.title.trim())
+    .bind(input.description.as_deref().map(str::trim).filter(|d| !d.is_empty()))
+    .bind(input.due_date)
+    .bind(input.priority)
+    .bind(id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to update task: {}", e);
+        ServerFnError::ServerError("Failed to update task".into())
+    })
+}
+
+/// Hands a task to another user; requires the `AssignTasks` permission
+#[server]
+pub async fn reassign_task(id: i32, assignee_id: Option<i32>) -> Result<Task, ServerFnError> {
+    let actor =
+        crate::users::session_user_with(shared::models::Permission::AssignTasks, "Only managers can reassign tasks").await?;
+
+    let db = crate::get_db().await;
+
+    let task = match sqlx::query_as::<_, Task>("UPDATE tasks SET assignee_id = $1 WHERE id = $2 RETURNING *")
+        .bind(assignee_id)
+        .bind(id)
+        .fetch_one(db)
+        .await
+    {
+        Ok(task) => task,
+        Err(e) => {
+            tracing::error!("Failed to reassign task: {}", e);
+            return Err(ServerFnError::ServerError("Failed to reassign task".into()));
+        }
+    };
+
+    tracing::info!("Task {} reassigned to {:?} by user {}", id, assignee_id, actor.id);
+    Ok(task)
+}
+
+/// Moves a task to a new status
+///
+/// Completing records the signed-in user and the time as the completion;
+/// any other status clears them.
+#[server]
+pub async fn set_task_status(id: i32, status: TaskStatus) -> Result<Task, ServerFnError> {
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    sqlx::query_as::<_, Task>(
+        r#"
+        UPDATE tasks
+        SET
+            status = $1,
+            completed_by = CASE WHEN $1 = 'completed' THEN COALESCE(completed_by, $2) END,
+            completed_at = CASE WHEN $1 = 'completed' THEN COALESCE(completed_at, NOW()) END
+        WHERE id = $3
+        RETURNING *
+        "#,
+    )
+    .bind(status)
+    .bind(actor.id)
+    .bind(id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to update task status: {}", e);
+        ServerFnError::ServerError("Failed to update task status".into())
+    })
+}
+
+/// Deletes a task the signed-in user may edit, with its checklist
+#[server]
+pub async fn delete_task(id: i32) -> Result<(), ServerFnError> {
+    session_task_editor(id, "You cannot delete this task").await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query("DELETE FROM tasks WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows deleted".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// The signed-in user, who must be allowed to change task `id`
+///
+/// See [`shared::models::Task::editable_by`]. `denied` is the error shown to
+/// signed-in users who may not.
+pub async fn session_task_editor(id: i32, denied: &str) -> Result<shared::models::User, ServerFnError> {
+    let actor = crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let task = sqlx::query_as::<_, Task>("SELECT * FROM tasks WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?;
+    let Some(task) = task else {
+        return Err(ServerFnError::Request(format!("Task {} not found", id)));
+    };
+    let (loan_officer_id,): (Option<i32>,) = sqlx::query_as("SELECT loan_officer_id FROM loans WHERE id = $1")
+        .bind(task.loan_id)
+        .fetch_one(db)
+        .await?;
+    if !task.editable_by(&actor, loan_officer_id) {
+        return Err(ServerFnError::Request(denied.to_string()));
+    }
+    Ok(actor)
+}
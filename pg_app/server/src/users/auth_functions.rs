@@ -1,11 +1,215 @@
 //pg_app/server/src/users/auth_functions.rs
+use dioxus::prelude::*;
+use shared::models::{AccessError, Permission, User};
+
+/// Signs a user in by username or email and starts their session
+///
+/// Failed attempts are counted and the account locks after five in a row
+/// (see [`User::can_login`]). A successful sign-in resets the counter,
+/// records `last_login` and sets the session cookie that identifies the user
+/// to every later server function (see [`session_user`]).
+#[server]
+pub async fn login_user(login: String, password: String) -> Result<User, ServerFnError> {
+    use base64::Engine;
+    use server_fn::axum_export::http::{header::SET_COOKIE, HeaderValue};
+    use sha2::{Digest, Sha256};
+    use shared::models::{session_cookie, SESSION_HOURS};
+
+    let db = crate::get_db().await;
+
+    let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE username = $1 OR LOWER(email) = LOWER($1)")
+        .bind(login.trim())
+        .fetch_optional(db)
+        .await?;
+
+    let Some(user) = user else {
+        return Err(ServerFnError::Request("Invalid username or password".to_string()));
+    };
+    if !user.can_login() {
+        return Err(ServerFnError::Request("Account is locked or inactive".to_string()));
+    }
+    if !user.verify_password(&password) {
+        sqlx::query("UPDATE users SET failed_login_attempts = failed_login_attempts + 1 WHERE id = $1")
+            .bind(user.id)
+            .execute(db)
+            .await?;
+        tracing::warn!("Failed login for user {}", user.id);
+        return Err(ServerFnError::Request("Invalid username or password".to_string()));
+    }
+
+    let user = sqlx::query_as::<_, User>(
+        "UPDATE users SET failed_login_attempts = 0, last_login = NOW() WHERE id = $1 RETURNING *",
+    )
+    .bind(user.id)
+    .fetch_one(db)
+    .await?;
+
+    // Only the token's hash is stored; the cookie carries the token itself
+    let token = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>());
+    let created = sqlx::query(
+        "INSERT INTO user_sessions (user_id, token_hash, expires_at) VALUES ($1, $2, NOW() + make_interval(hours => $3))",
+    )
+    .bind(user.id)
+    .bind(Sha256::digest(token.as_bytes()).to_vec())
+    .bind(SESSION_HOURS as i32)
+    .execute(db)
+    .await;
+    if let Err(e) = created {
+        tracing::error!("Failed to create session for user {}: {}", user.id, e);
+        return Err(ServerFnError::ServerError("Failed to sign in".into()));
+    }
+    let cookie = HeaderValue::from_str(&session_cookie(&token))?;
+    server_context().response_parts_mut().headers.append(SET_COOKIE, cookie);
+
+    tracing::info!("User {} signed in", user.id);
+    Ok(user)
+}
+
+/// The user signed in on this request's session, if any
+///
+/// Used to restore the signed-in user when the app starts.
+#[server]
+pub async fn get_session_user() -> Result<Option<User>, ServerFnError> {
+    use server_fn::axum_export::http::header::COOKIE;
+    use sha2::{Digest, Sha256};
+    use shared::models::session_token;
+
+    // The request lock is released before the query is awaited
+    let token = {
+        let context = server_context();
+        let parts = context.request_parts();
+        parts
+            .headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|header| header.to_str().ok())
+            .find_map(session_token)
+            .map(str::to_string)
+    };
+    let Some(token) = token else {
+        return Ok(None);
+    };
+
+    let db = crate::get_db().await;
+
+    let user = sqlx::query_as::<_, User>(
+        r#"
+        SELECT u.* FROM user_sessions s
+        JOIN users u ON u.id = s.user_id
+        WHERE s.token_hash = $1 AND s.expires_at > NOW()
+        "#,
+    )
+    .bind(Sha256::digest(token.as_bytes()).to_vec())
+    .fetch_optional(db)
+    .await?;
+
+    Ok(user)
+}
+
+/// Ends this request's session and clears its cookie
+#[server]
+pub async fn logout_user() -> Result<(), ServerFnError> {
+    use server_fn::axum_export::http::{header::{COOKIE, SET_COOKIE}, HeaderValue};
+    use sha2::{Digest, Sha256};
+    use shared::models::{expired_session_cookie, session_token};
+
+    let token = {
+        let context = server_context();
+        let parts = context.request_parts();
+        parts
+            .headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|header| header.to_str().ok())
+            .find_map(session_token)
+            .map(str::to_string)
+    };
+    if let Some(token) = token {
+        let db = crate::get_db().await;
+        sqlx::query("DELETE FROM user_sessions WHERE token_hash = $1 OR expires_at <= NOW()")
+            .bind(Sha256::digest(token.as_bytes()).to_vec())
+            .execute(db)
+            .await?;
+    }
+
+    let cookie = HeaderValue::from_str(&expired_session_cookie())?;
+    server_context().response_parts_mut().headers.append(SET_COOKIE, cookie);
+
+    Ok(())
+}
+
+/// The signed-in user acting on this request
+///
+/// Server functions take the acting user from here rather than from their
+/// arguments. Fails when there is no session or the account has since been
+/// locked or deactivated.
+pub async fn session_user() -> Result<User, ServerFnError> {
+    let session = get_session_user().await?;
+    shared::models::authorize(session, None).map_err(|e| ServerFnError::Request(e.to_string()))
+}
+
+/// The signed-in user acting on this request, who must have `permission`
+///
+/// `denied` is the error shown to signed-in users without it.
+pub async fn session_user_with(permission: Permission, denied: &str) -> Result<User, ServerFnError> {
+    let session = get_session_user().await?;
+    match shared::models::authorize(session, Some(permission)) {
+        Ok(user) => Ok(user),
+        Err(AccessError::Forbidden) => Err(ServerFnError::Request(denied.to_string())),
+        Err(e) => Err(ServerFnError::Request(e.to_string())),
+    }
+}
+
+/// The signed-in user, who must be allowed to change `loan_id`
+///
+/// See [`shared::models::can_edit_loan`]. `denied` is the error shown to
+/// signed-in users who may not.
+pub async fn session_loan_editor(loan_id: i32, denied: &str) -> Result<User, ServerFnError> {
+    let actor = session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let loan_officer_id: Option<(Option<i32>,)> = sqlx::query_as("SELECT loan_officer_id FROM loans WHERE id = $1")
+        .bind(loan_id)
+        .fetch_optional(db)
+        .await?;
+    let Some((loan_officer_id,)) = loan_officer_id else {
+        return Err(ServerFnError::Request(format!("Loan {} not found", loan_id)));
+    };
+    if !shared::models::can_edit_loan(&actor, loan_officer_id) {
+        return Err(ServerFnError::Request(denied.to_string()));
+    }
+    Ok(actor)
+}
+
+/// The signed-in user, who must be allowed to change `borrower_id`
+///
+/// See [`shared::models::can_edit_borrower`]; the borrower's loans include
+/// those they are a co-borrower on.
+pub async fn session_borrower_editor(borrower_id: i32, denied: &str) -> Result<User, ServerFnError> {
+    let actor = session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let loan_officer_ids: Vec<(Option<i32>,)> = sqlx::query_as(
+        r#"
+        SELECT loan_officer_id FROM loans WHERE borrower_id = $1
+        UNION ALL
+        SELECT l.loan_officer_id FROM loan_co_borrowers c JOIN loans l ON l.id = c.loan_id WHERE c.borrower_id = $1
+        "#,
+    )
+    .bind(borrower_id)
+    .fetch_all(db)
+    .await?;
+    let loan_officer_ids: Vec<Option<i32>> = loan_officer_ids.into_iter().map(|(id,)| id).collect();
+    if !shared::models::can_edit_borrower(&actor, &loan_officer_ids) {
+        return Err(ServerFnError::Request(denied.to_string()));
+    }
+    Ok(actor)
+}
+
 // verify_password(input: &str, stored_hash: &str) -> Result<bool>
-// login_user(email: &str, password: &str) -> Result<User>
-// logout_user(session_id: Uuid)
 // // Session management
-// create_session(user_id: i32) -> Result<Session>
-// get_user_from_session(session_token: &str) -> Option<User>
-// invalidate_session(session_id: Uuid)
 // refresh_token(old_token: &str) -> Result<NewToken>
 
 
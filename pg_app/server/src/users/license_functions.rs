@@ -0,0 +1,175 @@
+// pg_app/server/src/users/license_functions.rs
+use dioxus::prelude::*;
+use shared::dtos::ExpiringLicenseRow;
+use shared::models::{License, LicenseInput};
+
+/// A loan officer's state licenses, by state
+#[server]
+pub async fn get_licenses(user_id: i32) -> Result<Vec<License>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, License>("SELECT * FROM loan_officer_licenses WHERE user_id = $1 ORDER BY state")
+        .bind(user_id)
+        .fetch_all(db)
+        .await?;
+
+    Ok(result)
+}
+
+/// Sets or clears a user's NMLS ID; requires the `ManageUsers` permission
+#[server]
+pub async fn save_nmls_id(user_id: i32, nmls_id: Option<String>) -> Result<(), ServerFnError> {
+    crate::users::session_user_with(shared::models::Permission::ManageUsers, "Only administrators can change licensing")
+        .await?;
+
+    let db = crate::get_db().await;
+
+    let nmls_id = nmls_id.map(|id| id.trim().to_string()).filter(|id| !id.is_empty());
+    if let Some(id) = &nmls_id
+        && !shared::models::is_valid_nmls_id(id)
+    {
+        return Err(ServerFnError::Request("NMLS ID must be up to 12 digits".to_string()));
+    }
+
+    let result = sqlx::query("UPDATE users SET nmls_id = $1 WHERE id = $2")
+        .bind(&nmls_id)
+        .bind(user_id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request(format!("User {} not found", user_id)))
+    } else {
+        Ok(())
+    }
+}
+
+/// Adds a state license, or renews the one already held for the state;
+/// requires the `ManageUsers` permission
+#[server]
+pub async fn save_license(user_id: i32, input: LicenseInput) -> Result<License, ServerFnError> {
+    crate::users::session_user_with(shared::models::Permission::ManageUsers, "Only administrators can change licensing")
+        .await?;
+
+    let db = crate::get_db().await;
+
+    if let Err(e) = validator::Validate::validate(&input) {
+        return Err(ServerFnError::Request(e.to_string()));
+    }
+
+    sqlx::query_as::<_, License>(
+        r#"
+        INSERT INTO loan_officer_licenses (user_id, state, license_number, expires_on)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id, state) DO UPDATE SET
+            license_number = EXCLUDED.license_number,
+            expires_on = EXCLUDED.expires_on
+        RETURNING *
+        "#,
+    )
+    .bind(user_id)
+    .bind(&input.state)
+    .bind(input.license_number.trim())
+    .bind(input.expires_on)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to save license: {}", e);
+        ServerFnError::ServerError("Failed to save license".into())
+    })
+}
+
+/// Removes a state license; requires the `ManageUsers` permission
+#[server]
+pub async fn delete_license(id: i32) -> Result<(), ServerFnError> {
+    crate::users::session_user_with(shared::models::Permission::ManageUsers, "Only administrators can change licensing")
+        .await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query("DELETE FROM loan_officer_licenses WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        Err(ServerFnError::Request("No rows deleted".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Licenses of active users that expire within `days`, including any
+/// already expired, soonest first
+#[server]
+pub async fn get_expiring_licenses(days: i32) -> Result<Vec<ExpiringLicenseRow>, ServerFnError> {
+    crate::users::session_user().await?;
+
+    let db = crate::get_db().await;
+
+    let result = sqlx::query_as::<_, ExpiringLicenseRow>(
+        r#"
+        SELECT
+            lic.id AS license_id,
+            u.id AS user_id,
+            u.first_name || ' ' || u.last_name AS officer_name,
+            u.nmls_id,
+            lic.state,
+            lic.license_number,
+            lic.expires_on
+        FROM loan_officer_licenses lic
+        JOIN users u ON u.id = lic.user_id
+        WHERE u.is_active
+          AND lic.expires_on <= CURRENT_DATE + $1::INTEGER
+        ORDER BY lic.expires_on, u.last_name, lic.state
+        "#,
+    )
+    .bind(days)
+    .fetch_all(db)
+    .await?;
+
+    Ok(result)
+}
+
+/// Fails unless the loan officer may originate in `state` on `today`, or
+/// originate at all while the state is not known yet
+///
+/// Used wherever a loan's officer or its property's state changes, inside
+/// the transaction that makes the change: the officer's row and licenses
+/// are locked until it commits, so they cannot be revoked in between. The
+/// error is a request error carrying the [`shared::models::LicenseError`]
+/// message, e.g. "Marco Reyes is not licensed in TX".
+pub async fn ensure_licensed(
+    conn: &mut sqlx::PgConnection,
+    loan_officer_id: i32,
+    state: Option<&str>,
+    today: sqlx::types::chrono::NaiveDate,
+) -> Result<(), ServerFnError> {
+    let officer = sqlx::query_as::<_, shared::models::User>("SELECT * FROM users WHERE id = $1 FOR SHARE")
+        .bind(loan_officer_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+    let Some(officer) = officer else {
+        return Err(ServerFnError::Request(format!("Loan officer {} not found", loan_officer_id)));
+    };
+    let checked = match state {
+        Some(state) => {
+            let licenses = sqlx::query_as::<_, License>(
+                "SELECT * FROM loan_officer_licenses WHERE user_id = $1 AND state = $2 FOR SHARE",
+            )
+            .bind(loan_officer_id)
+            .bind(state)
+            .fetch_all(&mut *conn)
+            .await?;
+            shared::models::check_license(&officer, &licenses, state, today)
+        }
+        None => shared::models::check_originator(&officer),
+    };
+
+    match checked {
+        Ok(()) => Ok(()),
+        Err(e) => Err(ServerFnError::Request(e.to_string())),
+    }
+}
@@ -1,4 +1,12 @@
 pub mod user_functions;
 pub mod auth_functions;
+pub mod license_functions;
 
-pub use user_functions::{get_user, get_all_users, create_user, update_user, delete_user};
\ No newline at end of file
+pub use user_functions::{get_user, get_all_users, create_user, update_user, delete_user};
+pub use auth_functions::{
+    login_user, logout_user, get_session_user, session_user, session_user_with, session_loan_editor,
+    session_borrower_editor,
+};
+pub use license_functions::{
+    get_licenses, save_nmls_id, save_license, delete_license, get_expiring_licenses, ensure_licensed,
+};
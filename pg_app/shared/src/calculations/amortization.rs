@@ -0,0 +1,107 @@
+//! Level-payment amortization
+
+use serde::{Deserialize, Serialize};
+
+/// Monthly principal and interest payment in cents
+///
+/// Uses the standard annuity formula on a monthly rate of `annual_rate / 12`
+/// and rounds to the nearest cent. A zero rate spreads the principal evenly.
+///
+/// # Example
+/// ```
+/// use shared::calculations::amortization::monthly_payment_cents;
+///
+/// // $200,000 at 6% for 30 years
+/// assert_eq!(monthly_payment_cents(200_000_00, 6.0, 360), 1_199_10);
+/// assert_eq!(monthly_payment_cents(120_000_00, 0.0, 120), 1_000_00);
+/// ```
+pub fn monthly_payment_cents(principal_cents: i64, annual_rate_percent: f64, term_months: i32) -> i64 {
+    if term_months <= 0 {
+        return 0;
+    }
+    let principal = principal_cents as f64;
+    let n = term_months as f64;
+    let r = annual_rate_percent / 100.0 / 12.0;
+    if r == 0.0 {
+        return (principal / n).round() as i64;
+    }
+    (principal * r / (1.0 - (1.0 + r).powf(-n))).round() as i64
+}
+
+/// One scheduled payment on an amortization schedule
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AmortizationRow {
+    /// Payment number, starting at 1
+    pub number: u32,
+
+    /// Total payment, in cents
+    pub payment_cents: i64,
+
+    /// Interest portion, in cents
+    pub interest_cents: i64,
+
+    /// Principal portion, in cents
+    pub principal_cents: i64,
+
+    /// Balance after the payment, in cents
+    pub balance_cents: i64,
+}
+
+/// Full amortization schedule at the scheduled monthly payment
+///
+/// Interest is rounded to the cent each month and the final payment is
+/// adjusted so the loan pays off exactly, as on a real schedule.
+///
+/// # Example
+/// ```
+/// use shared::calculations::amortization::amortization_schedule;
+///
+/// let schedule = amortization_schedule(200_000_00, 6.0, 360);
+/// assert_eq!(schedule.len(), 360);
+/// assert_eq!(schedule[0].interest_cents, 1_000_00);
+/// assert_eq!(schedule[0].principal_cents, 199_10);
+/// assert_eq!(schedule.last().unwrap().balance_cents, 0);
+/// ```
+pub fn amortization_schedule(principal_cents: i64, annual_rate_percent: f64, term_months: i32) -> Vec<AmortizationRow> {
+    let payment = monthly_payment_cents(principal_cents, annual_rate_percent, term_months);
+    let r = annual_rate_percent / 100.0 / 12.0;
+    let mut balance = principal_cents;
+    let mut rows = Vec::with_capacity(term_months.max(0) as usize);
+    for number in 1..=term_months.max(0) as u32 {
+        if balance <= 0 {
+            break;
+        }
+        let interest = (balance as f64 * r).round() as i64;
+        let principal = if number == term_months as u32 {
+            balance
+        } else {
+            (payment - interest).min(balance)
+        };
+        balance -= principal;
+        rows.push(AmortizationRow {
+            number,
+            payment_cents: principal + interest,
+            interest_cents: interest,
+            principal_cents: principal,
+            balance_cents: balance,
+        });
+    }
+    rows
+}
+
+/// Total interest paid over the full term at the scheduled payment, in cents
+///
+/// Sums the interest column of [`amortization_schedule`].
+///
+/// # Example
+/// ```
+/// use shared::calculations::amortization::total_interest_cents;
+///
+/// assert_eq!(total_interest_cents(120_000_00, 0.0, 120), 0);
+/// ```
+pub fn total_interest_cents(principal_cents: i64, annual_rate_percent: f64, term_months: i32) -> i64 {
+    amortization_schedule(principal_cents, annual_rate_percent, term_months)
+        .iter()
+        .map(|row| row.interest_cents)
+        .sum()
+}
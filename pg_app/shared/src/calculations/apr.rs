@@ -0,0 +1,190 @@
+//! Annual percentage rate by the actuarial method of Regulation Z, Appendix J
+//!
+//! The APR is the nominal annual rate at which the present value of the
+//! payment stream equals the amount financed. Each payment is discounted as
+//! `P / ((1 + f·i) · (1 + i)^t)`, where `i` is the rate per unit period, `t`
+//! is the number of whole unit periods before the payment and `f` is the
+//! fraction of a unit period in an odd first period.
+
+use serde::{Deserialize, Serialize};
+
+/// APR tolerance for a regular transaction, in percentage points (1/8 of 1%)
+pub const APR_TOLERANCE: f64 = 0.125;
+
+/// APR tolerance for an irregular transaction, in percentage points (1/4 of 1%)
+pub const IRREGULAR_APR_TOLERANCE: f64 = 0.25;
+
+/// A run of equal payments, one per unit period
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaymentStream {
+    /// Amount of each payment, in cents
+    pub amount_cents: i64,
+
+    /// Number of payments in the run
+    pub count: u32,
+}
+
+/// Payments owed on a loan, measured from consummation
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaymentSchedule {
+    /// Payment runs in order; each begins the period after the last one ends
+    pub streams: Vec<PaymentStream>,
+
+    /// Unit periods per year (12 monthly, 24 semimonthly, 26 biweekly, 52 weekly)
+    pub periods_per_year: u32,
+
+    /// Whole unit periods from consummation to the first payment
+    pub first_period_units: u32,
+
+    /// Days beyond the whole unit periods in the first period
+    pub odd_days: u32,
+}
+
+impl PaymentSchedule {
+    /// Level monthly payments with a regular first period
+    ///
+    /// # Example
+    /// ```
+    /// use shared::calculations::apr::PaymentSchedule;
+    ///
+    /// let schedule = PaymentSchedule::monthly(1_199_10, 360);
+    /// assert_eq!(schedule.total_of_payments_cents(), 431_676_00);
+    /// ```
+    pub fn monthly(payment_cents: i64, count: u32) -> Self {
+        Self {
+            streams: vec![PaymentStream { amount_cents: payment_cents, count }],
+            periods_per_year: 12,
+            first_period_units: 1,
+            odd_days: 0,
+        }
+    }
+
+    /// Sets an irregular first period of `whole_units` unit periods plus `odd_days` days
+    pub fn with_first_period(mut self, whole_units: u32, odd_days: u32) -> Self {
+        self.first_period_units = whole_units;
+        self.odd_days = odd_days;
+        self
+    }
+
+    /// Appends a run of payments, e.g. a different final payment
+    pub fn then(mut self, amount_cents: i64, count: u32) -> Self {
+        self.streams.push(PaymentStream { amount_cents, count });
+        self
+    }
+
+    /// Sum of every scheduled payment, in cents
+    pub fn total_of_payments_cents(&self) -> i64 {
+        self.streams
+            .iter()
+            .map(|stream| stream.amount_cents * stream.count as i64)
+            .sum()
+    }
+
+    /// Days counted as one unit period when converting odd days to a fraction
+    ///
+    /// A month is 30 days, a half-month 15 and a week 7; other periods use
+    /// 365 days divided by the number of periods in a year.
+    pub fn days_per_unit_period(&self) -> f64 {
+        match self.periods_per_year {
+            12 => 30.0,
+            24 => 15.0,
+            52 => 7.0,
+            26 => 14.0,
+            n => 365.0 / n.max(1) as f64,
+        }
+    }
+
+    /// Present value of the payments at a rate of `rate` per unit period
+    fn present_value(&self, rate: f64) -> f64 {
+        let fraction = self.odd_days as f64 / self.days_per_unit_period();
+        let odd_period_factor = 1.0 + fraction * rate;
+        let mut periods = self.first_period_units as i32;
+        let mut total = 0.0;
+        for stream in &self.streams {
+            for _ in 0..stream.count {
+                total += stream.amount_cents as f64 / (odd_period_factor * (1.0 + rate).powi(periods));
+                periods += 1;
+            }
+        }
+        total
+    }
+}
+
+/// Annual percentage rate as a percentage (e.g. `6.125`)
+///
+/// Solves the Appendix J equation for the rate per unit period and
+/// multiplies by the periods in a year. Returns `None` when the amount
+/// financed is not positive, there are no payments, or the payments total
+/// less than the amount financed.
+///
+/// # Example
+/// ```
+/// use shared::calculations::apr::{annual_percentage_rate, PaymentSchedule};
+///
+/// // Appendix J (b)(5)(i): $5,000 financed, 36 monthly payments of $166.07
+/// let apr = annual_percentage_rate(5_000_00, &PaymentSchedule::monthly(166_07, 36)).unwrap();
+/// assert_eq!(format!("{apr:.2}"), "12.00");
+/// ```
+pub fn annual_percentage_rate(amount_financed_cents: i64, schedule: &PaymentSchedule) -> Option<f64> {
+    let total = schedule.total_of_payments_cents();
+    if amount_financed_cents <= 0 || total <= 0 || total < amount_financed_cents {
+        return None;
+    }
+
+    let target = amount_financed_cents as f64;
+    // Present value falls as the rate rises, so bisect between 0 and a rate
+    // that discounts the payments below the amount financed.
+    let mut low = 0.0;
+    let mut high = 1.0;
+    while schedule.present_value(high) > target {
+        high *= 2.0;
+        if high > 1.0e6 {
+            return None;
+        }
+    }
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        if schedule.present_value(mid) > target {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Some((low + high) / 2.0 * schedule.periods_per_year as f64 * 100.0)
+}
+
+/// APR of a loan with prepaid finance charges
+///
+/// The amount financed is the loan amount less the prepaid finance charges.
+///
+/// # Example
+/// ```
+/// use shared::calculations::apr::{loan_apr, PaymentSchedule};
+///
+/// let schedule = PaymentSchedule::monthly(1_199_10, 360);
+/// let note_only = loan_apr(200_000_00, 0, &schedule).unwrap();
+/// let with_points = loan_apr(200_000_00, 4_000_00, &schedule).unwrap();
+/// assert_eq!(format!("{note_only:.3}"), "6.000");
+/// assert!(with_points > note_only);
+/// ```
+pub fn loan_apr(loan_amount_cents: i64, prepaid_finance_charge_cents: i64, schedule: &PaymentSchedule) -> Option<f64> {
+    annual_percentage_rate(loan_amount_cents - prepaid_finance_charge_cents, schedule)
+}
+
+/// Whether a disclosed APR is accurate against the actual APR
+///
+/// Regular transactions allow 1/8 of a percentage point either way;
+/// irregular ones (multiple advances, irregular periods or amounts) allow 1/4.
+///
+/// # Example
+/// ```
+/// use shared::calculations::apr::apr_within_tolerance;
+///
+/// assert!(apr_within_tolerance(6.125, 6.250, false));
+/// assert!(!apr_within_tolerance(6.100, 6.250, false));
+/// assert!(apr_within_tolerance(6.100, 6.250, true));
+/// ```
+pub fn apr_within_tolerance(disclosed_apr: f64, actual_apr: f64, irregular: bool) -> bool {
+    let tolerance = if irregular { IRREGULAR_APR_TOLERANCE } else { APR_TOLERANCE };
+    (disclosed_apr - actual_apr).abs() <= tolerance + 1e-9
+}
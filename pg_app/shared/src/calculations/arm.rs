@@ -0,0 +1,127 @@
+//! Adjustable-rate mortgage payment projections
+
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::amortization::{amortization_schedule, monthly_payment_cents};
+use crate::models::ArmTerms;
+
+/// Index path assumed for a projection
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ArmScenario {
+    /// Rate rises by the full cap at every adjustment until the lifetime cap
+    WorstCase,
+    /// Index holds at this value (a percentage) from the first adjustment on
+    Index(f64),
+}
+
+/// A stretch of payments at one rate
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ArmPeriod {
+    /// First payment number at this rate, starting at 1
+    pub first_payment: u32,
+
+    /// Last payment number at this rate
+    pub last_payment: u32,
+
+    /// Interest rate as a percentage
+    pub rate: f64,
+
+    /// Monthly principal and interest payment, in cents
+    pub payment_cents: i64,
+
+    /// Balance when the rate takes effect, in cents
+    pub starting_balance_cents: i64,
+}
+
+/// Index plus margin, rounded to the nearest 1/8 of a percentage point
+///
+/// # Example
+/// ```
+/// use shared::calculations::arm::fully_indexed_rate;
+///
+/// assert_eq!(fully_indexed_rate(4.31, 2.75), 7.0);
+/// assert_eq!(fully_indexed_rate(4.20, 2.75), 7.0);
+/// assert_eq!(fully_indexed_rate(4.15, 2.75), 6.875);
+/// ```
+pub fn fully_indexed_rate(index: f64, margin: f64) -> f64 {
+    ((index + margin) * 8.0).round() / 8.0
+}
+
+/// Projects an ARM's rate and payment at each adjustment
+///
+/// The loan starts at `start_rate` for the initial fixed period. At each
+/// adjustment the rate moves toward the scenario's target by no more than the
+/// initial cap (first adjustment) or periodic cap (later ones), and stays
+/// between the floor and the start rate plus the lifetime cap. The payment is
+/// recast over the remaining term at every change.
+///
+/// # Example
+/// ```
+/// use shared::calculations::arm::{project_arm, ArmScenario};
+/// use shared::models::ArmTerms;
+///
+/// // $300,000 5/6 ARM at 6% with 2/1/5 caps
+/// let terms = ArmTerms::default();
+/// let path = project_arm(300_000_00, 6.0, 360, &terms, ArmScenario::WorstCase);
+/// assert_eq!(path[0].first_payment, 1);
+/// assert_eq!(path[0].last_payment, 60);
+/// assert_eq!(path[0].payment_cents, 1_798_65);
+/// assert_eq!(path[1].rate, 8.0);
+/// assert_eq!(path[2].rate, 9.0);
+/// assert_eq!(path[3].rate, 10.0);
+/// assert_eq!(path[4].rate, 11.0);
+/// assert_eq!(path.last().unwrap().rate, 11.0);
+/// assert_eq!(path.last().unwrap().last_payment, 360);
+/// ```
+pub fn project_arm(
+    amount_cents: i64,
+    start_rate: f64,
+    term_months: i32,
+    terms: &ArmTerms,
+    scenario: ArmScenario,
+) -> Vec<ArmPeriod> {
+    let term = term_months.max(0) as u32;
+    let ceiling = start_rate + terms.lifetime_cap;
+    let floor = terms.floor();
+
+    let mut periods = Vec::new();
+    let mut balance = amount_cents;
+    let mut rate = start_rate;
+    let mut payment_number = 1;
+    let mut period_length = terms.initial_fixed_months.max(1) as u32;
+
+    while payment_number <= term && balance > 0 {
+        let remaining = term - payment_number + 1;
+        let months = period_length.min(remaining);
+        let schedule = amortization_schedule(balance, rate, remaining as i32);
+
+        periods.push(ArmPeriod {
+            first_payment: payment_number,
+            last_payment: payment_number + months - 1,
+            rate,
+            payment_cents: monthly_payment_cents(balance, rate, remaining as i32),
+            starting_balance_cents: balance,
+        });
+
+        balance = schedule
+            .get(months as usize - 1)
+            .map(|row| row.balance_cents)
+            .unwrap_or(0);
+        payment_number += months;
+
+        let cap = if periods.len() == 1 { terms.initial_cap } else { terms.periodic_cap };
+        let target = match scenario {
+            ArmScenario::WorstCase => ceiling,
+            ArmScenario::Index(index) => fully_indexed_rate(index, terms.margin),
+        };
+        rate = target.clamp(rate - cap, rate + cap).min(ceiling).max(floor);
+        period_length = terms.adjustment_interval_months.max(1) as u32;
+    }
+
+    periods
+}
+
+/// Highest payment on a projected path, in cents
+pub fn max_payment_cents(periods: &[ArmPeriod]) -> i64 {
+    periods.iter().map(|period| period.payment_cents).max().unwrap_or(0)
+}
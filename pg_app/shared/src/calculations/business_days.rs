@@ -0,0 +1,194 @@
+//! Regulation Z business days and US federal holidays
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Which Regulation Z business-day definition to count with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BusinessDayRule {
+    /// Days the office is open for substantially all business functions,
+    /// taken as Monday to Friday except observed federal holidays
+    General,
+    /// All calendar days except Sundays and federal legal public holidays
+    /// (used for Closing Disclosure receipt and the mailbox rule)
+    Specific,
+}
+
+/// The `n`th `weekday` of a month (1-based)
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
+/// The last `weekday` of a month
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    nth_weekday(year, month, weekday, 5).or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+/// Legal public holidays under 5 U.S.C. 6103(a) on the dates the statute names
+///
+/// Juneteenth is included from 2021, the year it became a holiday.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::calculations::business_days::federal_holidays;
+///
+/// let holidays = federal_holidays(2025);
+/// assert_eq!(holidays.len(), 11);
+/// assert!(holidays.contains(&NaiveDate::from_ymd_opt(2025, 11, 27).unwrap())); // Thanksgiving
+/// assert!(holidays.contains(&NaiveDate::from_ymd_opt(2025, 5, 26).unwrap())); // Memorial Day
+/// ```
+pub fn federal_holidays(year: i32) -> Vec<NaiveDate> {
+    let fixed = |month: u32, day: u32| NaiveDate::from_ymd_opt(year, month, day);
+
+    [
+        fixed(1, 1),
+        nth_weekday(year, 1, Weekday::Mon, 3),
+        nth_weekday(year, 2, Weekday::Mon, 3),
+        last_weekday(year, 5, Weekday::Mon),
+        if year >= 2021 { fixed(6, 19) } else { None },
+        fixed(7, 4),
+        nth_weekday(year, 9, Weekday::Mon, 1),
+        nth_weekday(year, 10, Weekday::Mon, 2),
+        fixed(11, 11),
+        nth_weekday(year, 11, Weekday::Thu, 4),
+        fixed(12, 25),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Day a holiday is observed: Saturday holidays move to Friday, Sunday ones to Monday
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::calculations::business_days::observed_date;
+///
+/// // July 4, 2026 is a Saturday
+/// let july_4 = NaiveDate::from_ymd_opt(2026, 7, 4).unwrap();
+/// assert_eq!(observed_date(july_4), NaiveDate::from_ymd_opt(2026, 7, 3).unwrap());
+/// ```
+pub fn observed_date(holiday: NaiveDate) -> NaiveDate {
+    match holiday.weekday() {
+        Weekday::Sat => holiday.pred_opt().unwrap_or(holiday),
+        Weekday::Sun => holiday.succ_opt().unwrap_or(holiday),
+        _ => holiday,
+    }
+}
+
+/// Whether `date` is a federal holiday on the day it is observed
+pub fn is_observed_holiday(date: NaiveDate) -> bool {
+    // New Year's Day on a Saturday is observed on December 31 of the prior year
+    [date.year(), date.year() + 1]
+        .into_iter()
+        .flat_map(federal_holidays)
+        .any(|holiday| observed_date(holiday) == date)
+}
+
+/// Whether `date` counts as a business day under `rule`
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::calculations::business_days::{is_business_day, BusinessDayRule};
+///
+/// let saturday = NaiveDate::from_ymd_opt(2025, 6, 14).unwrap();
+/// assert!(!is_business_day(saturday, BusinessDayRule::General));
+/// assert!(is_business_day(saturday, BusinessDayRule::Specific));
+///
+/// let observed_july_4 = NaiveDate::from_ymd_opt(2026, 7, 3).unwrap();
+/// assert!(!is_business_day(observed_july_4, BusinessDayRule::General));
+/// assert!(is_business_day(observed_july_4, BusinessDayRule::Specific));
+/// ```
+pub fn is_business_day(date: NaiveDate, rule: BusinessDayRule) -> bool {
+    match rule {
+        BusinessDayRule::General => {
+            !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !is_observed_holiday(date)
+        }
+        BusinessDayRule::Specific => date.weekday() != Weekday::Sun && !federal_holidays(date.year()).contains(&date),
+    }
+}
+
+/// The `days`th business day after `date` (zero returns `date` itself)
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::calculations::business_days::{add_business_days, BusinessDayRule};
+///
+/// // Application on Wednesday, November 26, 2025; Thanksgiving is the 27th
+/// let applied = NaiveDate::from_ymd_opt(2025, 11, 26).unwrap();
+/// assert_eq!(
+///     add_business_days(applied, 3, BusinessDayRule::General),
+///     NaiveDate::from_ymd_opt(2025, 12, 2).unwrap()
+/// );
+/// assert_eq!(
+///     add_business_days(applied, 3, BusinessDayRule::Specific),
+///     NaiveDate::from_ymd_opt(2025, 12, 1).unwrap()
+/// );
+/// ```
+pub fn add_business_days(date: NaiveDate, days: u32, rule: BusinessDayRule) -> NaiveDate {
+    step_business_days(date, days, rule, |d| d.checked_add_days(Days::new(1)))
+}
+
+/// The `days`th business day before `date` (zero returns `date` itself)
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::calculations::business_days::{subtract_business_days, BusinessDayRule};
+///
+/// // Closing Tuesday, May 27, 2025, the day after Memorial Day
+/// let closing = NaiveDate::from_ymd_opt(2025, 5, 27).unwrap();
+/// assert_eq!(
+///     subtract_business_days(closing, 3, BusinessDayRule::Specific),
+///     NaiveDate::from_ymd_opt(2025, 5, 22).unwrap()
+/// );
+/// ```
+pub fn subtract_business_days(date: NaiveDate, days: u32, rule: BusinessDayRule) -> NaiveDate {
+    step_business_days(date, days, rule, |d| d.checked_sub_days(Days::new(1)))
+}
+
+fn step_business_days(
+    mut date: NaiveDate,
+    days: u32,
+    rule: BusinessDayRule,
+    step: impl Fn(NaiveDate) -> Option<NaiveDate>,
+) -> NaiveDate {
+    let mut counted = 0;
+    while counted < days {
+        let Some(next) = step(date) else { break };
+        date = next;
+        if is_business_day(date, rule) {
+            counted += 1;
+        }
+    }
+    date
+}
+
+/// Business days from `from` until `to`, negative when `to` is earlier
+///
+/// Counts the business days after `from` up to and including `to`.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::calculations::business_days::{business_days_between, BusinessDayRule};
+///
+/// let friday = NaiveDate::from_ymd_opt(2025, 6, 13).unwrap();
+/// let tuesday = NaiveDate::from_ymd_opt(2025, 6, 17).unwrap();
+/// assert_eq!(business_days_between(friday, tuesday, BusinessDayRule::General), 2);
+/// assert_eq!(business_days_between(tuesday, friday, BusinessDayRule::General), -2);
+/// ```
+pub fn business_days_between(from: NaiveDate, to: NaiveDate, rule: BusinessDayRule) -> i64 {
+    let (start, end, sign) = if to >= from { (from, to, 1) } else { (to, from, -1) };
+    let count = start
+        .iter_days()
+        .skip(1)
+        .take_while(|date| *date <= end)
+        .filter(|date| is_business_day(*date, rule))
+        .count() as i64;
+    sign * count
+}
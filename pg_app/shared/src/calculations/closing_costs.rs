@@ -0,0 +1,149 @@
+//! Closing cost totals, cash to close and finance charge from the fee worksheet
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::calculations::amortization::{monthly_payment_cents, total_interest_cents};
+use crate::calculations::apr::{annual_percentage_rate, PaymentSchedule};
+use crate::models::{ClosingAdjustments, FeePayer, FeeSection, Loan, LoanFee, LoanPurpose};
+
+/// Worksheet totals, laid out like the Closing Disclosure cost summary
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClosingCostSummary {
+    /// Borrower-paid total for each section, in section order
+    pub section_totals: Vec<(FeeSection, i64)>,
+
+    /// D. Total Loan Costs (A + B + C), borrower-paid, in cents
+    pub loan_costs_cents: i64,
+
+    /// I. Total Other Costs (E + F + G), borrower-paid, in cents
+    pub other_costs_cents: i64,
+
+    /// General lender credits, in cents
+    pub lender_credits_cents: i64,
+
+    /// J. Total Closing Costs (D + I − lender credits), in cents
+    pub total_closing_costs_cents: i64,
+
+    /// Fees paid by the seller, in cents
+    pub seller_paid_cents: i64,
+
+    /// Fees paid by the lender or others, in cents
+    pub paid_by_others_cents: i64,
+
+    /// Borrower-paid fees flagged as finance charges, in cents
+    pub prepaid_finance_charge_cents: i64,
+
+    /// Prepaid finance charges plus all scheduled interest, in cents; just
+    /// the prepaid charges while the note rate is not set
+    pub total_finance_charge_cents: i64,
+
+    /// Loan amount less prepaid finance charges, in cents
+    pub amount_financed_cents: i64,
+
+    /// Annual percentage rate on the scheduled payments, if the note rate is
+    /// set and it can be solved
+    pub apr: Option<f64>,
+
+    /// Cash the borrower brings to closing, in cents; negative means cash back
+    pub cash_to_close_cents: i64,
+}
+
+impl ClosingCostSummary {
+    /// Totals a loan's fee worksheet
+    ///
+    /// Cash to close is total closing costs plus the purchase price (or
+    /// payoffs, for a refinance) less the loan amount, the earnest money
+    /// deposit and seller credits. The purchase price only counts when the
+    /// loan is for a purchase, since a refinance has none to pay.
+    pub fn calculate(
+        fees: &[LoanFee],
+        adjustments: &ClosingAdjustments,
+        loan: &Loan,
+        purchase_price_cents: Option<i64>,
+    ) -> Self {
+        let section_totals: Vec<(FeeSection, i64)> = FeeSection::iter()
+            .map(|section| {
+                let total = fees
+                    .iter()
+                    .filter(|fee| fee.section == section && fee.is_borrower_paid())
+                    .map(|fee| fee.amount_cents)
+                    .sum();
+                (section, total)
+            })
+            .collect();
+
+        let loan_costs_cents: i64 = section_totals
+            .iter()
+            .filter(|(section, _)| section.is_loan_cost())
+            .map(|(_, total)| total)
+            .sum();
+        let other_costs_cents: i64 = section_totals
+            .iter()
+            .filter(|(section, _)| !section.is_loan_cost())
+            .map(|(_, total)| total)
+            .sum();
+        let total_closing_costs_cents = loan_costs_cents + other_costs_cents - adjustments.lender_credits_cents;
+
+        let paid_by = |payers: &[FeePayer]| -> i64 {
+            fees.iter()
+                .filter(|fee| payers.contains(&fee.payer))
+                .map(|fee| fee.amount_cents)
+                .sum()
+        };
+
+        let prepaid_finance_charge_cents: i64 = fees
+            .iter()
+            .filter(|fee| fee.finance_charge && fee.is_borrower_paid())
+            .map(|fee| fee.amount_cents)
+            .sum();
+        let amount_financed_cents = loan.amount_cents - prepaid_finance_charge_cents;
+        let (total_finance_charge_cents, apr) = match loan.note_rate {
+            Some(note_rate) => {
+                let schedule = PaymentSchedule::monthly(
+                    monthly_payment_cents(loan.amount_cents, note_rate, loan.term_months),
+                    loan.term_months.max(0) as u32,
+                );
+                (
+                    prepaid_finance_charge_cents + total_interest_cents(loan.amount_cents, note_rate, loan.term_months),
+                    annual_percentage_rate(amount_financed_cents, &schedule),
+                )
+            }
+            None => (prepaid_finance_charge_cents, None),
+        };
+
+        let purchase_price_cents = match loan.loan_purpose {
+            LoanPurpose::Purchase => purchase_price_cents.unwrap_or(0),
+            _ => 0,
+        };
+        let funds_needed = purchase_price_cents + adjustments.payoffs_cents;
+        let cash_to_close_cents = total_closing_costs_cents + funds_needed
+            - loan.amount_cents
+            - adjustments.earnest_money_cents
+            - adjustments.seller_credits_cents;
+
+        Self {
+            section_totals,
+            loan_costs_cents,
+            other_costs_cents,
+            lender_credits_cents: adjustments.lender_credits_cents,
+            total_closing_costs_cents,
+            seller_paid_cents: paid_by(&[FeePayer::Seller]),
+            paid_by_others_cents: paid_by(&[FeePayer::Lender, FeePayer::Other]),
+            prepaid_finance_charge_cents,
+            total_finance_charge_cents,
+            amount_financed_cents,
+            apr,
+            cash_to_close_cents,
+        }
+    }
+
+    /// Borrower-paid total for one section
+    pub fn section_total(&self, section: FeeSection) -> i64 {
+        self.section_totals
+            .iter()
+            .find(|(s, _)| *s == section)
+            .map(|(_, total)| *total)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,70 @@
+//! Debt-to-income (DTI) calculation from a borrower's liabilities
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Liability;
+
+/// Totals of a borrower's liabilities
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DebtSummary {
+    /// Sum of all unpaid balances in cents
+    pub total_balance_cents: i64,
+
+    /// Sum of every monthly payment in cents
+    pub total_monthly_cents: i64,
+
+    /// Monthly payments that count toward DTI in cents
+    ///
+    /// Excludes debts paid off at closing and debts flagged as excluded.
+    pub counted_monthly_cents: i64,
+
+    /// Balances that will be paid off at closing in cents
+    pub payoff_at_closing_cents: i64,
+}
+
+impl DebtSummary {
+    /// Builds the summary from a borrower's liabilities
+    pub fn from_liabilities(liabilities: &[Liability]) -> Self {
+        liabilities.iter().fold(Self::default(), |mut acc, l| {
+            acc.total_balance_cents += l.balance_cents;
+            acc.total_monthly_cents += l.monthly_payment_cents;
+            if l.counts_toward_dti() {
+                acc.counted_monthly_cents += l.monthly_payment_cents;
+            }
+            if l.paid_off_at_closing {
+                acc.payoff_at_closing_cents += l.balance_cents;
+            }
+            acc
+        })
+    }
+}
+
+/// Ratio of monthly debt to gross monthly income, as a percentage
+///
+/// Returns `None` when income is zero or negative since the ratio is undefined.
+///
+/// # Example
+/// ```
+/// use shared::calculations::dti::debt_to_income;
+///
+/// assert_eq!(debt_to_income(1_500_00, 5_000_00), Some(30.0));
+/// assert_eq!(debt_to_income(1_500_00, 0), None);
+/// ```
+pub fn debt_to_income(monthly_debt_cents: i64, monthly_income_cents: i64) -> Option<f64> {
+    if monthly_income_cents <= 0 {
+        return None;
+    }
+    Some(monthly_debt_cents as f64 / monthly_income_cents as f64 * 100.0)
+}
+
+/// Back-end DTI: proposed housing payment plus counted liabilities over income
+pub fn back_end_dti(
+    summary: &DebtSummary,
+    housing_payment_cents: i64,
+    monthly_income_cents: i64,
+) -> Option<f64> {
+    debt_to_income(
+        summary.counted_monthly_cents + housing_payment_cents,
+        monthly_income_cents,
+    )
+}
@@ -0,0 +1,179 @@
+//! Monthly escrow and the initial escrow deposit
+//!
+//! The initial deposit follows the aggregate accounting method of RESPA
+//! (12 CFR 1024.17): one year of monthly deposits and disbursements is run
+//! through a single account, and the deposit at closing is the least amount
+//! that keeps the lowest balance at the cushion.
+
+use serde::{Deserialize, Serialize};
+
+/// Largest cushion RESPA allows, in months of escrow payments
+pub const MAX_CUSHION_MONTHS: u32 = 2;
+
+/// A bill paid from escrow, such as property taxes or hazard insurance
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EscrowItem {
+    /// Label shown on the disclosure (e.g. `"Property Taxes"`)
+    pub name: String,
+
+    /// Total paid per year, in cents
+    pub annual_cents: i64,
+
+    /// Calendar months (1-12) the bill is paid in; the annual amount is split evenly
+    pub due_months: Vec<u32>,
+}
+
+impl EscrowItem {
+    /// Monthly escrow for this item, in cents
+    pub fn monthly_cents(&self) -> i64 {
+        (self.annual_cents as f64 / 12.0).round() as i64
+    }
+
+    /// Amount paid out in a calendar month, in cents
+    ///
+    /// Each due month pays an even share; cents that do not divide evenly go
+    /// to the last due month of the year, so the year's disbursements add up
+    /// to the annual amount.
+    pub fn disbursement_in(&self, month: u32) -> i64 {
+        let mut due_months: Vec<u32> = self.due_months.iter().copied().filter(|m| (1..=12).contains(m)).collect();
+        due_months.sort_unstable();
+        due_months.dedup();
+        if !due_months.contains(&month) {
+            return 0;
+        }
+        let installments = due_months.len() as i64;
+        let share = self.annual_cents / installments;
+        if due_months.last() == Some(&month) {
+            share + self.annual_cents % installments
+        } else {
+            share
+        }
+    }
+}
+
+/// Initial deposit for one item, as shown in Section G of the disclosures
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ItemDeposit {
+    /// Item label
+    pub name: String,
+
+    /// Monthly escrow for the item, in cents
+    pub monthly_cents: i64,
+
+    /// Months collected at closing
+    pub months: u32,
+
+    /// Amount collected at closing (monthly × months), in cents
+    pub deposit_cents: i64,
+}
+
+/// Result of an escrow account analysis
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EscrowAnalysis {
+    /// Monthly escrow payment across all items, in cents
+    pub monthly_cents: i64,
+
+    /// Cushion kept in the account, in cents
+    pub cushion_cents: i64,
+
+    /// Per-item deposits from a single-item analysis
+    pub items: Vec<ItemDeposit>,
+
+    /// Aggregate adjustment (zero or negative), in cents
+    pub aggregate_adjustment_cents: i64,
+
+    /// Initial escrow deposit at closing, in cents
+    pub initial_deposit_cents: i64,
+}
+
+/// Monthly escrow payment for a set of items, in cents
+///
+/// # Example
+/// ```
+/// use shared::calculations::escrow::{monthly_escrow_cents, EscrowItem};
+///
+/// let items = vec![
+///     EscrowItem { name: "Property Taxes".into(), annual_cents: 4_800_00, due_months: vec![6, 12] },
+///     EscrowItem { name: "Homeowners Insurance".into(), annual_cents: 1_200_00, due_months: vec![3] },
+/// ];
+/// assert_eq!(monthly_escrow_cents(&items), 500_00);
+/// ```
+pub fn monthly_escrow_cents(items: &[EscrowItem]) -> i64 {
+    items.iter().map(EscrowItem::monthly_cents).sum()
+}
+
+/// Least starting balance that keeps a year of activity at or above the cushion
+///
+/// Each month the payment is deposited first, then that month's bills are
+/// paid.
+fn required_starting_balance(items: &[EscrowItem], first_payment_month: u32, cushion_cents: i64) -> i64 {
+    let monthly = monthly_escrow_cents(items);
+    let mut balance = 0;
+    let mut lowest = 0;
+    for offset in 0..12 {
+        let month = (first_payment_month.clamp(1, 12) - 1 + offset) % 12 + 1;
+        balance += monthly;
+        balance -= items.iter().map(|item| item.disbursement_in(month)).sum::<i64>();
+        lowest = lowest.min(balance);
+    }
+    (cushion_cents - lowest).max(0)
+}
+
+/// Runs an aggregate escrow analysis for a loan's first year
+///
+/// `first_payment_month` is the calendar month (1-12) of the first mortgage
+/// payment and `cushion_months` is capped at [`MAX_CUSHION_MONTHS`]. Each
+/// item's deposit comes from a single-item analysis rounded up to whole
+/// months; the aggregate adjustment brings their sum down to the aggregate
+/// requirement.
+///
+/// # Example
+/// ```
+/// use shared::calculations::escrow::{escrow_analysis, EscrowItem};
+///
+/// let items = vec![
+///     EscrowItem { name: "Property Taxes".into(), annual_cents: 4_800_00, due_months: vec![6, 12] },
+///     EscrowItem { name: "Homeowners Insurance".into(), annual_cents: 1_200_00, due_months: vec![3] },
+/// ];
+/// // First payment in February with a two-month cushion
+/// let analysis = escrow_analysis(&items, 2, 2);
+/// assert_eq!(analysis.monthly_cents, 500_00);
+/// assert_eq!(analysis.cushion_cents, 1_000_00);
+/// assert!(analysis.aggregate_adjustment_cents <= 0);
+/// assert_eq!(
+///     analysis.initial_deposit_cents,
+///     analysis.items.iter().map(|i| i.deposit_cents).sum::<i64>() + analysis.aggregate_adjustment_cents,
+/// );
+/// ```
+pub fn escrow_analysis(items: &[EscrowItem], first_payment_month: u32, cushion_months: u32) -> EscrowAnalysis {
+    let cushion_months = cushion_months.min(MAX_CUSHION_MONTHS) as i64;
+    let monthly_cents = monthly_escrow_cents(items);
+    let cushion_cents = monthly_cents * cushion_months;
+
+    let deposits: Vec<ItemDeposit> = items
+        .iter()
+        .map(|item| {
+            let monthly = item.monthly_cents();
+            let single = std::slice::from_ref(item);
+            let needed = required_starting_balance(single, first_payment_month, monthly * cushion_months);
+            let months = if monthly > 0 { (needed + monthly - 1) / monthly } else { 0 };
+            ItemDeposit {
+                name: item.name.clone(),
+                monthly_cents: monthly,
+                months: months as u32,
+                deposit_cents: monthly * months,
+            }
+        })
+        .collect();
+
+    let initial_deposit_cents = required_starting_balance(items, first_payment_month, cushion_cents);
+    let item_total: i64 = deposits.iter().map(|d| d.deposit_cents).sum();
+
+    EscrowAnalysis {
+        monthly_cents,
+        cushion_cents,
+        items: deposits,
+        aggregate_adjustment_cents: (initial_deposit_cents - item_total).min(0),
+        initial_deposit_cents,
+    }
+}
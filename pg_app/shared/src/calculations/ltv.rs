@@ -0,0 +1,44 @@
+//! Loan-to-value (LTV) calculation
+
+/// Property value used as the LTV denominator
+///
+/// Uses the lower of the purchase price and the appraised value. When only
+/// one of them is known that one is used, and when neither is known the
+/// estimated value stands in (e.g. a refinance before the appraisal).
+///
+/// # Example
+/// ```
+/// use shared::calculations::ltv::valuation_basis;
+///
+/// assert_eq!(valuation_basis(Some(400_000_00), Some(390_000_00), None), Some(390_000_00));
+/// assert_eq!(valuation_basis(Some(400_000_00), None, Some(450_000_00)), Some(400_000_00));
+/// assert_eq!(valuation_basis(None, None, Some(450_000_00)), Some(450_000_00));
+/// ```
+pub fn valuation_basis(
+    purchase_price_cents: Option<i64>,
+    appraised_value_cents: Option<i64>,
+    estimated_value_cents: Option<i64>,
+) -> Option<i64> {
+    match (purchase_price_cents, appraised_value_cents) {
+        (Some(price), Some(appraised)) => Some(price.min(appraised)),
+        (Some(value), None) | (None, Some(value)) => Some(value),
+        (None, None) => estimated_value_cents,
+    }
+}
+
+/// Loan-to-value ratio as a percentage
+///
+/// Returns `None` when the value is zero or negative.
+///
+/// # Example
+/// ```
+/// use shared::calculations::ltv::loan_to_value;
+///
+/// assert_eq!(loan_to_value(320_000_00, 400_000_00), Some(80.0));
+/// ```
+pub fn loan_to_value(loan_amount_cents: i64, value_cents: i64) -> Option<f64> {
+    if value_cents <= 0 {
+        return None;
+    }
+    Some(loan_amount_cents as f64 / value_cents as f64 * 100.0)
+}
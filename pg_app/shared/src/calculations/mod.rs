@@ -0,0 +1,29 @@
+//! Mortgage calculations shared by the server and the UI
+//!
+//! Everything in here is pure: it takes model data in and returns numbers
+//! out, so the same figures show up on screen and in server-side reports.
+
+/// Level-payment amortization
+pub mod amortization;
+/// Annual percentage rate (Regulation Z, Appendix J)
+pub mod apr;
+/// Adjustable-rate payment projections
+pub mod arm;
+/// Regulation Z business days and federal holidays
+pub mod business_days;
+/// Closing costs, cash to close and finance charge
+pub mod closing_costs;
+/// Debt-to-income ratios
+pub mod dti;
+/// Monthly escrow and the initial escrow deposit
+pub mod escrow;
+/// Loan-to-value ratios
+pub mod ltv;
+/// PMI premiums and Homeowners Protection Act dates
+pub mod mortgage_insurance;
+/// Time in each pipeline status, SLA breaches and cycle times
+pub mod status_timeline;
+/// Fee tolerance buckets and cures between disclosures
+pub mod tolerance;
+/// TRID disclosure waiting periods
+pub mod trid;
@@ -0,0 +1,91 @@
+//! Private mortgage insurance premiums and Homeowners Protection Act dates
+
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::amortization::AmortizationRow;
+use crate::models::PmiRate;
+
+/// LTV at which the borrower may request PMI cancellation, as a percentage
+pub const HPA_CANCELLATION_LTV: f64 = 80.0;
+
+/// LTV at which PMI terminates automatically, as a percentage
+pub const HPA_TERMINATION_LTV: f64 = 78.0;
+
+/// Annual PMI rate for a loan from the rate table
+///
+/// Returns `None` when no table entry covers the LTV and credit score. The
+/// first matching entry wins.
+pub fn pmi_annual_rate(rates: &[PmiRate], ltv: f64, credit_score: i32) -> Option<f64> {
+    rates.iter().find(|rate| rate.covers(ltv, credit_score)).map(|rate| rate.annual_rate)
+}
+
+/// Monthly PMI premium in cents for an annual rate on the loan amount
+///
+/// # Example
+/// ```
+/// use shared::calculations::mortgage_insurance::monthly_pmi_cents;
+///
+/// // 0.60% a year on $300,000
+/// assert_eq!(monthly_pmi_cents(300_000_00, 0.60), 150_00);
+/// ```
+pub fn monthly_pmi_cents(loan_amount_cents: i64, annual_rate_percent: f64) -> i64 {
+    (loan_amount_cents as f64 * annual_rate_percent / 100.0 / 12.0).round() as i64
+}
+
+/// Projected PMI cancellation and termination dates
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HpaDates {
+    /// Scheduled payment date when LTV first reaches 80% (borrower may request cancellation)
+    pub cancellation_request: Option<NaiveDate>,
+
+    /// Scheduled payment date when LTV first reaches 78% (automatic termination)
+    pub automatic_termination: Option<NaiveDate>,
+
+    /// First day of the month after the amortization midpoint (final termination)
+    pub final_termination: Option<NaiveDate>,
+}
+
+/// Projects HPA dates from the amortization schedule
+///
+/// LTV is measured against the original value (the lesser of the purchase
+/// price and appraised value). Payment `n` falls `n - 1` months after the
+/// first payment date.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::calculations::amortization::amortization_schedule;
+/// use shared::calculations::mortgage_insurance::hpa_dates;
+///
+/// // $285,000 at 6.5% on a $300,000 home
+/// let schedule = amortization_schedule(285_000_00, 6.5, 360);
+/// let first = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+/// let dates = hpa_dates(&schedule, 300_000_00, first);
+/// assert!(dates.cancellation_request.unwrap() < dates.automatic_termination.unwrap());
+/// assert_eq!(dates.final_termination, NaiveDate::from_ymd_opt(2040, 7, 1));
+/// ```
+pub fn hpa_dates(schedule: &[AmortizationRow], original_value_cents: i64, first_payment_date: NaiveDate) -> HpaDates {
+    let payment_date = |number: u32| first_payment_date.checked_add_months(Months::new(number.saturating_sub(1)));
+    let first_at_ltv = |ltv: f64| {
+        if original_value_cents <= 0 {
+            return None;
+        }
+        let limit = original_value_cents as f64 * ltv / 100.0;
+        schedule
+            .iter()
+            .find(|row| row.balance_cents as f64 <= limit)
+            .and_then(|row| payment_date(row.number))
+    };
+
+    let midpoint = (schedule.len() as u32).div_ceil(2);
+    let final_termination = payment_date(midpoint)
+        .and_then(|date| date.with_day(1))
+        .and_then(|date| date.checked_add_months(Months::new(1)));
+
+    HpaDates {
+        cancellation_request: first_at_ltv(HPA_CANCELLATION_LTV),
+        automatic_termination: first_at_ltv(HPA_TERMINATION_LTV),
+        final_termination: if schedule.is_empty() { None } else { final_termination },
+    }
+}
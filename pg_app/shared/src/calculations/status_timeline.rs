@@ -0,0 +1,351 @@
+//! Time spent in each pipeline status, SLA breaches and cycle time reports
+//!
+//! Time in a status is counted in general business days: the business days
+//! after the loan entered the status up to and including the day it left
+//! (or today, while it is still there). A status with an SLA target is in
+//! breach once that count goes over the target.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::calculations::business_days::{add_business_days, business_days_between, BusinessDayRule};
+use crate::models::{sla_target_for, LoanStatus, SlaTarget, StatusChange};
+
+/// One stretch of time a loan spent in a status
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatusStage {
+    /// Status the loan was in
+    pub status: LoanStatus,
+
+    /// When the loan entered the status
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub entered_at: DateTime<Utc>,
+
+    /// Full name of the user who moved the loan into the status
+    pub entered_by: Option<String>,
+
+    /// When the loan left the status; `None` while it is current
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub left_at: Option<DateTime<Utc>>,
+
+    /// Business days spent in the status; `None` for a final status
+    pub business_days: Option<i64>,
+
+    /// SLA target for the status, in business days
+    pub target_days: Option<i32>,
+
+    /// Last business day the target allows
+    pub due_date: Option<NaiveDate>,
+}
+
+impl StatusStage {
+    /// Whether the loan is still in this status
+    pub fn is_current(&self) -> bool {
+        self.left_at.is_none()
+    }
+
+    /// Whether the loan spent (or has spent so far) longer than the target
+    pub fn is_breached(&self) -> bool {
+        self.days_over() > 0
+    }
+
+    /// Business days beyond the target; zero when within it or untargeted
+    pub fn days_over(&self) -> i64 {
+        match (self.business_days, self.target_days) {
+            (Some(days), Some(target)) => (days - i64::from(target)).max(0),
+            _ => 0,
+        }
+    }
+
+    /// Calendar time in the status, up to `now` while it is current
+    pub fn elapsed(&self, now: DateTime<Utc>) -> TimeDelta {
+        self.left_at.unwrap_or(now) - self.entered_at
+    }
+}
+
+/// Builds a loan's status timeline from its history as of `today`
+///
+/// `changes` are the loan's history entries, in any order. `opened_at` is
+/// when the loan was created and `status` where it is now; they are only
+/// needed for history that does not start with an opening entry.
+///
+/// # Example
+/// ```
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use shared::calculations::status_timeline::loan_timeline;
+/// use shared::models::{LoanStatus, SlaTarget, StatusChange};
+///
+/// let at = |d| Utc.with_ymd_and_hms(2025, 6, d, 15, 0, 0).unwrap();
+/// let change = |id, from, to, d| StatusChange {
+///     id,
+///     loan_id: 1,
+///     from_status: from,
+///     to_status: to,
+///     changed_by: None,
+///     changed_by_name: None,
+///     changed_at: at(d),
+/// };
+/// let history = [
+///     change(1, None, LoanStatus::Application, 2),
+///     change(2, Some(LoanStatus::Application), LoanStatus::Processing, 3),
+///     change(3, Some(LoanStatus::Processing), LoanStatus::Underwriting, 13),
+/// ];
+/// let targets = [SlaTarget { status: LoanStatus::Processing, business_days: 5, updated_by: None, updated_at: at(1) }];
+///
+/// let today = NaiveDate::from_ymd_opt(2025, 6, 16).unwrap();
+/// let timeline = loan_timeline(at(2), LoanStatus::Underwriting, &history, &targets, today);
+/// assert_eq!(timeline.len(), 3);
+/// // Processing from Tuesday, June 3 to Friday, June 13: eight business days
+/// assert_eq!(timeline[1].business_days, Some(8));
+/// assert_eq!(timeline[1].due_date, NaiveDate::from_ymd_opt(2025, 6, 10));
+/// assert!(timeline[1].is_breached());
+/// // Still in underwriting on Monday the 16th
+/// assert!(timeline[2].is_current());
+/// assert_eq!(timeline[2].business_days, Some(1));
+/// ```
+pub fn loan_timeline(
+    opened_at: DateTime<Utc>,
+    status: LoanStatus,
+    changes: &[StatusChange],
+    targets: &[SlaTarget],
+    today: NaiveDate,
+) -> Vec<StatusStage> {
+    let mut changes: Vec<&StatusChange> = changes.iter().collect();
+    changes.sort_by_key(|change| (change.changed_at, change.id));
+
+    let opening = match changes.first() {
+        None => Some(status),
+        Some(first) => first.from_status,
+    };
+
+    let mut stages: Vec<StatusStage> = Vec::with_capacity(changes.len() + 1);
+    if let Some(status) = opening {
+        stages.push(open_stage(status, opened_at, None));
+    }
+    for change in changes {
+        if let Some(last) = stages.last_mut() {
+            last.left_at = Some(change.changed_at);
+        }
+        stages.push(open_stage(change.to_status, change.changed_at, change.changed_by_name.clone()));
+    }
+
+    for stage in &mut stages {
+        measure(stage, targets, today);
+    }
+    stages
+}
+
+/// The stage a loan is in now, having entered `status` at `entered_at`
+///
+/// Used where only the latest history entry is loaded, such as the breach
+/// list on the dashboard.
+pub fn current_stage(status: LoanStatus, entered_at: DateTime<Utc>, targets: &[SlaTarget], today: NaiveDate) -> StatusStage {
+    let mut stage = open_stage(status, entered_at, None);
+    measure(&mut stage, targets, today);
+    stage
+}
+
+fn open_stage(status: LoanStatus, entered_at: DateTime<Utc>, entered_by: Option<String>) -> StatusStage {
+    StatusStage {
+        status,
+        entered_at,
+        entered_by,
+        left_at: None,
+        business_days: None,
+        target_days: None,
+        due_date: None,
+    }
+}
+
+/// Fills in the time spent in a stage and its target
+fn measure(stage: &mut StatusStage, targets: &[SlaTarget], today: NaiveDate) {
+    // Closed and denied files stay put, so there is nothing to time
+    if stage.status.is_final() {
+        return;
+    }
+    let entered = stage.entered_at.date_naive();
+    let end = stage.left_at.map_or(today, |left| left.date_naive());
+    stage.business_days = Some(business_days_between(entered, end, BusinessDayRule::General).max(0));
+
+    if let Some(target) = sla_target_for(targets, stage.status) {
+        stage.target_days = Some(target);
+        stage.due_date = Some(add_business_days(entered, target.max(0) as u32, BusinessDayRule::General));
+    }
+}
+
+// ===== Cycle Time Report =====
+
+/// A loan's timeline with the loan officer its time is credited to
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoanTimeline {
+    /// Loan ID
+    pub loan_id: i32,
+
+    /// Assigned loan officer
+    pub loan_officer_id: Option<i32>,
+
+    /// Assigned loan officer's full name
+    pub loan_officer_name: Option<String>,
+
+    /// Stages from [`loan_timeline`]
+    pub stages: Vec<StatusStage>,
+}
+
+/// Average time loans spent in one status
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StageCycleTime {
+    /// Status measured
+    pub status: LoanStatus,
+
+    /// Stages that ended during the period
+    pub completed: usize,
+
+    /// Average business days those stages lasted
+    pub average_business_days: f64,
+
+    /// How many of them went over the SLA target
+    pub breaches: usize,
+}
+
+/// Cycle times for one loan officer's files
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OfficerCycleTime {
+    /// Loan officer; `None` for unassigned files
+    pub loan_officer_id: Option<i32>,
+
+    /// Loan officer's full name
+    pub loan_officer_name: Option<String>,
+
+    /// Average time per status, in pipeline order
+    pub stages: Vec<StageCycleTime>,
+
+    /// Loans closed during the period
+    pub closed_loans: usize,
+
+    /// Average business days from application to closing for those loans
+    pub average_cycle_days: Option<f64>,
+}
+
+impl OfficerCycleTime {
+    /// Averages for `status`, if any stage of it ended during the period
+    pub fn stage(&self, status: LoanStatus) -> Option<&StageCycleTime> {
+        self.stages.iter().find(|stage| stage.status == status)
+    }
+}
+
+/// Average cycle time per status and per loan officer over a period
+///
+/// A stage counts towards the period it ended in, and a loan's full cycle
+/// (from leaving lead status to closing) towards the period it closed in.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CycleTimeReport {
+    /// First day of the period
+    pub from: NaiveDate,
+
+    /// Last day of the period
+    pub to: NaiveDate,
+
+    /// Average time per status across all loans, in pipeline order
+    pub stages: Vec<StageCycleTime>,
+
+    /// Loans closed during the period
+    pub closed_loans: usize,
+
+    /// Average business days from application to closing
+    pub average_cycle_days: Option<f64>,
+
+    /// The same figures per loan officer, by name with unassigned files last
+    pub officers: Vec<OfficerCycleTime>,
+}
+
+impl CycleTimeReport {
+    /// Builds the report over `from` to `to` inclusive
+    pub fn build(timelines: &[LoanTimeline], from: NaiveDate, to: NaiveDate) -> Self {
+        let all: Vec<&LoanTimeline> = timelines.iter().collect();
+        let cycles = cycle_days(&all, from, to);
+
+        let mut by_officer: BTreeMap<Option<i32>, Vec<&LoanTimeline>> = BTreeMap::new();
+        for timeline in timelines {
+            by_officer.entry(timeline.loan_officer_id).or_default().push(timeline);
+        }
+        let mut officers: Vec<OfficerCycleTime> = by_officer
+            .into_iter()
+            .map(|(loan_officer_id, timelines)| {
+                let cycles = cycle_days(&timelines, from, to);
+                OfficerCycleTime {
+                    loan_officer_id,
+                    loan_officer_name: timelines.iter().find_map(|timeline| timeline.loan_officer_name.clone()),
+                    stages: stage_averages(&timelines, from, to),
+                    closed_loans: cycles.len(),
+                    average_cycle_days: average(&cycles),
+                }
+            })
+            .filter(|officer| !officer.stages.is_empty() || officer.closed_loans > 0)
+            .collect();
+        officers.sort_by(|a, b| {
+            (a.loan_officer_id.is_none(), &a.loan_officer_name).cmp(&(b.loan_officer_id.is_none(), &b.loan_officer_name))
+        });
+
+        Self {
+            from,
+            to,
+            stages: stage_averages(&all, from, to),
+            closed_loans: cycles.len(),
+            average_cycle_days: average(&cycles),
+            officers,
+        }
+    }
+}
+
+fn in_period(at: DateTime<Utc>, from: NaiveDate, to: NaiveDate) -> bool {
+    (from..=to).contains(&at.date_naive())
+}
+
+fn stage_averages(timelines: &[&LoanTimeline], from: NaiveDate, to: NaiveDate) -> Vec<StageCycleTime> {
+    LoanStatus::iter()
+        .filter_map(|status| {
+            let ended: Vec<&StatusStage> = timelines
+                .iter()
+                .flat_map(|timeline| &timeline.stages)
+                .filter(|stage| stage.status == status && stage.left_at.is_some_and(|left| in_period(left, from, to)))
+                .collect();
+            let days: Vec<i64> = ended.iter().filter_map(|stage| stage.business_days).collect();
+            Some(StageCycleTime {
+                status,
+                completed: ended.len(),
+                average_business_days: average(&days)?,
+                breaches: ended.iter().filter(|stage| stage.is_breached()).count(),
+            })
+        })
+        .collect()
+}
+
+/// Business days from leaving lead status to closing, for each loan closed in the period
+fn cycle_days(timelines: &[&LoanTimeline], from: NaiveDate, to: NaiveDate) -> Vec<i64> {
+    timelines
+        .iter()
+        .filter_map(|timeline| {
+            let closed = timeline
+                .stages
+                .iter()
+                .find(|stage| stage.status == LoanStatus::Closed && in_period(stage.entered_at, from, to))?;
+            let started = timeline.stages.iter().find(|stage| stage.status != LoanStatus::Lead)?;
+            Some(business_days_between(
+                started.entered_at.date_naive(),
+                closed.entered_at.date_naive(),
+                BusinessDayRule::General,
+            ))
+        })
+        .collect()
+}
+
+fn average(values: &[i64]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<i64>() as f64 / values.len() as f64)
+    }
+}
@@ -0,0 +1,223 @@
+//! TRID fee tolerances between a Loan Estimate and a later disclosure
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{DisclosureFee, FeePayer, FeeSection};
+
+/// How much a fee may grow from the Loan Estimate (12 CFR 1026.19(e)(3))
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, strum::Display, strum::EnumIter)]
+pub enum ToleranceBucket {
+    /// May not increase at all
+    #[strum(serialize = "Zero Tolerance")]
+    Zero,
+    /// May increase by 10% in aggregate
+    #[strum(serialize = "10% Aggregate")]
+    TenPercent,
+    /// May change by any amount
+    #[strum(serialize = "No Limit")]
+    Unlimited,
+}
+
+/// Tolerance bucket for a fee
+///
+/// Origination charges, services the borrower cannot shop for and transfer
+/// taxes are zero tolerance. Recording fees and shoppable services from the
+/// lender's written list share the 10% bucket. Prepaids, escrow and services
+/// from a provider the borrower found off the list have no limit.
+pub fn tolerance_bucket(section: FeeSection, recording_fee: bool, off_list_provider: bool) -> ToleranceBucket {
+    match section {
+        FeeSection::Origination | FeeSection::CannotShop => ToleranceBucket::Zero,
+        FeeSection::CanShop if off_list_provider => ToleranceBucket::Unlimited,
+        FeeSection::CanShop => ToleranceBucket::TenPercent,
+        FeeSection::TaxesGovernment if recording_fee => ToleranceBucket::TenPercent,
+        FeeSection::TaxesGovernment => ToleranceBucket::Zero,
+        FeeSection::Prepaids | FeeSection::InitialEscrow => ToleranceBucket::Unlimited,
+    }
+}
+
+/// One fee compared across two disclosures
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FeeChange {
+    /// Disclosure section, as last disclosed
+    pub section: FeeSection,
+
+    /// Fee description as last disclosed
+    pub name: String,
+
+    /// Tolerance bucket, taken from the later disclosure when the fee is on it
+    pub bucket: ToleranceBucket,
+
+    /// Borrower-paid amount on the Loan Estimate, in cents; `None` if not disclosed
+    pub disclosed_cents: Option<i64>,
+
+    /// Borrower-paid amount on the later disclosure, in cents; `None` if dropped
+    pub final_cents: Option<i64>,
+
+    /// Cure owed for this fee alone (zero-tolerance fees only), in cents
+    pub cure_cents: i64,
+}
+
+impl FeeChange {
+    /// Increase (or decrease, when negative) from the Loan Estimate, in cents
+    pub fn change_cents(&self) -> i64 {
+        self.final_cents.unwrap_or(0) - self.disclosed_cents.unwrap_or(0)
+    }
+}
+
+/// Fee-by-fee tolerance comparison with the cure owed to the borrower
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToleranceReport {
+    /// Every borrower-paid fee on either disclosure, by section then name
+    pub changes: Vec<FeeChange>,
+
+    /// 10% bucket total on the Loan Estimate, in cents
+    pub ten_percent_disclosed_cents: i64,
+
+    /// 10% bucket total on the later disclosure, in cents
+    pub ten_percent_final_cents: i64,
+
+    /// Cure owed for zero-tolerance increases, in cents
+    pub zero_tolerance_cure_cents: i64,
+
+    /// Cure owed for the 10% bucket exceeding its limit, in cents
+    pub ten_percent_cure_cents: i64,
+
+    /// General lender credits on the Loan Estimate, in cents
+    pub lender_credits_disclosed_cents: i64,
+
+    /// General lender credits on the later disclosure, in cents
+    pub lender_credits_final_cents: i64,
+
+    /// Cure owed for lender credits decreasing, which is zero tolerance, in cents
+    pub lender_credit_cure_cents: i64,
+}
+
+impl ToleranceReport {
+    /// Compares borrower-paid fees on a Loan Estimate with a later disclosure
+    ///
+    /// Fees are matched by the worksheet fee they were copied from, so one
+    /// moved to another section is still the same fee; snapshots without that
+    /// link fall back to section and name (ignoring case and surrounding
+    /// spaces). A fee missing from one side counts as zero there. Seller-,
+    /// lender- and third-party-paid amounts are left out.
+    ///
+    /// # Example
+    /// ```
+    /// use shared::calculations::tolerance::ToleranceReport;
+    /// use shared::models::{DisclosureFee, FeePayer, FeeSection};
+    ///
+    /// let fee = |section, name: &str, amount_cents| DisclosureFee {
+    ///     id: 0,
+    ///     disclosure_id: 0,
+    ///     section,
+    ///     name: name.to_string(),
+    ///     amount_cents,
+    ///     payer: FeePayer::Borrower,
+    ///     paid_to: None,
+    ///     finance_charge: false,
+    ///     off_list_provider: false,
+    ///     recording_fee: name == "Recording Fees",
+    ///     loan_fee_id: None,
+    /// };
+    /// let le = vec![
+    ///     fee(FeeSection::Origination, "Origination Fee", 1_000_00),
+    ///     fee(FeeSection::CanShop, "Title - Settlement Fee", 600_00),
+    ///     fee(FeeSection::TaxesGovernment, "Recording Fees", 200_00),
+    /// ];
+    /// let cd = vec![
+    ///     fee(FeeSection::Origination, "Origination Fee", 1_050_00),
+    ///     fee(FeeSection::CanShop, "Title - Settlement Fee", 750_00),
+    ///     fee(FeeSection::TaxesGovernment, "Recording Fees", 200_00),
+    /// ];
+    ///
+    /// let report = ToleranceReport::compare(&le, &cd);
+    /// assert_eq!(report.zero_tolerance_cure_cents, 50_00);
+    /// // $950 against a limit of $880
+    /// assert_eq!(report.ten_percent_cure_cents, 70_00);
+    /// assert_eq!(report.total_cure_cents(), 120_00);
+    /// ```
+    pub fn compare(disclosed: &[DisclosureFee], final_fees: &[DisclosureFee]) -> Self {
+        let key = |fee: &DisclosureFee| match fee.loan_fee_id {
+            Some(id) => FeeKey::Fee(id),
+            None => FeeKey::Name(fee.section, fee.name.trim().to_lowercase()),
+        };
+        let mut lines: BTreeMap<FeeKey, FeeChange> = BTreeMap::new();
+
+        for (fee, is_final) in disclosed
+            .iter()
+            .map(|fee| (fee, false))
+            .chain(final_fees.iter().map(|fee| (fee, true)))
+            .filter(|(fee, _)| fee.payer == FeePayer::Borrower)
+        {
+            let line = lines.entry(key(fee)).or_insert_with(|| FeeChange {
+                section: fee.section,
+                name: fee.name.trim().to_string(),
+                bucket: tolerance_bucket(fee.section, fee.recording_fee, fee.off_list_provider),
+                disclosed_cents: None,
+                final_cents: None,
+                cure_cents: 0,
+            });
+            let amount = if is_final { &mut line.final_cents } else { &mut line.disclosed_cents };
+            *amount = Some(amount.unwrap_or(0) + fee.amount_cents);
+            if is_final {
+                line.section = fee.section;
+                line.name = fee.name.trim().to_string();
+                line.bucket = tolerance_bucket(fee.section, fee.recording_fee, fee.off_list_provider);
+            }
+        }
+
+        let mut lines: Vec<FeeChange> = lines.into_values().collect();
+        lines.sort_by_cached_key(|line| (line.section, line.name.to_lowercase()));
+
+        let mut report = Self::default();
+        for mut line in lines {
+            match line.bucket {
+                ToleranceBucket::Zero => {
+                    line.cure_cents = line.change_cents().max(0);
+                    report.zero_tolerance_cure_cents += line.cure_cents;
+                }
+                ToleranceBucket::TenPercent => {
+                    report.ten_percent_disclosed_cents += line.disclosed_cents.unwrap_or(0);
+                    report.ten_percent_final_cents += line.final_cents.unwrap_or(0);
+                }
+                ToleranceBucket::Unlimited => {}
+            }
+            report.changes.push(line);
+        }
+        report.ten_percent_cure_cents = (report.ten_percent_final_cents - report.ten_percent_limit_cents()).max(0);
+
+        report
+    }
+
+    /// Most the 10% bucket may total on the later disclosure, in cents
+    pub fn ten_percent_limit_cents(&self) -> i64 {
+        self.ten_percent_disclosed_cents * 110 / 100
+    }
+
+    /// Adds the general lender credits from both disclosures
+    ///
+    /// Lender credits may not decrease from the Loan Estimate; any drop is
+    /// owed back like a zero-tolerance fee increase.
+    pub fn with_lender_credits(mut self, disclosed_cents: i64, final_cents: i64) -> Self {
+        self.lender_credits_disclosed_cents = disclosed_cents;
+        self.lender_credits_final_cents = final_cents;
+        self.lender_credit_cure_cents = (disclosed_cents - final_cents).max(0);
+        self
+    }
+
+    /// Total cure owed to the borrower, in cents
+    pub fn total_cure_cents(&self) -> i64 {
+        self.zero_tolerance_cure_cents + self.ten_percent_cure_cents + self.lender_credit_cure_cents
+    }
+}
+
+/// What makes two disclosed fees the same fee
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum FeeKey {
+    /// Copied from the same worksheet line
+    Fee(i32),
+    /// Snapshot without a worksheet link: same section and name
+    Name(FeeSection, String),
+}
@@ -0,0 +1,145 @@
+//! TRID disclosure waiting periods
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::business_days::{add_business_days, business_days_between, subtract_business_days, BusinessDayRule};
+use crate::models::{DisclosureDelivery, TridDates};
+
+/// Business days after application by which the Loan Estimate must be delivered
+pub const LE_DELIVERY_DAYS: u32 = 3;
+
+/// Business days before consummation by which the Closing Disclosure must be received
+pub const CD_WAITING_DAYS: u32 = 3;
+
+/// Business days after delivery that mailed or emailed disclosures are presumed received
+pub const MAILBOX_RULE_DAYS: u32 = 3;
+
+/// Business days before a deadline at which an open milestone counts as at risk
+pub const AT_RISK_DAYS: i64 = 1;
+
+/// Where a milestone stands against its deadline
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+pub enum MilestoneStatus {
+    /// Done on or before the deadline
+    Met,
+    /// Done late, or still open after the deadline
+    Missed,
+    /// Open and the deadline is within [`AT_RISK_DAYS`] business days
+    #[strum(serialize = "At Risk")]
+    AtRisk,
+    /// Open with time to spare
+    Pending,
+    /// The deadline cannot be worked out yet (e.g. no closing date)
+    #[strum(serialize = "Not Scheduled")]
+    NotScheduled,
+}
+
+impl MilestoneStatus {
+    /// Whether the milestone needs attention on the dashboard
+    pub fn is_at_risk(&self) -> bool {
+        matches!(self, Self::Missed | Self::AtRisk)
+    }
+}
+
+/// One TRID deadline with its actual date
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TridMilestone {
+    /// What has to happen (e.g. `"Loan Estimate delivered"`)
+    pub name: String,
+
+    /// Latest date that satisfies the rule
+    pub due: Option<NaiveDate>,
+
+    /// Date it actually happened
+    pub actual: Option<NaiveDate>,
+
+    /// Standing against the deadline as of the day the timeline was built
+    pub status: MilestoneStatus,
+}
+
+/// Status of a milestone due on `due` and done on `actual`, as of `today`
+fn milestone_status(due: Option<NaiveDate>, actual: Option<NaiveDate>, today: NaiveDate, rule: BusinessDayRule) -> MilestoneStatus {
+    match (due, actual) {
+        (None, _) => MilestoneStatus::NotScheduled,
+        (Some(due), Some(actual)) if actual <= due => MilestoneStatus::Met,
+        (Some(_), Some(_)) => MilestoneStatus::Missed,
+        (Some(due), None) if today > due => MilestoneStatus::Missed,
+        (Some(due), None) if business_days_between(today, due, rule) <= AT_RISK_DAYS => MilestoneStatus::AtRisk,
+        (Some(_), None) => MilestoneStatus::Pending,
+    }
+}
+
+/// Date the borrower received (or is presumed to have received) the Closing Disclosure
+///
+/// A confirmed receipt date wins. Otherwise in-person delivery is received the
+/// same day, and mailed or emailed disclosures three specific business days later.
+pub fn cd_received_date(dates: &TridDates) -> Option<NaiveDate> {
+    dates.cd_received_date.or_else(|| {
+        dates.cd_delivered_date.map(|delivered| match dates.cd_delivery_method {
+            DisclosureDelivery::InPerson => delivered,
+            DisclosureDelivery::Mail | DisclosureDelivery::Electronic => {
+                add_business_days(delivered, MAILBOX_RULE_DAYS, BusinessDayRule::Specific)
+            }
+        })
+    })
+}
+
+/// Earliest consummation date the Closing Disclosure allows
+pub fn earliest_consummation(dates: &TridDates) -> Option<NaiveDate> {
+    cd_received_date(dates).map(|received| add_business_days(received, CD_WAITING_DAYS, BusinessDayRule::Specific))
+}
+
+/// Builds the TRID compliance timeline for a loan as of `today`
+///
+/// The Loan Estimate is due by the third general business day after
+/// application. The Closing Disclosure must be received by the third specific
+/// business day before consummation.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::calculations::trid::{trid_timeline, MilestoneStatus};
+/// use shared::models::{DisclosureDelivery, TridDates};
+///
+/// let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d);
+/// let dates = TridDates {
+///     le_delivered_date: date(6, 4),
+///     cd_delivered_date: date(6, 23),
+///     cd_delivery_method: DisclosureDelivery::Electronic,
+///     consummation_date: date(6, 27),
+///     ..Default::default()
+/// };
+///
+/// // Applied Monday, June 2; LE due Thursday, June 5
+/// let timeline = trid_timeline(date(6, 2), &dates, date(6, 24).unwrap());
+/// assert_eq!(timeline[0].due, date(6, 5));
+/// assert_eq!(timeline[0].status, MilestoneStatus::Met);
+///
+/// // Emailed Monday, presumed received Thursday, June 26 — too late for a Friday close
+/// assert_eq!(timeline[1].due, date(6, 24));
+/// assert_eq!(timeline[1].actual, date(6, 26));
+/// assert_eq!(timeline[1].status, MilestoneStatus::Missed);
+/// ```
+pub fn trid_timeline(application_date: Option<NaiveDate>, dates: &TridDates, today: NaiveDate) -> Vec<TridMilestone> {
+    let le_due = application_date.map(|applied| add_business_days(applied, LE_DELIVERY_DAYS, BusinessDayRule::General));
+    let cd_due = dates
+        .consummation_date
+        .map(|closing| subtract_business_days(closing, CD_WAITING_DAYS, BusinessDayRule::Specific));
+    let cd_received = cd_received_date(dates);
+
+    vec![
+        TridMilestone {
+            name: "Loan Estimate delivered".to_string(),
+            due: le_due,
+            actual: dates.le_delivered_date,
+            status: milestone_status(le_due, dates.le_delivered_date, today, BusinessDayRule::General),
+        },
+        TridMilestone {
+            name: "Closing Disclosure received".to_string(),
+            due: cd_due,
+            actual: cd_received,
+            status: milestone_status(cd_due, cd_received, today, BusinessDayRule::Specific),
+        },
+    ]
+}
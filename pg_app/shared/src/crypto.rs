@@ -0,0 +1,232 @@
+//! Field-level encryption of sensitive PII
+//!
+//! SSNs, dates of birth and account numbers are sealed with AES-256-GCM
+//! before they reach Postgres and stored as
+//! `v{version}:{base64(nonce || ciphertext)}`. The version names the key that
+//! sealed the value, so keys can be rotated: new values are sealed with the
+//! newest key in the [`Keyring`], values sealed with an older key still
+//! open, and the `reencrypt_pii` command re-seals them with the newest one.
+//!
+//! Columns holding sealed values are read and written through [`Sealed`],
+//! which seals when bound and opens when decoded, so rows come back from
+//! `query_as` in plain text on the server only. Values stored before
+//! encryption was added have no version prefix; they are read as they are
+//! until `reencrypt_pii` seals them.
+//!
+//! A sealed value cannot be searched, so columns that need exact-match
+//! lookups keep a blind index alongside: an HMAC-SHA256 of the normalized
+//! value under a separate key (see [`PiiField`]).
+//!
+//! # Configuration
+//! - `PII_KEYS`: comma-separated `version:key` pairs, each key 32 bytes in
+//!   base64, e.g. `1:<key>,2:<key>`. The highest version seals.
+//! - `PII_INDEX_KEY`: 32 bytes in base64, the blind index key. Changing it
+//!   means recomputing every index with `reencrypt_pii`.
+
+use chrono::NaiveDate;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef};
+use sqlx::{Decode, Encode, Postgres, Type, ValueRef};
+
+/// Why a value could not be sealed or opened
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CryptoError {
+    /// `PII_KEYS` or `PII_INDEX_KEY` is missing or invalid
+    #[error("PII encryption is not configured: {0}")]
+    Config(String),
+
+    /// The value was sealed with a key no longer in the keyring
+    #[error("No PII key with version {0}")]
+    UnknownKey(u32),
+
+    /// The stored value is not valid sealed text
+    #[error("Sealed value is malformed")]
+    Malformed,
+
+    /// The ciphertext failed authentication (wrong key or altered data)
+    #[error("Sealed value failed authentication")]
+    Tampered,
+
+    /// Opened text is not a valid value for the column
+    #[error("Opened value is invalid: {0}")]
+    InvalidValue(String),
+
+    /// Sealing and opening only happen on the server
+    #[error("PII can only be sealed or opened on the server")]
+    Unavailable,
+}
+
+/// Field with a blind index, which keeps each field's HMACs apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PiiField {
+    /// Social Security number
+    Ssn,
+    /// Bank or brokerage account number
+    AccountNumber,
+}
+
+impl PiiField {
+    /// Prefix mixed into the HMAC so equal values in different fields differ
+    #[cfg(feature = "backend")]
+    fn domain(&self) -> &'static str {
+        match self {
+            Self::Ssn => "ssn",
+            Self::AccountNumber => "account_number",
+        }
+    }
+
+    /// The value as indexed: SSN digits only, account numbers upper-case
+    /// without spaces or dashes
+    ///
+    /// # Example
+    /// ```
+    /// use shared::crypto::PiiField;
+    ///
+    /// assert_eq!(PiiField::Ssn.normalize(" 123-45-6789 "), "123456789");
+    /// assert_eq!(PiiField::AccountNumber.normalize("00 12-ab"), "0012AB");
+    /// ```
+    pub fn normalize(&self, value: &str) -> String {
+        match self {
+            Self::Ssn => value.chars().filter(char::is_ascii_digit).collect(),
+            Self::AccountNumber => value
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .map(|c| c.to_ascii_uppercase())
+                .collect(),
+        }
+    }
+}
+
+/// Version of the key that sealed `stored`, or `None` for legacy plain text
+///
+/// # Example
+/// ```
+/// use shared::crypto::sealed_version;
+///
+/// assert_eq!(sealed_version("v2:AAAA"), Some(2));
+/// assert_eq!(sealed_version("123456789"), None);
+/// ```
+pub fn sealed_version(stored: &str) -> Option<u32> {
+    let (prefix, _) = stored.split_once(':')?;
+    prefix.strip_prefix('v')?.parse().ok()
+}
+
+/// A value stored in the database as text
+pub trait PiiValue: Sized {
+    /// The value as text before sealing
+    fn to_plain(&self) -> String;
+
+    /// The value from opened text
+    fn from_plain(plain: &str) -> Result<Self, CryptoError>;
+}
+
+impl PiiValue for String {
+    fn to_plain(&self) -> String {
+        self.clone()
+    }
+
+    fn from_plain(plain: &str) -> Result<Self, CryptoError> {
+        Ok(plain.to_string())
+    }
+}
+
+impl PiiValue for NaiveDate {
+    fn to_plain(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+
+    fn from_plain(plain: &str) -> Result<Self, CryptoError> {
+        NaiveDate::parse_from_str(plain, "%Y-%m-%d").map_err(|_| CryptoError::InvalidValue(plain.to_string()))
+    }
+}
+
+/// An optional PII value in a sealed `TEXT` column
+///
+/// Binding one seals it with the current key; decoding one opens it. Model
+/// fields keep their plain type and are read with
+/// `#[sqlx(try_from = "Sealed<T>")]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sealed<T>(pub Option<T>);
+
+impl<T> Sealed<T> {
+    /// Wraps a value to be sealed when bound
+    pub fn new(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Sealed<String>> for Option<String> {
+    fn from(sealed: Sealed<String>) -> Self {
+        sealed.0
+    }
+}
+
+impl From<Sealed<NaiveDate>> for Option<NaiveDate> {
+    fn from(sealed: Sealed<NaiveDate>) -> Self {
+        sealed.0
+    }
+}
+
+impl<T> Type<Postgres> for Sealed<T> {
+    fn type_info() -> PgTypeInfo {
+        <String as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <String as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<T: PiiValue> Encode<'_, Postgres> for Sealed<T> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        match &self.0 {
+            Some(value) => {
+                let stored = seal_value(&value.to_plain())?;
+                <String as Encode<Postgres>>::encode_by_ref(&stored, buf)
+            }
+            None => Ok(IsNull::Yes),
+        }
+    }
+}
+
+impl<'r, T: PiiValue> Decode<'r, Postgres> for Sealed<T> {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        if value.is_null() {
+            return Ok(Self(None));
+        }
+        let stored = <&str as Decode<Postgres>>::decode(value)?;
+        // Legacy plain text needs no key
+        let plain = match sealed_version(stored) {
+            Some(_) => open_value(stored)?,
+            None => stored.to_string(),
+        };
+        Ok(Self(Some(T::from_plain(&plain)?)))
+    }
+}
+
+#[cfg(feature = "backend")]
+fn seal_value(plain: &str) -> Result<String, CryptoError> {
+    keyring()?.seal(plain)
+}
+
+#[cfg(feature = "backend")]
+fn open_value(stored: &str) -> Result<String, CryptoError> {
+    keyring()?.open(stored)
+}
+
+#[cfg(not(feature = "backend"))]
+fn seal_value(_plain: &str) -> Result<String, CryptoError> {
+    Err(CryptoError::Unavailable)
+}
+
+#[cfg(not(feature = "backend"))]
+fn open_value(_stored: &str) -> Result<String, CryptoError> {
+    Err(CryptoError::Unavailable)
+}
+
+#[cfg(feature = "backend")]
+mod keyring;
+
+#[cfg(feature = "backend")]
+pub use keyring::{blind_index, install_keyring, keyring, Keyring, PII_KEY_LEN};
@@ -0,0 +1,167 @@
+//! The PII keys and the AES-GCM and HMAC work done with them
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::{sealed_version, CryptoError, PiiField};
+
+/// Length in bytes of each sealing key and of the blind index key
+pub const PII_KEY_LEN: usize = 32;
+
+/// Bytes of nonce in front of each ciphertext
+const NONCE_LEN: usize = 12;
+
+/// Sealing keys by version, plus the blind index key
+pub struct Keyring {
+    keys: BTreeMap<u32, Aes256Gcm>,
+    index_key: [u8; PII_KEY_LEN],
+}
+
+impl std::fmt::Debug for Keyring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print key material
+        f.debug_struct("Keyring").field("versions", &self.keys.keys().collect::<Vec<_>>()).finish()
+    }
+}
+
+impl Keyring {
+    /// Builds a keyring from raw keys; versions start at 1
+    pub fn new(keys: &[(u32, [u8; PII_KEY_LEN])], index_key: [u8; PII_KEY_LEN]) -> Result<Self, CryptoError> {
+        if keys.is_empty() {
+            return Err(CryptoError::Config("at least one key is required".to_string()));
+        }
+        let mut ring = BTreeMap::new();
+        for (version, key) in keys {
+            if *version == 0 {
+                return Err(CryptoError::Config("key versions start at 1".to_string()));
+            }
+            let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| CryptoError::Config("bad key".to_string()))?;
+            if ring.insert(*version, cipher).is_some() {
+                return Err(CryptoError::Config(format!("key version {} is listed twice", version)));
+            }
+        }
+        Ok(Self { keys: ring, index_key })
+    }
+
+    /// Builds a keyring from the `PII_KEYS` and `PII_INDEX_KEY` formats
+    ///
+    /// # Example
+    /// ```
+    /// use shared::crypto::Keyring;
+    ///
+    /// let key = "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=";
+    /// let keyring = Keyring::from_config(&format!("1:{key}, 2:{key}"), key).unwrap();
+    /// assert_eq!(keyring.current_version(), 2);
+    /// assert!(Keyring::from_config("1:c2hvcnQ=", key).is_err());
+    /// ```
+    pub fn from_config(keys: &str, index_key: &str) -> Result<Self, CryptoError> {
+        let mut parsed = Vec::new();
+        for entry in keys.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (version, key) = entry
+                .split_once(':')
+                .ok_or_else(|| CryptoError::Config("PII_KEYS entries must be version:key".to_string()))?;
+            let version = version
+                .trim()
+                .parse()
+                .map_err(|_| CryptoError::Config(format!("'{}' is not a key version", version.trim())))?;
+            parsed.push((version, decode_key(key, "PII_KEYS")?));
+        }
+        Self::new(&parsed, decode_key(index_key, "PII_INDEX_KEY")?)
+    }
+
+    /// Builds the keyring from the `PII_KEYS` and `PII_INDEX_KEY` environment variables
+    pub fn from_env() -> Result<Self, CryptoError> {
+        let keys = std::env::var("PII_KEYS").map_err(|_| CryptoError::Config("PII_KEYS is not set".to_string()))?;
+        let index_key =
+            std::env::var("PII_INDEX_KEY").map_err(|_| CryptoError::Config("PII_INDEX_KEY is not set".to_string()))?;
+        Self::from_config(&keys, &index_key)
+    }
+
+    /// Version of the key new values are sealed with (the highest)
+    pub fn current_version(&self) -> u32 {
+        self.keys.keys().next_back().copied().unwrap_or_default()
+    }
+
+    /// Seals `plain` with the current key under a fresh random nonce
+    pub fn seal(&self, plain: &str) -> Result<String, CryptoError> {
+        let version = self.current_version();
+        let cipher = &self.keys[&version];
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher.encrypt(&nonce, plain.as_bytes()).map_err(|_| CryptoError::Malformed)?;
+
+        let mut payload = nonce.to_vec();
+        payload.extend_from_slice(&ciphertext);
+        Ok(format!("v{}:{}", version, STANDARD.encode(payload)))
+    }
+
+    /// Opens a sealed value; legacy plain text comes back unchanged
+    pub fn open(&self, stored: &str) -> Result<String, CryptoError> {
+        let Some(version) = sealed_version(stored) else {
+            return Ok(stored.to_string());
+        };
+        let cipher = self.keys.get(&version).ok_or(CryptoError::UnknownKey(version))?;
+        let (_, encoded) = stored.split_once(':').ok_or(CryptoError::Malformed)?;
+        let payload = STANDARD.decode(encoded).map_err(|_| CryptoError::Malformed)?;
+        if payload.len() < NONCE_LEN {
+            return Err(CryptoError::Malformed);
+        }
+        let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+        let plain = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| CryptoError::Tampered)?;
+        String::from_utf8(plain).map_err(|_| CryptoError::Malformed)
+    }
+
+    /// Whether `stored` is legacy plain text or sealed with an older key
+    pub fn needs_reseal(&self, stored: &str) -> bool {
+        sealed_version(stored) != Some(self.current_version())
+    }
+
+    /// `stored` sealed with the current key
+    pub fn reseal(&self, stored: &str) -> Result<String, CryptoError> {
+        self.seal(&self.open(stored)?)
+    }
+
+    /// HMAC-SHA256 of the normalized value, for exact-match lookups
+    pub fn blind_index(&self, field: PiiField, value: &str) -> Vec<u8> {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&self.index_key).expect("HMAC takes keys of any length");
+        mac.update(field.domain().as_bytes());
+        mac.update(b":");
+        mac.update(field.normalize(value).as_bytes());
+        mac.finalize().into_bytes().to_vec()
+    }
+}
+
+fn decode_key(encoded: &str, variable: &str) -> Result<[u8; PII_KEY_LEN], CryptoError> {
+    let bytes = STANDARD
+        .decode(encoded.trim())
+        .map_err(|_| CryptoError::Config(format!("{} holds a key that is not base64", variable)))?;
+    bytes
+        .try_into()
+        .map_err(|_| CryptoError::Config(format!("{} keys must be {} bytes", variable, PII_KEY_LEN)))
+}
+
+static KEYRING: OnceLock<Result<Keyring, CryptoError>> = OnceLock::new();
+
+/// The process keyring, loaded from the environment on first use
+pub fn keyring() -> Result<&'static Keyring, CryptoError> {
+    KEYRING.get_or_init(Keyring::from_env).as_ref().map_err(Clone::clone)
+}
+
+/// Sets the process keyring instead of loading it from the environment;
+/// returns `false` if one is already in use
+pub fn install_keyring(keyring: Keyring) -> bool {
+    KEYRING.set(Ok(keyring)).is_ok()
+}
+
+/// Blind index of `value` under the process keyring
+pub fn blind_index(field: PiiField, value: &str) -> Result<Vec<u8>, CryptoError> {
+    Ok(keyring()?.blind_index(field, value))
+}
@@ -0,0 +1,254 @@
+//! PDF documents generated from a loan
+//!
+//! Each [`DocumentKind`] has a plain-text template under `templates/`. The
+//! template is filled in from the loan's [`MergeFields`], split into layout
+//! [`Block`]s by [`template::parse`] and drawn by [`pdf::render_pdf`], all in
+//! Rust with no external tools. The amortization schedule adds its payment
+//! table after the template text.
+//!
+//! # Merge fields
+//!
+//! | Field                                  | Example                        |
+//! |----------------------------------------|--------------------------------|
+//! | `date`, `expiration_date`              | May 19, 2025 (expiry +90 days) |
+//! | `borrower.first_name`, `.last_name`, `.full_name` | Grace Okafor        |
+//! | `borrower.email`, `.phone`             | Blank becomes "Not provided"   |
+//! | `borrower.monthly_income`              | $9,500.00                      |
+//! | `loan.number`                          | LN-0042, or #42 before one is assigned |
+//! | `loan.amount`, `.payment`, `.total_interest` | $412,500.00              |
+//! | `loan.type`, `.purpose`, `.status`     | Conventional                   |
+//! | `loan.rate`, `.term`, `.ltv`           | 6.875%, 360 months (30 years), 75.00% |
+//! | `loan.application_date`                | May 1, 2025                    |
+//! | `property.address`, `.type`, `.occupancy`, `.value` | "To be determined" without a property |
+//! | `officer.name`, `.email`               | "Unassigned" without a loan officer |
+//! | `denial.date`, `.reasons`              | Reasons numbered, principal first; "Not denied" otherwise |
+//!
+//! The borrower's SSN is never a merge field.
+//!
+//! # Example
+//! ```
+//! use shared::documents::{render_pdf, template, MergeFields};
+//!
+//! let mut fields = MergeFields::new();
+//! fields.set("borrower.full_name", "Grace Okafor").set("loan.amount", "$412,500.00");
+//!
+//! let blocks = template::parse("# Loan Summary\nBorrower :: {{borrower.full_name}}\nLoan amount :: {{loan.amount}}", &fields).unwrap();
+//! let pdf = render_pdf("Loan Summary", &blocks);
+//! assert!(pdf.starts_with(b"%PDF-"));
+//! ```
+
+/// PDF layout and drawing
+pub mod pdf;
+/// Merge-field templates
+pub mod template;
+
+pub use pdf::{render_pdf, Block, Table, TableColumn};
+pub use template::{MergeFields, TemplateError};
+
+use chrono::{Days, NaiveDate};
+
+use crate::calculations::amortization::{amortization_schedule, monthly_payment_cents, total_interest_cents};
+use crate::calculations::ltv::loan_to_value;
+use crate::models::{AdverseAction, Borrower, DocumentKind, Loan, Property, User};
+use crate::money::format_cents;
+
+/// MIME type of every generated document
+pub const CONTENT_TYPE: &str = "application/pdf";
+
+/// Days a pre-approval letter stays valid
+pub const PRE_APPROVAL_DAYS: u64 = 90;
+
+/// Everything a document can draw on
+#[derive(Clone, Debug, PartialEq)]
+pub struct DocumentData {
+    /// Loan the document is for
+    pub loan: Loan,
+    /// Primary borrower
+    pub borrower: Borrower,
+    /// Subject property, once it is entered
+    pub property: Option<Property>,
+    /// Assigned loan officer
+    pub loan_officer: Option<User>,
+    /// The denial, for a denied application
+    pub adverse_action: Option<AdverseAction>,
+}
+
+impl DocumentData {
+    /// Values for every merge field, formatted for print
+    pub fn merge_fields(&self, today: NaiveDate) -> MergeFields {
+        let loan = &self.loan;
+        let borrower = &self.borrower;
+        let mut fields = MergeFields::new();
+
+        let expiration = today.checked_add_days(Days::new(PRE_APPROVAL_DAYS)).unwrap_or(today);
+        fields
+            .set("date", long_date(today))
+            .set("expiration_date", long_date(expiration));
+
+        fields
+            .set("borrower.first_name", borrower.first_name.as_str())
+            .set("borrower.last_name", borrower.last_name.as_str())
+            .set("borrower.full_name", format!("{} {}", borrower.first_name, borrower.last_name))
+            .set("borrower.email", or_not_provided(borrower.email.as_deref()))
+            .set("borrower.phone", or_not_provided(borrower.phone.as_deref()))
+            .set("borrower.monthly_income", format_cents(borrower.monthly_income_cents));
+
+        let terms = |calculate: fn(i64, f64, i32) -> i64| {
+            loan.note_rate
+                .map(|rate| format_cents(calculate(loan.amount_cents, rate, loan.term_months)))
+                .unwrap_or_else(|| TO_BE_DETERMINED.to_string())
+        };
+        fields
+            .set("loan.number", loan_number(loan))
+            .set("loan.amount", format_cents(loan.amount_cents))
+            .set("loan.type", loan.loan_type.to_string())
+            .set("loan.purpose", loan.loan_purpose.to_string())
+            .set("loan.status", loan.status.to_string())
+            .set(
+                "loan.rate",
+                loan.note_rate.map(|rate| format!("{rate:.3}%")).unwrap_or_else(|| TO_BE_DETERMINED.to_string()),
+            )
+            .set("loan.term", term(loan.term_months))
+            .set("loan.payment", terms(monthly_payment_cents))
+            .set("loan.total_interest", terms(total_interest_cents))
+            .set(
+                "loan.application_date",
+                loan.application_date.map(long_date).unwrap_or_else(|| "Not taken".to_string()),
+            );
+
+        let value = self.property.as_ref().and_then(Property::ltv_value_cents);
+        let ltv = value
+            .and_then(|value| loan_to_value(loan.amount_cents, value))
+            .map(|ratio| format!("{:.2}%", ratio));
+        fields
+            .set("loan.ltv", ltv.unwrap_or_else(|| TO_BE_DETERMINED.to_string()))
+            .set("property.value", value.map(format_cents).unwrap_or_else(|| TO_BE_DETERMINED.to_string()));
+        match &self.property {
+            Some(property) => fields
+                .set("property.address", property.address_line())
+                .set("property.type", property.property_type.to_string())
+                .set("property.occupancy", property.occupancy.to_string()),
+            None => fields
+                .set("property.address", TO_BE_DETERMINED)
+                .set("property.type", TO_BE_DETERMINED)
+                .set("property.occupancy", TO_BE_DETERMINED),
+        };
+
+        match &self.loan_officer {
+            Some(officer) => fields
+                .set("officer.name", officer.full_name())
+                .set("officer.email", officer.email.as_str()),
+            None => fields.set("officer.name", "Unassigned").set("officer.email", ""),
+        };
+
+        match &self.adverse_action {
+            Some(denial) => {
+                let reasons: Vec<String> = denial
+                    .statements()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, statement)| format!("{}. {}", i + 1, statement))
+                    .collect();
+                fields
+                    .set("denial.date", long_date(denial.decided_date))
+                    .set("denial.reasons", reasons.join("; "))
+            }
+            None => fields.set("denial.date", NOT_DENIED).set("denial.reasons", NOT_DENIED),
+        };
+
+        fields
+    }
+}
+
+const TO_BE_DETERMINED: &str = "To be determined";
+const NOT_DENIED: &str = "Not denied";
+
+fn or_not_provided(value: Option<&str>) -> String {
+    match value.map(str::trim) {
+        Some(value) if !value.is_empty() => value.to_string(),
+        _ => "Not provided".to_string(),
+    }
+}
+
+fn long_date(date: NaiveDate) -> String {
+    date.format("%B %-d, %Y").to_string()
+}
+
+fn loan_number(loan: &Loan) -> String {
+    loan.loan_number.clone().unwrap_or_else(|| format!("#{}", loan.id))
+}
+
+fn term(months: i32) -> String {
+    if months % 12 == 0 {
+        format!("{} months ({} years)", months, months / 12)
+    } else {
+        format!("{} months", months)
+    }
+}
+
+/// Template text for a document kind
+pub fn template_text(kind: DocumentKind) -> &'static str {
+    match kind {
+        DocumentKind::PreApprovalLetter => include_str!("templates/pre_approval_letter.txt"),
+        DocumentKind::LoanSummary => include_str!("templates/loan_summary.txt"),
+        DocumentKind::AmortizationSchedule => include_str!("templates/amortization_schedule.txt"),
+        DocumentKind::AdverseActionNotice => include_str!("templates/adverse_action_notice.txt"),
+    }
+}
+
+/// Fills in the kind's template and renders it as a PDF
+pub fn render_document(kind: DocumentKind, data: &DocumentData, today: NaiveDate) -> Result<Vec<u8>, TemplateError> {
+    let mut blocks = template::parse(template_text(kind), &data.merge_fields(today))?;
+    if kind == DocumentKind::AmortizationSchedule {
+        blocks.push(Block::Table(schedule_table(&data.loan)));
+    }
+    let title = format!("{} - Loan {}", kind, loan_number(&data.loan));
+    Ok(render_pdf(&title, &blocks))
+}
+
+/// Every scheduled payment of the loan as a table, empty until the note rate is set
+pub fn schedule_table(loan: &Loan) -> Table {
+    let column = |header: &str, width: f32, numeric: bool| TableColumn {
+        header: header.to_string(),
+        width,
+        numeric,
+    };
+    Table {
+        columns: vec![
+            column("Payment #", 0.12, false),
+            column("Payment", 0.2, true),
+            column("Principal", 0.2, true),
+            column("Interest", 0.2, true),
+            column("Balance", 0.28, true),
+        ],
+        rows: loan
+            .note_rate
+            .map(|rate| amortization_schedule(loan.amount_cents, rate, loan.term_months))
+            .unwrap_or_default()
+            .iter()
+            .map(|row| {
+                vec![
+                    row.number.to_string(),
+                    format_cents(row.payment_cents),
+                    format_cents(row.principal_cents),
+                    format_cents(row.interest_cents),
+                    format_cents(row.balance_cents),
+                ]
+            })
+            .collect(),
+    }
+}
+
+/// Download name such as `loan-summary-LN-0042-2025-05-19.pdf`
+///
+/// Characters other than letters, digits and `-` in the loan number become `-`.
+pub fn file_name(kind: DocumentKind, loan: &Loan, today: NaiveDate) -> String {
+    let number: String = loan
+        .loan_number
+        .clone()
+        .unwrap_or_else(|| loan.id.to_string())
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '-' })
+        .collect();
+    format!("{}-{}-{}.pdf", kind.code().replace('_', "-"), number, today.format("%Y-%m-%d"))
+}
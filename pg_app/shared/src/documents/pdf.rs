@@ -0,0 +1,407 @@
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+
+/// US Letter, in points
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 54.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+const TOP: f32 = PAGE_HEIGHT - MARGIN;
+/// Leaves room for the footer under the last line
+const BOTTOM: f32 = MARGIN + 24.0;
+
+const TITLE_SIZE: f32 = 18.0;
+const HEADING_SIZE: f32 = 12.0;
+const BODY_SIZE: f32 = 10.5;
+const TABLE_SIZE: f32 = 9.0;
+const FOOTER_SIZE: f32 = 8.0;
+const LEADING: f32 = 1.4;
+const LABEL_WIDTH: f32 = 190.0;
+
+/// One piece of a document's layout, top to bottom
+#[derive(Clone, Debug, PartialEq)]
+pub enum Block {
+    /// Large bold title
+    Title(String),
+    /// Bold section heading
+    Heading(String),
+    /// Body text, wrapped to the page width
+    Line(String),
+    /// Bold label with its value beside it
+    Field {
+        /// Left column
+        label: String,
+        /// Right column, wrapped
+        value: String,
+    },
+    /// Thin horizontal line
+    Rule,
+    /// Paragraph break
+    Space,
+    /// Table whose header repeats on every page it spans
+    Table(Table),
+}
+
+/// Rows of text under a header
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table {
+    /// Column headers and widths
+    pub columns: Vec<TableColumn>,
+    /// Cell text, one entry per column
+    pub rows: Vec<Vec<String>>,
+}
+
+/// One column of a [`Table`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableColumn {
+    /// Header text
+    pub header: String,
+    /// Share of the page width, from 0 to 1
+    pub width: f32,
+    /// Right-aligned, for amounts
+    pub numeric: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource(self) -> Name<'static> {
+        match self {
+            Self::Regular => Name(b"F1"),
+            Self::Bold => Name(b"F2"),
+        }
+    }
+
+    fn base_font(self) -> Name<'static> {
+        match self {
+            Self::Regular => Name(b"Helvetica"),
+            Self::Bold => Name(b"Helvetica-Bold"),
+        }
+    }
+
+    /// Glyph width in thousandths of the font size
+    fn width(self, c: char) -> u16 {
+        match c {
+            ' '..='~' => match self {
+                Self::Regular => HELVETICA[c as usize - 32],
+                Self::Bold => HELVETICA_BOLD[c as usize - 32],
+            },
+            '\u{2018}' | '\u{2019}' | '\u{b7}' => 278,
+            '\u{201c}' | '\u{201d}' => 500,
+            '\u{2022}' => 350,
+            '\u{2014}' | '\u{2026}' => 1000,
+            _ => 556,
+        }
+    }
+
+    fn text_width(self, text: &str, size: f32) -> f32 {
+        text.chars().map(|c| self.width(c) as f32).sum::<f32>() * size / 1000.0
+    }
+}
+
+/// Advance widths of the standard Helvetica fonts for `' '..='~'`, from the Adobe AFM files
+#[rustfmt::skip]
+const HELVETICA: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+#[rustfmt::skip]
+const HELVETICA_BOLD: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// Text as WinAnsi bytes, the encoding of the standard fonts
+///
+/// Latin-1 maps straight across; typographic quotes and dashes have their
+/// own codes, and anything else becomes `?`.
+fn win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            ' '..='~' | '\u{a0}'..='\u{ff}' => c as u8,
+            '\u{20ac}' => 0x80,
+            '\u{2026}' => 0x85,
+            '\u{2018}' => 0x91,
+            '\u{2019}' => 0x92,
+            '\u{201c}' => 0x93,
+            '\u{201d}' => 0x94,
+            '\u{2022}' => 0x95,
+            '\u{2013}' => 0x96,
+            '\u{2014}' => 0x97,
+            '\u{2122}' => 0x99,
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Breaks text into lines no wider than `max_width`, splitting words only
+/// when a single word is too wide
+fn wrap(text: &str, font: Font, size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", line, word)
+        };
+        if font.text_width(&candidate, size) <= max_width {
+            line = candidate;
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        for c in word.chars() {
+            line.push(c);
+            if line.chars().count() > 1 && font.text_width(&line, size) > max_width {
+                line.pop();
+                lines.push(std::mem::replace(&mut line, c.to_string()));
+            }
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+enum Op {
+    Text {
+        x: f32,
+        y: f32,
+        font: Font,
+        size: f32,
+        text: String,
+    },
+    Rule {
+        y: f32,
+    },
+}
+
+/// Places blocks on pages, tracking the position of the next line
+struct Layout {
+    pages: Vec<Vec<Op>>,
+    y: f32,
+}
+
+impl Layout {
+    fn new() -> Self {
+        Self {
+            pages: vec![Vec::new()],
+            y: TOP,
+        }
+    }
+
+    fn at_top(&self) -> bool {
+        self.y >= TOP
+    }
+
+    /// Starts a new page unless `height` still fits on this one
+    fn room(&mut self, height: f32) {
+        if self.y - height < BOTTOM && !self.at_top() {
+            self.pages.push(Vec::new());
+            self.y = TOP;
+        }
+    }
+
+    fn push(&mut self, op: Op) {
+        if let Some(page) = self.pages.last_mut() {
+            page.push(op);
+        }
+    }
+
+    fn text(&mut self, x: f32, font: Font, size: f32, text: impl Into<String>) {
+        let text = text.into();
+        if !text.is_empty() {
+            self.push(Op::Text {
+                x,
+                y: self.y - size,
+                font,
+                size,
+                text,
+            });
+        }
+    }
+
+    fn gap(&mut self, height: f32) {
+        if !self.at_top() {
+            self.y -= height;
+        }
+    }
+
+    fn lines(&mut self, text: &str, font: Font, size: f32) {
+        for line in wrap(text, font, size, CONTENT_WIDTH) {
+            self.room(size * LEADING);
+            self.text(MARGIN, font, size, line);
+            self.y -= size * LEADING;
+        }
+    }
+
+    fn rule(&mut self) {
+        self.room(12.0);
+        self.y -= 6.0;
+        self.push(Op::Rule { y: self.y });
+        self.y -= 6.0;
+    }
+
+    fn field(&mut self, label: &str, value: &str) {
+        let labels = wrap(label, Font::Bold, BODY_SIZE, LABEL_WIDTH - 10.0);
+        let values = wrap(value, Font::Regular, BODY_SIZE, CONTENT_WIDTH - LABEL_WIDTH);
+        let leading = BODY_SIZE * LEADING;
+        self.room(labels.len().max(values.len()) as f32 * leading);
+        for i in 0..labels.len().max(values.len()) {
+            if let Some(label) = labels.get(i) {
+                self.text(MARGIN, Font::Bold, BODY_SIZE, label.clone());
+            }
+            if let Some(value) = values.get(i) {
+                self.text(MARGIN + LABEL_WIDTH, Font::Regular, BODY_SIZE, value.clone());
+            }
+            self.y -= leading;
+        }
+    }
+
+    fn table_row(&mut self, table: &Table, cells: &[String], font: Font) {
+        let mut x = MARGIN;
+        for (column, cell) in table.columns.iter().zip(cells) {
+            let width = column.width * CONTENT_WIDTH;
+            let left = if column.numeric {
+                x + width - 4.0 - font.text_width(cell, TABLE_SIZE)
+            } else {
+                x
+            };
+            self.text(left, font, TABLE_SIZE, cell.clone());
+            x += width;
+        }
+        self.y -= TABLE_SIZE * LEADING;
+    }
+
+    fn table_header(&mut self, table: &Table) {
+        let headers: Vec<String> = table.columns.iter().map(|column| column.header.clone()).collect();
+        self.table_row(table, &headers, Font::Bold);
+        self.push(Op::Rule { y: self.y + 2.0 });
+        self.y -= 2.0;
+    }
+
+    fn table(&mut self, table: &Table) {
+        let leading = TABLE_SIZE * LEADING;
+        self.room(leading * 3.0);
+        self.table_header(table);
+        for row in &table.rows {
+            if self.y - leading < BOTTOM {
+                self.pages.push(Vec::new());
+                self.y = TOP;
+                self.table_header(table);
+            }
+            self.table_row(table, row, Font::Regular);
+        }
+    }
+}
+
+/// Lays out the blocks on US Letter pages and writes the PDF
+///
+/// Uses the standard Helvetica fonts, which every PDF reader has, so no
+/// font files are embedded. Each page gets a footer with the document title
+/// and `Page n of m`. The output has no timestamps, so the same blocks
+/// always give the same bytes.
+pub fn render_pdf(title: &str, blocks: &[Block]) -> Vec<u8> {
+    let mut layout = Layout::new();
+    for block in blocks {
+        match block {
+            Block::Title(text) => {
+                layout.lines(text, Font::Bold, TITLE_SIZE);
+                layout.gap(4.0);
+            }
+            Block::Heading(text) => {
+                layout.gap(8.0);
+                // Keep the heading with at least one line under it
+                layout.room(HEADING_SIZE * LEADING + BODY_SIZE * LEADING);
+                layout.lines(text, Font::Bold, HEADING_SIZE);
+            }
+            Block::Line(text) => layout.lines(text, Font::Regular, BODY_SIZE),
+            Block::Field { label, value } => layout.field(label, value),
+            Block::Rule => layout.rule(),
+            Block::Space => layout.gap(BODY_SIZE * 0.8),
+            Block::Table(table) => layout.table(table),
+        }
+    }
+
+    let catalog_id = Ref::new(1);
+    let tree_id = Ref::new(2);
+    let regular_id = Ref::new(3);
+    let bold_id = Ref::new(4);
+    let info_id = Ref::new(5);
+    let page_ids: Vec<Ref> = (0..layout.pages.len()).map(|i| Ref::new(6 + 2 * i as i32)).collect();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(tree_id);
+    pdf.pages(tree_id).kids(page_ids.iter().copied()).count(page_ids.len() as i32);
+    for (id, font) in [(regular_id, Font::Regular), (bold_id, Font::Bold)] {
+        pdf.type1_font(id)
+            .base_font(font.base_font())
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+    }
+    pdf.document_info(info_id).title(TextStr(title));
+
+    let page_count = layout.pages.len();
+    for (number, (ops, page_id)) in layout.pages.iter().zip(&page_ids).enumerate() {
+        let mut content = Content::new();
+        for op in ops {
+            match op {
+                Op::Text { x, y, font, size, text } => {
+                    content
+                        .begin_text()
+                        .set_font(font.resource(), *size)
+                        .next_line(*x, *y)
+                        .show(Str(&win_ansi(text)))
+                        .end_text();
+                }
+                Op::Rule { y } => {
+                    content
+                        .set_line_width(0.5)
+                        .move_to(MARGIN, *y)
+                        .line_to(PAGE_WIDTH - MARGIN, *y)
+                        .stroke();
+                }
+            }
+        }
+
+        let footer = format!("Page {} of {}", number + 1, page_count);
+        let footer_y = MARGIN - FOOTER_SIZE;
+        let footer_x = PAGE_WIDTH - MARGIN - Font::Regular.text_width(&footer, FOOTER_SIZE);
+        for (x, text) in [(MARGIN, title), (footer_x, footer.as_str())] {
+            content
+                .begin_text()
+                .set_font(Font::Regular.resource(), FOOTER_SIZE)
+                .next_line(x, footer_y)
+                .show(Str(&win_ansi(text)))
+                .end_text();
+        }
+
+        let content_id = Ref::new(page_id.get() + 1);
+        let mut page = pdf.page(*page_id);
+        page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT))
+            .parent(tree_id)
+            .contents(content_id);
+        page.resources()
+            .fonts()
+            .pair(Font::Regular.resource(), regular_id)
+            .pair(Font::Bold.resource(), bold_id);
+        page.finish();
+        pdf.stream(content_id, &content.finish());
+    }
+
+    pdf.finish()
+}
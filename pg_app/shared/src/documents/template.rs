@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use super::pdf::Block;
+
+/// Named values substituted for `{{name}}` in a template
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeFields {
+    values: BTreeMap<String, String>,
+}
+
+impl MergeFields {
+    /// No fields
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a field, replacing any earlier value
+    pub fn set(&mut self, name: &str, value: impl Into<String>) -> &mut Self {
+        self.values.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// Value of a field, if it is defined
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// Every field name, sorted
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+}
+
+/// A template that cannot be filled in
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    /// `{{name}}` where `name` is not a merge field
+    #[error("line {line}: unknown merge field `{field}`")]
+    UnknownField {
+        /// 1-based template line
+        line: usize,
+        /// Name inside the braces
+        field: String,
+    },
+
+    /// `{{` without a matching `}}` on the same line
+    #[error("line {line}: merge field is not closed with `}}}}`")]
+    Unclosed {
+        /// 1-based template line
+        line: usize,
+    },
+}
+
+/// Replaces every `{{name}}` in one template line
+///
+/// Spaces inside the braces are ignored. `line` is only used for errors.
+///
+/// # Example
+/// ```
+/// use shared::documents::template::{merge_line, MergeFields, TemplateError};
+///
+/// let mut fields = MergeFields::new();
+/// fields.set("borrower.first_name", "Grace");
+///
+/// assert_eq!(merge_line("Dear {{ borrower.first_name }},", 3, &fields).unwrap(), "Dear Grace,");
+/// assert_eq!(
+///     merge_line("Dear {{borrower.nickname}},", 3, &fields),
+///     Err(TemplateError::UnknownField { line: 3, field: "borrower.nickname".to_string() })
+/// );
+/// ```
+pub fn merge_line(text: &str, line: usize, fields: &MergeFields) -> Result<String, TemplateError> {
+    let mut merged = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        merged.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            return Err(TemplateError::Unclosed { line });
+        };
+        let name = after[..end].trim();
+        match fields.get(name) {
+            Some(value) => merged.push_str(value),
+            None => {
+                return Err(TemplateError::UnknownField {
+                    line,
+                    field: name.to_string(),
+                })
+            }
+        }
+        rest = &after[end + 2..];
+    }
+    merged.push_str(rest);
+    Ok(merged)
+}
+
+/// Fills in a template and splits it into layout blocks
+///
+/// The markup is one block per line, decided before the fields are merged
+/// so a value can never change the layout:
+///
+/// | Line                 | Block                                  |
+/// |----------------------|----------------------------------------|
+/// | `# Title`            | [`Block::Title`]                       |
+/// | `## Heading`         | [`Block::Heading`]                     |
+/// | `Label :: value`     | [`Block::Field`], label and value side by side |
+/// | `---`                | [`Block::Rule`]                        |
+/// | blank                | [`Block::Space`]                       |
+/// | anything else        | [`Block::Line`], wrapped to the page   |
+///
+/// # Example
+/// ```
+/// use shared::documents::pdf::Block;
+/// use shared::documents::template::{parse, MergeFields};
+///
+/// let mut fields = MergeFields::new();
+/// fields.set("loan.amount", "$412,500.00");
+///
+/// let blocks = parse("# Summary\n\nLoan amount :: {{loan.amount}}\n", &fields).unwrap();
+/// assert_eq!(
+///     blocks,
+///     vec![
+///         Block::Title("Summary".to_string()),
+///         Block::Space,
+///         Block::Field { label: "Loan amount".to_string(), value: "$412,500.00".to_string() },
+///     ]
+/// );
+/// ```
+pub fn parse(template: &str, fields: &MergeFields) -> Result<Vec<Block>, TemplateError> {
+    let mut blocks = Vec::new();
+    for (index, raw) in template.lines().enumerate() {
+        let line = index + 1;
+        let text = raw.trim_end();
+        let block = if text.is_empty() {
+            Block::Space
+        } else if text == "---" {
+            Block::Rule
+        } else if let Some(heading) = text.strip_prefix("## ") {
+            Block::Heading(merge_line(heading.trim(), line, fields)?)
+        } else if let Some(title) = text.strip_prefix("# ") {
+            Block::Title(merge_line(title.trim(), line, fields)?)
+        } else if let Some((label, value)) = text.split_once(" :: ") {
+            Block::Field {
+                label: merge_line(label.trim(), line, fields)?,
+                value: merge_line(value.trim(), line, fields)?,
+            }
+        } else {
+            Block::Line(merge_line(text, line, fields)?)
+        };
+        blocks.push(block);
+    }
+    Ok(blocks)
+}
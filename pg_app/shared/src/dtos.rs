@@ -3,9 +3,14 @@
 //! This module contains structures used for transferring data between
 //! the client and server, with validation and serialization support.
 
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Serialize, Deserialize};
 use validator::Validate;
 
+use crate::calculations::status_timeline::StatusStage;
+use crate::calculations::trid::TridMilestone;
+use crate::models::{LoanStatus, LoanType, Task};
+
 /// Data Transfer Object for creating new posts
 ///
 /// # Validation Rules
@@ -189,4 +194,351 @@ pub struct UserResponse {
     
     /// Last login date (ISO 8601) if available
     pub last_login: Option<String>,
-}
\ No newline at end of file
+}
+
+/// One row of the borrower table: a loan with its borrower and subject property
+///
+/// `state` comes from the subject property address and is `None` until a
+/// property has been entered for the loan.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct BorrowerTableRow {
+    /// Loan ID
+    pub loan_id: i32,
+
+    /// Borrower ID
+    pub borrower_id: i32,
+
+    /// Combined first and last name of the borrower
+    pub borrower_name: String,
+
+    /// Pipeline status
+    pub status: LoanStatus,
+
+    /// Loan number (if assigned)
+    pub loan_number: Option<String>,
+
+    /// Application date
+    pub application_date: Option<NaiveDate>,
+
+    /// Loan program
+    pub loan_type: LoanType,
+
+    /// Loan amount in cents
+    pub amount_cents: i64,
+
+    /// Note rate as a percentage, if quoted
+    pub note_rate: Option<f64>,
+
+    /// Subject property state
+    pub state: Option<String>,
+}
+
+/// A rate lock that is close to expiring, with enough loan context for the dashboard
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ExpiringLockRow {
+    /// Rate lock ID
+    pub rate_lock_id: i32,
+
+    /// Loan ID
+    pub loan_id: i32,
+
+    /// Loan number (if assigned)
+    pub loan_number: Option<String>,
+
+    /// Combined first and last name of the borrower
+    pub borrower_name: String,
+
+    /// Assigned loan officer
+    pub loan_officer_id: Option<i32>,
+
+    /// Locked rate as a percentage
+    pub rate: f64,
+
+    /// Current expiration date
+    pub expiration_date: NaiveDate,
+}
+
+/// A loan officer license that is close to expiring, for the licensing report
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ExpiringLicenseRow {
+    /// License ID
+    pub license_id: i32,
+
+    /// Licensed loan officer
+    pub user_id: i32,
+
+    /// Combined first and last name of the loan officer
+    pub officer_name: String,
+
+    /// Loan officer's NMLS ID
+    pub nmls_id: Option<String>,
+
+    /// Two-letter state code
+    pub state: String,
+
+    /// License number issued by the state
+    pub license_number: String,
+
+    /// Last day the license is valid
+    pub expires_on: NaiveDate,
+}
+
+/// A denied application whose adverse action notice has not been delivered
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PendingNoticeRow {
+    /// Loan ID
+    pub loan_id: i32,
+
+    /// Loan number (if assigned)
+    pub loan_number: Option<String>,
+
+    /// Combined first and last name of the borrower
+    pub borrower_name: String,
+
+    /// Combined first and last name of the assigned loan officer
+    pub loan_officer_name: Option<String>,
+
+    /// Day the application was denied
+    pub decided_date: NaiveDate,
+
+    /// Whether a notice has been generated
+    pub has_notice: bool,
+}
+
+/// Filters for the pipeline board; `None` fields match everything
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PipelineFilter {
+    /// Only loans assigned to this loan officer
+    pub loan_officer_id: Option<i32>,
+
+    /// Only loans of this program
+    pub loan_type: Option<LoanType>,
+
+    /// Earliest start date (inclusive)
+    pub from_date: Option<NaiveDate>,
+
+    /// Latest start date (inclusive)
+    pub to_date: Option<NaiveDate>,
+}
+
+/// A loan card on the pipeline board
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PipelineCard {
+    /// Loan ID
+    pub loan_id: i32,
+
+    /// Loan number (if assigned)
+    pub loan_number: Option<String>,
+
+    /// Combined first and last name of the borrower
+    pub borrower_name: String,
+
+    /// Pipeline status (the card's column)
+    pub status: LoanStatus,
+
+    /// Loan program
+    pub loan_type: LoanType,
+
+    /// Loan amount in cents
+    pub amount_cents: i64,
+
+    /// Note rate as a percentage, if quoted
+    pub note_rate: Option<f64>,
+
+    /// Assigned loan officer
+    pub loan_officer_id: Option<i32>,
+
+    /// Assigned loan officer's name
+    pub loan_officer_name: Option<String>,
+
+    /// Application date, or the date the loan was created when there is none
+    pub start_date: NaiveDate,
+}
+
+/// A task with its loan, assignee and checklist progress for task tables
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TaskRow {
+    /// The task itself
+    #[sqlx(flatten)]
+    pub task: Task,
+
+    /// Loan number (if assigned)
+    pub loan_number: Option<String>,
+
+    /// Combined first and last name of the borrower
+    pub borrower_name: String,
+
+    /// Assignee's full name
+    pub assignee_name: Option<String>,
+
+    /// Full name of the user who completed the task
+    pub completed_by_name: Option<String>,
+
+    /// Checklist items ticked
+    pub checklist_done: i64,
+
+    /// Checklist items in total
+    pub checklist_total: i64,
+}
+
+/// A TRID deadline that is missed or about to be, with loan context for the dashboard
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TridRiskRow {
+    /// Loan ID
+    pub loan_id: i32,
+
+    /// Loan number (if assigned)
+    pub loan_number: Option<String>,
+
+    /// Combined first and last name of the borrower
+    pub borrower_name: String,
+
+    /// Assigned loan officer
+    pub loan_officer_id: Option<i32>,
+
+    /// The deadline at risk
+    pub milestone: TridMilestone,
+}
+
+/// A loan that has been in its status longer than the SLA target, for the dashboard
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SlaBreachRow {
+    /// Loan ID
+    pub loan_id: i32,
+
+    /// Loan number (if assigned)
+    pub loan_number: Option<String>,
+
+    /// Combined first and last name of the borrower
+    pub borrower_name: String,
+
+    /// Assigned loan officer's full name
+    pub loan_officer_name: Option<String>,
+
+    /// The status the loan is stuck in
+    pub stage: StatusStage,
+}
+
+/// Outcome of importing a MISMO loan file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MismoImportResult {
+    /// Borrower created for the file
+    pub borrower_id: i32,
+
+    /// Loan created for the file
+    pub loan_id: i32,
+
+    /// Paths of document elements that were not imported
+    pub unmapped: Vec<String>,
+}
+
+/// Outcome of importing a Fannie Mae 3.2 file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FnmImportResult {
+    /// Loan created or updated for the file
+    pub loan_id: i32,
+
+    /// Whether the loan number matched an existing loan
+    pub loan_updated: bool,
+
+    /// Borrower for each applicant, the primary applicant first
+    pub borrower_ids: Vec<i32>,
+
+    /// Applicants matched to an existing borrower by SSN and last name
+    pub matched_borrowers: usize,
+
+    /// IDs of records that were skipped
+    pub ignored_records: Vec<String>,
+}
+
+/// Outcome of importing a borrower spreadsheet
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BorrowerImportResult {
+    /// Borrowers created, in file order
+    pub borrower_ids: Vec<i32>,
+
+    /// Lead loans opened for rows with a loan amount
+    pub leads_created: usize,
+
+    /// Rows that were not imported
+    pub skipped: usize,
+
+    /// CSV of the skipped rows and why, if any were skipped
+    pub error_report: Option<String>,
+}
+
+/// A generated file ready to download
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DownloadFile {
+    /// Suggested file name
+    pub file_name: String,
+
+    /// MIME type
+    pub content_type: String,
+
+    /// File contents, base64 encoded
+    pub base64: String,
+
+    /// Data rows in the file, not counting the header (zero for documents)
+    pub rows: usize,
+}
+
+impl DownloadFile {
+    /// `data:` URL for a download link
+    pub fn data_url(&self) -> String {
+        format!("data:{};base64,{}", self.content_type, self.base64)
+    }
+}
+
+/// A year's HMDA LAR and the edits it fails
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LarExport {
+    /// The pipe-delimited file; `rows` is the number of LAR records
+    pub file: DownloadFile,
+
+    /// Syntactical and validity edit failures, empty when the file is ready to submit
+    pub edits: Vec<crate::hmda::edits::EditFailure>,
+}
+
+/// A borrower merge with the names of the records involved, for the merge history
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct BorrowerMergeRow {
+    /// Merge ID
+    pub id: i32,
+
+    /// Record that was kept
+    pub survivor_id: i32,
+
+    /// Combined first and last name of the survivor
+    pub survivor_name: String,
+
+    /// Record merged into the survivor
+    pub merged_id: i32,
+
+    /// Combined first and last name of the merged record
+    pub merged_name: String,
+
+    /// Combined first and last name of the user who merged them
+    pub merged_by_name: Option<String>,
+
+    /// Loans moved to the survivor, as primary or co-applicant
+    pub loan_count: i32,
+
+    /// Timestamp of the merge
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub merged_at: DateTime<Utc>,
+
+    /// Timestamp of when the merge was undone
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub undone_at: Option<DateTime<Utc>>,
+}
+
+/// Liabilities and income of every applicant on a loan, for the back-end DTI
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LoanDebts {
+    /// Totals of the applicants' liabilities
+    pub summary: crate::calculations::dti::DebtSummary,
+
+    /// Combined gross monthly income of the applicants in cents
+    pub monthly_income_cents: i64,
+}
@@ -0,0 +1,323 @@
+//! Fuzzy matching of borrowers entered more than once
+//!
+//! Borrowers are compared in pairs on name, email, phone, date of birth and
+//! the last four digits of the SSN. Each agreement adds to the pair's score
+//! and each disagreement on a field that identifies a person (SSN, date of
+//! birth) takes from it. Pairs scoring [`CANDIDATE_SCORE`] or more are
+//! reported as [`DuplicateCandidate`]s, best match first.
+//!
+//! A name on its own never reaches the threshold: two applicants called
+//! John Smith need a matching email, phone, date of birth or SSN as well.
+//!
+//! Only pairs sharing a blocking key (email, phone, SSN last four, date of
+//! birth or the sound of the last name) are scored, so the report stays
+//! quick across the whole borrower table.
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Borrower;
+
+/// Lowest score reported as a possible duplicate
+pub const CANDIDATE_SCORE: i32 = 50;
+
+/// Score from which a pair is almost certainly the same person
+pub const STRONG_MATCH_SCORE: i32 = 80;
+
+/// One thing two borrower records agree or disagree on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+pub enum MatchSignal {
+    /// Same first and last name, ignoring case and punctuation
+    #[strum(serialize = "Same name")]
+    SameName,
+    /// Names spelled alike, e.g. "Jon Smyth" and "John Smith"
+    #[strum(serialize = "Similar name")]
+    SimilarName,
+    /// Same email address, ignoring case
+    #[strum(serialize = "Same email")]
+    SameEmail,
+    /// Same phone number, ignoring formatting and the country code
+    #[strum(serialize = "Same phone")]
+    SamePhone,
+    /// Same date of birth
+    #[strum(serialize = "Same date of birth")]
+    SameDateOfBirth,
+    /// Both dates of birth on file and different
+    #[strum(serialize = "Different date of birth")]
+    DifferentDateOfBirth,
+    /// Same nine-digit SSN
+    #[strum(serialize = "Same SSN")]
+    SameSsn,
+    /// Same last four SSN digits, the rest different (often a typo)
+    #[strum(serialize = "Same SSN last four")]
+    SameSsnLastFour,
+    /// Both SSNs on file and the last four digits differ
+    #[strum(serialize = "Different SSN")]
+    DifferentSsn,
+}
+
+impl MatchSignal {
+    /// Points this signal adds to (or takes from) a pair's score
+    pub fn weight(&self) -> i32 {
+        match self {
+            Self::SameName => 30,
+            Self::SimilarName => 20,
+            Self::SameEmail => 40,
+            Self::SamePhone => 30,
+            Self::SameDateOfBirth => 25,
+            Self::DifferentDateOfBirth => -30,
+            Self::SameSsn => 50,
+            Self::SameSsnLastFour => 20,
+            Self::DifferentSsn => -40,
+        }
+    }
+
+    /// Whether the signal counts against the pair being one person
+    pub fn is_conflict(&self) -> bool {
+        self.weight() < 0
+    }
+}
+
+/// Two borrower records that probably describe the same person
+///
+/// `first` is the older record (lower id), suggested as the survivor.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateCandidate {
+    /// Older of the two records
+    pub first: Borrower,
+
+    /// Newer of the two records
+    pub second: Borrower,
+
+    /// Sum of the signal weights
+    pub score: i32,
+
+    /// What the records agree and disagree on, strongest first
+    pub signals: Vec<MatchSignal>,
+}
+
+impl DuplicateCandidate {
+    /// Whether the pair is almost certainly the same person
+    pub fn is_strong(&self) -> bool {
+        self.score >= STRONG_MATCH_SCORE
+    }
+}
+
+/// Scores two borrowers against each other
+///
+/// # Example
+/// ```
+/// use shared::duplicates::{compare, MatchSignal};
+/// # use shared::models::Borrower;
+/// # let borrower = |id, first: &str, last: &str, email: &str| Borrower {
+/// #     id, first_name: first.into(), last_name: last.into(), email: Some(email.into()), phone: None,
+/// #     monthly_income_cents: 0, ssn: None, date_of_birth: None, pii_masked: false, created_at: chrono::Utc::now(), updated_at: chrono::Utc::now(),
+/// # };
+///
+/// let (score, signals) = compare(
+///     &borrower(1, "Grace", "Okafor", "grace@example.com"),
+///     &borrower(2, "grace", "O'Kafor", "Grace@Example.com"),
+/// );
+/// assert_eq!(signals, [MatchSignal::SameEmail, MatchSignal::SameName]);
+/// assert_eq!(score, 70);
+/// ```
+pub fn compare(a: &Borrower, b: &Borrower) -> (i32, Vec<MatchSignal>) {
+    let mut signals = Vec::new();
+
+    let (first_a, first_b) = (normalize_name(&a.first_name), normalize_name(&b.first_name));
+    let (last_a, last_b) = (normalize_name(&a.last_name), normalize_name(&b.last_name));
+    if first_a == first_b && last_a == last_b {
+        signals.push(MatchSignal::SameName);
+    } else if jaro_winkler(&first_a, &first_b) >= 0.85 && jaro_winkler(&last_a, &last_b) >= 0.9 {
+        signals.push(MatchSignal::SimilarName);
+    }
+
+    if let (Some(x), Some(y)) = (email_key(a), email_key(b))
+        && x == y
+    {
+        signals.push(MatchSignal::SameEmail);
+    }
+    if let (Some(x), Some(y)) = (phone_key(a), phone_key(b))
+        && x == y
+    {
+        signals.push(MatchSignal::SamePhone);
+    }
+
+    if let (Some(x), Some(y)) = (a.date_of_birth, b.date_of_birth) {
+        signals.push(if x == y { MatchSignal::SameDateOfBirth } else { MatchSignal::DifferentDateOfBirth });
+    }
+
+    if let (Some(x), Some(y)) = (a.ssn.as_deref(), b.ssn.as_deref()) {
+        signals.push(if x == y {
+            MatchSignal::SameSsn
+        } else if last_four(x) == last_four(y) {
+            MatchSignal::SameSsnLastFour
+        } else {
+            MatchSignal::DifferentSsn
+        });
+    }
+
+    signals.sort_by_key(|signal| -signal.weight());
+    (signals.iter().map(MatchSignal::weight).sum(), signals)
+}
+
+/// Possible duplicates among `borrowers`, best match first
+///
+/// Each pair appears once, with the lower id as `first`.
+pub fn find_duplicates(borrowers: &[Borrower]) -> Vec<DuplicateCandidate> {
+    let mut blocks: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, borrower) in borrowers.iter().enumerate() {
+        for key in blocking_keys(borrower) {
+            blocks.entry(key).or_default().push(index);
+        }
+    }
+
+    let mut pairs = BTreeSet::new();
+    for members in blocks.values() {
+        for (n, &i) in members.iter().enumerate() {
+            for &j in &members[n + 1..] {
+                pairs.insert((i.min(j), i.max(j)));
+            }
+        }
+    }
+
+    let mut candidates: Vec<DuplicateCandidate> = pairs
+        .into_iter()
+        .filter_map(|(i, j)| {
+            let (a, b) = (&borrowers[i], &borrowers[j]);
+            let (score, signals) = compare(a, b);
+            let (first, second) = if a.id <= b.id { (a, b) } else { (b, a) };
+            (score >= CANDIDATE_SCORE).then(|| DuplicateCandidate {
+                first: first.clone(),
+                second: second.clone(),
+                score,
+                signals,
+            })
+        })
+        .collect();
+    candidates.sort_by(|x, y| {
+        y.score
+            .cmp(&x.score)
+            .then(x.first.id.cmp(&y.first.id))
+            .then(x.second.id.cmp(&y.second.id))
+    });
+    candidates
+}
+
+/// Keys a borrower is grouped under; only borrowers sharing one are compared
+fn blocking_keys(borrower: &Borrower) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Some(email) = email_key(borrower) {
+        keys.push(format!("email:{}", email));
+    }
+    if let Some(phone) = phone_key(borrower) {
+        keys.push(format!("phone:{}", phone));
+    }
+    if let Some(ssn) = borrower.ssn.as_deref() {
+        keys.push(format!("ssn:{}", last_four(ssn)));
+    }
+    if let Some(born) = borrower.date_of_birth {
+        keys.push(format!("dob:{}", born));
+    }
+    if let Some(sound) = soundex(&borrower.last_name) {
+        keys.push(format!("name:{}", sound));
+    }
+    keys
+}
+
+/// Lowercase letters only, so "O'Kafor" and "okafor" compare equal
+fn normalize_name(name: &str) -> String {
+    name.chars().filter(|c| c.is_alphabetic()).flat_map(char::to_lowercase).collect()
+}
+
+fn email_key(borrower: &Borrower) -> Option<String> {
+    let email = borrower.email.as_deref()?.trim().to_lowercase();
+    (!email.is_empty()).then_some(email)
+}
+
+/// The last ten digits, dropping a leading country code
+fn phone_key(borrower: &Borrower) -> Option<String> {
+    let digits: String = borrower.phone.as_deref()?.chars().filter(char::is_ascii_digit).collect();
+    (digits.len() >= 7).then(|| digits[digits.len().saturating_sub(10)..].to_string())
+}
+
+fn last_four(ssn: &str) -> &str {
+    &ssn[ssn.len().saturating_sub(4)..]
+}
+
+/// American Soundex code of a name, e.g. `R163` for both Robert and Rupert
+fn soundex(name: &str) -> Option<String> {
+    fn digit(c: char) -> char {
+        match c {
+            'B' | 'F' | 'P' | 'V' => '1',
+            'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => '2',
+            'D' | 'T' => '3',
+            'L' => '4',
+            'M' | 'N' => '5',
+            'R' => '6',
+            'H' | 'W' => '-',
+            _ => '0',
+        }
+    }
+
+    let mut letters = name.chars().filter(char::is_ascii_alphabetic).map(|c| c.to_ascii_uppercase());
+    let first = letters.next()?;
+    let mut code = first.to_string();
+    let mut previous = digit(first);
+    for letter in letters {
+        let current = digit(letter);
+        // H and W do not separate letters with the same code
+        if current == '-' {
+            continue;
+        }
+        if current != '0' && current != previous {
+            code.push(current);
+            if code.len() == 4 {
+                break;
+            }
+        }
+        previous = current;
+    }
+    while code.len() < 4 {
+        code.push('0');
+    }
+    Some(code)
+}
+
+/// Jaro-Winkler similarity of two strings, from 0.0 (nothing alike) to 1.0
+fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() || b.is_empty() {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0usize;
+    for (i, ca) in a.iter().enumerate() {
+        let end = (i + window + 1).min(b.len());
+        for j in i.saturating_sub(window)..end {
+            if !b_matched[j] && b[j] == *ca {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let a_order = a.iter().zip(&a_matched).filter(|(_, m)| **m).map(|(c, _)| c);
+    let b_order = b.iter().zip(&b_matched).filter(|(_, m)| **m).map(|(c, _)| c);
+    let transpositions = a_order.zip(b_order).filter(|(x, y)| x != y).count() as f64 / 2.0;
+
+    let m = matches as f64;
+    let jaro = (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions) / m) / 3.0;
+    let prefix = a.iter().zip(&b).take(4).take_while(|(x, y)| x == y).count() as f64;
+    jaro + prefix * 0.1 * (1.0 - jaro)
+}
@@ -0,0 +1,160 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+
+use super::Cell;
+use crate::models::{LoanPurpose, LoanStatus, LoanType, UserRole};
+
+/// How a column's values are stored and formatted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnKind {
+    /// Free text
+    Text,
+    /// Whole number such as an id or a term in months
+    Integer,
+    /// Amount stored in cents
+    Money,
+    /// Percentage such as a note rate, stored as `6.875`
+    Rate,
+    /// Calendar date
+    Date,
+    /// Timestamp, exported in UTC
+    DateTime,
+    /// Yes or no
+    Boolean,
+}
+
+impl ColumnKind {
+    /// Postgres type the column expression is cast to
+    pub fn sql_type(&self) -> &'static str {
+        match self {
+            Self::Text => "TEXT",
+            Self::Integer | Self::Money => "BIGINT",
+            Self::Rate => "DOUBLE PRECISION",
+            Self::Date => "DATE",
+            Self::DateTime => "TIMESTAMPTZ",
+            Self::Boolean => "BOOLEAN",
+        }
+    }
+}
+
+/// A column that can be exported from a list
+#[derive(Debug, Clone, Copy)]
+pub struct ExportColumn {
+    /// Stable code used in requests
+    pub code: &'static str,
+
+    /// Header in the file
+    pub label: &'static str,
+
+    /// Type of the values
+    pub kind: ColumnKind,
+
+    /// SQL expression over the list's [`super::ExportList::source`]
+    pub sql: &'static str,
+
+    /// Turns a stored enum code such as `clear_to_close` into its display
+    /// name; `None` for columns exported as stored
+    pub display: Option<fn(&str) -> String>,
+}
+
+impl ExportColumn {
+    const fn new(code: &'static str, label: &'static str, kind: ColumnKind, sql: &'static str) -> Self {
+        Self {
+            code,
+            label,
+            kind,
+            sql,
+            display: None,
+        }
+    }
+
+    const fn code_of<T: FromStr + Display>(code: &'static str, label: &'static str, sql: &'static str) -> Self {
+        Self {
+            code,
+            label,
+            kind: ColumnKind::Text,
+            sql,
+            display: Some(display_code::<T>),
+        }
+    }
+
+    /// Reads this column's value from a row of [`super::ExportRequest::query`]
+    pub fn cell(&self, row: &PgRow, index: usize) -> Result<Cell, sqlx::Error> {
+        let cell = match self.kind {
+            ColumnKind::Text => row.try_get::<Option<String>, _>(index)?.map(|text| match self.display {
+                Some(display) => Cell::Text(display(&text)),
+                None => Cell::Text(text),
+            }),
+            ColumnKind::Integer => row.try_get::<Option<i64>, _>(index)?.map(Cell::Integer),
+            ColumnKind::Money => row.try_get::<Option<i64>, _>(index)?.map(Cell::Money),
+            ColumnKind::Rate => row.try_get::<Option<f64>, _>(index)?.map(Cell::Rate),
+            ColumnKind::Date => row.try_get::<Option<NaiveDate>, _>(index)?.map(Cell::Date),
+            ColumnKind::DateTime => row.try_get::<Option<DateTime<Utc>>, _>(index)?.map(Cell::DateTime),
+            ColumnKind::Boolean => row.try_get::<Option<bool>, _>(index)?.map(Cell::Boolean),
+        };
+        Ok(cell.unwrap_or(Cell::Empty))
+    }
+}
+
+fn display_code<T: FromStr + Display>(code: &str) -> String {
+    code.parse::<T>().map(|value| value.to_string()).unwrap_or_else(|_| code.to_string())
+}
+
+pub(crate) static USER_COLUMNS: &[ExportColumn] = &[
+    ExportColumn::new("id", "ID", ColumnKind::Integer, "u.id"),
+    ExportColumn::new("username", "Username", ColumnKind::Text, "u.username"),
+    ExportColumn::new("first_name", "First Name", ColumnKind::Text, "u.first_name"),
+    ExportColumn::new("last_name", "Last Name", ColumnKind::Text, "u.last_name"),
+    ExportColumn::new("email", "Email", ColumnKind::Text, "u.email"),
+    ExportColumn::code_of::<UserRole>("role", "Role", "u.role"),
+    ExportColumn::new("is_active", "Active", ColumnKind::Boolean, "u.is_active"),
+    ExportColumn::new("created_at", "Created", ColumnKind::DateTime, "u.created_at"),
+    ExportColumn::new("last_login", "Last Login", ColumnKind::DateTime, "u.last_login"),
+];
+
+pub(crate) static POST_COLUMNS: &[ExportColumn] = &[
+    ExportColumn::new("id", "ID", ColumnKind::Integer, "p.id"),
+    ExportColumn::new("title", "Title", ColumnKind::Text, "p.title"),
+    ExportColumn::new("body", "Body", ColumnKind::Text, "p.body"),
+    ExportColumn::new("created_at", "Created", ColumnKind::DateTime, "p.created_at"),
+    ExportColumn::new("updated_at", "Updated", ColumnKind::DateTime, "p.updated_at"),
+];
+
+pub(crate) static BORROWER_COLUMNS: &[ExportColumn] = &[
+    ExportColumn::new("id", "ID", ColumnKind::Integer, "b.id"),
+    ExportColumn::new("first_name", "First Name", ColumnKind::Text, "b.first_name"),
+    ExportColumn::new("last_name", "Last Name", ColumnKind::Text, "b.last_name"),
+    ExportColumn::new("email", "Email", ColumnKind::Text, "b.email"),
+    ExportColumn::new("phone", "Phone", ColumnKind::Text, "b.phone"),
+    ExportColumn::new("monthly_income", "Monthly Income", ColumnKind::Money, "b.monthly_income_cents"),
+    ExportColumn::new("created_at", "Created", ColumnKind::DateTime, "b.created_at"),
+    ExportColumn::new("updated_at", "Updated", ColumnKind::DateTime, "b.updated_at"),
+];
+
+pub(crate) static LOAN_COLUMNS: &[ExportColumn] = &[
+    ExportColumn::new("id", "ID", ColumnKind::Integer, "l.id"),
+    ExportColumn::new("loan_number", "Loan #", ColumnKind::Text, "l.loan_number"),
+    ExportColumn::new("borrower", "Borrower", ColumnKind::Text, "b.first_name || ' ' || b.last_name"),
+    ExportColumn::code_of::<LoanStatus>("status", "Status", "l.status"),
+    ExportColumn::code_of::<LoanType>("loan_type", "Type", "l.loan_type"),
+    ExportColumn::code_of::<LoanPurpose>("loan_purpose", "Purpose", "l.loan_purpose"),
+    ExportColumn::new("amount", "Amount", ColumnKind::Money, "l.amount_cents"),
+    ExportColumn::new("note_rate", "Rate", ColumnKind::Rate, "l.note_rate"),
+    ExportColumn::new("term_months", "Term (Months)", ColumnKind::Integer, "l.term_months"),
+    ExportColumn::new("application_date", "Application Date", ColumnKind::Date, "l.application_date"),
+    ExportColumn::new(
+        "start_date",
+        "Start Date",
+        ColumnKind::Date,
+        "COALESCE(l.application_date, l.created_at::DATE)",
+    ),
+    ExportColumn::new("loan_officer", "Loan Officer", ColumnKind::Text, "u.first_name || ' ' || u.last_name"),
+    ExportColumn::new("state", "State", ColumnKind::Text, "p.state"),
+    ExportColumn::new("purchase_price", "Purchase Price", ColumnKind::Money, "p.purchase_price_cents"),
+    ExportColumn::new("created_at", "Created", ColumnKind::DateTime, "l.created_at"),
+    ExportColumn::new("updated_at", "Updated", ColumnKind::DateTime, "l.updated_at"),
+];
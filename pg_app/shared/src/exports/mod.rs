@@ -0,0 +1,328 @@
+//! Spreadsheet exports of the list views
+//!
+//! Each list (users, posts, borrowers and loans) has a fixed set of
+//! [`ExportColumn`]s. An [`ExportRequest`] picks some of them, in order,
+//! together with the filter and sort the list is showing, and
+//! [`ExportRequest::query`] turns that into a single SQL statement. The
+//! server streams the result through an [`ExportWriter`] a row at a time,
+//! so a large export never sits in memory as a list of models.
+//!
+//! Money, rates and dates stay typed. CSV gets plain numbers and ISO dates
+//! that spreadsheets read as such, and XLSX gets numeric and date cells with
+//! a display format.
+//!
+//! # Example
+//! ```
+//! use shared::exports::{Cell, ExportFormat, ExportList, ExportRequest, ExportWriter};
+//!
+//! let request = ExportRequest::new(ExportList::Borrowers, ExportFormat::Csv, ["last_name", "monthly_income"])
+//!     .sorted_by("last_name", false);
+//! assert!(request.query().unwrap().ends_with("ORDER BY b.last_name ASC NULLS LAST, b.id"));
+//!
+//! let mut writer = ExportWriter::new(request.format, &request.columns().unwrap()).unwrap();
+//! writer.write_row(vec![Cell::Text("Ruiz".into()), Cell::Money(6_500_00)]).unwrap();
+//! let csv = String::from_utf8(writer.finish().unwrap()).unwrap();
+//! assert_eq!(csv, "Last Name,Monthly Income\nRuiz,6500.00\n");
+//! ```
+
+/// Column definitions for each list
+pub mod columns;
+/// CSV and XLSX output
+pub mod writer;
+
+pub use columns::{ColumnKind, ExportColumn};
+pub use writer::{Cell, ExportWriter};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::dtos::PipelineFilter;
+use crate::models::Permission;
+
+/// File type of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, strum::Display, strum::EnumIter)]
+pub enum ExportFormat {
+    /// Comma-separated values, UTF-8
+    #[default]
+    #[strum(serialize = "CSV")]
+    Csv,
+    /// Excel workbook with one sheet
+    #[strum(serialize = "Excel (XLSX)")]
+    Xlsx,
+}
+
+impl ExportFormat {
+    /// Short code used in form values
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Xlsx => "xlsx",
+        }
+    }
+
+    /// File name extension, without the dot
+    pub fn extension(&self) -> &'static str {
+        self.code()
+    }
+
+    /// MIME type for the download
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+}
+
+impl std::str::FromStr for ExportFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|format| format.code() == s)
+            .ok_or_else(|| format!("Invalid export format: {}", s))
+    }
+}
+
+/// List view that can be exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum::Display, strum::EnumIter)]
+pub enum ExportList {
+    /// Application users
+    #[strum(serialize = "Users")]
+    Users,
+    /// Blog posts
+    #[strum(serialize = "Posts")]
+    Posts,
+    /// Borrowers, without their SSN
+    #[strum(serialize = "Borrowers")]
+    Borrowers,
+    /// Loans with their borrower, loan officer and subject property
+    #[strum(serialize = "Loans")]
+    Loans,
+}
+
+impl ExportList {
+    /// Short code used in file names
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Users => "users",
+            Self::Posts => "posts",
+            Self::Borrowers => "borrowers",
+            Self::Loans => "loans",
+        }
+    }
+
+    /// Every column that can be exported, in display order
+    pub fn columns(&self) -> &'static [ExportColumn] {
+        match self {
+            Self::Users => columns::USER_COLUMNS,
+            Self::Posts => columns::POST_COLUMNS,
+            Self::Borrowers => columns::BORROWER_COLUMNS,
+            Self::Loans => columns::LOAN_COLUMNS,
+        }
+    }
+
+    /// Looks up a column by its code
+    pub fn column(&self, code: &str) -> Result<&'static ExportColumn, ExportError> {
+        self.columns()
+            .iter()
+            .find(|column| column.code == code)
+            .ok_or_else(|| ExportError::UnknownColumn {
+                list: *self,
+                column: code.to_string(),
+            })
+    }
+
+    /// Permission a user needs to export the list, if any
+    pub fn permission(&self) -> Option<Permission> {
+        match self {
+            Self::Users => Some(Permission::ManageUsers),
+            Self::Posts => None,
+            Self::Borrowers | Self::Loans => Some(Permission::ViewLoans),
+        }
+    }
+
+    /// `FROM` and `WHERE` clauses the column expressions are written against
+    ///
+    /// The loans query takes the [`PipelineFilter`] fields as `$1` to `$4`,
+    /// matching the pipeline board.
+    pub fn source(&self) -> &'static str {
+        match self {
+            Self::Users => "users u",
+            Self::Posts => "posts p",
+            Self::Borrowers => "borrowers b WHERE b.merged_into IS NULL",
+            Self::Loans => {
+                r#"loans l
+        JOIN borrowers b ON b.id = l.borrower_id
+        LEFT JOIN users u ON u.id = l.loan_officer_id
+        LEFT JOIN properties p ON p.loan_id = l.id
+        WHERE ($1::INTEGER IS NULL OR l.loan_officer_id = $1)
+          AND ($2::loan_type IS NULL OR l.loan_type = $2)
+          AND ($3::DATE IS NULL OR COALESCE(l.application_date, l.created_at::DATE) >= $3)
+          AND ($4::DATE IS NULL OR COALESCE(l.application_date, l.created_at::DATE) <= $4)"#
+            }
+        }
+    }
+
+    /// Primary key, which breaks ties in the sort so exports are repeatable
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Users => "u.id",
+            Self::Posts => "p.id",
+            Self::Borrowers => "b.id",
+            Self::Loans => "l.id",
+        }
+    }
+}
+
+/// One sort key of an export
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportSort {
+    /// Column code, which does not have to be one of the exported columns
+    pub column: String,
+
+    /// Largest or latest first
+    pub descending: bool,
+}
+
+impl ExportSort {
+    /// Smallest, earliest or A first
+    pub fn ascending(column: &str) -> Self {
+        Self {
+            column: column.to_string(),
+            descending: false,
+        }
+    }
+
+    /// Largest, latest or Z first
+    pub fn descending(column: &str) -> Self {
+        Self {
+            column: column.to_string(),
+            descending: true,
+        }
+    }
+}
+
+/// What to export and how
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExportRequest {
+    /// List to export
+    pub list: ExportList,
+
+    /// File type
+    pub format: ExportFormat,
+
+    /// Column codes in the order they appear in the file
+    pub columns: Vec<String>,
+
+    /// Sort keys, most significant first
+    pub sort: Vec<ExportSort>,
+
+    /// Filter for [`ExportList::Loans`]; ignored by the other lists
+    pub loan_filter: PipelineFilter,
+}
+
+impl ExportRequest {
+    /// Unsorted, unfiltered export of the given columns
+    pub fn new<S: Into<String>>(list: ExportList, format: ExportFormat, columns: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            list,
+            format,
+            columns: columns.into_iter().map(Into::into).collect(),
+            sort: Vec::new(),
+            loan_filter: PipelineFilter::default(),
+        }
+    }
+
+    /// Adds a sort key after any already given
+    pub fn sorted_by(mut self, column: &str, descending: bool) -> Self {
+        self.sort.push(ExportSort {
+            descending,
+            ..ExportSort::ascending(column)
+        });
+        self
+    }
+
+    /// The chosen columns, checking there is at least one and none repeat
+    pub fn columns(&self) -> Result<Vec<&'static ExportColumn>, ExportError> {
+        if self.columns.is_empty() {
+            return Err(ExportError::NoColumns);
+        }
+        let mut chosen: Vec<&'static ExportColumn> = Vec::with_capacity(self.columns.len());
+        for code in &self.columns {
+            let column = self.list.column(code)?;
+            if chosen.iter().any(|c| c.code == column.code) {
+                return Err(ExportError::DuplicateColumn(column.label.to_string()));
+            }
+            chosen.push(column);
+        }
+        Ok(chosen)
+    }
+
+    /// SQL selecting the chosen columns, filtered and sorted
+    ///
+    /// Only the expressions from the column tables end up in the statement;
+    /// the codes in the request just pick among them. Each column is cast
+    /// to its [`ColumnKind::sql_type`] so [`ExportColumn::cell`] can read it.
+    pub fn query(&self) -> Result<String, ExportError> {
+        let select: Vec<String> = self
+            .columns()?
+            .iter()
+            .map(|column| format!("({})::{}", column.sql, column.kind.sql_type()))
+            .collect();
+
+        let mut order = Vec::with_capacity(self.sort.len() + 1);
+        for key in &self.sort {
+            let column = self.list.column(&key.column)?;
+            let direction = if key.descending { "DESC" } else { "ASC" };
+            order.push(format!("{} {} NULLS LAST", column.sql, direction));
+        }
+        order.push(self.list.key().to_string());
+
+        Ok(format!(
+            "SELECT {} FROM {} ORDER BY {}",
+            select.join(", "),
+            self.list.source(),
+            order.join(", ")
+        ))
+    }
+
+    /// Download name such as `loans-2025-05-19.xlsx`
+    pub fn file_name(&self, date: NaiveDate) -> String {
+        format!("{}-{}.{}", self.list.code(), date.format("%Y-%m-%d"), self.format.extension())
+    }
+}
+
+/// Why an export could not be produced
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ExportError {
+    /// The request has no columns
+    #[error("Choose at least one column to export")]
+    NoColumns,
+
+    /// A column or sort code the list does not have
+    #[error("{list} have no column `{column}`")]
+    UnknownColumn {
+        /// List being exported
+        list: ExportList,
+        /// Code that was asked for
+        column: String,
+    },
+
+    /// The same column chosen twice
+    #[error("{0} is chosen more than once")]
+    DuplicateColumn(String),
+
+    /// A row with a different number of cells than there are columns
+    #[error("expected {expected} cells in the row but got {found}")]
+    RowLength {
+        /// Number of columns
+        expected: usize,
+        /// Number of cells given
+        found: usize,
+    },
+
+    /// The CSV or XLSX library failed
+    #[error("could not write the file: {0}")]
+    Write(String),
+}
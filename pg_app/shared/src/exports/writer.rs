@@ -0,0 +1,201 @@
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
+use rust_xlsxwriter::{ExcelDateTime, Format, Workbook, Worksheet, XlsxError};
+
+use super::{ColumnKind, ExportColumn, ExportError, ExportFormat};
+use crate::money::cents_to_dollars;
+
+/// One value in an export row
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    /// No value (SQL `NULL`)
+    Empty,
+    /// Free text
+    Text(String),
+    /// Whole number
+    Integer(i64),
+    /// Amount in cents
+    Money(i64),
+    /// Percentage, `6.875` for 6.875%
+    Rate(f64),
+    /// Calendar date
+    Date(NaiveDate),
+    /// Timestamp
+    DateTime(DateTime<Utc>),
+    /// Yes or no
+    Boolean(bool),
+}
+
+impl Cell {
+    /// Text for a CSV field
+    ///
+    /// Numbers carry no currency symbol or thousands separators and dates
+    /// are ISO 8601, so spreadsheet programs read them as numbers and dates.
+    /// Rates keep their percent sign, which spreadsheets read as a percentage.
+    ///
+    /// # Example
+    /// ```
+    /// use shared::exports::Cell;
+    ///
+    /// assert_eq!(Cell::Money(-1_234_56).to_csv(), "-1234.56");
+    /// assert_eq!(Cell::Rate(6.875).to_csv(), "6.875%");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        match self {
+            Self::Empty => String::new(),
+            Self::Text(text) => text.clone(),
+            Self::Integer(value) => value.to_string(),
+            Self::Money(cents) => {
+                let sign = if *cents < 0 { "-" } else { "" };
+                format!("{}{}.{:02}", sign, cents.unsigned_abs() / 100, cents.unsigned_abs() % 100)
+            }
+            Self::Rate(rate) => format!("{}%", rate),
+            Self::Date(date) => date.format("%Y-%m-%d").to_string(),
+            Self::DateTime(at) => at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            Self::Boolean(value) => if *value { "TRUE" } else { "FALSE" }.to_string(),
+        }
+    }
+}
+
+/// Writes an export one row at a time
+///
+/// The header row is written on creation. Rows must have one cell per
+/// column, in column order.
+pub struct ExportWriter {
+    output: Output,
+    columns: usize,
+    rows: usize,
+}
+
+enum Output {
+    Csv(Box<csv::Writer<Vec<u8>>>),
+    Xlsx {
+        sheet: Box<Worksheet>,
+        formats: Vec<Format>,
+    },
+}
+
+impl ExportWriter {
+    /// Starts a file with a header row of the column labels
+    pub fn new(format: ExportFormat, columns: &[&ExportColumn]) -> Result<Self, ExportError> {
+        let output = match format {
+            ExportFormat::Csv => {
+                let mut csv = csv::Writer::from_writer(Vec::new());
+                csv.write_record(columns.iter().map(|column| column.label))
+                    .map_err(|e| ExportError::Write(e.to_string()))?;
+                Output::Csv(Box::new(csv))
+            }
+            ExportFormat::Xlsx => {
+                let mut sheet = Box::new(Worksheet::new());
+                let bold = Format::new().set_bold();
+                for (col, column) in columns.iter().enumerate() {
+                    let col = col as u16;
+                    sheet.write_string_with_format(0, col, column.label, &bold).map_err(xlsx_error)?;
+                    sheet.set_column_width(col, column_width(column)).map_err(xlsx_error)?;
+                }
+                sheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+                let formats = columns.iter().map(|column| cell_format(column.kind)).collect();
+                Output::Xlsx { sheet, formats }
+            }
+        };
+        Ok(Self {
+            output,
+            columns: columns.len(),
+            rows: 0,
+        })
+    }
+
+    /// Appends a row
+    pub fn write_row(&mut self, cells: Vec<Cell>) -> Result<(), ExportError> {
+        if cells.len() != self.columns {
+            return Err(ExportError::RowLength {
+                expected: self.columns,
+                found: cells.len(),
+            });
+        }
+        match &mut self.output {
+            Output::Csv(csv) => {
+                csv.write_record(cells.iter().map(Cell::to_csv))
+                    .map_err(|e| ExportError::Write(e.to_string()))?;
+            }
+            Output::Xlsx { sheet, formats } => {
+                let row = self.rows as u32 + 1;
+                for (col, (cell, format)) in cells.iter().zip(formats.iter()).enumerate() {
+                    write_xlsx_cell(sheet, row, col as u16, cell, format)?;
+                }
+            }
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Number of rows written, not counting the header
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Finishes the file and returns its bytes
+    pub fn finish(self) -> Result<Vec<u8>, ExportError> {
+        match self.output {
+            Output::Csv(csv) => csv.into_inner().map_err(|e| ExportError::Write(e.to_string())),
+            Output::Xlsx { sheet, .. } => {
+                let mut workbook = Workbook::new();
+                workbook.push_worksheet(*sheet);
+                workbook.save_to_buffer().map_err(xlsx_error)
+            }
+        }
+    }
+}
+
+fn xlsx_error(e: XlsxError) -> ExportError {
+    ExportError::Write(e.to_string())
+}
+
+fn cell_format(kind: ColumnKind) -> Format {
+    match kind {
+        ColumnKind::Text | ColumnKind::Boolean => Format::new(),
+        ColumnKind::Integer => Format::new().set_num_format("0"),
+        ColumnKind::Money => Format::new().set_num_format("$#,##0.00"),
+        ColumnKind::Rate => Format::new().set_num_format("0.000%"),
+        ColumnKind::Date => Format::new().set_num_format("mm/dd/yyyy"),
+        ColumnKind::DateTime => Format::new().set_num_format("mm/dd/yyyy hh:mm"),
+    }
+}
+
+fn column_width(column: &ExportColumn) -> f64 {
+    let typical = match column.kind {
+        ColumnKind::Text => 20,
+        ColumnKind::Integer | ColumnKind::Boolean => 8,
+        ColumnKind::Money => 14,
+        ColumnKind::Rate | ColumnKind::Date => 11,
+        ColumnKind::DateTime => 17,
+    };
+    typical.max(column.label.len() + 2) as f64
+}
+
+fn write_xlsx_cell(sheet: &mut Worksheet, row: u32, col: u16, cell: &Cell, format: &Format) -> Result<(), ExportError> {
+    match cell {
+        Cell::Empty => return Ok(()),
+        Cell::Text(text) => sheet.write_string(row, col, text),
+        Cell::Integer(value) => sheet.write_number_with_format(row, col, *value as f64, format),
+        Cell::Money(cents) => sheet.write_number_with_format(row, col, cents_to_dollars(*cents), format),
+        Cell::Rate(rate) => sheet.write_number_with_format(row, col, rate / 100.0, format),
+        Cell::Date(date) => {
+            let date = excel_date(*date)?;
+            sheet.write_datetime_with_format(row, col, &date, format)
+        }
+        Cell::DateTime(at) => {
+            let time = at.time();
+            let datetime = excel_date(at.date_naive())?
+                .and_hms(time.hour() as u16, time.minute() as u8, time.second())
+                .map_err(xlsx_error)?;
+            sheet.write_datetime_with_format(row, col, &datetime, format)
+        }
+        Cell::Boolean(value) => sheet.write_boolean(row, col, *value),
+    }
+    .map_err(xlsx_error)?;
+    Ok(())
+}
+
+fn excel_date(date: NaiveDate) -> Result<ExcelDateTime, ExportError> {
+    ExcelDateTime::from_ymd(date.year() as u16, date.month() as u8, date.day() as u8).map_err(xlsx_error)
+}
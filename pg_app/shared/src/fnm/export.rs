@@ -0,0 +1,194 @@
+use super::layout::{
+    applicant, asset, file_header, income, liability, mortgage_terms, other_employer, primary_employer, property,
+    purpose, refinance, transaction,
+};
+use super::record::RecordWriter;
+use super::{
+    amount, code_for, FnmFile, APPLICANT, ASSET_TYPES, BASE_INCOME, CASH_OUT_REFINANCE, CO_APPLICANT, FNM_VERSION, LIABILITY_TYPES,
+    MORTGAGE_OTHER, MORTGAGE_TYPES, OCCUPANCIES, PURPOSE_PURCHASE, PURPOSE_REFINANCE, RATE_TERM_REFINANCE,
+};
+use crate::models::LoanPurpose;
+
+/// Writes a loan as a Fannie Mae 3.2 file with CRLF line endings
+///
+/// Records are grouped by type in specification order, so every `03A`
+/// comes before the first `04A`.
+///
+/// # Example
+/// ```
+/// use shared::fnm::{export::to_fnm, FnmFile};
+/// use shared::models::LoanInput;
+///
+/// let file = FnmFile {
+///     loan_number: Some("LN-1".to_string()),
+///     loan: LoanInput { amount_cents: 200_000_00, note_rate: 6.5, ..Default::default() },
+///     property: None,
+///     applicants: Vec::new(),
+/// };
+/// let fnm = to_fnm(&file);
+/// assert!(fnm.starts_with("0001  3.20 \r\n01A01"));
+/// ```
+pub fn to_fnm(file: &FnmFile) -> String {
+    let mut lines = Vec::new();
+    let loan = &file.loan;
+
+    lines.push(
+        RecordWriter::new(file_header::ID)
+            .set(file_header::FILE_TYPE, "1")
+            .set(file_header::FILE_VERSION, FNM_VERSION)
+            .finish(),
+    );
+
+    let mut terms = RecordWriter::new(mortgage_terms::ID);
+    match code_for(MORTGAGE_TYPES, &loan.loan_type) {
+        Some(code) => terms.set(mortgage_terms::MORTGAGE_APPLIED_FOR, code),
+        None => terms
+            .set(mortgage_terms::MORTGAGE_APPLIED_FOR, MORTGAGE_OTHER)
+            .set(mortgage_terms::MORTGAGE_OTHER, &loan.loan_type.to_string()),
+    };
+    terms
+        .set_opt(mortgage_terms::LENDER_CASE_NUMBER, file.loan_number.as_deref())
+        .set(mortgage_terms::LOAN_AMOUNT, &amount(loan.amount_cents))
+        .set(mortgage_terms::INTEREST_RATE, &format!("{:.3}", loan.note_rate))
+        .set(mortgage_terms::TERM_MONTHS, &loan.term_months.to_string());
+    lines.push(terms.finish());
+
+    if let Some(subject) = &file.property {
+        lines.push(
+            RecordWriter::new(property::ID)
+                .set(property::STREET, &subject.street)
+                .set(property::CITY, &subject.city)
+                .set(property::STATE, &subject.state)
+                .set(property::ZIP, &subject.zip)
+                .set(property::UNITS, &subject.units.to_string())
+                .finish(),
+        );
+    }
+
+    let mut loan_purpose = RecordWriter::new(purpose::ID);
+    loan_purpose.set(
+        purpose::PURPOSE,
+        if loan.loan_purpose == LoanPurpose::Purchase { PURPOSE_PURCHASE } else { PURPOSE_REFINANCE },
+    );
+    if let Some(subject) = &file.property {
+        loan_purpose.set_opt(purpose::PROPERTY_WILL_BE, code_for(OCCUPANCIES, &subject.occupancy));
+    }
+    lines.push(loan_purpose.finish());
+
+    if loan.loan_purpose != LoanPurpose::Purchase {
+        let code = if loan.loan_purpose == LoanPurpose::CashOutRefinance { CASH_OUT_REFINANCE[0] } else { RATE_TERM_REFINANCE };
+        lines.push(
+            RecordWriter::new(refinance::ID)
+                .set(refinance::PURPOSE_OF_REFINANCE, code)
+                .finish(),
+        );
+    }
+
+    for (i, person) in file.applicants.iter().enumerate() {
+        let borrower = &person.borrower;
+        lines.push(
+            RecordWriter::new(applicant::ID)
+                .set(applicant::INDICATOR, if i == 0 { APPLICANT } else { CO_APPLICANT })
+                .set(applicant::SSN, &person.ssn)
+                .set(applicant::FIRST_NAME, &borrower.first_name)
+                .set(applicant::LAST_NAME, &borrower.last_name)
+                .set_opt(applicant::HOME_PHONE, borrower.phone.as_deref())
+                .set_opt(applicant::EMAIL, borrower.email.as_deref())
+                .finish(),
+        );
+    }
+
+    for person in &file.applicants {
+        for job in person.employments.iter().filter(|job| job.is_primary) {
+            let mut record = RecordWriter::new(primary_employer::ID);
+            record
+                .set(primary_employer::SSN, &person.ssn)
+                .set(primary_employer::EMPLOYER_NAME, &job.employer_name)
+                .flag(primary_employer::SELF_EMPLOYED, job.self_employed);
+            if let Some(months) = job.months_on_job {
+                record
+                    .set(primary_employer::YEARS_ON_JOB, &(months / 12).to_string())
+                    .set(primary_employer::MONTHS_ON_JOB, &(months % 12).to_string());
+            }
+            lines.push(
+                record
+                    .set_opt(
+                        primary_employer::YEARS_IN_PROFESSION,
+                        job.years_in_profession.map(|years| years.to_string()).as_deref(),
+                    )
+                    .set_opt(primary_employer::POSITION, job.position.as_deref())
+                    .finish(),
+            );
+        }
+    }
+
+    for person in &file.applicants {
+        for job in person.employments.iter().filter(|job| !job.is_primary) {
+            lines.push(
+                RecordWriter::new(other_employer::ID)
+                    .set(other_employer::SSN, &person.ssn)
+                    .set(other_employer::EMPLOYER_NAME, &job.employer_name)
+                    .flag(other_employer::SELF_EMPLOYED, job.self_employed)
+                    .flag(other_employer::CURRENT, job.is_current)
+                    .date(other_employer::FROM_DATE, job.start_date)
+                    .date(other_employer::TO_DATE, job.end_date)
+                    .set(other_employer::MONTHLY_INCOME, &amount(job.monthly_income_cents))
+                    .set_opt(other_employer::POSITION, job.position.as_deref())
+                    .finish(),
+            );
+        }
+    }
+
+    for person in file.applicants.iter().filter(|person| person.borrower.monthly_income_cents > 0) {
+        lines.push(
+            RecordWriter::new(income::ID)
+                .set(income::SSN, &person.ssn)
+                .set(income::INCOME_TYPE, BASE_INCOME)
+                .set(income::AMOUNT, &amount(person.borrower.monthly_income_cents))
+                .finish(),
+        );
+    }
+
+    for person in &file.applicants {
+        for holding in &person.assets {
+            lines.push(
+                RecordWriter::new(asset::ID)
+                    .set(asset::SSN, &person.ssn)
+                    .set_opt(asset::ASSET_TYPE, code_for(ASSET_TYPES, &holding.asset_type))
+                    .set(asset::INSTITUTION, &holding.institution_name)
+                    .set_opt(asset::ACCOUNT_NUMBER, holding.account_number.as_deref().or(holding.account_last4.as_deref()))
+                    .set(asset::VALUE, &amount(holding.balance_cents))
+                    .finish(),
+            );
+        }
+    }
+
+    for person in &file.applicants {
+        for debt in &person.liabilities {
+            lines.push(
+                RecordWriter::new(liability::ID)
+                    .set(liability::SSN, &person.ssn)
+                    .set_opt(liability::LIABILITY_TYPE, code_for(LIABILITY_TYPES, &debt.liability_type))
+                    .set(liability::CREDITOR, &debt.creditor_name)
+                    .set_opt(liability::ACCOUNT_NUMBER, debt.account_last4.as_deref())
+                    .set(liability::MONTHLY_PAYMENT, &amount(debt.monthly_payment_cents))
+                    .set(liability::UNPAID_BALANCE, &amount(debt.balance_cents))
+                    .flag(liability::PAID_PRIOR_TO_CLOSING, debt.paid_off_at_closing)
+                    .flag(liability::OMITTED, debt.excluded)
+                    .finish(),
+            );
+        }
+    }
+
+    if let Some(price) = file.property.as_ref().and_then(|subject| subject.purchase_price_cents) {
+        lines.push(
+            RecordWriter::new(transaction::ID)
+                .set(transaction::PURCHASE_PRICE, &amount(price))
+                .finish(),
+        );
+    }
+
+    let mut out = lines.join("\r\n");
+    out.push_str("\r\n");
+    out
+}
@@ -0,0 +1,354 @@
+use std::collections::BTreeSet;
+
+use super::layout::{
+    applicant, asset, file_header, income, liability, mortgage_terms, other_employer, primary_employer, property,
+    purpose, refinance, transaction,
+};
+use super::record::{Field, RecordReader};
+use super::{
+    account_last4, FnmApplicant, FnmError, FnmFile, FnmImport, APPLICANT, ASSET_TYPES,
+    CASH_OUT_REFINANCE, CO_APPLICANT, LIABILITY_TYPES, MORTGAGE_OTHER, MORTGAGE_TYPES, OCCUPANCIES,
+    PURPOSE_PURCHASE, PURPOSE_REFINANCE,
+};
+use crate::models::{
+    AssetInput, BorrowerInput, EmploymentInput, LiabilityInput, LoanInput, LoanPurpose, LoanType, Occupancy,
+    PropertyInput, PropertyType,
+};
+
+/// Parses a Fannie Mae 3.2 file
+///
+/// Every record is checked and all problems are returned together, each
+/// with its line, record ID and the column of the bad field.
+///
+/// # Errors
+/// Returns one [`FnmError`] per bad record, plus line-0 errors when the
+/// `01A` record or the primary applicant is missing.
+///
+/// # Example
+/// ```
+/// use shared::fnm::import::parse_fnm;
+///
+/// let errors = parse_fnm("01A01\r\n").unwrap_err();
+/// assert_eq!(
+///     errors[0].to_string(),
+///     "line 1, record 01A, position 131: Loan Amount: is required"
+/// );
+/// ```
+pub fn parse_fnm(contents: &str) -> Result<FnmImport, Vec<FnmError>> {
+    let records: Vec<RecordReader> = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| RecordReader::new(i + 1, line))
+        .collect();
+
+    let mut errors = Vec::new();
+    let mut ignored = BTreeSet::new();
+
+    let mut loan = None;
+    let mut loan_number = None;
+    let mut subject = None;
+    let mut occupancy = None;
+    let mut purchase = true;
+    let mut cash_out = false;
+    let mut purchase_price_cents = None;
+    let mut applicants: Vec<FnmApplicant> = Vec::new();
+    let mut has_primary = false;
+
+    // Loan-level records and applicants first, so the SSN-keyed records
+    // below can be attached wherever they appear in the file
+    for record in &records {
+        let result = match record.id.as_str() {
+            file_header::ID => file_version(record),
+            mortgage_terms::ID => mortgage_terms(record).map(|(terms, number)| {
+                loan = Some(terms);
+                loan_number = number;
+            }),
+            property::ID => subject_property(record).map(|p| subject = Some(p)),
+            purpose::ID => loan_purpose(record).map(|(is_purchase, use_code)| {
+                purchase = is_purchase;
+                occupancy = use_code;
+            }),
+            refinance::ID => {
+                cash_out = record
+                    .text(refinance::PURPOSE_OF_REFINANCE)
+                    .is_some_and(|code| CASH_OUT_REFINANCE.contains(&code.as_str()));
+                Ok(())
+            }
+            applicant::ID => applicant(record, &applicants, has_primary).map(|(primary, person)| {
+                if primary {
+                    has_primary = true;
+                    applicants.insert(0, person);
+                } else {
+                    applicants.push(person);
+                }
+            }),
+            transaction::ID => record.amount(transaction::PURCHASE_PRICE).map(|price| purchase_price_cents = price),
+            primary_employer::ID | other_employer::ID | income::ID | asset::ID | liability::ID => Ok(()),
+            "" => Err(FnmError::new(record.line, "", 1, "Record ID is blank")),
+            other => {
+                ignored.insert(other.to_string());
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            errors.push(e);
+        }
+    }
+
+    for record in &records {
+        let result = match record.id.as_str() {
+            primary_employer::ID => primary_job(record).and_then(|(ssn, job)| {
+                find(record, &mut applicants, primary_employer::SSN, &ssn).map(|a| a.employments.push(job))
+            }),
+            other_employer::ID => other_job(record).and_then(|(ssn, job)| {
+                find(record, &mut applicants, other_employer::SSN, &ssn).map(|a| a.employments.push(job))
+            }),
+            income::ID => income_item(record).and_then(|(ssn, cents)| {
+                // Every income type is summed into one monthly figure
+                find(record, &mut applicants, income::SSN, &ssn).map(|a| a.borrower.monthly_income_cents += cents)
+            }),
+            asset::ID => holding(record).and_then(|(ssn, holding)| {
+                find(record, &mut applicants, asset::SSN, &ssn).map(|a| a.assets.push(holding))
+            }),
+            liability::ID => debt(record).and_then(|(ssn, debt)| {
+                find(record, &mut applicants, liability::SSN, &ssn).map(|a| a.liabilities.push(debt))
+            }),
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            errors.push(e);
+        }
+    }
+
+    if loan.is_none() && !errors.iter().any(|e| e.record == mortgage_terms::ID) {
+        errors.push(FnmError::new(0, mortgage_terms::ID, 1, "No 01A (mortgage type and terms) record"));
+    }
+    if applicants.is_empty() && !errors.iter().any(|e| e.record == applicant::ID) {
+        errors.push(FnmError::new(0, applicant::ID, 1, "No 03A (applicant data) record"));
+    }
+    if !errors.is_empty() {
+        errors.sort_by_key(|e| (e.line == 0, e.line));
+        return Err(errors);
+    }
+
+    let mut loan = loan.unwrap_or_default();
+    loan.loan_purpose = match (purchase, cash_out) {
+        (true, _) => LoanPurpose::Purchase,
+        (false, true) => LoanPurpose::CashOutRefinance,
+        (false, false) => LoanPurpose::Refinance,
+    };
+    let property = subject.map(|mut p: PropertyInput| {
+        p.occupancy = occupancy.unwrap_or_default();
+        p.purchase_price_cents = purchase_price_cents;
+        p
+    });
+
+    Ok(FnmImport {
+        file: FnmFile {
+            loan_number,
+            loan,
+            property,
+            applicants,
+        },
+        ignored_records: ignored.into_iter().collect(),
+    })
+}
+
+fn file_version(record: &RecordReader) -> Result<(), FnmError> {
+    match record.text(file_header::FILE_VERSION) {
+        Some(version) if !version.starts_with("3.2") => Err(record.error(
+            file_header::FILE_VERSION,
+            format!("version {} is not supported; expected 3.2", version),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn mortgage_terms(record: &RecordReader) -> Result<(LoanInput, Option<String>), FnmError> {
+    let code = record.required(mortgage_terms::MORTGAGE_APPLIED_FOR)?;
+    let loan_type = if code == MORTGAGE_OTHER {
+        match record.text(mortgage_terms::MORTGAGE_OTHER).as_deref() {
+            Some("Jumbo") => LoanType::Jumbo,
+            other => {
+                return Err(record.error(
+                    mortgage_terms::MORTGAGE_OTHER,
+                    format!("'{}' is not a loan program we offer", other.unwrap_or_default()),
+                ))
+            }
+        }
+    } else {
+        record
+            .code(mortgage_terms::MORTGAGE_APPLIED_FOR, MORTGAGE_TYPES)?
+            .unwrap_or_default()
+    };
+
+    let amount_cents = record
+        .amount(mortgage_terms::LOAN_AMOUNT)?
+        .ok_or_else(|| record.error(mortgage_terms::LOAN_AMOUNT, "is required"))?;
+    let loan = LoanInput {
+        loan_type,
+        amount_cents,
+        note_rate: record.number(mortgage_terms::INTEREST_RATE)?.unwrap_or(0.0),
+        term_months: record.number(mortgage_terms::TERM_MONTHS)?.unwrap_or(360),
+        ..Default::default()
+    };
+    Ok((loan, record.text(mortgage_terms::LENDER_CASE_NUMBER)))
+}
+
+fn subject_property(record: &RecordReader) -> Result<PropertyInput, FnmError> {
+    let units = record.number(property::UNITS)?.unwrap_or(1);
+    Ok(PropertyInput {
+        street: record.required(property::STREET)?,
+        city: record.required(property::CITY)?,
+        state: record.required(property::STATE)?,
+        zip: record.required(property::ZIP)?,
+        occupancy: Default::default(),
+        // The 3.2 records carry no property type; only the unit count tells them apart
+        property_type: if units >= 2 { PropertyType::TwoToFourUnit } else { PropertyType::SingleFamily },
+        units,
+        purchase_price_cents: None,
+        appraised_value_cents: None,
+        estimated_value_cents: None,
+    })
+}
+
+/// Whether the loan is a purchase, and the occupancy code
+fn loan_purpose(record: &RecordReader) -> Result<(bool, Option<Occupancy>), FnmError> {
+    let is_purchase = match record.required(purpose::PURPOSE)?.as_str() {
+        PURPOSE_PURCHASE => true,
+        PURPOSE_REFINANCE => false,
+        other => {
+            return Err(record.error(purpose::PURPOSE, format!("'{}' is not a purchase (16) or refinance (05)", other)))
+        }
+    };
+    Ok((is_purchase, record.code(purpose::PROPERTY_WILL_BE, OCCUPANCIES)?))
+}
+
+fn ssn(record: &RecordReader, field: Field) -> Result<String, FnmError> {
+    let ssn = record.required(field)?;
+    if ssn.len() != 9 || !ssn.chars().all(|c| c.is_ascii_digit()) {
+        return Err(record.error(field, format!("'{}' is not a nine-digit SSN", ssn)));
+    }
+    Ok(ssn)
+}
+
+/// The applicant, and whether they are the primary applicant
+fn applicant(
+    record: &RecordReader,
+    existing: &[FnmApplicant],
+    has_primary: bool,
+) -> Result<(bool, FnmApplicant), FnmError> {
+    let primary = match record.required(applicant::INDICATOR)?.as_str() {
+        APPLICANT => true,
+        CO_APPLICANT => false,
+        other => return Err(record.error(applicant::INDICATOR, format!("'{}' is not BW or QZ", other))),
+    };
+    let ssn = ssn(record, applicant::SSN)?;
+    if existing.iter().any(|a| a.ssn == ssn) {
+        return Err(record.error(applicant::SSN, "appears on more than one 03A record"));
+    }
+    if primary && has_primary {
+        return Err(record.error(applicant::INDICATOR, "only one applicant can be BW"));
+    }
+
+    Ok((
+        primary,
+        FnmApplicant {
+            ssn,
+            borrower: BorrowerInput {
+                first_name: record.required(applicant::FIRST_NAME)?,
+                last_name: record.required(applicant::LAST_NAME)?,
+                email: record.text(applicant::EMAIL),
+                phone: record.text(applicant::HOME_PHONE),
+                monthly_income_cents: 0,
+            },
+            employments: Vec::new(),
+            assets: Vec::new(),
+            liabilities: Vec::new(),
+        },
+    ))
+}
+
+fn find<'a>(
+    record: &RecordReader,
+    applicants: &'a mut [FnmApplicant],
+    field: Field,
+    ssn: &str,
+) -> Result<&'a mut FnmApplicant, FnmError> {
+    applicants
+        .iter_mut()
+        .find(|a| a.ssn == ssn)
+        .ok_or_else(|| record.error(field, format!("no 03A applicant has SSN ending {}", &ssn[5..])))
+}
+
+fn primary_job(record: &RecordReader) -> Result<(String, EmploymentInput), FnmError> {
+    let years: Option<i32> = record.number(primary_employer::YEARS_ON_JOB)?;
+    let months: Option<i32> = record.number(primary_employer::MONTHS_ON_JOB)?;
+    let job = EmploymentInput {
+        employer_name: record.required(primary_employer::EMPLOYER_NAME)?,
+        position: record.text(primary_employer::POSITION),
+        self_employed: record.flag(primary_employer::SELF_EMPLOYED)?,
+        is_primary: true,
+        is_current: true,
+        start_date: None,
+        end_date: None,
+        months_on_job: (years.is_some() || months.is_some())
+            .then(|| years.unwrap_or(0) * 12 + months.unwrap_or(0)),
+        years_in_profession: record.number(primary_employer::YEARS_IN_PROFESSION)?,
+        monthly_income_cents: 0,
+    };
+    Ok((ssn(record, primary_employer::SSN)?, job))
+}
+
+fn other_job(record: &RecordReader) -> Result<(String, EmploymentInput), FnmError> {
+    let job = EmploymentInput {
+        employer_name: record.required(other_employer::EMPLOYER_NAME)?,
+        position: record.text(other_employer::POSITION),
+        self_employed: record.flag(other_employer::SELF_EMPLOYED)?,
+        is_primary: false,
+        is_current: record.flag(other_employer::CURRENT)?,
+        start_date: record.date(other_employer::FROM_DATE)?,
+        end_date: record.date(other_employer::TO_DATE)?,
+        months_on_job: None,
+        years_in_profession: None,
+        monthly_income_cents: record.amount(other_employer::MONTHLY_INCOME)?.unwrap_or(0),
+    };
+    Ok((ssn(record, other_employer::SSN)?, job))
+}
+
+fn income_item(record: &RecordReader) -> Result<(String, i64), FnmError> {
+    let cents = record.amount(income::AMOUNT)?.unwrap_or(0);
+    Ok((ssn(record, income::SSN)?, cents))
+}
+
+fn holding(record: &RecordReader) -> Result<(String, AssetInput), FnmError> {
+    let asset_type = record
+        .code(asset::ASSET_TYPE, ASSET_TYPES)?
+        .ok_or_else(|| record.error(asset::ASSET_TYPE, "is required"))?;
+    let account = record.text(asset::ACCOUNT_NUMBER);
+    let holding = AssetInput {
+        asset_type,
+        institution_name: record.required(asset::INSTITUTION)?,
+        account_last4: account.as_deref().map(account_last4),
+        account_number: account,
+        balance_cents: record.amount(asset::VALUE)?.unwrap_or(0),
+    };
+    Ok((ssn(record, asset::SSN)?, holding))
+}
+
+fn debt(record: &RecordReader) -> Result<(String, LiabilityInput), FnmError> {
+    let liability_type = record
+        .code(liability::LIABILITY_TYPE, LIABILITY_TYPES)?
+        .ok_or_else(|| record.error(liability::LIABILITY_TYPE, "is required"))?;
+    let debt = LiabilityInput {
+        liability_type,
+        creditor_name: record.required(liability::CREDITOR)?,
+        account_last4: record.text(liability::ACCOUNT_NUMBER).map(|account| account_last4(&account)),
+        balance_cents: record.amount(liability::UNPAID_BALANCE)?.unwrap_or(0),
+        monthly_payment_cents: record.amount(liability::MONTHLY_PAYMENT)?.unwrap_or(0),
+        paid_off_at_closing: record.flag(liability::PAID_PRIOR_TO_CLOSING)?,
+        excluded: record.flag(liability::OMITTED)?,
+    };
+    Ok((ssn(record, liability::SSN)?, debt))
+}
@@ -0,0 +1,132 @@
+//! Field positions of the Fannie Mae 3.2 records we read and write
+//!
+//! Positions are 1-based and inclusive of the record ID in columns 1-3,
+//! matching the numbering in the specification.
+
+use super::record::Field;
+
+/// `000` File identification
+pub(crate) mod file_header {
+    use super::Field;
+    pub(crate) const ID: &str = "000";
+    pub(crate) const FILE_TYPE: Field = Field::new(4, 3, "File Type");
+    pub(crate) const FILE_VERSION: Field = Field::new(7, 5, "File Version Number");
+}
+
+/// `01A` Mortgage type and terms
+pub(crate) mod mortgage_terms {
+    use super::Field;
+    pub(crate) const ID: &str = "01A";
+    pub(crate) const MORTGAGE_APPLIED_FOR: Field = Field::new(4, 2, "Mortgage Applied For");
+    pub(crate) const MORTGAGE_OTHER: Field = Field::new(6, 80, "Mortgage Applied For (Other)");
+    pub(crate) const LENDER_CASE_NUMBER: Field = Field::new(116, 15, "Case Number");
+    pub(crate) const LOAN_AMOUNT: Field = Field::new(131, 15, "Loan Amount");
+    pub(crate) const INTEREST_RATE: Field = Field::new(146, 7, "Interest Rate");
+    pub(crate) const TERM_MONTHS: Field = Field::new(153, 3, "No. of Months");
+}
+
+/// `02A` Property information
+pub(crate) mod property {
+    use super::Field;
+    pub(crate) const ID: &str = "02A";
+    pub(crate) const STREET: Field = Field::new(4, 50, "Property Street Address");
+    pub(crate) const CITY: Field = Field::new(54, 35, "Property City");
+    pub(crate) const STATE: Field = Field::new(89, 2, "Property State");
+    pub(crate) const ZIP: Field = Field::new(91, 5, "Property Zip Code");
+    pub(crate) const UNITS: Field = Field::new(100, 3, "No. of Units");
+}
+
+/// `02B` Purpose of loan
+pub(crate) mod purpose {
+    use super::Field;
+    pub(crate) const ID: &str = "02B";
+    pub(crate) const PURPOSE: Field = Field::new(6, 2, "Purpose of Loan");
+    pub(crate) const PROPERTY_WILL_BE: Field = Field::new(88, 1, "Property will be");
+}
+
+/// `02D` Construction or refinance data
+pub(crate) mod refinance {
+    use super::Field;
+    pub(crate) const ID: &str = "02D";
+    pub(crate) const PURPOSE_OF_REFINANCE: Field = Field::new(68, 2, "Purpose of Refinance");
+}
+
+/// `03A` Applicant data
+pub(crate) mod applicant {
+    use super::Field;
+    pub(crate) const ID: &str = "03A";
+    pub(crate) const INDICATOR: Field = Field::new(4, 2, "Applicant / Co-Applicant Indicator");
+    pub(crate) const SSN: Field = Field::new(6, 9, "Applicant Social Security Number");
+    pub(crate) const FIRST_NAME: Field = Field::new(15, 35, "Applicant First Name");
+    pub(crate) const LAST_NAME: Field = Field::new(85, 35, "Applicant Last Name");
+    pub(crate) const HOME_PHONE: Field = Field::new(124, 10, "Home Phone");
+    pub(crate) const EMAIL: Field = Field::new(160, 80, "E-Mail Address");
+}
+
+/// `04A` Primary current employer
+pub(crate) mod primary_employer {
+    use super::Field;
+    pub(crate) const ID: &str = "04A";
+    pub(crate) const SSN: Field = Field::new(4, 9, "Applicant Social Security Number");
+    pub(crate) const EMPLOYER_NAME: Field = Field::new(13, 35, "Employer Name");
+    pub(crate) const SELF_EMPLOYED: Field = Field::new(129, 1, "Self Employed");
+    pub(crate) const YEARS_ON_JOB: Field = Field::new(130, 2, "Yrs. on this job");
+    pub(crate) const MONTHS_ON_JOB: Field = Field::new(132, 2, "Months on this job");
+    pub(crate) const YEARS_IN_PROFESSION: Field = Field::new(134, 2, "Yrs. employed in this line of work/profession");
+    pub(crate) const POSITION: Field = Field::new(136, 25, "Position/Title/Type of Business");
+}
+
+/// `04B` Secondary or previous employer
+pub(crate) mod other_employer {
+    use super::Field;
+    pub(crate) const ID: &str = "04B";
+    pub(crate) const SSN: Field = Field::new(4, 9, "Applicant Social Security Number");
+    pub(crate) const EMPLOYER_NAME: Field = Field::new(13, 35, "Employer Name");
+    pub(crate) const SELF_EMPLOYED: Field = Field::new(129, 1, "Self Employed");
+    pub(crate) const CURRENT: Field = Field::new(130, 1, "Current Employment Flag");
+    pub(crate) const FROM_DATE: Field = Field::new(131, 8, "From Date");
+    pub(crate) const TO_DATE: Field = Field::new(139, 8, "To Date");
+    pub(crate) const MONTHLY_INCOME: Field = Field::new(147, 15, "Monthly Income");
+    pub(crate) const POSITION: Field = Field::new(162, 25, "Position/Title/Type of Business");
+}
+
+/// `05I` Current monthly income
+pub(crate) mod income {
+    use super::Field;
+    pub(crate) const ID: &str = "05I";
+    pub(crate) const SSN: Field = Field::new(4, 9, "Applicant Social Security Number");
+    pub(crate) const INCOME_TYPE: Field = Field::new(13, 2, "Type of Income Code");
+    pub(crate) const AMOUNT: Field = Field::new(15, 15, "Income Amount");
+}
+
+/// `06C` Assets
+pub(crate) mod asset {
+    use super::Field;
+    pub(crate) const ID: &str = "06C";
+    pub(crate) const SSN: Field = Field::new(4, 9, "Applicant Social Security Number");
+    pub(crate) const ASSET_TYPE: Field = Field::new(13, 3, "Account/Asset Type");
+    pub(crate) const INSTITUTION: Field = Field::new(16, 35, "Depository/Stock/Bond Institution Name");
+    pub(crate) const ACCOUNT_NUMBER: Field = Field::new(132, 30, "Acct. no.");
+    pub(crate) const VALUE: Field = Field::new(162, 15, "Cash or Market Value");
+}
+
+/// `06L` Liabilities
+pub(crate) mod liability {
+    use super::Field;
+    pub(crate) const ID: &str = "06L";
+    pub(crate) const SSN: Field = Field::new(4, 9, "Applicant Social Security Number");
+    pub(crate) const LIABILITY_TYPE: Field = Field::new(13, 2, "Liability Type");
+    pub(crate) const CREDITOR: Field = Field::new(15, 35, "Creditor Name");
+    pub(crate) const ACCOUNT_NUMBER: Field = Field::new(131, 30, "Acct. no.");
+    pub(crate) const MONTHLY_PAYMENT: Field = Field::new(161, 15, "Monthly Payment Amount");
+    pub(crate) const UNPAID_BALANCE: Field = Field::new(179, 15, "Unpaid Balance");
+    pub(crate) const PAID_PRIOR_TO_CLOSING: Field = Field::new(194, 1, "Liability will be paid prior to closing");
+    pub(crate) const OMITTED: Field = Field::new(198, 1, "Omitted Indicator");
+}
+
+/// `07A` Details of transaction
+pub(crate) mod transaction {
+    use super::Field;
+    pub(crate) const ID: &str = "07A";
+    pub(crate) const PURCHASE_PRICE: Field = Field::new(4, 15, "Purchase Price");
+}
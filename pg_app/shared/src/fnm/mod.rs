@@ -0,0 +1,253 @@
+//! Fannie Mae 1003 3.2 ("FNM") fixed-width file exchange
+//!
+//! Brokers still send loans in this format. [`FnmFile`] holds what we keep
+//! from one: the loan terms, the subject property and every applicant with
+//! their employment, income, assets and liabilities. [`export::to_fnm`]
+//! writes it and [`import::parse_fnm`] reads it back.
+//!
+//! # Records
+//! | ID    | Contents                         | Kept as                       |
+//! |-------|----------------------------------|-------------------------------|
+//! | `000` | File identification              | checked for version 3.2x      |
+//! | `01A` | Mortgage type and terms          | [`LoanInput`], loan number    |
+//! | `02A` | Property information             | [`PropertyInput`]             |
+//! | `02B` | Purpose of loan                  | purpose, occupancy            |
+//! | `02D` | Refinance data                   | cash-out or not               |
+//! | `03A` | Applicant data                   | [`FnmApplicant`]              |
+//! | `04A` | Primary current employer         | [`EmploymentInput`]           |
+//! | `04B` | Secondary or previous employer   | [`EmploymentInput`]           |
+//! | `05I` | Current monthly income           | summed into monthly income    |
+//! | `06C` | Assets                           | [`AssetInput`]                |
+//! | `06L` | Liabilities                      | [`LiabilityInput`]            |
+//! | `07A` | Details of transaction           | purchase price                |
+//!
+//! Any other record is skipped and listed in [`FnmImport::ignored_records`].
+//! Employment, income, asset and liability records are tied to an applicant
+//! by SSN.
+//!
+//! The format is coarser than our records in places, so some values do not
+//! survive a round trip: property type is read back from the unit count
+//! alone, income is written as a single base income line, and auto and
+//! student loans are written as installment debts.
+
+/// Writing an [`FnmFile`]
+pub mod export;
+/// Reading an [`FnmFile`]
+pub mod import;
+mod layout;
+mod record;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    Asset, AssetInput, AssetType, Borrower, BorrowerInput, Employment, EmploymentInput, Liability, LiabilityInput,
+    LiabilityType, Loan, LoanInput, LoanType, Occupancy, Property, PropertyInput,
+};
+
+/// Version written to the `000` record
+pub const FNM_VERSION: &str = "3.20";
+
+/// A loan as carried in a Fannie Mae 3.2 file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FnmFile {
+    /// Lender case number, if assigned
+    pub loan_number: Option<String>,
+
+    /// Loan terms (`borrower_id` is not exchanged)
+    pub loan: LoanInput,
+
+    /// Subject property, if entered
+    pub property: Option<PropertyInput>,
+
+    /// Applicants, the primary applicant first
+    pub applicants: Vec<FnmApplicant>,
+}
+
+impl FnmFile {
+    /// Builds the file for a loan from its stored records
+    ///
+    /// A loan without a note rate is written at 0%; the server refuses to
+    /// export one.
+    pub fn from_records(loan: &Loan, property: Option<&Property>, applicants: Vec<FnmApplicant>) -> Self {
+        Self {
+            loan_number: loan.loan_number.clone(),
+            loan: LoanInput {
+                borrower_id: loan.borrower_id,
+                loan_officer_id: loan.loan_officer_id,
+                loan_type: loan.loan_type,
+                loan_purpose: loan.loan_purpose,
+                amount_cents: loan.amount_cents,
+                note_rate: loan.note_rate.unwrap_or_default(),
+                term_months: loan.term_months,
+                application_date: loan.application_date,
+            },
+            property: property.map(PropertyInput::from),
+            applicants,
+        }
+    }
+}
+
+/// One applicant with everything filed under their SSN
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FnmApplicant {
+    /// Social Security number, nine digits
+    pub ssn: String,
+
+    /// Name, contact details and total monthly income
+    pub borrower: BorrowerInput,
+
+    /// Current and previous jobs
+    pub employments: Vec<EmploymentInput>,
+
+    /// Accounts and other assets
+    pub assets: Vec<AssetInput>,
+
+    /// Debts
+    pub liabilities: Vec<LiabilityInput>,
+}
+
+impl FnmApplicant {
+    /// Builds an applicant from a borrower's stored records
+    ///
+    /// Returns `None` when the borrower has no SSN, since the file keys
+    /// every applicant record on it.
+    pub fn from_records(
+        borrower: &Borrower,
+        employments: &[Employment],
+        assets: &[Asset],
+        liabilities: &[Liability],
+    ) -> Option<Self> {
+        Some(Self {
+            ssn: borrower.ssn.clone()?,
+            borrower: BorrowerInput {
+                first_name: borrower.first_name.clone(),
+                last_name: borrower.last_name.clone(),
+                email: borrower.email.clone(),
+                phone: borrower.phone.clone(),
+                monthly_income_cents: borrower.monthly_income_cents,
+            },
+            employments: employments.iter().map(EmploymentInput::from).collect(),
+            assets: assets.iter().map(AssetInput::from).collect(),
+            liabilities: liabilities.iter().map(LiabilityInput::from).collect(),
+        })
+    }
+}
+
+/// Result of reading a Fannie Mae 3.2 file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FnmImport {
+    /// Records mapped from the file
+    pub file: FnmFile,
+
+    /// IDs of records that were skipped (e.g. `05H`, `08A`), each listed once
+    pub ignored_records: Vec<String>,
+}
+
+/// A problem found in a Fannie Mae 3.2 file
+///
+/// `line` is 1-based, or 0 for problems with the file as a whole (such as a
+/// missing `01A` record). `position` is the 1-based column where the bad
+/// field starts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[error("line {line}, record {record}, position {position}: {message}")]
+pub struct FnmError {
+    /// 1-based line number
+    pub line: usize,
+
+    /// Record ID (e.g. `06L`)
+    pub record: String,
+
+    /// 1-based column of the field
+    pub position: usize,
+
+    /// What was wrong with it
+    pub message: String,
+}
+
+impl FnmError {
+    /// Creates an error for the given line, record and column
+    pub fn new(line: usize, record: &str, position: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            record: record.to_string(),
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+// ===== Code tables =====
+// Where several of our values share one code, the first row is the one read back.
+
+/// `01A` Mortgage Applied For
+pub(crate) const MORTGAGE_TYPES: &[(&str, LoanType)] = &[
+    ("01", LoanType::Conventional),
+    ("02", LoanType::Va),
+    ("03", LoanType::Fha),
+    ("04", LoanType::Usda),
+];
+
+/// `02B` Property will be
+pub(crate) const OCCUPANCIES: &[(&str, Occupancy)] = &[
+    ("1", Occupancy::PrimaryResidence),
+    ("2", Occupancy::SecondHome),
+    ("D", Occupancy::Investment),
+];
+
+/// `06L` Liability Type
+pub(crate) const LIABILITY_TYPES: &[(&str, LiabilityType)] = &[
+    ("I", LiabilityType::Installment),
+    ("I", LiabilityType::Auto),
+    ("I", LiabilityType::Student),
+    ("M", LiabilityType::Mortgage),
+    ("R", LiabilityType::Revolving),
+    ("O", LiabilityType::Revolving),
+    ("L", LiabilityType::Auto),
+    ("Z", LiabilityType::Other),
+];
+
+/// `06C` Account/Asset Type
+pub(crate) const ASSET_TYPES: &[(&str, AssetType)] = &[
+    ("03", AssetType::Checking),
+    ("SG", AssetType::Savings),
+    ("F1", AssetType::CertificateOfDeposit),
+    ("F2", AssetType::MoneyMarket),
+    ("05", AssetType::Stocks),
+    ("06", AssetType::Bonds),
+    ("08", AssetType::Retirement),
+    ("GF", AssetType::Gift),
+    ("OL", AssetType::Other),
+];
+
+/// `02B` Purpose of Loan: purchase
+pub(crate) const PURPOSE_PURCHASE: &str = "16";
+/// `02B` Purpose of Loan: refinance
+pub(crate) const PURPOSE_REFINANCE: &str = "05";
+/// `01A` Mortgage Applied For: other, described in the next field
+pub(crate) const MORTGAGE_OTHER: &str = "07";
+/// `02D` Purpose of Refinance codes that take cash out
+pub(crate) const CASH_OUT_REFINANCE: &[&str] = &["01", "04", "11"];
+/// `02D` Purpose of Refinance: no cash-out rate/term
+pub(crate) const RATE_TERM_REFINANCE: &str = "F1";
+/// `05I` Type of Income Code: base employment income
+pub(crate) const BASE_INCOME: &str = "20";
+/// `03A` indicator for the primary applicant
+pub(crate) const APPLICANT: &str = "BW";
+/// `03A` indicator for a co-applicant
+pub(crate) const CO_APPLICANT: &str = "QZ";
+
+/// Code written for `value`, the first matching row of `table`
+pub(crate) fn code_for<T: PartialEq>(table: &[(&'static str, T)], value: &T) -> Option<&'static str> {
+    table.iter().find(|(_, v)| v == value).map(|(code, _)| *code)
+}
+
+/// The last four letters or digits of an account number
+pub(crate) fn account_last4(account: &str) -> String {
+    let chars: Vec<char> = account.chars().filter(char::is_ascii_alphanumeric).collect();
+    chars[chars.len().saturating_sub(4)..].iter().collect()
+}
+
+/// Amount in cents as FNM dollars (e.g. `"1234.50"`)
+pub(crate) fn amount(cents: i64) -> String {
+    format!("{}{}.{:02}", if cents < 0 { "-" } else { "" }, cents.abs() / 100, cents.abs() % 100)
+}
@@ -0,0 +1,162 @@
+//! Fixed-width record reading and writing
+
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+
+use super::FnmError;
+use crate::money::parse_dollars;
+
+/// A column in a record, as numbered in the Fannie Mae 3.2 specification
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Field {
+    /// 1-based position of the first character
+    pub(crate) start: usize,
+    /// Width in characters
+    pub(crate) len: usize,
+    /// Field name used in error messages
+    pub(crate) name: &'static str,
+}
+
+impl Field {
+    pub(crate) const fn new(start: usize, len: usize, name: &'static str) -> Self {
+        Self { start, len, name }
+    }
+}
+
+/// Builds one line of a file, left-justifying and space-padding each field
+pub(crate) struct RecordWriter {
+    chars: Vec<char>,
+}
+
+impl RecordWriter {
+    pub(crate) fn new(record_id: &str) -> Self {
+        Self {
+            chars: format!("{:<3}", record_id).chars().collect(),
+        }
+    }
+
+    /// Writes `value` into `field`, cutting it off at the field width
+    pub(crate) fn set(&mut self, field: Field, value: &str) -> &mut Self {
+        let end = field.start - 1 + field.len;
+        if self.chars.len() < end {
+            self.chars.resize(end, ' ');
+        }
+        let mut value = value.chars().chain(std::iter::repeat(' '));
+        for slot in &mut self.chars[field.start - 1..end] {
+            *slot = value.next().unwrap_or(' ');
+        }
+        self
+    }
+
+    /// Writes `value` when present
+    pub(crate) fn set_opt(&mut self, field: Field, value: Option<&str>) -> &mut Self {
+        if let Some(value) = value {
+            self.set(field, value);
+        }
+        self
+    }
+
+    /// Writes `Y` or `N`
+    pub(crate) fn flag(&mut self, field: Field, value: bool) -> &mut Self {
+        self.set(field, if value { "Y" } else { "N" })
+    }
+
+    /// Writes a date as `CCYYMMDD`
+    pub(crate) fn date(&mut self, field: Field, value: Option<NaiveDate>) -> &mut Self {
+        match value {
+            Some(date) => self.set(field, &date.format("%Y%m%d").to_string()),
+            None => self,
+        }
+    }
+
+    pub(crate) fn finish(&self) -> String {
+        self.chars.iter().collect()
+    }
+}
+
+/// One line of a file being read
+pub(crate) struct RecordReader {
+    /// 1-based line number
+    pub(crate) line: usize,
+    /// Record ID from positions 1-3, trimmed
+    pub(crate) id: String,
+    chars: Vec<char>,
+}
+
+impl RecordReader {
+    pub(crate) fn new(line: usize, text: &str) -> Self {
+        let chars: Vec<char> = text.chars().collect();
+        let id: String = chars.iter().take(3).collect();
+        Self {
+            line,
+            id: id.trim().to_string(),
+            chars,
+        }
+    }
+
+    /// Error pointing at a field of this record
+    pub(crate) fn error(&self, field: Field, message: impl Into<String>) -> FnmError {
+        FnmError::new(self.line, &self.id, field.start, format!("{}: {}", field.name, message.into()))
+    }
+
+    /// Trimmed, non-blank text of a field
+    pub(crate) fn text(&self, field: Field) -> Option<String> {
+        let start = (field.start - 1).min(self.chars.len());
+        let end = (field.start - 1 + field.len).min(self.chars.len());
+        let text: String = self.chars[start..end].iter().collect();
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    }
+
+    /// Text of a field that must be filled in
+    pub(crate) fn required(&self, field: Field) -> Result<String, FnmError> {
+        self.text(field).ok_or_else(|| self.error(field, "is required"))
+    }
+
+    /// Dollar amount in cents
+    pub(crate) fn amount(&self, field: Field) -> Result<Option<i64>, FnmError> {
+        self.text(field)
+            .map(|text| parse_dollars(&text).ok_or_else(|| self.error(field, format!("'{}' is not a dollar amount", text))))
+            .transpose()
+    }
+
+    /// Whole number or decimal
+    pub(crate) fn number<T: FromStr>(&self, field: Field) -> Result<Option<T>, FnmError> {
+        self.text(field)
+            .map(|text| text.parse().map_err(|_| self.error(field, format!("'{}' is not a number", text))))
+            .transpose()
+    }
+
+    /// `Y`/`N` indicator; blank reads as `N`
+    pub(crate) fn flag(&self, field: Field) -> Result<bool, FnmError> {
+        match self.text(field).as_deref() {
+            None | Some("N") => Ok(false),
+            Some("Y") => Ok(true),
+            Some(other) => Err(self.error(field, format!("'{}' is not Y or N", other))),
+        }
+    }
+
+    /// `CCYYMMDD` date
+    pub(crate) fn date(&self, field: Field) -> Result<Option<NaiveDate>, FnmError> {
+        self.text(field)
+            .map(|text| {
+                NaiveDate::parse_from_str(&text, "%Y%m%d")
+                    .map_err(|_| self.error(field, format!("'{}' is not a CCYYMMDD date", text)))
+            })
+            .transpose()
+    }
+
+    /// Value from a code table
+    pub(crate) fn code<T: Copy>(&self, field: Field, table: &[(&str, T)]) -> Result<Option<T>, FnmError> {
+        self.text(field)
+            .map(|text| {
+                table
+                    .iter()
+                    .find(|(code, _)| *code == text)
+                    .map(|(_, value)| *value)
+                    .ok_or_else(|| self.error(field, format!("unknown code '{}'", text)))
+            })
+            .transpose()
+    }
+}
@@ -0,0 +1,430 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::is_valid_uli;
+use super::lar::{field, LarFile, LarRow, TransmittalSheet};
+use super::NA;
+
+lazy_static! {
+    static ref LEI_REGEX: Regex = Regex::new(r"^[A-Z0-9]{20}$").unwrap();
+    static ref PHONE_REGEX: Regex = Regex::new(r"^[0-9]{3}-[0-9]{3}-[0-9]{4}$").unwrap();
+    static ref TAX_ID_REGEX: Regex = Regex::new(r"^[0-9]{2}-[0-9]{7}$").unwrap();
+    static ref ZIP_REGEX: Regex = Regex::new(r"^[0-9]{5}(-[0-9]{4})?$").unwrap();
+    static ref COUNTY_REGEX: Regex = Regex::new(r"^[0-9]{5}$").unwrap();
+    static ref TRACT_REGEX: Regex = Regex::new(r"^[0-9]{11}$").unwrap();
+}
+
+/// State codes accepted in a LAR: the states, DC and the territories
+const STATE_CODES: &[&str] = &[
+    "AL", "AK", "AZ", "AR", "CA", "CO", "CT", "DE", "DC", "FL", "GA", "HI", "ID", "IL", "IN", "IA", "KS", "KY", "LA",
+    "ME", "MD", "MA", "MI", "MN", "MS", "MO", "MT", "NE", "NV", "NH", "NJ", "NM", "NY", "NC", "ND", "OH", "OK", "OR",
+    "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VT", "VA", "WA", "WV", "WI", "WY", "AS", "GU", "MP", "PR", "VI",
+];
+
+/// Kind of edit, as the HMDA Platform groups them
+///
+/// Files failing either kind are rejected; quality and macro edits, which
+/// only need confirming, are not checked here.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+pub enum EditKind {
+    /// File structure: record counts, identifiers shared across records
+    Syntactical,
+    /// A field's value, alone or against the fields it depends on
+    Validity,
+}
+
+/// One failed edit
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EditFailure {
+    /// Edit ID from the Filing Instructions Guide (e.g. `V628-1`)
+    pub edit: String,
+
+    /// Syntactical or validity
+    pub kind: EditKind,
+
+    /// Loan the record came from; `None` for the transmittal sheet
+    pub loan_id: Option<i32>,
+
+    /// ULI of the record; `None` for the transmittal sheet
+    pub uli: Option<String>,
+
+    /// What is wrong
+    pub message: String,
+}
+
+/// Runs the syntactical and validity edits over a LAR for `year`
+///
+/// Returns every failure, transmittal sheet first, then by record.
+pub fn check_lar(file: &LarFile, year: i32) -> Vec<EditFailure> {
+    let mut failures = Vec::new();
+    check_transmittal(&file.transmittal, year, file.rows.len(), &mut failures);
+
+    let mut ulis = HashSet::new();
+    for row in &file.rows {
+        let mut check = RowCheck {
+            row,
+            failures: &mut failures,
+        };
+        if row.get(field::LEI) != file.transmittal.lei {
+            check.fail("S301", EditKind::Syntactical, "LEI does not match the transmittal sheet");
+        }
+        if !ulis.insert(row.get(field::ULI)) {
+            check.fail("S305", EditKind::Syntactical, "ULI appears more than once in the file");
+        }
+        check.identifiers();
+        check.dates(year);
+        check.preapproval();
+        check.property();
+        check.applicant_demographics();
+        check.co_applicant_demographics();
+        check.credit();
+        check.outcome();
+    }
+    failures
+}
+
+fn check_transmittal(ts: &TransmittalSheet, year: i32, rows: usize, failures: &mut Vec<EditFailure>) {
+    let mut fail = |edit: &str, kind: EditKind, message: &str| {
+        failures.push(EditFailure {
+            edit: edit.to_string(),
+            kind,
+            loan_id: None,
+            uli: None,
+            message: message.to_string(),
+        })
+    };
+    if ts.year != year {
+        fail("S302", EditKind::Syntactical, &format!("Transmittal sheet year must be {}", year));
+    }
+    if ts.total_entries != rows {
+        fail("S304", EditKind::Syntactical, "Total number of entries does not match the number of LAR records");
+    }
+    if !LEI_REGEX.is_match(&ts.lei) {
+        fail("V600", EditKind::Validity, "LEI must be 20 uppercase letters or digits");
+    }
+    let blank = [
+        ("institution name", &ts.institution_name),
+        ("contact name", &ts.contact_name),
+        ("contact email", &ts.contact_email),
+        ("contact street", &ts.contact_street),
+        ("contact city", &ts.contact_city),
+    ];
+    for (name, value) in blank {
+        if value.trim().is_empty() {
+            fail("V601", EditKind::Validity, &format!("Transmittal sheet {} is blank", name));
+        }
+    }
+    if !PHONE_REGEX.is_match(&ts.contact_phone) {
+        fail("V603", EditKind::Validity, "Contact phone must look like 999-999-9999");
+    }
+    if !STATE_CODES.contains(&ts.contact_state.as_str()) {
+        fail("V604", EditKind::Validity, "Contact office state is not a valid state code");
+    }
+    if !ZIP_REGEX.is_match(&ts.contact_zip) {
+        fail("V605", EditKind::Validity, "Contact office ZIP must be 5 or 9 digits");
+    }
+    if ts.total_entries == 0 {
+        fail("V606", EditKind::Validity, "The file has no LAR records for the year");
+    }
+    if !TAX_ID_REGEX.is_match(&ts.tax_id) {
+        fail("V607", EditKind::Validity, "Federal taxpayer ID must look like 99-9999999");
+    }
+}
+
+/// Codes for the applicant or the first co-applicant, and the edits they fall under
+struct Person {
+    ethnicity: usize,
+    ethnicity_observed: usize,
+    race: usize,
+    race_observed: usize,
+    sex: usize,
+    sex_observed: usize,
+    /// "No co-applicant" codes: ethnicity, race, sex, observed; `None` for the applicant
+    no_co_applicant: Option<(&'static str, &'static str, &'static str, &'static str)>,
+    edits: [&'static str; 7],
+}
+
+const APPLICANT: Person = Person {
+    ethnicity: field::APPLICANT_ETHNICITY,
+    ethnicity_observed: field::APPLICANT_ETHNICITY_OBSERVED,
+    race: field::APPLICANT_RACE,
+    race_observed: field::APPLICANT_RACE_OBSERVED,
+    sex: field::APPLICANT_SEX,
+    sex_observed: field::APPLICANT_SEX_OBSERVED,
+    no_co_applicant: None,
+    edits: ["V628", "V629", "V630", "V635", "V636", "V637", "V643"],
+};
+
+const CO_APPLICANT: Person = Person {
+    ethnicity: field::CO_APPLICANT_ETHNICITY,
+    ethnicity_observed: field::CO_APPLICANT_ETHNICITY_OBSERVED,
+    race: field::CO_APPLICANT_RACE,
+    race_observed: field::CO_APPLICANT_RACE_OBSERVED,
+    sex: field::CO_APPLICANT_SEX,
+    sex_observed: field::CO_APPLICANT_SEX_OBSERVED,
+    no_co_applicant: Some(("5", "8", "5", "4")),
+    edits: ["V631", "V632", "V633", "V638", "V639", "V640", "V645"],
+};
+
+/// Ethnicity or race fields of one person
+struct Selections {
+    name: &'static str,
+    first: usize,
+    observed: usize,
+    /// Codes allowed when collected by visual observation or surname
+    aggregates: &'static [&'static str],
+    not_provided: &'static str,
+    not_applicable: &'static str,
+    /// "No co-applicant" code, for the co-applicant only
+    none: Option<&'static str>,
+    edits: [&'static str; 3],
+}
+
+struct RowCheck<'a> {
+    row: &'a LarRow,
+    failures: &'a mut Vec<EditFailure>,
+}
+
+impl RowCheck<'_> {
+    fn fail(&mut self, edit: &str, kind: EditKind, message: &str) {
+        self.failures.push(EditFailure {
+            edit: edit.to_string(),
+            kind,
+            loan_id: Some(self.row.loan_id),
+            uli: Some(self.row.get(field::ULI).to_string()),
+            message: message.to_string(),
+        });
+    }
+
+    fn invalid(&mut self, edit: &str, message: &str) {
+        self.fail(edit, EditKind::Validity, message);
+    }
+
+    fn get(&self, number: usize) -> &str {
+        self.row.get(number)
+    }
+
+    fn action(&self) -> &str {
+        self.row.get(field::ACTION_TAKEN)
+    }
+
+    fn identifiers(&mut self) {
+        let uli = self.get(field::ULI);
+        if !(23..=45).contains(&uli.len()) || !uli.chars().all(|c| c.is_ascii_alphanumeric()) {
+            self.invalid("V608-1", "ULI must be 23 to 45 letters and digits; shorten the loan number");
+        } else if !is_valid_uli(uli) {
+            self.invalid("V609", "ULI check digits are wrong");
+        }
+        if self.get(field::LOAN_AMOUNT).parse::<f64>().is_ok_and(|amount| amount <= 0.0) {
+            self.invalid("V617", "Loan amount must be greater than zero");
+        }
+    }
+
+    fn dates(&mut self, year: i32) {
+        let action = self.action().to_string();
+        if !(1..=8).contains(&action.parse::<i32>().unwrap_or(0)) {
+            self.invalid("V618", "Action taken is missing");
+        }
+        let application = parse_date(self.get(field::APPLICATION_DATE));
+        if action != "6" && application.is_none() {
+            self.invalid("V610-2", "Application date is required unless the loan was purchased");
+        }
+        match parse_date(self.get(field::ACTION_TAKEN_DATE)) {
+            None => self.invalid("V619-1", "Action taken date is missing"),
+            Some(taken) => {
+                if chrono::Datelike::year(&taken) != year {
+                    self.invalid("V619-2", &format!("Action taken date must be in {}", year));
+                }
+                if application.is_some_and(|applied| taken < applied) {
+                    self.invalid("V619-3", "Action taken date is before the application date");
+                }
+            }
+        }
+    }
+
+    fn preapproval(&mut self) {
+        let requested = self.get(field::PREAPPROVAL) == "1";
+        if requested && self.get(field::LOAN_PURPOSE) != "1" {
+            self.invalid("V612-2", "Preapproval can only be requested for a home purchase");
+        }
+        if matches!(self.action(), "7" | "8") && !requested {
+            self.invalid("V613-2", "Preapproval actions need preapproval requested");
+        }
+        if requested && !matches!(self.action(), "1" | "2" | "7" | "8") {
+            self.invalid("V613-4", "With preapproval requested, action taken must be 1, 2, 7 or 8");
+        }
+    }
+
+    fn property(&mut self) {
+        let state = self.get(field::STATE);
+        if state != NA && !STATE_CODES.contains(&state) {
+            self.invalid("V623", "Property state is not a valid state code");
+        }
+        let zip = self.get(field::ZIP);
+        if zip != NA && !ZIP_REGEX.is_match(zip) {
+            self.invalid("V624", "Property ZIP must be 5 or 9 digits");
+        }
+        if self.get(field::STREET) != NA && [field::CITY, field::STATE, field::ZIP].iter().any(|&f| self.get(f) == NA) {
+            self.invalid("V622", "City, state and ZIP are required with a street address");
+        }
+        let tract = self.get(field::CENSUS_TRACT).to_string();
+        let county = self.get(field::COUNTY).to_string();
+        if tract != NA && !TRACT_REGEX.is_match(&tract) {
+            self.invalid("V625-1", "Census tract must be 11 digits or NA");
+        }
+        if county != NA && !COUNTY_REGEX.is_match(&county) {
+            self.invalid("V626", "County must be a five digit FIPS code or NA");
+        }
+        if tract != NA && county != NA && !tract.starts_with(&county) {
+            self.invalid("V627", "Census tract must start with the county code");
+        }
+    }
+
+    fn applicant_demographics(&mut self) {
+        self.demographics(&APPLICANT);
+        let age = self.get(field::APPLICANT_AGE);
+        if !age.parse::<i32>().is_ok_and(|a| a > 0) {
+            self.invalid("V651-1", "Applicant age is missing");
+        }
+    }
+
+    fn co_applicant_demographics(&mut self) {
+        self.demographics(&CO_APPLICANT);
+        let age = self.get(field::CO_APPLICANT_AGE);
+        if !age.parse::<i32>().is_ok_and(|a| a > 0) {
+            self.invalid("V652-1", "Co-applicant age is missing");
+        }
+    }
+
+    /// Ethnicity, race and sex of one person, each with how it was collected
+    fn demographics(&mut self, person: &Person) {
+        let who = if person.no_co_applicant.is_some() { "Co-applicant" } else { "Applicant" };
+        let [ethnicity, ethnicity_observed, ethnicity_na, race, race_observed, race_na, sex] = person.edits;
+        let no_co_applicant = person.no_co_applicant;
+
+        let groups = [
+            Selections {
+                name: "ethnicity",
+                first: person.ethnicity,
+                observed: person.ethnicity_observed,
+                aggregates: &["1", "2"],
+                not_provided: "3",
+                not_applicable: "4",
+                none: no_co_applicant.map(|codes| codes.0),
+                edits: [ethnicity, ethnicity_observed, ethnicity_na],
+            },
+            Selections {
+                name: "race",
+                first: person.race,
+                observed: person.race_observed,
+                aggregates: &["1", "2", "3", "4", "5"],
+                not_provided: "6",
+                not_applicable: "7",
+                none: no_co_applicant.map(|codes| codes.1),
+                edits: [race, race_observed, race_na],
+            },
+        ];
+        for group in groups {
+            self.selections(who, &group, no_co_applicant.map(|codes| codes.3));
+        }
+
+        let sex_code = self.get(person.sex).to_string();
+        let sex_observed = self.get(person.sex_observed).to_string();
+        if sex_code.is_empty() || sex_observed.is_empty() {
+            self.invalid(&format!("{}-1", sex), &format!("{} sex or how it was collected is missing", who));
+        } else {
+            if sex_observed == "1" && !matches!(sex_code.as_str(), "1" | "2") {
+                self.invalid(&format!("{}-2", sex), &format!("{} sex collected by visual observation must be male or female", who));
+            }
+            if (sex_code == "4") != (sex_observed == "3") {
+                self.invalid(&format!("{}-3", sex), &format!("{} sex not applicable must be collected as not applicable", who));
+            }
+            if let Some((_, _, none, no_observed)) = no_co_applicant
+                && (sex_code == none) != (sex_observed == no_observed)
+            {
+                self.invalid("V647", "Co-applicant sex and how it was collected must both say there is no co-applicant");
+            }
+        }
+    }
+
+    /// Up to five ethnicity or race codes and how they were collected
+    fn selections(&mut self, who: &str, group: &Selections, no_observed: Option<&str>) {
+        let [edit, edit_observed, edit_na] = group.edits;
+        let name = group.name;
+        let codes: Vec<String> = self.row.range(group.first, 5).iter().filter(|c| !c.is_empty()).cloned().collect();
+        let observed = self.get(group.observed).to_string();
+        let Some(first) = codes.first().filter(|_| !self.get(group.first).is_empty()).cloned() else {
+            self.invalid(&format!("{}-1", edit), &format!("{} {} is missing", who, name));
+            return;
+        };
+
+        if codes.iter().collect::<HashSet<_>>().len() != codes.len() {
+            self.invalid(&format!("{}-3", edit), &format!("{} {} lists a code twice", who, name));
+        }
+        let exclusive = [Some(group.not_provided), Some(group.not_applicable), group.none];
+        if exclusive.contains(&Some(first.as_str())) && codes.len() > 1 {
+            self.invalid(&format!("{}-4", edit), &format!("{} {} code {} cannot be combined with others", who, name, first));
+        }
+        match observed.as_str() {
+            "" => self.invalid(&format!("{}-1", edit_observed), &format!("How the {} {} was collected is missing", who.to_lowercase(), name)),
+            "1" if !codes.iter().all(|c| group.aggregates.contains(&c.as_str())) => self.invalid(
+                &format!("{}-2", edit_observed),
+                &format!("{} {} collected by visual observation or surname must use the aggregate categories", who, name),
+            ),
+            "2" if first == group.not_applicable => self.invalid(
+                &format!("{}-3", edit_observed),
+                &format!("{} {} provided by the applicant cannot be not applicable", who, name),
+            ),
+            _ => {}
+        }
+        if (first == group.not_applicable) != (observed == "3") {
+            self.invalid(edit_na, &format!("{} {} not applicable must be collected as not applicable", who, name));
+        }
+        if let (Some(none), Some(no_observed)) = (group.none, no_observed)
+            && (first == none) != (observed == no_observed)
+        {
+            self.invalid(edit_na, &format!("{} {} and how it was collected must both say there is no co-applicant", who, name));
+        }
+    }
+
+    fn credit(&mut self) {
+        for (score, model, edit, who) in [
+            (field::APPLICANT_CREDIT_SCORE, field::APPLICANT_CREDIT_MODEL, "V662-1", "Applicant"),
+            (field::CO_APPLICANT_CREDIT_SCORE, field::CO_APPLICANT_CREDIT_MODEL, "V664-1", "Co-applicant"),
+        ] {
+            let score = self.get(score).to_string();
+            let model = self.get(model).to_string();
+            if !matches!(score.as_str(), "8888" | "9999") && matches!(model.as_str(), "" | "9" | "10") {
+                self.invalid(edit, &format!("{} credit scoring model is required with a credit score", who));
+            }
+        }
+    }
+
+    fn outcome(&mut self) {
+        let action = self.action().to_string();
+        if matches!(action.as_str(), "2" | "3" | "4" | "5" | "7" | "8") && self.get(field::PURCHASER_TYPE) != "0" {
+            self.invalid("V656-2", "Only originated or purchased loans can have a purchaser");
+        }
+
+        let reasons: Vec<String> = self.row.range(field::DENIAL_REASON, 4).iter().filter(|c| !c.is_empty()).cloned().collect();
+        if reasons.iter().collect::<HashSet<_>>().len() != reasons.len() {
+            self.invalid("V669-3", "A denial reason is listed twice");
+        }
+        let denied = matches!(action.as_str(), "3" | "7");
+        let has_reason = reasons.first().is_some_and(|r| r != "10");
+        if denied && !has_reason {
+            self.invalid("V670-1", "A denied application needs at least one denial reason");
+        }
+        if !denied && has_reason {
+            self.invalid("V670-2", "Denial reasons are only reported for denied applications");
+        }
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y%m%d").ok()
+}
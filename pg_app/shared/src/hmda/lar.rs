@@ -0,0 +1,517 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::{uli, LarLoan, NA};
+use crate::models::{
+    ActionTaken, FeeSection, HmdaApplicant, HmdaFiler, LoanPurpose, LoanType, Occupancy, PropertyType,
+};
+
+/// Fields in a LAR record
+pub const LAR_FIELD_COUNT: usize = 110;
+/// Fields in the transmittal sheet record
+pub const TS_FIELD_COUNT: usize = 15;
+
+/// 1-based LAR data field numbers, as in the Filing Instructions Guide
+pub mod field {
+    /// Legal Entity Identifier
+    pub const LEI: usize = 2;
+    /// Universal Loan Identifier
+    pub const ULI: usize = 3;
+    /// Application date (YYYYMMDD)
+    pub const APPLICATION_DATE: usize = 4;
+    /// Loan purpose
+    pub const LOAN_PURPOSE: usize = 6;
+    /// Preapproval
+    pub const PREAPPROVAL: usize = 7;
+    /// Loan amount in dollars
+    pub const LOAN_AMOUNT: usize = 10;
+    /// Action taken
+    pub const ACTION_TAKEN: usize = 11;
+    /// Action taken date (YYYYMMDD)
+    pub const ACTION_TAKEN_DATE: usize = 12;
+    /// Property street address
+    pub const STREET: usize = 13;
+    /// Property city
+    pub const CITY: usize = 14;
+    /// Property state
+    pub const STATE: usize = 15;
+    /// Property ZIP code
+    pub const ZIP: usize = 16;
+    /// County FIPS code
+    pub const COUNTY: usize = 17;
+    /// Census tract
+    pub const CENSUS_TRACT: usize = 18;
+    /// Applicant ethnicity 1 (2-5 follow)
+    pub const APPLICANT_ETHNICITY: usize = 19;
+    /// Co-applicant ethnicity 1 (2-5 follow)
+    pub const CO_APPLICANT_ETHNICITY: usize = 25;
+    /// Applicant ethnicity collected by visual observation or surname
+    pub const APPLICANT_ETHNICITY_OBSERVED: usize = 31;
+    /// Co-applicant ethnicity collected by visual observation or surname
+    pub const CO_APPLICANT_ETHNICITY_OBSERVED: usize = 32;
+    /// Applicant race 1 (2-5 follow)
+    pub const APPLICANT_RACE: usize = 33;
+    /// Co-applicant race 1 (2-5 follow)
+    pub const CO_APPLICANT_RACE: usize = 41;
+    /// Applicant race collected by visual observation or surname
+    pub const APPLICANT_RACE_OBSERVED: usize = 49;
+    /// Co-applicant race collected by visual observation or surname
+    pub const CO_APPLICANT_RACE_OBSERVED: usize = 50;
+    /// Applicant sex
+    pub const APPLICANT_SEX: usize = 51;
+    /// Co-applicant sex
+    pub const CO_APPLICANT_SEX: usize = 52;
+    /// Applicant sex collected by visual observation or surname
+    pub const APPLICANT_SEX_OBSERVED: usize = 53;
+    /// Co-applicant sex collected by visual observation or surname
+    pub const CO_APPLICANT_SEX_OBSERVED: usize = 54;
+    /// Applicant age
+    pub const APPLICANT_AGE: usize = 55;
+    /// Co-applicant age
+    pub const CO_APPLICANT_AGE: usize = 56;
+    /// Gross annual income in thousands of dollars
+    pub const INCOME: usize = 57;
+    /// Type of purchaser
+    pub const PURCHASER_TYPE: usize = 58;
+    /// Rate spread
+    pub const RATE_SPREAD: usize = 59;
+    /// HOEPA status
+    pub const HOEPA_STATUS: usize = 60;
+    /// Applicant credit score
+    pub const APPLICANT_CREDIT_SCORE: usize = 62;
+    /// Co-applicant credit score
+    pub const CO_APPLICANT_CREDIT_SCORE: usize = 63;
+    /// Applicant credit scoring model
+    pub const APPLICANT_CREDIT_MODEL: usize = 64;
+    /// Co-applicant credit scoring model
+    pub const CO_APPLICANT_CREDIT_MODEL: usize = 66;
+    /// Reason for denial 1 (2-4 follow)
+    pub const DENIAL_REASON: usize = 68;
+}
+
+/// One LAR record
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LarRow {
+    /// Loan the record was built from
+    pub loan_id: i32,
+
+    /// The 110 field values, in file order
+    pub fields: Vec<String>,
+}
+
+impl LarRow {
+    /// Value of a field by its 1-based number in [`field`]
+    pub fn get(&self, number: usize) -> &str {
+        self.fields.get(number - 1).map(String::as_str).unwrap_or("")
+    }
+
+    /// `count` consecutive fields starting at `number`
+    pub fn range(&self, number: usize, count: usize) -> &[String] {
+        let start = (number - 1).min(self.fields.len());
+        &self.fields[start..(start + count).min(self.fields.len())]
+    }
+}
+
+/// The transmittal sheet record that heads the file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TransmittalSheet {
+    /// Financial institution name
+    pub institution_name: String,
+    /// Calendar year of the filing
+    pub year: i32,
+    /// Contact person's name
+    pub contact_name: String,
+    /// Contact phone (999-999-9999)
+    pub contact_phone: String,
+    /// Contact email
+    pub contact_email: String,
+    /// Contact office street
+    pub contact_street: String,
+    /// Contact office city
+    pub contact_city: String,
+    /// Contact office state
+    pub contact_state: String,
+    /// Contact office ZIP
+    pub contact_zip: String,
+    /// Federal agency code
+    pub federal_agency: i16,
+    /// Number of LAR records that follow
+    pub total_entries: usize,
+    /// Federal taxpayer ID (99-9999999)
+    pub tax_id: String,
+    /// Legal Entity Identifier
+    pub lei: String,
+}
+
+impl TransmittalSheet {
+    /// The 15 field values, in file order
+    pub fn fields(&self) -> Vec<String> {
+        vec![
+            "1".to_string(),
+            self.institution_name.clone(),
+            self.year.to_string(),
+            // Annual filers always report the fourth quarter
+            "4".to_string(),
+            self.contact_name.clone(),
+            self.contact_phone.clone(),
+            self.contact_email.clone(),
+            self.contact_street.clone(),
+            self.contact_city.clone(),
+            self.contact_state.clone(),
+            self.contact_zip.clone(),
+            self.federal_agency.to_string(),
+            self.total_entries.to_string(),
+            self.tax_id.clone(),
+            self.lei.clone(),
+        ]
+    }
+}
+
+/// A year's LAR: the transmittal sheet and one record per loan
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LarFile {
+    /// Filer record
+    pub transmittal: TransmittalSheet,
+    /// Loan records, in the order given
+    pub rows: Vec<LarRow>,
+}
+
+impl LarFile {
+    /// The file as uploaded: pipe-delimited, one record per line
+    ///
+    /// Pipes and line breaks inside values are replaced with spaces, since
+    /// the format has no quoting.
+    pub fn to_pipe_delimited(&self) -> String {
+        let mut out = String::new();
+        for fields in std::iter::once(self.transmittal.fields()).chain(self.rows.iter().map(|row| row.fields.clone())) {
+            let line: Vec<String> = fields.iter().map(|value| clean(value)).collect();
+            out.push_str(&line.join("|"));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Download name used by the HMDA Platform, e.g. `2025_LAR_<LEI>.txt`
+    pub fn file_name(&self) -> String {
+        format!("{}_LAR_{}.txt", self.transmittal.year, self.transmittal.lei)
+    }
+}
+
+/// Lays out the LAR for `year`
+///
+/// The caller picks the loans, normally those whose action taken date falls
+/// in the year; they are written in the order given.
+///
+/// # Example
+/// ```
+/// use chrono::Utc;
+/// use shared::hmda::lar::build_lar;
+/// use shared::models::{FederalAgency, HmdaFiler};
+///
+/// let filer = HmdaFiler {
+///     lei: "10BX939C5543TQA1144M".to_string(),
+///     institution_name: "Prairie Home Lending".to_string(),
+///     tax_id: "12-3456789".to_string(),
+///     federal_agency: FederalAgency::Cfpb,
+///     contact_name: "Dana Ortiz".to_string(),
+///     contact_phone: "555-010-2000".to_string(),
+///     contact_email: "compliance@example.com".to_string(),
+///     contact_street: "100 Main St".to_string(),
+///     contact_city: "Austin".to_string(),
+///     contact_state: "TX".to_string(),
+///     contact_zip: "78701".to_string(),
+///     updated_by: None,
+///     updated_at: Utc::now(),
+/// };
+/// let file = build_lar(&filer, 2025, &[]);
+/// assert_eq!(
+///     file.to_pipe_delimited(),
+///     "1|Prairie Home Lending|2025|4|Dana Ortiz|555-010-2000|compliance@example.com|100 Main St|Austin|TX|78701|9|0|12-3456789|10BX939C5543TQA1144M\n"
+/// );
+/// ```
+pub fn build_lar(filer: &HmdaFiler, year: i32, loans: &[LarLoan]) -> LarFile {
+    LarFile {
+        transmittal: TransmittalSheet {
+            institution_name: filer.institution_name.clone(),
+            year,
+            contact_name: filer.contact_name.clone(),
+            contact_phone: filer.contact_phone.clone(),
+            contact_email: filer.contact_email.clone(),
+            contact_street: filer.contact_street.clone(),
+            contact_city: filer.contact_city.clone(),
+            contact_state: filer.contact_state.clone(),
+            contact_zip: filer.contact_zip.clone(),
+            federal_agency: filer.federal_agency.code(),
+            total_entries: loans.len(),
+            tax_id: filer.tax_id.clone(),
+            lei: filer.lei.clone(),
+        },
+        rows: loans.iter().map(|loan| lar_row(&filer.lei, loan)).collect(),
+    }
+}
+
+/// Builds one loan's 110-field record
+pub fn lar_row(lei: &str, source: &LarLoan) -> LarRow {
+    let loan = &source.loan;
+    let hmda = &source.hmda;
+    let property = source.property.as_ref();
+    let action = hmda.action_taken;
+    let originated = action == Some(ActionTaken::Originated);
+    // Pricing and terms are reported for applications that reached approval
+    let approved = matches!(
+        action,
+        Some(ActionTaken::Originated | ActionTaken::ApprovedNotAccepted | ActionTaken::PreapprovalNotAccepted)
+    );
+    let purchased = action == Some(ActionTaken::Purchased);
+
+    let mut f: Vec<String> = Vec::with_capacity(LAR_FIELD_COUNT);
+    f.push("2".to_string());
+    f.push(lei.to_string());
+    f.push(uli(lei, &loan.loan_number.clone().unwrap_or_else(|| format!("LOAN{}", loan.id))));
+    f.push(if purchased { NA.to_string() } else { date(loan.application_date) });
+    f.push(
+        match loan.loan_type {
+            LoanType::Conventional | LoanType::Jumbo => "1",
+            LoanType::Fha => "2",
+            LoanType::Va => "3",
+            LoanType::Usda => "4",
+        }
+        .to_string(),
+    );
+    f.push(
+        match loan.loan_purpose {
+            LoanPurpose::Purchase => "1",
+            LoanPurpose::Refinance => "31",
+            LoanPurpose::CashOutRefinance => "32",
+        }
+        .to_string(),
+    );
+    f.push(hmda.preapproval.code().to_string());
+    let manufactured = property.is_some_and(|p| p.property_type == PropertyType::Manufactured);
+    f.push(if manufactured { "2" } else { "1" }.to_string());
+    f.push(
+        match property.map(|p| p.occupancy) {
+            Some(Occupancy::SecondHome) => "2",
+            Some(Occupancy::Investment) => "3",
+            _ => "1",
+        }
+        .to_string(),
+    );
+    f.push(dollars(loan.amount_cents));
+    f.push(code(action.map(|a| a.code())));
+    f.push(date(hmda.action_taken_date));
+
+    match property {
+        Some(p) => {
+            f.push(p.street.clone());
+            f.push(p.city.clone());
+            f.push(p.state.clone());
+            f.push(p.zip.clone());
+        }
+        None => f.extend(std::iter::repeat_n(NA.to_string(), 4)),
+    }
+    f.push(hmda.county.clone().unwrap_or_else(|| NA.to_string()));
+    f.push(hmda.census_tract.clone().unwrap_or_else(|| NA.to_string()));
+
+    let applicant = hmda.applicant();
+    let co_applicant = source.has_co_applicant.then(|| hmda.co_applicant());
+
+    // Ethnicity: five codes and the free-form "other" text, per applicant
+    f.extend(selections(applicant.ethnicities.iter().map(|e| e.code()), 1));
+    f.extend(match &co_applicant {
+        Some(co) => selections(co.ethnicities.iter().map(|e| e.code()), 1),
+        None => no_co_applicant(5, 1),
+    });
+    f.push(code(applicant.ethnicity_observed.map(|o| o.code())));
+    f.push(co_applicant_code(&co_applicant, |co| co.ethnicity_observed.map(|o| o.code()), 4));
+
+    // Race: five codes and three free-form fields, per applicant
+    f.extend(selections(applicant.races.iter().map(|r| r.code()), 3));
+    f.extend(match &co_applicant {
+        Some(co) => selections(co.races.iter().map(|r| r.code()), 3),
+        None => no_co_applicant(8, 3),
+    });
+    f.push(code(applicant.race_observed.map(|o| o.code())));
+    f.push(co_applicant_code(&co_applicant, |co| co.race_observed.map(|o| o.code()), 4));
+
+    f.push(code(applicant.sex.map(|s| s.code())));
+    f.push(co_applicant_code(&co_applicant, |co| co.sex.map(|s| s.code()), 5));
+    f.push(code(applicant.sex_observed.map(|o| o.code())));
+    f.push(co_applicant_code(&co_applicant, |co| co.sex_observed.map(|o| o.code()), 4));
+
+    f.push(age(&applicant, purchased));
+    f.push(match &co_applicant {
+        Some(co) => age(co, purchased),
+        None => "9999".to_string(),
+    });
+
+    let annual_income_cents = source.monthly_income_cents * 12;
+    f.push(if purchased || annual_income_cents <= 0 {
+        NA.to_string()
+    } else {
+        // Thousands of dollars, rounded
+        ((annual_income_cents + 50_000) / 100_000).to_string()
+    });
+    f.push(hmda.purchaser_type.code().to_string());
+    f.push(match hmda.rate_spread {
+        Some(spread) if approved => decimal(spread),
+        _ => NA.to_string(),
+    });
+    f.push(if originated { hmda.hoepa_status.code() } else { 3 }.to_string());
+    f.push(hmda.lien_status.code().to_string());
+
+    f.push(credit_score(&applicant));
+    f.push(match &co_applicant {
+        Some(co) => credit_score(co),
+        None => "9999".to_string(),
+    });
+    f.extend(credit_model(Some(&applicant), 9));
+    f.extend(credit_model(co_applicant.as_ref(), 10));
+
+    let mut reasons: Vec<String> = hmda.denial_reasons.iter().map(|r| r.code().to_string()).collect();
+    if reasons.is_empty() {
+        reasons.push("10".to_string());
+    }
+    reasons.resize(4, String::new());
+    f.extend(reasons);
+    f.push(String::new());
+
+    // Pricing, from the borrower-paid side of the fee worksheet
+    if originated {
+        let borrower_paid = |include: &dyn Fn(FeeSection, &str) -> bool| -> i64 {
+            source
+                .fees
+                .iter()
+                .filter(|fee| fee.is_borrower_paid() && include(fee.section, &fee.name))
+                .map(|fee| fee.amount_cents)
+                .sum()
+        };
+        let discount_points =
+            borrower_paid(&|section, name| section == FeeSection::Origination && name.to_lowercase().contains("discount"));
+        f.push(dollars(borrower_paid(&|section, _| section.is_loan_cost())));
+        f.push(NA.to_string());
+        f.push(dollars(borrower_paid(&|section, _| section == FeeSection::Origination)));
+        f.push(if discount_points > 0 { dollars(discount_points) } else { String::new() });
+        f.push(if source.lender_credits_cents > 0 { dollars(source.lender_credits_cents) } else { String::new() });
+    } else {
+        f.extend(std::iter::repeat_n(NA.to_string(), 5));
+    }
+
+    f.push(match loan.note_rate {
+        Some(rate) if approved => decimal(rate),
+        _ => NA.to_string(),
+    });
+    f.push(NA.to_string());
+    f.push(match hmda.debt_to_income {
+        Some(dti) if !purchased => decimal(dti),
+        _ => NA.to_string(),
+    });
+    let value_cents = property.and_then(|p| p.appraised_value_cents.or(p.ltv_value_cents()));
+    f.push(match value_cents {
+        Some(value) if value > 0 && !purchased => decimal(loan.amount_cents as f64 / value as f64 * 100.0),
+        _ => NA.to_string(),
+    });
+    f.push(loan.term_months.to_string());
+    f.push(match &source.arm_terms {
+        Some(arm) => arm.initial_fixed_months.to_string(),
+        None => NA.to_string(),
+    });
+    // Balloon, interest-only, negative amortization, other non-amortizing: no
+    f.extend(std::iter::repeat_n("2".to_string(), 4));
+    f.push(value_cents.map(dollars).unwrap_or_else(|| NA.to_string()));
+    // Manufactured home secured property type and land interest
+    if manufactured {
+        f.push("1".to_string());
+        f.push("1".to_string());
+    } else {
+        f.push("3".to_string());
+        f.push("5".to_string());
+    }
+    f.push(property.map(|p| p.units.to_string()).unwrap_or_default());
+    f.push(NA.to_string());
+    // Submitted directly to, and initially payable to, the institution
+    f.push("1".to_string());
+    f.push("1".to_string());
+    f.push(source.nmls_id.clone().filter(|id| !id.trim().is_empty()).unwrap_or_else(|| NA.to_string()));
+    // AUS 1-5 and free-form, then results 1-5 and free-form: not applicable
+    f.push("6".to_string());
+    f.extend(std::iter::repeat_n(String::new(), 5));
+    f.push("17".to_string());
+    f.extend(std::iter::repeat_n(String::new(), 5));
+    // Reverse mortgage, open-end line of credit, business purpose: no
+    f.extend(std::iter::repeat_n("2".to_string(), 3));
+
+    debug_assert_eq!(f.len(), LAR_FIELD_COUNT);
+    LarRow { loan_id: loan.id, fields: f }
+}
+
+/// Up to five codes padded with blanks, then `free_form` blank text fields
+fn selections(codes: impl Iterator<Item = i16>, free_form: usize) -> Vec<String> {
+    let mut fields: Vec<String> = codes.take(5).map(|c| c.to_string()).collect();
+    fields.resize(5 + free_form, String::new());
+    fields
+}
+
+/// The "no co-applicant" code, four blanks, then blank free-form fields
+fn no_co_applicant(code: i16, free_form: usize) -> Vec<String> {
+    selections(std::iter::once(code), free_form)
+}
+
+fn co_applicant_code(
+    co_applicant: &Option<HmdaApplicant>,
+    value: impl Fn(&HmdaApplicant) -> Option<i16>,
+    none: i16,
+) -> String {
+    match co_applicant {
+        Some(co) => code(value(co)),
+        None => none.to_string(),
+    }
+}
+
+fn age(applicant: &HmdaApplicant, purchased: bool) -> String {
+    match applicant.age {
+        _ if purchased => "8888".to_string(),
+        Some(age) => age.to_string(),
+        None => String::new(),
+    }
+}
+
+fn credit_score(applicant: &HmdaApplicant) -> String {
+    applicant.credit_score.map(|score| score.to_string()).unwrap_or_else(|| "8888".to_string())
+}
+
+/// Model code and its blank free-form field; `none` when there is no such applicant
+fn credit_model(applicant: Option<&HmdaApplicant>, none: i16) -> [String; 2] {
+    let model = match applicant {
+        Some(a) if a.credit_score.is_none() => "9".to_string(),
+        Some(a) => code(a.credit_model.map(|m| m.code())),
+        None => none.to_string(),
+    };
+    [model, String::new()]
+}
+
+fn code(value: Option<i16>) -> String {
+    value.map(|c| c.to_string()).unwrap_or_default()
+}
+
+fn date(value: Option<NaiveDate>) -> String {
+    match value {
+        Some(d) => format!("{:04}{:02}{:02}", d.year(), d.month(), d.day()),
+        None => NA.to_string(),
+    }
+}
+
+/// Dollars, with cents only when there are any (e.g. `"412500"`, `"1020.5"`)
+fn dollars(cents: i64) -> String {
+    decimal(cents as f64 / 100.0)
+}
+
+/// Up to three decimal places with trailing zeros dropped
+fn decimal(value: f64) -> String {
+    let text = format!("{:.3}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+fn clean(value: &str) -> String {
+    value.replace(['|', '\r', '\n'], " ").trim().to_string()
+}
@@ -0,0 +1,107 @@
+//! HMDA Loan/Application Register (LAR) file and its edit checks
+//!
+//! Each year a covered lender files a pipe-delimited LAR with the CFPB: one
+//! transmittal sheet record describing the filer, then one 110-field record
+//! per application with final action taken that year. [`lar::build_lar`]
+//! lays out the file from a loan's stored records and its [`HmdaData`], and
+//! [`edits::check_lar`] runs the syntactical and validity edits from the
+//! FFIEC Filing Instructions Guide, which the HMDA Platform would otherwise
+//! reject the file for.
+//!
+//! # Where the fields come from
+//! | LAR fields                         | Source                                   |
+//! |------------------------------------|------------------------------------------|
+//! | ULI                                | LEI + loan number + check digits         |
+//! | Loan type, purpose, amount, rate   | [`Loan`]                                 |
+//! | Address, occupancy, units, value   | [`Property`]                             |
+//! | Construction method                | manufactured property type or site-built |
+//! | Income                             | applicants' monthly income × 12          |
+//! | Pricing (total loan costs etc.)    | borrower-paid fees, originated loans     |
+//! | Introductory rate period           | [`ArmTerms`]                             |
+//! | NMLSR ID                           | loan officer's NMLS ID                   |
+//! | Action taken, demographics, tract… | [`HmdaData`]                             |
+//!
+//! Fields for products we do not originate (reverse mortgages, open-end
+//! lines, balloon and interest-only features, business purpose loans) are
+//! reported as "no", and fields we do not track (prepayment penalty,
+//! multifamily affordable units, AUS) as not applicable.
+
+/// Checking a LAR against the FFIEC edits
+pub mod edits;
+/// Building and writing the LAR file
+pub mod lar;
+
+use crate::models::{ArmTerms, HmdaData, Loan, LoanFee, Property};
+
+/// Value written for a field that does not apply
+pub const NA: &str = "NA";
+
+/// Everything about one loan that goes into its LAR record
+#[derive(Clone, Debug, PartialEq)]
+pub struct LarLoan {
+    /// The loan
+    pub loan: Loan,
+
+    /// Subject property, if entered
+    pub property: Option<Property>,
+
+    /// HMDA fields kept for the loan
+    pub hmda: HmdaData,
+
+    /// Whether the loan has at least one co-applicant
+    pub has_co_applicant: bool,
+
+    /// Applicants' combined gross monthly income in cents
+    pub monthly_income_cents: i64,
+
+    /// Loan officer's NMLS ID
+    pub nmls_id: Option<String>,
+
+    /// Closing cost worksheet
+    pub fees: Vec<LoanFee>,
+
+    /// General lender credits in cents
+    pub lender_credits_cents: i64,
+
+    /// Adjustable-rate terms, if the loan is an ARM
+    pub arm_terms: Option<ArmTerms>,
+}
+
+/// Universal Loan Identifier for a loan
+///
+/// The LEI, then the loan number with anything but letters and digits
+/// dropped, then two check digits computed as in ISO 7064 MOD 97-10.
+///
+/// # Example
+/// ```
+/// use shared::hmda::{is_valid_uli, uli};
+///
+/// let id = uli("10BX939C5543TQA1144M", "999-143X");
+/// assert_eq!(id, "10BX939C5543TQA1144M999143X38");
+/// assert!(is_valid_uli(&id));
+/// ```
+pub fn uli(lei: &str, loan_number: &str) -> String {
+    let base: String = lei
+        .chars()
+        .chain(loan_number.chars())
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let check = 98 - mod97(&format!("{}00", base));
+    format!("{}{:02}", base, check)
+}
+
+/// Whether a ULI's check digits are right (the whole value is 1 mod 97)
+pub fn is_valid_uli(uli: &str) -> bool {
+    uli.len() > 2 && uli.chars().all(|c| c.is_ascii_alphanumeric()) && mod97(&uli.to_ascii_uppercase()) == 1
+}
+
+/// Remainder mod 97 of the number formed by replacing each letter with
+/// its two-digit value (A = 10 … Z = 35)
+fn mod97(value: &str) -> u32 {
+    value.chars().fold(0, |rem, c| match c.to_digit(36) {
+        Some(n) if n >= 10 => (rem * 100 + n) % 97,
+        Some(n) => (rem * 10 + n) % 97,
+        None => rem,
+    })
+}
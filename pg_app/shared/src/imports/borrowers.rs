@@ -0,0 +1,457 @@
+//! Borrower and lead spreadsheet import
+//!
+//! Loan officers bring prospects over from their own spreadsheets, so the
+//! columns are not fixed. An import runs in three steps:
+//!
+//! 1. [`read_csv`] splits the file into a header and rows.
+//! 2. Each column is mapped to a [`BorrowerField`], starting from
+//!    [`guess_mapping`], and the user corrects it. [`check_mapping`] makes
+//!    sure there is enough to build a borrower from.
+//! 3. [`check_rows`] builds a [`BorrowerInput`] for each row, validates it
+//!    with the model's rules and looks for duplicates. Rows that cannot be
+//!    imported are listed in an [`error_report`] the user can download, fix
+//!    and upload again.
+//!
+//! # Example
+//! ```
+//! use shared::imports::borrowers::{check_rows, guess_mapping, read_csv, RowStatus};
+//!
+//! let table = read_csv("First,Last,E-mail\nAna,Ruiz,ana@example.com\nBo,,bo.example.com\n").unwrap();
+//! let mapping = guess_mapping(&table.headers);
+//! let rows = check_rows(&table, &mapping, &[]);
+//!
+//! assert_eq!(rows[0].status, RowStatus::Ready);
+//! assert_eq!(
+//!     rows[1].status,
+//!     RowStatus::Invalid(vec![
+//!         "Last name must be 1-100 characters".to_string(),
+//!         "Must be a valid email address".to_string(),
+//!     ])
+//! );
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use validator::Validate;
+
+use super::ImportError;
+use crate::models::{Borrower, BorrowerInput};
+use crate::money::parse_dollars;
+
+/// Borrower field a spreadsheet column can be mapped to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum::Display, strum::EnumIter)]
+pub enum BorrowerField {
+    /// First name
+    #[strum(serialize = "First Name")]
+    FirstName,
+    /// Last name
+    #[strum(serialize = "Last Name")]
+    LastName,
+    /// First and last name in one column, as `First Last` or `Last, First`
+    #[strum(serialize = "Full Name")]
+    FullName,
+    /// Contact email address
+    #[strum(serialize = "Email")]
+    Email,
+    /// Contact phone number
+    #[strum(serialize = "Phone")]
+    Phone,
+    /// Gross monthly income in dollars
+    #[strum(serialize = "Monthly Income")]
+    MonthlyIncome,
+    /// Gross annual income in dollars, divided by twelve
+    #[strum(serialize = "Annual Income")]
+    AnnualIncome,
+    /// Desired loan amount in dollars; a filled-in amount opens a lead
+    #[strum(serialize = "Loan Amount")]
+    LoanAmount,
+}
+
+impl BorrowerField {
+    /// Returns the code used in select inputs
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::FirstName => "first_name",
+            Self::LastName => "last_name",
+            Self::FullName => "full_name",
+            Self::Email => "email",
+            Self::Phone => "phone",
+            Self::MonthlyIncome => "monthly_income",
+            Self::AnnualIncome => "annual_income",
+            Self::LoanAmount => "loan_amount",
+        }
+    }
+
+    /// Header spellings recognized by [`guess_mapping`], lowercased with
+    /// spaces, dashes and underscores removed
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Self::FirstName => &["firstname", "first", "fname", "givenname"],
+            Self::LastName => &["lastname", "last", "lname", "surname", "familyname"],
+            Self::FullName => &["name", "fullname", "borrower", "borrowername", "contact", "prospect"],
+            Self::Email => &["email", "emailaddress", "mail"],
+            Self::Phone => &["phone", "phonenumber", "mobile", "cell", "cellphone", "homephone", "telephone"],
+            Self::MonthlyIncome => &["monthlyincome", "income", "grossmonthlyincome", "incomemonthly"],
+            Self::AnnualIncome => &["annualincome", "yearlyincome", "salary", "grossannualincome"],
+            Self::LoanAmount => &["loanamount", "amount", "desiredloanamount", "requestedamount"],
+        }
+    }
+}
+
+impl std::str::FromStr for BorrowerField {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|field| field.code() == s)
+            .ok_or_else(|| format!("Invalid borrower field: {}", s))
+    }
+}
+
+/// A spreadsheet split into its header and rows
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CsvTable {
+    /// Column headers from the first line
+    pub headers: Vec<String>,
+
+    /// Data rows with the line each starts on
+    pub rows: Vec<CsvRow>,
+}
+
+/// One data row of a [`CsvTable`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CsvRow {
+    /// 1-based line in the file (the header is line 1)
+    pub line: usize,
+
+    /// Cell values, trimmed; short rows are padded with blanks
+    pub values: Vec<String>,
+}
+
+/// Reads a CSV file with a header row
+///
+/// Blank lines are skipped. Rows may have fewer cells than the header.
+///
+/// # Errors
+/// Returns an error for a file with no header or no data rows, or a line
+/// that is not valid CSV.
+pub fn read_csv(contents: &str) -> Result<CsvTable, ImportError> {
+    let contents = contents.trim_start_matches('\u{feff}');
+    // The reader's own line count and offsets include skipped blank lines,
+    // so count from the first character of the record instead
+    let bytes = contents.as_bytes();
+    let line_at = |position: Option<&csv::Position>, fallback: usize| {
+        position
+            .map(|p| {
+                let start = bytes[p.byte() as usize..]
+                    .iter()
+                    .position(|b| !matches!(b, b'\r' | b'\n'))
+                    .map_or(bytes.len(), |skip| p.byte() as usize + skip);
+                bytes[..start].iter().filter(|b| **b == b'\n').count() + 1
+            })
+            .unwrap_or(fallback)
+    };
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(bytes);
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| ImportError::new(1, format!("unreadable header: {}", e)))?
+        .iter()
+        .map(str::to_string)
+        .collect();
+    if headers.iter().all(String::is_empty) {
+        return Err(ImportError::new(1, "the first line must name the columns"));
+    }
+
+    let mut rows = Vec::new();
+    for (i, record) in reader.records().enumerate() {
+        let record = record.map_err(|e| ImportError::new(line_at(e.position(), i + 2), e.to_string()))?;
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+        let mut values: Vec<String> = record.iter().map(str::to_string).collect();
+        values.resize(headers.len().max(values.len()), String::new());
+        rows.push(CsvRow {
+            line: line_at(record.position(), i + 2),
+            values,
+        });
+    }
+    if rows.is_empty() {
+        return Err(ImportError::new(0, "the file has no rows below the header"));
+    }
+
+    Ok(CsvTable { headers, rows })
+}
+
+/// Suggests a field for each column from its header
+///
+/// Each field is given to the first column that matches it; unrecognized
+/// columns are left unmapped.
+///
+/// # Example
+/// ```
+/// use shared::imports::borrowers::{guess_mapping, BorrowerField};
+///
+/// let headers = ["Borrower Name", "Cell Phone", "Notes", "Salary"].map(String::from);
+/// assert_eq!(
+///     guess_mapping(&headers),
+///     vec![
+///         Some(BorrowerField::FullName),
+///         Some(BorrowerField::Phone),
+///         None,
+///         Some(BorrowerField::AnnualIncome),
+///     ]
+/// );
+/// ```
+pub fn guess_mapping(headers: &[String]) -> Vec<Option<BorrowerField>> {
+    let mut taken = Vec::new();
+    headers
+        .iter()
+        .map(|header| {
+            let key: String = header
+                .to_lowercase()
+                .chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .collect();
+            let field = BorrowerField::iter().find(|field| !taken.contains(field) && field.aliases().contains(&key.as_str()));
+            taken.extend(field);
+            field
+        })
+        .collect()
+}
+
+/// Checks that a mapping can build borrowers
+///
+/// # Errors
+/// Returns a message when no name columns are mapped, when a full name is
+/// mixed with first or last names, or when a field is mapped twice.
+pub fn check_mapping(mapping: &[Option<BorrowerField>]) -> Result<(), String> {
+    let mapped: Vec<BorrowerField> = mapping.iter().flatten().copied().collect();
+    if let Some(field) = BorrowerField::iter().find(|f| mapped.iter().filter(|m| *m == f).count() > 1) {
+        return Err(format!("{} is mapped to more than one column", field));
+    }
+    let has = |field| mapped.contains(&field);
+    if has(BorrowerField::FullName) && (has(BorrowerField::FirstName) || has(BorrowerField::LastName)) {
+        return Err("Map either Full Name or First Name and Last Name, not both".to_string());
+    }
+    let named = has(BorrowerField::FullName) || (has(BorrowerField::FirstName) && has(BorrowerField::LastName));
+    if !named {
+        return Err("Map a Full Name column, or both First Name and Last Name".to_string());
+    }
+    if has(BorrowerField::MonthlyIncome) && has(BorrowerField::AnnualIncome) {
+        return Err("Map either Monthly Income or Annual Income, not both".to_string());
+    }
+    Ok(())
+}
+
+/// Whether a row can be imported
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RowStatus {
+    /// Valid and new
+    Ready,
+    /// Failed validation; one message per problem
+    Invalid(Vec<String>),
+    /// Matches a borrower already on file
+    Duplicate {
+        /// The existing borrower
+        borrower_id: i32,
+        /// Their full name
+        name: String,
+    },
+    /// Repeats an earlier row of the same file
+    RepeatedRow {
+        /// Line of the earlier row
+        line: usize,
+    },
+}
+
+impl RowStatus {
+    /// Why the row is skipped, or `None` when it will be imported
+    pub fn problem(&self) -> Option<String> {
+        match self {
+            Self::Ready => None,
+            Self::Invalid(messages) => Some(messages.join("; ")),
+            Self::Duplicate { borrower_id, name } => Some(format!("Matches existing borrower {} (#{})", name, borrower_id)),
+            Self::RepeatedRow { line } => Some(format!("Repeats line {}", line)),
+        }
+    }
+}
+
+/// A row mapped onto a borrower and checked
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BorrowerImportRow {
+    /// 1-based line in the file
+    pub line: usize,
+
+    /// The borrower built from the row; may be incomplete when invalid
+    pub input: BorrowerInput,
+
+    /// Loan amount for the lead, when the column is mapped and filled in
+    pub loan_amount_cents: Option<i64>,
+
+    /// Whether the row will be imported
+    pub status: RowStatus,
+}
+
+/// Builds, validates and de-duplicates every row
+///
+/// A row is a duplicate of an existing borrower, or of an earlier row, when
+/// the email addresses match, or the names match and the phone numbers end
+/// in the same ten digits.
+pub fn check_rows(table: &CsvTable, mapping: &[Option<BorrowerField>], existing: &[Borrower]) -> Vec<BorrowerImportRow> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    table
+        .rows
+        .iter()
+        .map(|row| {
+            let (input, loan_amount_cents, mut problems) = build_row(row, mapping);
+            if let Err(errors) = input.validate() {
+                let mut fields: Vec<_> = errors.field_errors().into_iter().collect();
+                fields.sort_by_key(|(field, _)| field_order(field));
+                problems.extend(
+                    fields
+                        .into_iter()
+                        .flat_map(|(_, errs)| errs.iter())
+                        .map(|e| e.message.as_ref().map(|m| m.to_string()).unwrap_or_else(|| e.code.to_string())),
+                );
+            }
+
+            let keys = match_keys(&input.first_name, &input.last_name, input.email.as_deref(), input.phone.as_deref());
+            let status = if !problems.is_empty() {
+                RowStatus::Invalid(problems)
+            } else if let Some(borrower) = existing.iter().find(|b| {
+                let theirs = match_keys(&b.first_name, &b.last_name, b.email.as_deref(), b.phone.as_deref());
+                keys.iter().any(|key| theirs.contains(key))
+            }) {
+                RowStatus::Duplicate {
+                    borrower_id: borrower.id,
+                    name: borrower.full_name(),
+                }
+            } else if let Some(line) = keys.iter().find_map(|key| seen.get(key)) {
+                RowStatus::RepeatedRow { line: *line }
+            } else {
+                for key in keys {
+                    seen.insert(key, row.line);
+                }
+                RowStatus::Ready
+            };
+
+            BorrowerImportRow {
+                line: row.line,
+                input,
+                loan_amount_cents,
+                status,
+            }
+        })
+        .collect()
+}
+
+/// The rows that will not be imported, as CSV
+///
+/// Each skipped row is written back with its original cells after a
+/// `line` and `problem` column, so the user can fix the file and upload
+/// just these rows again. Returns `None` when every row is ready.
+pub fn error_report(table: &CsvTable, rows: &[BorrowerImportRow]) -> Option<String> {
+    let problems: HashMap<usize, String> = rows
+        .iter()
+        .filter_map(|row| row.status.problem().map(|problem| (row.line, problem)))
+        .collect();
+    if problems.is_empty() {
+        return None;
+    }
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let header = ["line", "problem"].into_iter().map(str::to_string).chain(table.headers.iter().cloned());
+    writer.write_record(header).ok()?;
+    for row in table.rows.iter().filter(|row| problems.contains_key(&row.line)) {
+        let record = [row.line.to_string(), problems[&row.line].clone()]
+            .into_iter()
+            .chain(row.values.iter().cloned());
+        writer.write_record(record).ok()?;
+    }
+    String::from_utf8(writer.into_inner().ok()?).ok()
+}
+
+/// The mapped cells of a row as a borrower, plus problems the model's
+/// validation cannot see (amounts that are not numbers)
+fn build_row(row: &CsvRow, mapping: &[Option<BorrowerField>]) -> (BorrowerInput, Option<i64>, Vec<String>) {
+    let mut input = BorrowerInput::default();
+    let mut loan_amount_cents = None;
+    let mut problems = Vec::new();
+
+    let mut amount = |field: BorrowerField, text: &str| -> Option<i64> {
+        let cents = parse_dollars(text);
+        if cents.is_none() {
+            problems.push(format!("{} '{}' is not a dollar amount", field, text));
+        }
+        cents
+    };
+
+    for (field, text) in mapping.iter().zip(&row.values) {
+        let (Some(field), text) = (field, text.trim()) else { continue };
+        if text.is_empty() {
+            continue;
+        }
+        match field {
+            BorrowerField::FirstName => input.first_name = text.to_string(),
+            BorrowerField::LastName => input.last_name = text.to_string(),
+            BorrowerField::FullName => (input.first_name, input.last_name) = split_name(text),
+            BorrowerField::Email => input.email = Some(text.to_string()),
+            BorrowerField::Phone => input.phone = Some(text.to_string()),
+            BorrowerField::MonthlyIncome => input.monthly_income_cents = amount(*field, text).unwrap_or(0),
+            BorrowerField::AnnualIncome => {
+                let annual = amount(*field, text).unwrap_or(0);
+                input.monthly_income_cents = (annual + 6) / 12;
+            }
+            BorrowerField::LoanAmount => loan_amount_cents = amount(*field, text),
+        }
+    }
+    if loan_amount_cents.is_some_and(|cents| cents <= 0) {
+        problems.push("Loan Amount must be more than zero".to_string());
+    }
+
+    (input, loan_amount_cents, problems)
+}
+
+/// Splits `Last, First` or `First Middle Last` into first and last name
+fn split_name(name: &str) -> (String, String) {
+    if let Some((last, first)) = name.split_once(',') {
+        return (first.trim().to_string(), last.trim().to_string());
+    }
+    match name.rsplit_once(char::is_whitespace) {
+        Some((first, last)) => (first.trim().to_string(), last.trim().to_string()),
+        None => (name.to_string(), String::new()),
+    }
+}
+
+/// Keeps validation messages in the order the fields appear on the form
+fn field_order(field: &str) -> usize {
+    ["first_name", "last_name", "email", "phone", "monthly_income_cents"]
+        .iter()
+        .position(|f| *f == field)
+        .unwrap_or(usize::MAX)
+}
+
+/// Values that identify a person: their email, and their name with the
+/// last ten digits of their phone number
+fn match_keys(first_name: &str, last_name: &str, email: Option<&str>, phone: Option<&str>) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Some(email) = email.map(str::trim).filter(|e| !e.is_empty()) {
+        keys.push(format!("email:{}", email.to_lowercase()));
+    }
+    let digits: String = phone.unwrap_or_default().chars().filter(char::is_ascii_digit).collect();
+    if digits.len() >= 7 {
+        keys.push(format!(
+            "phone:{}|{}|{}",
+            first_name.trim().to_lowercase(),
+            last_name.trim().to_lowercase(),
+            &digits[digits.len().saturating_sub(10)..]
+        ));
+    }
+    keys
+}
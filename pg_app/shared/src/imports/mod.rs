@@ -0,0 +1,35 @@
+//! Parsers for data files brought in from outside the application
+//!
+//! Parsers turn file contents into model input structs. They never touch
+//! the database; the server functions decide what to do with the result.
+
+/// Borrower and lead spreadsheets (CSV) with column mapping
+pub mod borrowers;
+/// Credit report tradeline files (JSON or CSV)
+pub mod tradelines;
+
+use serde::{Deserialize, Serialize};
+
+/// A problem found while parsing an import file
+///
+/// `line` is 1-based. For CSV it is the line in the file (the header is
+/// line 1); for JSON it is the position of the record in the list.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[error("line {line}: {message}")]
+pub struct ImportError {
+    /// 1-based line or record number
+    pub line: usize,
+
+    /// What was wrong with it
+    pub message: String,
+}
+
+impl ImportError {
+    /// Creates an error for the given line
+    pub fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Credit report tradeline import
+//!
+//! Staff export tradelines from the credit report vendor and upload the file
+//! on the borrower page instead of retyping each debt. Two layouts are
+//! accepted.
+//!
+//! # JSON
+//! Either a bare array or an object with a `tradelines` array. Amounts are
+//! in dollars.
+//!
+//! ```json
+//! {
+//!   "tradelines": [
+//!     {
+//!       "creditor": "Capital One",
+//!       "type": "revolving",
+//!       "balance": 2450.17,
+//!       "monthly_payment": 75,
+//!       "account": "4111111111111234"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! # CSV
+//! A header row is required. Columns may appear in any order; `account` is
+//! optional. Amounts may include `$` and thousands separators.
+//!
+//! ```text
+//! creditor,type,balance,monthly_payment,account
+//! Capital One,revolving,"$2,450.17",75.00,4111111111111234
+//! Toyota Financial,auto,18200,412.50,
+//! ```
+//!
+//! # Field Rules
+//! - `creditor`: Required, 1-100 characters
+//! - `type`: `auto`, `student`, `revolving`, `mortgage`, `installment` or
+//!   `other` (common spellings such as `credit_card` and `auto loan` work too)
+//! - `balance`, `monthly_payment`: Dollars, not negative; blank means zero
+//! - `account`: Only the last four digits are kept
+//!
+//! Imported tradelines always start counted toward DTI; staff flag payoffs
+//! and exclusions afterwards.
+
+use serde::Deserialize;
+use validator::Validate;
+
+use super::ImportError;
+use crate::models::{LiabilityInput, LiabilityType};
+use crate::money::{dollars_to_cents, parse_dollars};
+
+/// File layouts accepted by [`parse_tradelines`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, Deserialize)]
+pub enum TradelineFormat {
+    /// JSON array or `{"tradelines": [...]}` object
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+impl TradelineFormat {
+    /// Picks a format from a file name's extension
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        let lower = name.to_lowercase();
+        if lower.ends_with(".json") {
+            Some(Self::Json)
+        } else if lower.ends_with(".csv") {
+            Some(Self::Csv)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses a tradeline file into liability inputs
+///
+/// Every record is checked; all problems are returned together so the user
+/// can fix the file in one pass.
+///
+/// # Errors
+/// Returns one `ImportError` per bad record, or a single error at line 0
+/// when the file itself cannot be read.
+pub fn parse_tradelines(
+    contents: &str,
+    format: TradelineFormat,
+) -> Result<Vec<LiabilityInput>, Vec<ImportError>> {
+    let records = match format {
+        TradelineFormat::Json => json_records(contents)?,
+        TradelineFormat::Csv => csv_records(contents)?,
+    };
+
+    let mut parsed = Vec::with_capacity(records.len());
+    let mut errors = Vec::new();
+    for (line, record) in records {
+        match record.and_then(|raw| raw.into_input()) {
+            Ok(input) => parsed.push(input),
+            Err(message) => errors.push(ImportError::new(line, message)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(errors)
+    }
+}
+
+/// One tradeline as it appears in the file, before validation
+#[derive(Debug, Deserialize)]
+struct RawTradeline {
+    creditor: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    balance: Amount,
+    #[serde(default)]
+    monthly_payment: Amount,
+    #[serde(default)]
+    account: Option<String>,
+}
+
+/// Dollar amount that may be written as a JSON number or a string
+#[derive(Debug, Default, Deserialize)]
+#[serde(untagged)]
+enum Amount {
+    #[default]
+    Missing,
+    Number(f64),
+    Text(String),
+}
+
+impl Amount {
+    fn to_cents(&self, field: &str) -> Result<i64, String> {
+        match self {
+            Self::Missing => Ok(0),
+            Self::Number(n) => Ok(dollars_to_cents(*n)),
+            Self::Text(t) if t.trim().is_empty() => Ok(0),
+            Self::Text(t) => parse_dollars(t).ok_or_else(|| format!("{} '{}' is not a dollar amount", field, t)),
+        }
+    }
+}
+
+impl RawTradeline {
+    fn into_input(self) -> Result<LiabilityInput, String> {
+        let liability_type: LiabilityType = self.kind.parse()?;
+        let digits: String = self
+            .account
+            .unwrap_or_default()
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect();
+        let account_last4 = (digits.len() >= 4).then(|| digits[digits.len() - 4..].to_string());
+
+        let input = LiabilityInput {
+            liability_type,
+            creditor_name: self.creditor.trim().to_string(),
+            account_last4,
+            balance_cents: self.balance.to_cents("balance")?,
+            monthly_payment_cents: self.monthly_payment.to_cents("monthly_payment")?,
+            paid_off_at_closing: false,
+            excluded: false,
+        };
+        input.validate().map_err(|e| e.to_string())?;
+        Ok(input)
+    }
+}
+
+type Records = Vec<(usize, Result<RawTradeline, String>)>;
+
+fn json_records(contents: &str) -> Result<Records, Vec<ImportError>> {
+    let value: serde_json::Value = serde_json::from_str(contents)
+        .map_err(|e| vec![ImportError::new(0, format!("invalid JSON: {}", e))])?;
+
+    let items = match value {
+        serde_json::Value::Array(items) => items,
+        serde_json::Value::Object(mut map) => match map.remove("tradelines") {
+            Some(serde_json::Value::Array(items)) => items,
+            _ => return Err(vec![ImportError::new(0, "expected a \"tradelines\" array")]),
+        },
+        _ => return Err(vec![ImportError::new(0, "expected an array of tradelines")]),
+    };
+
+    Ok(items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| (i + 1, serde_json::from_value(item).map_err(|e| e.to_string())))
+        .collect())
+}
+
+fn csv_records(contents: &str) -> Result<Records, Vec<ImportError>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(contents.as_bytes());
+
+    let headers = reader
+        .headers()
+        .map_err(|e| vec![ImportError::new(1, format!("unreadable header: {}", e))])?
+        .clone();
+    for required in ["creditor", "type"] {
+        if !headers.iter().any(|h| h.eq_ignore_ascii_case(required)) {
+            return Err(vec![ImportError::new(1, format!("missing '{}' column", required))]);
+        }
+    }
+    let headers = csv::StringRecord::from(
+        headers.iter().map(|h| h.to_lowercase()).collect::<Vec<_>>(),
+    );
+
+    Ok(reader
+        .records()
+        .enumerate()
+        .map(|(i, row)| {
+            let line = row
+                .as_ref()
+                .ok()
+                .and_then(|r| r.position())
+                .map(|p| p.line() as usize)
+                .unwrap_or(i + 2);
+            let record = row
+                .map_err(|e| e.to_string())
+                .and_then(|r| r.deserialize::<CsvTradeline>(Some(&headers)).map_err(|e| e.to_string()))
+                .map(RawTradeline::from);
+            (line, record)
+        })
+        .collect())
+}
+
+/// CSV columns are always text; converted into [`RawTradeline`]
+#[derive(Debug, Deserialize)]
+struct CsvTradeline {
+    creditor: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    balance: String,
+    #[serde(default)]
+    monthly_payment: String,
+    #[serde(default)]
+    account: Option<String>,
+}
+
+impl From<CsvTradeline> for RawTradeline {
+    fn from(row: CsvTradeline) -> Self {
+        Self {
+            creditor: row.creditor,
+            kind: row.kind,
+            balance: Amount::Text(row.balance),
+            monthly_payment: Amount::Text(row.monthly_payment),
+            account: row.account,
+        }
+    }
+}
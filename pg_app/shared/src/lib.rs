@@ -9,6 +9,30 @@ pub mod dtos;
 pub mod models;
 /// Module for Validation
 pub mod validation;
+/// Module for money formatting and parsing
+pub mod money;
+/// Module for mortgage calculations
+pub mod calculations;
+/// Module for import file parsers
+pub mod imports;
+/// Module for CSV and XLSX exports of the list views
+pub mod exports;
+/// Module for field-level encryption of PII
+pub mod crypto;
+/// Module for generated PDF loan documents
+pub mod documents;
+/// Module for duplicate borrower detection
+pub mod duplicates;
+/// Module for Fannie Mae 3.2 flat-file exchange
+pub mod fnm;
+/// Module for the HMDA Loan/Application Register
+pub mod hmda;
+/// Module for MISMO 3.4 XML exchange
+pub mod mismo;
+/// Module for masking PII in responses and displays
+pub mod masking;
+/// Module for markdown rendering
+pub mod markdown;
 
 pub mod error;
 
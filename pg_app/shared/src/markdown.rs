@@ -0,0 +1,28 @@
+//! Markdown rendering for user-entered text such as notes
+
+use pulldown_cmark::{html, Event, Options, Parser};
+
+/// Renders markdown to HTML that is safe to inject into the page
+///
+/// Raw HTML in the source is escaped rather than passed through, so a note
+/// cannot inject markup or scripts. Tables, strikethrough and task lists are
+/// enabled.
+///
+/// # Example
+/// ```
+/// use shared::markdown::render_markdown;
+///
+/// assert_eq!(render_markdown("**bold**"), "<p><strong>bold</strong></p>\n");
+/// assert!(!render_markdown("<script>alert(1)</script>").contains("<script>"));
+/// ```
+pub fn render_markdown(source: &str) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let parser = Parser::new_ext(source, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        other => other,
+    });
+
+    let mut output = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut output, parser);
+    output
+}
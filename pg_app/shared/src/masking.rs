@@ -0,0 +1,153 @@
+//! Masking of PII in server responses and on screen
+//!
+//! Server functions mask every SSN, date of birth and account number before
+//! a response leaves the server, whatever the caller's role, so browsers only
+//! ever hold values such as `***-**-1234`. A full value is sent only by an
+//! explicit reveal of that one value, which needs
+//! [`Permission::RevealPii`](crate::models::Permission::RevealPii) and is
+//! written to the audit log. Having the permission never unmasks a response
+//! by itself.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::duplicates::DuplicateCandidate;
+use crate::models::{Asset, Borrower, BorrowerMerge};
+
+/// SSN with all but the last four digits hidden; already masked values are
+/// returned unchanged
+///
+/// # Example
+/// ```
+/// use shared::masking::mask_ssn;
+///
+/// assert_eq!(mask_ssn("123456789"), "***-**-6789");
+/// assert_eq!(mask_ssn("***-**-6789"), "***-**-6789");
+/// ```
+pub fn mask_ssn(ssn: &str) -> String {
+    format!("***-**-{}", last_four(ssn))
+}
+
+/// SSN with dashes (`123-45-6789`), for a revealed value
+pub fn format_ssn(ssn: &str) -> String {
+    if ssn.len() == 9 && ssn.chars().all(|c| c.is_ascii_digit()) {
+        format!("{}-{}-{}", &ssn[..3], &ssn[3..5], &ssn[5..])
+    } else {
+        ssn.to_string()
+    }
+}
+
+/// Account number with all but the last four characters hidden
+///
+/// # Example
+/// ```
+/// use shared::masking::mask_account_number;
+///
+/// assert_eq!(mask_account_number("Z88-310455"), "****0455");
+/// ```
+pub fn mask_account_number(account: &str) -> String {
+    format!("****{}", last_four(account))
+}
+
+/// Date of birth for display; a masked date shows only its year
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::masking::display_date_of_birth;
+///
+/// let born = NaiveDate::from_ymd_opt(1988, 4, 12).unwrap();
+/// assert_eq!(display_date_of_birth(born, false), "04/12/1988");
+/// assert_eq!(display_date_of_birth(born, true), "**/**/1988");
+/// ```
+pub fn display_date_of_birth(date: NaiveDate, masked: bool) -> String {
+    if masked {
+        format!("**/**/{}", date.year())
+    } else {
+        date.format("%m/%d/%Y").to_string()
+    }
+}
+
+/// Whether a text value has been masked
+pub fn is_masked(value: &str) -> bool {
+    value.contains('*')
+}
+
+fn last_four(value: &str) -> String {
+    let chars: Vec<char> = value.chars().filter(char::is_ascii_alphanumeric).collect();
+    chars[chars.len().saturating_sub(4)..].iter().collect()
+}
+
+/// A masked value that can be revealed in full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumIter)]
+pub enum RevealField {
+    /// A borrower's Social Security number
+    #[strum(serialize = "SSN")]
+    Ssn,
+
+    /// A borrower's date of birth
+    #[strum(serialize = "Date of Birth")]
+    DateOfBirth,
+
+    /// An asset's full account number
+    #[strum(serialize = "Account Number")]
+    AccountNumber,
+}
+
+impl RevealField {
+    /// Kind of record the value belongs to, as written to the audit log
+    pub fn entity(&self) -> &'static str {
+        match self {
+            Self::Ssn | Self::DateOfBirth => "borrower",
+            Self::AccountNumber => "asset",
+        }
+    }
+}
+
+/// A response type that carries PII
+///
+/// Applied to every response for every caller; reveal-on-demand is the only
+/// way a full value reaches the browser.
+pub trait MaskPii {
+    /// The value with its PII masked, ready to leave the server
+    fn mask_pii(self) -> Self;
+}
+
+impl MaskPii for Borrower {
+    fn mask_pii(self) -> Self {
+        if self.pii_masked {
+            return self;
+        }
+        Self {
+            ssn: self.ssn.as_deref().map(mask_ssn),
+            // Keeps the year, so age checks still read sensibly
+            date_of_birth: self.date_of_birth.and_then(|date| NaiveDate::from_ymd_opt(date.year(), 1, 1)),
+            pii_masked: true,
+            ..self
+        }
+    }
+}
+
+impl MaskPii for Asset {
+    fn mask_pii(self) -> Self {
+        Self { account_number: self.account_number.as_deref().map(mask_account_number), ..self }
+    }
+}
+
+impl MaskPii for BorrowerMerge {
+    fn mask_pii(self) -> Self {
+        Self { prior_ssn: self.prior_ssn.as_deref().map(mask_ssn), prior_date_of_birth: None, ..self }
+    }
+}
+
+impl MaskPii for DuplicateCandidate {
+    fn mask_pii(self) -> Self {
+        Self { first: self.first.mask_pii(), second: self.second.mask_pii(), ..self }
+    }
+}
+
+impl<T: MaskPii> MaskPii for Vec<T> {
+    fn mask_pii(self) -> Self {
+        self.into_iter().map(MaskPii::mask_pii).collect()
+    }
+}
@@ -0,0 +1,257 @@
+use super::xml::Element;
+use super::{
+    amount, fee_payer_type, fee_section_type, liability_type, loan_purpose_type, mortgage_type, property_usage_type,
+    MismoLoanFile, EXTENSION_NAMESPACE, MISMO_NAMESPACE, MISMO_REFERENCE_MODEL,
+};
+use crate::models::{FeeSection, LiabilityInput, LoanFeeInput, PropertyInput, PropertyType};
+
+/// Writes a loan file as a MISMO 3.4 `MESSAGE` holding one `DEAL`
+///
+/// Child elements follow the reference model's sequence order, so the output
+/// validates against the MISMO 3.4 schema.
+///
+/// # Example
+/// ```
+/// use shared::mismo::{export::to_xml, import::from_xml, MismoLoanFile};
+/// use shared::models::{BorrowerInput, LoanInput};
+///
+/// let file = MismoLoanFile {
+///     loan_number: Some("2025-0001".to_string()),
+///     loan: LoanInput { amount_cents: 320_000_00, note_rate: 6.5, ..Default::default() },
+///     borrower: BorrowerInput {
+///         first_name: "Ada".to_string(),
+///         last_name: "Lovelace".to_string(),
+///         ..Default::default()
+///     },
+///     liabilities: Vec::new(),
+///     property: None,
+///     fees: Vec::new(),
+/// };
+/// let xml = to_xml(&file);
+/// assert!(xml.contains("<BaseLoanAmount>320000.00</BaseLoanAmount>"));
+/// assert_eq!(from_xml(&xml).unwrap().file, file);
+/// ```
+pub fn to_xml(file: &MismoLoanFile) -> String {
+    let mut deal = Element::new("DEAL");
+    if let Some(property) = &file.property {
+        deal = deal.child(
+            Element::new("COLLATERALS").child(Element::new("COLLATERAL").child(subject_property(property))),
+        );
+    }
+    if !file.liabilities.is_empty() {
+        deal = deal.child(Element::new("LIABILITIES").children(file.liabilities.iter().map(liability)));
+    }
+    deal = deal
+        .child(Element::new("LOANS").child(loan(file)))
+        .child(Element::new("PARTIES").child(borrower(file)));
+
+    let message = Element::new("MESSAGE")
+        .attr("xmlns", MISMO_NAMESPACE)
+        .attr("xmlns:los", EXTENSION_NAMESPACE)
+        .attr("MISMOReferenceModelIdentifier", MISMO_REFERENCE_MODEL)
+        .child(
+            Element::new("DEAL_SETS")
+                .child(Element::new("DEAL_SET").child(Element::new("DEALS").child(deal))),
+        );
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    message.write(&mut out, 0);
+    out
+}
+
+fn subject_property(property: &PropertyInput) -> Element {
+    let address = Element::new("ADDRESS")
+        .leaf("AddressLineText", &property.street)
+        .leaf("CityName", &property.city)
+        .leaf("PostalCode", &property.zip)
+        .leaf("StateCode", &property.state);
+
+    let mut detail = Element::new("PROPERTY_DETAIL");
+    detail = match property.property_type {
+        PropertyType::Townhouse => detail.leaf("AttachmentType", "Attached"),
+        PropertyType::SingleFamily | PropertyType::TwoToFourUnit | PropertyType::Pud => {
+            detail.leaf("AttachmentType", "Detached")
+        }
+        PropertyType::Condominium | PropertyType::Manufactured => detail,
+    };
+    if property.property_type == PropertyType::Manufactured {
+        detail = detail.leaf("ConstructionMethodType", "Manufactured");
+    }
+    detail = detail
+        .leaf("FinancedUnitCount", property.units)
+        .leaf_opt("PropertyEstimatedValueAmount", property.estimated_value_cents.map(amount))
+        .leaf("PropertyUsageType", property_usage_type(property.occupancy));
+    if property.property_type == PropertyType::Pud {
+        detail = detail.leaf("PUDIndicator", true);
+    }
+
+    let mut subject = Element::new("SUBJECT_PROPERTY").child(address);
+    if property.property_type == PropertyType::Condominium {
+        subject = subject.child(
+            Element::new("PROJECT")
+                .child(Element::new("PROJECT_DETAIL").leaf("ProjectLegalStructureType", "Condominium")),
+        );
+    }
+    subject = subject.child(detail);
+    if let Some(appraised) = property.appraised_value_cents {
+        subject = subject.child(
+            Element::new("PROPERTY_VALUATIONS").child(
+                Element::new("PROPERTY_VALUATION").child(
+                    Element::new("PROPERTY_VALUATION_DETAIL")
+                        .leaf("PropertyValuationAmount", amount(appraised))
+                        .leaf("PropertyValuationMethodType", "FullAppraisal"),
+                ),
+            ),
+        );
+    }
+    if let Some(price) = property.purchase_price_cents {
+        subject = subject.child(
+            Element::new("SALES_CONTRACTS").child(
+                Element::new("SALES_CONTRACT")
+                    .child(Element::new("SALES_CONTRACT_DETAIL").leaf("SalesContractAmount", amount(price))),
+            ),
+        );
+    }
+    subject
+}
+
+fn liability(liability: &LiabilityInput) -> Element {
+    let (kind, other) = liability_type(liability.liability_type);
+    Element::new("LIABILITY")
+        .child(
+            Element::new("LIABILITY_DETAIL")
+                .leaf_opt("LiabilityAccountIdentifier", liability.account_last4.as_deref())
+                .leaf("LiabilityExclusionIndicator", liability.excluded)
+                .leaf("LiabilityMonthlyPaymentAmount", amount(liability.monthly_payment_cents))
+                .leaf("LiabilityPayoffStatusIndicator", liability.paid_off_at_closing)
+                .leaf("LiabilityType", kind)
+                .leaf_opt("LiabilityTypeOtherDescription", other)
+                .leaf("LiabilityUnpaidBalanceAmount", amount(liability.balance_cents)),
+        )
+        .child(
+            Element::new("LIABILITY_HOLDER")
+                .child(Element::new("NAME").leaf("FullName", &liability.creditor_name)),
+        )
+}
+
+fn fee(fee: &LoanFeeInput) -> Element {
+    let mut element = Element::new("FEE").child(
+        Element::new("FEE_DETAIL")
+            .leaf("FeeActualTotalAmount", amount(fee.amount_cents))
+            .leaf("FeeIntegratedDisclosureSectionType", fee_section_type(fee.section))
+            .leaf("FeeType", if fee.recording_fee { "RecordingFeeTotal" } else { "Other" })
+            .leaf("FeeTypeOtherDescription", &fee.name),
+    );
+    if let Some(paid_to) = fee.paid_to.as_deref().filter(|p| !p.trim().is_empty()) {
+        element = element.child(
+            Element::new("FEE_PAID_TO").child(
+                Element::new("LEGAL_ENTITY").child(Element::new("LEGAL_ENTITY_DETAIL").leaf("FullName", paid_to)),
+            ),
+        );
+    }
+    element = element.child(
+        Element::new("FEE_PAYMENTS").child(
+            Element::new("FEE_PAYMENT")
+                .leaf("FeeActualPaymentAmount", amount(fee.amount_cents))
+                .leaf("FeePaymentIncludedInAPRIndicator", fee.finance_charge)
+                .leaf("FeePaymentPaidByType", fee_payer_type(fee.payer)),
+        ),
+    );
+    if fee.section == FeeSection::CanShop && fee.off_list_provider {
+        element = element.child(
+            Element::new("EXTENSION").child(Element::new("OTHER").leaf("los:OffListProviderIndicator", true)),
+        );
+    }
+    element
+}
+
+fn loan(file: &MismoLoanFile) -> Element {
+    let loan = &file.loan;
+    let (purpose, cash_out) = loan_purpose_type(loan.loan_purpose);
+    let (mortgage, mortgage_other) = mortgage_type(loan.loan_type);
+
+    let mut element = Element::new("LOAN").attr("LoanRoleType", "SubjectLoan");
+    if !file.fees.is_empty() {
+        element = element.child(
+            Element::new("FEE_INFORMATION").child(Element::new("FEES").children(file.fees.iter().map(fee))),
+        );
+    }
+    if let Some(applied) = loan.application_date {
+        element = element.child(Element::new("LOAN_DETAIL").leaf("ApplicationReceivedDate", applied.format("%Y-%m-%d")));
+    }
+    if let Some(number) = file.loan_number.as_deref() {
+        element = element.child(
+            Element::new("LOAN_IDENTIFIERS").child(
+                Element::new("LOAN_IDENTIFIER")
+                    .leaf("LoanIdentifier", number)
+                    .leaf("LoanIdentifierType", "LenderLoan"),
+            ),
+        );
+    }
+    element = element.child(
+        Element::new("MATURITY").child(
+            Element::new("MATURITY_RULE")
+                .leaf("LoanMaturityPeriodCount", loan.term_months)
+                .leaf("LoanMaturityPeriodType", "Month"),
+        ),
+    );
+    if let Some(cash_out) = cash_out {
+        element = element.child(Element::new("REFINANCE").leaf("RefinanceCashOutDeterminationType", cash_out));
+    }
+    element.child(
+        Element::new("TERMS_OF_LOAN")
+            .leaf("BaseLoanAmount", amount(loan.amount_cents))
+            .leaf("LoanPurposeType", purpose)
+            .leaf("MortgageType", mortgage)
+            .leaf_opt("MortgageTypeOtherDescription", mortgage_other)
+            .leaf("NoteRatePercent", format!("{:.4}", loan.note_rate)),
+    )
+}
+
+fn borrower(file: &MismoLoanFile) -> Element {
+    let borrower = &file.borrower;
+
+    let mut contact_points = Vec::new();
+    if let Some(email) = borrower.email.as_deref().filter(|e| !e.trim().is_empty()) {
+        contact_points.push(
+            Element::new("CONTACT_POINT")
+                .child(Element::new("CONTACT_POINT_EMAIL").leaf("ContactPointEmailValue", email)),
+        );
+    }
+    if let Some(phone) = borrower.phone.as_deref().filter(|p| !p.trim().is_empty()) {
+        contact_points.push(
+            Element::new("CONTACT_POINT")
+                .child(Element::new("CONTACT_POINT_TELEPHONE").leaf("ContactPointTelephoneValue", phone)),
+        );
+    }
+
+    let mut individual = Element::new("INDIVIDUAL");
+    if !contact_points.is_empty() {
+        individual = individual.child(Element::new("CONTACT_POINTS").children(contact_points));
+    }
+    individual = individual.child(
+        Element::new("NAME")
+            .leaf("FirstName", &borrower.first_name)
+            .leaf("LastName", &borrower.last_name),
+    );
+
+    let income = Element::new("CURRENT_INCOME").child(
+        Element::new("CURRENT_INCOME_ITEMS").child(
+            Element::new("CURRENT_INCOME_ITEM").child(
+                Element::new("CURRENT_INCOME_ITEM_DETAIL")
+                    .leaf("CurrentIncomeMonthlyTotalAmount", amount(borrower.monthly_income_cents))
+                    .leaf("EmploymentIncomeIndicator", true)
+                    .leaf("IncomeType", "Base"),
+            ),
+        ),
+    );
+
+    Element::new("PARTY").child(individual).child(
+        Element::new("ROLES").child(
+            Element::new("ROLE")
+                .child(Element::new("BORROWER").child(income))
+                .child(Element::new("ROLE_DETAIL").leaf("PartyRoleType", "Borrower")),
+        ),
+    )
+}
+
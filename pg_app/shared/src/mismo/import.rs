@@ -0,0 +1,359 @@
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use strum::IntoEnumIterator;
+
+use super::xml::Element;
+use super::{
+    fee_payer_type, fee_section_type, liability_type, mortgage_type, property_usage_type,
+    MismoError, MismoImport, MismoLoanFile,
+};
+use crate::models::{
+    BorrowerInput, FeePayer, FeeSection, LiabilityInput, LiabilityType, LoanFeeInput, LoanInput, LoanPurpose,
+    LoanType, Occupancy, PropertyInput, PropertyType,
+};
+use crate::money::parse_dollars;
+
+const DEAL_PATH: &str = "DEAL_SETS/DEAL_SET/DEALS/DEAL";
+
+/// Reads the first `DEAL` of a MISMO 3.4 `MESSAGE`
+///
+/// Only the first borrower party is imported. Elements that carry data but
+/// were not read (co-borrowers, employers, declarations, ...) are listed in
+/// [`MismoImport::unmapped`] by their path from `DEAL`.
+///
+/// # Errors
+/// Returns [`MismoError`] when the document is not XML, has no deal, loan or
+/// borrower, or holds a value that cannot be mapped (e.g. an unknown
+/// `MortgageType`).
+///
+/// # Example
+/// ```
+/// use shared::mismo::{import::from_xml, MismoError};
+///
+/// assert_eq!(
+///     from_xml("<MESSAGE><DEAL_SETS/></MESSAGE>").unwrap_err(),
+///     MismoError::Missing("DEAL_SETS/DEAL_SET/DEALS/DEAL".to_string()),
+/// );
+/// ```
+pub fn from_xml(xml: &str) -> Result<MismoImport, MismoError> {
+    let message = Element::parse(xml).map_err(MismoError::Xml)?;
+    let deal = message
+        .find(DEAL_PATH)
+        .ok_or_else(|| MismoError::Missing(DEAL_PATH.to_string()))?;
+
+    let loan_element = deal
+        .find_all("LOANS/LOAN")
+        .into_iter()
+        .find(|loan| loan.attribute("LoanRoleType").is_none_or(|role| role == "SubjectLoan"))
+        .ok_or_else(|| MismoError::Missing("LOANS/LOAN".to_string()))?;
+    let party = deal
+        .find_all("PARTIES/PARTY")
+        .into_iter()
+        .find(|party| {
+            party
+                .find_all("ROLES/ROLE/ROLE_DETAIL")
+                .iter()
+                .any(|role| role.text("PartyRoleType") == Some("Borrower"))
+        })
+        .ok_or_else(|| MismoError::Missing("PARTIES/PARTY with PartyRoleType Borrower".to_string()))?;
+
+    let file = MismoLoanFile {
+        loan_number: loan_element
+            .find_all("LOAN_IDENTIFIERS/LOAN_IDENTIFIER")
+            .into_iter()
+            .find(|id| id.text("LoanIdentifierType").is_none_or(|t| t == "LenderLoan"))
+            .and_then(|id| id.text("LoanIdentifier"))
+            .map(str::to_string),
+        loan: loan(loan_element)?,
+        borrower: borrower(party)?,
+        liabilities: deal
+            .find_all("LIABILITIES/LIABILITY")
+            .into_iter()
+            .map(liability)
+            .collect::<Result<_, _>>()?,
+        property: deal
+            .find("COLLATERALS/COLLATERAL/SUBJECT_PROPERTY")
+            .map(subject_property)
+            .transpose()?,
+        fees: loan_element
+            .find_all("FEE_INFORMATION/FEES/FEE")
+            .into_iter()
+            .map(fee)
+            .collect::<Result<_, _>>()?,
+    };
+
+    let mut unmapped = BTreeSet::new();
+    deal.unmapped("", &mut unmapped);
+    Ok(MismoImport {
+        file,
+        unmapped: unmapped.into_iter().collect(),
+    })
+}
+
+fn invalid(path: &str, value: &str) -> MismoError {
+    MismoError::InvalidValue {
+        path: path.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn dollars(element: &Element, path: &str) -> Result<Option<i64>, MismoError> {
+    element
+        .text(path)
+        .map(|text| parse_dollars(text).ok_or_else(|| invalid(path, text)))
+        .transpose()
+}
+
+fn indicator(element: &Element, path: &str) -> Result<bool, MismoError> {
+    match element.text(path) {
+        None | Some("false") | Some("0") => Ok(false),
+        Some("true") | Some("1") => Ok(true),
+        Some(other) => Err(invalid(path, other)),
+    }
+}
+
+/// Finds the enum value whose MISMO code matches the element text
+fn lookup<T: IntoEnumIterator + Copy>(
+    element: &Element,
+    path: &str,
+    code: impl Fn(T) -> &'static str,
+) -> Result<Option<T>, MismoError> {
+    element
+        .text(path)
+        .map(|text| T::iter().find(|value| code(*value) == text).ok_or_else(|| invalid(path, text)))
+        .transpose()
+}
+
+fn loan(loan: &Element) -> Result<LoanInput, MismoError> {
+    let terms = loan
+        .find("TERMS_OF_LOAN")
+        .ok_or_else(|| MismoError::Missing("LOAN/TERMS_OF_LOAN".to_string()))?;
+
+    let loan_type = match terms.text("MortgageType") {
+        Some("Other") => match terms.text("MortgageTypeOtherDescription") {
+            Some("Jumbo") => LoanType::Jumbo,
+            other => return Err(invalid("TERMS_OF_LOAN/MortgageTypeOtherDescription", other.unwrap_or(""))),
+        },
+        _ => lookup(terms, "MortgageType", |t| mortgage_type(t).0)?.unwrap_or_default(),
+    };
+
+    let loan_purpose = match terms.text("LoanPurposeType") {
+        Some("Refinance") => match loan.text("REFINANCE/RefinanceCashOutDeterminationType") {
+            Some("CashOut") => LoanPurpose::CashOutRefinance,
+            _ => LoanPurpose::Refinance,
+        },
+        Some("Purchase") | None => LoanPurpose::Purchase,
+        Some(other) => return Err(invalid("TERMS_OF_LOAN/LoanPurposeType", other)),
+    };
+
+    let note_rate = match terms.text("NoteRatePercent") {
+        Some(text) => text.parse().map_err(|_| invalid("TERMS_OF_LOAN/NoteRatePercent", text))?,
+        None => 0.0,
+    };
+    let term_months = match loan.find("MATURITY/MATURITY_RULE") {
+        Some(rule) => {
+            let count = rule.text("LoanMaturityPeriodCount");
+            let months: i32 = match count {
+                Some(text) => text.parse().map_err(|_| invalid("MATURITY_RULE/LoanMaturityPeriodCount", text))?,
+                None => 360,
+            };
+            match rule.text("LoanMaturityPeriodType") {
+                Some("Year") => months * 12,
+                Some("Month") | None => months,
+                Some(other) => return Err(invalid("MATURITY_RULE/LoanMaturityPeriodType", other)),
+            }
+        }
+        None => 360,
+    };
+    let application_date = loan
+        .text("LOAN_DETAIL/ApplicationReceivedDate")
+        .map(|text| {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .map_err(|_| invalid("LOAN_DETAIL/ApplicationReceivedDate", text))
+        })
+        .transpose()?;
+
+    Ok(LoanInput {
+        loan_type,
+        loan_purpose,
+        amount_cents: dollars(terms, "BaseLoanAmount")?.unwrap_or(0),
+        note_rate,
+        term_months,
+        application_date,
+        ..Default::default()
+    })
+}
+
+fn borrower(party: &Element) -> Result<BorrowerInput, MismoError> {
+    let individual = party
+        .find("INDIVIDUAL")
+        .ok_or_else(|| MismoError::Missing("PARTY/INDIVIDUAL".to_string()))?;
+    let contacts = individual.find_all("CONTACT_POINTS/CONTACT_POINT");
+
+    let mut monthly_income_cents = 0;
+    for item in party.find_all("ROLES/ROLE/BORROWER/CURRENT_INCOME/CURRENT_INCOME_ITEMS/CURRENT_INCOME_ITEM") {
+        if let Some(detail) = item.find("CURRENT_INCOME_ITEM_DETAIL") {
+            // Every income type is summed into one monthly figure
+            detail.mark_all();
+            monthly_income_cents += dollars(detail, "CurrentIncomeMonthlyTotalAmount")?.unwrap_or(0);
+        }
+    }
+
+    Ok(BorrowerInput {
+        first_name: individual.text("NAME/FirstName").unwrap_or_default().to_string(),
+        last_name: individual.text("NAME/LastName").unwrap_or_default().to_string(),
+        email: contacts
+            .iter()
+            .find_map(|c| c.text("CONTACT_POINT_EMAIL/ContactPointEmailValue"))
+            .map(str::to_string),
+        phone: contacts
+            .iter()
+            .find_map(|c| c.text("CONTACT_POINT_TELEPHONE/ContactPointTelephoneValue"))
+            .map(str::to_string),
+        monthly_income_cents,
+    })
+}
+
+fn liability(liability: &Element) -> Result<LiabilityInput, MismoError> {
+    let detail = liability
+        .find("LIABILITY_DETAIL")
+        .ok_or_else(|| MismoError::Missing("LIABILITY/LIABILITY_DETAIL".to_string()))?;
+
+    let kind = detail.text("LiabilityType").unwrap_or("Other");
+    let other = detail.text("LiabilityTypeOtherDescription");
+    let liability_type = LiabilityType::iter()
+        .find(|t| liability_type(*t) == (kind, other))
+        .or_else(|| LiabilityType::iter().find(|t| liability_type(*t).0 == kind))
+        .ok_or_else(|| invalid("LIABILITY_DETAIL/LiabilityType", kind))?;
+
+    // Only the last four digits of an account number are kept
+    let account_last4 = detail.text("LiabilityAccountIdentifier").map(|account| {
+        let digits: Vec<char> = account.chars().filter(char::is_ascii_alphanumeric).collect();
+        digits[digits.len().saturating_sub(4)..].iter().collect::<String>()
+    });
+
+    Ok(LiabilityInput {
+        liability_type,
+        creditor_name: liability
+            .text("LIABILITY_HOLDER/NAME/FullName")
+            .unwrap_or_default()
+            .to_string(),
+        account_last4,
+        balance_cents: dollars(detail, "LiabilityUnpaidBalanceAmount")?.unwrap_or(0),
+        monthly_payment_cents: dollars(detail, "LiabilityMonthlyPaymentAmount")?.unwrap_or(0),
+        paid_off_at_closing: indicator(detail, "LiabilityPayoffStatusIndicator")?,
+        excluded: indicator(detail, "LiabilityExclusionIndicator")?,
+    })
+}
+
+fn subject_property(subject: &Element) -> Result<PropertyInput, MismoError> {
+    let address = subject.find("ADDRESS");
+    let address_text = |path: &str| {
+        address
+            .and_then(|a| a.text(path))
+            .unwrap_or_default()
+            .to_string()
+    };
+    let detail = subject.find("PROPERTY_DETAIL");
+    let units: i32 = match detail.and_then(|d| d.text("FinancedUnitCount")) {
+        Some(text) => text.parse().map_err(|_| invalid("PROPERTY_DETAIL/FinancedUnitCount", text))?,
+        None => 1,
+    };
+
+    let manufactured = detail.and_then(|d| d.text("ConstructionMethodType")) == Some("Manufactured");
+    let condominium = subject.text("PROJECT/PROJECT_DETAIL/ProjectLegalStructureType") == Some("Condominium");
+    let pud = match detail {
+        Some(detail) => indicator(detail, "PUDIndicator")?,
+        None => false,
+    };
+    let attached = detail.and_then(|d| d.text("AttachmentType")) == Some("Attached");
+    let property_type = if manufactured {
+        PropertyType::Manufactured
+    } else if condominium {
+        PropertyType::Condominium
+    } else if pud {
+        PropertyType::Pud
+    } else if units >= 2 {
+        PropertyType::TwoToFourUnit
+    } else if attached {
+        PropertyType::Townhouse
+    } else {
+        PropertyType::SingleFamily
+    };
+
+    let occupancy = match detail {
+        Some(detail) => lookup(detail, "PropertyUsageType", property_usage_type)?.unwrap_or_default(),
+        None => Occupancy::default(),
+    };
+
+    let appraised_value_cents = subject
+        .find_all("PROPERTY_VALUATIONS/PROPERTY_VALUATION/PROPERTY_VALUATION_DETAIL")
+        .into_iter()
+        .map(|valuation| dollars(valuation.mark_all(), "PropertyValuationAmount"))
+        .find_map(Result::transpose)
+        .transpose()?;
+    let purchase_price_cents = subject
+        .find_all("SALES_CONTRACTS/SALES_CONTRACT/SALES_CONTRACT_DETAIL")
+        .into_iter()
+        .map(|contract| dollars(contract, "SalesContractAmount"))
+        .find_map(Result::transpose)
+        .transpose()?;
+
+    Ok(PropertyInput {
+        street: address_text("AddressLineText"),
+        city: address_text("CityName"),
+        state: address_text("StateCode"),
+        zip: address_text("PostalCode"),
+        occupancy,
+        property_type,
+        units,
+        purchase_price_cents,
+        appraised_value_cents,
+        estimated_value_cents: match detail {
+            Some(detail) => dollars(detail, "PropertyEstimatedValueAmount")?,
+            None => None,
+        },
+    })
+}
+
+fn fee(fee: &Element) -> Result<LoanFeeInput, MismoError> {
+    let detail = fee
+        .find("FEE_DETAIL")
+        .ok_or_else(|| MismoError::Missing("FEE/FEE_DETAIL".to_string()))?;
+    let payment = fee.find("FEE_PAYMENTS/FEE_PAYMENT");
+
+    let section = lookup(detail, "FeeIntegratedDisclosureSectionType", fee_section_type)?
+        .ok_or_else(|| MismoError::Missing("FEE_DETAIL/FeeIntegratedDisclosureSectionType".to_string()))?;
+    let fee_type = detail.text("FeeType");
+    let name = detail
+        .text("FeeTypeOtherDescription")
+        .or(fee_type)
+        .ok_or_else(|| MismoError::Missing("FEE_DETAIL/FeeType".to_string()))?;
+
+    let (paid_cents, payer, finance_charge) = match payment {
+        Some(payment) => (
+            dollars(payment, "FeeActualPaymentAmount")?,
+            lookup(payment, "FeePaymentPaidByType", fee_payer_type)?.unwrap_or(FeePayer::Borrower),
+            indicator(payment, "FeePaymentIncludedInAPRIndicator")?,
+        ),
+        None => (None, FeePayer::Borrower, false),
+    };
+    let amount_cents = dollars(detail, "FeeActualTotalAmount")?.or(paid_cents).unwrap_or(0);
+
+    Ok(LoanFeeInput {
+        section,
+        name: name.to_string(),
+        amount_cents,
+        payer,
+        paid_to: fee
+            .text("FEE_PAID_TO/LEGAL_ENTITY/LEGAL_ENTITY_DETAIL/FullName")
+            .map(str::to_string),
+        finance_charge,
+        off_list_provider: section == FeeSection::CanShop
+            && indicator(fee, "EXTENSION/OTHER/OffListProviderIndicator")?,
+        // MISMO's RecordingFeeForDeed, RecordingFeeForMortgage, RecordingFeeTotal, ...
+        recording_fee: section == FeeSection::TaxesGovernment
+            && fee_type.is_some_and(|fee_type| fee_type.starts_with("RecordingFee")),
+    })
+}
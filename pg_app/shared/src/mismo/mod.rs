@@ -0,0 +1,206 @@
+//! MISMO 3.4 XML exchange with the LOS and pricing vendors
+//!
+//! [`MismoLoanFile`] is one loan with everything that travels with it. It is
+//! written as a single `DEAL` inside a `MESSAGE` by [`export::to_xml`] and
+//! read back by [`import::from_xml`], which also lists every element of the
+//! incoming document that was not mapped onto our records.
+//!
+//! # Mapping
+//! - Loan: `LOANS/LOAN` (`TERMS_OF_LOAN`, `MATURITY`, `LOAN_DETAIL`,
+//!   `LOAN_IDENTIFIERS`, `REFINANCE`)
+//! - Fees: `LOAN/FEE_INFORMATION/FEES/FEE`
+//! - Borrower and income: the first `PARTIES/PARTY` whose role is `Borrower`
+//! - Liabilities: `DEAL/LIABILITIES/LIABILITY`
+//! - Subject property: `COLLATERALS/COLLATERAL/SUBJECT_PROPERTY`
+//!
+//! Employment history is not kept in this application, so `EMPLOYER`
+//! elements are reported as unmapped on import. The off-list provider flag
+//! on shoppable fees has no MISMO data point and travels in the fee's
+//! `EXTENSION`.
+
+/// Writing a [`MismoLoanFile`] as a MISMO `MESSAGE`
+pub mod export;
+/// Reading a MISMO `MESSAGE` into a [`MismoLoanFile`]
+pub mod import;
+mod xml;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    Borrower, BorrowerInput, FeePayer, FeeSection, Liability, LiabilityInput, LiabilityType, Loan, LoanFee,
+    LoanFeeInput, LoanInput, LoanPurpose, LoanType, Occupancy, Property, PropertyInput,
+};
+
+/// Default namespace of MISMO 3.x residential documents
+pub const MISMO_NAMESPACE: &str = "http://www.mismo.org/residential/2009/schemas";
+
+/// Reference model written to `MISMOReferenceModelIdentifier`
+pub const MISMO_REFERENCE_MODEL: &str = "3.4.032420160128";
+
+/// Namespace for data points MISMO has no element for
+pub const EXTENSION_NAMESPACE: &str = "urn:pg-app:mismo-extension";
+
+/// A loan with its borrower, liabilities, property and fees
+///
+/// `loan.borrower_id` is not exchanged; importers link the loan to the
+/// borrower they create.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MismoLoanFile {
+    /// Lender loan number, if assigned
+    pub loan_number: Option<String>,
+
+    /// Loan terms
+    pub loan: LoanInput,
+
+    /// Primary borrower
+    pub borrower: BorrowerInput,
+
+    /// Borrower's debts
+    pub liabilities: Vec<LiabilityInput>,
+
+    /// Subject property, if entered
+    pub property: Option<PropertyInput>,
+
+    /// Fee worksheet lines
+    pub fees: Vec<LoanFeeInput>,
+}
+
+impl MismoLoanFile {
+    /// Gathers stored records for export
+    ///
+    /// A loan without a note rate is written at 0%; the server refuses to
+    /// export one.
+    pub fn from_records(
+        loan: &Loan,
+        borrower: &Borrower,
+        liabilities: &[Liability],
+        property: Option<&Property>,
+        fees: &[LoanFee],
+    ) -> Self {
+        Self {
+            loan_number: loan.loan_number.clone(),
+            loan: LoanInput {
+                borrower_id: loan.borrower_id,
+                loan_officer_id: loan.loan_officer_id,
+                loan_type: loan.loan_type,
+                loan_purpose: loan.loan_purpose,
+                amount_cents: loan.amount_cents,
+                note_rate: loan.note_rate.unwrap_or_default(),
+                term_months: loan.term_months,
+                application_date: loan.application_date,
+            },
+            borrower: BorrowerInput {
+                first_name: borrower.first_name.clone(),
+                last_name: borrower.last_name.clone(),
+                email: borrower.email.clone(),
+                phone: borrower.phone.clone(),
+                monthly_income_cents: borrower.monthly_income_cents,
+            },
+            liabilities: liabilities.iter().map(LiabilityInput::from).collect(),
+            property: property.map(PropertyInput::from),
+            fees: fees.iter().map(LoanFeeInput::from).collect(),
+        }
+    }
+}
+
+/// Result of reading a MISMO document
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MismoImport {
+    /// Records mapped from the document
+    pub file: MismoLoanFile,
+
+    /// Paths (e.g. `DEAL/PARTIES/PARTY/ROLES/ROLE/BORROWER/EMPLOYERS/EMPLOYER/EMPLOYMENT/EmploymentStatusType`)
+    /// of data elements that were not mapped, each listed once
+    pub unmapped: Vec<String>,
+}
+
+/// Why a MISMO document could not be read
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+pub enum MismoError {
+    /// The document is not well-formed XML
+    #[error("Invalid XML: {0}")]
+    Xml(String),
+
+    /// A required element is absent
+    #[error("Missing required element {0}")]
+    Missing(String),
+
+    /// An element holds a value we cannot use
+    #[error("Invalid value {value:?} in {path}")]
+    InvalidValue {
+        /// Element path
+        path: String,
+        /// Text found there
+        value: String,
+    },
+}
+
+// ===== Enumeration mappings =====
+
+/// `FeeIntegratedDisclosureSectionType` for a fee section
+pub(crate) fn fee_section_type(section: FeeSection) -> &'static str {
+    match section {
+        FeeSection::Origination => "OriginationCharges",
+        FeeSection::CannotShop => "ServicesBorrowerDidNotShopFor",
+        FeeSection::CanShop => "ServicesBorrowerDidShopFor",
+        FeeSection::TaxesGovernment => "TaxesAndOtherGovernmentFees",
+        FeeSection::Prepaids => "Prepaids",
+        FeeSection::InitialEscrow => "InitialEscrowPaymentAtClosing",
+    }
+}
+
+/// `FeePaymentPaidByType` for a payer
+pub(crate) fn fee_payer_type(payer: FeePayer) -> &'static str {
+    match payer {
+        FeePayer::Borrower => "Buyer",
+        FeePayer::Seller => "Seller",
+        FeePayer::Lender => "Lender",
+        FeePayer::Other => "ThirdParty",
+    }
+}
+
+/// `LiabilityType` and `LiabilityTypeOtherDescription` for a liability type
+pub(crate) fn liability_type(liability_type: LiabilityType) -> (&'static str, Option<&'static str>) {
+    match liability_type {
+        LiabilityType::Auto => ("Other", Some("Automobile")),
+        LiabilityType::Student => ("Other", Some("StudentLoan")),
+        LiabilityType::Revolving => ("Revolving", None),
+        LiabilityType::Mortgage => ("MortgageLoan", None),
+        LiabilityType::Installment => ("Installment", None),
+        LiabilityType::Other => ("Other", None),
+    }
+}
+
+/// `MortgageType` and `MortgageTypeOtherDescription` for a loan program
+pub(crate) fn mortgage_type(loan_type: LoanType) -> (&'static str, Option<&'static str>) {
+    match loan_type {
+        LoanType::Conventional => ("Conventional", None),
+        LoanType::Fha => ("FHA", None),
+        LoanType::Va => ("VA", None),
+        LoanType::Usda => ("USDARuralDevelopment", None),
+        LoanType::Jumbo => ("Other", Some("Jumbo")),
+    }
+}
+
+/// `LoanPurposeType` and `RefinanceCashOutDeterminationType` for a purpose
+pub(crate) fn loan_purpose_type(purpose: LoanPurpose) -> (&'static str, Option<&'static str>) {
+    match purpose {
+        LoanPurpose::Purchase => ("Purchase", None),
+        LoanPurpose::Refinance => ("Refinance", Some("NoCashOut")),
+        LoanPurpose::CashOutRefinance => ("Refinance", Some("CashOut")),
+    }
+}
+
+/// `PropertyUsageType` for an occupancy
+pub(crate) fn property_usage_type(occupancy: Occupancy) -> &'static str {
+    match occupancy {
+        Occupancy::PrimaryResidence => "PrimaryResidence",
+        Occupancy::SecondHome => "SecondHome",
+        Occupancy::Investment => "Investment",
+    }
+}
+
+/// Amount in cents as MISMO dollars (e.g. `"1234.50"`)
+pub(crate) fn amount(cents: i64) -> String {
+    format!("{}{}.{:02}", if cents < 0 { "-" } else { "" }, cents.abs() / 100, cents.abs() % 100)
+}
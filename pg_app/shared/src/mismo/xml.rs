@@ -0,0 +1,199 @@
+//! Minimal XML element tree for building and reading MISMO documents
+
+use std::cell::Cell;
+use std::collections::BTreeSet;
+
+use quick_xml::escape::escape;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// One XML element with its attributes, text and children
+///
+/// Lookups through [`Element::find`] and friends mark the elements they
+/// touch, so after mapping a document [`Element::unmapped`] can list the
+/// data that nothing read.
+#[derive(Debug, Default)]
+pub(crate) struct Element {
+    pub(crate) name: String,
+    attributes: Vec<(String, String)>,
+    text: String,
+    children: Vec<Element>,
+    used: Cell<bool>,
+}
+
+impl Element {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds an attribute
+    pub(crate) fn attr(mut self, name: &str, value: &str) -> Self {
+        self.attributes.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a child element
+    pub(crate) fn child(mut self, child: Element) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Adds every element in `children`
+    pub(crate) fn children(mut self, children: impl IntoIterator<Item = Element>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Adds a child holding only text
+    pub(crate) fn leaf(self, name: &str, value: impl ToString) -> Self {
+        let mut leaf = Element::new(name);
+        leaf.text = value.to_string();
+        self.child(leaf)
+    }
+
+    /// Adds a text child when `value` is present and not blank
+    pub(crate) fn leaf_opt(self, name: &str, value: Option<impl ToString>) -> Self {
+        match value.map(|v| v.to_string()).filter(|v| !v.trim().is_empty()) {
+            Some(value) => self.leaf(name, value),
+            None => self,
+        }
+    }
+
+    /// Serializes the element and its children with two-space indentation
+    pub(crate) fn write(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        out.push_str(&indent);
+        out.push('<');
+        out.push_str(&self.name);
+        for (name, value) in &self.attributes {
+            out.push_str(&format!(" {}=\"{}\"", name, escape(value.as_str())));
+        }
+        if self.children.is_empty() && self.text.is_empty() {
+            out.push_str("/>\n");
+        } else if self.children.is_empty() {
+            out.push_str(&format!(">{}</{}>\n", escape(self.text.as_str()), self.name));
+        } else {
+            out.push_str(">\n");
+            for child in &self.children {
+                child.write(out, depth + 1);
+            }
+            out.push_str(&format!("{}</{}>\n", indent, self.name));
+        }
+    }
+
+    /// Parses a document into its root element
+    ///
+    /// Namespace prefixes are dropped from element names; text is trimmed.
+    pub(crate) fn parse(xml: &str) -> Result<Self, String> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+
+        let open = |start: &quick_xml::events::BytesStart| -> Result<Element, String> {
+            let mut element = Element::new(&String::from_utf8_lossy(start.local_name().as_ref()));
+            for attribute in start.attributes() {
+                let attribute = attribute.map_err(|e| e.to_string())?;
+                let value = attribute.unescape_value().map_err(|e| e.to_string())?;
+                element
+                    .attributes
+                    .push((String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned(), value.into_owned()));
+            }
+            Ok(element)
+        };
+
+        let mut stack: Vec<Element> = Vec::new();
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|e| format!("XML error at byte {}: {}", reader.error_position(), e))?;
+            match event {
+                Event::Start(start) => stack.push(open(&start)?),
+                Event::Empty(start) => {
+                    let element = open(&start)?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some(current) = stack.last_mut() {
+                        current.text.push_str(&text.unescape().map_err(|e| e.to_string())?);
+                    }
+                }
+                Event::CData(data) => {
+                    if let Some(current) = stack.last_mut() {
+                        current.text.push_str(&String::from_utf8_lossy(&data));
+                    }
+                }
+                Event::End(_) => {
+                    let element = stack.pop().ok_or("Unexpected closing tag")?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                Event::Eof => return Err("Document ended before the root element closed".to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Marks this element as read
+    pub(crate) fn mark(&self) -> &Self {
+        self.used.set(true);
+        self
+    }
+
+    /// Marks this element and everything below it as read
+    pub(crate) fn mark_all(&self) -> &Self {
+        self.used.set(true);
+        for child in &self.children {
+            child.mark_all();
+        }
+        self
+    }
+
+    /// Value of an attribute
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    /// Every element at a `/`-separated path below this one
+    pub(crate) fn find_all(&self, path: &str) -> Vec<&Element> {
+        let mut current = vec![self];
+        for name in path.split('/') {
+            current = current
+                .into_iter()
+                .flat_map(|element| element.children.iter().filter(|child| child.name == name))
+                .map(Element::mark)
+                .collect();
+        }
+        current
+    }
+
+    /// First element at a `/`-separated path below this one
+    pub(crate) fn find(&self, path: &str) -> Option<&Element> {
+        self.find_all(path).into_iter().next()
+    }
+
+    /// Trimmed, non-empty text of the first element at `path`
+    pub(crate) fn text(&self, path: &str) -> Option<&str> {
+        self.find(path).map(|element| element.text.trim()).filter(|text| !text.is_empty())
+    }
+
+    /// Paths of text-bearing elements that were never read
+    pub(crate) fn unmapped(&self, prefix: &str, out: &mut BTreeSet<String>) {
+        let path = if prefix.is_empty() { self.name.clone() } else { format!("{}/{}", prefix, self.name) };
+        if self.children.is_empty() {
+            if !self.used.get() && !self.text.trim().is_empty() {
+                out.insert(path);
+            }
+            return;
+        }
+        for child in &self.children {
+            child.unmapped(&path, out);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// pg_app/shared/src/models/adverse_action_models.rs
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::{Validate, ValidationError};
+
+use super::hmda_models::DenialReason;
+use super::trid_models::DisclosureDelivery;
+
+/// Days after a denial within which ECOA requires the notice to be delivered
+pub const ADVERSE_ACTION_NOTICE_DAYS: u64 = 30;
+
+/// Last day to deliver the adverse action notice for a denial on `decided_on`
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::models::notice_due_date;
+///
+/// let decided = NaiveDate::from_ymd_opt(2025, 5, 19).unwrap();
+/// assert_eq!(notice_due_date(decided), NaiveDate::from_ymd_opt(2025, 6, 18).unwrap());
+/// ```
+pub fn notice_due_date(decided_on: NaiveDate) -> NaiveDate {
+    decided_on
+        .checked_add_days(Days::new(ADVERSE_ACTION_NOTICE_DAYS))
+        .unwrap_or(decided_on)
+}
+
+// ===== Adverse Action Model =====
+
+/// The denial of an application and the notice sent for it
+///
+/// Saved when a loan moves to Denied; the notice is generated as a loan
+/// document and its delivery recorded later.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct AdverseAction {
+    /// Denied loan
+    pub loan_id: i32,
+
+    /// Reasons for the denial, principal reason first
+    pub reasons: Vec<DenialReason>,
+
+    /// Statement of the reason when `reasons` includes Other
+    pub other_reason: Option<String>,
+
+    /// Day the application was denied
+    pub decided_date: NaiveDate,
+
+    /// User who denied the application
+    pub decided_by: Option<i32>,
+
+    /// Latest generated notice, if any
+    pub document_id: Option<i32>,
+
+    /// Day the notice was delivered or placed in the mail
+    pub delivered_date: Option<NaiveDate>,
+
+    /// How the notice was delivered
+    pub delivery_method: Option<DisclosureDelivery>,
+
+    /// User who recorded the delivery
+    pub delivered_by: Option<i32>,
+
+    /// Timestamp of when the denial was recorded
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the record was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl AdverseAction {
+    /// Last day to deliver the notice
+    pub fn due_date(&self) -> NaiveDate {
+        notice_due_date(self.decided_date)
+    }
+
+    /// Whether the notice was delivered on time; `None` until it is delivered
+    pub fn delivered_on_time(&self) -> Option<bool> {
+        self.delivered_date.map(|delivered| delivered <= self.due_date())
+    }
+
+    /// Reasons as printed on the notice, principal reason first
+    pub fn statements(&self) -> Vec<String> {
+        denial_statements(&self.reasons, self.other_reason.as_deref())
+    }
+}
+
+/// Reasons as printed on the notice, with `other` standing in for Other
+///
+/// # Example
+/// ```
+/// use shared::models::{denial_statements, DenialReason};
+///
+/// assert_eq!(
+///     denial_statements(&[DenialReason::DebtToIncome, DenialReason::Other], Some("Bankruptcy within two years")),
+///     ["Excessive obligations in relation to income", "Bankruptcy within two years"],
+/// );
+/// ```
+pub fn denial_statements(reasons: &[DenialReason], other: Option<&str>) -> Vec<String> {
+    reasons
+        .iter()
+        .map(|reason| match (reason, other.map(str::trim)) {
+            (DenialReason::Other, Some(text)) if !text.is_empty() => text.to_string(),
+            _ => reason.notice_statement().to_string(),
+        })
+        .collect()
+}
+
+/// Fields supplied when denying an application
+///
+/// # Validation Rules
+/// - Reasons: one to four, none repeated, principal reason first
+/// - Other reason: required, up to 200 characters, when Other is chosen
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[validate(schema(function = "validate_denial_input"))]
+pub struct DenialInput {
+    /// Reasons for the denial, principal reason first
+    #[validate(length(min = 1, max = 4, message = "Choose one to four reasons for the denial"))]
+    pub reasons: Vec<DenialReason>,
+
+    /// Statement of the reason when Other is chosen
+    #[validate(length(max = 200, message = "The other reason must be at most 200 characters"))]
+    pub other_reason: Option<String>,
+}
+
+fn validate_denial_input(input: &DenialInput) -> Result<(), ValidationError> {
+    let mut reasons = input.reasons.clone();
+    reasons.sort_by_key(DenialReason::code);
+    reasons.dedup();
+    if reasons.len() != input.reasons.len() {
+        return Err(ValidationError::new("reasons").with_message("A denial reason is listed twice".into()));
+    }
+    let other_given = input.other_reason.as_deref().is_some_and(|text| !text.trim().is_empty());
+    if input.reasons.contains(&DenialReason::Other) && !other_given {
+        return Err(ValidationError::new("other_reason").with_message("Describe the other reason for the denial".into()));
+    }
+    Ok(())
+}
+
+/// Fields supplied when recording delivery of the notice
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoticeDelivery {
+    /// Day the notice was delivered or placed in the mail
+    pub delivered_date: NaiveDate,
+
+    /// How the notice was delivered
+    pub delivery_method: DisclosureDelivery,
+}
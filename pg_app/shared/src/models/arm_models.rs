@@ -0,0 +1,131 @@
+// pg_app/shared/src/models/arm_models.rs
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use validator::Validate;
+
+// ===== ARM Enums =====
+
+/// Index an adjustable rate follows
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `arm_index`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "arm_index", rename_all = "snake_case")]
+pub enum ArmIndex {
+    /// 30-day average SOFR
+    #[default]
+    #[strum(serialize = "30-Day Average SOFR")]
+    Sofr30Day,
+    /// 1-year constant maturity Treasury
+    #[strum(serialize = "1-Year CMT")]
+    Treasury1Year,
+    /// Wall Street Journal prime rate
+    #[strum(serialize = "Prime")]
+    Prime,
+    /// Any other published index
+    Other,
+}
+
+impl ArmIndex {
+    /// Database code for this index (e.g. `"treasury_1_year"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Sofr30Day => "sofr_30_day",
+            Self::Treasury1Year => "treasury_1_year",
+            Self::Prime => "prime",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl std::str::FromStr for ArmIndex {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|index| index.code() == s)
+            .ok_or_else(|| format!("Invalid ARM index: {}", s))
+    }
+}
+
+// ===== ARM Models =====
+
+/// Adjustable-rate terms for a loan; the loan's note rate is the start rate
+///
+/// # Validation Rules
+/// - Initial fixed period and adjustment interval: 1-480 months
+/// - Margin, caps and floor: 0-25%
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow, Validate)]
+pub struct ArmTerms {
+    /// Loan these terms belong to
+    pub loan_id: i32,
+
+    /// Months at the start rate before the first adjustment
+    #[validate(range(min = 1, max = 480, message = "Initial fixed period must be 1-480 months"))]
+    pub initial_fixed_months: i32,
+
+    /// Months between later adjustments
+    #[validate(range(min = 1, max = 480, message = "Adjustment interval must be 1-480 months"))]
+    pub adjustment_interval_months: i32,
+
+    /// Index the rate follows
+    pub rate_index: ArmIndex,
+
+    /// Current index value, as a percentage
+    #[validate(range(min = 0.0, max = 25.0, message = "Index must be between 0% and 25%"))]
+    pub index_value: f64,
+
+    /// Margin added to the index, in percentage points
+    #[validate(range(min = 0.0, max = 25.0, message = "Margin must be between 0% and 25%"))]
+    pub margin: f64,
+
+    /// Most the rate may move at the first adjustment, in percentage points
+    #[validate(range(min = 0.0, max = 25.0, message = "Initial cap must be between 0% and 25%"))]
+    pub initial_cap: f64,
+
+    /// Most the rate may move at each later adjustment, in percentage points
+    #[validate(range(min = 0.0, max = 25.0, message = "Periodic cap must be between 0% and 25%"))]
+    pub periodic_cap: f64,
+
+    /// Most the rate may rise above the start rate over the life of the loan
+    #[validate(range(min = 0.0, max = 25.0, message = "Lifetime cap must be between 0% and 25%"))]
+    pub lifetime_cap: f64,
+
+    /// Lowest rate allowed; `None` means the margin
+    #[validate(range(min = 0.0, max = 25.0, message = "Floor must be between 0% and 25%"))]
+    pub floor_rate: Option<f64>,
+}
+
+impl ArmTerms {
+    /// Lowest rate the loan can adjust to
+    pub fn floor(&self) -> f64 {
+        self.floor_rate.unwrap_or(self.margin)
+    }
+
+    /// Short name in the usual "fixed years / interval months" form (e.g. `"5/6 ARM"`)
+    pub fn product_name(&self) -> String {
+        if self.initial_fixed_months % 12 == 0 {
+            format!("{}/{} ARM", self.initial_fixed_months / 12, self.adjustment_interval_months)
+        } else {
+            format!("{}-month/{} ARM", self.initial_fixed_months, self.adjustment_interval_months)
+        }
+    }
+}
+
+impl Default for ArmTerms {
+    /// A 5/6 SOFR ARM with 2/1/5 caps
+    fn default() -> Self {
+        Self {
+            loan_id: 0,
+            initial_fixed_months: 60,
+            adjustment_interval_months: 6,
+            rate_index: ArmIndex::default(),
+            index_value: 0.0,
+            margin: 2.75,
+            initial_cap: 2.0,
+            periodic_cap: 1.0,
+            lifetime_cap: 5.0,
+            floor_rate: None,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+// pg_app/shared/src/models/audit_models.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+/// Kind of action recorded in the audit log
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `audit_action`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, strum::Display, strum::EnumIter)]
+#[sqlx(type_name = "audit_action", rename_all = "snake_case")]
+pub enum AuditAction {
+    /// A duplicate borrower was merged into another
+    #[strum(serialize = "Borrower Merge")]
+    BorrowerMerge,
+
+    /// A borrower merge was undone
+    #[strum(serialize = "Borrower Merge Undone")]
+    BorrowerMergeUndo,
+
+    /// A masked SSN, date of birth or account number was shown in full
+    #[strum(serialize = "PII Revealed")]
+    PiiReveal,
+}
+
+impl AuditAction {
+    /// Database code for this action (e.g. `"borrower_merge"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::BorrowerMerge => "borrower_merge",
+            Self::BorrowerMergeUndo => "borrower_merge_undo",
+            Self::PiiReveal => "pii_reveal",
+        }
+    }
+}
+
+impl std::str::FromStr for AuditAction {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|action| action.code() == s)
+            .ok_or_else(|| format!("Invalid audit action: {}", s))
+    }
+}
+
+/// One entry in the append-only audit log
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct AuditEntry {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// What was done
+    pub action: AuditAction,
+
+    /// User who did it
+    pub actor_id: Option<i32>,
+
+    /// Kind of record acted on (e.g. `"borrower"`)
+    pub entity: String,
+
+    /// ID of the record acted on
+    pub entity_id: i32,
+
+    /// Human-readable description of the action
+    pub detail: String,
+
+    /// Timestamp of when the action was taken
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
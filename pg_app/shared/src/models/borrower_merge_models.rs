@@ -0,0 +1,252 @@
+// pg_app/shared/src/models/borrower_merge_models.rs
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+use super::borrower_models::Borrower;
+use crate::crypto::Sealed;
+
+/// Hours after a merge during which it can be undone
+pub const BORROWER_MERGE_UNDO_HOURS: i64 = 72;
+
+/// Last moment a merge made at `merged_at` can be undone
+pub fn merge_undo_deadline(merged_at: DateTime<Utc>) -> DateTime<Utc> {
+    merged_at + Duration::hours(BORROWER_MERGE_UNDO_HOURS)
+}
+
+/// A borrower field whose surviving value is chosen when merging
+///
+/// # Database Representation
+/// Stored as `SMALLINT` codes in `borrower_merges.taken_fields`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter)]
+#[repr(i16)]
+pub enum MergeField {
+    /// First name
+    #[strum(serialize = "First Name")]
+    FirstName = 1,
+    /// Last name
+    #[strum(serialize = "Last Name")]
+    LastName = 2,
+    /// Email address
+    Email = 3,
+    /// Phone number
+    Phone = 4,
+    /// Date of birth
+    #[strum(serialize = "Date of Birth")]
+    DateOfBirth = 5,
+    /// Social Security number
+    #[strum(serialize = "SSN")]
+    Ssn = 6,
+    /// Gross monthly income
+    #[strum(serialize = "Monthly Income")]
+    MonthlyIncome = 7,
+}
+
+impl MergeField {
+    /// Database code for this field
+    pub fn code(&self) -> i16 {
+        *self as i16
+    }
+
+    /// The field's value on `borrower` as shown when choosing; only the last
+    /// four digits of an SSN are shown
+    pub fn display(&self, borrower: &Borrower) -> String {
+        match self {
+            Self::FirstName => borrower.first_name.clone(),
+            Self::LastName => borrower.last_name.clone(),
+            Self::Email => borrower.email.clone().unwrap_or_default(),
+            Self::Phone => borrower.phone.clone().unwrap_or_default(),
+            Self::DateOfBirth => borrower
+                .date_of_birth
+                .map(|d| crate::masking::display_date_of_birth(d, borrower.pii_masked))
+                .unwrap_or_default(),
+            Self::Ssn => borrower.ssn.as_deref().map(crate::masking::mask_ssn).unwrap_or_default(),
+            Self::MonthlyIncome => crate::money::format_cents(borrower.monthly_income_cents),
+        }
+    }
+
+    /// Whether `borrower` has no value for the field
+    pub fn is_blank(&self, borrower: &Borrower) -> bool {
+        match self {
+            Self::FirstName => borrower.first_name.trim().is_empty(),
+            Self::LastName => borrower.last_name.trim().is_empty(),
+            Self::Email => borrower.email.as_deref().is_none_or(|email| email.trim().is_empty()),
+            Self::Phone => borrower.phone.as_deref().is_none_or(|phone| phone.trim().is_empty()),
+            Self::DateOfBirth => borrower.date_of_birth.is_none(),
+            Self::Ssn => borrower.ssn.is_none(),
+            Self::MonthlyIncome => borrower.monthly_income_cents == 0,
+        }
+    }
+
+    fn copy(&self, from: &Borrower, to: &mut Borrower) {
+        match self {
+            Self::FirstName => to.first_name = from.first_name.clone(),
+            Self::LastName => to.last_name = from.last_name.clone(),
+            Self::Email => to.email = from.email.clone(),
+            Self::Phone => to.phone = from.phone.clone(),
+            Self::DateOfBirth => to.date_of_birth = from.date_of_birth,
+            Self::Ssn => to.ssn = from.ssn.clone(),
+            Self::MonthlyIncome => to.monthly_income_cents = from.monthly_income_cents,
+        }
+    }
+}
+
+/// Which record each field's surviving value comes from
+///
+/// Fields not listed in `from_merged` keep the survivor's value.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MergeSelection {
+    /// Record that is kept
+    pub survivor_id: i32,
+
+    /// Record merged into the survivor and then hidden
+    pub merged_id: i32,
+
+    /// Fields whose value is taken from the merged record
+    pub from_merged: Vec<MergeField>,
+}
+
+impl MergeSelection {
+    /// Keeps the survivor's values, filling in the fields it lacks from the
+    /// merged record
+    pub fn new(survivor: &Borrower, merged: &Borrower) -> Self {
+        use strum::IntoEnumIterator;
+        Self {
+            survivor_id: survivor.id,
+            merged_id: merged.id,
+            from_merged: MergeField::iter()
+                .filter(|field| field.is_blank(survivor) && !field.is_blank(merged))
+                .collect(),
+        }
+    }
+
+    /// Whether the field's value is taken from the merged record
+    pub fn takes(&self, field: MergeField) -> bool {
+        self.from_merged.contains(&field)
+    }
+
+    /// Takes the field from the merged record, or keeps the survivor's
+    pub fn choose(&mut self, field: MergeField, from_merged: bool) {
+        self.from_merged.retain(|taken| *taken != field);
+        if from_merged {
+            self.from_merged.push(field);
+        }
+    }
+
+    /// The survivor as it will be after the merge
+    pub fn apply(&self, survivor: &Borrower, merged: &Borrower) -> Borrower {
+        let mut result = survivor.clone();
+        for field in &self.from_merged {
+            field.copy(merged, &mut result);
+        }
+        result
+    }
+}
+
+/// One merge of a duplicate borrower into the surviving record
+///
+/// The merged borrower is kept, hidden from lists, and the ids of every row
+/// moved to the survivor are recorded, so the merge can be undone within
+/// [`BORROWER_MERGE_UNDO_HOURS`]. Documents and tasks belong to loans and
+/// move with them.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct BorrowerMerge {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Record that was kept
+    pub survivor_id: i32,
+
+    /// Record merged into the survivor
+    pub merged_id: i32,
+
+    /// Fields the survivor took from the merged record
+    pub taken_fields: Vec<MergeField>,
+
+    /// Survivor's first name before the merge
+    pub prior_first_name: String,
+
+    /// Survivor's last name before the merge
+    pub prior_last_name: String,
+
+    /// Survivor's email before the merge
+    pub prior_email: Option<String>,
+
+    /// Survivor's phone before the merge
+    pub prior_phone: Option<String>,
+
+    /// Survivor's date of birth before the merge; sealed at rest
+    #[sqlx(try_from = "Sealed<NaiveDate>")]
+    pub prior_date_of_birth: Option<NaiveDate>,
+
+    /// Survivor's SSN before the merge; sealed at rest
+    #[sqlx(try_from = "Sealed<String>")]
+    pub prior_ssn: Option<String>,
+
+    /// Survivor's monthly income in cents before the merge
+    pub prior_monthly_income_cents: i64,
+
+    /// Loans moved to the survivor as primary applicant
+    pub loan_ids: Vec<i32>,
+
+    /// Loans on which the survivor replaced the merged record as co-applicant
+    pub co_borrower_loan_ids: Vec<i32>,
+
+    /// Borrower notes moved to the survivor
+    pub note_ids: Vec<i32>,
+
+    /// Liabilities moved to the survivor
+    pub liability_ids: Vec<i32>,
+
+    /// Employments moved to the survivor
+    pub employment_ids: Vec<i32>,
+
+    /// Assets moved to the survivor
+    pub asset_ids: Vec<i32>,
+
+    /// User who merged the records
+    pub merged_by: Option<i32>,
+
+    /// Timestamp of the merge
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub merged_at: DateTime<Utc>,
+
+    /// User who undid the merge
+    pub undone_by: Option<i32>,
+
+    /// Timestamp of when the merge was undone
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub undone_at: Option<DateTime<Utc>>,
+}
+
+impl BorrowerMerge {
+    /// Last moment the merge can be undone
+    pub fn undo_deadline(&self) -> DateTime<Utc> {
+        merge_undo_deadline(self.merged_at)
+    }
+
+    /// Whether the merge can still be undone at `now`
+    pub fn can_undo(&self, now: DateTime<Utc>) -> bool {
+        self.undone_at.is_none() && now <= self.undo_deadline()
+    }
+
+    /// The survivor with its pre-merge values put back in the fields it
+    /// took from the merged record; later edits to other fields are kept
+    pub fn restore(&self, survivor: &Borrower) -> Borrower {
+        let prior = Borrower {
+            first_name: self.prior_first_name.clone(),
+            last_name: self.prior_last_name.clone(),
+            email: self.prior_email.clone(),
+            phone: self.prior_phone.clone(),
+            date_of_birth: self.prior_date_of_birth,
+            ssn: self.prior_ssn.clone(),
+            monthly_income_cents: self.prior_monthly_income_cents,
+            ..survivor.clone()
+        };
+        let mut result = survivor.clone();
+        for field in &self.taken_fields {
+            field.copy(&prior, &mut result);
+        }
+        result
+    }
+}
@@ -0,0 +1,530 @@
+// pg_app/shared/src/models/borrower_models.rs
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use validator::Validate;
+use crate::crypto::Sealed;
+
+// ===== Borrower Model =====
+
+/// A loan applicant
+///
+/// # Validation Rules
+/// - Names: 1-100 characters
+/// - Email: Valid format (if provided)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow, Validate)]
+pub struct Borrower {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// First name (1-100 characters)
+    #[validate(length(min = 1, max = 100, message = "First name must be 1-100 characters"))]
+    pub first_name: String,
+
+    /// Last name (1-100 characters)
+    #[validate(length(min = 1, max = 100, message = "Last name must be 1-100 characters"))]
+    pub last_name: String,
+
+    /// Contact email address
+    #[validate(email(message = "Must be a valid email address"))]
+    pub email: Option<String>,
+
+    /// Contact phone number
+    #[validate(length(max = 30, message = "Phone must be at most 30 characters"))]
+    pub phone: Option<String>,
+
+    /// Gross monthly income in cents
+    #[validate(range(min = 0, message = "Monthly income cannot be negative"))]
+    pub monthly_income_cents: i64,
+
+    /// Social Security number, nine digits without dashes; sealed at rest
+    #[validate(length(equal = 9, message = "SSN must be 9 digits"))]
+    #[sqlx(try_from = "Sealed<String>")]
+    pub ssn: Option<String>,
+
+    /// Date of birth; sealed at rest
+    #[sqlx(try_from = "Sealed<NaiveDate>")]
+    pub date_of_birth: Option<NaiveDate>,
+
+    /// Whether the SSN and date of birth were masked for a response; a
+    /// masked date of birth keeps only its year
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub pii_masked: bool,
+
+    /// Timestamp of when the borrower was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the borrower was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Borrower {
+    /// Returns the borrower's full name
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+}
+
+/// Fields supplied when creating or updating a borrower
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct BorrowerInput {
+    /// First name (1-100 characters)
+    #[validate(length(min = 1, max = 100, message = "First name must be 1-100 characters"))]
+    pub first_name: String,
+
+    /// Last name (1-100 characters)
+    #[validate(length(min = 1, max = 100, message = "Last name must be 1-100 characters"))]
+    pub last_name: String,
+
+    /// Contact email address
+    #[validate(email(message = "Must be a valid email address"))]
+    pub email: Option<String>,
+
+    /// Contact phone number
+    #[validate(length(max = 30, message = "Phone must be at most 30 characters"))]
+    pub phone: Option<String>,
+
+    /// Gross monthly income in cents
+    #[validate(range(min = 0, message = "Monthly income cannot be negative"))]
+    pub monthly_income_cents: i64,
+}
+
+// ===== Liability Model =====
+
+/// Category of a debt owed by a borrower
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `liability_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "liability_type", rename_all = "snake_case")]
+pub enum LiabilityType {
+    /// Auto loan or lease
+    #[strum(serialize = "Auto")]
+    Auto,
+
+    /// Student loan
+    #[strum(serialize = "Student")]
+    Student,
+
+    /// Credit card or other revolving account
+    #[strum(serialize = "Revolving")]
+    #[default]
+    Revolving,
+
+    /// Mortgage on another property
+    #[strum(serialize = "Mortgage")]
+    Mortgage,
+
+    /// Other installment loan
+    #[strum(serialize = "Installment")]
+    Installment,
+
+    /// Anything not covered above
+    #[strum(serialize = "Other")]
+    Other,
+}
+
+impl LiabilityType {
+    /// Database/import code for this type (e.g. `"student"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Student => "student",
+            Self::Revolving => "revolving",
+            Self::Mortgage => "mortgage",
+            Self::Installment => "installment",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl std::str::FromStr for LiabilityType {
+    type Err = String;
+
+    /// Accepts the database codes plus common credit report spellings
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace([' ', '-'], "_").as_str() {
+            "auto" | "auto_loan" | "lease" => Ok(Self::Auto),
+            "student" | "student_loan" | "education" => Ok(Self::Student),
+            "revolving" | "credit_card" | "revolving_charge" => Ok(Self::Revolving),
+            "mortgage" | "mortgage_loan" | "real_estate" => Ok(Self::Mortgage),
+            "installment" => Ok(Self::Installment),
+            "other" => Ok(Self::Other),
+            _ => Err(format!("Invalid liability type: {}", s)),
+        }
+    }
+}
+
+/// A single debt (credit tradeline) owed by a borrower
+///
+/// Liabilities flagged `paid_off_at_closing` or `excluded` are kept for the
+/// record but do not count toward the monthly debt used in DTI.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Liability {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Borrower who owes the debt
+    pub borrower_id: i32,
+
+    /// Category of the debt
+    pub liability_type: LiabilityType,
+
+    /// Name of the creditor
+    pub creditor_name: String,
+
+    /// Last four digits of the account number
+    pub account_last4: Option<String>,
+
+    /// Unpaid balance in cents
+    pub balance_cents: i64,
+
+    /// Required monthly payment in cents
+    pub monthly_payment_cents: i64,
+
+    /// Debt will be paid off from loan proceeds at closing
+    pub paid_off_at_closing: bool,
+
+    /// Debt is excluded from DTI (e.g. fewer than 10 payments remaining)
+    pub excluded: bool,
+
+    /// Where the record came from (`manual`, `credit_report`, `mismo` or `fnm`)
+    pub source: String,
+
+    /// Timestamp of when the liability was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the liability was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Liability {
+    /// Whether this debt counts toward monthly obligations for DTI
+    pub fn counts_toward_dti(&self) -> bool {
+        !self.paid_off_at_closing && !self.excluded
+    }
+}
+
+impl From<&Liability> for LiabilityInput {
+    fn from(liability: &Liability) -> Self {
+        Self {
+            liability_type: liability.liability_type,
+            creditor_name: liability.creditor_name.clone(),
+            account_last4: liability.account_last4.clone(),
+            balance_cents: liability.balance_cents,
+            monthly_payment_cents: liability.monthly_payment_cents,
+            paid_off_at_closing: liability.paid_off_at_closing,
+            excluded: liability.excluded,
+        }
+    }
+}
+
+/// Fields supplied when creating or updating a liability
+///
+/// # Validation Rules
+/// - Creditor name: 1-100 characters
+/// - Account last four: exactly 4 characters (if provided)
+/// - Amounts: Not negative
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct LiabilityInput {
+    /// Category of the debt
+    pub liability_type: LiabilityType,
+
+    /// Name of the creditor (1-100 characters)
+    #[validate(length(min = 1, max = 100, message = "Creditor name must be 1-100 characters"))]
+    pub creditor_name: String,
+
+    /// Last four digits of the account number
+    #[validate(length(equal = 4, message = "Account must be the last 4 digits"))]
+    pub account_last4: Option<String>,
+
+    /// Unpaid balance in cents
+    #[validate(range(min = 0, message = "Balance cannot be negative"))]
+    pub balance_cents: i64,
+
+    /// Required monthly payment in cents
+    #[validate(range(min = 0, message = "Monthly payment cannot be negative"))]
+    pub monthly_payment_cents: i64,
+
+    /// Debt will be paid off at closing
+    #[serde(default)]
+    pub paid_off_at_closing: bool,
+
+    /// Debt is excluded from DTI
+    #[serde(default)]
+    pub excluded: bool,
+}
+
+// ===== Employment Model =====
+
+/// A job held by a borrower, current or previous
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Employment {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Borrower who holds the job
+    pub borrower_id: i32,
+
+    /// Name of the employer (or the borrower's business)
+    pub employer_name: String,
+
+    /// Position or title
+    pub position: Option<String>,
+
+    /// Borrower owns the business
+    pub self_employed: bool,
+
+    /// The primary current job, whose income is the borrower's monthly income
+    pub is_primary: bool,
+
+    /// Job is still held
+    pub is_current: bool,
+
+    /// First day on the job (secondary and previous jobs)
+    pub start_date: Option<NaiveDate>,
+
+    /// Last day on the job (previous jobs)
+    pub end_date: Option<NaiveDate>,
+
+    /// Time on the job in months (primary job)
+    pub months_on_job: Option<i32>,
+
+    /// Years in this line of work (primary job)
+    pub years_in_profession: Option<i32>,
+
+    /// Monthly income in cents for secondary and previous jobs
+    pub monthly_income_cents: i64,
+
+    /// Where the record came from (`manual` or `fnm`)
+    pub source: String,
+
+    /// Timestamp of when the employment was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the employment was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Employment> for EmploymentInput {
+    fn from(employment: &Employment) -> Self {
+        Self {
+            employer_name: employment.employer_name.clone(),
+            position: employment.position.clone(),
+            self_employed: employment.self_employed,
+            is_primary: employment.is_primary,
+            is_current: employment.is_current,
+            start_date: employment.start_date,
+            end_date: employment.end_date,
+            months_on_job: employment.months_on_job,
+            years_in_profession: employment.years_in_profession,
+            monthly_income_cents: employment.monthly_income_cents,
+        }
+    }
+}
+
+/// Fields supplied when creating an employment record
+///
+/// # Validation Rules
+/// - Employer name: 1-100 characters
+/// - Position: at most 100 characters
+/// - Durations and income: Not negative
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct EmploymentInput {
+    /// Name of the employer (1-100 characters)
+    #[validate(length(min = 1, max = 100, message = "Employer name must be 1-100 characters"))]
+    pub employer_name: String,
+
+    /// Position or title
+    #[validate(length(max = 100, message = "Position must be at most 100 characters"))]
+    pub position: Option<String>,
+
+    /// Borrower owns the business
+    #[serde(default)]
+    pub self_employed: bool,
+
+    /// The primary current job
+    #[serde(default)]
+    pub is_primary: bool,
+
+    /// Job is still held
+    #[serde(default)]
+    pub is_current: bool,
+
+    /// First day on the job
+    pub start_date: Option<NaiveDate>,
+
+    /// Last day on the job
+    pub end_date: Option<NaiveDate>,
+
+    /// Time on the job in months
+    #[validate(range(min = 0, message = "Months on job cannot be negative"))]
+    pub months_on_job: Option<i32>,
+
+    /// Years in this line of work
+    #[validate(range(min = 0, message = "Years in profession cannot be negative"))]
+    pub years_in_profession: Option<i32>,
+
+    /// Monthly income in cents for secondary and previous jobs
+    #[validate(range(min = 0, message = "Monthly income cannot be negative"))]
+    pub monthly_income_cents: i64,
+}
+
+// ===== Asset Model =====
+
+/// Category of an account or other asset held by a borrower
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `asset_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "asset_type", rename_all = "snake_case")]
+pub enum AssetType {
+    /// Checking account
+    #[default]
+    #[strum(serialize = "Checking")]
+    Checking,
+
+    /// Savings account
+    #[strum(serialize = "Savings")]
+    Savings,
+
+    /// Certificate of deposit
+    #[strum(serialize = "Certificate of Deposit")]
+    CertificateOfDeposit,
+
+    /// Money market fund
+    #[strum(serialize = "Money Market")]
+    MoneyMarket,
+
+    /// Stocks
+    #[strum(serialize = "Stocks")]
+    Stocks,
+
+    /// Bonds
+    #[strum(serialize = "Bonds")]
+    Bonds,
+
+    /// 401(k), IRA or other retirement account
+    #[strum(serialize = "Retirement")]
+    Retirement,
+
+    /// Gift of funds toward closing
+    #[strum(serialize = "Gift")]
+    Gift,
+
+    /// Anything not covered above
+    #[strum(serialize = "Other")]
+    Other,
+}
+
+impl AssetType {
+    /// Database code for this type (e.g. `"money_market"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Checking => "checking",
+            Self::Savings => "savings",
+            Self::CertificateOfDeposit => "certificate_of_deposit",
+            Self::MoneyMarket => "money_market",
+            Self::Stocks => "stocks",
+            Self::Bonds => "bonds",
+            Self::Retirement => "retirement",
+            Self::Gift => "gift",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl std::str::FromStr for AssetType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|asset_type| asset_type.code() == s)
+            .ok_or_else(|| format!("Invalid asset type: {}", s))
+    }
+}
+
+/// An account or other asset a borrower can draw on for closing and reserves
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Asset {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Borrower who holds the asset
+    pub borrower_id: i32,
+
+    /// Category of the asset
+    pub asset_type: AssetType,
+
+    /// Bank, brokerage or donor holding the funds
+    pub institution_name: String,
+
+    /// Last four digits of the account number
+    pub account_last4: Option<String>,
+
+    /// Full account number, when known; sealed at rest
+    #[sqlx(try_from = "Sealed<String>")]
+    pub account_number: Option<String>,
+
+    /// Cash or market value in cents
+    pub balance_cents: i64,
+
+    /// Where the record came from (`manual` or `fnm`)
+    pub source: String,
+
+    /// Timestamp of when the asset was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the asset was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Asset> for AssetInput {
+    fn from(asset: &Asset) -> Self {
+        Self {
+            asset_type: asset.asset_type,
+            institution_name: asset.institution_name.clone(),
+            account_last4: asset.account_last4.clone(),
+            account_number: asset.account_number.clone(),
+            balance_cents: asset.balance_cents,
+        }
+    }
+}
+
+/// Fields supplied when creating an asset
+///
+/// # Validation Rules
+/// - Institution name: 1-100 characters
+/// - Account last four: exactly 4 characters (if provided)
+/// - Balance: Not negative
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct AssetInput {
+    /// Category of the asset
+    pub asset_type: AssetType,
+
+    /// Bank, brokerage or donor holding the funds (1-100 characters)
+    #[validate(length(min = 1, max = 100, message = "Institution name must be 1-100 characters"))]
+    pub institution_name: String,
+
+    /// Last four digits of the account number
+    #[validate(length(equal = 4, message = "Account must be the last 4 digits"))]
+    pub account_last4: Option<String>,
+
+    /// Full account number, when known
+    #[serde(default)]
+    #[validate(length(max = 30, message = "Account number must be at most 30 characters"))]
+    pub account_number: Option<String>,
+
+    /// Cash or market value in cents
+    #[validate(range(min = 0, message = "Balance cannot be negative"))]
+    pub balance_cents: i64,
+}
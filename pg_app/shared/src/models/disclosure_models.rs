@@ -0,0 +1,134 @@
+// pg_app/shared/src/models/disclosure_models.rs
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+use super::fee_models::{FeePayer, FeeSection};
+
+// ===== Disclosure Enums =====
+
+/// Which TRID form was issued
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `disclosure_kind`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "disclosure_kind", rename_all = "snake_case")]
+pub enum DisclosureKind {
+    /// Loan Estimate (initial or revised)
+    #[default]
+    #[strum(serialize = "Loan Estimate")]
+    LoanEstimate,
+    /// Closing Disclosure (initial or corrected)
+    #[strum(serialize = "Closing Disclosure")]
+    ClosingDisclosure,
+}
+
+impl DisclosureKind {
+    /// Database code for this kind (e.g. `"loan_estimate"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::LoanEstimate => "loan_estimate",
+            Self::ClosingDisclosure => "closing_disclosure",
+        }
+    }
+
+    /// Short form name (`"LE"` or `"CD"`)
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::LoanEstimate => "LE",
+            Self::ClosingDisclosure => "CD",
+        }
+    }
+}
+
+impl std::str::FromStr for DisclosureKind {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|kind| kind.code() == s)
+            .ok_or_else(|| format!("Invalid disclosure kind: {}", s))
+    }
+}
+
+// ===== Disclosure Models =====
+
+/// An issued Loan Estimate or Closing Disclosure
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Disclosure {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Loan the disclosure was issued for
+    pub loan_id: i32,
+
+    /// Which form was issued
+    pub kind: DisclosureKind,
+
+    /// Date the disclosure was issued
+    pub issued_date: NaiveDate,
+
+    /// User who issued it
+    pub issued_by: Option<i32>,
+
+    /// General lender credits at issue, in cents
+    pub lender_credits_cents: i64,
+
+    /// Timestamp of when the snapshot was taken
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl Disclosure {
+    /// Label for pickers, e.g. `"LE issued 06/04/2025"`
+    pub fn label(&self) -> String {
+        format!("{} issued {}", self.kind.abbreviation(), self.issued_date.format("%m/%d/%Y"))
+    }
+}
+
+/// Fields supplied when issuing a disclosure
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DisclosureInput {
+    /// Which form is being issued
+    pub kind: DisclosureKind,
+
+    /// Date it is issued
+    pub issued_date: NaiveDate,
+}
+
+/// A fee as it stood on an issued disclosure
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct DisclosureFee {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Disclosure the fee was frozen on
+    pub disclosure_id: i32,
+
+    /// Disclosure section
+    pub section: FeeSection,
+
+    /// Fee description
+    pub name: String,
+
+    /// Amount in cents
+    pub amount_cents: i64,
+
+    /// Who pays it
+    pub payer: FeePayer,
+
+    /// Who receives it
+    pub paid_to: Option<String>,
+
+    /// Counts toward the finance charge
+    pub finance_charge: bool,
+
+    /// Borrower shopped for a provider not on the lender's written list (Section C)
+    pub off_list_provider: bool,
+
+    /// Government recording fee, in the 10% tolerance bucket (Section E)
+    pub recording_fee: bool,
+
+    /// Worksheet fee this was copied from; `None` for snapshots taken before it was recorded
+    pub loan_fee_id: Option<i32>,
+}
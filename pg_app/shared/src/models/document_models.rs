@@ -0,0 +1,83 @@
+// pg_app/shared/src/models/document_models.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+// ===== Document Enums =====
+
+/// Which generated document a file is
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `document_kind`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "document_kind", rename_all = "snake_case")]
+pub enum DocumentKind {
+    /// Letter stating the borrower is pre-approved for the loan terms
+    #[default]
+    #[strum(serialize = "Pre-Approval Letter")]
+    PreApprovalLetter,
+    /// One-page summary of the borrower, property and loan terms
+    #[strum(serialize = "Loan Summary")]
+    LoanSummary,
+    /// Month-by-month principal and interest schedule
+    #[strum(serialize = "Amortization Schedule")]
+    AmortizationSchedule,
+    /// ECOA statement of credit denial with its principal reasons
+    #[strum(serialize = "Adverse Action Notice")]
+    AdverseActionNotice,
+}
+
+impl DocumentKind {
+    /// Database code for this kind (e.g. `"loan_summary"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::PreApprovalLetter => "pre_approval_letter",
+            Self::LoanSummary => "loan_summary",
+            Self::AmortizationSchedule => "amortization_schedule",
+            Self::AdverseActionNotice => "adverse_action_notice",
+        }
+    }
+}
+
+impl std::str::FromStr for DocumentKind {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|kind| kind.code() == s)
+            .ok_or_else(|| format!("Invalid document kind: {}", s))
+    }
+}
+
+// ===== Document Models =====
+
+/// A generated file saved on a loan
+///
+/// The file itself stays in the database until it is downloaded.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct LoanDocument {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Loan the document belongs to
+    pub loan_id: i32,
+
+    /// Which document it is
+    pub kind: DocumentKind,
+
+    /// Download name, e.g. `loan-summary-LN-0042-2025-05-19.pdf`
+    pub file_name: String,
+
+    /// MIME type of the file
+    pub content_type: String,
+
+    /// File size in bytes
+    pub size_bytes: i32,
+
+    /// User who generated it
+    pub created_by: Option<i32>,
+
+    /// Timestamp of when it was generated
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
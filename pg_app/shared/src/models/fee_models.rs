@@ -0,0 +1,247 @@
+// pg_app/shared/src/models/fee_models.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use validator::Validate;
+
+// ===== Fee Enums =====
+
+/// Loan Estimate / Closing Disclosure section a fee is disclosed in
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `fee_section`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "fee_section", rename_all = "snake_case")]
+pub enum FeeSection {
+    /// A. Origination Charges
+    #[default]
+    #[strum(serialize = "Origination Charges")]
+    Origination,
+    /// B. Services You Cannot Shop For
+    #[strum(serialize = "Services You Cannot Shop For")]
+    CannotShop,
+    /// C. Services You Can Shop For
+    #[strum(serialize = "Services You Can Shop For")]
+    CanShop,
+    /// E. Taxes and Other Government Fees
+    #[strum(serialize = "Taxes and Other Government Fees")]
+    TaxesGovernment,
+    /// F. Prepaids
+    Prepaids,
+    /// G. Initial Escrow Payment at Closing
+    #[strum(serialize = "Initial Escrow Payment at Closing")]
+    InitialEscrow,
+}
+
+impl FeeSection {
+    /// Database code for this section (e.g. `"cannot_shop"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Origination => "origination",
+            Self::CannotShop => "cannot_shop",
+            Self::CanShop => "can_shop",
+            Self::TaxesGovernment => "taxes_government",
+            Self::Prepaids => "prepaids",
+            Self::InitialEscrow => "initial_escrow",
+        }
+    }
+
+    /// Section letter on the disclosure forms
+    pub fn letter(&self) -> char {
+        match self {
+            Self::Origination => 'A',
+            Self::CannotShop => 'B',
+            Self::CanShop => 'C',
+            Self::TaxesGovernment => 'E',
+            Self::Prepaids => 'F',
+            Self::InitialEscrow => 'G',
+        }
+    }
+
+    /// Whether the section is part of Loan Costs (D) rather than Other Costs (I)
+    pub fn is_loan_cost(&self) -> bool {
+        matches!(self, Self::Origination | Self::CannotShop | Self::CanShop)
+    }
+}
+
+impl std::str::FromStr for FeeSection {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|section| section.code() == s)
+            .ok_or_else(|| format!("Invalid fee section: {}", s))
+    }
+}
+
+/// Who pays a fee
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `fee_payer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "fee_payer", rename_all = "snake_case")]
+pub enum FeePayer {
+    /// Paid by the borrower at or before closing
+    #[default]
+    Borrower,
+    /// Paid by the seller
+    Seller,
+    /// Paid by the lender
+    Lender,
+    /// Paid by someone else (e.g. a builder or employer)
+    Other,
+}
+
+impl FeePayer {
+    /// Database code for this payer (e.g. `"seller"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Borrower => "borrower",
+            Self::Seller => "seller",
+            Self::Lender => "lender",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl std::str::FromStr for FeePayer {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|payer| payer.code() == s)
+            .ok_or_else(|| format!("Invalid fee payer: {}", s))
+    }
+}
+
+// ===== Fee Models =====
+
+/// One line of a loan's closing cost worksheet
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct LoanFee {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Loan the fee belongs to
+    pub loan_id: i32,
+
+    /// Disclosure section
+    pub section: FeeSection,
+
+    /// Fee description (e.g. "Appraisal Fee")
+    pub name: String,
+
+    /// Amount in cents
+    pub amount_cents: i64,
+
+    /// Who pays it
+    pub payer: FeePayer,
+
+    /// Who receives it
+    pub paid_to: Option<String>,
+
+    /// Counts toward the finance charge
+    pub finance_charge: bool,
+
+    /// Borrower shopped for a provider not on the lender's written list (Section C)
+    pub off_list_provider: bool,
+
+    /// Government recording fee, in the 10% tolerance bucket (Section E)
+    pub recording_fee: bool,
+
+    /// Timestamp of when the fee was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the fee was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LoanFee {
+    /// Whether the borrower bears this fee
+    pub fn is_borrower_paid(&self) -> bool {
+        self.payer == FeePayer::Borrower
+    }
+}
+
+/// Fields supplied when creating or updating a fee
+///
+/// # Validation Rules
+/// - Name: 1-200 characters
+/// - Amount: Zero or more
+/// - Paid to: At most 200 characters
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct LoanFeeInput {
+    /// Disclosure section
+    pub section: FeeSection,
+
+    /// Fee description
+    #[validate(length(min = 1, max = 200, message = "Fee name must be 1-200 characters"))]
+    pub name: String,
+
+    /// Amount in cents
+    #[validate(range(min = 0, message = "Amount cannot be negative"))]
+    pub amount_cents: i64,
+
+    /// Who pays it
+    pub payer: FeePayer,
+
+    /// Who receives it
+    #[validate(length(max = 200, message = "Paid to must be at most 200 characters"))]
+    pub paid_to: Option<String>,
+
+    /// Counts toward the finance charge
+    pub finance_charge: bool,
+
+    /// Borrower shopped for a provider not on the lender's written list (Section C)
+    pub off_list_provider: bool,
+
+    /// Government recording fee, in the 10% tolerance bucket (Section E)
+    pub recording_fee: bool,
+}
+
+impl From<&LoanFee> for LoanFeeInput {
+    fn from(fee: &LoanFee) -> Self {
+        Self {
+            section: fee.section,
+            name: fee.name.clone(),
+            amount_cents: fee.amount_cents,
+            payer: fee.payer,
+            paid_to: fee.paid_to.clone(),
+            finance_charge: fee.finance_charge,
+            off_list_provider: fee.off_list_provider,
+            recording_fee: fee.recording_fee,
+        }
+    }
+}
+
+/// Deposits, credits and payoffs that feed cash to close
+///
+/// # Validation Rules
+/// - All amounts: Zero or more
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, FromRow, Validate)]
+pub struct ClosingAdjustments {
+    /// Loan these adjustments belong to
+    pub loan_id: i32,
+
+    /// Earnest money deposit already paid, in cents
+    #[validate(range(min = 0, message = "Deposit cannot be negative"))]
+    pub earnest_money_cents: i64,
+
+    /// Seller credits toward the borrower's costs, in cents
+    #[validate(range(min = 0, message = "Seller credits cannot be negative"))]
+    pub seller_credits_cents: i64,
+
+    /// General lender credits, in cents
+    #[validate(range(min = 0, message = "Lender credits cannot be negative"))]
+    pub lender_credits_cents: i64,
+
+    /// Existing liens paid off by a refinance, in cents
+    #[validate(range(min = 0, message = "Payoffs cannot be negative"))]
+    pub payoffs_cents: i64,
+
+    /// APR on the most recent disclosure sent to the borrower, as a percentage
+    #[validate(range(min = 0.0, max = 100.0, message = "Disclosed APR must be between 0% and 100%"))]
+    pub disclosed_apr: Option<f64>,
+}
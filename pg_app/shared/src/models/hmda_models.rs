@@ -0,0 +1,831 @@
+// pg_app/shared/src/models/hmda_models.rs
+use chrono::{DateTime, NaiveDate, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use validator::{Validate, ValidationError};
+
+use super::loan_models::LoanStatus;
+
+lazy_static! {
+    /// Five digit county FIPS code (state + county)
+    static ref COUNTY_REGEX: Regex = Regex::new(r"^[0-9]{5}$").unwrap();
+    /// Eleven digit census tract (state + county + tract)
+    static ref CENSUS_TRACT_REGEX: Regex = Regex::new(r"^[0-9]{11}$").unwrap();
+    /// Legal Entity Identifier: 20 letters or digits
+    static ref LEI_REGEX: Regex = Regex::new(r"^[A-Z0-9]{20}$").unwrap();
+    /// Federal taxpayer ID as 99-9999999
+    static ref TAX_ID_REGEX: Regex = Regex::new(r"^[0-9]{2}-[0-9]{7}$").unwrap();
+    /// Phone as 999-999-9999
+    static ref PHONE_REGEX: Regex = Regex::new(r"^[0-9]{3}-[0-9]{3}-[0-9]{4}$").unwrap();
+    /// Two-letter uppercase state code
+    static ref STATE_REGEX: Regex = Regex::new(r"^[A-Z]{2}$").unwrap();
+    /// Five digit ZIP with optional +4
+    static ref ZIP_REGEX: Regex = Regex::new(r"^[0-9]{5}(-[0-9]{4})?$").unwrap();
+}
+
+/// Most ethnicity or race selections reported per applicant
+pub const HMDA_MAX_SELECTIONS: usize = 5;
+
+/// Most denial reasons reported per application
+pub const HMDA_MAX_DENIAL_REASONS: usize = 4;
+
+// ===== HMDA Code Enums =====
+// Each variant's discriminant is its code in the FFIEC Filing Instructions
+// Guide, which is also what the database stores.
+
+/// Adds `code()` and parsing from the code to enums whose discriminant is
+/// the HMDA code
+macro_rules! hmda_codes {
+    ($($name:ident),* $(,)?) => {$(
+        impl $name {
+            /// HMDA code for this value
+            pub fn code(&self) -> i16 {
+                *self as i16
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                use strum::IntoEnumIterator;
+                Self::iter()
+                    .find(|value| value.code().to_string() == s.trim())
+                    .ok_or_else(|| format!("Invalid {} code: {}", stringify!($name), s))
+            }
+        }
+    )*};
+}
+
+hmda_codes!(
+    ActionTaken,
+    Preapproval,
+    Ethnicity,
+    Race,
+    Sex,
+    ObservationBasis,
+    CreditScoreModel,
+    DenialReason,
+    LienStatus,
+    HoepaStatus,
+    PurchaserType,
+    FederalAgency,
+);
+
+/// Final action on an application, as reported in HMDA
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter)]
+#[repr(i16)]
+pub enum ActionTaken {
+    /// Loan originated
+    #[strum(serialize = "Loan Originated")]
+    Originated = 1,
+    /// Application approved but not accepted
+    #[strum(serialize = "Approved, Not Accepted")]
+    ApprovedNotAccepted = 2,
+    /// Application denied
+    #[strum(serialize = "Application Denied")]
+    Denied = 3,
+    /// Application withdrawn by applicant
+    #[strum(serialize = "Withdrawn by Applicant")]
+    Withdrawn = 4,
+    /// File closed for incompleteness
+    #[strum(serialize = "File Closed for Incompleteness")]
+    Incomplete = 5,
+    /// Purchased loan
+    #[strum(serialize = "Purchased Loan")]
+    Purchased = 6,
+    /// Preapproval request denied
+    #[strum(serialize = "Preapproval Request Denied")]
+    PreapprovalDenied = 7,
+    /// Preapproval request approved but not accepted
+    #[strum(serialize = "Preapproval Approved, Not Accepted")]
+    PreapprovalNotAccepted = 8,
+}
+
+impl ActionTaken {
+    /// Action implied by a final pipeline status, used to prefill the form
+    pub fn from_status(status: LoanStatus) -> Option<Self> {
+        match status {
+            LoanStatus::Closed => Some(Self::Originated),
+            LoanStatus::Denied => Some(Self::Denied),
+            LoanStatus::Withdrawn => Some(Self::Withdrawn),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the application came through a preapproval program
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[repr(i16)]
+pub enum Preapproval {
+    /// Preapproval requested
+    #[strum(serialize = "Preapproval Requested")]
+    Requested = 1,
+    /// Preapproval not requested
+    #[default]
+    #[strum(serialize = "Preapproval Not Requested")]
+    NotRequested = 2,
+}
+
+/// Applicant ethnicity; the aggregate and its detailed categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter)]
+#[repr(i16)]
+pub enum Ethnicity {
+    /// Hispanic or Latino
+    #[strum(serialize = "Hispanic or Latino")]
+    HispanicOrLatino = 1,
+    /// Mexican
+    Mexican = 11,
+    /// Puerto Rican
+    #[strum(serialize = "Puerto Rican")]
+    PuertoRican = 12,
+    /// Cuban
+    Cuban = 13,
+    /// Other Hispanic or Latino
+    #[strum(serialize = "Other Hispanic or Latino")]
+    OtherHispanicOrLatino = 14,
+    /// Not Hispanic or Latino
+    #[strum(serialize = "Not Hispanic or Latino")]
+    NotHispanicOrLatino = 2,
+    /// Applicant did not provide the information in a mail, internet or telephone application
+    #[strum(serialize = "Information Not Provided")]
+    NotProvided = 3,
+    /// Not applicable
+    #[strum(serialize = "Not Applicable")]
+    NotApplicable = 4,
+}
+
+impl Ethnicity {
+    /// Whether this is one of the two aggregate categories, the only ones a
+    /// lender may record from visual observation or surname
+    pub fn is_aggregate(&self) -> bool {
+        matches!(self, Self::HispanicOrLatino | Self::NotHispanicOrLatino)
+    }
+
+    /// Whether this code stands alone rather than describing the applicant
+    pub fn is_exclusive(&self) -> bool {
+        matches!(self, Self::NotProvided | Self::NotApplicable)
+    }
+}
+
+/// Applicant race; the aggregate and its detailed categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter)]
+#[repr(i16)]
+pub enum Race {
+    /// American Indian or Alaska Native
+    #[strum(serialize = "American Indian or Alaska Native")]
+    AmericanIndianOrAlaskaNative = 1,
+    /// Asian
+    Asian = 2,
+    /// Asian Indian
+    #[strum(serialize = "Asian Indian")]
+    AsianIndian = 21,
+    /// Chinese
+    Chinese = 22,
+    /// Filipino
+    Filipino = 23,
+    /// Japanese
+    Japanese = 24,
+    /// Korean
+    Korean = 25,
+    /// Vietnamese
+    Vietnamese = 26,
+    /// Other Asian
+    #[strum(serialize = "Other Asian")]
+    OtherAsian = 27,
+    /// Black or African American
+    #[strum(serialize = "Black or African American")]
+    BlackOrAfricanAmerican = 3,
+    /// Native Hawaiian or Other Pacific Islander
+    #[strum(serialize = "Native Hawaiian or Other Pacific Islander")]
+    PacificIslander = 4,
+    /// Native Hawaiian
+    #[strum(serialize = "Native Hawaiian")]
+    NativeHawaiian = 41,
+    /// Guamanian or Chamorro
+    #[strum(serialize = "Guamanian or Chamorro")]
+    GuamanianOrChamorro = 42,
+    /// Samoan
+    Samoan = 43,
+    /// Other Pacific Islander
+    #[strum(serialize = "Other Pacific Islander")]
+    OtherPacificIslander = 44,
+    /// White
+    White = 5,
+    /// Applicant did not provide the information in a mail, internet or telephone application
+    #[strum(serialize = "Information Not Provided")]
+    NotProvided = 6,
+    /// Not applicable
+    #[strum(serialize = "Not Applicable")]
+    NotApplicable = 7,
+}
+
+impl Race {
+    /// Whether this is one of the five aggregate categories, the only ones a
+    /// lender may record from visual observation or surname
+    pub fn is_aggregate(&self) -> bool {
+        matches!(
+            self,
+            Self::AmericanIndianOrAlaskaNative | Self::Asian | Self::BlackOrAfricanAmerican | Self::PacificIslander | Self::White
+        )
+    }
+
+    /// Whether this code stands alone rather than describing the applicant
+    pub fn is_exclusive(&self) -> bool {
+        matches!(self, Self::NotProvided | Self::NotApplicable)
+    }
+}
+
+/// Applicant sex
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter)]
+#[repr(i16)]
+pub enum Sex {
+    /// Male
+    Male = 1,
+    /// Female
+    Female = 2,
+    /// Applicant did not provide the information in a mail, internet or telephone application
+    #[strum(serialize = "Information Not Provided")]
+    NotProvided = 3,
+    /// Not applicable
+    #[strum(serialize = "Not Applicable")]
+    NotApplicable = 4,
+    /// Applicant selected both male and female
+    #[strum(serialize = "Selected Both Male and Female")]
+    MaleAndFemale = 6,
+}
+
+/// Whether a demographic field was recorded by the lender from visual
+/// observation or surname, because the applicant declined in person
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter)]
+#[repr(i16)]
+pub enum ObservationBasis {
+    /// Collected on the basis of visual observation or surname
+    #[strum(serialize = "Collected by Visual Observation or Surname")]
+    Observed = 1,
+    /// Provided by the applicant
+    #[strum(serialize = "Provided by Applicant")]
+    NotObserved = 2,
+    /// Not applicable
+    #[strum(serialize = "Not Applicable")]
+    NotApplicable = 3,
+}
+
+/// Credit scoring model behind a reported score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter)]
+#[repr(i16)]
+pub enum CreditScoreModel {
+    /// Equifax Beacon 5.0
+    #[strum(serialize = "Equifax Beacon 5.0")]
+    EquifaxBeacon5 = 1,
+    /// Experian Fair Isaac
+    #[strum(serialize = "Experian Fair Isaac")]
+    ExperianFairIsaac = 2,
+    /// FICO Risk Score Classic 04
+    #[strum(serialize = "FICO Risk Score Classic 04")]
+    FicoClassic04 = 3,
+    /// FICO Risk Score Classic 98
+    #[strum(serialize = "FICO Risk Score Classic 98")]
+    FicoClassic98 = 4,
+    /// VantageScore 2.0
+    #[strum(serialize = "VantageScore 2.0")]
+    VantageScore2 = 5,
+    /// VantageScore 3.0
+    #[strum(serialize = "VantageScore 3.0")]
+    VantageScore3 = 6,
+    /// More than one credit scoring model
+    #[strum(serialize = "More Than One Model")]
+    MoreThanOne = 7,
+    /// Other credit scoring model
+    #[strum(serialize = "Other Model")]
+    Other = 8,
+    /// Not applicable
+    #[strum(serialize = "Not Applicable")]
+    NotApplicable = 9,
+}
+
+/// Principal reason an application was denied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter)]
+#[repr(i16)]
+pub enum DenialReason {
+    /// Debt-to-income ratio
+    #[strum(serialize = "Debt-to-Income Ratio")]
+    DebtToIncome = 1,
+    /// Employment history
+    #[strum(serialize = "Employment History")]
+    EmploymentHistory = 2,
+    /// Credit history
+    #[strum(serialize = "Credit History")]
+    CreditHistory = 3,
+    /// Collateral
+    Collateral = 4,
+    /// Insufficient cash (down payment, closing costs)
+    #[strum(serialize = "Insufficient Cash")]
+    InsufficientCash = 5,
+    /// Unverifiable information
+    #[strum(serialize = "Unverifiable Information")]
+    UnverifiableInformation = 6,
+    /// Credit application incomplete
+    #[strum(serialize = "Credit Application Incomplete")]
+    ApplicationIncomplete = 7,
+    /// Mortgage insurance denied
+    #[strum(serialize = "Mortgage Insurance Denied")]
+    MortgageInsuranceDenied = 8,
+    /// Other
+    Other = 9,
+}
+
+impl DenialReason {
+    /// Statement of the reason for an adverse action notice
+    pub fn notice_statement(&self) -> &'static str {
+        match self {
+            Self::DebtToIncome => "Excessive obligations in relation to income",
+            Self::EmploymentHistory => "Length or stability of employment",
+            Self::CreditHistory => "Delinquent, derogatory or insufficient credit history",
+            Self::Collateral => "Value or type of collateral not sufficient",
+            Self::InsufficientCash => "Insufficient funds for the down payment and closing costs",
+            Self::UnverifiableInformation => "Unable to verify the information provided",
+            Self::ApplicationIncomplete => "Credit application incomplete",
+            Self::MortgageInsuranceDenied => "Mortgage insurance denied",
+            Self::Other => "Other",
+        }
+    }
+}
+
+/// Lien position of the loan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[repr(i16)]
+pub enum LienStatus {
+    /// Secured by a first lien
+    #[default]
+    #[strum(serialize = "First Lien")]
+    First = 1,
+    /// Secured by a subordinate lien
+    #[strum(serialize = "Subordinate Lien")]
+    Subordinate = 2,
+}
+
+/// Whether the loan is a high-cost mortgage under HOEPA
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[repr(i16)]
+pub enum HoepaStatus {
+    /// High-cost mortgage
+    #[strum(serialize = "High-Cost Mortgage")]
+    HighCost = 1,
+    /// Not a high-cost mortgage
+    #[default]
+    #[strum(serialize = "Not a High-Cost Mortgage")]
+    NotHighCost = 2,
+    /// Not applicable
+    #[strum(serialize = "Not Applicable")]
+    NotApplicable = 3,
+}
+
+/// Who bought the loan in the same calendar year it was originated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[repr(i16)]
+pub enum PurchaserType {
+    /// Not sold, or not applicable
+    #[default]
+    #[strum(serialize = "Not Sold")]
+    NotSold = 0,
+    /// Fannie Mae
+    #[strum(serialize = "Fannie Mae")]
+    FannieMae = 1,
+    /// Ginnie Mae
+    #[strum(serialize = "Ginnie Mae")]
+    GinnieMae = 2,
+    /// Freddie Mac
+    #[strum(serialize = "Freddie Mac")]
+    FreddieMac = 3,
+    /// Farmer Mac
+    #[strum(serialize = "Farmer Mac")]
+    FarmerMac = 4,
+    /// Private securitizer
+    #[strum(serialize = "Private Securitizer")]
+    PrivateSecuritizer = 5,
+    /// Commercial bank, savings bank or savings association
+    #[strum(serialize = "Commercial or Savings Bank")]
+    Bank = 6,
+    /// Credit union, mortgage company or finance company
+    #[strum(serialize = "Credit Union or Mortgage Company")]
+    CreditUnionOrMortgageCompany = 71,
+    /// Life insurance company
+    #[strum(serialize = "Life Insurance Company")]
+    LifeInsurance = 72,
+    /// Affiliate institution
+    #[strum(serialize = "Affiliate Institution")]
+    Affiliate = 8,
+    /// Other type of purchaser
+    Other = 9,
+}
+
+/// Federal agency the filer reports to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[repr(i16)]
+pub enum FederalAgency {
+    /// Office of the Comptroller of the Currency
+    #[strum(serialize = "OCC")]
+    Occ = 1,
+    /// Federal Reserve System
+    #[strum(serialize = "Federal Reserve")]
+    FederalReserve = 2,
+    /// Federal Deposit Insurance Corporation
+    #[strum(serialize = "FDIC")]
+    Fdic = 3,
+    /// National Credit Union Administration
+    #[strum(serialize = "NCUA")]
+    Ncua = 5,
+    /// Department of Housing and Urban Development
+    #[strum(serialize = "HUD")]
+    Hud = 7,
+    /// Consumer Financial Protection Bureau
+    #[default]
+    #[strum(serialize = "CFPB")]
+    Cfpb = 9,
+}
+
+// ===== HMDA Loan Data =====
+
+/// HMDA fields for one loan that are not kept elsewhere on the file
+///
+/// Loan terms, the property address and the loan officer's NMLS ID come
+/// from their own records when the LAR is built. Demographic fields are
+/// `None` (or empty) until collected, which the edit checks report.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct HmdaData {
+    /// Loan the data belongs to
+    pub loan_id: i32,
+
+    /// Final action on the application
+    pub action_taken: Option<ActionTaken>,
+
+    /// Date of the final action
+    pub action_taken_date: Option<NaiveDate>,
+
+    /// Whether a preapproval was requested
+    pub preapproval: Preapproval,
+
+    /// Five digit county FIPS code of the property
+    pub county: Option<String>,
+
+    /// Eleven digit census tract of the property
+    pub census_tract: Option<String>,
+
+    /// Applicant ethnicities, up to five
+    pub applicant_ethnicities: Vec<Ethnicity>,
+
+    /// How the applicant's ethnicity was collected
+    pub applicant_ethnicity_observed: Option<ObservationBasis>,
+
+    /// Applicant races, up to five
+    pub applicant_races: Vec<Race>,
+
+    /// How the applicant's race was collected
+    pub applicant_race_observed: Option<ObservationBasis>,
+
+    /// Applicant sex
+    pub applicant_sex: Option<Sex>,
+
+    /// How the applicant's sex was collected
+    pub applicant_sex_observed: Option<ObservationBasis>,
+
+    /// Applicant age in years at application
+    pub applicant_age: Option<i32>,
+
+    /// Applicant credit score relied on
+    pub applicant_credit_score: Option<i32>,
+
+    /// Model behind the applicant's credit score
+    pub applicant_credit_model: Option<CreditScoreModel>,
+
+    /// First co-applicant ethnicities, up to five
+    pub co_applicant_ethnicities: Vec<Ethnicity>,
+
+    /// How the co-applicant's ethnicity was collected
+    pub co_applicant_ethnicity_observed: Option<ObservationBasis>,
+
+    /// First co-applicant races, up to five
+    pub co_applicant_races: Vec<Race>,
+
+    /// How the co-applicant's race was collected
+    pub co_applicant_race_observed: Option<ObservationBasis>,
+
+    /// First co-applicant sex
+    pub co_applicant_sex: Option<Sex>,
+
+    /// How the co-applicant's sex was collected
+    pub co_applicant_sex_observed: Option<ObservationBasis>,
+
+    /// First co-applicant age in years at application
+    pub co_applicant_age: Option<i32>,
+
+    /// First co-applicant credit score relied on
+    pub co_applicant_credit_score: Option<i32>,
+
+    /// Model behind the co-applicant's credit score
+    pub co_applicant_credit_model: Option<CreditScoreModel>,
+
+    /// Reasons for denial, up to four, principal reason first
+    pub denial_reasons: Vec<DenialReason>,
+
+    /// Difference between the APR and the average prime offer rate, in percent
+    pub rate_spread: Option<f64>,
+
+    /// Whether the loan is a high-cost mortgage
+    pub hoepa_status: HoepaStatus,
+
+    /// Lien position
+    pub lien_status: LienStatus,
+
+    /// Who bought the loan, if sold in the year it was originated
+    pub purchaser_type: PurchaserType,
+
+    /// Debt-to-income ratio relied on, in percent
+    pub debt_to_income: Option<f64>,
+
+    /// User who last changed the data
+    pub updated_by: Option<i32>,
+
+    /// Timestamp of when the data was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HmdaData {
+    /// Demographics and credit score of the applicant
+    pub fn applicant(&self) -> HmdaApplicant {
+        HmdaApplicant {
+            ethnicities: self.applicant_ethnicities.clone(),
+            ethnicity_observed: self.applicant_ethnicity_observed,
+            races: self.applicant_races.clone(),
+            race_observed: self.applicant_race_observed,
+            sex: self.applicant_sex,
+            sex_observed: self.applicant_sex_observed,
+            age: self.applicant_age,
+            credit_score: self.applicant_credit_score,
+            credit_model: self.applicant_credit_model,
+        }
+    }
+
+    /// Demographics and credit score of the first co-applicant
+    pub fn co_applicant(&self) -> HmdaApplicant {
+        HmdaApplicant {
+            ethnicities: self.co_applicant_ethnicities.clone(),
+            ethnicity_observed: self.co_applicant_ethnicity_observed,
+            races: self.co_applicant_races.clone(),
+            race_observed: self.co_applicant_race_observed,
+            sex: self.co_applicant_sex,
+            sex_observed: self.co_applicant_sex_observed,
+            age: self.co_applicant_age,
+            credit_score: self.co_applicant_credit_score,
+            credit_model: self.co_applicant_credit_model,
+        }
+    }
+}
+
+/// Demographic information and credit score of one applicant
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct HmdaApplicant {
+    /// Ethnicities, up to five
+    #[validate(length(max = 5, message = "Report at most five ethnicities"))]
+    pub ethnicities: Vec<Ethnicity>,
+
+    /// How ethnicity was collected
+    pub ethnicity_observed: Option<ObservationBasis>,
+
+    /// Races, up to five
+    #[validate(length(max = 5, message = "Report at most five races"))]
+    pub races: Vec<Race>,
+
+    /// How race was collected
+    pub race_observed: Option<ObservationBasis>,
+
+    /// Sex
+    pub sex: Option<Sex>,
+
+    /// How sex was collected
+    pub sex_observed: Option<ObservationBasis>,
+
+    /// Age in years at application
+    #[validate(range(min = 1, max = 150, message = "Age must be 1-150"))]
+    pub age: Option<i32>,
+
+    /// Credit score relied on
+    #[validate(range(min = 1, max = 999, message = "Credit score must be 1-999"))]
+    pub credit_score: Option<i32>,
+
+    /// Model behind the credit score
+    pub credit_model: Option<CreditScoreModel>,
+}
+
+/// Fields supplied when saving a loan's HMDA data
+///
+/// # Validation Rules
+/// - County: five digits; census tract: eleven digits
+/// - Applicants: at most five ethnicities and races
+/// - Denial reasons: at most four, none repeated
+/// - Action taken and its date are given together
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[validate(schema(function = "validate_hmda_input"))]
+pub struct HmdaInput {
+    /// Final action on the application
+    pub action_taken: Option<ActionTaken>,
+
+    /// Date of the final action
+    pub action_taken_date: Option<NaiveDate>,
+
+    /// Whether a preapproval was requested
+    pub preapproval: Preapproval,
+
+    /// Five digit county FIPS code
+    #[validate(regex(path = *COUNTY_REGEX, message = "County must be a five digit FIPS code"))]
+    pub county: Option<String>,
+
+    /// Eleven digit census tract
+    #[validate(regex(path = *CENSUS_TRACT_REGEX, message = "Census tract must be 11 digits"))]
+    pub census_tract: Option<String>,
+
+    /// Applicant demographics and credit score
+    #[validate(nested)]
+    pub applicant: HmdaApplicant,
+
+    /// First co-applicant demographics and credit score
+    #[validate(nested)]
+    pub co_applicant: HmdaApplicant,
+
+    /// Reasons for denial, principal reason first
+    #[validate(length(max = 4, message = "Report at most four denial reasons"))]
+    pub denial_reasons: Vec<DenialReason>,
+
+    /// Rate spread in percent
+    #[validate(range(min = -99.0, max = 99.0, message = "Rate spread must be between -99 and 99"))]
+    pub rate_spread: Option<f64>,
+
+    /// Whether the loan is a high-cost mortgage
+    pub hoepa_status: HoepaStatus,
+
+    /// Lien position
+    pub lien_status: LienStatus,
+
+    /// Who bought the loan
+    pub purchaser_type: PurchaserType,
+
+    /// Debt-to-income ratio in percent
+    #[validate(range(min = 0.0, max = 999.0, message = "Debt-to-income must be 0-999%"))]
+    pub debt_to_income: Option<f64>,
+}
+
+impl From<&HmdaData> for HmdaInput {
+    fn from(data: &HmdaData) -> Self {
+        Self {
+            action_taken: data.action_taken,
+            action_taken_date: data.action_taken_date,
+            preapproval: data.preapproval,
+            county: data.county.clone(),
+            census_tract: data.census_tract.clone(),
+            applicant: data.applicant(),
+            co_applicant: data.co_applicant(),
+            denial_reasons: data.denial_reasons.clone(),
+            rate_spread: data.rate_spread,
+            hoepa_status: data.hoepa_status,
+            lien_status: data.lien_status,
+            purchaser_type: data.purchaser_type,
+            debt_to_income: data.debt_to_income,
+        }
+    }
+}
+
+fn validate_hmda_input(input: &HmdaInput) -> Result<(), ValidationError> {
+    if input.action_taken.is_some() != input.action_taken_date.is_some() {
+        return Err(ValidationError::new("action_taken")
+            .with_message("Action taken and its date must be entered together".into()));
+    }
+    let mut reasons = input.denial_reasons.clone();
+    reasons.sort_by_key(DenialReason::code);
+    reasons.dedup();
+    if reasons.len() != input.denial_reasons.len() {
+        return Err(ValidationError::new("denial_reasons").with_message("A denial reason is listed twice".into()));
+    }
+    Ok(())
+}
+
+// ===== HMDA Filer =====
+
+/// The reporting institution, printed on the LAR transmittal sheet
+///
+/// Kept in a single settings row.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct HmdaFiler {
+    /// Legal Entity Identifier, 20 characters
+    pub lei: String,
+
+    /// Financial institution name
+    pub institution_name: String,
+
+    /// Federal taxpayer identification number (99-9999999)
+    pub tax_id: String,
+
+    /// Federal agency the institution reports to
+    pub federal_agency: FederalAgency,
+
+    /// Contact person's name
+    pub contact_name: String,
+
+    /// Contact phone number (999-999-9999)
+    pub contact_phone: String,
+
+    /// Contact email address
+    pub contact_email: String,
+
+    /// Contact office street address
+    pub contact_street: String,
+
+    /// Contact office city
+    pub contact_city: String,
+
+    /// Contact office two-letter state code
+    pub contact_state: String,
+
+    /// Contact office ZIP code
+    pub contact_zip: String,
+
+    /// User who last changed the filer details
+    pub updated_by: Option<i32>,
+
+    /// Timestamp of when the filer details were last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Fields supplied when saving the filer details
+///
+/// # Validation Rules
+/// - LEI: 20 uppercase letters or digits
+/// - Tax ID: 99-9999999
+/// - Phone: 999-999-9999
+/// - Name, contact and address: required
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct HmdaFilerInput {
+    /// Legal Entity Identifier
+    #[validate(regex(path = *LEI_REGEX, message = "LEI must be 20 uppercase letters or digits"))]
+    pub lei: String,
+
+    /// Financial institution name
+    #[validate(length(min = 1, max = 200, message = "Institution name must be 1-200 characters"))]
+    pub institution_name: String,
+
+    /// Federal taxpayer identification number
+    #[validate(regex(path = *TAX_ID_REGEX, message = "Tax ID must look like 99-9999999"))]
+    pub tax_id: String,
+
+    /// Federal agency the institution reports to
+    pub federal_agency: FederalAgency,
+
+    /// Contact person's name
+    #[validate(length(min = 1, max = 100, message = "Contact name must be 1-100 characters"))]
+    pub contact_name: String,
+
+    /// Contact phone number
+    #[validate(regex(path = *PHONE_REGEX, message = "Phone must look like 999-999-9999"))]
+    pub contact_phone: String,
+
+    /// Contact email address
+    #[validate(email(message = "Must be a valid email address"))]
+    pub contact_email: String,
+
+    /// Contact office street address
+    #[validate(length(min = 1, max = 200, message = "Street must be 1-200 characters"))]
+    pub contact_street: String,
+
+    /// Contact office city
+    #[validate(length(min = 1, max = 100, message = "City must be 1-100 characters"))]
+    pub contact_city: String,
+
+    /// Contact office two-letter state code
+    #[validate(regex(path = *STATE_REGEX, message = "State must be a two-letter code"))]
+    pub contact_state: String,
+
+    /// Contact office ZIP code
+    #[validate(regex(path = *ZIP_REGEX, message = "ZIP must be 5 or 9 digits"))]
+    pub contact_zip: String,
+}
+
+impl From<&HmdaFiler> for HmdaFilerInput {
+    fn from(filer: &HmdaFiler) -> Self {
+        Self {
+            lei: filer.lei.clone(),
+            institution_name: filer.institution_name.clone(),
+            tax_id: filer.tax_id.clone(),
+            federal_agency: filer.federal_agency,
+            contact_name: filer.contact_name.clone(),
+            contact_phone: filer.contact_phone.clone(),
+            contact_email: filer.contact_email.clone(),
+            contact_street: filer.contact_street.clone(),
+            contact_city: filer.contact_city.clone(),
+            contact_state: filer.contact_state.clone(),
+            contact_zip: filer.contact_zip.clone(),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+// pg_app/shared/src/models/license_models.rs
+use chrono::{DateTime, NaiveDate, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+use super::User;
+
+lazy_static! {
+    /// Two-letter uppercase state code
+    static ref STATE_REGEX: Regex = Regex::new(r"^[A-Z]{2}$").unwrap();
+    /// NMLS unique identifiers are all digits
+    static ref NMLS_ID_REGEX: Regex = Regex::new(r"^[0-9]{1,12}$").unwrap();
+}
+
+/// Days ahead the expiring license report looks
+pub const LICENSE_EXPIRY_WARNING_DAYS: i32 = 60;
+
+// ===== License Model =====
+
+/// A loan officer's license to originate in one state
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct License {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Licensed loan officer
+    pub user_id: i32,
+
+    /// Two-letter state code
+    pub state: String,
+
+    /// License number issued by the state
+    pub license_number: String,
+
+    /// Last day the license is valid
+    pub expires_on: NaiveDate,
+
+    /// Timestamp of when the license was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the license was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Whether `id` looks like an NMLS unique identifier (up to 12 digits)
+pub fn is_valid_nmls_id(id: &str) -> bool {
+    NMLS_ID_REGEX.is_match(id)
+}
+
+impl License {
+    /// Whether the license is valid on the given day
+    pub fn is_current(&self, on: NaiveDate) -> bool {
+        on <= self.expires_on
+    }
+}
+
+/// Fields supplied when adding or renewing a license
+///
+/// # Validation Rules
+/// - State: Two uppercase letters
+/// - License number: 1-50 characters
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+pub struct LicenseInput {
+    /// Two-letter state code
+    #[validate(regex(path = *STATE_REGEX, message = "State must be a two-letter code"))]
+    pub state: String,
+
+    /// License number issued by the state
+    #[validate(length(min = 1, max = 50, message = "License number must be 1-50 characters"))]
+    pub license_number: String,
+
+    /// Last day the license is valid
+    pub expires_on: NaiveDate,
+}
+
+impl From<&License> for LicenseInput {
+    fn from(license: &License) -> Self {
+        Self {
+            state: license.state.clone(),
+            license_number: license.license_number.clone(),
+            expires_on: license.expires_on,
+        }
+    }
+}
+
+// ===== License Check =====
+
+/// Why a loan officer may not originate a loan in a state
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+pub enum LicenseError {
+    /// The officer has no NMLS ID on file
+    #[error("{officer} has no NMLS ID on file")]
+    MissingNmlsId {
+        /// Loan officer's name
+        officer: String,
+    },
+
+    /// No license for the state
+    #[error("{officer} is not licensed in {state}")]
+    Unlicensed {
+        /// Loan officer's name
+        officer: String,
+        /// Subject property state
+        state: String,
+    },
+
+    /// The license for the state has lapsed
+    #[error("{officer}'s {state} license expired on {expires_on}")]
+    Expired {
+        /// Loan officer's name
+        officer: String,
+        /// Subject property state
+        state: String,
+        /// Last day the license was valid
+        expires_on: NaiveDate,
+    },
+}
+
+/// Checks a loan officer may originate loans at all: they need an NMLS ID
+///
+/// Used when an officer is assigned before the subject property's state is
+/// known; [`check_license`] covers the state once it is.
+pub fn check_originator(officer: &User) -> Result<(), LicenseError> {
+    if officer.nmls_id.as_deref().is_none_or(|id| id.trim().is_empty()) {
+        return Err(LicenseError::MissingNmlsId {
+            officer: officer.full_name(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks a loan officer may originate a loan on a property in `state`
+///
+/// The officer needs an NMLS ID (see [`check_originator`]) and a license for
+/// the state that has not expired on `on`.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::models::{check_license, LicenseError, User};
+///
+/// let mut officer: User = serde_json::from_str(
+///     r#"{"id":3,"username":"mreyes","first_name":"Marco","last_name":"Reyes","email":"marco@example.com",
+///         "role":"LoanOfficer","is_active":true,"created_at":null,"last_login":null,"failed_login_attempts":0}"#,
+/// ).unwrap();
+/// let today = NaiveDate::from_ymd_opt(2025, 5, 19).unwrap();
+///
+/// assert_eq!(
+///     check_license(&officer, &[], "TX", today),
+///     Err(LicenseError::MissingNmlsId { officer: "Marco Reyes".to_string() })
+/// );
+/// officer.nmls_id = Some("1234567".to_string());
+/// assert_eq!(
+///     check_license(&officer, &[], "TX", today).unwrap_err().to_string(),
+///     "Marco Reyes is not licensed in TX"
+/// );
+/// ```
+pub fn check_license(officer: &User, licenses: &[License], state: &str, on: NaiveDate) -> Result<(), LicenseError> {
+    check_originator(officer)?;
+    let license = licenses
+        .iter()
+        .filter(|license| license.user_id == officer.id && license.state == state)
+        .max_by_key(|license| license.expires_on);
+    match license {
+        None => Err(LicenseError::Unlicensed {
+            officer: officer.full_name(),
+            state: state.to_string(),
+        }),
+        Some(license) if !license.is_current(on) => Err(LicenseError::Expired {
+            officer: officer.full_name(),
+            state: state.to_string(),
+            expires_on: license.expires_on,
+        }),
+        Some(_) => Ok(()),
+    }
+}
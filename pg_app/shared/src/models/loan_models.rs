@@ -0,0 +1,348 @@
+// pg_app/shared/src/models/loan_models.rs
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use validator::Validate;
+
+use super::role_models::{Permission, UserRole};
+
+// ===== Loan Enums =====
+
+/// Where a loan is in the origination pipeline
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `loan_status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "loan_status", rename_all = "snake_case")]
+pub enum LoanStatus {
+    /// Prospect, no application yet
+    #[default]
+    Lead,
+    /// Application taken
+    Application,
+    /// File is being assembled
+    Processing,
+    /// Submitted to underwriting
+    Underwriting,
+    /// Approved by underwriting
+    Approved,
+    /// All conditions cleared
+    #[strum(serialize = "Clear to Close")]
+    ClearToClose,
+    /// Loan funded and closed
+    Closed,
+    /// Application denied
+    Denied,
+    /// Borrower withdrew the application
+    Withdrawn,
+}
+
+impl LoanStatus {
+    /// Database code for this status (e.g. `"clear_to_close"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Lead => "lead",
+            Self::Application => "application",
+            Self::Processing => "processing",
+            Self::Underwriting => "underwriting",
+            Self::Approved => "approved",
+            Self::ClearToClose => "clear_to_close",
+            Self::Closed => "closed",
+            Self::Denied => "denied",
+            Self::Withdrawn => "withdrawn",
+        }
+    }
+
+    /// Statuses a loan may move to from this one
+    ///
+    /// Files move forward one stage at a time, may step back one stage for
+    /// rework, and may be withdrawn until closing. Withdrawn files can be
+    /// reopened as applications; closed and denied files are final.
+    pub fn allowed_transitions(&self) -> &'static [LoanStatus] {
+        use LoanStatus::*;
+        match self {
+            Lead => &[Application, Withdrawn],
+            Application => &[Processing, Denied, Withdrawn],
+            Processing => &[Underwriting, Application, Withdrawn],
+            Underwriting => &[Approved, Denied, Processing, Withdrawn],
+            Approved => &[ClearToClose, Underwriting, Withdrawn],
+            ClearToClose => &[Closed, Approved, Withdrawn],
+            Closed | Denied => &[],
+            Withdrawn => &[Application],
+        }
+    }
+
+    /// Whether no move out of this status is possible (closed and denied files)
+    pub fn is_final(&self) -> bool {
+        self.allowed_transitions().is_empty()
+    }
+
+    /// Whether a loan may move directly from this status to `to`
+    pub fn can_transition_to(&self, to: LoanStatus) -> bool {
+        self.allowed_transitions().contains(&to)
+    }
+
+    /// Permission needed to move a loan into this status
+    ///
+    /// Credit decisions and closing need `ProcessLoans`; every other move can
+    /// also be made by the assigned loan officer with `EditOwnLoans`.
+    pub fn required_permission(&self) -> Permission {
+        match self {
+            Self::Approved | Self::ClearToClose | Self::Closed | Self::Denied => Permission::ProcessLoans,
+            _ => Permission::EditOwnLoans,
+        }
+    }
+}
+
+/// Why a pipeline status change was refused
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TransitionError {
+    /// The move is not in [`LoanStatus::allowed_transitions`]
+    #[error("A loan cannot move from {from} to {to}")]
+    NotAllowed {
+        /// Current status
+        from: LoanStatus,
+        /// Requested status
+        to: LoanStatus,
+    },
+    /// The user's role does not permit the move
+    #[error("You do not have permission to move a loan to {0}")]
+    Forbidden(LoanStatus),
+    /// The user only edits their own loans and this one is assigned elsewhere
+    #[error("Only the assigned loan officer can move this loan")]
+    NotAssigned,
+}
+
+/// Checks whether a user may move a loan from `from` to `to`
+///
+/// `ProcessLoans` (and `All`) may move any loan; `EditOwnLoans` only covers
+/// loans assigned to the user.
+///
+/// # Example
+/// ```
+/// use shared::models::{check_transition, LoanStatus, TransitionError, UserRole};
+///
+/// assert!(check_transition(UserRole::LoanOfficer, 7, Some(7), LoanStatus::Lead, LoanStatus::Application).is_ok());
+/// assert_eq!(
+///     check_transition(UserRole::LoanOfficer, 7, Some(7), LoanStatus::Underwriting, LoanStatus::Approved),
+///     Err(TransitionError::Forbidden(LoanStatus::Approved)),
+/// );
+/// assert!(check_transition(UserRole::Processor, 3, Some(7), LoanStatus::Underwriting, LoanStatus::Approved).is_ok());
+/// ```
+pub fn check_transition(
+    role: UserRole,
+    user_id: i32,
+    loan_officer_id: Option<i32>,
+    from: LoanStatus,
+    to: LoanStatus,
+) -> Result<(), TransitionError> {
+    if !from.can_transition_to(to) {
+        return Err(TransitionError::NotAllowed { from, to });
+    }
+    if role.has_permission(Permission::ProcessLoans) {
+        return Ok(());
+    }
+    match to.required_permission() {
+        Permission::EditOwnLoans if role.has_permission(Permission::EditOwnLoans) => {
+            if loan_officer_id == Some(user_id) {
+                Ok(())
+            } else {
+                Err(TransitionError::NotAssigned)
+            }
+        }
+        _ => Err(TransitionError::Forbidden(to)),
+    }
+}
+
+impl std::str::FromStr for LoanStatus {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|status| status.code() == s)
+            .ok_or_else(|| format!("Invalid loan status: {}", s))
+    }
+}
+
+/// Loan program
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `loan_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "loan_type", rename_all = "snake_case")]
+pub enum LoanType {
+    /// Conforming conventional loan
+    #[default]
+    Conventional,
+    /// FHA insured
+    #[strum(serialize = "FHA")]
+    Fha,
+    /// VA guaranteed
+    #[strum(serialize = "VA")]
+    Va,
+    /// USDA rural development
+    #[strum(serialize = "USDA")]
+    Usda,
+    /// Non-conforming jumbo loan
+    Jumbo,
+}
+
+impl LoanType {
+    /// Database code for this type (e.g. `"fha"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Conventional => "conventional",
+            Self::Fha => "fha",
+            Self::Va => "va",
+            Self::Usda => "usda",
+            Self::Jumbo => "jumbo",
+        }
+    }
+}
+
+impl std::str::FromStr for LoanType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|t| t.code() == s)
+            .ok_or_else(|| format!("Invalid loan type: {}", s))
+    }
+}
+
+/// Why the borrower is taking the loan
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `loan_purpose`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "loan_purpose", rename_all = "snake_case")]
+pub enum LoanPurpose {
+    /// Home purchase
+    #[default]
+    Purchase,
+    /// Rate/term refinance
+    Refinance,
+    /// Cash-out refinance
+    #[strum(serialize = "Cash-Out Refinance")]
+    CashOutRefinance,
+}
+
+impl LoanPurpose {
+    /// Database code for this purpose (e.g. `"cash_out_refinance"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Purchase => "purchase",
+            Self::Refinance => "refinance",
+            Self::CashOutRefinance => "cash_out_refinance",
+        }
+    }
+}
+
+impl std::str::FromStr for LoanPurpose {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|p| p.code() == s)
+            .ok_or_else(|| format!("Invalid loan purpose: {}", s))
+    }
+}
+
+// ===== Loan Model =====
+
+/// A mortgage loan file for one borrower
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Loan {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Human-facing loan number, assigned once
+    pub loan_number: Option<String>,
+
+    /// Primary borrower
+    pub borrower_id: i32,
+
+    /// Assigned loan officer
+    pub loan_officer_id: Option<i32>,
+
+    /// Pipeline status
+    pub status: LoanStatus,
+
+    /// Loan program
+    pub loan_type: LoanType,
+
+    /// Purchase or refinance
+    pub loan_purpose: LoanPurpose,
+
+    /// Loan amount in cents
+    pub amount_cents: i64,
+
+    /// Note rate as a percentage (e.g. `6.875`); `None` for imported leads
+    /// until a rate is quoted
+    pub note_rate: Option<f64>,
+
+    /// Amortization term in months
+    pub term_months: i32,
+
+    /// Date the application was taken
+    pub application_date: Option<NaiveDate>,
+
+    /// Timestamp of when the loan was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the loan was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Fields supplied when creating or updating a loan
+///
+/// # Validation Rules
+/// - Amount: Greater than zero
+/// - Note rate: 0-25%
+/// - Term: 1-480 months
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+pub struct LoanInput {
+    /// Primary borrower
+    pub borrower_id: i32,
+
+    /// Assigned loan officer
+    pub loan_officer_id: Option<i32>,
+
+    /// Loan program
+    pub loan_type: LoanType,
+
+    /// Purchase or refinance
+    pub loan_purpose: LoanPurpose,
+
+    /// Loan amount in cents
+    #[validate(range(min = 1, message = "Loan amount must be greater than zero"))]
+    pub amount_cents: i64,
+
+    /// Note rate as a percentage
+    #[validate(range(min = 0.0, max = 25.0, message = "Note rate must be between 0% and 25%"))]
+    pub note_rate: f64,
+
+    /// Amortization term in months
+    #[validate(range(min = 1, max = 480, message = "Term must be 1-480 months"))]
+    pub term_months: i32,
+
+    /// Date the application was taken
+    pub application_date: Option<NaiveDate>,
+}
+
+impl Default for LoanInput {
+    fn default() -> Self {
+        Self {
+            borrower_id: 0,
+            loan_officer_id: None,
+            loan_type: LoanType::default(),
+            loan_purpose: LoanPurpose::default(),
+            amount_cents: 0,
+            note_rate: 0.0,
+            term_months: 360,
+            application_date: None,
+        }
+    }
+}
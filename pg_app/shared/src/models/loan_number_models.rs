@@ -0,0 +1,272 @@
+// pg_app/shared/src/models/loan_number_models.rs
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::{Validate, ValidationError};
+
+lazy_static! {
+    /// One to ten uppercase letters or digits
+    static ref BRANCH_REGEX: Regex = Regex::new(r"^[A-Z0-9]{1,10}$").unwrap();
+}
+
+/// Longest loan number the `loans.loan_number` column holds
+pub const LOAN_NUMBER_MAX_LEN: usize = 30;
+
+/// Widest zero padding `{SEQ:n}` accepts
+const MAX_SEQ_WIDTH: usize = 12;
+
+// ===== Loan Number Format =====
+
+/// One piece of a [`LoanNumberFormat`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Branch,
+    Year,
+    ShortYear,
+    Month,
+    Sequence(usize),
+}
+
+/// A parsed loan number pattern such as `{BRANCH}-{YYYY}-{SEQ:6}`
+///
+/// | Token       | Becomes                                           |
+/// |-------------|---------------------------------------------------|
+/// | `{BRANCH}`  | Branch code from the settings, e.g. `AUS`         |
+/// | `{YYYY}`    | Four-digit year the loan was created              |
+/// | `{YY}`      | Two-digit year                                    |
+/// | `{MM}`      | Two-digit month                                   |
+/// | `{SEQ:n}`   | Next number for the prefix, zero-padded to `n` digits (`{SEQ}` is unpadded) |
+///
+/// Everything else is copied as is and may only be letters, digits, `-`,
+/// `_`, `.` or `/`. The format needs exactly one `{SEQ}`.
+///
+/// Each distinct prefix (the number with the sequence left out) counts
+/// from 1 on its own, so `{BRANCH}-{YYYY}-{SEQ:6}` restarts every year and
+/// for every branch.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::models::LoanNumberFormat;
+///
+/// let format = LoanNumberFormat::parse("{BRANCH}-{YYYY}-{SEQ:6}").unwrap();
+/// let date = NaiveDate::from_ymd_opt(2025, 5, 19).unwrap();
+///
+/// assert_eq!(format.prefix("AUS", date), "AUS-2025-#");
+/// assert_eq!(format.render("AUS", date, 42), "AUS-2025-000042");
+/// assert_eq!(format.render("AUS", date, 1_234_567), "AUS-2025-1234567");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoanNumberFormat {
+    parts: Vec<Part>,
+}
+
+impl LoanNumberFormat {
+    /// Parses and checks a format string
+    pub fn parse(format: &str) -> Result<Self, LoanNumberFormatError> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut rest = format.trim();
+        while let Some(c) = rest.chars().next() {
+            if c == '{' {
+                let Some(end) = rest.find('}') else {
+                    return Err(LoanNumberFormatError::Unclosed);
+                };
+                if !literal.is_empty() {
+                    parts.push(Part::Literal(std::mem::take(&mut literal)));
+                }
+                parts.push(Self::token(&rest[1..end])?);
+                rest = &rest[end + 1..];
+            } else if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/') {
+                literal.push(c);
+                rest = &rest[1..];
+            } else {
+                return Err(LoanNumberFormatError::InvalidCharacter(c));
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+
+        match parts.iter().filter(|part| matches!(part, Part::Sequence(_))).count() {
+            0 => Err(LoanNumberFormatError::MissingSequence),
+            1 => Ok(Self { parts }),
+            _ => Err(LoanNumberFormatError::RepeatedSequence),
+        }
+    }
+
+    fn token(name: &str) -> Result<Part, LoanNumberFormatError> {
+        let part = match name.trim() {
+            "BRANCH" => Part::Branch,
+            "YYYY" => Part::Year,
+            "YY" => Part::ShortYear,
+            "MM" => Part::Month,
+            "SEQ" => Part::Sequence(0),
+            other => match other.strip_prefix("SEQ:").map(str::parse::<usize>) {
+                Some(Ok(width)) if (1..=MAX_SEQ_WIDTH).contains(&width) => Part::Sequence(width),
+                Some(_) => return Err(LoanNumberFormatError::SequenceWidth(other.to_string())),
+                None => return Err(LoanNumberFormatError::UnknownToken(other.to_string())),
+            },
+        };
+        Ok(part)
+    }
+
+    /// Whether the format has a `{BRANCH}` token
+    pub fn uses_branch(&self) -> bool {
+        self.parts.contains(&Part::Branch)
+    }
+
+    fn write(&self, branch: &str, date: NaiveDate, sequence: &str) -> String {
+        let mut number = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Literal(text) => number.push_str(text),
+                Part::Branch => number.push_str(branch),
+                Part::Year => number.push_str(&format!("{:04}", date.year())),
+                Part::ShortYear => number.push_str(&format!("{:02}", date.year().rem_euclid(100))),
+                Part::Month => number.push_str(&format!("{:02}", date.month())),
+                Part::Sequence(_) => number.push_str(sequence),
+            }
+        }
+        number
+    }
+
+    /// The number with `#` in place of the sequence
+    ///
+    /// Loans whose numbers share a prefix draw from the same sequence. `#`
+    /// cannot appear in a format, so two formats only share a prefix when
+    /// they would produce the same numbers.
+    pub fn prefix(&self, branch: &str, date: NaiveDate) -> String {
+        self.write(branch, date, "#")
+    }
+
+    /// The loan number for the given sequence value
+    pub fn render(&self, branch: &str, date: NaiveDate, sequence: i64) -> String {
+        let width = self
+            .parts
+            .iter()
+            .find_map(|part| match part {
+                Part::Sequence(width) => Some(*width),
+                _ => None,
+            })
+            .unwrap_or_default();
+        self.write(branch, date, &format!("{:0width$}", sequence, width = width))
+    }
+}
+
+impl std::str::FromStr for LoanNumberFormat {
+    type Err = LoanNumberFormatError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Why a loan number format was rejected
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+pub enum LoanNumberFormatError {
+    /// A `{` with no `}` after it
+    #[error("a `{{` in the format is never closed")]
+    Unclosed,
+
+    /// A token other than the ones listed on [`LoanNumberFormat`]
+    #[error("unknown token `{{{0}}}`; use BRANCH, YYYY, YY, MM or SEQ:n")]
+    UnknownToken(String),
+
+    /// `{SEQ:n}` with `n` outside 1-12
+    #[error("`{{{0}}}` needs a width from 1 to 12")]
+    SequenceWidth(String),
+
+    /// Text that is not a letter, digit, `-`, `_`, `.` or `/`
+    #[error("`{0}` cannot be used in a loan number")]
+    InvalidCharacter(char),
+
+    /// No `{SEQ}` token
+    #[error("the format needs a {{SEQ}} or {{SEQ:n}} token")]
+    MissingSequence,
+
+    /// More than one `{SEQ}` token
+    #[error("the format can only have one {{SEQ}} token")]
+    RepeatedSequence,
+}
+
+// ===== Loan Number Settings =====
+
+/// The company's loan number format, kept in a single settings row
+///
+/// Numbers are assigned once when a loan is created; changing the format
+/// only affects loans created afterwards.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct LoanNumberSettings {
+    /// Pattern parsed by [`LoanNumberFormat`]
+    pub format: String,
+
+    /// Value of the `{BRANCH}` token
+    pub branch_code: String,
+
+    /// User who last changed the settings
+    pub updated_by: Option<i32>,
+
+    /// Timestamp of when the settings were last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Fields supplied when changing the loan number format
+///
+/// # Validation Rules
+/// - Format: parses as a [`LoanNumberFormat`] and its numbers, padded to
+///   the sequence width, fit in 30 characters
+/// - Branch code: 1-10 uppercase letters or digits
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[validate(schema(function = "validate_settings_input"))]
+pub struct LoanNumberSettingsInput {
+    /// Pattern such as `{BRANCH}-{YYYY}-{SEQ:6}`
+    pub format: String,
+
+    /// Value of the `{BRANCH}` token
+    #[validate(regex(path = *BRANCH_REGEX, message = "Branch code must be 1-10 uppercase letters or digits"))]
+    pub branch_code: String,
+}
+
+impl LoanNumberSettingsInput {
+    /// Example of the first number the settings produce on `date`
+    pub fn example(&self, date: NaiveDate) -> Result<String, LoanNumberFormatError> {
+        Ok(LoanNumberFormat::parse(&self.format)?.render(&self.branch_code, date, 1))
+    }
+}
+
+impl Default for LoanNumberSettingsInput {
+    fn default() -> Self {
+        Self {
+            format: "{BRANCH}-{YYYY}-{SEQ:6}".to_string(),
+            branch_code: "MAIN".to_string(),
+        }
+    }
+}
+
+impl From<&LoanNumberSettings> for LoanNumberSettingsInput {
+    fn from(settings: &LoanNumberSettings) -> Self {
+        Self {
+            format: settings.format.clone(),
+            branch_code: settings.branch_code.clone(),
+        }
+    }
+}
+
+fn validate_settings_input(input: &LoanNumberSettingsInput) -> Result<(), ValidationError> {
+    let format = match LoanNumberFormat::parse(&input.format) {
+        Ok(format) => format,
+        Err(e) => return Err(ValidationError::new("loan_number_format").with_message(e.to_string().into())),
+    };
+    // The widest month and year, with the sequence at its padded width
+    let sample = format.render(&input.branch_code, NaiveDate::from_ymd_opt(2099, 12, 31).unwrap_or_default(), 1);
+    if sample.len() > LOAN_NUMBER_MAX_LEN {
+        return Err(ValidationError::new("loan_number_length").with_message(
+            format!("Loan numbers such as {} would be longer than {} characters", sample, LOAN_NUMBER_MAX_LEN).into(),
+        ));
+    }
+    Ok(())
+}
@@ -1,7 +1,70 @@
+mod adverse_action_models;
+mod arm_models;
+mod audit_models;
+mod borrower_merge_models;
+mod borrower_models;
+mod disclosure_models;
+mod document_models;
+mod fee_models;
+mod hmda_models;
+mod license_models;
+mod loan_models;
+mod loan_number_models;
+mod note_models;
+mod notification_models;
+mod pmi_models;
 mod post_models;
+mod property_models;
+mod rate_lock_models;
 mod role_models;
+mod session_models;
+mod status_history_models;
+mod task_models;
+mod trid_models;
 mod user_models;
 
 pub use role_models::{Permission, UserRole};
 pub use user_models::User;
-pub use post_models::*;
\ No newline at end of file
+pub use post_models::*;
+pub use adverse_action_models::{
+    denial_statements, notice_due_date, AdverseAction, DenialInput, NoticeDelivery, ADVERSE_ACTION_NOTICE_DAYS,
+};
+pub use arm_models::{ArmIndex, ArmTerms};
+pub use audit_models::{AuditAction, AuditEntry};
+pub use borrower_merge_models::{
+    merge_undo_deadline, BorrowerMerge, MergeField, MergeSelection, BORROWER_MERGE_UNDO_HOURS,
+};
+pub use borrower_models::{
+    Asset, AssetInput, AssetType, Borrower, BorrowerInput, Employment, EmploymentInput, Liability, LiabilityInput,
+    LiabilityType,
+};
+pub use disclosure_models::{Disclosure, DisclosureFee, DisclosureInput, DisclosureKind};
+pub use document_models::{DocumentKind, LoanDocument};
+pub use fee_models::{ClosingAdjustments, FeePayer, FeeSection, LoanFee, LoanFeeInput};
+pub use hmda_models::{
+    ActionTaken, CreditScoreModel, DenialReason, Ethnicity, FederalAgency, HmdaApplicant, HmdaData, HmdaFiler, HmdaFilerInput,
+    HmdaInput, HoepaStatus, LienStatus, ObservationBasis, Preapproval, PurchaserType, Race, Sex, HMDA_MAX_DENIAL_REASONS,
+    HMDA_MAX_SELECTIONS,
+};
+pub use license_models::{
+    check_license, check_originator, is_valid_nmls_id, License, LicenseError, LicenseInput, LICENSE_EXPIRY_WARNING_DAYS,
+};
+pub use loan_models::{check_transition, Loan, LoanInput, LoanPurpose, LoanStatus, LoanType, TransitionError};
+pub use loan_number_models::{
+    LoanNumberFormat, LoanNumberFormatError, LoanNumberSettings, LoanNumberSettingsInput, LOAN_NUMBER_MAX_LEN,
+};
+pub use property_models::{Occupancy, Property, PropertyInput, PropertyType};
+pub use note_models::{build_threads, extract_mentions, Note, NoteEdit, NoteInput, NoteSubject, NoteThread};
+pub use notification_models::Notification;
+pub use pmi_models::{PmiRate, PmiRateInput};
+pub use rate_lock_models::{
+    lock_alert_threshold, lock_expiration, RateLock, RateLockExtension, RateLockExtensionInput, RateLockInput, RateLockStatus,
+    RATE_LOCK_ALERT_DAYS,
+};
+pub use session_models::{
+    authorize, can_edit_borrower, can_edit_loan, can_see_internal_notes, expired_session_cookie, session_cookie,
+    session_token, AccessError, SESSION_COOKIE, SESSION_HOURS,
+};
+pub use status_history_models::{sla_target_for, SlaTarget, StatusChange, SLA_TARGET_MAX_DAYS};
+pub use task_models::{can_assign_task, task_urgency, ChecklistItem, Task, TaskInput, TaskPriority, TaskStatus};
+pub use trid_models::{DisclosureDelivery, TridDates};
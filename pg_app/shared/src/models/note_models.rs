@@ -0,0 +1,211 @@
+// pg_app/shared/src/models/note_models.rs
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+lazy_static! {
+    /// `@username`, using the same character rules as usernames
+    static ref MENTION_REGEX: Regex = Regex::new(r"(?:^|[^A-Za-z0-9_@])@([A-Za-z0-9_]{3,50})\b").unwrap();
+}
+
+/// What a note is attached to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoteSubject {
+    /// A loan file
+    Loan(i32),
+    /// A borrower
+    Borrower(i32),
+}
+
+impl NoteSubject {
+    /// `(loan_id, borrower_id)` column values for this subject
+    pub fn columns(&self) -> (Option<i32>, Option<i32>) {
+        match *self {
+            Self::Loan(id) => (Some(id), None),
+            Self::Borrower(id) => (None, Some(id)),
+        }
+    }
+}
+
+/// A note or reply, with its author's name
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Note {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Loan the note is attached to
+    pub loan_id: Option<i32>,
+
+    /// Borrower the note is attached to
+    pub borrower_id: Option<i32>,
+
+    /// Note this one replies to; `None` for top-level notes
+    pub parent_id: Option<i32>,
+
+    /// User who wrote the note
+    pub author_id: Option<i32>,
+
+    /// Author's full name
+    pub author_name: Option<String>,
+
+    /// Markdown source
+    pub body: String,
+
+    /// Pinned notes sort to the top of the thread list
+    pub pinned: bool,
+
+    /// Hidden from borrower-facing views
+    pub internal_only: bool,
+
+    /// When the body was last edited
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub edited_at: Option<DateTime<Utc>>,
+
+    /// Timestamp of when the note was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the note was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Note {
+    /// Usernames mentioned in the body
+    pub fn mentions(&self) -> Vec<String> {
+        extract_mentions(&self.body)
+    }
+
+    /// Plain-text notification for someone mentioned in this note
+    ///
+    /// Names the note's loan or borrower rather than quoting the body, which
+    /// is markdown written by the author.
+    pub fn mention_message(&self, author_name: &str) -> String {
+        match (self.loan_id, self.borrower_id) {
+            (Some(loan_id), _) => format!("{} mentioned you on loan #{}", author_name, loan_id),
+            (None, Some(borrower_id)) => format!("{} mentioned you on borrower #{}", author_name, borrower_id),
+            (None, None) => format!("{} mentioned you in a note", author_name),
+        }
+    }
+}
+
+/// Fields supplied when writing or editing a note
+///
+/// # Validation Rules
+/// - Body: 1-10000 characters
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+pub struct NoteInput {
+    /// Markdown source
+    #[validate(length(min = 1, max = 10000, message = "Note must be 1-10000 characters"))]
+    pub body: String,
+
+    /// Pin to the top of the list
+    pub pinned: bool,
+
+    /// Hide from borrower-facing views
+    pub internal_only: bool,
+}
+
+impl Default for NoteInput {
+    fn default() -> Self {
+        Self {
+            body: String::new(),
+            pinned: false,
+            internal_only: true,
+        }
+    }
+}
+
+impl From<&Note> for NoteInput {
+    fn from(note: &Note) -> Self {
+        Self {
+            body: note.body.clone(),
+            pinned: note.pinned,
+            internal_only: note.internal_only,
+        }
+    }
+}
+
+/// A previous version of a note body
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct NoteEdit {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Note that was edited
+    pub note_id: i32,
+
+    /// Body before the edit
+    pub previous_body: String,
+
+    /// User who made the edit
+    pub edited_by: Option<i32>,
+
+    /// Name of the user who made the edit
+    pub editor_name: Option<String>,
+
+    /// When the edit was made
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub edited_at: DateTime<Utc>,
+}
+
+/// A note with its replies, nested to any depth
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoteThread {
+    /// The note itself
+    pub note: Note,
+
+    /// Replies, oldest first
+    pub replies: Vec<NoteThread>,
+}
+
+/// Usernames `@mentioned` in a note body, in order of first appearance
+///
+/// Email addresses are not treated as mentions.
+///
+/// # Example
+/// ```
+/// use shared::models::extract_mentions;
+///
+/// let body = "@jsmith please check with @ana_l and @jsmith (cc ops@example.com)";
+/// assert_eq!(extract_mentions(body), vec!["jsmith", "ana_l"]);
+/// ```
+pub fn extract_mentions(body: &str) -> Vec<String> {
+    let mut mentions: Vec<String> = Vec::new();
+    for capture in MENTION_REGEX.captures_iter(body) {
+        let username = &capture[1];
+        if !mentions.iter().any(|m| m == username) {
+            mentions.push(username.to_string());
+        }
+    }
+    mentions
+}
+
+/// Arranges a flat list of notes into threads
+///
+/// Top-level notes are ordered pinned first, then newest first; replies are
+/// ordered oldest first. Replies whose parent is not in the list are dropped.
+pub fn build_threads(notes: Vec<Note>) -> Vec<NoteThread> {
+    let mut children: HashMap<Option<i32>, Vec<Note>> = HashMap::new();
+    for note in notes {
+        children.entry(note.parent_id).or_default().push(note);
+    }
+
+    fn attach(note: Note, children: &mut HashMap<Option<i32>, Vec<Note>>) -> NoteThread {
+        let mut replies = children.remove(&Some(note.id)).unwrap_or_default();
+        replies.sort_by_key(|reply| (reply.created_at, reply.id));
+        NoteThread {
+            replies: replies.into_iter().map(|reply| attach(reply, children)).collect(),
+            note,
+        }
+    }
+
+    let mut roots = children.remove(&None).unwrap_or_default();
+    roots.sort_by(|a, b| b.pinned.cmp(&a.pinned).then(b.created_at.cmp(&a.created_at)).then(b.id.cmp(&a.id)));
+    roots.into_iter().map(|root| attach(root, &mut children)).collect()
+}
@@ -0,0 +1,46 @@
+// pg_app/shared/src/models/notification_models.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// An in-app notification
+///
+/// Notifications with no `user_id` are shown to everyone.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Notification {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Recipient, or `None` for everyone
+    pub user_id: Option<i32>,
+
+    /// What raised it (e.g. `rate_lock_expiring`)
+    pub kind: String,
+
+    /// Short heading
+    pub title: String,
+
+    /// Body text
+    pub message: String,
+
+    /// Related loan, if any
+    pub loan_id: Option<i32>,
+
+    /// Identifies the event so it is only raised once
+    pub reference_key: Option<String>,
+
+    /// When the signed-in user marked it read
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub read_at: Option<DateTime<Utc>>,
+
+    /// Timestamp of when the notification was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl Notification {
+    /// Whether the notification has not been read yet
+    pub fn is_unread(&self) -> bool {
+        self.read_at.is_none()
+    }
+}
@@ -0,0 +1,99 @@
+// pg_app/shared/src/models/pmi_models.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// One cell of the private mortgage insurance rate table
+///
+/// A rate applies when the LTV and credit score both fall inside its bands
+/// (bounds inclusive).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct PmiRate {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Lowest LTV in the band, as a percentage
+    pub ltv_min: f64,
+
+    /// Highest LTV in the band, as a percentage
+    pub ltv_max: f64,
+
+    /// Lowest credit score in the band
+    pub credit_score_min: i32,
+
+    /// Highest credit score in the band
+    pub credit_score_max: i32,
+
+    /// Annual premium as a percentage of the loan amount (e.g. `0.52`)
+    pub annual_rate: f64,
+
+    /// Timestamp of when the rate was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the rate was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PmiRate {
+    /// Whether this rate covers the given LTV and credit score
+    pub fn covers(&self, ltv: f64, credit_score: i32) -> bool {
+        (self.ltv_min..=self.ltv_max).contains(&ltv)
+            && (self.credit_score_min..=self.credit_score_max).contains(&credit_score)
+    }
+}
+
+/// Fields supplied when creating or updating a PMI rate
+///
+/// # Validation Rules
+/// - LTV bounds: 0-125%
+/// - Credit score bounds: 300-850
+/// - Annual rate: 0-5%
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+pub struct PmiRateInput {
+    /// Lowest LTV in the band
+    #[validate(range(min = 0.0, max = 125.0, message = "LTV must be between 0% and 125%"))]
+    pub ltv_min: f64,
+
+    /// Highest LTV in the band
+    #[validate(range(min = 0.0, max = 125.0, message = "LTV must be between 0% and 125%"))]
+    pub ltv_max: f64,
+
+    /// Lowest credit score in the band
+    #[validate(range(min = 300, max = 850, message = "Credit score must be 300-850"))]
+    pub credit_score_min: i32,
+
+    /// Highest credit score in the band
+    #[validate(range(min = 300, max = 850, message = "Credit score must be 300-850"))]
+    pub credit_score_max: i32,
+
+    /// Annual premium as a percentage of the loan amount
+    #[validate(range(min = 0.0, max = 5.0, message = "Rate must be between 0% and 5%"))]
+    pub annual_rate: f64,
+}
+
+impl Default for PmiRateInput {
+    fn default() -> Self {
+        Self {
+            ltv_min: 80.01,
+            ltv_max: 85.0,
+            credit_score_min: 620,
+            credit_score_max: 850,
+            annual_rate: 0.0,
+        }
+    }
+}
+
+impl From<&PmiRate> for PmiRateInput {
+    fn from(rate: &PmiRate) -> Self {
+        Self {
+            ltv_min: rate.ltv_min,
+            ltv_max: rate.ltv_max,
+            credit_score_min: rate.credit_score_min,
+            credit_score_max: rate.credit_score_max,
+            annual_rate: rate.annual_rate,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+// pg_app/shared/src/models/property_models.rs
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use validator::Validate;
+
+use crate::calculations::ltv;
+
+lazy_static! {
+    /// Two-letter uppercase state code
+    static ref STATE_REGEX: Regex = Regex::new(r"^[A-Z]{2}$").unwrap();
+    /// Five digit ZIP with optional +4
+    static ref ZIP_REGEX: Regex = Regex::new(r"^[0-9]{5}(-[0-9]{4})?$").unwrap();
+}
+
+// ===== Property Enums =====
+
+/// How the borrower will use the property
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `occupancy_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "occupancy_type", rename_all = "snake_case")]
+pub enum Occupancy {
+    /// Borrower's primary residence
+    #[default]
+    #[strum(serialize = "Primary Residence")]
+    PrimaryResidence,
+    /// Second home or vacation property
+    #[strum(serialize = "Second Home")]
+    SecondHome,
+    /// Investment or rental property
+    Investment,
+}
+
+impl Occupancy {
+    /// Database code for this occupancy (e.g. `"second_home"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::PrimaryResidence => "primary_residence",
+            Self::SecondHome => "second_home",
+            Self::Investment => "investment",
+        }
+    }
+}
+
+impl std::str::FromStr for Occupancy {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|o| o.code() == s)
+            .ok_or_else(|| format!("Invalid occupancy: {}", s))
+    }
+}
+
+/// Kind of dwelling
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `property_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "property_type", rename_all = "snake_case")]
+pub enum PropertyType {
+    /// Detached single family home
+    #[default]
+    #[strum(serialize = "Single Family")]
+    SingleFamily,
+    /// Condominium unit
+    Condominium,
+    /// Attached townhouse
+    Townhouse,
+    /// Two to four unit building
+    #[strum(serialize = "2-4 Unit")]
+    TwoToFourUnit,
+    /// Manufactured home
+    Manufactured,
+    /// Planned unit development
+    #[strum(serialize = "PUD")]
+    Pud,
+}
+
+impl PropertyType {
+    /// Database code for this property type (e.g. `"single_family"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::SingleFamily => "single_family",
+            Self::Condominium => "condominium",
+            Self::Townhouse => "townhouse",
+            Self::TwoToFourUnit => "two_to_four_unit",
+            Self::Manufactured => "manufactured",
+            Self::Pud => "pud",
+        }
+    }
+}
+
+impl std::str::FromStr for PropertyType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|t| t.code() == s)
+            .ok_or_else(|| format!("Invalid property type: {}", s))
+    }
+}
+
+// ===== Property Model =====
+
+/// The subject property (collateral) securing a loan
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Property {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Loan this property secures (one property per loan)
+    pub loan_id: i32,
+
+    /// Street address
+    pub street: String,
+
+    /// City
+    pub city: String,
+
+    /// Two-letter state code
+    pub state: String,
+
+    /// ZIP or ZIP+4
+    pub zip: String,
+
+    /// Intended occupancy
+    pub occupancy: Occupancy,
+
+    /// Kind of dwelling
+    pub property_type: PropertyType,
+
+    /// Number of units (1-4)
+    pub units: i32,
+
+    /// Contract purchase price in cents (purchases only)
+    pub purchase_price_cents: Option<i64>,
+
+    /// Appraised value in cents
+    pub appraised_value_cents: Option<i64>,
+
+    /// Borrower or AVM estimated value in cents
+    pub estimated_value_cents: Option<i64>,
+
+    /// Timestamp of when the property was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the property was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Property {
+    /// Single line address, e.g. `"1 Main St, Springfield, IL 62701"`
+    pub fn address_line(&self) -> String {
+        format!("{}, {}, {} {}", self.street, self.city, self.state, self.zip)
+    }
+
+    /// Value used as the LTV denominator
+    ///
+    /// See [`ltv::valuation_basis`] for the rules.
+    pub fn ltv_value_cents(&self) -> Option<i64> {
+        ltv::valuation_basis(
+            self.purchase_price_cents,
+            self.appraised_value_cents,
+            self.estimated_value_cents,
+        )
+    }
+}
+
+/// Fields supplied when creating or updating the subject property
+///
+/// # Validation Rules
+/// - Street: 1-200 characters
+/// - City: 1-100 characters
+/// - State: Two uppercase letters
+/// - ZIP: `12345` or `12345-6789`
+/// - Units: 1-4
+/// - Values: Greater than zero (if provided)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+pub struct PropertyInput {
+    /// Street address (1-200 characters)
+    #[validate(length(min = 1, max = 200, message = "Street must be 1-200 characters"))]
+    pub street: String,
+
+    /// City (1-100 characters)
+    #[validate(length(min = 1, max = 100, message = "City must be 1-100 characters"))]
+    pub city: String,
+
+    /// Two-letter state code
+    #[validate(regex(path = *STATE_REGEX, message = "State must be a two-letter code"))]
+    pub state: String,
+
+    /// ZIP or ZIP+4
+    #[validate(regex(path = *ZIP_REGEX, message = "ZIP must be 12345 or 12345-6789"))]
+    pub zip: String,
+
+    /// Intended occupancy
+    pub occupancy: Occupancy,
+
+    /// Kind of dwelling
+    pub property_type: PropertyType,
+
+    /// Number of units (1-4)
+    #[validate(range(min = 1, max = 4, message = "Units must be 1-4"))]
+    pub units: i32,
+
+    /// Contract purchase price in cents
+    #[validate(range(min = 1, message = "Purchase price must be greater than zero"))]
+    pub purchase_price_cents: Option<i64>,
+
+    /// Appraised value in cents
+    #[validate(range(min = 1, message = "Appraised value must be greater than zero"))]
+    pub appraised_value_cents: Option<i64>,
+
+    /// Estimated value in cents
+    #[validate(range(min = 1, message = "Estimated value must be greater than zero"))]
+    pub estimated_value_cents: Option<i64>,
+}
+
+impl Default for PropertyInput {
+    fn default() -> Self {
+        Self {
+            street: String::new(),
+            city: String::new(),
+            state: String::new(),
+            zip: String::new(),
+            occupancy: Occupancy::default(),
+            property_type: PropertyType::default(),
+            units: 1,
+            purchase_price_cents: None,
+            appraised_value_cents: None,
+            estimated_value_cents: None,
+        }
+    }
+}
+
+impl From<&Property> for PropertyInput {
+    fn from(property: &Property) -> Self {
+        Self {
+            street: property.street.clone(),
+            city: property.city.clone(),
+            state: property.state.clone(),
+            zip: property.zip.clone(),
+            occupancy: property.occupancy,
+            property_type: property.property_type,
+            units: property.units,
+            purchase_price_cents: property.purchase_price_cents,
+            appraised_value_cents: property.appraised_value_cents,
+            estimated_value_cents: property.estimated_value_cents,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// pg_app/shared/src/models/rate_lock_models.rs
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use validator::Validate;
+
+/// Days before expiration at which a lock raises an alert
+///
+/// An alert is also raised on the expiration day and once the lock lapses.
+pub const RATE_LOCK_ALERT_DAYS: [i64; 3] = [7, 3, 1];
+
+/// Lifecycle of a rate lock
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `rate_lock_status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "rate_lock_status", rename_all = "snake_case")]
+pub enum RateLockStatus {
+    /// Lock is in force
+    #[default]
+    Active,
+    /// Lock lapsed without the loan closing
+    Expired,
+    /// Lock was given up (e.g. relocked or withdrawn)
+    Cancelled,
+}
+
+/// A rate lock on a loan
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct RateLock {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Loan the lock belongs to
+    pub loan_id: i32,
+
+    /// Locked rate as a percentage (e.g. `6.875`)
+    pub rate: f64,
+
+    /// Discount points; negative for lender credits
+    pub points: f64,
+
+    /// Date the lock was taken
+    pub lock_date: NaiveDate,
+
+    /// Original lock period in days
+    pub lock_period_days: i32,
+
+    /// Current expiration date, including any extensions
+    pub expiration_date: NaiveDate,
+
+    /// Lifecycle status
+    pub status: RateLockStatus,
+
+    /// Timestamp of when the lock was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the lock was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RateLock {
+    /// Whole days from `today` until expiration; negative once expired
+    pub fn days_until_expiration(&self, today: NaiveDate) -> i64 {
+        (self.expiration_date - today).num_days()
+    }
+
+    /// Whether an active lock expires within `days` of `today` (or already has)
+    pub fn expires_within(&self, today: NaiveDate, days: i64) -> bool {
+        self.status == RateLockStatus::Active && self.days_until_expiration(today) <= days
+    }
+}
+
+/// Expiration date for a lock taken on `lock_date` for `period_days`
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::models::lock_expiration;
+///
+/// let locked = NaiveDate::from_ymd_opt(2025, 5, 1).unwrap();
+/// assert_eq!(lock_expiration(locked, 30), NaiveDate::from_ymd_opt(2025, 5, 31));
+/// ```
+pub fn lock_expiration(lock_date: NaiveDate, period_days: i32) -> Option<NaiveDate> {
+    lock_date.checked_add_days(Days::new(u64::try_from(period_days).ok()?))
+}
+
+/// Alert threshold that `days_left` falls under, if any
+///
+/// Returns the tightest entry of [`RATE_LOCK_ALERT_DAYS`] that has been reached,
+/// `Some(0)` on the expiration day, and `None` when no alert is due yet or the
+/// lock has already expired.
+///
+/// # Example
+/// ```
+/// use shared::models::lock_alert_threshold;
+///
+/// assert_eq!(lock_alert_threshold(10), None);
+/// assert_eq!(lock_alert_threshold(5), Some(7));
+/// assert_eq!(lock_alert_threshold(1), Some(1));
+/// assert_eq!(lock_alert_threshold(0), Some(0));
+/// assert_eq!(lock_alert_threshold(-1), None);
+/// ```
+pub fn lock_alert_threshold(days_left: i64) -> Option<i64> {
+    match days_left {
+        d if d < 0 => None,
+        0 => Some(0),
+        d => RATE_LOCK_ALERT_DAYS.iter().copied().filter(|t| d <= *t).min(),
+    }
+}
+
+/// Fields supplied when locking a rate
+///
+/// # Validation Rules
+/// - Rate: 0-25%
+/// - Points: -10 to 10
+/// - Lock period: 1-365 days
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+pub struct RateLockInput {
+    /// Locked rate as a percentage
+    #[validate(range(min = 0.0, max = 25.0, message = "Rate must be between 0% and 25%"))]
+    pub rate: f64,
+
+    /// Discount points; negative for lender credits
+    #[validate(range(min = -10.0, max = 10.0, message = "Points must be between -10 and 10"))]
+    pub points: f64,
+
+    /// Date the lock was taken
+    pub lock_date: NaiveDate,
+
+    /// Lock period in days
+    #[validate(range(min = 1, max = 365, message = "Lock period must be 1-365 days"))]
+    pub lock_period_days: i32,
+}
+
+/// One extension applied to a rate lock
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct RateLockExtension {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Lock that was extended
+    pub rate_lock_id: i32,
+
+    /// Number of days added
+    pub extension_days: i32,
+
+    /// Expiration before the extension
+    pub previous_expiration: NaiveDate,
+
+    /// Expiration after the extension
+    pub new_expiration: NaiveDate,
+
+    /// Price of the extension in points
+    pub cost_points: f64,
+
+    /// Why the lock was extended
+    pub reason: Option<String>,
+
+    /// Timestamp of when the extension was recorded
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Fields supplied when extending a rate lock
+///
+/// # Validation Rules
+/// - Extension: 1-90 days
+/// - Cost: 0-5 points
+/// - Reason: At most 500 characters
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct RateLockExtensionInput {
+    /// Number of days to add
+    #[validate(range(min = 1, max = 90, message = "Extension must be 1-90 days"))]
+    pub extension_days: i32,
+
+    /// Price of the extension in points
+    #[validate(range(min = 0.0, max = 5.0, message = "Extension cost must be 0-5 points"))]
+    pub cost_points: f64,
+
+    /// Why the lock is being extended
+    #[validate(length(max = 500, message = "Reason must be at most 500 characters"))]
+    pub reason: Option<String>,
+}
@@ -8,18 +8,19 @@ use std::collections::HashSet;
 /// Defines the access levels and responsibilities for system users
 ///
 /// # Database Representation
-/// Stored as PostgreSQL enum type `user_role` with lowercase values
+/// Stored as PostgreSQL enum type `user_role` with snake_case values
 ///
 /// # Variants
 /// - `Admin`: Full system access (superuser)
 /// - `LoanOfficer`: Default role for loan processing staff
 /// - `Processor`: Limited access role for data entry
+/// - `Manager`: Team lead who processes loans and assigns work
 ///
 /// # Examples
 ///
 /// ## Database Usage
 /// ```sql
-/// CREATE TYPE user_role AS ENUM ('admin', 'loan_officer', 'processor');
+/// CREATE TYPE user_role AS ENUM ('admin', 'loan_officer', 'processor', 'manager');
 /// ```
 ///
 /// ## Rust Usage
@@ -28,7 +29,7 @@ use std::collections::HashSet;
 /// assert_eq!(role.to_string(), "Loan Officer"); // Display formatting
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
-#[sqlx(type_name = "user_role", rename_all = "lowercase")]
+#[sqlx(type_name = "user_role", rename_all = "snake_case")]
 pub enum UserRole {
     /// System administrator with unrestricted access
     #[strum(serialize = "Administrator")]
@@ -41,6 +42,9 @@ pub enum UserRole {
     
     /// Loan processor with data entry permissions
     Processor,
+
+    /// Team manager who can process loans and reassign work
+    Manager,
 }
 
 /// Granular access controls for system functionality
@@ -55,6 +59,9 @@ pub enum UserRole {
 /// - `EditOwnLoans`: Modify self-created loans
 /// - `ProcessLoans`: Approve/reject applications
 /// - `ManageUsers`: Create/modify user accounts
+/// - `AssignTasks`: Reassign tasks between users
+/// - `ManageSettings`: Edit company-wide tables such as PMI rates
+/// - `RevealPii`: Reveal one masked SSN, date of birth or account number on demand
 ///
 /// # Example Permission Check
 /// ```rust
@@ -83,6 +90,16 @@ pub enum Permission {
     
     /// Manage user accounts and roles
     ManageUsers,
+
+    /// Reassign tasks to other users
+    AssignTasks,
+
+    /// Edit company-wide settings and rate tables
+    ManageSettings,
+
+    /// Reveal a masked SSN, date of birth or account number on demand; each
+    /// reveal is audited. Responses stay masked for these users too.
+    RevealPii,
 }
 
 impl Permission {
@@ -101,6 +118,7 @@ impl UserRole {
             "admin" => Some(Self::Admin),
             "loan_officer" => Some(Self::LoanOfficer),
             "processor" => Some(Self::Processor),
+            "manager" => Some(Self::Manager),
             _ => None,
         }
     }
@@ -120,7 +138,10 @@ impl UserRole {
             Self::LoanOfficer => [ViewLoans, CreateLoans, EditOwnLoans]
                 .into_iter()
                 .collect(),
-            Self::Processor => [ViewLoans, ProcessLoans]
+            Self::Processor => [ViewLoans, ProcessLoans, RevealPii]
+                .into_iter()
+                .collect(),
+            Self::Manager => [ViewLoans, CreateLoans, ProcessLoans, AssignTasks, ManageSettings]
                 .into_iter()
                 .collect(),
         }
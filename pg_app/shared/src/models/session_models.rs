@@ -0,0 +1,93 @@
+// pg_app/shared/src/models/session_models.rs
+use super::{Permission, User};
+
+/// Cookie holding the session token set by `login_user`
+pub const SESSION_COOKIE: &str = "pg_session";
+
+/// Hours a session lasts after sign-in
+pub const SESSION_HOURS: i64 = 12;
+
+/// Why a caller may not perform an action
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum AccessError {
+    /// No valid session came with the request
+    #[error("Sign in to continue")]
+    SignedOut,
+
+    /// The session belongs to a locked or deactivated account
+    #[error("Account is locked or inactive")]
+    Inactive,
+
+    /// The signed-in user's role lacks the permission
+    #[error("You do not have permission to do this")]
+    Forbidden,
+}
+
+/// The signed-in user if they may act, and have `permission` when one is given
+///
+/// Server functions get `session` from the request's session cookie, never
+/// from their arguments, so callers cannot act as someone else.
+pub fn authorize(session: Option<User>, permission: Option<Permission>) -> Result<User, AccessError> {
+    let user = session.ok_or(AccessError::SignedOut)?;
+    if !user.can_login() {
+        return Err(AccessError::Inactive);
+    }
+    if permission.is_some_and(|permission| !user.has_permission(permission)) {
+        return Err(AccessError::Forbidden);
+    }
+    Ok(user)
+}
+
+/// Whether `user` may read notes marked internal-only
+///
+/// Internal notes are for staff who can view loans; anyone else gets only the
+/// notes that are safe to show a borrower.
+pub fn can_see_internal_notes(user: &User) -> bool {
+    user.has_permission(Permission::ViewLoans)
+}
+
+/// Whether `user` may change a loan assigned to `loan_officer_id`
+///
+/// `ProcessLoans` covers every loan; `EditOwnLoans` covers the loans
+/// assigned to the user.
+pub fn can_edit_loan(user: &User, loan_officer_id: Option<i32>) -> bool {
+    user.has_permission(Permission::ProcessLoans)
+        || (user.has_permission(Permission::EditOwnLoans) && loan_officer_id == Some(user.id))
+}
+
+/// Whether `user` may change a borrower on loans assigned to `loan_officer_ids`
+///
+/// As [`can_edit_loan`] for any one of the borrower's loans. A borrower on
+/// no loan yet is a prospect that anyone with `EditOwnLoans` may work.
+pub fn can_edit_borrower(user: &User, loan_officer_ids: &[Option<i32>]) -> bool {
+    user.has_permission(Permission::ProcessLoans)
+        || (user.has_permission(Permission::EditOwnLoans)
+            && (loan_officer_ids.is_empty() || loan_officer_ids.contains(&Some(user.id))))
+}
+
+/// The session token in a `Cookie` request header, if any
+pub fn session_token(cookie_header: &str) -> Option<&str> {
+    cookie_header
+        .split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == SESSION_COOKIE)
+        .map(|(_, token)| token)
+        .filter(|token| !token.is_empty())
+}
+
+/// `Set-Cookie` value that stores `token` for [`SESSION_HOURS`]
+///
+/// The cookie is `HttpOnly` so scripts cannot read it.
+pub fn session_cookie(token: &str) -> String {
+    format!(
+        "{}={}; Path=/; Max-Age={}; HttpOnly; Secure; SameSite=Strict",
+        SESSION_COOKIE,
+        token,
+        SESSION_HOURS * 60 * 60
+    )
+}
+
+/// `Set-Cookie` value that removes the session cookie
+pub fn expired_session_cookie() -> String {
+    format!("{}=; Path=/; Max-Age=0; HttpOnly; Secure; SameSite=Strict", SESSION_COOKIE)
+}
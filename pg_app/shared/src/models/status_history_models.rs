@@ -0,0 +1,64 @@
+// pg_app/shared/src/models/status_history_models.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use super::loan_models::LoanStatus;
+
+/// Longest SLA target that can be set, in business days
+pub const SLA_TARGET_MAX_DAYS: i32 = 90;
+
+/// One recorded status change of a loan
+///
+/// Every loan has a first entry with no `from_status`, written when the loan
+/// is created, so its history covers its whole life.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct StatusChange {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Loan that changed status
+    pub loan_id: i32,
+
+    /// Status the loan left; `None` for the status it was opened in
+    pub from_status: Option<LoanStatus>,
+
+    /// Status the loan entered
+    pub to_status: LoanStatus,
+
+    /// User who made the change, if any
+    pub changed_by: Option<i32>,
+
+    /// Full name of the user who made the change (joined from `users`)
+    #[sqlx(default)]
+    pub changed_by_name: Option<String>,
+
+    /// Timestamp of the change
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub changed_at: DateTime<Utc>,
+}
+
+/// How long a loan should stay in a status, in general business days
+///
+/// Statuses without a target are never flagged. Closed and denied files are
+/// final, so they cannot have one.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct SlaTarget {
+    /// Status the target applies to
+    pub status: LoanStatus,
+
+    /// Business days a loan may spend in the status before it is in breach
+    pub business_days: i32,
+
+    /// User who last changed the target
+    pub updated_by: Option<i32>,
+
+    /// Timestamp of when the target was last changed
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Target for `status` among `targets`, in business days
+pub fn sla_target_for(targets: &[SlaTarget], status: LoanStatus) -> Option<i32> {
+    targets.iter().find(|target| target.status == status).map(|target| target.business_days)
+}
@@ -0,0 +1,253 @@
+// pg_app/shared/src/models/task_models.rs
+use std::cmp::Reverse;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use validator::Validate;
+
+use super::{can_edit_loan, Permission, User};
+
+// ===== Task Enums =====
+
+/// How important a task is
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `task_priority`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "task_priority", rename_all = "snake_case")]
+pub enum TaskPriority {
+    /// Can wait
+    Low,
+    /// Default priority
+    #[default]
+    Normal,
+    /// Should be done before normal work
+    High,
+    /// Drop everything
+    Urgent,
+}
+
+impl TaskPriority {
+    /// Database code for this priority (e.g. `"urgent"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+            Self::Urgent => "urgent",
+        }
+    }
+}
+
+impl std::str::FromStr for TaskPriority {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|p| p.code() == s)
+            .ok_or_else(|| format!("Invalid task priority: {}", s))
+    }
+}
+
+/// Where a task is in its lifecycle
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `task_status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "task_status", rename_all = "snake_case")]
+pub enum TaskStatus {
+    /// Not started
+    #[default]
+    Open,
+    /// Being worked on
+    #[strum(serialize = "In Progress")]
+    InProgress,
+    /// Waiting on someone else
+    Blocked,
+    /// Done
+    Completed,
+    /// No longer needed
+    Cancelled,
+}
+
+impl TaskStatus {
+    /// Database code for this status (e.g. `"in_progress"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::InProgress => "in_progress",
+            Self::Blocked => "blocked",
+            Self::Completed => "completed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// Whether the task still needs doing
+    pub fn is_open(&self) -> bool {
+        !matches!(self, Self::Completed | Self::Cancelled)
+    }
+}
+
+impl std::str::FromStr for TaskStatus {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|status| status.code() == s)
+            .ok_or_else(|| format!("Invalid task status: {}", s))
+    }
+}
+
+// ===== Task Models =====
+
+/// A piece of work on a loan, assigned to one user
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Task {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Loan the task belongs to
+    pub loan_id: i32,
+
+    /// Short description of the work
+    pub title: String,
+
+    /// Longer details
+    pub description: Option<String>,
+
+    /// User responsible for the task
+    pub assignee_id: Option<i32>,
+
+    /// User who created the task
+    pub created_by: Option<i32>,
+
+    /// Date the task is due
+    pub due_date: Option<NaiveDate>,
+
+    /// Importance
+    pub priority: TaskPriority,
+
+    /// Lifecycle status
+    pub status: TaskStatus,
+
+    /// User who completed the task
+    pub completed_by: Option<i32>,
+
+    /// When the task was completed
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub completed_at: Option<DateTime<Utc>>,
+
+    /// Timestamp of when the task was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+
+    /// Timestamp of when the task was last modified
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Task {
+    /// Whether the task is still open and its due date has passed
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status.is_open() && self.due_date.is_some_and(|due| due < today)
+    }
+
+    /// Whether `user` may edit or delete the task or change its checklist
+    ///
+    /// The assignee and creator may, as may anyone who can reassign tasks or
+    /// edit the task's loan, whose officer is `loan_officer_id`.
+    pub fn editable_by(&self, user: &User, loan_officer_id: Option<i32>) -> bool {
+        self.assignee_id == Some(user.id)
+            || self.created_by == Some(user.id)
+            || user.has_permission(Permission::AssignTasks)
+            || can_edit_loan(user, loan_officer_id)
+    }
+}
+
+/// Whether `user` may open a task assigned to `assignee_id`
+///
+/// Anyone may take a task themselves; giving one to someone else, or leaving
+/// it unassigned, requires the `AssignTasks` permission.
+pub fn can_assign_task(user: &User, assignee_id: Option<i32>) -> bool {
+    assignee_id == Some(user.id) || user.has_permission(Permission::AssignTasks)
+}
+
+/// Fields supplied when creating or editing a task
+///
+/// # Validation Rules
+/// - Title: 1-200 characters
+/// - Description: At most 5000 characters
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct TaskInput {
+    /// Short description of the work
+    #[validate(length(min = 1, max = 200, message = "Title must be 1-200 characters"))]
+    pub title: String,
+
+    /// Longer details
+    #[validate(length(max = 5000, message = "Description must be at most 5000 characters"))]
+    pub description: Option<String>,
+
+    /// User responsible for the task
+    pub assignee_id: Option<i32>,
+
+    /// Date the task is due
+    pub due_date: Option<NaiveDate>,
+
+    /// Importance
+    pub priority: TaskPriority,
+}
+
+/// One checklist line on a task
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct ChecklistItem {
+    /// Unique database identifier (auto-incremented)
+    pub id: i32,
+
+    /// Task the item belongs to
+    pub task_id: i32,
+
+    /// What needs checking
+    pub label: String,
+
+    /// Display order
+    pub position: i32,
+
+    /// Whether the item is done
+    pub is_done: bool,
+
+    /// User who ticked the item
+    pub done_by: Option<i32>,
+
+    /// When the item was ticked
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub done_at: Option<DateTime<Utc>>,
+
+    /// Timestamp of when the item was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Sort key putting the most urgent task first
+///
+/// Open tasks come before closed ones; then tasks with a due date, earliest
+/// (so most overdue) first; then higher priority.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use shared::models::{task_urgency, TaskPriority, TaskStatus};
+///
+/// let may = |d| NaiveDate::from_ymd_opt(2025, 5, d);
+/// let overdue = task_urgency(TaskStatus::Open, may(1), TaskPriority::Low);
+/// let due_later = task_urgency(TaskStatus::Open, may(9), TaskPriority::Urgent);
+/// let undated = task_urgency(TaskStatus::Open, None, TaskPriority::Urgent);
+/// assert!(overdue < due_later && due_later < undated);
+/// ```
+pub fn task_urgency(
+    status: TaskStatus,
+    due_date: Option<NaiveDate>,
+    priority: TaskPriority,
+) -> (bool, bool, Option<NaiveDate>, Reverse<TaskPriority>) {
+    (!status.is_open(), due_date.is_none(), due_date, Reverse(priority))
+}
@@ -0,0 +1,68 @@
+// pg_app/shared/src/models/trid_models.rs
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+// ===== TRID Enums =====
+
+/// How a disclosure was delivered to the borrower
+///
+/// # Database Representation
+/// Stored as PostgreSQL enum type `disclosure_delivery`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, strum::Display, strum::EnumIter, Default)]
+#[sqlx(type_name = "disclosure_delivery", rename_all = "snake_case")]
+pub enum DisclosureDelivery {
+    /// Handed to the borrower; received the same day
+    #[strum(serialize = "In Person")]
+    InPerson,
+    /// Sent by mail
+    Mail,
+    /// Sent by email or portal
+    #[default]
+    Electronic,
+}
+
+impl DisclosureDelivery {
+    /// Database code for this method (e.g. `"in_person"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InPerson => "in_person",
+            Self::Mail => "mail",
+            Self::Electronic => "electronic",
+        }
+    }
+}
+
+impl std::str::FromStr for DisclosureDelivery {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|method| method.code() == s)
+            .ok_or_else(|| format!("Invalid delivery method: {}", s))
+    }
+}
+
+// ===== TRID Models =====
+
+/// Disclosure delivery and closing dates for a loan
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct TridDates {
+    /// Loan these dates belong to
+    pub loan_id: i32,
+
+    /// Date the Loan Estimate was delivered or placed in the mail
+    pub le_delivered_date: Option<NaiveDate>,
+
+    /// Date the Closing Disclosure was delivered or placed in the mail
+    pub cd_delivered_date: Option<NaiveDate>,
+
+    /// How the Closing Disclosure was delivered
+    pub cd_delivery_method: DisclosureDelivery,
+
+    /// Confirmed receipt of the Closing Disclosure, when known
+    pub cd_received_date: Option<NaiveDate>,
+
+    /// Scheduled or actual consummation (closing) date
+    pub consummation_date: Option<NaiveDate>,
+}
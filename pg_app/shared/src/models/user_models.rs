@@ -39,6 +39,8 @@ pub struct User {
     #[serde(with = "chrono::serde::ts_seconds_option")]
     pub last_login: Option<DateTime<Utc>>,
     pub failed_login_attempts: i32,
+    /// NMLS unique identifier, required to originate loans
+    pub nmls_id: Option<String>,
 }
 
 impl User {
@@ -77,6 +79,7 @@ impl User {
             created_at: None,
             last_login: None,
             failed_login_attempts: 0,
+            nmls_id: None,
         };
 
         user.validate()?;
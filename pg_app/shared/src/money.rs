@@ -0,0 +1,89 @@
+//! Helpers for monetary amounts
+//!
+//! All money is stored and passed around as whole cents (`i64`) so that
+//! totals never pick up floating point drift. These helpers convert to and
+//! from the dollar strings shown in the UI.
+
+/// Formats cents as a dollar string with thousands separators
+///
+/// # Example
+/// ```
+/// use shared::money::format_cents;
+///
+/// assert_eq!(format_cents(123_456_78), "$123,456.78");
+/// assert_eq!(format_cents(-5), "-$0.05");
+/// ```
+pub fn format_cents(cents: i64) -> String {
+    let sign = if cents < 0 { "-" } else { "" };
+    let abs = cents.unsigned_abs();
+    let dollars = (abs / 100).to_string();
+
+    let mut grouped = String::with_capacity(dollars.len() + dollars.len() / 3);
+    for (i, ch) in dollars.chars().enumerate() {
+        if i > 0 && (dollars.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+
+    format!("{}${}.{:02}", sign, grouped, abs % 100)
+}
+
+/// Parses a dollar amount such as `"1,234.5"` or `"$99"` into cents
+///
+/// Returns `None` for empty or malformed input, or more than two decimals.
+///
+/// # Example
+/// ```
+/// use shared::money::parse_dollars;
+///
+/// assert_eq!(parse_dollars("$1,234.50"), Some(123_450));
+/// assert_eq!(parse_dollars("abc"), None);
+/// ```
+pub fn parse_dollars(input: &str) -> Option<i64> {
+    let cleaned: String = input
+        .trim()
+        .chars()
+        .filter(|c| *c != ',' && *c != '$')
+        .collect();
+    if cleaned.is_empty() {
+        return None;
+    }
+
+    let (negative, digits) = match cleaned.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, cleaned.as_str()),
+    };
+
+    let (whole, fraction) = match digits.split_once('.') {
+        Some((w, f)) => (w, f),
+        None => (digits, ""),
+    };
+    if fraction.len() > 2
+        || !whole.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+        || (whole.is_empty() && fraction.is_empty())
+    {
+        return None;
+    }
+
+    let whole: i64 = if whole.is_empty() { 0 } else { whole.parse().ok()? };
+    let fraction: i64 = match fraction.len() {
+        0 => 0,
+        1 => fraction.parse::<i64>().ok()? * 10,
+        _ => fraction.parse().ok()?,
+    };
+
+    let cents = whole.checked_mul(100)?.checked_add(fraction)?;
+    Some(if negative { -cents } else { cents })
+}
+
+/// Converts cents to floating point dollars for rate math
+pub fn cents_to_dollars(cents: i64) -> f64 {
+    cents as f64 / 100.0
+}
+
+/// Converts floating point dollars to cents, rounding half away from zero
+pub fn dollars_to_cents(dollars: f64) -> i64 {
+    (dollars * 100.0).round() as i64
+}
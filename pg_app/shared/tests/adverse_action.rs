@@ -0,0 +1,87 @@
+//! Adverse action notices: denial reasons and the delivery deadline
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use shared::models::{notice_due_date, AdverseAction, DenialInput, DenialReason, DisclosureDelivery};
+use validator::Validate;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+}
+
+fn denial() -> AdverseAction {
+    let at = Utc.with_ymd_and_hms(2025, 5, 16, 9, 0, 0).unwrap();
+    AdverseAction {
+        loan_id: 42,
+        reasons: vec![DenialReason::DebtToIncome, DenialReason::Collateral],
+        other_reason: None,
+        decided_date: date(5, 16),
+        decided_by: Some(3),
+        document_id: Some(9),
+        delivered_date: None,
+        delivery_method: None,
+        delivered_by: None,
+        created_at: at,
+        updated_at: at,
+    }
+}
+
+#[test]
+fn denial_needs_one_to_four_distinct_reasons() {
+    let input = |reasons: Vec<DenialReason>| DenialInput { reasons, other_reason: None };
+
+    assert!(input(vec![DenialReason::CreditHistory]).validate().is_ok());
+    assert!(input(vec![]).validate().is_err());
+    assert!(input(vec![DenialReason::Collateral, DenialReason::Collateral]).validate().is_err());
+    assert!(input(vec![
+        DenialReason::DebtToIncome,
+        DenialReason::EmploymentHistory,
+        DenialReason::CreditHistory,
+        DenialReason::Collateral,
+        DenialReason::InsufficientCash,
+    ])
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn other_reason_must_be_described() {
+    let mut input = DenialInput {
+        reasons: vec![DenialReason::Other],
+        other_reason: Some("   ".to_string()),
+    };
+    assert!(input.validate().is_err());
+
+    input.other_reason = Some("Bankruptcy discharged within two years".to_string());
+    assert!(input.validate().is_ok());
+
+    input.other_reason = Some("x".repeat(201));
+    assert!(input.validate().is_err());
+}
+
+#[test]
+fn notice_is_due_thirty_days_after_the_denial() {
+    let mut denial = denial();
+    assert_eq!(denial.due_date(), date(6, 15));
+    assert_eq!(notice_due_date(date(12, 15)), NaiveDate::from_ymd_opt(2026, 1, 14).unwrap());
+    assert_eq!(denial.delivered_on_time(), None);
+
+    denial.delivered_date = Some(date(6, 15));
+    denial.delivery_method = Some(DisclosureDelivery::Mail);
+    assert_eq!(denial.delivered_on_time(), Some(true));
+
+    denial.delivered_date = Some(date(6, 16));
+    assert_eq!(denial.delivered_on_time(), Some(false));
+}
+
+#[test]
+fn statements_follow_the_chosen_order() {
+    let mut denial = denial();
+    assert_eq!(
+        denial.statements(),
+        ["Excessive obligations in relation to income", "Value or type of collateral not sufficient"]
+    );
+
+    // Without a description, Other prints as itself rather than a blank line
+    denial.reasons = vec![DenialReason::Other];
+    assert_eq!(denial.statements(), ["Other"]);
+}
@@ -0,0 +1,143 @@
+//! APR calculations checked against Regulation Z, Appendix J
+// Amounts are written as dollars_cents, e.g. `166_07` for $166.07
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::{TimeZone, Utc};
+use shared::calculations::apr::{
+    annual_percentage_rate, apr_within_tolerance, loan_apr, PaymentSchedule, PaymentStream,
+};
+use shared::calculations::closing_costs::ClosingCostSummary;
+use shared::models::{ClosingAdjustments, FeePayer, FeeSection, Loan, LoanFee, LoanPurpose, LoanStatus, LoanType};
+
+fn apr_2dp(amount_financed_cents: i64, schedule: &PaymentSchedule) -> String {
+    format!("{:.2}", annual_percentage_rate(amount_financed_cents, schedule).unwrap())
+}
+
+#[test]
+fn appendix_j_monthly_regular_first_period() {
+    // (b)(5)(i): $5,000 financed 1/10/78, 36 monthly payments of $166.07 from 2/10/78
+    let schedule = PaymentSchedule::monthly(166_07, 36);
+    assert_eq!(apr_2dp(5_000_00, &schedule), "12.00");
+}
+
+#[test]
+fn appendix_j_semimonthly_short_first_period() {
+    // (b)(5)(iii): $5,000 financed 3/1/78, 24 semimonthly payments of $219.91 from 3/10/78
+    let schedule = PaymentSchedule {
+        streams: vec![PaymentStream { amount_cents: 219_91, count: 24 }],
+        periods_per_year: 24,
+        first_period_units: 0,
+        odd_days: 9,
+    };
+    assert_eq!(apr_2dp(5_000_00, &schedule), "10.85");
+}
+
+#[test]
+fn no_finance_charge_beyond_interest_matches_note_rate() {
+    // $200,000 at 6% for 30 years
+    let apr = loan_apr(200_000_00, 0, &PaymentSchedule::monthly(1_199_10, 360)).unwrap();
+    assert!((apr - 6.0).abs() < 0.001, "apr was {apr}");
+}
+
+#[test]
+fn prepaid_finance_charges_raise_apr() {
+    let schedule = PaymentSchedule::monthly(1_199_10, 360);
+    let apr = loan_apr(200_000_00, 4_000_00, &schedule).unwrap();
+    assert_eq!(format!("{apr:.3}"), "6.189");
+}
+
+#[test]
+fn long_first_period_lowers_apr() {
+    let regular = PaymentSchedule::monthly(166_07, 36);
+    let long = PaymentSchedule::monthly(166_07, 36).with_first_period(1, 15);
+    let regular_apr = annual_percentage_rate(5_000_00, &regular).unwrap();
+    let long_apr = annual_percentage_rate(5_000_00, &long).unwrap();
+    assert!(long_apr < regular_apr);
+}
+
+#[test]
+fn final_payment_stream_is_discounted_last() {
+    let level = PaymentSchedule::monthly(166_07, 36);
+    let balloon = PaymentSchedule::monthly(166_07, 35).then(166_07, 1);
+    assert_eq!(
+        annual_percentage_rate(5_000_00, &level),
+        annual_percentage_rate(5_000_00, &balloon)
+    );
+    assert_eq!(balloon.total_of_payments_cents(), 36 * 166_07);
+}
+
+#[test]
+fn solved_rate_discounts_payments_back_to_amount_financed() {
+    let schedule = PaymentSchedule::monthly(773_44, 360).with_first_period(0, 20);
+    let apr = annual_percentage_rate(158_000_00, &schedule).unwrap();
+    let i = apr / 100.0 / 12.0;
+    let factor = 1.0 + 20.0 / 30.0 * i;
+    let pv: f64 = (0..360).map(|t| 773_44.0 / (factor * (1.0 + i).powi(t))).sum();
+    assert!((pv - 158_000_00.0).abs() < 1.0, "present value was {pv}");
+}
+
+#[test]
+fn unsolvable_inputs_return_none() {
+    let schedule = PaymentSchedule::monthly(100_00, 12);
+    assert_eq!(annual_percentage_rate(0, &schedule), None);
+    assert_eq!(annual_percentage_rate(1_300_00, &schedule), None);
+    assert_eq!(annual_percentage_rate(1_000_00, &PaymentSchedule::monthly(0, 12)), None);
+}
+
+#[test]
+fn zero_finance_charge_is_zero_apr() {
+    let apr = annual_percentage_rate(1_200_00, &PaymentSchedule::monthly(100_00, 12)).unwrap();
+    assert!(apr.abs() < 1e-9);
+}
+
+#[test]
+fn tolerance_is_one_eighth_for_regular_transactions() {
+    assert!(apr_within_tolerance(6.000, 6.125, false));
+    assert!(apr_within_tolerance(6.250, 6.125, false));
+    assert!(!apr_within_tolerance(6.000, 6.126, false));
+    assert!(apr_within_tolerance(6.000, 6.250, true));
+    assert!(!apr_within_tolerance(6.000, 6.251, true));
+}
+
+#[test]
+fn leads_without_a_note_rate_have_no_apr() {
+    let at = Utc.with_ymd_and_hms(2025, 5, 1, 9, 0, 0).unwrap();
+    let mut lead = Loan {
+        id: 9,
+        loan_number: None,
+        borrower_id: 4,
+        loan_officer_id: Some(3),
+        status: LoanStatus::Lead,
+        loan_type: LoanType::Conventional,
+        loan_purpose: LoanPurpose::Purchase,
+        amount_cents: 200_000_00,
+        note_rate: None,
+        term_months: 360,
+        application_date: None,
+        created_at: at,
+        updated_at: at,
+    };
+    let fees = [LoanFee {
+        id: 1,
+        loan_id: 9,
+        section: FeeSection::Origination,
+        name: "Origination Fee".to_string(),
+        amount_cents: 4_000_00,
+        payer: FeePayer::Borrower,
+        paid_to: None,
+        finance_charge: true,
+        off_list_provider: false,
+        recording_fee: false,
+        created_at: at,
+        updated_at: at,
+    }];
+
+    let summary = ClosingCostSummary::calculate(&fees, &ClosingAdjustments::default(), &lead, None);
+    assert_eq!(summary.apr, None);
+    assert_eq!(summary.total_finance_charge_cents, 4_000_00);
+    assert_eq!(summary.amount_financed_cents, 196_000_00);
+
+    lead.note_rate = Some(6.0);
+    let summary = ClosingCostSummary::calculate(&fees, &ClosingAdjustments::default(), &lead, None);
+    assert_eq!(summary.apr.map(|apr| format!("{apr:.3}")), Some("6.189".to_string()));
+}
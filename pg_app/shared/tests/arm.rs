@@ -0,0 +1,87 @@
+//! Adjustable-rate projections: caps, floor, fully indexed rate and recast payments
+// Amounts are written as dollars_cents, e.g. `1_798_65` for $1,798.65
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use shared::calculations::arm::{fully_indexed_rate, max_payment_cents, project_arm, ArmPeriod, ArmScenario};
+use shared::models::ArmTerms;
+
+fn rates(path: &[ArmPeriod]) -> Vec<f64> {
+    path.iter().map(|period| period.rate).collect()
+}
+
+#[test]
+fn fully_indexed_rate_rounds_to_the_nearest_eighth() {
+    assert_eq!(fully_indexed_rate(4.31, 2.75), 7.0);
+    assert_eq!(fully_indexed_rate(4.0625, 2.75), 6.875);
+    assert_eq!(fully_indexed_rate(4.06, 2.75), 6.75);
+    assert_eq!(fully_indexed_rate(0.0, 2.75), 2.75);
+}
+
+#[test]
+fn worst_case_rises_by_the_caps_to_the_lifetime_ceiling() {
+    // $300,000 5/6 ARM at 6% with 2/1/5 caps
+    let path = project_arm(300_000_00, 6.0, 360, &ArmTerms::default(), ArmScenario::WorstCase);
+
+    assert_eq!(rates(&path)[..6], [6.0, 8.0, 9.0, 10.0, 11.0, 11.0]);
+    assert_eq!(path[0].payment_cents, 1_798_65);
+    assert_eq!(path[0].starting_balance_cents, 300_000_00);
+    // Fixed for 60 months, then 50 six-month periods
+    assert_eq!(path.len(), 51);
+    assert_eq!((path[1].first_payment, path[1].last_payment), (61, 66));
+    assert_eq!(path.last().unwrap().last_payment, 360);
+    // Recasting at the ceiling moves the payment by rounding cents only
+    let max = max_payment_cents(&path);
+    assert!(path.iter().filter(|period| period.rate == 11.0).all(|period| max - period.payment_cents <= 1));
+}
+
+#[test]
+fn periods_cover_every_payment_once() {
+    let terms = ArmTerms {
+        initial_fixed_months: 84,
+        adjustment_interval_months: 12,
+        ..ArmTerms::default()
+    };
+    let path = project_arm(250_000_00, 5.5, 360, &terms, ArmScenario::Index(3.5));
+
+    assert_eq!(path[0].first_payment, 1);
+    for pair in path.windows(2) {
+        assert_eq!(pair[1].first_payment, pair[0].last_payment + 1);
+        assert!(pair[1].starting_balance_cents < pair[0].starting_balance_cents);
+    }
+    assert_eq!(path.last().unwrap().last_payment, 360);
+}
+
+#[test]
+fn falling_index_is_limited_by_the_caps() {
+    // Index 1% + margin 2.75%: the first adjustment may only fall 2 points
+    let path = project_arm(300_000_00, 6.0, 360, &ArmTerms::default(), ArmScenario::Index(1.0));
+
+    assert_eq!(rates(&path)[..4], [6.0, 4.0, 3.75, 3.75]);
+    assert!(path[1].payment_cents < path[0].payment_cents);
+    assert_eq!(max_payment_cents(&path), path[0].payment_cents);
+}
+
+#[test]
+fn rate_never_drops_below_the_floor() {
+    let terms = ArmTerms {
+        floor_rate: Some(5.0),
+        ..ArmTerms::default()
+    };
+    let path = project_arm(300_000_00, 6.0, 360, &terms, ArmScenario::Index(0.0));
+    assert!(path.iter().all(|period| period.rate >= 5.0));
+    assert_eq!(path[1].rate, 5.0);
+
+    // Without a floor rate the margin is the floor
+    let path = project_arm(300_000_00, 6.0, 360, &ArmTerms::default(), ArmScenario::Index(0.0));
+    assert_eq!(path.last().unwrap().rate, 2.75);
+}
+
+#[test]
+fn product_names_use_years_when_they_can() {
+    assert_eq!(ArmTerms::default().product_name(), "5/6 ARM");
+    let terms = ArmTerms {
+        initial_fixed_months: 18,
+        ..ArmTerms::default()
+    };
+    assert_eq!(terms.product_name(), "18-month/6 ARM");
+}
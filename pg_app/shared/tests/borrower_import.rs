@@ -0,0 +1,127 @@
+//! Borrower spreadsheet import against `tests/data/borrowers/prospects.csv`
+// Amounts are written as dollars_cents, e.g. `8_000_00` for $8,000.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::Utc;
+use shared::imports::borrowers::{
+    check_mapping, check_rows, error_report, guess_mapping, read_csv, BorrowerField, RowStatus,
+};
+use shared::models::{Borrower, BorrowerInput};
+
+const PROSPECTS: &str = include_str!("data/borrowers/prospects.csv");
+
+fn existing() -> Vec<Borrower> {
+    vec![Borrower {
+        id: 42,
+        first_name: "Grace".to_string(),
+        last_name: "Okafor".to_string(),
+        email: Some("grace.okafor@example.com".to_string()),
+        phone: None,
+        monthly_income_cents: 10_000_00,
+        ssn: None,
+        date_of_birth: None,
+        pii_masked: false,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }]
+}
+
+#[test]
+fn headers_are_mapped_from_common_spellings() {
+    let table = read_csv(PROSPECTS).unwrap();
+    let mapping = guess_mapping(&table.headers);
+    assert_eq!(
+        mapping,
+        vec![
+            Some(BorrowerField::FullName),
+            Some(BorrowerField::Email),
+            Some(BorrowerField::Phone),
+            Some(BorrowerField::AnnualIncome),
+            Some(BorrowerField::LoanAmount),
+            None,
+        ]
+    );
+    assert_eq!(check_mapping(&mapping), Ok(()));
+}
+
+#[test]
+fn rows_are_built_validated_and_deduplicated() {
+    let table = read_csv(PROSPECTS).unwrap();
+    let rows = check_rows(&table, &guess_mapping(&table.headers), &existing());
+
+    // The blank line 5 is skipped
+    let lines: Vec<usize> = rows.iter().map(|r| r.line).collect();
+    assert_eq!(lines, vec![2, 3, 4, 6, 7, 8, 9]);
+
+    assert_eq!(rows[0].status, RowStatus::Ready);
+    assert_eq!(
+        rows[0].input,
+        BorrowerInput {
+            first_name: "Linh".to_string(),
+            last_name: "Nguyen".to_string(),
+            email: Some("linh.nguyen@example.com".to_string()),
+            phone: Some("(512) 555-0101".to_string()),
+            monthly_income_cents: 8_000_00,
+        }
+    );
+    assert_eq!(rows[0].loan_amount_cents, Some(385_000_00));
+
+    // validator accepts a domain without a dot, so this row is fine
+    assert_eq!(rows[1].status, RowStatus::Ready);
+    assert_eq!(rows[1].loan_amount_cents, None);
+
+    assert_eq!(
+        rows[2].status,
+        RowStatus::Duplicate {
+            borrower_id: 42,
+            name: "Grace Okafor".to_string()
+        }
+    );
+    assert_eq!(
+        rows[3].status,
+        RowStatus::Invalid(vec!["Annual Income 'sixty thousand' is not a dollar amount".to_string()])
+    );
+    // Same name and phone digits as line 2
+    assert_eq!(rows[4].status, RowStatus::RepeatedRow { line: 2 });
+    assert_eq!(
+        rows[5].status,
+        RowStatus::Invalid(vec!["Loan Amount must be more than zero".to_string()])
+    );
+    assert_eq!(
+        rows[6].status,
+        RowStatus::Invalid(vec!["Last name must be 1-100 characters".to_string()])
+    );
+}
+
+#[test]
+fn error_report_lists_skipped_rows_with_original_cells() {
+    let table = read_csv(PROSPECTS).unwrap();
+    let rows = check_rows(&table, &guess_mapping(&table.headers), &existing());
+    let report = error_report(&table, &rows).unwrap();
+    let lines: Vec<&str> = report.lines().collect();
+
+    assert_eq!(lines[0], "line,problem,Borrower Name,E-mail,Cell Phone,Salary,Loan Amount,Referred By");
+    assert_eq!(
+        lines[1],
+        "4,Matches existing borrower Grace Okafor (#42),Grace Okafor,GRACE.OKAFOR@EXAMPLE.COM,,\"120,000.00\",\"$450,000\",Past client"
+    );
+    assert_eq!(lines[3], "7,Repeats line 2,Linh Nguyen,,512.555.0101,96000,,Open house");
+    assert_eq!(lines.len(), 6);
+}
+
+#[test]
+fn mappings_without_names_are_rejected() {
+    assert_eq!(
+        check_mapping(&[Some(BorrowerField::FirstName), Some(BorrowerField::Email)]),
+        Err("Map a Full Name column, or both First Name and Last Name".to_string())
+    );
+    assert_eq!(
+        check_mapping(&[Some(BorrowerField::FullName), Some(BorrowerField::Phone), Some(BorrowerField::Phone)]),
+        Err("Phone is mapped to more than one column".to_string())
+    );
+}
+
+#[test]
+fn empty_files_are_rejected() {
+    assert_eq!(read_csv("First,Last\n\n").unwrap_err().to_string(), "line 0: the file has no rows below the header");
+}
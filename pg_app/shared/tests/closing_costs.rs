@@ -0,0 +1,144 @@
+//! Closing cost worksheet: section totals, cash to close and finance charge
+// Amounts are written as dollars_cents, e.g. `412_500_00` for $412,500.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use shared::calculations::amortization::total_interest_cents;
+use shared::calculations::closing_costs::ClosingCostSummary;
+use shared::models::{ClosingAdjustments, FeePayer, FeeSection, Loan, LoanFee, LoanPurpose, LoanStatus, LoanType};
+
+fn loan(loan_purpose: LoanPurpose) -> Loan {
+    let at = Utc.with_ymd_and_hms(2025, 5, 1, 9, 0, 0).unwrap();
+    Loan {
+        id: 42,
+        loan_number: Some("LN-0042".to_string()),
+        borrower_id: 7,
+        loan_officer_id: Some(3),
+        status: LoanStatus::Processing,
+        loan_type: LoanType::Conventional,
+        loan_purpose,
+        amount_cents: 200_000_00,
+        note_rate: Some(6.0),
+        term_months: 360,
+        application_date: NaiveDate::from_ymd_opt(2025, 5, 1),
+        created_at: at,
+        updated_at: at,
+    }
+}
+
+fn fee(section: FeeSection, name: &str, amount_cents: i64, payer: FeePayer, finance_charge: bool) -> LoanFee {
+    let at = Utc.with_ymd_and_hms(2025, 5, 1, 9, 0, 0).unwrap();
+    LoanFee {
+        id: 0,
+        loan_id: 42,
+        section,
+        name: name.to_string(),
+        amount_cents,
+        payer,
+        paid_to: None,
+        finance_charge,
+        off_list_provider: false,
+        recording_fee: false,
+        created_at: at,
+        updated_at: at,
+    }
+}
+
+fn worksheet() -> Vec<LoanFee> {
+    use FeePayer::*;
+    use FeeSection::*;
+    vec![
+        fee(Origination, "Origination Fee", 2_000_00, Borrower, true),
+        fee(Origination, "Discount Points", 1_000_00, Borrower, true),
+        fee(CannotShop, "Appraisal Fee", 600_00, Borrower, false),
+        fee(CannotShop, "Credit Report", 50_00, Seller, false),
+        fee(CanShop, "Title - Settlement Fee", 800_00, Borrower, true),
+        fee(TaxesGovernment, "Recording Fees", 150_00, Borrower, false),
+        fee(Prepaids, "Prepaid Interest", 500_00, Borrower, true),
+        fee(Prepaids, "Homeowners Insurance Premium", 1_500_00, Lender, false),
+        fee(InitialEscrow, "Property Taxes", 1_200_00, Borrower, false),
+    ]
+}
+
+fn adjustments() -> ClosingAdjustments {
+    ClosingAdjustments {
+        loan_id: 42,
+        earnest_money_cents: 5_000_00,
+        seller_credits_cents: 1_000_00,
+        lender_credits_cents: 250_00,
+        payoffs_cents: 0,
+        disclosed_apr: None,
+    }
+}
+
+#[test]
+fn sections_total_only_what_the_borrower_pays() {
+    let summary =
+        ClosingCostSummary::calculate(&worksheet(), &adjustments(), &loan(LoanPurpose::Purchase), Some(250_000_00));
+
+    assert_eq!(summary.section_total(FeeSection::Origination), 3_000_00);
+    assert_eq!(summary.section_total(FeeSection::CannotShop), 600_00);
+    assert_eq!(summary.section_total(FeeSection::CanShop), 800_00);
+    assert_eq!(summary.section_total(FeeSection::TaxesGovernment), 150_00);
+    assert_eq!(summary.section_total(FeeSection::Prepaids), 500_00);
+    assert_eq!(summary.section_total(FeeSection::InitialEscrow), 1_200_00);
+    assert_eq!(summary.loan_costs_cents, 4_400_00);
+    assert_eq!(summary.other_costs_cents, 1_850_00);
+    assert_eq!(summary.total_closing_costs_cents, 6_000_00);
+    assert_eq!(summary.seller_paid_cents, 50_00);
+    assert_eq!(summary.paid_by_others_cents, 1_500_00);
+}
+
+#[test]
+fn purchase_cash_to_close_adds_the_price_and_takes_off_deposits() {
+    let summary =
+        ClosingCostSummary::calculate(&worksheet(), &adjustments(), &loan(LoanPurpose::Purchase), Some(250_000_00));
+
+    // 6,000 costs + 250,000 price − 200,000 loan − 5,000 deposit − 1,000 seller credits
+    assert_eq!(summary.cash_to_close_cents, 50_000_00);
+}
+
+#[test]
+fn refinance_cash_to_close_ignores_the_purchase_price() {
+    let adjustments = ClosingAdjustments {
+        earnest_money_cents: 0,
+        seller_credits_cents: 0,
+        payoffs_cents: 180_000_00,
+        ..adjustments()
+    };
+
+    for purpose in [LoanPurpose::Refinance, LoanPurpose::CashOutRefinance] {
+        // A price left on the property from its purchase is not owed again
+        let summary = ClosingCostSummary::calculate(&worksheet(), &adjustments, &loan(purpose), Some(250_000_00));
+
+        // 6,000 costs + 180,000 payoffs − 200,000 loan is cash back
+        assert_eq!(summary.cash_to_close_cents, -14_000_00, "{purpose}");
+    }
+}
+
+#[test]
+fn finance_charge_is_prepaid_charges_plus_interest() {
+    let loan = loan(LoanPurpose::Purchase);
+    let summary = ClosingCostSummary::calculate(&worksheet(), &adjustments(), &loan, None);
+
+    assert_eq!(summary.prepaid_finance_charge_cents, 4_300_00);
+    assert_eq!(summary.amount_financed_cents, 195_700_00);
+    assert_eq!(
+        summary.total_finance_charge_cents,
+        4_300_00 + total_interest_cents(200_000_00, 6.0, 360)
+    );
+    let apr = summary.apr.unwrap();
+    assert!(apr > 6.0 && apr < 6.5, "{apr}");
+}
+
+#[test]
+fn finance_charge_without_a_rate_is_just_the_prepaid_charges() {
+    let loan = Loan {
+        note_rate: None,
+        ..loan(LoanPurpose::Purchase)
+    };
+    let summary = ClosingCostSummary::calculate(&worksheet(), &adjustments(), &loan, None);
+
+    assert_eq!(summary.total_finance_charge_cents, 4_300_00);
+    assert_eq!(summary.apr, None);
+}
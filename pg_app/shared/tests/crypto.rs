@@ -0,0 +1,114 @@
+//! Sealing PII with versioned keys and the blind indexes used to look it up
+#![cfg(feature = "backend")]
+
+use shared::crypto::{sealed_version, CryptoError, Keyring, PiiField, PII_KEY_LEN};
+
+const INDEX_KEY: [u8; PII_KEY_LEN] = [7; PII_KEY_LEN];
+
+fn keyring(versions: &[u32]) -> Keyring {
+    let keys: Vec<(u32, [u8; PII_KEY_LEN])> = versions.iter().map(|v| (*v, [*v as u8; PII_KEY_LEN])).collect();
+    Keyring::new(&keys, INDEX_KEY).unwrap()
+}
+
+#[test]
+fn sealed_values_open_and_never_repeat() {
+    let keys = keyring(&[1]);
+    let first = keys.seal("123456789").unwrap();
+    let second = keys.seal("123456789").unwrap();
+
+    assert!(first.starts_with("v1:"));
+    assert!(!first.contains("123456789"));
+    // A fresh nonce each time, so equal SSNs cannot be spotted in the table
+    assert_ne!(first, second);
+    assert_eq!(keys.open(&first).unwrap(), "123456789");
+    assert_eq!(keys.open(&second).unwrap(), "123456789");
+}
+
+#[test]
+fn rotation_keeps_old_values_readable_until_resealed() {
+    let old = keyring(&[1]);
+    let stored = old.seal("1988-04-12").unwrap();
+
+    let rotated = keyring(&[1, 2]);
+    assert_eq!(rotated.current_version(), 2);
+    assert_eq!(rotated.open(&stored).unwrap(), "1988-04-12");
+    assert!(rotated.needs_reseal(&stored));
+
+    let resealed = rotated.reseal(&stored).unwrap();
+    assert_eq!(sealed_version(&resealed), Some(2));
+    assert!(!rotated.needs_reseal(&resealed));
+
+    // Once the old key is retired, only resealed values open
+    let retired = keyring(&[2]);
+    assert_eq!(retired.open(&resealed).unwrap(), "1988-04-12");
+    assert_eq!(retired.open(&stored), Err(CryptoError::UnknownKey(1)));
+}
+
+#[test]
+fn legacy_plain_text_reads_as_is_and_needs_sealing() {
+    let keys = keyring(&[1]);
+    assert_eq!(keys.open("123456789").unwrap(), "123456789");
+    assert!(keys.needs_reseal("123456789"));
+    assert_eq!(sealed_version(&keys.reseal("123456789").unwrap()), Some(1));
+}
+
+#[test]
+fn altered_or_foreign_ciphertext_is_rejected() {
+    let keys = keyring(&[1]);
+    let stored = keys.seal("000123456789").unwrap();
+
+    let (prefix, payload) = stored.split_once(':').unwrap();
+    let mut altered: Vec<char> = payload.chars().collect();
+    altered[20] = if altered[20] == 'A' { 'B' } else { 'A' };
+    let altered = format!("{}:{}", prefix, altered.into_iter().collect::<String>());
+    assert_eq!(keys.open(&altered), Err(CryptoError::Tampered));
+
+    // Same version number, different key
+    let other = Keyring::new(&[(1, [9; PII_KEY_LEN])], INDEX_KEY).unwrap();
+    assert_eq!(other.open(&stored), Err(CryptoError::Tampered));
+
+    assert_eq!(keys.open("v1:not base64!"), Err(CryptoError::Malformed));
+    assert_eq!(keys.open("v1:AAAA"), Err(CryptoError::Malformed));
+}
+
+#[test]
+fn blind_index_matches_normalized_values_per_field() {
+    let keys = keyring(&[1]);
+    let index = keys.blind_index(PiiField::Ssn, "123456789");
+
+    assert_eq!(index.len(), 32);
+    assert_eq!(keys.blind_index(PiiField::Ssn, "123-45-6789"), index);
+    assert_ne!(keys.blind_index(PiiField::Ssn, "123456780"), index);
+    // Same digits as an account number index differently
+    assert_ne!(keys.blind_index(PiiField::AccountNumber, "123456789"), index);
+    assert_eq!(
+        keys.blind_index(PiiField::AccountNumber, "z88-310455"),
+        keys.blind_index(PiiField::AccountNumber, "Z88 310455")
+    );
+
+    // The index key, not the sealing keys, decides the index
+    assert_eq!(keyring(&[1, 2]).blind_index(PiiField::Ssn, "123456789"), index);
+    let other = Keyring::new(&[(1, [1; PII_KEY_LEN])], [8; PII_KEY_LEN]).unwrap();
+    assert_ne!(other.blind_index(PiiField::Ssn, "123456789"), index);
+}
+
+#[test]
+fn config_errors_never_echo_keys() {
+    let key = "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=";
+    assert!(Keyring::from_config(&format!("1:{key}"), key).is_ok());
+
+    for (keys, index_key) in [
+        ("", key),
+        (key, key),
+        ("0:MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=", key),
+        ("1:MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=,1:MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=", key),
+        ("1:MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=", "c2hvcnQ="),
+    ] {
+        let err = Keyring::from_config(keys, index_key).unwrap_err();
+        assert!(matches!(err, CryptoError::Config(_)), "{keys}: {err}");
+        assert!(!err.to_string().contains(key), "{err}");
+    }
+
+    let debug = format!("{:?}", keyring(&[1, 3]));
+    assert_eq!(debug, "Keyring { versions: [1, 3] }");
+}
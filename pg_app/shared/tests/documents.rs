@@ -0,0 +1,240 @@
+//! PDF loan documents: merge fields, templates and page layout
+// Amounts are written as dollars_cents, e.g. `412_500_00` for $412,500.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use shared::documents::{file_name, render_document, render_pdf, template, Block, DocumentData, TemplateError};
+use shared::models::{
+    AdverseAction, Borrower, DenialReason, DocumentKind, Loan, LoanPurpose, LoanStatus, LoanType, Occupancy, Property,
+    PropertyType, User, UserRole,
+};
+use strum::IntoEnumIterator;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 5, 19).unwrap()
+}
+
+fn data() -> DocumentData {
+    let at = Utc.with_ymd_and_hms(2025, 5, 1, 9, 0, 0).unwrap();
+    DocumentData {
+        loan: Loan {
+            id: 42,
+            loan_number: Some("LN-0042".to_string()),
+            borrower_id: 7,
+            loan_officer_id: Some(3),
+            status: LoanStatus::Processing,
+            loan_type: LoanType::Conventional,
+            loan_purpose: LoanPurpose::Purchase,
+            amount_cents: 412_500_00,
+            note_rate: Some(6.875),
+            term_months: 360,
+            application_date: NaiveDate::from_ymd_opt(2025, 5, 1),
+            created_at: at,
+            updated_at: at,
+        },
+        borrower: Borrower {
+            id: 7,
+            first_name: "Grace".to_string(),
+            last_name: "Okafor".to_string(),
+            email: Some("grace@example.com".to_string()),
+            phone: None,
+            monthly_income_cents: 11_250_00,
+            ssn: Some("123-45-6789".to_string()),
+            date_of_birth: None,
+            pii_masked: false,
+            created_at: at,
+            updated_at: at,
+        },
+        property: Some(Property {
+            id: 11,
+            loan_id: 42,
+            street: "18 Larkspur Ln".to_string(),
+            city: "Austin".to_string(),
+            state: "TX".to_string(),
+            zip: "78704".to_string(),
+            occupancy: Occupancy::PrimaryResidence,
+            property_type: PropertyType::SingleFamily,
+            units: 1,
+            purchase_price_cents: Some(550_000_00),
+            appraised_value_cents: None,
+            estimated_value_cents: None,
+            created_at: at,
+            updated_at: at,
+        }),
+        loan_officer: Some(User {
+            id: 3,
+            username: "mreyes".to_string(),
+            first_name: "Marco".to_string(),
+            last_name: "Reyes".to_string(),
+            email: "marco@example.com".to_string(),
+            password_hash: String::new(),
+            role: UserRole::default(),
+            is_active: true,
+            created_at: None,
+            last_login: None,
+            failed_login_attempts: 0,
+            nmls_id: Some("1234567".to_string()),
+        }),
+        adverse_action: None,
+    }
+}
+
+fn pdf_text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+#[test]
+fn merge_fields_are_formatted_for_print() {
+    let fields = data().merge_fields(today());
+
+    assert_eq!(fields.get("date"), Some("May 19, 2025"));
+    assert_eq!(fields.get("expiration_date"), Some("August 17, 2025"));
+    assert_eq!(fields.get("loan.amount"), Some("$412,500.00"));
+    assert_eq!(fields.get("loan.rate"), Some("6.875%"));
+    assert_eq!(fields.get("loan.term"), Some("360 months (30 years)"));
+    assert_eq!(fields.get("loan.payment"), Some("$2,709.83"));
+    assert_eq!(fields.get("loan.ltv"), Some("75.00%"));
+    assert_eq!(fields.get("borrower.phone"), Some("Not provided"));
+    assert_eq!(fields.get("property.address"), Some("18 Larkspur Ln, Austin, TX 78704"));
+    assert_eq!(fields.get("officer.name"), Some("Marco Reyes"));
+    assert!(fields.names().all(|name| !name.contains("ssn")));
+}
+
+#[test]
+fn missing_property_and_officer_have_placeholders() {
+    let mut data = data();
+    data.property = None;
+    data.loan_officer = None;
+    data.loan.loan_number = None;
+    let fields = data.merge_fields(today());
+
+    assert_eq!(fields.get("property.address"), Some("To be determined"));
+    assert_eq!(fields.get("loan.ltv"), Some("To be determined"));
+    assert_eq!(fields.get("officer.name"), Some("Unassigned"));
+    assert_eq!(fields.get("loan.number"), Some("#42"));
+    assert_eq!(
+        file_name(DocumentKind::LoanSummary, &data.loan, today()),
+        "loan-summary-42-2025-05-19.pdf"
+    );
+}
+
+#[test]
+fn leads_without_a_rate_leave_the_terms_to_be_determined() {
+    let mut data = data();
+    data.loan.status = LoanStatus::Lead;
+    data.loan.note_rate = None;
+    let fields = data.merge_fields(today());
+
+    assert_eq!(fields.get("loan.rate"), Some("To be determined"));
+    assert_eq!(fields.get("loan.payment"), Some("To be determined"));
+    assert_eq!(fields.get("loan.total_interest"), Some("To be determined"));
+    assert_eq!(fields.get("loan.amount"), Some("$412,500.00"));
+    assert!(shared::documents::schedule_table(&data.loan).rows.is_empty());
+}
+
+#[test]
+fn every_template_renders() {
+    for kind in DocumentKind::iter() {
+        let bytes = render_document(kind, &data(), today()).unwrap();
+        let text = pdf_text(&bytes);
+        assert!(bytes.starts_with(b"%PDF-"), "{kind}");
+        assert!(text.contains("/BaseFont /Helvetica"), "{kind}");
+        assert!(text.contains("Grace Okafor"), "{kind}");
+        assert!(!text.contains("123-45-6789"), "{kind}");
+        // Same input, same bytes
+        assert_eq!(bytes, render_document(kind, &data(), today()).unwrap(), "{kind}");
+    }
+}
+
+#[test]
+fn pre_approval_letter_states_the_terms() {
+    let text = pdf_text(&render_document(DocumentKind::PreApprovalLetter, &data(), today()).unwrap());
+
+    assert!(text.contains("(Dear Grace,)"));
+    assert!(text.contains("($412,500.00)"));
+    assert!(text.contains("($2,709.83 per month)"));
+    assert!(text.contains("(Page 1 of 1)"));
+    assert_eq!(
+        file_name(DocumentKind::PreApprovalLetter, &data().loan, today()),
+        "pre-approval-letter-LN-0042-2025-05-19.pdf"
+    );
+}
+
+#[test]
+fn adverse_action_notice_lists_the_reasons_in_order() {
+    let mut data = data();
+    let at = Utc.with_ymd_and_hms(2025, 5, 16, 9, 0, 0).unwrap();
+    data.loan.status = LoanStatus::Denied;
+    data.adverse_action = Some(AdverseAction {
+        loan_id: 42,
+        reasons: vec![DenialReason::CreditHistory, DenialReason::Other],
+        other_reason: Some("Bankruptcy discharged within two years".to_string()),
+        decided_date: NaiveDate::from_ymd_opt(2025, 5, 16).unwrap(),
+        decided_by: Some(3),
+        document_id: None,
+        delivered_date: None,
+        delivery_method: None,
+        delivered_by: None,
+        created_at: at,
+        updated_at: at,
+    });
+
+    let fields = data.merge_fields(today());
+    assert_eq!(fields.get("denial.date"), Some("May 16, 2025"));
+    assert_eq!(
+        fields.get("denial.reasons"),
+        Some("1. Delinquent, derogatory or insufficient credit history; 2. Bankruptcy discharged within two years")
+    );
+
+    let text = pdf_text(&render_document(DocumentKind::AdverseActionNotice, &data, today()).unwrap());
+    assert!(text.contains("(Statement of Credit Denial)"));
+    assert!(text.contains("Equal Credit Opportunity Act"));
+    assert_eq!(
+        file_name(DocumentKind::AdverseActionNotice, &data.loan, today()),
+        "adverse-action-notice-LN-0042-2025-05-19.pdf"
+    );
+}
+
+#[test]
+fn amortization_schedule_spans_pages_with_every_payment() {
+    let text = pdf_text(&render_document(DocumentKind::AmortizationSchedule, &data(), today()).unwrap());
+
+    let pages = text.matches("(Page ").count();
+    assert!(pages >= 7, "{pages} pages");
+    assert!(text.contains(&format!("(Page {pages} of {pages})")));
+    // The table header repeats on each page
+    assert_eq!(text.matches("(Balance)").count(), pages);
+    assert!(text.contains("(360)"));
+    assert!(text.contains("($0.00)"));
+}
+
+#[test]
+fn template_errors_name_the_line() {
+    let fields = data().merge_fields(today());
+
+    assert_eq!(
+        template::parse("# Letter\n\nSSN :: {{borrower.ssn}}", &fields),
+        Err(TemplateError::UnknownField {
+            line: 3,
+            field: "borrower.ssn".to_string()
+        })
+    );
+    assert_eq!(
+        template::parse("Dear {{borrower.first_name", &fields),
+        Err(TemplateError::Unclosed { line: 1 })
+    );
+}
+
+#[test]
+fn long_lines_wrap_and_text_is_win_ansi() {
+    let words = "closing ".repeat(60);
+    let bytes = render_pdf(
+        "Notes",
+        &[Block::Line(words.trim().to_string()), Block::Line("Café – “quoted”".to_string())],
+    );
+
+    // Wrapped onto several lines, none holding all the words
+    assert!(bytes.windows(9).filter(|w| w == b"(closing ").count() >= 3);
+    // Non-ASCII text is written as a hex string of WinAnsi codes
+    assert!(pdf_text(&bytes).to_lowercase().contains("<436166e92096209371756f74656494>"));
+}
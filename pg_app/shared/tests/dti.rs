@@ -0,0 +1,64 @@
+//! Debt totals and debt-to-income ratios from a borrower's liabilities
+// Amounts are written as dollars_cents, e.g. `412_50` for $412.50
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::{TimeZone, Utc};
+use shared::calculations::dti::{back_end_dti, debt_to_income, DebtSummary};
+use shared::models::{Liability, LiabilityType};
+
+fn liability(balance_cents: i64, monthly_payment_cents: i64, paid_off_at_closing: bool, excluded: bool) -> Liability {
+    let at = Utc.with_ymd_and_hms(2025, 5, 1, 9, 0, 0).unwrap();
+    Liability {
+        id: 0,
+        borrower_id: 7,
+        liability_type: LiabilityType::Installment,
+        creditor_name: "Creditor".to_string(),
+        account_last4: None,
+        balance_cents,
+        monthly_payment_cents,
+        paid_off_at_closing,
+        excluded,
+        source: "manual".to_string(),
+        created_at: at,
+        updated_at: at,
+    }
+}
+
+#[test]
+fn payoffs_and_exclusions_do_not_count_toward_dti() {
+    let summary = DebtSummary::from_liabilities(&[
+        liability(18_200_00, 412_50, false, false),
+        liability(2_450_17, 75_00, true, false),
+        liability(900_00, 150_00, false, true),
+    ]);
+
+    assert_eq!(summary.total_balance_cents, 21_550_17);
+    assert_eq!(summary.total_monthly_cents, 637_50);
+    assert_eq!(summary.counted_monthly_cents, 412_50);
+    assert_eq!(summary.payoff_at_closing_cents, 2_450_17);
+}
+
+#[test]
+fn no_liabilities_is_an_empty_summary() {
+    assert_eq!(DebtSummary::from_liabilities(&[]), DebtSummary::default());
+}
+
+#[test]
+fn ratio_needs_positive_income() {
+    assert_eq!(debt_to_income(1_500_00, 5_000_00), Some(30.0));
+    assert_eq!(debt_to_income(0, 5_000_00), Some(0.0));
+    assert_eq!(debt_to_income(1_500_00, 0), None);
+    assert_eq!(debt_to_income(1_500_00, -1), None);
+}
+
+#[test]
+fn back_end_dti_adds_the_housing_payment_to_counted_debts() {
+    let summary = DebtSummary::from_liabilities(&[
+        liability(18_200_00, 500_00, false, false),
+        liability(2_450_17, 75_00, true, false),
+    ]);
+
+    // ($500 + $2,500) / $10,000
+    assert_eq!(back_end_dti(&summary, 2_500_00, 10_000_00), Some(30.0));
+    assert_eq!(back_end_dti(&summary, 2_500_00, 0), None);
+}
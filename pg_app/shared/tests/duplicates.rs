@@ -0,0 +1,178 @@
+//! Duplicate borrower detection and the merge selection
+// Amounts are written as dollars_cents, e.g. `9_500_00` for $9,500.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use shared::duplicates::{find_duplicates, MatchSignal, CANDIDATE_SCORE};
+use shared::models::{Borrower, BorrowerMerge, MergeField, MergeSelection};
+
+fn borrower(id: i32, first: &str, last: &str) -> Borrower {
+    Borrower {
+        id,
+        first_name: first.to_string(),
+        last_name: last.to_string(),
+        email: None,
+        phone: None,
+        monthly_income_cents: 0,
+        ssn: None,
+        date_of_birth: None,
+        pii_masked: false,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn born(y: i32, m: u32, d: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(y, m, d)
+}
+
+#[test]
+fn misspelled_name_with_same_birthday_and_phone_is_found() {
+    let grace = Borrower {
+        phone: Some("(512) 555-0142".to_string()),
+        date_of_birth: born(1988, 4, 12),
+        ..borrower(3, "Grace", "Okafor")
+    };
+    let typo = Borrower {
+        phone: Some("+1 512.555.0142".to_string()),
+        date_of_birth: born(1988, 4, 12),
+        ..borrower(9, "Grace", "Okafr")
+    };
+    let stranger = Borrower { date_of_birth: born(1988, 4, 12), ..borrower(5, "Tomas", "Lindqvist") };
+
+    let found = find_duplicates(&[typo, stranger, grace]);
+    assert_eq!(found.len(), 1);
+    let pair = &found[0];
+    assert_eq!((pair.first.id, pair.second.id), (3, 9));
+    assert_eq!(pair.signals, [MatchSignal::SamePhone, MatchSignal::SameDateOfBirth, MatchSignal::SimilarName]);
+    assert_eq!(pair.score, 75);
+    assert!(!pair.is_strong());
+}
+
+#[test]
+fn name_alone_is_not_enough() {
+    let found = find_duplicates(&[borrower(1, "John", "Smith"), borrower(2, "John", "Smith")]);
+    assert!(found.is_empty());
+
+    let with_email = Borrower { email: Some("JSmith@example.com ".to_string()), ..borrower(2, "John", "Smith") };
+    let found = find_duplicates(&[
+        Borrower { email: Some("jsmith@example.com".to_string()), ..borrower(1, "John", "Smith") },
+        with_email,
+    ]);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].signals, [MatchSignal::SameEmail, MatchSignal::SameName]);
+}
+
+#[test]
+fn conflicting_identifiers_rule_a_pair_out() {
+    let a = Borrower {
+        ssn: Some("123456789".to_string()),
+        date_of_birth: born(1970, 1, 1),
+        email: Some("family@example.com".to_string()),
+        ..borrower(1, "Maria", "Garcia")
+    };
+    // Same household email, different person
+    let b = Borrower {
+        ssn: Some("987650000".to_string()),
+        date_of_birth: born(1998, 6, 30),
+        email: Some("family@example.com".to_string()),
+        ..borrower(2, "Mario", "Garcia")
+    };
+    assert!(find_duplicates(&[a.clone(), b]).is_empty());
+
+    // One digit off in the first five: same last four still counts
+    let typo = Borrower { ssn: Some("123556789".to_string()), ..a.clone() };
+    let found = find_duplicates(&[a, Borrower { id: 2, ..typo }]);
+    assert_eq!(found.len(), 1);
+    assert!(found[0].signals.contains(&MatchSignal::SameSsnLastFour));
+    assert!(found[0].score >= CANDIDATE_SCORE);
+}
+
+#[test]
+fn same_ssn_is_a_candidate_whatever_the_name() {
+    let found = find_duplicates(&[
+        Borrower { ssn: Some("555443333".to_string()), ..borrower(4, "Robert", "Nguyen") },
+        Borrower { ssn: Some("555443333".to_string()), ..borrower(7, "Bob", "Nguyen-Tran") },
+    ]);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].signals, [MatchSignal::SameSsn]);
+}
+
+#[test]
+fn merge_selection_fills_blanks_and_honours_choices() {
+    let survivor = Borrower {
+        email: Some("grace@example.com".to_string()),
+        monthly_income_cents: 9_500_00,
+        ..borrower(3, "Grace", "Okafor")
+    };
+    let merged = Borrower {
+        email: Some("g.okafor@work.example".to_string()),
+        phone: Some("512-555-0142".to_string()),
+        ssn: Some("123456789".to_string()),
+        monthly_income_cents: 11_250_00,
+        ..borrower(9, "Grace", "Okafr")
+    };
+
+    let mut selection = MergeSelection::new(&survivor, &merged);
+    assert_eq!((selection.survivor_id, selection.merged_id), (3, 9));
+    assert_eq!(selection.from_merged, [MergeField::Phone, MergeField::Ssn]);
+
+    selection.choose(MergeField::MonthlyIncome, true);
+    selection.choose(MergeField::Ssn, false);
+    selection.choose(MergeField::Ssn, false);
+    let result = selection.apply(&survivor, &merged);
+    assert_eq!(result.id, 3);
+    assert_eq!(result.last_name, "Okafor");
+    assert_eq!(result.email.as_deref(), Some("grace@example.com"));
+    assert_eq!(result.phone.as_deref(), Some("512-555-0142"));
+    assert_eq!(result.ssn, None);
+    assert_eq!(result.monthly_income_cents, 11_250_00);
+
+    assert_eq!(MergeField::Ssn.display(&merged), "***-**-6789");
+    assert_eq!(MergeField::MonthlyIncome.display(&survivor), "$9,500.00");
+}
+
+#[test]
+fn undo_restores_only_the_taken_fields_within_the_window() {
+    let merged_at = Utc.with_ymd_and_hms(2025, 5, 19, 15, 0, 0).unwrap();
+    let merge = BorrowerMerge {
+        id: 1,
+        survivor_id: 3,
+        merged_id: 9,
+        taken_fields: vec![MergeField::Phone, MergeField::MonthlyIncome],
+        prior_first_name: "Grace".to_string(),
+        prior_last_name: "Okafor".to_string(),
+        prior_email: Some("grace@example.com".to_string()),
+        prior_phone: None,
+        prior_date_of_birth: None,
+        prior_ssn: None,
+        prior_monthly_income_cents: 9_500_00,
+        loan_ids: vec![42],
+        co_borrower_loan_ids: vec![],
+        note_ids: vec![5, 6],
+        liability_ids: vec![],
+        employment_ids: vec![],
+        asset_ids: vec![],
+        merged_by: Some(2),
+        merged_at,
+        undone_by: None,
+        undone_at: None,
+    };
+
+    // The email was edited after the merge; undo keeps that edit
+    let current = Borrower {
+        email: Some("grace.okafor@example.com".to_string()),
+        phone: Some("512-555-0142".to_string()),
+        monthly_income_cents: 11_250_00,
+        ..borrower(3, "Grace", "Okafor")
+    };
+    let restored = merge.restore(&current);
+    assert_eq!(restored.email.as_deref(), Some("grace.okafor@example.com"));
+    assert_eq!(restored.phone, None);
+    assert_eq!(restored.monthly_income_cents, 9_500_00);
+
+    assert!(merge.can_undo(merged_at + Duration::hours(71)));
+    assert!(!merge.can_undo(merged_at + Duration::hours(73)));
+    let undone = BorrowerMerge { undone_at: Some(merged_at + Duration::hours(1)), ..merge };
+    assert!(!undone.can_undo(merged_at + Duration::hours(2)));
+}
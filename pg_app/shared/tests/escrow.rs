@@ -0,0 +1,81 @@
+//! Escrow disbursements and the RESPA aggregate initial deposit
+// Amounts are written as dollars_cents, e.g. `500_01` for $500.01
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use shared::calculations::escrow::{escrow_analysis, monthly_escrow_cents, EscrowItem};
+
+fn item(annual_cents: i64, due_months: Vec<u32>) -> EscrowItem {
+    EscrowItem {
+        name: "Property Taxes".to_string(),
+        annual_cents,
+        due_months,
+    }
+}
+
+fn year_total(item: &EscrowItem) -> i64 {
+    (1..=12).map(|month| item.disbursement_in(month)).sum()
+}
+
+#[test]
+fn uneven_installments_give_the_remainder_to_the_last() {
+    let taxes = item(1_000_01, vec![6, 12]);
+    assert_eq!(taxes.disbursement_in(6), 500_00);
+    assert_eq!(taxes.disbursement_in(12), 500_01);
+    assert_eq!(taxes.disbursement_in(1), 0);
+
+    let thirds = item(1_000_00, vec![4, 8, 12]);
+    assert_eq!(thirds.disbursement_in(4), 333_33);
+    assert_eq!(thirds.disbursement_in(8), 333_33);
+    assert_eq!(thirds.disbursement_in(12), 333_34);
+}
+
+#[test]
+fn disbursements_add_up_to_the_annual_bill() {
+    for annual_cents in [0, 1, 2_400_00, 3_117_47, 10_000_03] {
+        for due_months in [vec![], vec![3], vec![6, 12], vec![1, 4, 7, 10], (1..=12).collect()] {
+            let bill = item(annual_cents, due_months.clone());
+            let expected = if due_months.is_empty() { 0 } else { annual_cents };
+            assert_eq!(year_total(&bill), expected, "{annual_cents} in {due_months:?}");
+        }
+    }
+}
+
+#[test]
+fn repeated_and_unordered_months_are_paid_once() {
+    let taxes = item(1_000_01, vec![12, 6, 6, 13]);
+    assert_eq!(taxes.disbursement_in(6), 500_00);
+    assert_eq!(taxes.disbursement_in(12), 500_01);
+    assert_eq!(year_total(&taxes), 1_000_01);
+}
+
+#[test]
+fn monthly_escrow_rounds_each_item() {
+    let items = vec![item(1_000_01, vec![12]), item(1_200_00, vec![3])];
+    // $83.33 + $100.00
+    assert_eq!(monthly_escrow_cents(&items), 183_33);
+}
+
+#[test]
+fn initial_deposit_covers_the_lowest_balance_plus_the_cushion() {
+    let items = vec![
+        item(4_800_00, vec![6, 12]),
+        EscrowItem {
+            name: "Homeowners Insurance".to_string(),
+            annual_cents: 1_200_00,
+            due_months: vec![3],
+        },
+    ];
+
+    // First payment in February: the balance bottoms out at -$1,100 after June's taxes
+    let analysis = escrow_analysis(&items, 2, 2);
+    assert_eq!(analysis.monthly_cents, 500_00);
+    assert_eq!(analysis.cushion_cents, 1_000_00);
+    assert_eq!(analysis.initial_deposit_cents, 2_100_00);
+    assert_eq!(
+        analysis.initial_deposit_cents,
+        analysis.items.iter().map(|i| i.deposit_cents).sum::<i64>() + analysis.aggregate_adjustment_cents,
+    );
+
+    // The cushion is capped at two months
+    assert_eq!(escrow_analysis(&items, 2, 6).cushion_cents, 1_000_00);
+}
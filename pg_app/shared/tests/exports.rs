@@ -0,0 +1,165 @@
+//! List exports: column lookup, the generated SQL and the CSV and XLSX output
+// Amounts are written as dollars_cents, e.g. `8_000_00` for $8,000.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use std::io::Read;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use shared::exports::{Cell, ExportError, ExportFormat, ExportList, ExportRequest, ExportWriter};
+use strum::IntoEnumIterator;
+
+fn loan_row() -> Vec<Cell> {
+    vec![
+        Cell::Text("Okafor, Grace".to_string()),
+        Cell::Text("Clear to Close".to_string()),
+        Cell::Money(412_500_00),
+        Cell::Rate(6.875),
+        Cell::Date(NaiveDate::from_ymd_opt(2025, 5, 1).unwrap()),
+        Cell::Empty,
+    ]
+}
+
+fn loan_request(format: ExportFormat) -> ExportRequest {
+    ExportRequest::new(
+        ExportList::Loans,
+        format,
+        ["borrower", "status", "amount", "note_rate", "application_date", "state"],
+    )
+}
+
+fn export(request: &ExportRequest, rows: Vec<Vec<Cell>>) -> Vec<u8> {
+    let mut writer = ExportWriter::new(request.format, &request.columns().unwrap()).unwrap();
+    for row in rows {
+        writer.write_row(row).unwrap();
+    }
+    writer.finish().unwrap()
+}
+
+fn zip_entry(bytes: &[u8], name: &str) -> String {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+    let mut xml = String::new();
+    archive.by_name(name).unwrap().read_to_string(&mut xml).unwrap();
+    xml
+}
+
+#[test]
+fn every_list_has_unique_column_codes() {
+    for list in ExportList::iter() {
+        let codes: Vec<&str> = list.columns().iter().map(|column| column.code).collect();
+        let all = ExportRequest::new(list, ExportFormat::Csv, codes.clone());
+        assert_eq!(all.columns().unwrap().len(), codes.len(), "{list}");
+        assert!(all.query().unwrap().starts_with("SELECT "), "{list}");
+    }
+    assert!(ExportList::Borrowers.column("ssn").is_err());
+}
+
+#[test]
+fn query_casts_columns_and_sorts_with_a_tie_breaker() {
+    let request = loan_request(ExportFormat::Csv).sorted_by("updated_at", true);
+    let sql = request.query().unwrap();
+
+    assert!(sql.starts_with(
+        "SELECT (b.first_name || ' ' || b.last_name)::TEXT, (l.status)::TEXT, (l.amount_cents)::BIGINT, \
+         (l.note_rate)::DOUBLE PRECISION, (l.application_date)::DATE, (p.state)::TEXT FROM loans l"
+    ));
+    assert!(sql.contains("($2::loan_type IS NULL OR l.loan_type = $2)"));
+    assert!(sql.ends_with("ORDER BY l.updated_at DESC NULLS LAST, l.id"));
+}
+
+#[test]
+fn bad_requests_are_rejected() {
+    let none = ExportRequest::new(ExportList::Users, ExportFormat::Csv, Vec::<String>::new());
+    assert_eq!(none.query(), Err(ExportError::NoColumns));
+
+    let twice = ExportRequest::new(ExportList::Users, ExportFormat::Csv, ["email", "email"]);
+    assert_eq!(twice.query(), Err(ExportError::DuplicateColumn("Email".to_string())));
+
+    let injected =
+        ExportRequest::new(ExportList::Users, ExportFormat::Csv, ["email"]).sorted_by("1; DROP TABLE users", false);
+    assert_eq!(
+        injected.query().unwrap_err().to_string(),
+        "Users have no column `1; DROP TABLE users`"
+    );
+
+    let request = loan_request(ExportFormat::Csv);
+    let mut writer = ExportWriter::new(request.format, &request.columns().unwrap()).unwrap();
+    assert_eq!(
+        writer.write_row(vec![Cell::Empty]),
+        Err(ExportError::RowLength { expected: 6, found: 1 })
+    );
+}
+
+#[test]
+fn csv_keeps_numbers_and_dates_machine_readable() {
+    let request = loan_request(ExportFormat::Csv);
+    let mut second = loan_row();
+    second[2] = Cell::Money(-5);
+    second[4] = Cell::Empty;
+    second[5] = Cell::Text("TX".to_string());
+
+    let csv = String::from_utf8(export(&request, vec![loan_row(), second])).unwrap();
+
+    assert_eq!(
+        csv,
+        "Borrower,Status,Amount,Rate,Application Date,State\n\
+         \"Okafor, Grace\",Clear to Close,412500.00,6.875%,2025-05-01,\n\
+         \"Okafor, Grace\",Clear to Close,-0.05,6.875%,,TX\n"
+    );
+}
+
+#[test]
+fn csv_writes_timestamps_in_utc_and_booleans_as_spreadsheet_literals() {
+    let request = ExportRequest::new(ExportList::Users, ExportFormat::Csv, ["username", "is_active", "last_login"]);
+    let at = Utc.with_ymd_and_hms(2025, 5, 19, 14, 5, 9).unwrap();
+
+    let csv = String::from_utf8(export(
+        &request,
+        vec![
+            vec![Cell::Text("jdoe".to_string()), Cell::Boolean(true), Cell::DateTime(at)],
+            vec![Cell::Text("new_hire".to_string()), Cell::Boolean(false), Cell::Empty],
+        ],
+    ))
+    .unwrap();
+
+    assert_eq!(
+        csv,
+        "Username,Active,Last Login\njdoe,TRUE,2025-05-19 14:05:09\nnew_hire,FALSE,\n"
+    );
+}
+
+#[test]
+fn xlsx_cells_are_typed() {
+    let request = loan_request(ExportFormat::Xlsx);
+    let bytes = export(&request, vec![loan_row()]);
+    assert!(bytes.starts_with(b"PK"));
+
+    let sheet = zip_entry(&bytes, "xl/worksheets/sheet1.xml");
+    let strings = zip_entry(&bytes, "xl/sharedStrings.xml");
+
+    // Header and text cells go to the shared string table
+    assert!(strings.contains("<t>Application Date</t>"));
+    assert!(strings.contains("<t>Okafor, Grace</t>"));
+    // Money, the rate as a fraction and the date as an Excel serial number,
+    // each with a number format
+    assert!(sheet.contains(r#"<c r="C2" s="2"><v>412500</v></c>"#), "{sheet}");
+    assert!(sheet.contains(r#"<c r="D2" s="3"><v>0.06875</v></c>"#), "{sheet}");
+    assert!(sheet.contains(r#"<c r="E2" s="4"><v>45778</v></c>"#), "{sheet}");
+    // Empty cells are left out and the header row is frozen
+    assert!(!sheet.contains(r#"r="F2""#));
+    assert!(sheet.contains(r#"<pane ySplit="1""#));
+
+    let styles = zip_entry(&bytes, "xl/styles.xml");
+    assert!(styles.contains(r#"formatCode="$#,##0.00""#));
+    assert!(styles.contains(r#"formatCode="0.000%""#));
+    assert!(styles.contains(r#"formatCode="mm/dd/yyyy""#));
+}
+
+#[test]
+fn file_name_has_the_list_date_and_extension() {
+    let request = loan_request(ExportFormat::Xlsx);
+    assert_eq!(
+        request.file_name(NaiveDate::from_ymd_opt(2025, 5, 19).unwrap()),
+        "loans-2025-05-19.xlsx"
+    );
+    assert_eq!(ExportFormat::Xlsx.code().parse(), Ok(ExportFormat::Xlsx));
+}
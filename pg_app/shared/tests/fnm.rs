@@ -0,0 +1,275 @@
+//! Fannie Mae 3.2 export and import against the sample files in `tests/data/fnm`
+// Amounts are written as dollars_cents, e.g. `1_295_00` for $1,295.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::NaiveDate;
+use shared::fnm::export::to_fnm;
+use shared::fnm::import::parse_fnm;
+use shared::fnm::{FnmApplicant, FnmFile};
+use shared::models::{
+    AssetInput, AssetType, BorrowerInput, EmploymentInput, LiabilityInput, LiabilityType, LoanInput, LoanPurpose,
+    LoanType, Occupancy, PropertyInput, PropertyType,
+};
+
+const PURCHASE: &str = include_str!("data/fnm/purchase_joint.fnm");
+const REFINANCE: &str = include_str!("data/fnm/cash_out_refinance_va.fnm");
+
+fn date(y: i32, m: u32, d: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(y, m, d)
+}
+
+fn refinance_file() -> FnmFile {
+    FnmFile {
+        loan_number: Some("LN-2025-00610".to_string()),
+        loan: LoanInput {
+            loan_type: LoanType::Va,
+            loan_purpose: LoanPurpose::CashOutRefinance,
+            amount_cents: 310_250_00,
+            note_rate: 6.125,
+            term_months: 360,
+            ..Default::default()
+        },
+        property: Some(PropertyInput {
+            street: "1415 Juniper Ridge Ct".to_string(),
+            city: "Colorado Springs".to_string(),
+            state: "CO".to_string(),
+            zip: "80918".to_string(),
+            occupancy: Occupancy::PrimaryResidence,
+            property_type: PropertyType::SingleFamily,
+            units: 1,
+            purchase_price_cents: None,
+            appraised_value_cents: None,
+            estimated_value_cents: None,
+        }),
+        applicants: vec![FnmApplicant {
+            ssn: "555667777".to_string(),
+            borrower: BorrowerInput {
+                first_name: "Dana".to_string(),
+                last_name: "Whitfield".to_string(),
+                email: Some("dana.whitfield@example.com".to_string()),
+                phone: Some("7195550162".to_string()),
+                monthly_income_cents: 8_125_00,
+            },
+            employments: vec![
+                EmploymentInput {
+                    employer_name: "Peak Aerospace Systems".to_string(),
+                    position: Some("Test Engineer".to_string()),
+                    is_primary: true,
+                    is_current: true,
+                    months_on_job: Some(43),
+                    years_in_profession: Some(12),
+                    ..Default::default()
+                },
+                EmploymentInput {
+                    employer_name: "US Air Force".to_string(),
+                    position: Some("Avionics Technician".to_string()),
+                    start_date: date(2013, 6, 3),
+                    end_date: date(2021, 9, 30),
+                    monthly_income_cents: 4_380_00,
+                    ..Default::default()
+                },
+            ],
+            assets: vec![AssetInput {
+                asset_type: AssetType::Savings,
+                institution_name: "Navy Federal Credit Union".to_string(),
+                account_last4: Some("3308".to_string()),
+                account_number: Some("3308".to_string()),
+                balance_cents: 14_902_61,
+            }],
+            liabilities: vec![
+                LiabilityInput {
+                    liability_type: LiabilityType::Mortgage,
+                    creditor_name: "Freedom Mortgage".to_string(),
+                    account_last4: Some("7714".to_string()),
+                    balance_cents: 241_880_09,
+                    monthly_payment_cents: 1_702_33,
+                    paid_off_at_closing: true,
+                    excluded: false,
+                },
+                LiabilityInput {
+                    liability_type: LiabilityType::Revolving,
+                    creditor_name: "USAA Credit Card".to_string(),
+                    account_last4: None,
+                    balance_cents: 0,
+                    monthly_payment_cents: 0,
+                    paid_off_at_closing: false,
+                    excluded: true,
+                },
+            ],
+        }],
+    }
+}
+
+#[test]
+fn purchase_sample_maps_onto_records() {
+    let import = parse_fnm(PURCHASE).unwrap();
+    let file = import.file;
+
+    assert_eq!(file.loan_number.as_deref(), Some("BRK-24-1187"));
+    assert_eq!(
+        file.loan,
+        LoanInput {
+            loan_type: LoanType::Conventional,
+            loan_purpose: LoanPurpose::Purchase,
+            amount_cents: 412_000_00,
+            note_rate: 6.75,
+            term_months: 360,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        file.property,
+        Some(PropertyInput {
+            street: "2207 Cedar Hollow Rd".to_string(),
+            city: "Raleigh".to_string(),
+            state: "NC".to_string(),
+            zip: "27615".to_string(),
+            occupancy: Occupancy::PrimaryResidence,
+            property_type: PropertyType::SingleFamily,
+            units: 1,
+            purchase_price_cents: Some(515_000_00),
+            appraised_value_cents: None,
+            estimated_value_cents: None,
+        })
+    );
+
+    // The BW applicant comes first even though the QZ record is listed first
+    let ssns: Vec<&str> = file.applicants.iter().map(|a| a.ssn.as_str()).collect();
+    assert_eq!(ssns, vec!["111223333", "222334444"]);
+
+    let primary = &file.applicants[0];
+    assert_eq!(
+        primary.borrower,
+        BorrowerInput {
+            first_name: "Arjun".to_string(),
+            last_name: "Raman".to_string(),
+            email: Some("arjun.raman@example.com".to_string()),
+            phone: Some("9195550123".to_string()),
+            // Base and bonus income are summed
+            monthly_income_cents: 12_187_50,
+        }
+    );
+    assert_eq!(
+        primary.employments,
+        vec![
+            EmploymentInput {
+                employer_name: "Triangle Biologics".to_string(),
+                position: Some("Lab Director".to_string()),
+                is_primary: true,
+                is_current: true,
+                months_on_job: Some(55),
+                years_in_profession: Some(11),
+                ..Default::default()
+            },
+            EmploymentInput {
+                employer_name: "Duke Health".to_string(),
+                position: Some("Research Scientist".to_string()),
+                start_date: date(2016, 8, 1),
+                end_date: date(2020, 10, 15),
+                monthly_income_cents: 6_100_00,
+                ..Default::default()
+            },
+        ]
+    );
+    let assets: Vec<(AssetType, Option<&str>, i64)> = primary
+        .assets
+        .iter()
+        .map(|a| (a.asset_type, a.account_last4.as_deref(), a.balance_cents))
+        .collect();
+    assert_eq!(
+        assets,
+        vec![
+            (AssetType::Checking, Some("9031"), 18_420_55),
+            (AssetType::Retirement, Some("0455"), 96_310_00),
+        ]
+    );
+    assert_eq!(primary.assets[1].account_number.as_deref(), Some("Z88-310455"));
+    let debts: Vec<(LiabilityType, &str, Option<&str>, i64, i64)> = primary
+        .liabilities
+        .iter()
+        .map(|l| (l.liability_type, l.creditor_name.as_str(), l.account_last4.as_deref(), l.balance_cents, l.monthly_payment_cents))
+        .collect();
+    assert_eq!(
+        debts,
+        vec![
+            (LiabilityType::Revolving, "American Express", Some("0005"), 3_480_12, 125_00),
+            (LiabilityType::Auto, "Honda Financial Services", Some("1337"), 5_446_00, 389_00),
+        ]
+    );
+
+    let co = &file.applicants[1];
+    assert_eq!(co.borrower.first_name, "Priya");
+    assert_eq!(co.borrower.email, None);
+    assert_eq!(co.borrower.monthly_income_cents, 5_400_00);
+    assert!(co.employments[0].self_employed);
+    assert_eq!(co.employments[0].months_on_job, Some(24));
+    assert_eq!(co.assets[0].asset_type, AssetType::Savings);
+    assert_eq!(co.liabilities[0].liability_type, LiabilityType::Installment);
+    assert!(co.liabilities[0].excluded);
+}
+
+#[test]
+fn purchase_sample_lists_ignored_records() {
+    let import = parse_fnm(PURCHASE).unwrap();
+    assert_eq!(import.ignored_records, vec!["00A", "05H", "08A", "EH", "ET", "TH", "TT"]);
+}
+
+#[test]
+fn refinance_sample_matches_export() {
+    assert_eq!(to_fnm(&refinance_file()), REFINANCE);
+}
+
+#[test]
+fn refinance_sample_round_trips() {
+    let import = parse_fnm(REFINANCE).unwrap();
+    assert_eq!(import.file, refinance_file());
+    assert!(import.ignored_records.is_empty());
+}
+
+#[test]
+fn coarse_codes_are_lossy_on_export() {
+    let mut file = refinance_file();
+    file.applicants[0].liabilities[1].liability_type = LiabilityType::Student;
+    let again = parse_fnm(&to_fnm(&file)).unwrap().file;
+    // Student loans have no code of their own and come back as installment debts
+    assert_eq!(again.applicants[0].liabilities[1].liability_type, LiabilityType::Installment);
+}
+
+#[test]
+fn errors_report_line_record_and_position() {
+    // Line 20 is the first 06L; its Unpaid Balance starts at position 179
+    let bad_amount = PURCHASE.replace("3480.12", "3,480.x");
+    let errors = parse_fnm(&bad_amount).unwrap_err();
+    assert_eq!(
+        errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        vec!["line 20, record 06L, position 179: Unpaid Balance: '3,480.x' is not a dollar amount"]
+    );
+
+    let unknown_ssn = PURCHASE.replace("05I222334444", "05I999887777");
+    let errors = parse_fnm(&unknown_ssn).unwrap_err();
+    assert_eq!(errors[0].to_string(), "line 15, record 05I, position 4: Applicant Social Security Number: no 03A applicant has SSN ending 7777");
+}
+
+#[test]
+fn every_problem_is_reported() {
+    let contents: String = PURCHASE
+        .split("\r\n")
+        .filter(|line| !line.starts_with("01A"))
+        .map(|line| line.replace("QZ222334444", "XX222334444"))
+        .collect::<Vec<_>>()
+        .join("\r\n");
+    let errors = parse_fnm(&contents).unwrap_err();
+    let found: Vec<(usize, &str, usize)> = errors.iter().map(|e| (e.line, e.record.as_str(), e.position)).collect();
+    assert_eq!(
+        found,
+        vec![
+            // The co-applicant's records can no longer find them
+            (7, "03A", 4),
+            (10, "04A", 4),
+            (14, "05I", 4),
+            (18, "06C", 4),
+            (21, "06L", 4),
+            (0, "01A", 1),
+        ]
+    );
+}
@@ -0,0 +1,243 @@
+//! HMDA LAR: ULIs, record layout and the FFIEC edits
+// Amounts are written as dollars_cents, e.g. `412_500_00` for $412,500.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use shared::hmda::edits::{check_lar, EditFailure};
+use shared::hmda::lar::{build_lar, field, LarFile, LAR_FIELD_COUNT};
+use shared::hmda::{is_valid_uli, uli, LarLoan};
+use shared::models::{
+    ActionTaken, CreditScoreModel, DenialReason, Ethnicity, FederalAgency, HmdaApplicant, HmdaData, HmdaFiler,
+    HmdaInput, HoepaStatus, LienStatus, Loan, LoanPurpose, LoanStatus, LoanType, ObservationBasis, Occupancy,
+    Preapproval, Property, PropertyType, PurchaserType, Race, Sex,
+};
+use validator::Validate;
+
+const LEI: &str = "10BX939C5543TQA1144M";
+
+fn filer() -> HmdaFiler {
+    HmdaFiler {
+        lei: LEI.to_string(),
+        institution_name: "Prairie Home Lending".to_string(),
+        tax_id: "12-3456789".to_string(),
+        federal_agency: FederalAgency::Cfpb,
+        contact_name: "Dana Ortiz".to_string(),
+        contact_phone: "555-010-2000".to_string(),
+        contact_email: "compliance@example.com".to_string(),
+        contact_street: "100 Main St".to_string(),
+        contact_city: "Austin".to_string(),
+        contact_state: "TX".to_string(),
+        contact_zip: "78701".to_string(),
+        updated_by: None,
+        updated_at: Utc::now(),
+    }
+}
+
+/// An originated purchase with one applicant and every HMDA field filled in
+fn originated() -> LarLoan {
+    let at = Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap();
+    LarLoan {
+        loan: Loan {
+            id: 42,
+            loan_number: Some("LN-0042".to_string()),
+            borrower_id: 7,
+            loan_officer_id: Some(3),
+            status: LoanStatus::Closed,
+            loan_type: LoanType::Conventional,
+            loan_purpose: LoanPurpose::Purchase,
+            amount_cents: 412_500_00,
+            note_rate: Some(6.875),
+            term_months: 360,
+            application_date: NaiveDate::from_ymd_opt(2025, 3, 3),
+            created_at: at,
+            updated_at: at,
+        },
+        property: Some(Property {
+            id: 11,
+            loan_id: 42,
+            street: "18 Larkspur Ln".to_string(),
+            city: "Austin".to_string(),
+            state: "TX".to_string(),
+            zip: "78704".to_string(),
+            occupancy: Occupancy::PrimaryResidence,
+            property_type: PropertyType::SingleFamily,
+            units: 1,
+            purchase_price_cents: Some(550_000_00),
+            appraised_value_cents: Some(550_000_00),
+            estimated_value_cents: None,
+            created_at: at,
+            updated_at: at,
+        }),
+        hmda: HmdaData {
+            loan_id: 42,
+            action_taken: Some(ActionTaken::Originated),
+            action_taken_date: NaiveDate::from_ymd_opt(2025, 4, 18),
+            preapproval: Preapproval::NotRequested,
+            county: Some("48453".to_string()),
+            census_tract: Some("48453001305".to_string()),
+            applicant_ethnicities: vec![Ethnicity::NotHispanicOrLatino],
+            applicant_ethnicity_observed: Some(ObservationBasis::NotObserved),
+            applicant_races: vec![Race::Asian, Race::Korean],
+            applicant_race_observed: Some(ObservationBasis::NotObserved),
+            applicant_sex: Some(Sex::Female),
+            applicant_sex_observed: Some(ObservationBasis::NotObserved),
+            applicant_age: Some(34),
+            applicant_credit_score: Some(742),
+            applicant_credit_model: Some(CreditScoreModel::FicoClassic04),
+            co_applicant_ethnicities: vec![],
+            co_applicant_ethnicity_observed: None,
+            co_applicant_races: vec![],
+            co_applicant_race_observed: None,
+            co_applicant_sex: None,
+            co_applicant_sex_observed: None,
+            co_applicant_age: None,
+            co_applicant_credit_score: None,
+            co_applicant_credit_model: None,
+            denial_reasons: vec![],
+            rate_spread: Some(0.412),
+            hoepa_status: HoepaStatus::NotHighCost,
+            lien_status: LienStatus::First,
+            purchaser_type: PurchaserType::FannieMae,
+            debt_to_income: Some(38.5),
+            updated_by: Some(3),
+            updated_at: at,
+        },
+        has_co_applicant: false,
+        monthly_income_cents: 11_250_00,
+        nmls_id: Some("1234567".to_string()),
+        fees: vec![],
+        lender_credits_cents: 0,
+        arm_terms: None,
+    }
+}
+
+fn lar(loans: &[LarLoan]) -> LarFile {
+    build_lar(&filer(), 2025, loans)
+}
+
+fn edit_ids(failures: &[EditFailure]) -> Vec<&str> {
+    failures.iter().map(|failure| failure.edit.as_str()).collect()
+}
+
+#[test]
+fn uli_check_digits_round_trip() {
+    let id = uli(LEI, "LN-0042");
+    assert!(id.starts_with("10BX939C5543TQA1144MLN0042"));
+    assert_eq!(id.len(), LEI.len() + "LN0042".len() + 2);
+    assert!(is_valid_uli(&id));
+    assert!(is_valid_uli(&id.to_lowercase()));
+
+    let mut tampered = id.clone();
+    tampered.replace_range(20..21, "M");
+    assert!(!is_valid_uli(&tampered));
+    assert!(!is_valid_uli("LN-0042"));
+}
+
+#[test]
+fn lar_row_lays_out_every_field() {
+    let file = lar(&[originated()]);
+    let row = &file.rows[0];
+
+    assert_eq!(row.fields.len(), LAR_FIELD_COUNT);
+    assert_eq!(row.loan_id, 42);
+    assert_eq!(row.get(field::LEI), LEI);
+    assert!(is_valid_uli(row.get(field::ULI)));
+    assert_eq!(row.get(field::APPLICATION_DATE), "20250303");
+    assert_eq!(row.get(field::LOAN_PURPOSE), "1");
+    assert_eq!(row.get(field::LOAN_AMOUNT), "412500");
+    assert_eq!(row.get(field::ACTION_TAKEN), "1");
+    assert_eq!(row.get(field::ACTION_TAKEN_DATE), "20250418");
+    assert_eq!(row.get(field::CENSUS_TRACT), "48453001305");
+    assert_eq!(row.range(field::APPLICANT_RACE, 5), ["2", "25", "", "", ""]);
+    assert_eq!(row.get(field::INCOME), "135");
+    assert_eq!(row.get(field::RATE_SPREAD), "0.412");
+    assert_eq!(row.get(field::DENIAL_REASON), "10");
+
+    // No co-applicant: each co-applicant field says so
+    assert_eq!(row.get(field::CO_APPLICANT_ETHNICITY), "5");
+    assert_eq!(row.get(field::CO_APPLICANT_RACE), "8");
+    assert_eq!(row.get(field::CO_APPLICANT_SEX), "5");
+    assert_eq!(row.get(field::CO_APPLICANT_SEX_OBSERVED), "4");
+    assert_eq!(row.get(field::CO_APPLICANT_AGE), "9999");
+    assert_eq!(row.get(field::CO_APPLICANT_CREDIT_SCORE), "9999");
+
+    let text = file.to_pipe_delimited();
+    assert_eq!(text.lines().count(), 2);
+    assert!(text.lines().all(|line| !line.contains('\r')));
+    assert_eq!(text.lines().nth(1).unwrap().split('|').count(), LAR_FIELD_COUNT);
+    assert_eq!(file.file_name(), format!("2025_LAR_{}.txt", LEI));
+}
+
+#[test]
+fn complete_record_passes_the_edits() {
+    let failures = check_lar(&lar(&[originated()]), 2025);
+    assert!(failures.is_empty(), "{:?}", failures);
+}
+
+#[test]
+fn denial_without_reasons_fails_v670() {
+    let mut loan = originated();
+    loan.hmda.action_taken = Some(ActionTaken::Denied);
+    loan.hmda.purchaser_type = PurchaserType::NotSold;
+    let failures = check_lar(&lar(&[loan.clone()]), 2025);
+    assert_eq!(edit_ids(&failures), ["V670-1"]);
+
+    loan.hmda.denial_reasons = vec![DenialReason::CreditHistory];
+    assert!(check_lar(&lar(&[loan]), 2025).is_empty());
+}
+
+#[test]
+fn missing_demographics_are_reported() {
+    let mut loan = originated();
+    loan.hmda.applicant_races.clear();
+    loan.hmda.applicant_sex_observed = None;
+    let failures = check_lar(&lar(&[loan]), 2025);
+    let ids = edit_ids(&failures);
+
+    assert!(ids.contains(&"V635-1"), "{:?}", ids);
+    assert!(ids.contains(&"V643-1"), "{:?}", ids);
+    assert!(failures.iter().all(|failure| failure.loan_id == Some(42)));
+}
+
+#[test]
+fn tract_must_be_in_the_county() {
+    let mut loan = originated();
+    loan.hmda.county = Some("48491".to_string());
+    assert_eq!(edit_ids(&check_lar(&lar(&[loan]), 2025)), ["V627"]);
+}
+
+#[test]
+fn transmittal_sheet_is_checked_against_the_year() {
+    let failures = check_lar(&lar(&[originated()]), 2026);
+    let ids = edit_ids(&failures);
+    assert!(ids.contains(&"S302"));
+    assert!(ids.contains(&"V619-2"));
+    assert_eq!(failures[0].uli, None);
+}
+
+#[test]
+fn hmda_input_validation() {
+    let valid = HmdaInput::from(&originated().hmda);
+    assert!(valid.validate().is_ok());
+
+    let without_date = HmdaInput { action_taken_date: None, ..valid.clone() };
+    assert!(without_date.validate().is_err());
+
+    let repeated = HmdaInput {
+        denial_reasons: vec![DenialReason::Collateral, DenialReason::Collateral],
+        ..valid.clone()
+    };
+    assert!(repeated.validate().is_err());
+
+    let bad_tract = HmdaInput { census_tract: Some("4845300130".to_string()), ..valid.clone() };
+    assert!(bad_tract.validate().is_err());
+
+    let too_many_races = HmdaInput {
+        applicant: HmdaApplicant {
+            races: vec![Race::Asian, Race::Chinese, Race::Korean, Race::Japanese, Race::Filipino, Race::White],
+            ..valid.applicant.clone()
+        },
+        ..valid
+    };
+    assert!(too_many_races.validate().is_err());
+}
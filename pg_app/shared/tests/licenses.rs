@@ -0,0 +1,133 @@
+//! Loan officer licensing: NMLS IDs, state licenses and the origination check
+
+use chrono::{NaiveDate, Utc};
+use shared::models::{
+    check_license, check_originator, is_valid_nmls_id, License, LicenseError, LicenseInput, User, UserRole,
+};
+use validator::Validate;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn officer() -> User {
+    User {
+        id: 3,
+        username: "mreyes".to_string(),
+        first_name: "Marco".to_string(),
+        last_name: "Reyes".to_string(),
+        email: "marco@example.com".to_string(),
+        password_hash: String::new(),
+        role: UserRole::LoanOfficer,
+        is_active: true,
+        created_at: None,
+        last_login: None,
+        failed_login_attempts: 0,
+        nmls_id: Some("1234567".to_string()),
+    }
+}
+
+fn license(user_id: i32, state: &str, expires_on: NaiveDate) -> License {
+    License {
+        id: 1,
+        user_id,
+        state: state.to_string(),
+        license_number: "LO-55120".to_string(),
+        expires_on,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+#[test]
+fn a_current_license_in_the_state_passes() {
+    let licenses = [license(3, "CO", date(2025, 1, 31)), license(3, "TX", date(2025, 12, 31))];
+
+    assert_eq!(check_license(&officer(), &licenses, "TX", date(2025, 5, 19)), Ok(()));
+    // Valid through the expiration day itself
+    assert_eq!(check_license(&officer(), &licenses, "TX", date(2025, 12, 31)), Ok(()));
+}
+
+#[test]
+fn other_states_and_other_officers_do_not_count() {
+    let licenses = [license(3, "CO", date(2025, 12, 31)), license(4, "TX", date(2025, 12, 31))];
+
+    assert_eq!(
+        check_license(&officer(), &licenses, "TX", date(2025, 5, 19)),
+        Err(LicenseError::Unlicensed {
+            officer: "Marco Reyes".to_string(),
+            state: "TX".to_string(),
+        })
+    );
+}
+
+#[test]
+fn an_expired_license_fails_with_its_date() {
+    let licenses = [license(3, "TX", date(2025, 5, 18))];
+
+    let err = check_license(&officer(), &licenses, "TX", date(2025, 5, 19)).unwrap_err();
+    assert_eq!(
+        err,
+        LicenseError::Expired {
+            officer: "Marco Reyes".to_string(),
+            state: "TX".to_string(),
+            expires_on: date(2025, 5, 18),
+        }
+    );
+    assert_eq!(err.to_string(), "Marco Reyes's TX license expired on 2025-05-18");
+}
+
+#[test]
+fn an_officer_needs_an_nmls_id_first() {
+    let licenses = [license(3, "TX", date(2025, 12, 31))];
+    let mut officer = officer();
+
+    for nmls_id in [None, Some("  ".to_string())] {
+        officer.nmls_id = nmls_id;
+        assert_eq!(
+            check_license(&officer, &licenses, "TX", date(2025, 5, 19)),
+            Err(LicenseError::MissingNmlsId {
+                officer: "Marco Reyes".to_string()
+            })
+        );
+    }
+}
+
+#[test]
+fn officers_assigned_before_the_property_state_is_known_still_need_an_nmls_id() {
+    let mut officer = officer();
+    assert_eq!(check_originator(&officer), Ok(()));
+
+    officer.nmls_id = None;
+    assert_eq!(
+        check_originator(&officer).unwrap_err().to_string(),
+        "Marco Reyes has no NMLS ID on file"
+    );
+}
+
+#[test]
+fn nmls_ids_are_up_to_twelve_digits() {
+    assert!(is_valid_nmls_id("1"));
+    assert!(is_valid_nmls_id("123456789012"));
+    assert!(!is_valid_nmls_id(""));
+    assert!(!is_valid_nmls_id("1234567890123"));
+    assert!(!is_valid_nmls_id("NMLS1234"));
+}
+
+#[test]
+fn license_input_needs_a_state_code_and_number() {
+    let input = LicenseInput::from(&license(3, "TX", date(2025, 12, 31)));
+    assert!(input.validate().is_ok());
+
+    let lowercase = LicenseInput {
+        state: "tx".to_string(),
+        ..input.clone()
+    };
+    assert!(lowercase.validate().is_err());
+
+    let blank = LicenseInput {
+        license_number: String::new(),
+        ..input
+    };
+    assert!(blank.validate().is_err());
+}
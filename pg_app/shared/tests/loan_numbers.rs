@@ -0,0 +1,76 @@
+//! Loan number formats: parsing, prefixes and the settings checks
+
+use chrono::NaiveDate;
+use shared::models::{LoanNumberFormat, LoanNumberFormatError, LoanNumberSettingsInput};
+use validator::Validate;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn tokens_render_from_the_branch_and_date() {
+    let format: LoanNumberFormat = "LN/{YY}{MM}/{BRANCH}.{SEQ}".parse().unwrap();
+    assert!(format.uses_branch());
+    assert_eq!(format.render("AUS", date(2025, 3, 9), 7), "LN/2503/AUS.7");
+
+    let plain = LoanNumberFormat::parse(" {YYYY}-{SEQ:4} ").unwrap();
+    assert!(!plain.uses_branch());
+    assert_eq!(plain.render("AUS", date(2025, 3, 9), 12), "2025-0012");
+}
+
+#[test]
+fn prefixes_split_sequences_by_branch_and_period() {
+    let format = LoanNumberFormat::parse("{BRANCH}-{YYYY}-{SEQ:6}").unwrap();
+
+    assert_eq!(format.prefix("AUS", date(2025, 1, 1)), format.prefix("AUS", date(2025, 12, 31)));
+    assert_ne!(format.prefix("AUS", date(2025, 12, 31)), format.prefix("AUS", date(2026, 1, 1)));
+    assert_ne!(format.prefix("AUS", date(2025, 1, 1)), format.prefix("DAL", date(2025, 1, 1)));
+
+    // Same text either side of the sequence means the same numbers, so one sequence
+    let padded = LoanNumberFormat::parse("AUS-{YYYY}-{SEQ:8}").unwrap();
+    assert_eq!(padded.prefix("", date(2025, 5, 1)), format.prefix("AUS", date(2025, 5, 1)));
+}
+
+#[test]
+fn bad_formats_are_rejected() {
+    let cases = [
+        ("{YYYY}-0001", LoanNumberFormatError::MissingSequence),
+        ("{SEQ}-{SEQ:4}", LoanNumberFormatError::RepeatedSequence),
+        ("{YEAR}-{SEQ}", LoanNumberFormatError::UnknownToken("YEAR".to_string())),
+        ("{SEQ:0}", LoanNumberFormatError::SequenceWidth("SEQ:0".to_string())),
+        ("{SEQ:13}", LoanNumberFormatError::SequenceWidth("SEQ:13".to_string())),
+        ("LN {SEQ}", LoanNumberFormatError::InvalidCharacter(' ')),
+        ("LN#{SEQ}", LoanNumberFormatError::InvalidCharacter('#')),
+        ("{BRANCH-{SEQ}", LoanNumberFormatError::UnknownToken("BRANCH-{SEQ".to_string())),
+        ("LN-{SEQ", LoanNumberFormatError::Unclosed),
+    ];
+    for (format, error) in cases {
+        assert_eq!(LoanNumberFormat::parse(format), Err(error), "{format}");
+    }
+}
+
+#[test]
+fn settings_must_fit_the_loan_number_column() {
+    let default = LoanNumberSettingsInput::default();
+    assert!(default.validate().is_ok());
+    assert_eq!(default.example(date(2025, 5, 19)).unwrap(), "MAIN-2025-000001");
+
+    let too_long = LoanNumberSettingsInput {
+        format: "{BRANCH}-{BRANCH}-{YYYY}{MM}-{SEQ:12}".to_string(),
+        branch_code: "HEADOFFICE".to_string(),
+    };
+    assert!(too_long.validate().unwrap_err().to_string().contains("longer than 30 characters"));
+
+    let lower = LoanNumberSettingsInput {
+        branch_code: "aus".to_string(),
+        ..LoanNumberSettingsInput::default()
+    };
+    assert!(lower.validate().is_err());
+
+    let unparsable = LoanNumberSettingsInput {
+        format: "{YYYY}".to_string(),
+        ..LoanNumberSettingsInput::default()
+    };
+    assert!(unparsable.validate().unwrap_err().to_string().contains("needs a {SEQ}"));
+}
@@ -0,0 +1,52 @@
+//! Loan-to-value: which value is the denominator and when there is none
+// Amounts are written as dollars_cents, e.g. `412_500_00` for $412,500.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use shared::calculations::ltv::{loan_to_value, valuation_basis};
+use shared::models::PropertyInput;
+use validator::Validate;
+
+#[test]
+fn lesser_of_price_and_appraisal_is_used() {
+    assert_eq!(valuation_basis(Some(500_000_00), Some(480_000_00), None), Some(480_000_00));
+    assert_eq!(valuation_basis(Some(480_000_00), Some(500_000_00), None), Some(480_000_00));
+    // The estimate never overrides a known price or appraisal
+    assert_eq!(valuation_basis(Some(500_000_00), Some(480_000_00), Some(400_000_00)), Some(480_000_00));
+}
+
+#[test]
+fn missing_appraisal_falls_back_to_price_then_estimate() {
+    assert_eq!(valuation_basis(Some(500_000_00), None, Some(550_000_00)), Some(500_000_00));
+    assert_eq!(valuation_basis(None, Some(480_000_00), Some(550_000_00)), Some(480_000_00));
+    assert_eq!(valuation_basis(None, None, Some(550_000_00)), Some(550_000_00));
+    assert_eq!(valuation_basis(None, None, None), None);
+}
+
+#[test]
+fn zero_value_has_no_ratio() {
+    assert_eq!(loan_to_value(400_000_00, 0), None);
+    assert_eq!(loan_to_value(400_000_00, -1), None);
+    assert_eq!(loan_to_value(400_000_00, 500_000_00), Some(80.0));
+    assert_eq!(loan_to_value(0, 500_000_00), Some(0.0));
+}
+
+#[test]
+fn zero_appraisal_is_rejected_before_it_is_saved() {
+    let input = PropertyInput {
+        street: "18 Larkspur Ln".to_string(),
+        city: "Austin".to_string(),
+        state: "TX".to_string(),
+        zip: "78704".to_string(),
+        purchase_price_cents: Some(500_000_00),
+        appraised_value_cents: Some(0),
+        ..PropertyInput::default()
+    };
+    let errors = input.validate().unwrap_err();
+    assert!(errors.field_errors().contains_key("appraised_value_cents"));
+
+    let input = PropertyInput {
+        appraised_value_cents: None,
+        ..input
+    };
+    assert!(input.validate().is_ok());
+}
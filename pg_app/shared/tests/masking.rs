@@ -0,0 +1,139 @@
+//! Masking PII before it leaves the server, and who may reveal it
+// Amounts are written as dollars_cents, e.g. `9_500_00` for $9,500.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::{NaiveDate, Utc};
+use shared::duplicates::find_duplicates;
+use shared::masking::{format_ssn, is_masked, MaskPii, RevealField};
+use shared::models::{authorize, AccessError, Asset, AssetType, Borrower, MergeField, Permission, User, UserRole};
+
+fn grace() -> Borrower {
+    Borrower {
+        id: 3,
+        first_name: "Grace".to_string(),
+        last_name: "Okafor".to_string(),
+        email: Some("grace@example.com".to_string()),
+        phone: Some("512-555-0142".to_string()),
+        monthly_income_cents: 9_500_00,
+        ssn: Some("123456789".to_string()),
+        date_of_birth: NaiveDate::from_ymd_opt(1988, 4, 12),
+        pii_masked: false,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+#[test]
+fn borrower_keeps_only_last_four_and_birth_year() {
+    let masked = grace().mask_pii();
+
+    assert!(masked.pii_masked);
+    assert_eq!(masked.ssn.as_deref(), Some("***-**-6789"));
+    assert_eq!(masked.date_of_birth, NaiveDate::from_ymd_opt(1988, 1, 1));
+    assert_eq!(MergeField::DateOfBirth.display(&masked), "**/**/1988");
+    assert_eq!(MergeField::Ssn.display(&masked), "***-**-6789");
+    // Everything else is left alone
+    assert_eq!(masked.email, grace().email);
+    assert_eq!(masked.monthly_income_cents, 9_500_00);
+
+    // Masking twice changes nothing
+    assert_eq!(masked.clone().mask_pii(), masked);
+
+    let unmasked = grace();
+    assert_eq!(MergeField::DateOfBirth.display(&unmasked), "04/12/1988");
+    assert_eq!(MergeField::Ssn.display(&unmasked), "***-**-6789");
+}
+
+#[test]
+fn blanks_stay_blank() {
+    let masked = Borrower { ssn: None, date_of_birth: None, ..grace() }.mask_pii();
+    assert_eq!(masked.ssn, None);
+    assert_eq!(masked.date_of_birth, None);
+    assert!(masked.pii_masked);
+}
+
+#[test]
+fn duplicate_pairs_are_masked_after_matching() {
+    let twin = Borrower { id: 9, email: Some("g.okafor@work.example".to_string()), ..grace() };
+    let found = find_duplicates(&[grace(), twin]).mask_pii();
+
+    assert_eq!(found.len(), 1);
+    let pair = &found[0];
+    assert!(pair.is_strong());
+    for borrower in [&pair.first, &pair.second] {
+        assert!(borrower.ssn.as_deref().is_some_and(is_masked));
+        assert_eq!(borrower.date_of_birth, NaiveDate::from_ymd_opt(1988, 1, 1));
+    }
+}
+
+#[test]
+fn account_numbers_keep_last_four() {
+    let now = Utc::now();
+    let asset = Asset {
+        id: 5,
+        borrower_id: 3,
+        asset_type: AssetType::Retirement,
+        institution_name: "Fidelity 401k".to_string(),
+        account_last4: Some("0455".to_string()),
+        account_number: Some("Z88-310455".to_string()),
+        balance_cents: 96_310_00,
+        source: "fnm".to_string(),
+        created_at: now,
+        updated_at: now,
+    };
+    let masked = vec![asset.clone(), Asset { id: 6, account_number: None, ..asset }].mask_pii();
+    assert_eq!(masked[0].account_number.as_deref(), Some("****0455"));
+    assert_eq!(masked[1].account_number, None);
+}
+
+#[test]
+fn revealed_ssns_are_formatted() {
+    assert_eq!(format_ssn("123456789"), "123-45-6789");
+    assert_eq!(format_ssn("12345"), "12345");
+    assert_eq!(RevealField::Ssn.entity(), "borrower");
+    assert_eq!(RevealField::AccountNumber.entity(), "asset");
+}
+
+#[test]
+fn processors_and_admins_may_reveal() {
+    assert!(UserRole::Processor.has_permission(Permission::RevealPii));
+    assert!(UserRole::Admin.has_permission(Permission::RevealPii));
+    assert!(!UserRole::LoanOfficer.has_permission(Permission::RevealPii));
+    assert!(!UserRole::Manager.has_permission(Permission::RevealPii));
+}
+
+fn user(role: UserRole) -> User {
+    User {
+        id: 7,
+        username: "dpatel".to_string(),
+        first_name: "Dana".to_string(),
+        last_name: "Patel".to_string(),
+        email: "dana@example.com".to_string(),
+        password_hash: String::new(),
+        role,
+        is_active: true,
+        created_at: None,
+        last_login: None,
+        failed_login_attempts: 0,
+        nmls_id: None,
+    }
+}
+
+#[test]
+fn unauthorized_callers_cannot_reveal() {
+    // reveal_pii takes the caller from the session, so a request without one is turned away
+    assert_eq!(authorize(None, Some(Permission::RevealPii)), Err(AccessError::SignedOut));
+    assert_eq!(
+        authorize(Some(user(UserRole::LoanOfficer)), Some(Permission::RevealPii)),
+        Err(AccessError::Forbidden)
+    );
+
+    let mut deactivated = user(UserRole::Processor);
+    deactivated.is_active = false;
+    assert_eq!(authorize(Some(deactivated), Some(Permission::RevealPii)), Err(AccessError::Inactive));
+    let mut locked = user(UserRole::Processor);
+    locked.failed_login_attempts = 5;
+    assert_eq!(authorize(Some(locked), Some(Permission::RevealPii)), Err(AccessError::Inactive));
+
+    assert_eq!(authorize(Some(user(UserRole::Processor)), Some(Permission::RevealPii)).map(|user| user.id), Ok(7));
+}
@@ -0,0 +1,226 @@
+//! MISMO 3.4 export and import against the sample files in `tests/data/mismo`
+// Amounts are written as dollars_cents, e.g. `1_295_00` for $1,295.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use chrono::NaiveDate;
+use shared::mismo::export::to_xml;
+use shared::mismo::import::from_xml;
+use shared::mismo::{MismoError, MismoLoanFile};
+use shared::models::{
+    BorrowerInput, FeePayer, FeeSection, LiabilityInput, LiabilityType, LoanFeeInput, LoanInput, LoanPurpose,
+    LoanType, Occupancy, PropertyInput, PropertyType,
+};
+
+const PURCHASE: &str = include_str!("data/mismo/purchase_conventional.xml");
+const REFINANCE: &str = include_str!("data/mismo/cash_out_refinance_fha.xml");
+
+fn date(y: i32, m: u32, d: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(y, m, d)
+}
+
+fn fee(section: FeeSection, name: &str, amount_cents: i64, paid_to: Option<&str>) -> LoanFeeInput {
+    LoanFeeInput {
+        section,
+        name: name.to_string(),
+        amount_cents,
+        paid_to: paid_to.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+fn refinance_file() -> MismoLoanFile {
+    MismoLoanFile {
+        loan_number: Some("LN-2025-00522".to_string()),
+        loan: LoanInput {
+            loan_type: LoanType::Fha,
+            loan_purpose: LoanPurpose::CashOutRefinance,
+            amount_cents: 288_500_00,
+            note_rate: 6.25,
+            term_months: 360,
+            application_date: date(2025, 5, 6),
+            ..Default::default()
+        },
+        borrower: BorrowerInput {
+            first_name: "Terrence".to_string(),
+            last_name: "O'Neil".to_string(),
+            email: Some("t.oneil@example.com".to_string()),
+            phone: None,
+            monthly_income_cents: 7_240_00,
+        },
+        liabilities: vec![
+            LiabilityInput {
+                liability_type: LiabilityType::Mortgage,
+                creditor_name: "Rocket Mortgage".to_string(),
+                account_last4: Some("5521".to_string()),
+                balance_cents: 201_344_18,
+                monthly_payment_cents: 1_618_40,
+                paid_off_at_closing: true,
+                excluded: false,
+            },
+            LiabilityInput {
+                liability_type: LiabilityType::Student,
+                creditor_name: "Nelnet".to_string(),
+                account_last4: None,
+                balance_cents: 18_200_00,
+                monthly_payment_cents: 0,
+                paid_off_at_closing: false,
+                excluded: true,
+            },
+        ],
+        property: Some(PropertyInput {
+            street: "88 Harbor View Dr Unit 4B".to_string(),
+            city: "Tampa".to_string(),
+            state: "FL".to_string(),
+            zip: "33602".to_string(),
+            occupancy: Occupancy::PrimaryResidence,
+            property_type: PropertyType::Condominium,
+            units: 1,
+            purchase_price_cents: None,
+            appraised_value_cents: Some(362_000_00),
+            estimated_value_cents: Some(355_000_00),
+        }),
+        fees: vec![
+            LoanFeeInput {
+                finance_charge: true,
+                ..fee(FeeSection::Origination, "Origination Fee", 2_885_00, None)
+            },
+            fee(FeeSection::CannotShop, "Appraisal Fee", 725_00, Some("Gulf Coast Valuations")),
+            LoanFeeInput {
+                finance_charge: true,
+                off_list_provider: true,
+                ..fee(FeeSection::CanShop, "Title - Lender's Policy", 1_340_00, Some("Bayshore Title <Tampa>"))
+            },
+            LoanFeeInput {
+                recording_fee: true,
+                ..fee(FeeSection::TaxesGovernment, "Recording Fees", 212_50, Some("Hillsborough County Clerk"))
+            },
+            LoanFeeInput {
+                payer: FeePayer::Lender,
+                ..fee(FeeSection::Prepaids, "Prepaid Interest", 494_35, None)
+            },
+        ],
+    }
+}
+
+#[test]
+fn purchase_sample_maps_onto_records() {
+    let import = from_xml(PURCHASE).unwrap();
+    let file = import.file;
+
+    assert_eq!(file.loan_number.as_deref(), Some("LN-2025-00417"));
+    assert_eq!(
+        file.loan,
+        LoanInput {
+            loan_type: LoanType::Conventional,
+            loan_purpose: LoanPurpose::Purchase,
+            amount_cents: 432_000_00,
+            note_rate: 6.875,
+            term_months: 360,
+            application_date: date(2025, 4, 28),
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        file.borrower,
+        BorrowerInput {
+            first_name: "Maria".to_string(),
+            last_name: "Delgado".to_string(),
+            email: Some("maria.delgado@example.com".to_string()),
+            phone: Some("3035550148".to_string()),
+            // Base and bonus income are summed
+            monthly_income_cents: 9_916_67,
+        }
+    );
+
+    assert_eq!(file.liabilities.len(), 2);
+    assert_eq!(file.liabilities[0].liability_type, LiabilityType::Revolving);
+    assert_eq!(file.liabilities[0].account_last4.as_deref(), Some("9012"));
+    assert_eq!(file.liabilities[0].balance_cents, 2_310_44);
+    assert_eq!(file.liabilities[1].liability_type, LiabilityType::Auto);
+    assert_eq!(file.liabilities[1].account_last4.as_deref(), Some("1845"));
+    assert!(file.liabilities[1].paid_off_at_closing);
+
+    let property = file.property.unwrap();
+    assert_eq!(property.property_type, PropertyType::Pud);
+    assert_eq!(property.occupancy, Occupancy::PrimaryResidence);
+    assert_eq!((property.city.as_str(), property.state.as_str()), ("Boulder", "CO"));
+    assert_eq!(property.purchase_price_cents, Some(540_000_00));
+    assert_eq!(property.estimated_value_cents, Some(545_000_00));
+    assert_eq!(property.appraised_value_cents, None);
+
+    let fees: Vec<(FeeSection, &str, i64, FeePayer, bool, bool)> = file
+        .fees
+        .iter()
+        .map(|f| (f.section, f.name.as_str(), f.amount_cents, f.payer, f.finance_charge, f.off_list_provider))
+        .collect();
+    assert_eq!(
+        fees,
+        vec![
+            (FeeSection::Origination, "Underwriting Fee", 1_295_00, FeePayer::Borrower, true, false),
+            (FeeSection::CannotShop, "AppraisalFee", 650_00, FeePayer::Borrower, false, false),
+            (FeeSection::CanShop, "Title - Settlement Fee", 1_150_00, FeePayer::Borrower, true, true),
+            (FeeSection::Origination, "Seller Paid Origination Credit", 3_500_00, FeePayer::Seller, false, false),
+        ]
+    );
+    assert_eq!(file.fees[2].paid_to.as_deref(), Some("Flatirons Title & Escrow"));
+}
+
+#[test]
+fn purchase_sample_reports_unmapped_elements() {
+    let import = from_xml(PURCHASE).unwrap();
+    let employer = "DEAL/PARTIES/PARTY/ROLES/ROLE/BORROWER/EMPLOYERS/EMPLOYER";
+    assert_eq!(
+        import.unmapped,
+        vec![
+            "DEAL/COLLATERALS/COLLATERAL/SUBJECT_PROPERTY/ADDRESS/CountyName".to_string(),
+            "DEAL/LOANS/LOAN/LOAN_DETAIL/BalloonIndicator".to_string(),
+            // The co-borrower is not imported
+            "DEAL/PARTIES/PARTY/INDIVIDUAL/NAME/FirstName".to_string(),
+            "DEAL/PARTIES/PARTY/INDIVIDUAL/NAME/LastName".to_string(),
+            format!("{employer}/EMPLOYMENT/EmploymentPositionDescription"),
+            format!("{employer}/EMPLOYMENT/EmploymentStartDate"),
+            format!("{employer}/EMPLOYMENT/EmploymentStatusType"),
+            format!("{employer}/LEGAL_ENTITY/LEGAL_ENTITY_DETAIL/FullName"),
+            "DEAL/PARTIES/PARTY/ROLES/ROLE/ROLE_DETAIL/PartyRoleType".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn refinance_sample_matches_export() {
+    assert_eq!(to_xml(&refinance_file()), REFINANCE);
+}
+
+#[test]
+fn refinance_sample_round_trips() {
+    let import = from_xml(REFINANCE).unwrap();
+    assert_eq!(import.file, refinance_file());
+    assert!(import.unmapped.is_empty(), "unexpected unmapped elements: {:?}", import.unmapped);
+}
+
+#[test]
+fn imported_purchase_survives_export() {
+    let file = from_xml(PURCHASE).unwrap().file;
+    let again = from_xml(&to_xml(&file)).unwrap();
+    assert_eq!(again.file, file);
+    assert!(again.unmapped.is_empty());
+}
+
+#[test]
+fn unknown_enumerations_are_rejected() {
+    let xml = PURCHASE.replace("<MortgageType>Conventional</MortgageType>", "<MortgageType>LocalAgency</MortgageType>");
+    assert_eq!(
+        from_xml(&xml).unwrap_err(),
+        MismoError::InvalidValue {
+            path: "MortgageType".to_string(),
+            value: "LocalAgency".to_string(),
+        }
+    );
+}
+
+#[test]
+fn malformed_documents_are_rejected() {
+    assert!(matches!(from_xml("<MESSAGE><DEAL_SETS>"), Err(MismoError::Xml(_))));
+    let no_borrower = PURCHASE.replace("<PartyRoleType>Borrower</PartyRoleType>", "<PartyRoleType>Seller</PartyRoleType>");
+    assert!(matches!(from_xml(&no_borrower), Err(MismoError::Missing(_))));
+}
@@ -0,0 +1,39 @@
+//! Notes: @mentions and the notifications they raise
+
+use chrono::{TimeZone, Utc};
+use shared::models::Note;
+
+fn note(loan_id: Option<i32>, borrower_id: Option<i32>, body: &str) -> Note {
+    let at = Utc.with_ymd_and_hms(2025, 5, 19, 9, 0, 0).unwrap();
+    Note {
+        id: 1,
+        loan_id,
+        borrower_id,
+        parent_id: None,
+        author_id: Some(3),
+        author_name: Some("Marco Reyes".to_string()),
+        body: body.to_string(),
+        pinned: false,
+        internal_only: true,
+        edited_at: None,
+        created_at: at,
+        updated_at: at,
+    }
+}
+
+#[test]
+fn mentions_are_read_from_the_body() {
+    let note = note(Some(42), None, "@gokafor can you check the VOE? cc @tlee");
+    assert_eq!(note.mentions(), vec!["gokafor".to_string(), "tlee".to_string()]);
+}
+
+#[test]
+fn mention_message_names_the_subject_not_the_body() {
+    let body = "@gokafor <img src=x onerror=alert(1)> see the appraisal";
+
+    let message = note(Some(42), None, body).mention_message("Marco Reyes");
+    assert_eq!(message, "Marco Reyes mentioned you on loan #42");
+
+    let message = note(None, Some(7), body).mention_message("Marco Reyes");
+    assert_eq!(message, "Marco Reyes mentioned you on borrower #7");
+}
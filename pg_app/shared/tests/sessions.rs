@@ -0,0 +1,80 @@
+//! Session cookies set at sign-in and the checks made on the signed-in user
+
+use shared::models::{
+    can_edit_borrower, can_edit_loan, can_see_internal_notes, expired_session_cookie, session_cookie, session_token,
+    User, UserRole, SESSION_COOKIE,
+};
+
+fn user(id: i32, role: UserRole) -> User {
+    User {
+        id,
+        username: format!("user{id}"),
+        first_name: "Test".to_string(),
+        last_name: "User".to_string(),
+        email: format!("user{id}@example.com"),
+        password_hash: String::new(),
+        role,
+        is_active: true,
+        created_at: None,
+        last_login: None,
+        failed_login_attempts: 0,
+        nmls_id: None,
+    }
+}
+
+#[test]
+fn token_is_read_from_among_other_cookies() {
+    assert_eq!(session_token("pg_session=abc123"), Some("abc123"));
+    assert_eq!(session_token("theme=dark; pg_session=abc123; lang=en"), Some("abc123"));
+    assert_eq!(session_token("theme=dark;pg_session=abc123"), Some("abc123"));
+}
+
+#[test]
+fn missing_or_empty_tokens_are_signed_out() {
+    assert_eq!(session_token(""), None);
+    assert_eq!(session_token("theme=dark"), None);
+    assert_eq!(session_token("pg_session="), None);
+    assert_eq!(session_token("other_pg_session=abc123"), None);
+}
+
+#[test]
+fn cookie_is_hidden_from_scripts_and_other_sites() {
+    let cookie = session_cookie("abc123");
+    assert!(cookie.starts_with(&format!("{SESSION_COOKIE}=abc123;")));
+    for attribute in ["HttpOnly", "Secure", "SameSite=Strict", "Path=/", "Max-Age=43200"] {
+        assert!(cookie.contains(attribute), "{cookie} lacks {attribute}");
+    }
+
+    let expired = expired_session_cookie();
+    assert!(expired.starts_with(&format!("{SESSION_COOKIE}=;")));
+    assert!(expired.contains("Max-Age=0"));
+}
+
+#[test]
+fn loan_officers_edit_only_their_own_loans() {
+    let officer = user(3, UserRole::LoanOfficer);
+    assert!(can_edit_loan(&officer, Some(3)));
+    assert!(!can_edit_loan(&officer, Some(4)));
+    assert!(!can_edit_loan(&officer, None));
+
+    let processor = user(5, UserRole::Processor);
+    assert!(can_edit_loan(&processor, Some(4)));
+    assert!(can_edit_loan(&processor, None));
+}
+
+#[test]
+fn borrowers_follow_their_loans_and_prospects_are_open() {
+    let officer = user(3, UserRole::LoanOfficer);
+    assert!(can_edit_borrower(&officer, &[]));
+    assert!(can_edit_borrower(&officer, &[Some(4), Some(3)]));
+    assert!(!can_edit_borrower(&officer, &[Some(4), None]));
+
+    assert!(can_edit_borrower(&user(1, UserRole::Admin), &[Some(4)]));
+}
+
+#[test]
+fn staff_roles_see_internal_notes() {
+    for role in [UserRole::Admin, UserRole::LoanOfficer, UserRole::Processor, UserRole::Manager] {
+        assert!(can_see_internal_notes(&user(1, role)), "{role}");
+    }
+}
@@ -0,0 +1,183 @@
+//! Time in each pipeline status, SLA breaches and the cycle time report
+
+use chrono::{DateTime, NaiveDate, TimeDelta, TimeZone, Utc};
+use shared::calculations::status_timeline::{loan_timeline, CycleTimeReport, LoanTimeline};
+use shared::models::{LoanStatus, SlaTarget, StatusChange};
+
+use LoanStatus::*;
+
+/// 3pm UTC on a day in June 2025
+fn at(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, day, 15, 0, 0).unwrap()
+}
+
+fn june(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 6, day).unwrap()
+}
+
+/// History for a loan that was opened in the first status and then moved
+/// through the rest, one entry per `(status, day)`
+fn history(loan_id: i32, moves: &[(LoanStatus, u32)]) -> Vec<StatusChange> {
+    let mut from = None;
+    moves
+        .iter()
+        .enumerate()
+        .map(|(i, (to, day))| {
+            let change = StatusChange {
+                id: loan_id * 100 + i as i32,
+                loan_id,
+                from_status: from,
+                to_status: *to,
+                changed_by: Some(2),
+                changed_by_name: Some("Priya Shah".to_string()),
+                changed_at: at(*day),
+            };
+            from = Some(*to);
+            change
+        })
+        .collect()
+}
+
+fn targets() -> Vec<SlaTarget> {
+    [(Application, 3), (Processing, 5), (Underwriting, 5)]
+        .into_iter()
+        .map(|(status, business_days)| SlaTarget { status, business_days, updated_by: None, updated_at: at(1) })
+        .collect()
+}
+
+fn timeline(loan_id: i32, officer: Option<(i32, &str)>, moves: &[(LoanStatus, u32)], today: NaiveDate) -> LoanTimeline {
+    let changes = history(loan_id, moves);
+    LoanTimeline {
+        loan_id,
+        loan_officer_id: officer.map(|(id, _)| id),
+        loan_officer_name: officer.map(|(_, name)| name.to_string()),
+        stages: loan_timeline(at(moves[0].1), moves[moves.len() - 1].0, &changes, &targets(), today),
+    }
+}
+
+#[test]
+fn each_status_gets_a_stage_with_business_days() {
+    // Out of order on purpose: the timeline sorts by time
+    let mut changes = history(1, &[(Lead, 2), (Application, 4), (Processing, 6), (Underwriting, 16)]);
+    changes.reverse();
+    let stages = loan_timeline(at(2), Underwriting, &changes, &targets(), june(18));
+
+    let statuses: Vec<LoanStatus> = stages.iter().map(|stage| stage.status).collect();
+    assert_eq!(statuses, [Lead, Application, Processing, Underwriting]);
+    assert_eq!(stages[0].entered_by.as_deref(), Some("Priya Shah"));
+    assert_eq!(stages[0].left_at, Some(at(4)));
+
+    // Friday the 6th to Monday the 16th spans a weekend either side of a full week
+    assert_eq!(stages[2].business_days, Some(6));
+    assert_eq!(stages[2].elapsed(at(30)), TimeDelta::days(10));
+    // Leads have no target
+    assert_eq!(stages[0].target_days, None);
+    assert!(!stages[0].is_breached());
+
+    // The current stage runs to today and is timed against now
+    let current = &stages[3];
+    assert!(current.is_current());
+    assert_eq!(current.business_days, Some(2));
+    assert_eq!(current.elapsed(at(18)), TimeDelta::days(2));
+}
+
+#[test]
+fn stages_over_target_are_breaches() {
+    let stages = loan_timeline(
+        at(2),
+        Underwriting,
+        &history(1, &[(Application, 2), (Processing, 5), (Underwriting, 13)]),
+        &targets(),
+        june(23),
+    );
+
+    // Application: Monday the 2nd to Thursday the 5th, within three days
+    assert_eq!(stages[0].business_days, Some(3));
+    assert_eq!(stages[0].due_date, Some(june(5)));
+    assert!(!stages[0].is_breached());
+
+    // Processing: Thursday the 5th to Friday the 13th is six business days
+    assert_eq!(stages[1].due_date, Some(june(12)));
+    assert!(stages[1].is_breached());
+    assert_eq!(stages[1].days_over(), 1);
+
+    // Underwriting is still open; Juneteenth does not count against it
+    assert_eq!(stages[2].business_days, Some(5));
+    assert_eq!(stages[2].due_date, Some(june(23)));
+    assert!(!stages[2].is_breached());
+}
+
+#[test]
+fn final_statuses_are_not_timed() {
+    let stages = loan_timeline(
+        at(2),
+        Denied,
+        &history(1, &[(Application, 2), (Underwriting, 3), (Denied, 9)]),
+        &targets(),
+        june(30),
+    );
+    let denied = stages.last().unwrap();
+    assert!(denied.is_current());
+    assert_eq!(denied.business_days, None);
+    assert!(!denied.is_breached());
+}
+
+#[test]
+fn history_without_an_opening_entry_starts_at_creation() {
+    // Recorded before opening statuses were, so the first entry is a move
+    let mut changes = history(1, &[(Application, 2), (Processing, 4)]);
+    changes.remove(0);
+    let stages = loan_timeline(at(2), Processing, &changes, &targets(), june(4));
+    assert_eq!(stages[0].status, Application);
+    assert_eq!(stages[0].entered_at, at(2));
+    assert_eq!(stages[0].entered_by, None);
+    assert_eq!(stages[0].business_days, Some(2));
+
+    // No history at all is a single open stage
+    let stages = loan_timeline(at(2), Lead, &[], &targets(), june(4));
+    assert_eq!(stages.len(), 1);
+    assert_eq!(stages[0].status, Lead);
+    assert!(stages[0].is_current());
+}
+
+#[test]
+fn report_averages_stages_ended_in_the_period() {
+    let today = june(30);
+    let ana = Some((7, "Ana Ruiz"));
+    let ben = Some((3, "Ben Cole"));
+    let timelines = [
+        // Processing 3 days, closed on the 16th after 10 business days
+        timeline(1, ana, &[(Application, 2), (Processing, 4), (Underwriting, 9), (Approved, 11), (ClearToClose, 12), (Closed, 16)], today),
+        // Processing 7 days, a breach
+        timeline(2, ana, &[(Application, 3), (Processing, 4), (Underwriting, 13)], today),
+        // Processing ended before the period; underwriting took 10 days
+        timeline(3, ben, &[(Application, 2), (Processing, 3), (Underwriting, 5), (Approved, 20)], today),
+        // Unassigned, processing 2 days
+        timeline(4, None, &[(Processing, 16), (Underwriting, 18)], today),
+    ];
+    let report = CycleTimeReport::build(&timelines, june(6), june(30));
+
+    let processing = report.stages.iter().find(|stage| stage.status == Processing).unwrap();
+    assert_eq!(processing.completed, 3);
+    assert_eq!(processing.average_business_days, 4.0);
+    assert_eq!(processing.breaches, 1);
+    // Statuses nobody left in the period are not listed
+    assert!(report.stages.iter().all(|stage| stage.status != Lead && stage.status != Closed));
+    assert_eq!(report.closed_loans, 1);
+    assert_eq!(report.average_cycle_days, Some(10.0));
+
+    let names: Vec<Option<&str>> = report.officers.iter().map(|o| o.loan_officer_name.as_deref()).collect();
+    assert_eq!(names, [Some("Ana Ruiz"), Some("Ben Cole"), None]);
+
+    let ana = &report.officers[0];
+    assert_eq!(ana.stage(Processing).unwrap().average_business_days, 5.0);
+    assert_eq!(ana.stage(Processing).unwrap().breaches, 1);
+    assert_eq!(ana.closed_loans, 1);
+
+    let ben = &report.officers[1];
+    assert_eq!(ben.stage(Processing), None);
+    assert_eq!(ben.stage(Underwriting).unwrap().average_business_days, 10.0);
+    assert_eq!(ben.stage(Underwriting).unwrap().breaches, 1);
+    assert_eq!(ben.closed_loans, 0);
+    assert_eq!(ben.average_cycle_days, None);
+}
@@ -0,0 +1,99 @@
+//! Loan tasks: urgency order, overdue flags and who may assign or change them
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use shared::models::{can_assign_task, task_urgency, Task, TaskPriority, TaskStatus, User, UserRole};
+
+fn may(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 5, day).unwrap()
+}
+
+fn user(id: i32, role: UserRole) -> User {
+    User {
+        id,
+        username: format!("user{id}"),
+        first_name: "Test".to_string(),
+        last_name: "User".to_string(),
+        email: format!("user{id}@example.com"),
+        password_hash: String::new(),
+        role,
+        is_active: true,
+        created_at: None,
+        last_login: None,
+        failed_login_attempts: 0,
+        nmls_id: None,
+    }
+}
+
+fn task(assignee_id: Option<i32>, created_by: Option<i32>) -> Task {
+    let at = Utc.with_ymd_and_hms(2025, 5, 1, 9, 0, 0).unwrap();
+    Task {
+        id: 1,
+        loan_id: 42,
+        title: "Order appraisal".to_string(),
+        description: None,
+        assignee_id,
+        created_by,
+        due_date: Some(may(9)),
+        priority: TaskPriority::Normal,
+        status: TaskStatus::Open,
+        completed_by: None,
+        completed_at: None,
+        created_at: at,
+        updated_at: at,
+    }
+}
+
+#[test]
+fn open_dated_urgent_tasks_sort_first() {
+    let mut keys = [
+        ("closed", task_urgency(TaskStatus::Completed, Some(may(1)), TaskPriority::Urgent)),
+        ("undated", task_urgency(TaskStatus::Open, None, TaskPriority::Urgent)),
+        ("later", task_urgency(TaskStatus::Open, Some(may(20)), TaskPriority::Low)),
+        ("soon low", task_urgency(TaskStatus::Open, Some(may(9)), TaskPriority::Low)),
+        ("soon urgent", task_urgency(TaskStatus::InProgress, Some(may(9)), TaskPriority::Urgent)),
+    ];
+    keys.sort_by_key(|(_, key)| *key);
+
+    let order: Vec<&str> = keys.iter().map(|(name, _)| *name).collect();
+    assert_eq!(order, ["soon urgent", "soon low", "later", "undated", "closed"]);
+}
+
+#[test]
+fn only_open_tasks_past_due_are_overdue() {
+    let mut task = task(Some(3), Some(3));
+    assert!(!task.is_overdue(may(9)));
+    assert!(task.is_overdue(may(10)));
+
+    task.status = TaskStatus::Completed;
+    assert!(!task.is_overdue(may(10)));
+
+    task.status = TaskStatus::Open;
+    task.due_date = None;
+    assert!(!task.is_overdue(may(10)));
+}
+
+#[test]
+fn assigning_others_needs_assign_tasks() {
+    let officer = user(3, UserRole::LoanOfficer);
+    assert!(can_assign_task(&officer, Some(3)));
+    assert!(!can_assign_task(&officer, Some(4)));
+    assert!(!can_assign_task(&officer, None));
+
+    let manager = user(5, UserRole::Manager);
+    assert!(can_assign_task(&manager, Some(4)));
+    assert!(can_assign_task(&manager, None));
+}
+
+#[test]
+fn assignee_creator_and_loan_editors_may_change_a_task() {
+    let officer = user(3, UserRole::LoanOfficer);
+    // On someone else's loan, only their own tasks
+    assert!(task(Some(3), Some(9)).editable_by(&officer, Some(4)));
+    assert!(task(None, Some(3)).editable_by(&officer, Some(4)));
+    assert!(!task(Some(9), Some(9)).editable_by(&officer, Some(4)));
+    // On their own loan, any task
+    assert!(task(Some(9), Some(9)).editable_by(&officer, Some(3)));
+
+    assert!(task(Some(9), Some(9)).editable_by(&user(5, UserRole::Processor), Some(4)));
+    assert!(task(Some(9), Some(9)).editable_by(&user(6, UserRole::Manager), None));
+}
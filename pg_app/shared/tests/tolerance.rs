@@ -0,0 +1,182 @@
+//! Loan Estimate to Closing Disclosure fee tolerances and cures
+// Amounts are written as dollars_cents, e.g. `1_050_00` for $1,050.00
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use shared::calculations::tolerance::{tolerance_bucket, ToleranceBucket, ToleranceReport};
+use shared::models::{DisclosureFee, FeePayer, FeeSection};
+
+fn fee(section: FeeSection, name: &str, amount_cents: i64) -> DisclosureFee {
+    DisclosureFee {
+        id: 0,
+        disclosure_id: 0,
+        section,
+        name: name.to_string(),
+        amount_cents,
+        payer: FeePayer::Borrower,
+        paid_to: None,
+        finance_charge: false,
+        off_list_provider: false,
+        recording_fee: false,
+        loan_fee_id: None,
+    }
+}
+
+fn recording(amount_cents: i64) -> DisclosureFee {
+    DisclosureFee {
+        recording_fee: true,
+        ..fee(FeeSection::TaxesGovernment, "Recording Fees", amount_cents)
+    }
+}
+
+#[test]
+fn fees_fall_into_the_regulation_buckets() {
+    use FeeSection::*;
+
+    assert_eq!(tolerance_bucket(Origination, false, false), ToleranceBucket::Zero);
+    assert_eq!(tolerance_bucket(CannotShop, false, false), ToleranceBucket::Zero);
+    assert_eq!(tolerance_bucket(CanShop, false, false), ToleranceBucket::TenPercent);
+    assert_eq!(tolerance_bucket(CanShop, false, true), ToleranceBucket::Unlimited);
+    assert_eq!(tolerance_bucket(TaxesGovernment, true, false), ToleranceBucket::TenPercent);
+    assert_eq!(tolerance_bucket(TaxesGovernment, false, false), ToleranceBucket::Zero);
+    assert_eq!(tolerance_bucket(Prepaids, false, false), ToleranceBucket::Unlimited);
+    assert_eq!(tolerance_bucket(InitialEscrow, false, false), ToleranceBucket::Unlimited);
+}
+
+#[test]
+fn zero_tolerance_decreases_do_not_offset_increases() {
+    let le = vec![
+        fee(FeeSection::Origination, "Origination Fee", 1_000_00),
+        fee(FeeSection::CannotShop, "Appraisal Fee", 600_00),
+    ];
+    let cd = vec![
+        fee(FeeSection::Origination, "Origination Fee", 900_00),
+        fee(FeeSection::CannotShop, "Appraisal Fee", 650_00),
+        fee(FeeSection::CannotShop, "Flood Certification", 15_00),
+    ];
+
+    let report = ToleranceReport::compare(&le, &cd);
+    assert_eq!(report.zero_tolerance_cure_cents, 65_00);
+    let flood = report.changes.iter().find(|c| c.name == "Flood Certification").unwrap();
+    assert_eq!((flood.disclosed_cents, flood.final_cents, flood.cure_cents), (None, Some(15_00), 15_00));
+    let origination = report.changes.iter().find(|c| c.name == "Origination Fee").unwrap();
+    assert_eq!((origination.change_cents(), origination.cure_cents), (-100_00, 0));
+}
+
+#[test]
+fn ten_percent_bucket_is_cured_only_beyond_the_limit() {
+    let le = vec![
+        fee(FeeSection::CanShop, "Title - Settlement Fee", 800_00),
+        recording(200_00),
+    ];
+    let at_limit = vec![
+        fee(FeeSection::CanShop, "Title - Settlement Fee", 900_00),
+        recording(200_00),
+    ];
+    let over_limit = vec![
+        fee(FeeSection::CanShop, "Title - Settlement Fee", 900_01),
+        recording(200_00),
+    ];
+
+    let report = ToleranceReport::compare(&le, &at_limit);
+    assert_eq!(report.ten_percent_limit_cents(), 1_100_00);
+    assert_eq!(report.total_cure_cents(), 0);
+
+    let report = ToleranceReport::compare(&le, &over_limit);
+    assert_eq!(report.ten_percent_final_cents, 1_100_01);
+    assert_eq!(report.ten_percent_cure_cents, 1);
+    assert!(report.changes.iter().all(|change| change.cure_cents == 0));
+}
+
+#[test]
+fn only_borrower_paid_fees_are_compared() {
+    let le = vec![fee(FeeSection::Origination, "Origination Fee", 1_000_00)];
+    let cd = vec![
+        fee(FeeSection::Origination, "Origination Fee", 1_000_00),
+        DisclosureFee {
+            payer: FeePayer::Seller,
+            ..fee(FeeSection::Origination, "Origination Fee", 500_00)
+        },
+        DisclosureFee {
+            payer: FeePayer::Lender,
+            ..fee(FeeSection::CannotShop, "Appraisal Fee", 600_00)
+        },
+    ];
+
+    let report = ToleranceReport::compare(&le, &cd);
+    assert_eq!(report.changes.len(), 1);
+    assert_eq!(report.total_cure_cents(), 0);
+}
+
+#[test]
+fn fees_match_by_section_and_name_ignoring_case_and_spaces() {
+    let le = vec![fee(FeeSection::CannotShop, "Credit Report", 50_00)];
+    let cd = vec![
+        fee(FeeSection::CannotShop, " credit report ", 30_00),
+        fee(FeeSection::CannotShop, "CREDIT REPORT", 30_00),
+    ];
+
+    let report = ToleranceReport::compare(&le, &cd);
+    assert_eq!(report.changes.len(), 1);
+    assert_eq!(report.changes[0].final_cents, Some(60_00));
+    assert_eq!(report.zero_tolerance_cure_cents, 10_00);
+}
+
+#[test]
+fn shopping_off_the_list_lifts_the_limit() {
+    let le = vec![fee(FeeSection::CanShop, "Pest Inspection", 100_00)];
+    let cd = vec![DisclosureFee {
+        off_list_provider: true,
+        ..fee(FeeSection::CanShop, "Pest Inspection", 300_00)
+    }];
+
+    let report = ToleranceReport::compare(&le, &cd);
+    assert_eq!(report.changes[0].bucket, ToleranceBucket::Unlimited);
+    assert_eq!(report.ten_percent_disclosed_cents, 0);
+    assert_eq!(report.total_cure_cents(), 0);
+}
+
+#[test]
+fn recording_fees_are_flagged_not_guessed_from_the_name() {
+    // "Deed recording tax" is a transfer tax despite its name
+    let le = vec![fee(FeeSection::TaxesGovernment, "Deed Recording Tax", 100_00), recording(100_00)];
+    let cd = vec![fee(FeeSection::TaxesGovernment, "Deed Recording Tax", 105_00), recording(105_00)];
+
+    let report = ToleranceReport::compare(&le, &cd);
+    let tax = report.changes.iter().find(|c| c.name == "Deed Recording Tax").unwrap();
+    assert_eq!((tax.bucket, tax.cure_cents), (ToleranceBucket::Zero, 5_00));
+    assert_eq!(report.ten_percent_final_cents, 105_00);
+    assert_eq!(report.total_cure_cents(), 5_00);
+}
+
+#[test]
+fn a_fee_moved_to_another_section_is_still_the_same_fee() {
+    let le = vec![DisclosureFee {
+        loan_fee_id: Some(7),
+        ..fee(FeeSection::CannotShop, "Survey Fee", 400_00)
+    }];
+    let cd = vec![DisclosureFee {
+        loan_fee_id: Some(7),
+        ..fee(FeeSection::CanShop, "Survey", 420_00)
+    }];
+
+    let report = ToleranceReport::compare(&le, &cd);
+    assert_eq!(report.changes.len(), 1);
+    let survey = &report.changes[0];
+    assert_eq!((survey.section, survey.name.as_str()), (FeeSection::CanShop, "Survey"));
+    assert_eq!((survey.disclosed_cents, survey.final_cents), (Some(400_00), Some(420_00)));
+    // Within the 10% of its new bucket, and not a new zero-tolerance fee
+    assert_eq!(survey.bucket, ToleranceBucket::TenPercent);
+    assert_eq!(report.total_cure_cents(), 0);
+}
+
+#[test]
+fn lender_credits_may_not_decrease() {
+    let le = vec![fee(FeeSection::Origination, "Origination Fee", 1_000_00)];
+
+    let report = ToleranceReport::compare(&le, &le).with_lender_credits(500_00, 350_00);
+    assert_eq!(report.lender_credit_cure_cents, 150_00);
+    assert_eq!(report.total_cure_cents(), 150_00);
+
+    let report = ToleranceReport::compare(&le, &le).with_lender_credits(500_00, 650_00);
+    assert_eq!(report.total_cure_cents(), 0);
+}
@@ -0,0 +1,80 @@
+//! Credit report tradeline files in the documented JSON and CSV layouts
+// Amounts are written as dollars_cents, e.g. `2_450_17` for $2,450.17
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use shared::imports::tradelines::{parse_tradelines, TradelineFormat};
+use shared::models::LiabilityType;
+
+#[test]
+fn json_accepts_an_object_or_a_bare_array() {
+    let wrapped = r#"{"tradelines": [
+        {"creditor": "Capital One", "type": "revolving", "balance": 2450.17, "monthly_payment": 75, "account": "4111111111111234"}
+    ]}"#;
+    let parsed = parse_tradelines(wrapped, TradelineFormat::Json).unwrap();
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].creditor_name, "Capital One");
+    assert_eq!(parsed[0].liability_type, LiabilityType::Revolving);
+    assert_eq!(parsed[0].balance_cents, 2_450_17);
+    assert_eq!(parsed[0].monthly_payment_cents, 75_00);
+    assert_eq!(parsed[0].account_last4.as_deref(), Some("1234"));
+    assert!(!parsed[0].paid_off_at_closing && !parsed[0].excluded);
+
+    let bare = r#"[{"creditor": "Sallie Mae", "type": "student loan", "balance": "12,000.00"}]"#;
+    let parsed = parse_tradelines(bare, TradelineFormat::Json).unwrap();
+    assert_eq!(parsed[0].liability_type, LiabilityType::Student);
+    assert_eq!(parsed[0].balance_cents, 12_000_00);
+    assert_eq!(parsed[0].monthly_payment_cents, 0);
+    assert_eq!(parsed[0].account_last4, None);
+}
+
+#[test]
+fn csv_columns_may_come_in_any_order() {
+    let contents = "\
+Type,Creditor,Monthly_Payment,Balance
+auto,Toyota Financial,412.50,\"$18,200\"
+credit-card,Chase,,
+";
+    let parsed = parse_tradelines(contents, TradelineFormat::Csv).unwrap();
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(parsed[0].liability_type, LiabilityType::Auto);
+    assert_eq!(parsed[0].balance_cents, 18_200_00);
+    assert_eq!(parsed[0].monthly_payment_cents, 412_50);
+    assert_eq!(parsed[1].liability_type, LiabilityType::Revolving);
+    assert_eq!((parsed[1].balance_cents, parsed[1].monthly_payment_cents), (0, 0));
+}
+
+#[test]
+fn every_bad_record_is_reported_with_its_line() {
+    let contents = "\
+creditor,type,balance,monthly_payment
+Capital One,revolving,100,10
+,auto,100,10
+Wells Fargo,boat,100,10
+Discover,revolving,lots,10
+";
+    let errors = parse_tradelines(contents, TradelineFormat::Csv).unwrap_err();
+    let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+    assert_eq!(lines, [3, 4, 5]);
+    assert!(errors[1].message.contains("boat"));
+    assert!(errors[2].message.contains("balance 'lots'"));
+}
+
+#[test]
+fn unreadable_files_fail_at_line_zero_or_the_header() {
+    let errors = parse_tradelines("{not json", TradelineFormat::Json).unwrap_err();
+    assert_eq!(errors[0].line, 0);
+
+    let errors = parse_tradelines(r#"{"debts": []}"#, TradelineFormat::Json).unwrap_err();
+    assert_eq!(errors[0].line, 0);
+
+    let errors = parse_tradelines("creditor,balance\nChase,100\n", TradelineFormat::Csv).unwrap_err();
+    assert_eq!(errors[0].line, 1);
+    assert!(errors[0].message.contains("'type'"));
+}
+
+#[test]
+fn format_follows_the_file_extension() {
+    assert_eq!(TradelineFormat::from_file_name("report.JSON"), Some(TradelineFormat::Json));
+    assert_eq!(TradelineFormat::from_file_name("tradelines.csv"), Some(TradelineFormat::Csv));
+    assert_eq!(TradelineFormat::from_file_name("report.pdf"), None);
+}
@@ -0,0 +1,95 @@
+//! TRID waiting periods on the federal business-day calendar
+
+use chrono::NaiveDate;
+use shared::calculations::trid::{cd_received_date, earliest_consummation, trid_timeline, MilestoneStatus};
+use shared::models::{DisclosureDelivery, TridDates};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn cd_dates(delivered: NaiveDate, method: DisclosureDelivery) -> TridDates {
+    TridDates {
+        cd_delivered_date: Some(delivered),
+        cd_delivery_method: method,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn loan_estimate_is_due_three_general_business_days_after_application() {
+    // Applied Wednesday before Thanksgiving; the holiday and weekend are skipped
+    let timeline = trid_timeline(Some(date(2025, 11, 26)), &TridDates::default(), date(2025, 11, 26));
+    assert_eq!(timeline[0].due, Some(date(2025, 12, 2)));
+}
+
+#[test]
+fn open_loan_estimate_moves_from_pending_to_at_risk_to_missed() {
+    // Applied Monday, June 2, 2025; due Thursday, June 5
+    let applied = Some(date(2025, 6, 2));
+    let status = |today| trid_timeline(applied, &TridDates::default(), today)[0].status;
+
+    assert_eq!(status(date(2025, 6, 3)), MilestoneStatus::Pending);
+    assert_eq!(status(date(2025, 6, 4)), MilestoneStatus::AtRisk);
+    assert_eq!(status(date(2025, 6, 5)), MilestoneStatus::AtRisk);
+    assert_eq!(status(date(2025, 6, 6)), MilestoneStatus::Missed);
+    assert!(MilestoneStatus::AtRisk.is_at_risk());
+    assert!(MilestoneStatus::Missed.is_at_risk());
+    assert!(!MilestoneStatus::Pending.is_at_risk());
+}
+
+#[test]
+fn delivered_loan_estimate_is_met_or_missed_by_its_date() {
+    let applied = Some(date(2025, 6, 2));
+    let delivered = |day| TridDates {
+        le_delivered_date: Some(date(2025, 6, day)),
+        ..Default::default()
+    };
+
+    assert_eq!(trid_timeline(applied, &delivered(5), date(2025, 6, 20))[0].status, MilestoneStatus::Met);
+    assert_eq!(trid_timeline(applied, &delivered(6), date(2025, 6, 20))[0].status, MilestoneStatus::Missed);
+}
+
+#[test]
+fn milestones_without_dates_are_not_scheduled() {
+    let timeline = trid_timeline(None, &TridDates::default(), date(2025, 6, 2));
+    assert_eq!(timeline[0].status, MilestoneStatus::NotScheduled);
+    assert_eq!(timeline[1].status, MilestoneStatus::NotScheduled);
+}
+
+#[test]
+fn mailed_closing_disclosure_is_presumed_received_three_specific_business_days_later() {
+    // Delivered Thursday, June 12, 2025: Friday and Saturday count, Sunday does not
+    let mailed = cd_dates(date(2025, 6, 12), DisclosureDelivery::Mail);
+    assert_eq!(cd_received_date(&mailed), Some(date(2025, 6, 16)));
+    assert_eq!(
+        cd_received_date(&cd_dates(date(2025, 6, 12), DisclosureDelivery::Electronic)),
+        Some(date(2025, 6, 16))
+    );
+    assert_eq!(
+        cd_received_date(&cd_dates(date(2025, 6, 12), DisclosureDelivery::InPerson)),
+        Some(date(2025, 6, 12))
+    );
+
+    // A confirmed receipt date wins over the presumption
+    let confirmed = TridDates {
+        cd_received_date: Some(date(2025, 6, 13)),
+        ..mailed.clone()
+    };
+    assert_eq!(cd_received_date(&confirmed), Some(date(2025, 6, 13)));
+
+    // Received Monday, June 16; Juneteenth is skipped, so closing can be Friday
+    assert_eq!(earliest_consummation(&mailed), Some(date(2025, 6, 20)));
+}
+
+#[test]
+fn closing_disclosure_must_be_received_three_specific_business_days_before_consummation() {
+    let dates = TridDates {
+        consummation_date: Some(date(2025, 6, 27)),
+        ..cd_dates(date(2025, 6, 24), DisclosureDelivery::InPerson)
+    };
+    let timeline = trid_timeline(Some(date(2025, 6, 2)), &dates, date(2025, 6, 24));
+    assert_eq!(timeline[1].due, Some(date(2025, 6, 24)));
+    assert_eq!(timeline[1].actual, Some(date(2025, 6, 24)));
+    assert_eq!(timeline[1].status, MilestoneStatus::Met);
+}